@@ -0,0 +1,26 @@
+//! Print the annotations of a live session to stdout as JSON Lines, as the analyzers
+//! detect them.
+//!
+//! Run `ocypode serve` on the machine with the sim, then point the example at it:
+//!
+//! ```sh
+//! $ cargo run --example annotations_stdout -- 192.168.1.20:7878
+//! ```
+
+use ocypode::{NetworkTelemetryProducer, annotation_sink, collect_telemetry};
+
+fn main() {
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "127.0.0.1:7878".to_string());
+
+    let sink = annotation_sink(|event| match serde_json::to_string(&event) {
+        Ok(line) => println!("{}", line),
+        Err(e) => eprintln!("Could not serialize {:?}: {}", event.annotation, e),
+    });
+
+    if let Err(e) = collect_telemetry(NetworkTelemetryProducer::new(address), vec![sink]) {
+        eprintln!("Telemetry collection stopped: {}", e);
+        std::process::exit(1);
+    }
+}
@@ -1,10 +1,16 @@
 // Error types for ocypode
 
-use crate::telemetry::TelemetryOutput;
+use crate::telemetry::{GameSource, TelemetryOutput};
 use snafu::Snafu;
 use std::{io, sync::mpsc::SendError};
 
+/// Errors returned by Ocypode.
+///
+/// Common failure modes use structured variants so library consumers can match on
+/// them directly. The enum is `#[non_exhaustive]`, new variants may be added in
+/// minor releases.
 #[derive(Debug, Snafu)]
+#[non_exhaustive]
 pub enum OcypodeError {
     // Errors for the iRacing client
     #[snafu(display("Unable to find iRacing session"))]
@@ -20,8 +26,17 @@ pub enum OcypodeError {
     // Errors while reading and broadcasting telemetry data
     #[snafu(display("Missing iRacing client, session not initialized"))]
     MissingIRacingSession,
+    #[snafu(display("Unable to connect to {game:?} telemetry: {reason}"))]
+    ProducerConnectError { game: GameSource, reason: String },
+    #[snafu(display("Session info unavailable from {game:?}: {reason}"))]
+    SessionInfoUnavailable { game: GameSource, reason: String },
     #[snafu(display("Telemetry point producer error"))]
     TelemetryProducerError { description: String },
+    #[snafu(display("Telemetry stream error on {address}"))]
+    NetworkTelemetryError { address: String, source: io::Error },
+    #[cfg(feature = "acc-broadcast")]
+    #[snafu(display("ACC broadcasting API client error"))]
+    BroadcastClientError { source: io::Error },
     #[snafu(display("Error broadcasting telemetry data point"))]
     TelemetryBroadcastError {
         source: Box<SendError<TelemetryOutput>>,
@@ -30,6 +45,11 @@ pub enum OcypodeError {
     // Errors for the telemetry writer
     #[snafu(display("Error writing telemetry file"))]
     WriterError { source: io::Error },
+    #[cfg(feature = "parquet")]
+    #[snafu(display("Error writing Parquet telemetry file"))]
+    ParquetWriterError {
+        source: parquet::errors::ParquetError,
+    },
 
     // Config management errors
     #[snafu(display("Could not find application data directory to save config file"))]
@@ -44,6 +64,21 @@ pub enum OcypodeError {
     InvalidTelemetryFile { path: String },
     #[snafu(display("Error loading telemetry file"))]
     TelemetryLoaderError { source: io::Error },
+    #[snafu(display("Could not parse telemetry at line {line}: {source}"))]
+    TelemetryParseError {
+        line: usize,
+        source: serde_json::Error,
+    },
+    #[snafu(display("Invalid iRacing telemetry file: {reason}"))]
+    InvalidIbtFile { reason: String },
+    #[snafu(display("Invalid session metadata field {field}: {reason}"))]
+    InvalidSessionMetadata { field: String, reason: String },
+    #[snafu(display("Could not read or save the session notes"))]
+    SessionNotesError { source: io::Error },
+    #[snafu(display("Could not save the lap report image"))]
+    ReportExportError { source: image::ImageError },
+    #[snafu(display("Could not serialize the session analysis"))]
+    AnalysisSerializeError { source: serde_json::Error },
     #[snafu(display(
         "Legacy telemetry file format detected. This file was created with an older version of Ocypode and is not compatible with the current version. Please re-record your session with the current version."
     ))]
@@ -2,10 +2,37 @@
 // This allows integration tests to access internal modules
 
 pub mod errors;
+pub mod session_analysis;
+pub mod session_diff;
 pub mod setup_assistant;
 pub mod telemetry;
 
 // Re-export commonly used types
 pub use errors::OcypodeError;
-pub use setup_assistant::{CornerPhase, FindingType, SetupAssistant};
-pub use telemetry::{SessionInfo, TelemetryData, TelemetryOutput};
+pub use session_analysis::{LapFilter, LapStats, SessionAnalysis, StintStats};
+pub use session_diff::{FindingChange, FindingDelta, SessionDiff};
+pub use setup_assistant::{
+    CarRecommendationOverrides, CornerPhase, CornerPhaseClassifier, FindingType,
+    ProcessedRecommendation, RecommendationEngine, RecommendationOrder, RecommendationOverride,
+    SetupAssistant,
+};
+#[cfg(feature = "acc-broadcast")]
+pub use telemetry::acc_broadcast::{
+    AccBroadcastClient, AccBroadcastConfig, RaceFlag, RaceStandings, SessionPhase,
+};
+pub use telemetry::analyzer_config::{AnalyzerConfig, AnalyzerKind};
+pub use telemetry::annotation_stream::{AnnotationEvent, annotation_channel, annotation_sink};
+pub use telemetry::builder::TelemetryDataBuilder;
+pub use telemetry::coasting::{CoastingDetection, CoastingStats, CornerCoasting};
+pub use telemetry::collect_telemetry;
+pub use telemetry::corner_speed::{CornerMinSpeed, CornerSpeedDeficit, OverSlowing};
+pub use telemetry::corners::{CornerDetection, CornerWindow, DetectedCorner};
+pub use telemetry::data_quality::{DataQualityReport, FieldCoverage};
+pub use telemetry::grip_usage::{CornerGripUsage, GripUsage};
+pub use telemetry::ibt::{IbtTelemetry, read_ibt, read_ibt_with_progress};
+pub use telemetry::producer::{MockTelemetryProducer, NetworkTelemetryProducer, TelemetryProducer};
+pub use telemetry::steering_reversals::SteeringReversals;
+pub use telemetry::stint::{Pace, Stint, StintLap, StintSummary, Trend};
+pub use telemetry::{
+    ExitUndersteerCause, LoadTransferTiming, SessionInfo, TelemetryData, TelemetryOutput,
+};
@@ -1,17 +1,34 @@
 mod errors;
+#[cfg(feature = "parquet")]
+mod parquet_writer;
+mod session_analysis;
+mod session_diff;
+mod session_notes;
 mod setup_assistant;
 mod telemetry;
 mod ui;
+mod validate;
 mod writer;
 
-use std::{path::PathBuf, sync::mpsc, thread};
+use std::{
+    net::TcpListener,
+    path::{Path, PathBuf},
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+    thread::{self, JoinHandle},
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use clap::{Parser, Subcommand, ValueEnum, arg};
-use egui::Vec2;
 use errors::OcypodeError;
+use log::{debug, error, info, warn};
 #[cfg(windows)]
 use telemetry::producer::{ACCTelemetryProducer, IRacingTelemetryProducer};
-use ui::analysis::TelemetryAnalysisApp;
+use telemetry::{PedalDeadzones, producer::NetworkTelemetryProducer};
+use ui::analysis::{TelemetryAnalysisApp, trends::TrendsApp};
 use ui::live::{HISTORY_SECONDS, LiveTelemetryApp, config::AppConfig};
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -23,6 +40,64 @@ enum GameSource {
     ACC,
 }
 
+impl From<GameSource> for telemetry::GameSource {
+    fn from(value: GameSource) -> Self {
+        match value {
+            GameSource::IRacing => telemetry::GameSource::IRacing,
+            GameSource::ACC => telemetry::GameSource::ACC,
+        }
+    }
+}
+
+/// Where the live windows read telemetry from
+#[derive(Debug, Clone)]
+enum LiveSource {
+    /// The sim running on this machine
+    Game(GameSource),
+    /// Another machine streaming telemetry with `ocypode serve`
+    Remote(String),
+}
+
+/// File format of the telemetry saved with `--output`
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+enum OutputFormat {
+    /// One JSON object per line, can be loaded back in the analysis window
+    #[default]
+    Jsonl,
+    /// Columnar file for DuckDB, pandas and similar tools
+    #[cfg(feature = "parquet")]
+    Parquet,
+}
+
+impl OutputFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Jsonl => "jsonl",
+            #[cfg(feature = "parquet")]
+            OutputFormat::Parquet => "parquet",
+        }
+    }
+}
+
+/// Set on Ctrl-C or when the UI closes, the recorder then closes its file and stops
+static RECORDING_SHUTDOWN: AtomicBool = AtomicBool::new(false);
+/// Thread recording telemetry with `--output`, joined before exiting so the file is complete
+static RECORDER: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+/// Asks the recorder to close its output file and waits until it has.
+fn stop_recording() {
+    RECORDING_SHUTDOWN.store(true, Ordering::SeqCst);
+    let recorder = RECORDER
+        .lock()
+        .ok()
+        .and_then(|mut recorder| recorder.take());
+    if let Some(recorder) = recorder
+        && recorder.join().is_err()
+    {
+        error!("Telemetry recorder panicked");
+    }
+}
+
 // OcypodeError is now defined in errors.rs
 
 #[derive(Parser, Debug)]
@@ -42,166 +117,522 @@ enum Commands {
         #[arg(short, long)]
         output: Option<PathBuf>,
 
+        /// Format of the output file
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::default())]
+        format: OutputFormat,
+
+        /// Treat the output as a directory and start a new file, named after the track and start time, for each session
+        #[arg(long, requires = "output")]
+        split_sessions: bool,
+
+        #[arg(short, long, value_enum, required_unless_present = "remote")]
+        game: Option<GameSource>,
+
+        /// Show the telemetry streamed by `ocypode serve` on another machine, e.g. 192.168.1.20:7878
+        #[arg(long, conflicts_with = "game")]
+        remote: Option<String>,
+
+        /// Open regular decorated windows instead of the always-on-top overlay. Persisted in the config file
+        #[arg(long, conflicts_with = "overlay")]
+        windowed: bool,
+
+        /// Switch back to the always-on-top transparent overlay. Persisted in the config file
+        #[arg(long)]
+        overlay: bool,
+
+        /// Also stream the telemetry to stdout as JSON Lines, for other tools to consume
+        #[arg(long)]
+        stdout: bool,
+    },
+    /// Stream live telemetry over the network to `ocypode live --remote`, without opening any window
+    Serve {
         #[arg(short, long, value_enum)]
         game: GameSource,
+
+        /// Address and port to listen on
+        #[arg(short, long, default_value = "0.0.0.0:7878")]
+        address: String,
     },
     Load {
+        /// Telemetry file to open, a JSONL capture or an iRacing .ibt file
         #[arg(short, long)]
         input: PathBuf,
+
+        /// Telemetry file of an earlier session to compare the findings with
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+    /// Print the lap stats, setup findings and recommendations of a telemetry file, without opening any window
+    Analyze {
+        /// Telemetry file to analyze, a JSONL capture or an iRacing .ibt file
+        input: PathBuf,
+
+        /// Print the analysis of every session as JSON, for scripts and other tools
+        #[arg(long)]
+        json: bool,
+
+        /// Telemetry file of an earlier session, print which findings were resolved,
+        /// decreased or appeared since
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+    /// Plot best lap times and setup findings of many telemetry files over time
+    Trends {
+        /// Telemetry files to add to the trend project saved in the config file
+        inputs: Vec<PathBuf>,
+
+        /// Empty the trend project before adding the inputs
+        #[arg(long)]
+        clear: bool,
+    },
+    /// Check a JSONL telemetry file for malformed and out-of-order records
+    Validate {
+        input: PathBuf,
+
+        /// Write a copy of the file without the malformed and out-of-order records
+        #[arg(long)]
+        fix: bool,
+
+        /// Path of the cleaned copy, defaults to the input name with a `.fixed.jsonl` extension
+        #[arg(short, long, requires = "fix")]
+        output: Option<PathBuf>,
     },
 }
 
-fn live(window_size: usize, output: Option<PathBuf>, game: GameSource) -> Result<(), OcypodeError> {
+fn live(
+    window_size: usize,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+    split_sessions: bool,
+    source: LiveSource,
+    windowed: Option<bool>,
+    stdout: bool,
+) -> Result<(), OcypodeError> {
     #[cfg(not(windows))]
-    {
-        eprintln!("Error: Live telemetry is only supported on Windows");
-        eprintln!("Supported games: iracing, acc");
-        return Err(OcypodeError::TelemetryProducerError {
-            description: "Live telemetry is only supported on Windows".to_string(),
+    if let LiveSource::Game(game) = source {
+        error!("Live telemetry is only supported on Windows");
+        info!(
+            "Use --remote to show the telemetry streamed by `ocypode serve` on a Windows machine"
+        );
+        return Err(OcypodeError::ProducerConnectError {
+            game: game.into(),
+            reason: "live telemetry is only supported on Windows".to_string(),
         });
     }
 
-    #[cfg(windows)]
-    {
-        println!("Starting telemetry collection for {:?}...", game);
-        println!("Waiting for game connection (this may take up to 10 minutes)...");
-        println!("Make sure you're in an active session (on track, not in menus)");
-
-        let (telemtry_tx, telemetry_rx) = mpsc::channel::<telemetry::TelemetryOutput>();
-
-        // if we need to write an output file we create a new channel and have the telemetry reader send to both the plotting
-        // and writer channels
-        if let Some(output_file) = output {
-            let (telemetry_writer_tx, telemetry_writer_rx) =
-                mpsc::channel::<telemetry::TelemetryOutput>();
-
-            thread::spawn(move || {
-                // Instantiate the correct producer based on the game parameter
-                let result = match game {
-                    GameSource::IRacing => {
-                        let telemetry_producer = IRacingTelemetryProducer::default();
-                        telemetry::collect_telemetry(
-                            telemetry_producer,
-                            telemtry_tx,
-                            Some(telemetry_writer_tx),
-                        )
-                    }
-                    GameSource::ACC => {
-                        let telemetry_producer = ACCTelemetryProducer::default();
-                        telemetry::collect_telemetry(
-                            telemetry_producer,
-                            telemtry_tx,
-                            Some(telemetry_writer_tx),
-                        )
-                    }
-                };
-
-                if let Err(e) = result {
-                    // Only log the error if it's not a SendError (which happens when UI closes)
-                    match e {
-                        OcypodeError::TelemetryBroadcastError { .. } => {
-                            // UI closed, this is expected - exit gracefully
-                        }
-                        _ => {
-                            eprintln!("Error while reading telemetry: {:?}", e);
-                        }
-                    }
-                }
-            });
-            thread::spawn(move || writer::write_telemetry(&output_file, telemetry_writer_rx));
-        } else {
-            thread::spawn(move || {
-                // Instantiate the correct producer based on the game parameter
-                let result = match game {
-                    GameSource::IRacing => {
-                        let telemetry_producer = IRacingTelemetryProducer::default();
-                        telemetry::collect_telemetry(telemetry_producer, telemtry_tx, None)
-                    }
-                    GameSource::ACC => {
-                        let telemetry_producer = ACCTelemetryProducer::default();
-                        telemetry::collect_telemetry(telemetry_producer, telemtry_tx, None)
-                    }
-                };
-
-                if let Err(e) = result {
-                    // Only log the error if it's not a SendError (which happens when UI closes)
-                    match e {
-                        OcypodeError::TelemetryBroadcastError { .. } => {
-                            // UI closed, this is expected - exit gracefully
-                        }
-                        _ => {
-                            eprintln!("Error while reading telemetry: {:?}", e);
-                        }
-                    }
+    match &source {
+        LiveSource::Game(game) => {
+            info!("Starting telemetry collection for {:?}...", game);
+            info!("Waiting for game connection (this may take up to 10 minutes)...");
+            info!("Make sure you're in an active session (on track, not in menus)");
+        }
+        LiveSource::Remote(address) => {
+            info!("Connecting to the telemetry stream at {}...", address);
+        }
+    }
+
+    let (telemtry_tx, telemetry_rx) = mpsc::channel::<telemetry::TelemetryOutput>();
+
+    // Multiplayer data comes from ACC's broadcasting API, shown by the UI until it closes
+    #[cfg(feature = "acc-broadcast")]
+    let acc_broadcast = match source {
+        LiveSource::Game(GameSource::ACC) => {
+            match telemetry::acc_broadcast::AccBroadcastClient::start(Default::default()) {
+                Ok(client) => Some(client),
+                Err(e) => {
+                    error!("Could not start ACC broadcasting client: {}", e);
+                    None
                 }
-            });
+            }
+        }
+        _ => None,
+    };
+
+    let mut app_config = AppConfig::from_local_file().unwrap_or(AppConfig {
+        window_size_s: window_size,
+        ..Default::default()
+    });
+    if let Some(windowed) = windowed {
+        app_config.windowed = windowed;
+    }
+
+    // every consumer gets its own channel, the collector sends each point to all of them
+    let mut sinks = vec![telemtry_tx];
+    let mut recording = None;
+    if let Some(output_path) = output {
+        // the output is a directory of recordings only with --split-sessions, other
+        // directories are never cleaned up
+        if split_sessions {
+            let now_s = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default();
+            if let Err(e) =
+                writer::apply_retention(&output_path, &app_config.recording_retention, now_s)
+            {
+                error!(
+                    "Could not clean up old recordings in {:?}: {}",
+                    output_path, e
+                );
+            }
         }
 
-        let app_config = AppConfig::from_local_file().unwrap_or(AppConfig {
-            window_size_s: window_size,
-            ..Default::default()
+        let (telemetry_writer_tx, telemetry_writer_rx) =
+            mpsc::channel::<telemetry::TelemetryOutput>();
+        sinks.push(telemetry_writer_tx);
+
+        let recording_index = writer::RecordingIndex::default();
+        recording = Some(recording_index.clone());
+        let recorder = thread::spawn(move || {
+            let write_file = match format {
+                OutputFormat::Jsonl => writer::write_telemetry,
+                #[cfg(feature = "parquet")]
+                OutputFormat::Parquet => parquet_writer::write_telemetry_parquet,
+            };
+            let result = writer::record_telemetry(
+                &output_path,
+                split_sessions,
+                format.extension(),
+                telemetry_writer_rx,
+                &RECORDING_SHUTDOWN,
+                &recording_index,
+                write_file,
+            );
+            if let Err(e) = result {
+                error!(
+                    "Error while recording telemetry to {:?}: {}",
+                    output_path, e
+                );
+            }
+        });
+        if let Ok(mut slot) = RECORDER.lock() {
+            *slot = Some(recorder);
+        }
+    }
+    if stdout {
+        let (telemetry_stdout_tx, telemetry_stdout_rx) =
+            mpsc::channel::<telemetry::TelemetryOutput>();
+        sinks.push(telemetry_stdout_tx);
+
+        thread::spawn(move || {
+            if let Err(e) = writer::stream_telemetry_stdout(telemetry_stdout_rx) {
+                error!("Error while streaming telemetry to stdout: {}", e);
+            }
         });
-        let telemetry_window_position = app_config.telemetry_window_position.clone();
-
-        let mut native_options = eframe::NativeOptions::default();
-        native_options.viewport = native_options
-            .viewport
-            .with_always_on_top()
-            .with_decorations(false)
-            .with_transparent(true)
-            .with_inner_size(Vec2::new(500., 200.))
-            .with_position(telemetry_window_position);
-
-        eframe::run_native(
-            "Ocypode",
-            native_options,
-            Box::new(|cc| {
-                Ok(Box::new(LiveTelemetryApp::new(
-                    telemetry_rx,
-                    app_config,
-                    cc,
-                )))
-            }),
-        )
-        .expect("could not start app");
     }
 
+    let pedal_deadzones = app_config.pedal_deadzones;
+    thread::spawn(move || {
+        let result = match source {
+            LiveSource::Game(game) => collect_game_telemetry(game, pedal_deadzones, sinks),
+            LiveSource::Remote(address) => {
+                telemetry::collect_telemetry(NetworkTelemetryProducer::new(address), sinks)
+            }
+        };
+
+        log_telemetry_result(result);
+    });
+
+    let telemetry_window_position = app_config.telemetry_window_position.clone();
+
+    let mut native_options = eframe::NativeOptions::default();
+    native_options.viewport = app_config
+        .apply_window_mode(native_options.viewport)
+        .with_inner_size(app_config.window_layout.main_window_size())
+        .with_position(telemetry_window_position);
+
+    eframe::run_native(
+        "Ocypode",
+        native_options,
+        Box::new(|cc| {
+            let app = LiveTelemetryApp::new(telemetry_rx, app_config, cc).with_recording(recording);
+            #[cfg(feature = "acc-broadcast")]
+            let app = app.with_acc_broadcast(acc_broadcast);
+            Ok(Box::new(app))
+        }),
+    )
+    .expect("could not start app");
+
+    stop_recording();
+
     Ok(())
 }
 
-fn load(input: &PathBuf) -> Result<(), OcypodeError> {
-    if !input.exists() {
-        return Err(OcypodeError::InvalidTelemetryFile {
-            path: format!("{:?}", input),
-        });
+/// Stream live telemetry from the game to every client connected to `address`.
+fn serve(game: GameSource, address: &str) -> Result<(), OcypodeError> {
+    let listener = TcpListener::bind(address).map_err(|e| OcypodeError::NetworkTelemetryError {
+        address: address.to_string(),
+        source: e,
+    })?;
+    info!("Streaming {:?} telemetry on {}", game, address);
+
+    let (telemetry_tx, telemetry_rx) = mpsc::channel::<telemetry::TelemetryOutput>();
+    thread::spawn(move || writer::serve_telemetry_tcp(listener, telemetry_rx));
+
+    let pedal_deadzones = AppConfig::from_local_file()
+        .unwrap_or_default()
+        .pedal_deadzones;
+    collect_game_telemetry(game, pedal_deadzones, vec![telemetry_tx])
+}
+
+/// Read telemetry from the sim running on this machine and send it to `sinks`.
+fn collect_game_telemetry(
+    game: GameSource,
+    pedal_deadzones: PedalDeadzones,
+    sinks: Vec<mpsc::Sender<telemetry::TelemetryOutput>>,
+) -> Result<(), OcypodeError> {
+    match game {
+        #[cfg(windows)]
+        GameSource::IRacing => telemetry::collect_telemetry(
+            IRacingTelemetryProducer::default().with_pedal_deadzones(pedal_deadzones),
+            sinks,
+        ),
+        #[cfg(windows)]
+        GameSource::ACC => telemetry::collect_telemetry(
+            ACCTelemetryProducer::default().with_pedal_deadzones(pedal_deadzones),
+            sinks,
+        ),
+        #[cfg(not(windows))]
+        _ => {
+            let _ = pedal_deadzones;
+            drop(sinks);
+            Err(OcypodeError::ProducerConnectError {
+                game: game.into(),
+                reason: "live telemetry is only supported on Windows".to_string(),
+            })
+        }
+    }
+}
+
+/// Log the outcome of the telemetry collection thread.
+fn log_telemetry_result(result: Result<(), OcypodeError>) {
+    match result {
+        Ok(()) => debug!("Telemetry collection stopped"),
+        // Every sink is gone once the UI closes and the recorder stops, this is expected
+        Err(OcypodeError::TelemetryBroadcastError { .. }) => {
+            debug!("No telemetry consumers left, stopping telemetry collection")
+        }
+        // The sim left the session, report it without the debug dump
+        Err(e @ OcypodeError::SessionInfoUnavailable { .. }) => {
+            warn!("Telemetry collection stopped: {}", e)
+        }
+        Err(e) => error!("Error while reading telemetry: {:?}", e),
+    }
+}
+
+fn load(input: &PathBuf, baseline: Option<PathBuf>) -> Result<(), OcypodeError> {
+    for path in std::iter::once(input).chain(baseline.as_ref()) {
+        if !path.exists() {
+            return Err(OcypodeError::InvalidTelemetryFile {
+                path: format!("{:?}", path),
+            });
+        }
     }
     eframe::run_native(
         "Ocypode Telemetry",
         eframe::NativeOptions::default(),
-        Box::new(|cc| Ok(Box::new(TelemetryAnalysisApp::from_file(input, cc)))),
+        Box::new(|cc| {
+            let app = TelemetryAnalysisApp::from_file(
+                input,
+                AppConfig::from_local_file().unwrap_or_default(),
+                cc,
+            );
+            Ok(Box::new(match baseline {
+                Some(baseline) => app.with_baseline(baseline),
+                None => app,
+            }))
+        }),
+    )
+    .expect("could not start app");
+    Ok(())
+}
+
+/// A session of the `analyze --json` output: the analysis with its diff against the
+/// baseline session as an extra key, so scripts reading the analysis keep working
+#[derive(serde::Serialize)]
+struct AnalyzedSession<'a> {
+    #[serde(flatten)]
+    analysis: &'a session_analysis::SessionAnalysis,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    baseline_diff: Option<&'a session_diff::SessionDiff>,
+}
+
+/// Print the analysis of every session in the telemetry file, as text or JSON. With a
+/// baseline, print how the findings of every session changed since the baseline, under
+/// the `baseline_diff` key of each session in JSON and after the analysis in text.
+fn analyze(input: &Path, json: bool, baseline: Option<&Path>) -> Result<(), OcypodeError> {
+    let lap_filter = AppConfig::from_local_file().unwrap_or_default().lap_filter;
+    let analyses =
+        session_analysis::SessionAnalysis::from_file_with_lap_filter(input, &lap_filter)?;
+    let baseline = match baseline {
+        Some(baseline) => {
+            session_analysis::SessionAnalysis::from_file_with_lap_filter(baseline, &lap_filter)?
+        }
+        None => Vec::new(),
+    };
+    let diffs: Vec<Option<session_diff::SessionDiff>> = analyses
+        .iter()
+        .map(|analysis| session_diff::SessionDiff::against_baseline(&baseline, analysis))
+        .collect();
+    if json {
+        let sessions: Vec<AnalyzedSession> = analyses
+            .iter()
+            .zip(&diffs)
+            .map(|(analysis, diff)| AnalyzedSession {
+                analysis,
+                baseline_diff: diff.as_ref(),
+            })
+            .collect();
+        let json = serde_json::to_string_pretty(&sessions)
+            .map_err(|e| OcypodeError::AnalysisSerializeError { source: e })?;
+        println!("{}", json);
+    } else {
+        for (analysis, diff) in analyses.iter().zip(&diffs) {
+            println!("{}", analysis);
+            if let Some(diff) = diff {
+                println!("{}", diff);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Add the inputs to the trend project in the config file and open the trend view.
+fn trends(inputs: &[PathBuf], clear: bool) -> Result<(), OcypodeError> {
+    let mut app_config = AppConfig::from_local_file().unwrap_or_default();
+    if clear {
+        app_config.trend_project.clear();
+    }
+    for input in inputs {
+        if !input.exists() {
+            return Err(OcypodeError::InvalidTelemetryFile {
+                path: format!("{:?}", input),
+            });
+        }
+        // the project is reopened from any working directory
+        let input = std::path::absolute(input)
+            .map_err(|e| OcypodeError::TelemetryLoaderError { source: e })?;
+        if !app_config.trend_project.contains(&input) {
+            app_config.trend_project.push(input);
+        }
+    }
+    app_config.save()?;
+
+    eframe::run_native(
+        "Ocypode Trends",
+        eframe::NativeOptions::default(),
+        Box::new(|cc| Ok(Box::new(TrendsApp::new(app_config, cc)))),
     )
     .expect("could not start app");
     Ok(())
 }
 
+/// Print a summary of the telemetry file and optionally write a cleaned copy.
+///
+/// Returns an error when the file cannot be read or has issues that dropping
+/// records cannot repair.
+fn validate(input: &PathBuf, fix: bool, output: Option<PathBuf>) -> Result<(), OcypodeError> {
+    let lap_filter = AppConfig::from_local_file().unwrap_or_default().lap_filter;
+    let report = validate::validate_jsonl(input, &lap_filter)?;
+    println!("{}", report);
+
+    if report.has_unrecoverable_issues() {
+        return Err(OcypodeError::InvalidTelemetryFile {
+            path: format!("{:?}", input),
+        });
+    }
+    if fix {
+        let output = output.unwrap_or_else(|| validate::fixed_file_path(input));
+        report.write_fixed(&output)?;
+        println!("Cleaned copy written to {}", output.display());
+    }
+    Ok(())
+}
+
 fn main() {
-    // Always initialize logging, not just in debug mode
-    colog::init();
+    // Always initialize logging, not just in debug mode. Info by default, RUST_LOG
+    // overrides the level, e.g. RUST_LOG=debug or RUST_LOG=ocypode=warn
+    colog::default_builder()
+        .filter_level(log::LevelFilter::Info)
+        .parse_default_env()
+        .init();
 
     let cli = Args::parse();
     ctrlc::set_handler(move || {
-        println!("Exiting...");
+        info!("Exiting...");
+        stop_recording();
         std::process::exit(0);
     })
     .expect("Could not set Ctrl-C handler");
     match &cli.command {
-        Commands::Load { input } => {
-            load(input).expect("Error while analyzing telemetry file");
+        Commands::Load { input, baseline } => {
+            if let Err(e) = load(input, baseline.clone()) {
+                error!("Error while analyzing telemetry file: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Analyze {
+            input,
+            json,
+            baseline,
+        } => {
+            if let Err(e) = analyze(input, *json, baseline.as_deref()) {
+                error!("Error while analyzing telemetry file: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Trends { inputs, clear } => {
+            if let Err(e) = trends(inputs, *clear) {
+                error!("Error while opening the trend view: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Validate { input, fix, output } => {
+            if let Err(e) = validate(input, *fix, output.clone()) {
+                error!("Error while validating telemetry file: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Serve { game, address } => {
+            if let Err(e) = serve(*game, address) {
+                error!("Error while streaming telemetry: {}", e);
+                std::process::exit(1);
+            }
         }
         Commands::Live {
             window,
             output,
+            format,
+            split_sessions,
             game,
-        } => live(*window, output.clone(), *game).expect("Error while running live telemetry"),
+            remote,
+            windowed,
+            overlay,
+            stdout,
+        } => {
+            // Only override the persisted window mode when a flag is passed
+            let windowed = match (windowed, overlay) {
+                (true, _) => Some(true),
+                (_, true) => Some(false),
+                _ => None,
+            };
+            let source = match (game, remote) {
+                (_, Some(address)) => LiveSource::Remote(address.clone()),
+                (Some(game), None) => LiveSource::Game(*game),
+                (None, None) => unreachable!("clap requires --game without --remote"),
+            };
+            if let Err(e) = live(
+                *window,
+                output.clone(),
+                *format,
+                *split_sessions,
+                source,
+                windowed,
+                *stdout,
+            ) {
+                error!("Error while running live telemetry: {}", e);
+                std::process::exit(1);
+            }
+        }
     };
 }
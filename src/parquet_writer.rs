@@ -0,0 +1,317 @@
+use std::{fs::File, path::PathBuf, sync::Arc, sync::mpsc::Receiver};
+
+use arrow::{
+    array::{
+        ArrayRef, BooleanArray, Float32Array, Int8Array, ListBuilder, RecordBatch, StringArray,
+        StringBuilder, UInt8Array, UInt32Array, UInt64Array,
+    },
+    error::ArrowError,
+};
+use log::warn;
+use parquet::{
+    arrow::ArrowWriter,
+    basic::Compression,
+    file::{metadata::KeyValue, properties::WriterProperties},
+};
+
+use crate::{
+    OcypodeError,
+    telemetry::{SessionInfo, TelemetryData, TelemetryOutput, TireInfo},
+};
+
+/// Key of the file metadata entry holding the JSON array of sessions
+pub(crate) const SESSIONS_METADATA_KEY: &str = "ocypode.sessions";
+
+/// Points buffered before they are written as a record batch
+const BATCH_SIZE: usize = 4096;
+
+/// Builds a column from the buffered `(session_no, point)` rows.
+macro_rules! column {
+    ($name:expr, $array:ty, $values:expr) => {
+        (
+            $name,
+            Arc::new(<$array>::from_iter($values)) as ArrayRef,
+            true,
+        )
+    };
+}
+
+/// Builds a column named after each listed `TelemetryData` field.
+macro_rules! field_columns {
+    ($rows:expr, $array:ty: $($field:ident),* $(,)?) => {
+        [$(column!(stringify!($field), $array, $rows.iter().map(|(_, p)| p.$field))),*]
+    };
+}
+
+/// Builds the flattened temperature columns of a tire, prefixed with its position.
+macro_rules! tire_columns {
+    ($rows:expr, $prefix:literal, $tire:ident) => {
+        tire_columns!(@fields $rows, $prefix, $tire,
+            left_carcass_temp, middle_carcass_temp, right_carcass_temp,
+            left_surface_temp, middle_surface_temp, right_surface_temp)
+    };
+    (@fields $rows:expr, $prefix:literal, $tire:ident, $($field:ident),*) => {
+        [$(column!(
+            concat!($prefix, "_", stringify!($field)),
+            Float32Array,
+            $rows.iter().map(|(_, p)| p.$tire.as_ref().map(|t: &TireInfo| t.$field))
+        )),*]
+    };
+}
+
+fn build_batch(rows: &[(Option<u32>, TelemetryData)]) -> Result<RecordBatch, ArrowError> {
+    let mut annotations = ListBuilder::new(StringBuilder::new());
+    for (_, point) in rows {
+        for annotation in &point.annotations {
+            match serde_json::to_string(annotation) {
+                Ok(json) => annotations.values().append_value(json),
+                Err(e) => warn!("Skipping annotation due to serialization error: {}", e),
+            }
+        }
+        annotations.append(true);
+    }
+
+    let mut columns = vec![
+        column!("session_no", UInt32Array, rows.iter().map(|(s, _)| *s)),
+        column!(
+            "point_no",
+            UInt64Array,
+            rows.iter().map(|(_, p)| p.point_no as u64)
+        ),
+        column!(
+            "timestamp_ms",
+            UInt64Array,
+            rows.iter().map(|(_, p)| p.timestamp_ms as u64)
+        ),
+        column!(
+            "game_source",
+            StringArray,
+            rows.iter()
+                .map(|(_, p)| Some(format!("{:?}", p.game_source)))
+        ),
+    ];
+    columns.extend(field_columns!(rows, Int8Array: gear));
+    columns.extend(field_columns!(rows, Float32Array:
+        speed_mps, engine_rpm, max_engine_rpm, shift_point_rpm,
+        throttle, brake, brake_pressure_pct, clutch, steering_angle_rad, steering_pct,
+        lap_distance_m, lap_distance_pct,
+    ));
+    columns.extend(field_columns!(rows, UInt32Array: lap_number));
+    columns.extend(field_columns!(rows, Float32Array:
+        world_position_x, world_position_y, world_position_z,
+        world_velocity_x, world_velocity_y, world_velocity_z,
+        track_position_pct,
+    ));
+    columns.extend(field_columns!(rows, UInt8Array: track_sector));
+    columns.extend(field_columns!(rows, Float32Array: last_lap_time_s, best_lap_time_s));
+    columns.extend(field_columns!(rows, BooleanArray:
+        is_pit_limiter_engaged, is_in_pit_lane, is_abs_active, is_tc_active,
+    ));
+    columns.extend(field_columns!(rows, Float32Array:
+        latitude_deg, longitude_deg, lateral_accel_mps2, longitudinal_accel_mps2,
+        pitch_rad, pitch_rate_rps, roll_rad, roll_rate_rps, yaw_rad, yaw_rate_rps,
+    ));
+    columns.extend(tire_columns!(rows, "lf", lf_tire_info));
+    columns.extend(tire_columns!(rows, "rf", rf_tire_info));
+    columns.extend(tire_columns!(rows, "lr", lr_tire_info));
+    columns.extend(tire_columns!(rows, "rr", rr_tire_info));
+    columns.extend(field_columns!(rows, Float32Array:
+        lf_wheel_speed_mps, rf_wheel_speed_mps, lr_wheel_speed_mps, rr_wheel_speed_mps,
+        lf_slip_ratio, rf_slip_ratio, lr_slip_ratio, rr_slip_ratio,
+    ));
+    columns.push((
+        "annotations",
+        Arc::new(annotations.finish()) as ArrayRef,
+        true,
+    ));
+
+    RecordBatch::try_from_iter_with_nullable(columns)
+}
+
+/// Writes telemetry data to a columnar Parquet file.
+///
+/// # File Format
+///
+/// Each row is a `DataPoint`, with the same field names and units as the JSON Lines
+/// format written by [`crate::writer::write_telemetry`]. All columns are nullable.
+///
+/// - `session_no`: Index of the session in the `ocypode.sessions` metadata, null for
+///   points received before the first `SessionChange`
+/// - `point_no`, `timestamp_ms`: Unsigned 64-bit integers
+/// - `game_source`: `"IRacing"` or `"ACC"`
+/// - `gear` (int8), `lap_number` (uint32), `track_sector` (uint8)
+/// - `is_pit_limiter_engaged`, `is_in_pit_lane`, `is_abs_active`, `is_tc_active`: Booleans
+/// - All other `TelemetryData` scalar fields: 32-bit floats
+/// - Tire data is flattened to `<tire>_<field>` columns, for example
+///   `lf_middle_surface_temp`, with `lf`, `rf`, `lr` and `rr` prefixes
+/// - `annotations`: List of strings, each one an annotation serialized as JSON the
+///   same way it appears in the JSON Lines file
+///
+/// `SessionChange` entries are not rows, they are collected and written as a JSON
+/// array of `SessionInfo` objects in the `ocypode.sessions` key of the file metadata.
+///
+/// Example with DuckDB:
+/// ```sql
+/// SELECT lap_number, max(speed_mps) FROM 'session.parquet' GROUP BY lap_number;
+/// SELECT point_no, unnest(annotations) FROM 'session.parquet';
+/// ```
+pub fn write_telemetry_parquet(
+    file: &PathBuf,
+    telemetry_receiver: Receiver<TelemetryOutput>,
+) -> Result<(), OcypodeError> {
+    let telemetry_file = File::create(file).map_err(|e| OcypodeError::WriterError { source: e })?;
+    let schema = build_batch(&[])
+        .map_err(|e| OcypodeError::ParquetWriterError { source: e.into() })?
+        .schema();
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut writer = ArrowWriter::try_new(telemetry_file, schema, Some(properties))
+        .map_err(|e| OcypodeError::ParquetWriterError { source: e })?;
+
+    let mut sessions: Vec<SessionInfo> = Vec::new();
+    let mut rows = Vec::with_capacity(BATCH_SIZE);
+    for output in &telemetry_receiver {
+        match output {
+            TelemetryOutput::DataPoint(point) => {
+                let session_no = sessions.len().checked_sub(1).map(|s| s as u32);
+                rows.push((session_no, *point));
+            }
+            TelemetryOutput::SessionChange(session_info) => sessions.push(session_info),
+        }
+
+        if rows.len() >= BATCH_SIZE {
+            write_batch(&mut writer, &rows)?;
+            rows.clear();
+        }
+    }
+    if !rows.is_empty() {
+        write_batch(&mut writer, &rows)?;
+    }
+
+    match serde_json::to_string(&sessions) {
+        Ok(json) => {
+            writer.append_key_value_metadata(KeyValue::new(SESSIONS_METADATA_KEY.to_string(), json))
+        }
+        Err(e) => warn!(
+            "Skipping session metadata due to serialization error: {}",
+            e
+        ),
+    }
+    writer
+        .close()
+        .map_err(|e| OcypodeError::ParquetWriterError { source: e })?;
+    Ok(())
+}
+
+fn write_batch(
+    writer: &mut ArrowWriter<File>,
+    rows: &[(Option<u32>, TelemetryData)],
+) -> Result<(), OcypodeError> {
+    let batch =
+        build_batch(rows).map_err(|e| OcypodeError::ParquetWriterError { source: e.into() })?;
+    writer
+        .write(&batch)
+        .map_err(|e| OcypodeError::ParquetWriterError { source: e })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::{GameSource, TelemetryAnnotation};
+    use arrow::array::{Array, AsArray};
+    use arrow::datatypes::{Float32Type, UInt32Type};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use std::sync::mpsc;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_write_telemetry_parquet() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let file_path = temp_file.path().to_path_buf();
+        let (tx, rx) = mpsc::channel();
+
+        tx.send(TelemetryOutput::SessionChange(SessionInfo {
+            game_source: GameSource::ACC,
+            track_name: "Monza".to_string(),
+            ..Default::default()
+        }))
+        .unwrap();
+        for i in 0..3 {
+            tx.send(TelemetryOutput::DataPoint(Box::new(TelemetryData {
+                game_source: GameSource::ACC,
+                point_no: i,
+                speed_mps: Some(40. + i as f32),
+                lf_tire_info: (i == 1).then(|| TireInfo {
+                    left_carcass_temp: 80.,
+                    middle_carcass_temp: 81.,
+                    right_carcass_temp: 82.,
+                    left_surface_temp: 90.,
+                    middle_surface_temp: 91.,
+                    right_surface_temp: 92.,
+                }),
+                annotations: if i == 2 {
+                    vec![TelemetryAnnotation::Scrub {
+                        avg_yaw_rate_change: 1.,
+                        cur_yaw_rate_change: 2.,
+                        is_scrubbing: true,
+                    }]
+                } else {
+                    Vec::new()
+                },
+                ..Default::default()
+            })))
+            .unwrap();
+        }
+        drop(tx);
+
+        write_telemetry_parquet(&file_path, rx).unwrap();
+
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new(File::open(&file_path).unwrap()).unwrap();
+        let sessions = builder
+            .metadata()
+            .file_metadata()
+            .key_value_metadata()
+            .and_then(|kv| kv.iter().find(|kv| kv.key == SESSIONS_METADATA_KEY))
+            .and_then(|kv| kv.value.clone())
+            .unwrap();
+        let sessions: Vec<SessionInfo> = serde_json::from_str(&sessions).unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].track_name, "Monza");
+
+        let batches: Vec<RecordBatch> = builder.build().unwrap().map(|b| b.unwrap()).collect();
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.num_rows(), 3);
+
+        let session_no = batch.column_by_name("session_no").unwrap();
+        assert_eq!(session_no.as_primitive::<UInt32Type>().value(0), 0);
+        let speed = batch.column_by_name("speed_mps").unwrap();
+        assert_eq!(speed.as_primitive::<Float32Type>().value(2), 42.);
+
+        let tire = batch
+            .column_by_name("lf_middle_surface_temp")
+            .unwrap()
+            .as_primitive::<Float32Type>();
+        assert!(tire.is_null(0));
+        assert_eq!(tire.value(1), 91.);
+        assert!(batch.column_by_name("rr_left_carcass_temp").is_some());
+
+        let annotations = batch
+            .column_by_name("annotations")
+            .unwrap()
+            .as_list::<i32>();
+        assert!(annotations.value(0).is_empty());
+        let annotation = annotations.value(2);
+        let annotation: TelemetryAnnotation =
+            serde_json::from_str(annotation.as_string::<i32>().value(0)).unwrap();
+        assert!(matches!(
+            annotation,
+            TelemetryAnnotation::Scrub {
+                is_scrubbing: true,
+                ..
+            }
+        ));
+    }
+}
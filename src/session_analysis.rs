@@ -0,0 +1,656 @@
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    OcypodeError,
+    setup_assistant::{Finding, ProcessedRecommendation, SetupAssistant},
+    telemetry::{
+        SessionInfo, TelemetryData, TelemetryOutput, analyzer_config::AnalyzerConfig,
+        coasting::CoastingStats, ibt::read_ibt, migration::RecordMigrator, stint::StintSummary,
+    },
+};
+
+/// Analysis of a recorded session, the output of `ocypode analyze --json`.
+///
+/// Holds what the analysis window shows for a session without any of its UI: stats of
+/// each lap, the setup findings over the whole session and the recommendations for the
+/// findings that passed the setup assistant's thresholds. Fields are only added to this
+/// struct, with a default for older dumps, so scripts reading it keep working.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionAnalysis {
+    pub session: SessionInfo,
+    pub laps: Vec<LapStats>,
+    /// Fastest lap driven from start to finish without visiting the pit lane
+    pub best_lap_ms: Option<u128>,
+    /// Lap time and tire temperature trends of each stint, in the order they were driven
+    #[serde(default)]
+    pub stints: Vec<StintStats>,
+    /// Setup findings over the session, most detected first
+    pub findings: Vec<Finding>,
+    /// Recommendations for the actionable findings, highest priority first
+    pub recommendations: Vec<ProcessedRecommendation>,
+}
+
+/// Stats of a single lap of a [`SessionAnalysis`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LapStats {
+    /// Index of the lap within the session, as listed by the analysis window
+    pub lap_index: usize,
+    /// Lap number reported by the sim
+    pub lap_number: Option<u32>,
+    /// Time of a lap driven from start to finish without visiting the pit lane
+    pub lap_time_ms: Option<u128>,
+    pub points: usize,
+    pub top_speed_mps: Option<f32>,
+    /// Average surface temperature of the four tires over the lap
+    pub avg_tire_temp: Option<f32>,
+    /// Issues the analyzers reported in the lap, an issue lasting several points counts once
+    pub issue_count: usize,
+    /// Share of the lap (0 to 100) with neither throttle nor brake applied
+    #[serde(default)]
+    pub coasting_pct: f32,
+    /// Share of the lap (0 to 100) coasted for longer than an apex transition
+    #[serde(default)]
+    pub excessive_coasting_pct: f32,
+}
+
+/// Trends of a stint of a [`SessionAnalysis`], the valid laps between two pit stops
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StintStats {
+    /// Indexes of the laps of the stint, as listed by the analysis window
+    pub lap_indexes: Vec<usize>,
+    /// Seconds lost per lap over the stint, negative when the driver got faster. `None`
+    /// for stints too short to fit a trend
+    pub degradation_s_per_lap: Option<f64>,
+    /// Tire temperature change in °C per lap over the stint
+    pub tire_temp_change_per_lap: Option<f64>,
+}
+
+/// Minimum length of a lap in a recording, below which the segment between two lap
+/// changes is dropped by the loader.
+///
+/// Spins, resets and telemetry glitches can change the lap number for a few points,
+/// leaving tiny laps that clutter the lap list. A lap is kept when it has at least
+/// `min_points` points and covers at least `min_lap_distance_pct` of the lap. Both are
+/// 0 by default, keeping every lap.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LapFilter {
+    pub min_points: usize,
+    /// Share of the lap (0.0 to 1.0) driven over the lap, laps without lap distance
+    /// are only checked for their points
+    pub min_lap_distance_pct: f32,
+}
+
+impl LapFilter {
+    /// Returns true when `lap` is long enough to be a lap. The empty lap before the
+    /// first lap number of a session is kept, it only holds the place of lap 0.
+    pub fn keeps(&self, lap: &[TelemetryData]) -> bool {
+        if lap.is_empty() {
+            return true;
+        }
+        lap.len() >= self.min_points
+            && lap_distance_covered(lap).is_none_or(|pct| pct >= self.min_lap_distance_pct)
+    }
+}
+
+/// Share of the lap driven over `points`, adding up the forward moves of the lap
+/// distance so a segment jittering around the line does not cover the whole lap.
+/// `None` when the points have no lap distance.
+fn lap_distance_covered(points: &[TelemetryData]) -> Option<f32> {
+    let pcts: Vec<f32> = points.iter().filter_map(|p| p.lap_distance_pct).collect();
+    if pcts.is_empty() {
+        return None;
+    }
+    Some(
+        pcts.windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .filter(|step| *step > 0.0 && *step < LAP_WRAP_ARM_PCT)
+            .sum(),
+    )
+}
+
+/// Telemetry of a session in a recording, split into laps
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RecordedSession {
+    pub(crate) info: SessionInfo,
+    pub(crate) laps: Vec<Vec<TelemetryData>>,
+}
+
+impl SessionAnalysis {
+    /// Analyze every session of a recording, a JSONL capture or an iRacing `.ibt` file.
+    ///
+    /// The annotations saved in JSONL captures are used as they are, `.ibt` files are
+    /// annotated with the analyzer config exported from the tuning window. The per-lap
+    /// stats of both use the thresholds of that config.
+    pub fn from_file(path: &Path) -> Result<Vec<Self>, OcypodeError> {
+        Self::from_file_with_lap_filter(path, &LapFilter::default())
+    }
+
+    /// Analyze every session of a recording, dropping the laps `lap_filter` rejects.
+    pub fn from_file_with_lap_filter(
+        path: &Path,
+        lap_filter: &LapFilter,
+    ) -> Result<Vec<Self>, OcypodeError> {
+        if !path.exists() {
+            return Err(OcypodeError::InvalidTelemetryFile {
+                path: format!("{:?}", path),
+            });
+        }
+        let is_ibt = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("ibt"));
+        let config = AnalyzerConfig::from_local_file().unwrap_or_default();
+        let outputs = if is_ibt {
+            let mut telemetry = read_ibt(path)?;
+            config.annotate(&mut telemetry.points, &telemetry.session_info);
+            telemetry.into_outputs()
+        } else {
+            read_jsonl(path)?
+        };
+        Self::from_outputs_with_config(outputs, lap_filter, &config)
+    }
+
+    /// Analyze every session in the records of a recording.
+    pub fn from_outputs(
+        outputs: impl IntoIterator<Item = TelemetryOutput>,
+    ) -> Result<Vec<Self>, OcypodeError> {
+        Self::from_outputs_with_lap_filter(outputs, &LapFilter::default())
+    }
+
+    /// Analyze every session in the records of a recording, dropping the laps
+    /// `lap_filter` rejects.
+    pub fn from_outputs_with_lap_filter(
+        outputs: impl IntoIterator<Item = TelemetryOutput>,
+        lap_filter: &LapFilter,
+    ) -> Result<Vec<Self>, OcypodeError> {
+        Self::from_outputs_with_config(outputs, lap_filter, &AnalyzerConfig::default())
+    }
+
+    /// Analyze every session in the records of a recording, dropping the laps
+    /// `lap_filter` rejects and measuring the laps with the thresholds of `config`.
+    pub fn from_outputs_with_config(
+        outputs: impl IntoIterator<Item = TelemetryOutput>,
+        lap_filter: &LapFilter,
+        config: &AnalyzerConfig,
+    ) -> Result<Vec<Self>, OcypodeError> {
+        Ok(group_sessions(outputs, lap_filter)?
+            .into_iter()
+            .map(|session| Self::from_laps(session.info, &session.laps, config))
+            .collect())
+    }
+
+    /// Analyze a session from the telemetry of its laps, in order.
+    pub fn from_laps(
+        session: SessionInfo,
+        laps: &[Vec<TelemetryData>],
+        config: &AnalyzerConfig,
+    ) -> Self {
+        let stints = StintSummary::from_laps(laps.iter().map(Vec::as_slice));
+        let stint_laps: Vec<_> = stints.stints.iter().flat_map(|s| s.laps.iter()).collect();
+        let lap_stats = laps
+            .iter()
+            .enumerate()
+            .filter(|(_, points)| !points.is_empty())
+            .map(|(lap_index, points)| {
+                let stint_lap = stint_laps.iter().find(|l| l.lap_index == lap_index);
+                let coasting = CoastingStats::from_lap(points, &config.coasting);
+                LapStats {
+                    lap_index,
+                    lap_number: points.iter().find_map(|p| p.lap_number),
+                    lap_time_ms: stint_lap.map(|l| l.lap_time_ms),
+                    points: points.len(),
+                    top_speed_mps: points.iter().filter_map(|p| p.speed_mps).reduce(f32::max),
+                    avg_tire_temp: stint_lap.and_then(|l| l.avg_tire_temp),
+                    issue_count: count_issues(points),
+                    coasting_pct: coasting.coasting_pct(),
+                    excessive_coasting_pct: coasting.excessive_coasting_pct(),
+                }
+            })
+            .collect();
+
+        let mut setup_assistant = SetupAssistant::new();
+        setup_assistant.set_corner_detection(config.corner_detection);
+        for point in laps.iter().flatten() {
+            setup_assistant.process_telemetry(point);
+        }
+        let actionable: Vec<Finding> = setup_assistant
+            .get_actionable_findings()
+            .into_iter()
+            .cloned()
+            .collect();
+        let recommendations = setup_assistant.recommend_for_findings(&actionable);
+        let mut findings: Vec<Finding> = setup_assistant.get_findings().values().cloned().collect();
+        findings.sort_by(|a, b| {
+            b.occurrence_count
+                .cmp(&a.occurrence_count)
+                .then_with(|| a.finding_type.to_string().cmp(&b.finding_type.to_string()))
+        });
+
+        let stint_stats = stints
+            .stints
+            .iter()
+            .map(|stint| StintStats {
+                lap_indexes: stint.laps.iter().map(|l| l.lap_index).collect(),
+                degradation_s_per_lap: stint.degradation_s_per_lap(),
+                tire_temp_change_per_lap: stint.tire_temp_change_per_lap(),
+            })
+            .collect();
+
+        Self {
+            session,
+            laps: lap_stats,
+            best_lap_ms: stints.best_lap().map(|l| l.lap_time_ms),
+            stints: stint_stats,
+            findings,
+            recommendations,
+        }
+    }
+}
+
+impl Display for SessionAnalysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({})",
+            self.session.track_name, self.session.track_configuration
+        )?;
+        if let Some(car_name) = &self.session.car_name {
+            write!(f, ", {}", car_name)?;
+        }
+        writeln!(f)?;
+        match self.best_lap_ms {
+            Some(best_lap_ms) => writeln!(f, "Best lap: {:.3}s", best_lap_ms as f64 / 1000.)?,
+            None => writeln!(f, "Best lap: none driven from start to finish")?,
+        }
+
+        writeln!(f, "Laps:")?;
+        for lap in &self.laps {
+            let lap_time = lap
+                .lap_time_ms
+                .map_or("-".to_string(), |ms| format!("{:.3}s", ms as f64 / 1000.));
+            writeln!(
+                f,
+                "  {:>3}  {:>9}  {} issues, {:.1}% coasting ({:.1}% excessive)",
+                lap.lap_index,
+                lap_time,
+                lap.issue_count,
+                lap.coasting_pct,
+                lap.excessive_coasting_pct
+            )?;
+        }
+
+        writeln!(f, "Stints:")?;
+        for stint in &self.stints {
+            let trend = |value: Option<f64>, unit: &str| {
+                value.map_or("-".to_string(), |v| format!("{:+.3}{}/lap", v, unit))
+            };
+            writeln!(
+                f,
+                "  laps {:>3}-{:<3}  {} lap time, {} tire temperature",
+                stint.lap_indexes.first().copied().unwrap_or_default(),
+                stint.lap_indexes.last().copied().unwrap_or_default(),
+                trend(stint.degradation_s_per_lap, "s"),
+                trend(stint.tire_temp_change_per_lap, "°C")
+            )?;
+        }
+
+        writeln!(f, "Findings:")?;
+        for finding in &self.findings {
+            writeln!(
+                f,
+                "  {} ({}): {} occurrences",
+                finding.finding_type, finding.corner_phase, finding.occurrence_count
+            )?;
+        }
+
+        writeln!(f, "Recommendations:")?;
+        for processed in &self.recommendations {
+            let rec = &processed.recommendation;
+            writeln!(
+                f,
+                "  {} {}: {}{}",
+                rec.adjustment,
+                rec.parameter,
+                rec.description,
+                if processed.has_conflict {
+                    " (conflicting)"
+                } else {
+                    ""
+                }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse the records of a JSONL capture, upgrading those of older format versions.
+fn read_jsonl(path: &Path) -> Result<Vec<TelemetryOutput>, OcypodeError> {
+    let file =
+        std::fs::File::open(path).map_err(|e| OcypodeError::TelemetryLoaderError { source: e })?;
+    let mut migrator = RecordMigrator::new();
+    let mut outputs = Vec::new();
+    for (line_no, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|e| OcypodeError::TelemetryLoaderError { source: e })?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        outputs.push(
+            migrator
+                .parse(&line)
+                .map_err(|e| OcypodeError::TelemetryParseError {
+                    line: line_no + 1,
+                    source: e,
+                })?,
+        );
+    }
+    Ok(outputs)
+}
+
+/// Lap distance the car has to pass before crossing the line again counts as a new lap
+const LAP_WRAP_ARM_PCT: f32 = 0.5;
+/// Lap distance past which the car is approaching the line
+const LAP_WRAP_END_PCT: f32 = 0.9;
+/// Lap distance below which the car has crossed the line
+const LAP_WRAP_START_PCT: f32 = 0.1;
+
+/// Lap numbers for recordings that only have the lap distance, counted from the lap
+/// distance wrapping from the end of the lap back to its start.
+///
+/// The lap distance jitters around the line, going from 0.999 to 0.001 and back. A
+/// wrap only counts once the car went past half the lap since the previous one, so the
+/// jitter does not add laps of a few points. Only a recording starting at the end of a
+/// lap is armed past the end of the lap, its first crossing is a new lap.
+#[derive(Debug)]
+struct LapWrapDetector {
+    lap_number: u32,
+    last_pct: Option<f32>,
+    is_armed: bool,
+}
+
+impl LapWrapDetector {
+    fn new() -> Self {
+        Self {
+            lap_number: 1,
+            last_pct: None,
+            is_armed: false,
+        }
+    }
+
+    /// Lap number of a point at `lap_distance_pct`
+    fn update(&mut self, lap_distance_pct: f32) -> u32 {
+        if self.is_armed
+            && self
+                .last_pct
+                .is_some_and(|last_pct| last_pct > LAP_WRAP_END_PCT)
+            && lap_distance_pct < LAP_WRAP_START_PCT
+        {
+            self.lap_number += 1;
+            self.is_armed = false;
+        } else if lap_distance_pct >= LAP_WRAP_ARM_PCT
+            && (lap_distance_pct <= LAP_WRAP_END_PCT || self.last_pct.is_none())
+        {
+            self.is_armed = true;
+        }
+        self.last_pct = Some(lap_distance_pct);
+        self.lap_number
+    }
+}
+
+/// Group the records of a recording into sessions and laps. A lap starts whenever the
+/// lap number changes, so points recorded before the first lap number are a lap of
+/// their own, possibly empty.
+///
+/// Points without a lap number, as in some iRacing recordings, are numbered from their
+/// lap distance by [`LapWrapDetector`], starting from lap 1 for each session. Laps
+/// rejected by `lap_filter` are left empty, so the laps after them keep their index.
+/// Returns an error at the first session header that fails `SessionInfo::validate`.
+pub(crate) fn group_sessions(
+    outputs: impl IntoIterator<Item = TelemetryOutput>,
+    lap_filter: &LapFilter,
+) -> Result<Vec<RecordedSession>, OcypodeError> {
+    let mut invalid_session = Ok(());
+    let outputs = outputs.into_iter().map_while(|output| {
+        if let TelemetryOutput::SessionChange(session_info) = &output
+            && let Err(e) = session_info.validate()
+        {
+            invalid_session = Err(e);
+            return None;
+        }
+        Some(output)
+    });
+    let sessions = split_sessions(outputs, lap_filter);
+    invalid_session?;
+    Ok(sessions)
+}
+
+/// Group the records of a recording into sessions and laps as [`group_sessions`] does,
+/// without checking the session headers.
+pub(crate) fn split_sessions(
+    outputs: impl IntoIterator<Item = TelemetryOutput>,
+    lap_filter: &LapFilter,
+) -> Vec<RecordedSession> {
+    let mut sessions = Vec::new();
+    let mut cur_lap_no: u32 = 0;
+    let mut cur_session = RecordedSession::default();
+    let mut cur_lap = Vec::new();
+    let mut lap_wraps = LapWrapDetector::new();
+    for output in outputs {
+        match output {
+            TelemetryOutput::DataPoint(mut telemetry_point) => {
+                if telemetry_point.lap_number.is_none()
+                    && let Some(lap_distance_pct) = telemetry_point.lap_distance_pct
+                {
+                    telemetry_point.lap_number = Some(lap_wraps.update(lap_distance_pct));
+                }
+                let lap_no = telemetry_point.lap_number.unwrap_or(0);
+                if lap_no != cur_lap_no {
+                    cur_session.push_lap(std::mem::take(&mut cur_lap), lap_filter);
+                    cur_lap_no = lap_no;
+                }
+                cur_lap.push(*telemetry_point);
+            }
+            TelemetryOutput::SessionChange(session_info) => {
+                if !cur_lap.is_empty() {
+                    cur_session.push_lap(std::mem::take(&mut cur_lap), lap_filter);
+                }
+                // if we already have data points we are starting a new session
+                if !cur_session.laps.is_empty() {
+                    sessions.push(std::mem::take(&mut cur_session));
+                }
+                cur_lap_no = 0;
+                lap_wraps = LapWrapDetector::new();
+                cur_session.info = session_info;
+            }
+        }
+    }
+    sessions.push(cur_session);
+    sessions
+}
+
+impl RecordedSession {
+    fn push_lap(&mut self, lap: Vec<TelemetryData>, lap_filter: &LapFilter) {
+        self.laps.push(if lap_filter.keeps(&lap) {
+            lap
+        } else {
+            Vec::new()
+        });
+    }
+}
+
+/// Issues reported by the analyzers over `points`. An annotation active on consecutive
+/// points is a single issue, such as a slide lasting several points.
+pub(crate) fn count_issues(points: &[TelemetryData]) -> usize {
+    let mut previous: HashSet<String> = HashSet::new();
+    let mut count = 0;
+    for point in points {
+        let active: HashSet<String> = point
+            .annotations
+            .iter()
+            .filter(|a| a.is_active())
+            .map(|a| a.to_string())
+            .collect();
+        count += active.difference(&previous).count();
+        previous = active;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::TelemetryAnnotation;
+
+    /// Two laps of 60s, the second one with a slide on every other point
+    fn recording() -> Vec<TelemetryOutput> {
+        let session = SessionInfo {
+            track_name: "Spa".to_string(),
+            track_configuration: "GP".to_string(),
+            ..SessionInfo::default()
+        };
+        let points = (0..=200u32).map(|i| {
+            let lap = i / 100;
+            let annotations = if lap == 1 && i % 2 == 0 {
+                vec![TelemetryAnnotation::Scrub {
+                    avg_yaw_rate_change: 0.05,
+                    cur_yaw_rate_change: 0.3,
+                    is_scrubbing: true,
+                }]
+            } else {
+                Vec::new()
+            };
+            TelemetryData {
+                point_no: i as usize,
+                timestamp_ms: i as u128 * 600,
+                lap_number: Some(lap + 1),
+                lap_distance_pct: Some((i % 100) as f32 / 99.),
+                speed_mps: Some(40. + (i % 100) as f32 / 10.),
+                brake: Some(0.5),
+                steering_pct: Some(0.2),
+                annotations,
+                ..TelemetryData::default()
+            }
+        });
+        std::iter::once(TelemetryOutput::SessionChange(session))
+            .chain(points.map(|p| TelemetryOutput::DataPoint(Box::new(p))))
+            .collect()
+    }
+
+    #[test]
+    fn test_laps_detected_from_lap_distance_wrap() {
+        // two and a half laps without lap numbers, joined halfway through the first one,
+        // with the lap distance jittering around the line at each crossing. The last lap
+        // is still in progress when the recording ends, so it is left out.
+        let mut pcts: Vec<f32> = (50..100).map(|i| i as f32 / 100.).collect();
+        for _ in 0..2 {
+            pcts.extend([0.999, 0.001, 0.998, 0.002]);
+            pcts.extend((1..100).map(|i| i as f32 / 100.));
+        }
+        let outputs = pcts.iter().enumerate().map(|(i, pct)| {
+            TelemetryOutput::DataPoint(Box::new(TelemetryData {
+                point_no: i,
+                lap_distance_pct: Some(*pct),
+                ..TelemetryData::default()
+            }))
+        });
+
+        let sessions = group_sessions(outputs, &LapFilter::default()).unwrap();
+        let laps: Vec<(Option<u32>, usize)> = sessions[0]
+            .laps
+            .iter()
+            .map(|lap| (lap.first().and_then(|p| p.lap_number), lap.len()))
+            .collect();
+        assert_eq!(laps, vec![(None, 0), (Some(1), 51), (Some(2), 103)]);
+    }
+
+    #[test]
+    fn test_junk_laps_are_dropped() {
+        let lap = |lap_number: u32, points: usize| {
+            (0..points).map(move |i| {
+                TelemetryOutput::DataPoint(Box::new(TelemetryData {
+                    lap_number: Some(lap_number),
+                    lap_distance_pct: Some(i as f32 / points as f32),
+                    ..TelemetryData::default()
+                }))
+            })
+        };
+        // a reset changes the lap number for 5 points in the middle of the session
+        let outputs: Vec<_> = lap(1, 100)
+            .chain(lap(2, 5))
+            .chain(lap(3, 100))
+            .chain(lap(4, 1))
+            .collect();
+        let lap_filter = LapFilter {
+            min_points: 20,
+            min_lap_distance_pct: 0.5,
+        };
+
+        let sessions = group_sessions(outputs.clone(), &lap_filter).unwrap();
+        let laps: Vec<_> = sessions[0]
+            .laps
+            .iter()
+            .map(|lap| (lap.first().and_then(|p| p.lap_number), lap.len()))
+            .collect();
+        // rejected laps are left empty so the laps after them keep their index
+        assert_eq!(
+            laps,
+            vec![(None, 0), (Some(1), 100), (None, 0), (Some(3), 100)]
+        );
+
+        // every lap is kept by default
+        let sessions = group_sessions(outputs, &LapFilter::default()).unwrap();
+        assert_eq!(sessions[0].laps[2].len(), 5);
+    }
+
+    #[test]
+    fn test_session_analysis() {
+        let analyses = SessionAnalysis::from_outputs(recording()).unwrap();
+        assert_eq!(analyses.len(), 1);
+        let analysis = &analyses[0];
+        assert_eq!(analysis.session.track_name, "Spa");
+
+        // the empty lap before the first lap number is left out, indexes are kept, and
+        // the lap in progress when the recording ends is not complete
+        let lap_indexes: Vec<_> = analysis.laps.iter().map(|l| l.lap_index).collect();
+        assert_eq!(lap_indexes, vec![1, 2]);
+        assert_eq!(analysis.laps[0].lap_time_ms, Some(59_400));
+        assert_eq!(analysis.laps[0].issue_count, 0);
+        assert_eq!(analysis.laps[1].issue_count, 50);
+        // the brake is held through the whole recording
+        assert_eq!(analysis.laps[0].coasting_pct, 0.);
+        assert_eq!(analysis.best_lap_ms, Some(59_400));
+        // a single complete lap is too short for a trend
+        assert_eq!(analysis.stints.len(), 1);
+        assert_eq!(analysis.stints[0].lap_indexes, vec![1]);
+        assert_eq!(analysis.stints[0].degradation_s_per_lap, None);
+
+        assert!(
+            analysis
+                .findings
+                .first()
+                .is_some_and(|f| f.occurrence_count == 50)
+        );
+        assert!(!analysis.recommendations.is_empty());
+    }
+
+    #[test]
+    fn test_session_analysis_round_trips_through_json() {
+        let analyses = SessionAnalysis::from_outputs(recording()).unwrap();
+        let json = serde_json::to_string(&analyses).unwrap();
+        let parsed: Vec<SessionAnalysis> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed[0].laps, analyses[0].laps);
+        assert_eq!(parsed[0].stints, analyses[0].stints);
+        assert_eq!(parsed[0].findings.len(), analyses[0].findings.len());
+        assert_eq!(
+            parsed[0].recommendations.len(),
+            analyses[0].recommendations.len()
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{session_analysis::SessionAnalysis, setup_assistant::FindingType};
+
+/// Change in detections per lap, as a share of the rate before, within which a finding
+/// is unchanged
+const UNCHANGED_TOLERANCE: f32 = 0.1;
+
+/// How a finding moved from the session before a change to the session after it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FindingChange {
+    /// Detected before, not after
+    Resolved,
+    Decreased,
+    Unchanged,
+    Increased,
+    /// Not detected before, detected after
+    Appeared,
+}
+
+impl Display for FindingChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FindingChange::Resolved => write!(f, "resolved"),
+            FindingChange::Decreased => write!(f, "decreased"),
+            FindingChange::Unchanged => write!(f, "unchanged"),
+            FindingChange::Increased => write!(f, "increased"),
+            FindingChange::Appeared => write!(f, "appeared"),
+        }
+    }
+}
+
+/// A finding of either session and how often it was detected in each
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FindingDelta {
+    pub finding_type: FindingType,
+    /// Detections per lap in the session before the change, 0 when not detected
+    pub before_per_lap: f32,
+    /// Detections per lap in the session after the change, 0 when not detected
+    pub after_per_lap: f32,
+    pub before_severity: Option<f32>,
+    pub after_severity: Option<f32>,
+    pub change: FindingChange,
+}
+
+/// Setup findings of a session compared with an earlier one, typically driven before a
+/// setup change, to confirm the change helped.
+///
+/// Sessions rarely have the same number of laps, so findings are compared by their
+/// detections per lap. A rate within 10% of the one before is unchanged. Findings are
+/// listed with the largest change first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionDiff {
+    pub findings: Vec<FindingDelta>,
+}
+
+impl SessionDiff {
+    pub fn between(before: &SessionAnalysis, after: &SessionAnalysis) -> Self {
+        let per_lap = |analysis: &SessionAnalysis, finding_type: &FindingType| {
+            let finding = analysis
+                .findings
+                .iter()
+                .find(|f| &f.finding_type == finding_type);
+            let rate = finding.map_or(0.0, |f| {
+                f.occurrence_count as f32 / analysis.laps.len().max(1) as f32
+            });
+            (rate, finding.map(|f| f.severity))
+        };
+
+        let mut finding_types: Vec<&FindingType> = Vec::new();
+        for finding in before.findings.iter().chain(&after.findings) {
+            if !finding_types.contains(&&finding.finding_type) {
+                finding_types.push(&finding.finding_type);
+            }
+        }
+
+        let mut findings: Vec<FindingDelta> = finding_types
+            .into_iter()
+            .map(|finding_type| {
+                let (before_per_lap, before_severity) = per_lap(before, finding_type);
+                let (after_per_lap, after_severity) = per_lap(after, finding_type);
+                FindingDelta {
+                    finding_type: finding_type.clone(),
+                    before_per_lap,
+                    after_per_lap,
+                    before_severity,
+                    after_severity,
+                    change: change(before_per_lap, after_per_lap),
+                }
+            })
+            .collect();
+        findings.sort_by(|a, b| {
+            let moved = |d: &FindingDelta| (d.after_per_lap - d.before_per_lap).abs();
+            moved(b)
+                .total_cmp(&moved(a))
+                .then_with(|| a.finding_type.to_string().cmp(&b.finding_type.to_string()))
+        });
+        SessionDiff { findings }
+    }
+
+    /// Compare `after` with the session of `baseline` driven on the same track and
+    /// configuration, or the first one when none matches. `None` for an empty baseline.
+    pub fn against_baseline(baseline: &[SessionAnalysis], after: &SessionAnalysis) -> Option<Self> {
+        let before = baseline
+            .iter()
+            .find(|b| {
+                b.session.track_name == after.session.track_name
+                    && b.session.track_configuration == after.session.track_configuration
+            })
+            .or(baseline.first())?;
+        Some(Self::between(before, after))
+    }
+
+    /// Findings detected less often, or not at all, after the change
+    pub fn improved(&self) -> impl Iterator<Item = &FindingDelta> {
+        self.findings
+            .iter()
+            .filter(|d| matches!(d.change, FindingChange::Resolved | FindingChange::Decreased))
+    }
+
+    /// Findings detected more often, or for the first time, after the change
+    pub fn worsened(&self) -> impl Iterator<Item = &FindingDelta> {
+        self.findings
+            .iter()
+            .filter(|d| matches!(d.change, FindingChange::Increased | FindingChange::Appeared))
+    }
+}
+
+fn change(before_per_lap: f32, after_per_lap: f32) -> FindingChange {
+    if before_per_lap == 0.0 {
+        return FindingChange::Appeared;
+    }
+    if after_per_lap == 0.0 {
+        return FindingChange::Resolved;
+    }
+    let relative = (after_per_lap - before_per_lap) / before_per_lap;
+    if relative.abs() <= UNCHANGED_TOLERANCE {
+        FindingChange::Unchanged
+    } else if relative < 0.0 {
+        FindingChange::Decreased
+    } else {
+        FindingChange::Increased
+    }
+}
+
+impl Display for SessionDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Findings against the baseline: {} improved, {} worsened",
+            self.improved().count(),
+            self.worsened().count()
+        )?;
+        for delta in &self.findings {
+            writeln!(
+                f,
+                "  {}: {} ({:.1} -> {:.1} per lap)",
+                delta.finding_type, delta.change, delta.before_per_lap, delta.after_per_lap
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        setup_assistant::{CornerPhase, Finding},
+        telemetry::SessionInfo,
+    };
+
+    fn analysis(laps: usize, findings: &[(FindingType, usize)]) -> SessionAnalysis {
+        SessionAnalysis {
+            session: SessionInfo::default(),
+            laps: (0..laps)
+                .map(|lap_index| crate::session_analysis::LapStats {
+                    lap_index,
+                    lap_number: Some(lap_index as u32 + 1),
+                    lap_time_ms: None,
+                    points: 100,
+                    top_speed_mps: None,
+                    avg_tire_temp: None,
+                    issue_count: 0,
+                    coasting_pct: 0.,
+                    excessive_coasting_pct: 0.,
+                })
+                .collect(),
+            best_lap_ms: None,
+            stints: Vec::new(),
+            findings: findings
+                .iter()
+                .map(|(finding_type, occurrence_count)| Finding {
+                    finding_type: finding_type.clone(),
+                    occurrence_count: *occurrence_count,
+                    corner_phase: CornerPhase::Entry,
+                    last_detected: 0,
+                    severity: 0.5,
+                    occurrence_timestamps: Default::default(),
+                    occurrence_lap_distances: Default::default(),
+                    occurrence_causes: Default::default(),
+                    cause_tally: Default::default(),
+                    corner_type_tally: Default::default(),
+                })
+                .collect(),
+            recommendations: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_findings_compared_per_lap() {
+        let before = analysis(
+            4,
+            &[
+                (FindingType::CornerEntryUndersteer, 20),
+                (FindingType::CornerExitOversteer, 8),
+                (FindingType::MidCornerUndersteer, 4),
+            ],
+        );
+        // twice the laps, so 8 mid-corner understeers is the same rate as before
+        let after = analysis(
+            8,
+            &[
+                (FindingType::CornerExitOversteer, 4),
+                (FindingType::MidCornerUndersteer, 8),
+                (FindingType::FrontBrakeLock, 16),
+            ],
+        );
+
+        let diff = SessionDiff::between(&before, &after);
+        let changes: Vec<_> = diff
+            .findings
+            .iter()
+            .map(|d| (d.finding_type.clone(), d.change))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (FindingType::CornerEntryUndersteer, FindingChange::Resolved),
+                (FindingType::FrontBrakeLock, FindingChange::Appeared),
+                (FindingType::CornerExitOversteer, FindingChange::Decreased),
+                (FindingType::MidCornerUndersteer, FindingChange::Unchanged),
+            ]
+        );
+        assert_eq!(diff.findings[0].before_per_lap, 5.0);
+        assert_eq!(diff.findings[0].after_severity, None);
+        assert_eq!(diff.improved().count(), 2);
+        assert_eq!(diff.worsened().count(), 1);
+    }
+}
@@ -0,0 +1,92 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::OcypodeError;
+
+/// Freeform notes about the sessions of a recording, e.g. the setup that was run.
+///
+/// Recordings are streamed as they are written, so the notes live in a sidecar file
+/// next to the recording, `<recording>.notes.json`, which the live window writes while
+/// recording and the analysis window edits afterwards. Notes are indexed by the position
+/// of the session in the recording, in the order the analysis window lists them.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SessionNotes {
+    sessions: Vec<String>,
+}
+
+impl SessionNotes {
+    /// Load the notes saved next to `recording`, empty when there are none yet.
+    pub(crate) fn load(recording: &Path) -> Result<Self, OcypodeError> {
+        let path = notes_file_path(recording);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let file =
+            std::fs::File::open(path).map_err(|e| OcypodeError::SessionNotesError { source: e })?;
+        serde_json::from_reader(file)
+            .map_err(|e| OcypodeError::SessionNotesError { source: e.into() })
+    }
+
+    /// Save the notes next to `recording`, overwriting the previous ones.
+    pub(crate) fn save(&self, recording: &Path) -> Result<(), OcypodeError> {
+        let json = serde_json::to_vec_pretty(self)
+            .map_err(|e| OcypodeError::SessionNotesError { source: e.into() })?;
+        std::fs::write(notes_file_path(recording), json)
+            .map_err(|e| OcypodeError::SessionNotesError { source: e })
+    }
+
+    /// Notes of the session at `session`, empty if none were written.
+    pub(crate) fn get(&self, session: usize) -> &str {
+        self.sessions.get(session).map_or("", String::as_str)
+    }
+
+    /// Notes of the session at `session` for editing, sessions before it get empty notes.
+    pub(crate) fn get_mut(&mut self, session: usize) -> &mut String {
+        if self.sessions.len() <= session {
+            self.sessions.resize(session + 1, String::new());
+        }
+        &mut self.sessions[session]
+    }
+}
+
+/// Returns the `<stem>.notes.json` path of the notes kept for `recording`.
+pub(crate) fn notes_file_path(recording: &Path) -> PathBuf {
+    let stem = recording
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    recording.with_file_name(format!("{}.notes.json", stem))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notes_file_path() {
+        assert_eq!(
+            notes_file_path(Path::new("/tmp/monza_20261016-143012.jsonl")),
+            PathBuf::from("/tmp/monza_20261016-143012.notes.json")
+        );
+    }
+
+    #[test]
+    fn test_notes_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let recording = dir.path().join("spa.jsonl");
+        assert_eq!(
+            SessionNotes::load(&recording).unwrap(),
+            SessionNotes::default()
+        );
+
+        let mut notes = SessionNotes::default();
+        *notes.get_mut(1) = "Softer rear ARB, +1 rear wing".to_string();
+        notes.save(&recording).unwrap();
+
+        let loaded = SessionNotes::load(&recording).unwrap();
+        assert_eq!(loaded.get(0), "");
+        assert_eq!(loaded.get(1), "Softer rear ARB, +1 rear wing");
+        assert_eq!(loaded.get(2), "");
+    }
+}
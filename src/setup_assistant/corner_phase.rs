@@ -0,0 +1,319 @@
+//! Corner phase classification with hysteresis.
+//!
+//! Classifying each telemetry point on its own flickers between phases whenever
+//! an input hovers around a threshold, e.g. a driver holding 10% brake through
+//! turn-in. [`CornerPhaseClassifier`] keeps track of the current phase, uses
+//! lower thresholds to leave a phase than to enter it, and only switches once
+//! the new phase has held for a short debounce window.
+
+use super::CornerPhase;
+use crate::telemetry::TelemetryData;
+
+/// Brake input that starts the entry phase
+pub const BRAKE_ENTER_THRESHOLD: f32 = 0.1;
+/// Brake input below which the entry phase ends
+pub const BRAKE_RELEASE_THRESHOLD: f32 = 0.05;
+/// Throttle input that starts the exit phase
+pub const THROTTLE_ENTER_THRESHOLD: f32 = 0.1;
+/// Throttle input below which the exit phase ends
+pub const THROTTLE_RELEASE_THRESHOLD: f32 = 0.05;
+/// Steering input (fraction of full lock) that starts a corner
+pub const STEERING_ENTER_THRESHOLD: f32 = 0.05;
+/// Steering input (fraction of full lock) below which the corner ends
+pub const STEERING_RELEASE_THRESHOLD: f32 = 0.03;
+/// Time a new phase must hold before the classifier switches to it
+pub const DEFAULT_DEBOUNCE_MS: u128 = 100;
+
+#[derive(Clone, Copy)]
+struct Thresholds {
+    brake: f32,
+    throttle: f32,
+    steering: f32,
+}
+
+const ENTER_THRESHOLDS: Thresholds = Thresholds {
+    brake: BRAKE_ENTER_THRESHOLD,
+    throttle: THROTTLE_ENTER_THRESHOLD,
+    steering: STEERING_ENTER_THRESHOLD,
+};
+
+/// Classify a single telemetry point, without any history.
+///
+/// Uses brake, throttle, and steering inputs to determine which phase of the
+/// corner the car is in. Prefer [`CornerPhaseClassifier`] when processing a
+/// stream of points.
+pub fn classify_corner_phase(telemetry: &TelemetryData) -> CornerPhase {
+    classify(telemetry, ENTER_THRESHOLDS)
+}
+
+fn classify(telemetry: &TelemetryData, thresholds: Thresholds) -> CornerPhase {
+    let brake = telemetry.brake.unwrap_or(0.0);
+    let throttle = telemetry.throttle.unwrap_or(0.0);
+    let steering = telemetry.steering_pct.unwrap_or(0.0).abs();
+
+    // Entry: braking with steering
+    if brake > thresholds.brake && steering > thresholds.steering {
+        return CornerPhase::Entry;
+    }
+
+    // Exit: throttle with steering
+    if throttle > thresholds.throttle && steering > thresholds.steering {
+        return CornerPhase::Exit;
+    }
+
+    // Mid: steering but minimal throttle/brake (coasting)
+    if steering > thresholds.steering && brake < thresholds.brake && throttle < thresholds.throttle
+    {
+        return CornerPhase::Mid;
+    }
+
+    // Straight: minimal steering
+    if steering < thresholds.steering {
+        return CornerPhase::Straight;
+    }
+
+    CornerPhase::Unknown
+}
+
+/// Stateful corner phase classifier for a stream of telemetry points.
+///
+/// Inputs that keep the car in its current phase are compared against the lower
+/// release thresholds, so a phase is entered at e.g. 10% brake but only left
+/// below 5%. A different phase must then be seen continuously for the debounce
+/// window before the classifier reports it.
+#[derive(Debug, Clone)]
+pub struct CornerPhaseClassifier {
+    phase: CornerPhase,
+    /// Phase the inputs point at and the timestamp it was first seen at
+    candidate: Option<(CornerPhase, u128)>,
+    debounce_ms: u128,
+}
+
+impl CornerPhaseClassifier {
+    pub fn new() -> Self {
+        Self::with_debounce(DEFAULT_DEBOUNCE_MS)
+    }
+
+    /// Create a classifier that switches phase once a new phase held for `debounce_ms`.
+    pub fn with_debounce(debounce_ms: u128) -> Self {
+        Self {
+            phase: CornerPhase::Unknown,
+            candidate: None,
+            debounce_ms,
+        }
+    }
+
+    /// The current stable phase
+    pub fn phase(&self) -> CornerPhase {
+        self.phase
+    }
+
+    /// Forget the current phase, e.g. after a session change.
+    pub fn reset(&mut self) {
+        self.phase = CornerPhase::Unknown;
+        self.candidate = None;
+    }
+
+    /// Classify the next telemetry point and return the stable phase.
+    pub fn update(&mut self, telemetry: &TelemetryData) -> CornerPhase {
+        let observed = classify(telemetry, self.thresholds());
+        if observed == self.phase {
+            self.candidate = None;
+            return self.phase;
+        }
+        // nothing to stabilize against until the first phase is known
+        if self.phase == CornerPhase::Unknown {
+            self.phase = observed;
+            self.candidate = None;
+            return self.phase;
+        }
+
+        let since = match self.candidate {
+            Some((candidate, since)) if candidate == observed => since,
+            _ => telemetry.timestamp_ms,
+        };
+        if telemetry.timestamp_ms.saturating_sub(since) >= self.debounce_ms {
+            self.phase = observed;
+            self.candidate = None;
+        } else {
+            self.candidate = Some((observed, since));
+        }
+        self.phase
+    }
+
+    /// Thresholds for the next point, lowered for the inputs that hold the current phase
+    fn thresholds(&self) -> Thresholds {
+        let in_corner = matches!(
+            self.phase,
+            CornerPhase::Entry | CornerPhase::Mid | CornerPhase::Exit
+        );
+        Thresholds {
+            brake: if self.phase == CornerPhase::Entry {
+                BRAKE_RELEASE_THRESHOLD
+            } else {
+                BRAKE_ENTER_THRESHOLD
+            },
+            throttle: if self.phase == CornerPhase::Exit {
+                THROTTLE_RELEASE_THRESHOLD
+            } else {
+                THROTTLE_ENTER_THRESHOLD
+            },
+            steering: if in_corner {
+                STEERING_RELEASE_THRESHOLD
+            } else {
+                STEERING_ENTER_THRESHOLD
+            },
+        }
+    }
+}
+
+impl Default for CornerPhaseClassifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::PedalDeadzones;
+
+    fn point(timestamp_ms: u128, brake: f32, throttle: f32, steering_pct: f32) -> TelemetryData {
+        TelemetryData {
+            timestamp_ms,
+            brake: Some(brake),
+            throttle: Some(throttle),
+            steering_pct: Some(steering_pct),
+            ..Default::default()
+        }
+    }
+
+    fn run(classifier: &mut CornerPhaseClassifier, points: &[TelemetryData]) -> Vec<CornerPhase> {
+        points.iter().map(|p| classifier.update(p)).collect()
+    }
+
+    #[test]
+    fn test_no_flicker_with_brake_around_threshold() {
+        // trail braking with the pedal hovering around the 10% entry threshold
+        let points: Vec<_> = (0..20)
+            .map(|i| {
+                let brake = if i % 2 == 0 { 0.11 } else { 0.09 };
+                point(i * 16, brake, 0.0, 0.3)
+            })
+            .collect();
+
+        let phases = run(&mut CornerPhaseClassifier::new(), &points);
+        assert!(phases.iter().all(|p| *p == CornerPhase::Entry));
+
+        // the stateless classification flickers on the same points
+        let stateless: Vec<_> = points.iter().map(classify_corner_phase).collect();
+        assert!(stateless.contains(&CornerPhase::Mid));
+    }
+
+    #[test]
+    fn test_no_flicker_with_steering_around_threshold() {
+        // a fast kink taken with the wheel hovering around 5% lock
+        let points: Vec<_> = (0..20)
+            .map(|i| {
+                let steering = if i % 3 == 0 { 0.06 } else { 0.04 };
+                point(i * 16, 0.0, 1.0, steering)
+            })
+            .collect();
+
+        let mut classifier = CornerPhaseClassifier::new();
+        classifier.update(&point(0, 0.0, 1.0, 0.0));
+        let phases = run(&mut classifier, &points);
+        assert!(phases.iter().all(|p| *p == CornerPhase::Straight));
+    }
+
+    #[test]
+    fn test_switches_after_debounce() {
+        let mut classifier = CornerPhaseClassifier::new();
+        assert_eq!(
+            classifier.update(&point(0, 0.0, 1.0, 0.0)),
+            CornerPhase::Straight
+        );
+        // braking into the corner, reported once it held for the debounce window
+        assert_eq!(
+            classifier.update(&point(16, 0.8, 0.0, 0.2)),
+            CornerPhase::Straight
+        );
+        assert_eq!(
+            classifier.update(&point(64, 0.8, 0.0, 0.2)),
+            CornerPhase::Straight
+        );
+        assert_eq!(
+            classifier.update(&point(116, 0.8, 0.0, 0.2)),
+            CornerPhase::Entry
+        );
+        assert_eq!(classifier.phase(), CornerPhase::Entry);
+    }
+
+    #[test]
+    fn test_interrupted_candidate_restarts_debounce() {
+        let mut classifier = CornerPhaseClassifier::new();
+        classifier.update(&point(0, 0.0, 1.0, 0.0));
+        classifier.update(&point(16, 0.8, 0.0, 0.2));
+        classifier.update(&point(80, 0.0, 1.0, 0.0));
+        // the entry seen at 16ms does not count towards the debounce anymore
+        assert_eq!(
+            classifier.update(&point(120, 0.8, 0.0, 0.2)),
+            CornerPhase::Straight
+        );
+        assert_eq!(
+            classifier.update(&point(220, 0.8, 0.0, 0.2)),
+            CornerPhase::Entry
+        );
+    }
+
+    #[test]
+    fn test_resting_throttle_inside_deadzone_is_not_exit() {
+        // coasting through the apex with a worn pedal resting at 12% throttle
+        let deadzones = PedalDeadzones {
+            throttle: 0.15,
+            ..PedalDeadzones::default()
+        };
+        let points: Vec<_> = (0..20).map(|i| point(i * 16, 0.0, 0.12, 0.3)).collect();
+
+        let phases = run(&mut CornerPhaseClassifier::new(), &points);
+        assert!(phases.iter().all(|p| *p == CornerPhase::Exit));
+
+        let points: Vec<_> = points
+            .into_iter()
+            .map(|p| p.with_pedal_deadzones(&deadzones))
+            .collect();
+        let phases = run(&mut CornerPhaseClassifier::new(), &points);
+        assert!(phases.iter().all(|p| *p == CornerPhase::Mid));
+    }
+
+    #[test]
+    fn test_deadzone_keeps_deliberate_throttle() {
+        let deadzones = PedalDeadzones {
+            throttle: 0.03,
+            brake: 0.03,
+            clutch: 0.03,
+        };
+        // a pedal resting at 3% is released, the driver opening the throttle is not
+        let resting = point(0, 0.02, 0.029, 0.3).with_pedal_deadzones(&deadzones);
+        assert_eq!(resting.throttle, Some(0.));
+        assert_eq!(resting.brake, Some(0.));
+        assert_eq!(classify_corner_phase(&resting), CornerPhase::Mid);
+
+        let exit = point(16, 0.0, 0.4, 0.3).with_pedal_deadzones(&deadzones);
+        assert_eq!(exit.throttle, Some(0.4));
+        assert_eq!(classify_corner_phase(&exit), CornerPhase::Exit);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut classifier = CornerPhaseClassifier::with_debounce(0);
+        classifier.update(&point(0, 0.8, 0.0, 0.2));
+        assert_eq!(classifier.phase(), CornerPhase::Entry);
+        classifier.reset();
+        assert_eq!(classifier.phase(), CornerPhase::Unknown);
+        assert_eq!(
+            classifier.update(&point(16, 0.0, 1.0, 0.3)),
+            CornerPhase::Exit
+        );
+    }
+}
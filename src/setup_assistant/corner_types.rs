@@ -0,0 +1,139 @@
+//! Attribution of findings to the speed class of the corner they were detected in.
+//!
+//! A finding is only known to belong to a slow, medium or fast corner once the corner
+//! is over and its apex speed is known, so [`CornerTypeTracker`] holds the detections
+//! of the corner in progress until then. Detections on the straights are not counted
+//! towards any corner type.
+
+use crate::telemetry::{
+    TelemetryData,
+    corners::{CornerDetection, CornerType},
+};
+
+use super::{Finding, FindingType, ProcessedRecommendation};
+
+/// Detections of a finding in each type of corner.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CornerTypeTally {
+    pub slow: usize,
+    pub medium: usize,
+    pub fast: usize,
+}
+
+impl CornerTypeTally {
+    pub fn record(&mut self, corner_type: CornerType) {
+        match corner_type {
+            CornerType::Slow => self.slow += 1,
+            CornerType::Medium => self.medium += 1,
+            CornerType::Fast => self.fast += 1,
+        }
+    }
+
+    pub fn count(&self, corner_type: CornerType) -> usize {
+        match corner_type {
+            CornerType::Slow => self.slow,
+            CornerType::Medium => self.medium,
+            CornerType::Fast => self.fast,
+        }
+    }
+
+    /// True when no detection was classified in any type of corner
+    pub fn is_empty(&self) -> bool {
+        self.slow == 0 && self.medium == 0 && self.fast == 0
+    }
+
+    pub fn merge(&mut self, other: &CornerTypeTally) {
+        self.slow += other.slow;
+        self.medium += other.medium;
+        self.fast += other.fast;
+    }
+}
+
+/// Confirmed findings detected in one type of corner and the recommendations they lead
+/// to.
+#[derive(Debug, Clone)]
+pub struct CornerTypeRecommendations {
+    /// `None` for the findings without any detection in a classified corner
+    pub corner_type: Option<CornerType>,
+    /// Findings counting only their detections in this type of corner, most frequent
+    /// first
+    pub findings: Vec<Finding>,
+    pub recommendations: Vec<ProcessedRecommendation>,
+}
+
+/// Corner in progress, with the findings detected in it so far.
+struct TrackedCorner {
+    start_ms: u128,
+    /// `None` until a point of the corner reports its speed
+    min_speed_mps: Option<f32>,
+    detections: Vec<FindingType>,
+}
+
+/// Finds corners in a stream of telemetry points and reports the findings detected in
+/// each once it is over, with the type of the corner.
+pub struct CornerTypeTracker {
+    detection: CornerDetection,
+    corner: Option<TrackedCorner>,
+}
+
+impl CornerTypeTracker {
+    /// Track the corners `detection` finds, the analyzer config's
+    /// `corner_detection` thresholds.
+    pub fn new(detection: CornerDetection) -> Self {
+        Self {
+            detection,
+            corner: None,
+        }
+    }
+
+    /// Follow the corner with `telemetry` and the findings detected at it.
+    ///
+    /// Returns the type of the corner that just ended, with every finding detected in
+    /// it, including those detected at the point that ends it, like a slide that only
+    /// ends with the corner. Corners shorter than the detection's minimum duration or
+    /// without any speed are dropped.
+    pub fn update(
+        &mut self,
+        telemetry: &TelemetryData,
+        detections: impl IntoIterator<Item = FindingType>,
+    ) -> Option<(CornerType, Vec<FindingType>)> {
+        if !self.detection.is_cornering(telemetry) {
+            let mut corner = self.corner.take()?;
+            corner.detections.extend(detections);
+            let duration_ms = telemetry.timestamp_ms.saturating_sub(corner.start_ms);
+            if duration_ms < u128::from(self.detection.min_duration_ms)
+                || corner.detections.is_empty()
+            {
+                return None;
+            }
+            let corner_type = CornerType::from_apex_speed(corner.min_speed_mps?);
+            return Some((corner_type, corner.detections));
+        }
+
+        let corner = self.corner.get_or_insert(TrackedCorner {
+            start_ms: telemetry.timestamp_ms,
+            min_speed_mps: None,
+            detections: Vec::new(),
+        });
+        if let Some(speed_mps) = telemetry.speed_mps {
+            corner.min_speed_mps = Some(
+                corner
+                    .min_speed_mps
+                    .map_or(speed_mps, |min| min.min(speed_mps)),
+            );
+        }
+        corner.detections.extend(detections);
+        None
+    }
+
+    /// Forget the corner in progress.
+    pub fn reset(&mut self) {
+        self.corner = None;
+    }
+}
+
+impl Default for CornerTypeTracker {
+    fn default() -> Self {
+        Self::new(CornerDetection::default())
+    }
+}
@@ -1,13 +1,34 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 
-use crate::telemetry::TelemetryData;
+use crate::telemetry::{
+    ExitUndersteerCause, LoadTransferTiming, TelemetryData,
+    corners::{CornerDetection, CornerType, CornerWindow},
+};
 
+pub mod corner_phase;
+pub mod corner_types;
+pub mod overrides;
 pub mod recommendations;
-pub use recommendations::{RecommendationEngine, SetupRecommendation};
+pub mod report;
+pub use corner_phase::CornerPhaseClassifier;
+pub use corner_types::{CornerTypeRecommendations, CornerTypeTally, CornerTypeTracker};
+pub use overrides::{CarRecommendationOverrides, RecommendationOverride};
+pub use recommendations::{
+    ProcessedRecommendation, RecommendationEngine, RecommendationOrder, SetupRecommendation,
+    TriedRecommendation,
+};
+pub use report::render_markdown_report;
 
 #[cfg(test)]
 mod recommendation_tests;
 
+/// Occurrences required before a finding is shown as actionable
+pub const DEFAULT_MIN_OCCURRENCES: usize = 3;
+/// Severity required before a finding is shown as actionable
+pub const DEFAULT_MIN_SEVERITY: f32 = 0.0;
+/// Occurrence timestamps kept per finding, older ones are dropped
+pub const MAX_OCCURRENCE_HISTORY: usize = 500;
+
 /// Types of handling issues that can be detected during a session.
 ///
 /// Each finding type corresponds to a specific driving issue that can be
@@ -44,6 +65,38 @@ pub enum FindingType {
     BottomingOut,
     /// Excessive trail braking into corners
     ExcessiveTrailbraking,
+    /// Front outer tire edges hotter than the inner edges
+    FrontCamberTooPositive,
+    /// Front inner tire edges much hotter than the outer edges
+    FrontCamberTooNegative,
+    /// Rear outer tire edges hotter than the inner edges
+    RearCamberTooPositive,
+    /// Rear inner tire edges much hotter than the outer edges
+    RearCamberTooNegative,
+    /// Wheels locking right after stomping the brake pedal
+    BrakeStompLock,
+    /// ABS stepping in repeatedly under braking
+    FrequentAbsIntervention,
+    /// Traction control cutting power repeatedly on corner exit
+    FrequentTcIntervention,
+    /// Constant small steering corrections needed to hold the car straight at speed
+    StraightLineInstability,
+    /// Rear stepping out after a downshift on corner entry
+    DownshiftInstability,
+    /// Middle of the front treads hotter than their edges
+    FrontTirePressureTooHigh,
+    /// Edges of the front treads hotter than their middle
+    FrontTirePressureTooLow,
+    /// Middle of the rear treads hotter than their edges
+    RearTirePressureTooHigh,
+    /// Edges of the rear treads hotter than their middle
+    RearTirePressureTooLow,
+    /// Throttle stabbed on and off on corner exit
+    ThrottleStabbing,
+    /// Upshifts past the RPM where the next gear pulls harder
+    UpshiftOverRevving,
+    /// Upshifts before the RPM where the next gear pulls harder
+    UpshiftUnderRevving,
 }
 
 impl std::fmt::Display for FindingType {
@@ -64,6 +117,22 @@ impl std::fmt::Display for FindingType {
             FindingType::TireCold => write!(f, "Cold Tires"),
             FindingType::BottomingOut => write!(f, "Bottoming Out"),
             FindingType::ExcessiveTrailbraking => write!(f, "Excessive Trail Braking"),
+            FindingType::FrontCamberTooPositive => write!(f, "Front Camber Too Positive"),
+            FindingType::FrontCamberTooNegative => write!(f, "Front Camber Too Negative"),
+            FindingType::RearCamberTooPositive => write!(f, "Rear Camber Too Positive"),
+            FindingType::RearCamberTooNegative => write!(f, "Rear Camber Too Negative"),
+            FindingType::BrakeStompLock => write!(f, "Brake Stomp Lock"),
+            FindingType::FrequentAbsIntervention => write!(f, "Frequent ABS Intervention"),
+            FindingType::FrequentTcIntervention => write!(f, "Frequent TC Intervention"),
+            FindingType::StraightLineInstability => write!(f, "Straight-Line Instability"),
+            FindingType::DownshiftInstability => write!(f, "Downshift Instability"),
+            FindingType::FrontTirePressureTooHigh => write!(f, "Front Tire Pressure Too High"),
+            FindingType::FrontTirePressureTooLow => write!(f, "Front Tire Pressure Too Low"),
+            FindingType::RearTirePressureTooHigh => write!(f, "Rear Tire Pressure Too High"),
+            FindingType::RearTirePressureTooLow => write!(f, "Rear Tire Pressure Too Low"),
+            FindingType::ThrottleStabbing => write!(f, "Throttle Stabbing"),
+            FindingType::UpshiftOverRevving => write!(f, "Upshift Over-Revving"),
+            FindingType::UpshiftUnderRevving => write!(f, "Upshift Under-Revving"),
         }
     }
 }
@@ -84,6 +153,185 @@ pub struct Finding {
     pub last_detected: u128,
     /// Severity of the issue (0.0 to 1.0)
     pub severity: f32,
+    /// Timestamps of the most recent detections (milliseconds since epoch), oldest
+    /// first and bounded by [`MAX_OCCURRENCE_HISTORY`]
+    #[serde(default)]
+    pub occurrence_timestamps: VecDeque<u128>,
+    /// Lap distances of the detections in `occurrence_timestamps`, newest last, `None`
+    /// for detections at points without a lap distance
+    #[serde(default)]
+    pub occurrence_lap_distances: VecDeque<Option<f32>>,
+    /// Causes of the detections in `occurrence_timestamps`, newest last, `None` for
+    /// detections without a cause
+    #[serde(default)]
+    pub occurrence_causes: VecDeque<Option<ExitUndersteerCause>>,
+    /// Detections tagged with a likely cause, only exit throttle lifts carry one
+    #[serde(default)]
+    pub cause_tally: CauseTally,
+    /// Detections in slow, medium and fast corners, detections on the straights or in
+    /// a corner still in progress are not counted
+    #[serde(default)]
+    pub corner_type_tally: CornerTypeTally,
+}
+
+impl Finding {
+    /// Count a new detection of the finding at `timestamp_ms` and `lap_distance_pct`.
+    pub fn record_occurrence(&mut self, timestamp_ms: u128, lap_distance_pct: Option<f32>) {
+        self.occurrence_count += 1;
+        self.last_detected = timestamp_ms;
+        if self.occurrence_timestamps.len() >= MAX_OCCURRENCE_HISTORY {
+            self.occurrence_timestamps.pop_front();
+        }
+        self.occurrence_timestamps.push_back(timestamp_ms);
+        if self.occurrence_lap_distances.len() >= MAX_OCCURRENCE_HISTORY {
+            self.occurrence_lap_distances.pop_front();
+        }
+        self.occurrence_lap_distances.push_back(lap_distance_pct);
+        if self.occurrence_causes.len() >= MAX_OCCURRENCE_HISTORY {
+            self.occurrence_causes.pop_front();
+        }
+        self.occurrence_causes.push_back(None);
+    }
+
+    /// Put the latest detection down to `cause`.
+    pub fn record_cause(&mut self, cause: ExitUndersteerCause) {
+        self.cause_tally.record(cause);
+        if let Some(latest) = self.occurrence_causes.back_mut() {
+            *latest = Some(cause);
+        }
+    }
+
+    /// The finding counting only its detections within `window`, `None` when none of
+    /// them are. Only detections still in the occurrence history are counted, and the
+    /// corner type tally is kept whole as it is not tracked per detection.
+    pub fn within(&self, window: &CornerWindow) -> Option<Finding> {
+        // findings saved before lap distances or causes were tracked have fewer of them,
+        // the newest detections line up
+        let causes = self
+            .occurrence_causes
+            .iter()
+            .rev()
+            .copied()
+            .chain(std::iter::repeat(None));
+        let mut occurrences: Vec<(u128, Option<f32>, Option<ExitUndersteerCause>)> = self
+            .occurrence_timestamps
+            .iter()
+            .rev()
+            .zip(self.occurrence_lap_distances.iter().rev())
+            .zip(causes)
+            .filter(|((_, lap_distance_pct), _)| {
+                lap_distance_pct.is_some_and(|d| window.contains(d))
+            })
+            .map(|((timestamp_ms, lap_distance_pct), cause)| {
+                (*timestamp_ms, *lap_distance_pct, cause)
+            })
+            .collect();
+        occurrences.reverse();
+        let last_detected = occurrences.last()?.0;
+        let mut cause_tally = CauseTally::default();
+        for cause in occurrences.iter().filter_map(|(_, _, cause)| *cause) {
+            cause_tally.record(cause);
+        }
+        Some(Finding {
+            occurrence_count: occurrences.len(),
+            last_detected,
+            occurrence_timestamps: occurrences.iter().map(|(t, _, _)| *t).collect(),
+            occurrence_lap_distances: occurrences.iter().map(|(_, d, _)| *d).collect(),
+            occurrence_causes: occurrences.iter().map(|(_, _, c)| *c).collect(),
+            cause_tally,
+            ..self.clone()
+        })
+    }
+
+    /// Add the detections of `other`, a finding of the same type from another lap or
+    /// session.
+    pub fn merge(&mut self, other: &Finding) {
+        self.occurrence_count += other.occurrence_count;
+        self.last_detected = self.last_detected.max(other.last_detected);
+        self.severity = self.severity.max(other.severity);
+        self.occurrence_timestamps
+            .extend(other.occurrence_timestamps.iter().copied());
+        self.occurrence_lap_distances
+            .extend(other.occurrence_lap_distances.iter().copied());
+        let excess = self
+            .occurrence_timestamps
+            .len()
+            .saturating_sub(MAX_OCCURRENCE_HISTORY);
+        self.occurrence_timestamps.drain(..excess);
+        let excess = self
+            .occurrence_lap_distances
+            .len()
+            .saturating_sub(MAX_OCCURRENCE_HISTORY);
+        self.occurrence_lap_distances.drain(..excess);
+        self.occurrence_causes
+            .extend(other.occurrence_causes.iter().copied());
+        let excess = self
+            .occurrence_causes
+            .len()
+            .saturating_sub(MAX_OCCURRENCE_HISTORY);
+        self.occurrence_causes.drain(..excess);
+        self.cause_tally.differential += other.cause_tally.differential;
+        self.cause_tally.aero += other.cause_tally.aero;
+        self.corner_type_tally.merge(&other.corner_type_tally);
+    }
+
+    /// Most frequent cause of the finding, [`ExitUndersteerCause::Unknown`] when no
+    /// detection carried one or the causes are tied.
+    pub fn likely_cause(&self) -> ExitUndersteerCause {
+        self.cause_tally.likely_cause()
+    }
+}
+
+/// Detections of a finding put down to the differential and to aero.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CauseTally {
+    pub differential: usize,
+    pub aero: usize,
+}
+
+impl CauseTally {
+    /// Count a detection with the given cause, unknown causes are not counted.
+    pub fn record(&mut self, cause: ExitUndersteerCause) {
+        match cause {
+            ExitUndersteerCause::Differential => self.differential += 1,
+            ExitUndersteerCause::Aero => self.aero += 1,
+            ExitUndersteerCause::Unknown => {}
+        }
+    }
+
+    pub fn likely_cause(&self) -> ExitUndersteerCause {
+        match self.differential.cmp(&self.aero) {
+            std::cmp::Ordering::Greater => ExitUndersteerCause::Differential,
+            std::cmp::Ordering::Less => ExitUndersteerCause::Aero,
+            std::cmp::Ordering::Equal => ExitUndersteerCause::Unknown,
+        }
+    }
+}
+
+/// Slow and abrupt load transfers timed in the session.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LoadTransferTally {
+    pub slow: usize,
+    pub abrupt: usize,
+}
+
+impl LoadTransferTally {
+    /// Count a load transfer, transfers within the thresholds are not counted.
+    pub fn record(&mut self, is_slow: bool, is_abrupt: bool) {
+        if is_slow {
+            self.slow += 1;
+        } else if is_abrupt {
+            self.abrupt += 1;
+        }
+    }
+
+    pub fn likely_timing(&self) -> LoadTransferTiming {
+        match self.slow.cmp(&self.abrupt) {
+            std::cmp::Ordering::Greater => LoadTransferTiming::Slow,
+            std::cmp::Ordering::Less => LoadTransferTiming::Abrupt,
+            std::cmp::Ordering::Equal => LoadTransferTiming::Unknown,
+        }
+    }
 }
 
 /// The phase of a corner where a finding was detected.
@@ -116,6 +364,18 @@ impl std::fmt::Display for CornerPhase {
     }
 }
 
+/// Oversteer detections of a slide still in progress, held back until the slide ends
+/// and it is known whether the driver caught it
+#[derive(Debug, Clone)]
+struct PendingSlide {
+    finding_type: FindingType,
+    corner_phase: CornerPhase,
+    /// Timestamps and lap distances of the points of the slide
+    occurrences: Vec<(u128, Option<f32>)>,
+    /// Whether the driver caught the slide, as of its latest point
+    is_recovered: bool,
+}
+
 /// Core state manager for the Setup Assistant feature.
 ///
 /// The SetupAssistant processes telemetry data to extract findings, manages
@@ -134,20 +394,73 @@ pub struct SetupAssistant {
     findings: HashMap<FindingType, Finding>,
     /// Set of findings that the user has confirmed
     confirmed_findings: HashSet<FindingType>,
+    /// Recommendations the user has already tried, kept across sessions
+    tried_recommendations: HashSet<TriedRecommendation>,
     /// Engine for mapping findings to setup recommendations
     recommendation_engine: RecommendationEngine,
+    /// Findings seen fewer times than this are tracked but not actionable
+    min_occurrences: usize,
+    /// Findings below this severity are tracked but not actionable
+    min_severity: f32,
+    /// Stable corner phase of the telemetry stream
+    corner_phase: CornerPhaseClassifier,
+    /// Corner in progress, to count findings by the type of corner they were found in
+    corner_type: CornerTypeTracker,
+    /// Load transfers of the session, to point the damper recommendations
+    load_transfer: LoadTransferTally,
+    /// Oversteer slides in progress, at most one per finding type
+    pending_slides: Vec<PendingSlide>,
 }
 
 impl SetupAssistant {
     /// Create a new SetupAssistant instance.
     pub fn new() -> Self {
+        Self::with_thresholds(DEFAULT_MIN_OCCURRENCES, DEFAULT_MIN_SEVERITY)
+    }
+
+    /// Create a new SetupAssistant with custom actionable finding thresholds.
+    ///
+    /// See [`SetupAssistant::get_actionable_findings`].
+    pub fn with_thresholds(min_occurrences: usize, min_severity: f32) -> Self {
         Self {
             findings: HashMap::new(),
             confirmed_findings: HashSet::new(),
+            tried_recommendations: HashSet::new(),
             recommendation_engine: RecommendationEngine::new(),
+            min_occurrences,
+            min_severity,
+            corner_phase: CornerPhaseClassifier::new(),
+            corner_type: CornerTypeTracker::default(),
+            load_transfer: LoadTransferTally::default(),
+            pending_slides: Vec::new(),
         }
     }
 
+    /// Count findings by the type of the corners `detection` finds, usually the
+    /// `corner_detection` thresholds of the analyzer config. Forgets the corner in
+    /// progress.
+    pub fn set_corner_detection(&mut self, detection: CornerDetection) {
+        self.corner_type = CornerTypeTracker::new(detection);
+    }
+
+    /// Use the recommendations tuned for `car_name`, or the generic ones when the car
+    /// is unknown or has no overrides.
+    pub fn set_car(&mut self, car_name: Option<&str>, overrides: &CarRecommendationOverrides) {
+        self.recommendation_engine = match car_name {
+            Some(car_name) => RecommendationEngine::for_car(car_name, overrides),
+            None => RecommendationEngine::new(),
+        };
+    }
+
+    /// Use the recommendations tuned for `car_name` in the overrides file of the config
+    /// directory. The file is read on every call so edits apply from the next session.
+    pub fn set_car_from_local_overrides(&mut self, car_name: Option<&str>) {
+        self.set_car(
+            car_name,
+            &CarRecommendationOverrides::from_local_file().unwrap_or_default(),
+        );
+    }
+
     /// Process telemetry data to extract and aggregate findings.
     ///
     /// This method examines telemetry annotations and converts them into
@@ -160,74 +473,150 @@ impl SetupAssistant {
     /// - Aggregates duplicate findings with occurrence counting
     /// - Classifies corner phase from telemetry state
     /// - Classifies slip by context (throttle/brake state)
+    ///
+    /// Oversteer detections are held back until the slide ends, on the first point
+    /// without them, and only counted if the driver did not catch the slide.
     pub fn process_telemetry(&mut self, telemetry: &TelemetryData) {
-        // Classify corner phase from telemetry state
-        let corner_phase = Self::classify_corner_phase(telemetry);
+        // Classify corner phase from telemetry state, debounced across points
+        let corner_phase = self.corner_phase.update(telemetry);
 
         // Process each annotation
+        let mut detections = Vec::new();
+        let mut sliding = Vec::new();
         for annotation in &telemetry.annotations {
+            if let crate::telemetry::TelemetryAnnotation::LoadTransfer {
+                is_slow, is_abrupt, ..
+            } = annotation
+            {
+                self.load_transfer.record(*is_slow, *is_abrupt);
+            }
             // Map annotation to finding type based on context
-            if let Some(finding_type) = Self::annotation_to_finding_type(annotation, telemetry) {
-                // Get or create finding
-                let finding = self
-                    .findings
-                    .entry(finding_type.clone())
-                    .or_insert(Finding {
-                        finding_type: finding_type.clone(),
-                        occurrence_count: 0,
+            if let Some(finding_type) =
+                Self::annotation_to_finding_type(annotation, telemetry, corner_phase)
+            {
+                if let Some(is_recovered) = Self::slide_recovery(annotation) {
+                    self.hold_slide_occurrence(
+                        finding_type.clone(),
                         corner_phase,
-                        last_detected: telemetry.timestamp_ms,
-                        severity: 0.5,
-                    });
+                        telemetry,
+                        is_recovered,
+                    );
+                    sliding.push(finding_type);
+                    continue;
+                }
 
                 // Aggregate: increment occurrence count
-                finding.occurrence_count += 1;
-                finding.last_detected = telemetry.timestamp_ms;
+                let finding = self.record_finding(
+                    finding_type.clone(),
+                    corner_phase,
+                    telemetry.timestamp_ms,
+                    telemetry.lap_distance_pct,
+                );
+                if let crate::telemetry::TelemetryAnnotation::ExitThrottleLift { cause, .. } =
+                    annotation
+                {
+                    finding.record_cause(*cause);
+                }
+                detections.push(finding_type);
             }
         }
-    }
 
-    /// Classify the corner phase based on telemetry state.
-    ///
-    /// Uses brake, throttle, and steering inputs to determine which phase
-    /// of the corner the car is in.
-    ///
-    /// # Requirements
-    ///
-    /// Implements Requirement 1.4: Corner phase classification
-    fn classify_corner_phase(telemetry: &TelemetryData) -> CornerPhase {
-        const MIN_BRAKE_THRESHOLD: f32 = 0.1;
-        const MIN_THROTTLE_THRESHOLD: f32 = 0.1;
-        const MIN_STEERING_THRESHOLD: f32 = 0.05;
-
-        let brake = telemetry.brake.unwrap_or(0.0);
-        let throttle = telemetry.throttle.unwrap_or(0.0);
-        let steering = telemetry.steering_pct.unwrap_or(0.0).abs();
-
-        // Entry: braking with steering
-        if brake > MIN_BRAKE_THRESHOLD && steering > MIN_STEERING_THRESHOLD {
-            return CornerPhase::Entry;
+        // A slide ends on the first point without its oversteer detection, a caught
+        // slide is driving on the edge rather than a setup issue
+        let (ended, ongoing): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_slides)
+            .into_iter()
+            .partition(|slide| !sliding.contains(&slide.finding_type));
+        self.pending_slides = ongoing;
+        for slide in ended.into_iter().filter(|slide| !slide.is_recovered) {
+            for (timestamp_ms, lap_distance_pct) in slide.occurrences {
+                self.record_finding(
+                    slide.finding_type.clone(),
+                    slide.corner_phase,
+                    timestamp_ms,
+                    lap_distance_pct,
+                );
+            }
+            detections.push(slide.finding_type);
         }
 
-        // Exit: throttle with steering
-        if throttle > MIN_THROTTLE_THRESHOLD && steering > MIN_STEERING_THRESHOLD {
-            return CornerPhase::Exit;
+        // The type of a corner is only known once its apex speed is
+        if let Some((corner_type, finding_types)) = self.corner_type.update(telemetry, detections) {
+            for finding_type in finding_types {
+                if let Some(finding) = self.findings.get_mut(&finding_type) {
+                    finding.corner_type_tally.record(corner_type);
+                }
+            }
         }
+    }
 
-        // Mid: steering but minimal throttle/brake (coasting)
-        if steering > MIN_STEERING_THRESHOLD
-            && brake < MIN_BRAKE_THRESHOLD
-            && throttle < MIN_THROTTLE_THRESHOLD
-        {
-            return CornerPhase::Mid;
-        }
+    /// Count a detection of `finding_type`, creating the finding on its first one.
+    fn record_finding(
+        &mut self,
+        finding_type: FindingType,
+        corner_phase: CornerPhase,
+        timestamp_ms: u128,
+        lap_distance_pct: Option<f32>,
+    ) -> &mut Finding {
+        let finding = self
+            .findings
+            .entry(finding_type.clone())
+            .or_insert(Finding {
+                finding_type,
+                occurrence_count: 0,
+                corner_phase,
+                last_detected: timestamp_ms,
+                severity: 0.5,
+                occurrence_timestamps: VecDeque::new(),
+                occurrence_lap_distances: VecDeque::new(),
+                occurrence_causes: VecDeque::new(),
+                cause_tally: CauseTally::default(),
+                corner_type_tally: CornerTypeTally::default(),
+            });
+        finding.record_occurrence(timestamp_ms, lap_distance_pct);
+        finding
+    }
+
+    /// Whether the driver caught the slide so far, `None` for annotations that are
+    /// not oversteer slides
+    fn slide_recovery(annotation: &crate::telemetry::TelemetryAnnotation) -> Option<bool> {
+        use crate::telemetry::TelemetryAnnotation;
 
-        // Straight: minimal steering
-        if steering < MIN_STEERING_THRESHOLD {
-            return CornerPhase::Straight;
+        match annotation {
+            TelemetryAnnotation::EntryOversteer { is_recovered, .. }
+            | TelemetryAnnotation::MidCornerOversteer { is_recovered, .. } => Some(*is_recovered),
+            _ => None,
         }
+    }
 
-        CornerPhase::Unknown
+    /// Add an oversteer detection to the slide in progress, starting one if needed.
+    fn hold_slide_occurrence(
+        &mut self,
+        finding_type: FindingType,
+        corner_phase: CornerPhase,
+        telemetry: &TelemetryData,
+        is_recovered: bool,
+    ) {
+        let slide = match self
+            .pending_slides
+            .iter()
+            .position(|slide| slide.finding_type == finding_type)
+        {
+            Some(index) => &mut self.pending_slides[index],
+            None => {
+                self.pending_slides.push(PendingSlide {
+                    finding_type,
+                    corner_phase,
+                    occurrences: Vec::new(),
+                    is_recovered,
+                });
+                self.pending_slides.last_mut().unwrap()
+            }
+        };
+        slide
+            .occurrences
+            .push((telemetry.timestamp_ms, telemetry.lap_distance_pct));
+        // only the last point of the slide settles whether it was caught
+        slide.is_recovered = is_recovered;
     }
 
     /// Map a telemetry annotation to a finding type based on context.
@@ -243,6 +632,7 @@ impl SetupAssistant {
     fn annotation_to_finding_type(
         annotation: &crate::telemetry::TelemetryAnnotation,
         telemetry: &TelemetryData,
+        corner_phase: CornerPhase,
     ) -> Option<FindingType> {
         use crate::telemetry::TelemetryAnnotation;
 
@@ -261,6 +651,7 @@ impl SetupAssistant {
                 is_slip,
                 prev_speed,
                 cur_speed,
+                slip_ratio: _,
             } => {
                 if !*is_slip {
                     return None;
@@ -299,7 +690,6 @@ impl SetupAssistant {
                     // and we're in the corner exit phase
                     const MIN_STEERING_FOR_OVERSTEER: f32 = 0.05;
                     let steering = telemetry.steering_pct.unwrap_or(0.0).abs();
-                    let corner_phase = Self::classify_corner_phase(telemetry);
 
                     if steering > MIN_STEERING_FOR_OVERSTEER && corner_phase == CornerPhase::Exit {
                         Some(FindingType::CornerExitPowerOversteer)
@@ -324,7 +714,10 @@ impl SetupAssistant {
                 }
             }
 
-            // Entry oversteer
+            // Brake release quality is driving feedback, not a setup issue
+            TelemetryAnnotation::TrailbrakeRelease { .. } => None,
+
+            // Entry oversteer, held back until the slide ends to leave out caught slides
             TelemetryAnnotation::EntryOversteer { is_oversteer, .. } => {
                 if *is_oversteer {
                     Some(FindingType::CornerEntryOversteer)
@@ -342,7 +735,7 @@ impl SetupAssistant {
                 }
             }
 
-            // Mid-corner oversteer
+            // Mid-corner oversteer, held back until the slide ends like entry oversteer
             TelemetryAnnotation::MidCornerOversteer { is_oversteer, .. } => {
                 if *is_oversteer {
                     Some(FindingType::MidCornerOversteer)
@@ -352,8 +745,15 @@ impl SetupAssistant {
             }
 
             // Front brake lock
-            TelemetryAnnotation::FrontBrakeLock { is_front_lock, .. } => {
-                if *is_front_lock {
+            // Locks caused by stomping the pedal need technique feedback, not a bias change
+            TelemetryAnnotation::FrontBrakeLock {
+                is_front_lock,
+                is_initial_application_lock,
+                ..
+            } => {
+                if *is_initial_application_lock {
+                    Some(FindingType::BrakeStompLock)
+                } else if *is_front_lock {
                     Some(FindingType::FrontBrakeLock)
                 } else {
                     None
@@ -396,8 +796,179 @@ impl SetupAssistant {
                 }
             }
 
+            // Camber imbalance maps to the axle furthest from the ideal temperature spread
+            TelemetryAnnotation::CamberImbalance {
+                lf_spread,
+                rf_spread,
+                lr_spread,
+                rr_spread,
+                is_imbalanced,
+            } => {
+                if !*is_imbalanced {
+                    return None;
+                }
+
+                use crate::telemetry::camber_analyzer::{
+                    MAX_INNER_OUTER_SPREAD, MIN_INNER_OUTER_SPREAD,
+                };
+
+                // Distance outside the ideal range, negative when the outer edge is too hot
+                let deviation = |spread: f32| {
+                    if spread < MIN_INNER_OUTER_SPREAD {
+                        spread - MIN_INNER_OUTER_SPREAD
+                    } else if spread > MAX_INNER_OUTER_SPREAD {
+                        spread - MAX_INNER_OUTER_SPREAD
+                    } else {
+                        0.0
+                    }
+                };
+                let front = deviation((lf_spread + rf_spread) / 2.0);
+                let rear = deviation((lr_spread + rr_spread) / 2.0);
+
+                if front == 0.0 && rear == 0.0 {
+                    None
+                } else if front.abs() >= rear.abs() {
+                    if front < 0.0 {
+                        Some(FindingType::FrontCamberTooPositive)
+                    } else {
+                        Some(FindingType::FrontCamberTooNegative)
+                    }
+                } else if rear < 0.0 {
+                    Some(FindingType::RearCamberTooPositive)
+                } else {
+                    Some(FindingType::RearCamberTooNegative)
+                }
+            }
+
             // Short shifting is not a setup issue, so we don't map it
             TelemetryAnnotation::ShortShifting { .. } => None,
+
+            // Over and under revving are shifting technique first, the gearing can help
+            TelemetryAnnotation::ShiftPoint {
+                is_over_revving,
+                is_under_revving,
+                ..
+            } => {
+                if *is_over_revving {
+                    Some(FindingType::UpshiftOverRevving)
+                } else if *is_under_revving {
+                    Some(FindingType::UpshiftUnderRevving)
+                } else {
+                    None
+                }
+            }
+
+            // Clutch bog and kick are driving technique feedback, not setup issues
+            TelemetryAnnotation::ClutchEngagement { .. } => None,
+
+            // Over-rotating the wheel on turn-in is driving technique, not a setup issue
+            TelemetryAnnotation::EntrySteeringOvershoot { .. } => None,
+
+            // One annotation covers a whole corner, braking aids are checked first
+            TelemetryAnnotation::ElectronicsIntervention {
+                is_excessive_abs,
+                is_excessive_tc,
+                ..
+            } => {
+                if *is_excessive_abs {
+                    Some(FindingType::FrequentAbsIntervention)
+                } else if *is_excessive_tc {
+                    Some(FindingType::FrequentTcIntervention)
+                } else {
+                    None
+                }
+            }
+
+            TelemetryAnnotation::StraightLineInstability { is_unstable, .. } => {
+                is_unstable.then_some(FindingType::StraightLineInstability)
+            }
+
+            // Repeated kerb strikes point at the line or kerb usage, not a setup issue
+            TelemetryAnnotation::KerbStrike { .. } => None,
+
+            // Lifting with the steering wound on confirms the front washing wide on exit
+            TelemetryAnnotation::ExitThrottleLift {
+                is_understeer_lift, ..
+            } => is_understeer_lift.then_some(FindingType::CornerExitUndersteer),
+
+            // Stabbing the throttle is technique first, traction control only softens it
+            TelemetryAnnotation::ThrottleStab { is_stabbing, .. } => {
+                is_stabbing.then_some(FindingType::ThrottleStabbing)
+            }
+
+            // Pedal overlap is technique feedback, and deliberate for left-foot brakers
+            TelemetryAnnotation::PedalOverlap { .. } => None,
+
+            // Busy hands are technique feedback, a car that will not run straight is
+            // reported by the straight-line instability analyzer
+            TelemetryAnnotation::SteeringReversals { .. } => None,
+
+            // A missed downshift is a driver error, the setup cannot prevent it
+            TelemetryAnnotation::OverRev { .. } => None,
+
+            // Braking before the car settled from the last corner unsettles any setup,
+            // counting it as entry understeer would point at the wrong fix
+            TelemetryAnnotation::TransitionInstability { .. } => None,
+
+            // The speed of the weight transfer is set by the slow-speed dampers, it
+            // decides which way the damper recommendations of other findings go
+            TelemetryAnnotation::LoadTransfer { .. } => None,
+
+            // Only downshifts after which the rear actually slid, auto-blip cars spike the
+            // RPM too but stay settled
+            TelemetryAnnotation::DownshiftInstability { is_unstable, .. } => {
+                if *is_unstable {
+                    Some(FindingType::DownshiftInstability)
+                } else {
+                    None
+                }
+            }
+
+            // Pressure is classified per tire, the axle furthest out of the window is
+            // reported like for camber
+            TelemetryAnnotation::TirePressureImbalance {
+                lf_spread,
+                rf_spread,
+                lr_spread,
+                rr_spread,
+                is_imbalanced,
+            } => {
+                if !*is_imbalanced {
+                    return None;
+                }
+
+                use crate::telemetry::tire_pressure_analyzer::{
+                    MAX_MIDDLE_EDGE_SPREAD, MIN_MIDDLE_EDGE_SPREAD,
+                };
+
+                // Distance outside the window, negative when the edges are too hot
+                let deviation = |spread: f32| {
+                    if spread < MIN_MIDDLE_EDGE_SPREAD {
+                        spread - MIN_MIDDLE_EDGE_SPREAD
+                    } else if spread > MAX_MIDDLE_EDGE_SPREAD {
+                        spread - MAX_MIDDLE_EDGE_SPREAD
+                    } else {
+                        0.0
+                    }
+                };
+                let widest = |a: f32, b: f32| if a.abs() >= b.abs() { a } else { b };
+                let front = widest(deviation(*lf_spread), deviation(*rf_spread));
+                let rear = widest(deviation(*lr_spread), deviation(*rr_spread));
+
+                if front == 0.0 && rear == 0.0 {
+                    None
+                } else if front.abs() >= rear.abs() {
+                    if front > 0.0 {
+                        Some(FindingType::FrontTirePressureTooHigh)
+                    } else {
+                        Some(FindingType::FrontTirePressureTooLow)
+                    }
+                } else if rear > 0.0 {
+                    Some(FindingType::RearTirePressureTooHigh)
+                } else {
+                    Some(FindingType::RearTirePressureTooLow)
+                }
+            }
         }
     }
 
@@ -434,6 +1005,22 @@ impl SetupAssistant {
         &self.findings
     }
 
+    /// Get the findings that passed the occurrence and severity thresholds.
+    ///
+    /// Findings below the thresholds are still tracked, so a one-off event does not
+    /// clutter the list but is surfaced once it happens again.
+    pub fn get_actionable_findings(&self) -> Vec<&Finding> {
+        self.findings
+            .values()
+            .filter(|f| self.is_actionable(f))
+            .collect()
+    }
+
+    /// Check if a finding passed the occurrence and severity thresholds.
+    pub fn is_actionable(&self, finding: &Finding) -> bool {
+        finding.occurrence_count >= self.min_occurrences && finding.severity >= self.min_severity
+    }
+
     /// Get setup recommendations for all confirmed findings.
     ///
     /// Returns recommendations only for findings that the user has confirmed.
@@ -471,10 +1058,179 @@ impl SetupAssistant {
     /// - Sorted by priority (highest first)
     /// - Conflicts detected and marked
     /// - Duplicate adjustments consolidated
+    /// - Confidence scored from the confirmed findings' occurrences and severity
     pub fn get_processed_recommendations(&self) -> Vec<recommendations::ProcessedRecommendation> {
-        let raw_recommendations = self.get_recommendations();
+        let mut processed = self.recommendations_for(
+            &self.confirmed_findings,
+            self.findings.get(&FindingType::CornerExitUndersteer),
+        );
+
+        let confirmed: Vec<Finding> = self
+            .findings
+            .values()
+            .filter(|f| self.confirmed_findings.contains(&f.finding_type))
+            .cloned()
+            .collect();
+        self.recommendation_engine
+            .score_confidence(&mut processed, &confirmed);
+        processed
+    }
+
+    /// Processed recommendations for `findings`, as if they were the confirmed ones,
+    /// with confidence scored from them.
+    ///
+    /// Used for findings gathered outside of the assistant, such as the findings of a
+    /// single corner across the laps of a session.
+    pub fn recommend_for_findings(
+        &self,
+        findings: &[Finding],
+    ) -> Vec<recommendations::ProcessedRecommendation> {
+        let finding_types = findings.iter().map(|f| f.finding_type.clone()).collect();
+        let mut processed = self.recommendations_for(
+            &finding_types,
+            findings
+                .iter()
+                .find(|f| f.finding_type == FindingType::CornerExitUndersteer),
+        );
+        self.recommendation_engine
+            .score_confidence(&mut processed, findings);
+        processed
+    }
+
+    fn recommendations_for(
+        &self,
+        finding_types: &HashSet<FindingType>,
+        exit_understeer: Option<&Finding>,
+    ) -> Vec<recommendations::ProcessedRecommendation> {
+        let exit_understeer_cause = exit_understeer
+            .map(Finding::likely_cause)
+            .unwrap_or_default();
+        self.recommendation_engine.recommend_for_cause(
+            finding_types,
+            exit_understeer_cause,
+            self.load_transfer.likely_timing(),
+        )
+    }
+
+    /// Confirmed findings and their recommendations grouped by the type of corner they
+    /// were detected in, slow corners first.
+    ///
+    /// Each group counts only the detections in its type of corner, so understeer in
+    /// slow corners and oversteer in fast ones get their own recommendations. Confirmed
+    /// findings without any classified detection, e.g. on the straights or saved before
+    /// corner types were tracked, come last in a group without a corner type. Empty when
+    /// no finding is confirmed.
+    pub fn get_recommendations_by_corner_type(&self) -> Vec<CornerTypeRecommendations> {
+        let confirmed: Vec<&Finding> = self
+            .findings
+            .values()
+            .filter(|f| self.confirmed_findings.contains(&f.finding_type))
+            .collect();
+        if confirmed.is_empty() {
+            return Vec::new();
+        }
+
+        let mut groups: Vec<CornerTypeRecommendations> = CornerType::ALL
+            .into_iter()
+            .filter_map(|corner_type| {
+                let mut findings: Vec<Finding> = confirmed
+                    .iter()
+                    .filter(|f| f.corner_type_tally.count(corner_type) > 0)
+                    .map(|f| Finding {
+                        occurrence_count: f.corner_type_tally.count(corner_type),
+                        ..(*f).clone()
+                    })
+                    .collect();
+                if findings.is_empty() {
+                    return None;
+                }
+                findings.sort_by(|a, b| b.occurrence_count.cmp(&a.occurrence_count));
+                let recommendations = self.recommend_for_findings(&findings);
+                Some(CornerTypeRecommendations {
+                    corner_type: Some(corner_type),
+                    findings,
+                    recommendations,
+                })
+            })
+            .collect();
+
+        let mut unclassified: Vec<Finding> = confirmed
+            .into_iter()
+            .filter(|f| f.corner_type_tally.is_empty())
+            .cloned()
+            .collect();
+        if !unclassified.is_empty() {
+            unclassified.sort_by(|a, b| b.occurrence_count.cmp(&a.occurrence_count));
+            let recommendations = self.recommend_for_findings(&unclassified);
+            groups.push(CornerTypeRecommendations {
+                corner_type: None,
+                findings: unclassified,
+                recommendations,
+            });
+        }
+        groups
+    }
+
+    /// Findings counting only their detections within `window`, findings without any
+    /// detection in it are left out.
+    pub fn findings_within(&self, window: &CornerWindow) -> Vec<Finding> {
+        self.findings
+            .values()
+            .filter_map(|f| f.within(window))
+            .collect()
+    }
+
+    /// Whether the user has tried the recommendation for any of the confirmed findings
+    /// that recommend it.
+    pub fn is_tried(&self, recommendation: &SetupRecommendation) -> bool {
         self.recommendation_engine
-            .process_recommendations(raw_recommendations)
+            .findings_recommending(&self.confirmed_findings, recommendation)
+            .into_iter()
+            .any(|finding_type| {
+                self.tried_recommendations.contains(&TriedRecommendation {
+                    finding_type,
+                    parameter: recommendation.parameter.clone(),
+                })
+            })
+    }
+
+    /// Mark the recommendation as tried, or not, for every confirmed finding that
+    /// recommends it.
+    ///
+    /// Tried recommendations are independent from confirmations and are not cleared
+    /// with the session, so the user can see which changes were already made when the
+    /// same issue comes back.
+    pub fn set_tried(&mut self, recommendation: &SetupRecommendation, tried: bool) {
+        let finding_types = self
+            .recommendation_engine
+            .findings_recommending(&self.confirmed_findings, recommendation);
+        for finding_type in finding_types {
+            let key = TriedRecommendation {
+                finding_type,
+                parameter: recommendation.parameter.clone(),
+            };
+            if tried {
+                self.tried_recommendations.insert(key);
+            } else {
+                self.tried_recommendations.remove(&key);
+            }
+        }
+    }
+
+    /// Render a markdown report of the confirmed findings and their recommendations.
+    ///
+    /// Confirmed findings are listed most frequent first, followed by the processed
+    /// recommendations grouped by setup category. See [`render_markdown_report`].
+    pub fn export_report(&self) -> String {
+        let mut confirmed: Vec<Finding> = self
+            .findings
+            .values()
+            .filter(|f| self.confirmed_findings.contains(&f.finding_type))
+            .cloned()
+            .collect();
+        confirmed.sort_by(|a, b| b.occurrence_count.cmp(&a.occurrence_count));
+
+        render_markdown_report(&confirmed, &self.get_processed_recommendations())
     }
 
     /// Clear all findings and state for a new session.
@@ -488,6 +1244,10 @@ impl SetupAssistant {
     pub fn clear_session(&mut self) {
         self.findings.clear();
         self.confirmed_findings.clear();
+        self.corner_phase.reset();
+        self.corner_type.reset();
+        self.load_transfer = LoadTransferTally::default();
+        self.pending_slides.clear();
     }
 
     /// Get the current findings for persistence.
@@ -512,6 +1272,11 @@ impl SetupAssistant {
         &self.confirmed_findings
     }
 
+    /// Get the tried recommendations for persistence.
+    pub fn get_tried_recommendations_for_persistence(&self) -> &HashSet<TriedRecommendation> {
+        &self.tried_recommendations
+    }
+
     /// Restore findings from persisted state.
     ///
     /// Replaces the current findings with the provided state.
@@ -533,6 +1298,14 @@ impl SetupAssistant {
     pub fn restore_confirmed_findings(&mut self, confirmed_findings: HashSet<FindingType>) {
         self.confirmed_findings = confirmed_findings;
     }
+
+    /// Restore tried recommendations from persisted state.
+    pub fn restore_tried_recommendations(
+        &mut self,
+        tried_recommendations: HashSet<TriedRecommendation>,
+    ) {
+        self.tried_recommendations = tried_recommendations;
+    }
 }
 
 impl Default for SetupAssistant {
@@ -544,6 +1317,7 @@ impl Default for SetupAssistant {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use corner_phase::classify_corner_phase;
 
     #[test]
     fn test_new_setup_assistant_is_empty() {
@@ -607,6 +1381,7 @@ mod tests {
                     cur_gear: 2,
                     cur_rpm_increase: 500.0,
                     is_wheelspin: true,
+                    slip_ratio: None,
                 },
             ],
             ..Default::default()
@@ -733,70 +1508,373 @@ mod tests {
     }
 
     #[test]
-    fn test_classify_corner_phase_entry() {
-        use crate::telemetry::TelemetryData;
+    fn test_finding_timestamps_follow_the_clock() {
+        use crate::telemetry::{TelemetryAnnotation, clock::ManualClock};
 
-        let telemetry = TelemetryData {
-            brake: Some(0.8),
-            throttle: Some(0.0),
-            steering_pct: Some(0.3),
-            ..Default::default()
-        };
+        let clock = ManualClock::new(60_000);
+        let mut assistant = SetupAssistant::new();
+        for _ in 0..3 {
+            // points are stamped when they are made, the assistant keeps their time
+            assistant.process_telemetry(&TelemetryData {
+                annotations: vec![TelemetryAnnotation::Scrub {
+                    avg_yaw_rate_change: 0.1,
+                    cur_yaw_rate_change: 0.3,
+                    is_scrubbing: true,
+                }],
+                ..TelemetryData::now(&clock)
+            });
+            clock.advance(250);
+        }
 
-        let phase = SetupAssistant::classify_corner_phase(&telemetry);
-        assert_eq!(phase, CornerPhase::Entry);
+        let finding = &assistant.get_findings()[&FindingType::CornerEntryUndersteer];
+        assert_eq!(finding.occurrence_count, 3);
+        assert_eq!(finding.last_detected, 60_500);
+        assert_eq!(
+            finding.occurrence_timestamps,
+            VecDeque::from([60_000, 60_250, 60_500])
+        );
     }
 
     #[test]
-    fn test_classify_corner_phase_exit() {
-        use crate::telemetry::TelemetryData;
-
-        let telemetry = TelemetryData {
-            brake: Some(0.0),
-            throttle: Some(0.7),
-            steering_pct: Some(0.3),
-            ..Default::default()
+    fn test_finding_keeps_bounded_occurrence_history() {
+        let mut finding = Finding {
+            finding_type: FindingType::CornerEntryUndersteer,
+            occurrence_count: 0,
+            corner_phase: CornerPhase::Entry,
+            last_detected: 0,
+            severity: 0.5,
+            occurrence_timestamps: VecDeque::new(),
+            occurrence_lap_distances: VecDeque::new(),
+            occurrence_causes: VecDeque::new(),
+            cause_tally: CauseTally::default(),
+            corner_type_tally: CornerTypeTally::default(),
         };
 
-        let phase = SetupAssistant::classify_corner_phase(&telemetry);
-        assert_eq!(phase, CornerPhase::Exit);
+        for timestamp_ms in 0..(MAX_OCCURRENCE_HISTORY as u128 + 10) {
+            finding.record_occurrence(timestamp_ms, None);
+        }
+
+        assert_eq!(finding.occurrence_count, MAX_OCCURRENCE_HISTORY + 10);
+        assert_eq!(finding.last_detected, MAX_OCCURRENCE_HISTORY as u128 + 9);
+        assert_eq!(finding.occurrence_timestamps.len(), MAX_OCCURRENCE_HISTORY);
+        assert_eq!(finding.occurrence_timestamps.front(), Some(&10));
+        assert_eq!(
+            finding.occurrence_lap_distances.len(),
+            MAX_OCCURRENCE_HISTORY
+        );
     }
 
     #[test]
-    fn test_classify_corner_phase_mid() {
-        use crate::telemetry::TelemetryData;
+    fn test_finding_within_corner_window() {
+        let mut finding = Finding {
+            finding_type: FindingType::CornerExitUndersteer,
+            occurrence_count: 0,
+            corner_phase: CornerPhase::Exit,
+            last_detected: 0,
+            severity: 0.5,
+            occurrence_timestamps: VecDeque::new(),
+            occurrence_lap_distances: VecDeque::new(),
+            occurrence_causes: VecDeque::new(),
+            cause_tally: CauseTally::default(),
+            corner_type_tally: CornerTypeTally::default(),
+        };
+        for (timestamp_ms, lap_distance_pct) in [
+            (100, Some(0.12)),
+            (200, Some(0.45)),
+            (300, None),
+            (400, Some(0.48)),
+        ] {
+            finding.record_occurrence(timestamp_ms, lap_distance_pct);
+            // the detection outside the corner is put down to aero
+            finding.record_cause(if timestamp_ms == 100 {
+                ExitUndersteerCause::Aero
+            } else {
+                ExitUndersteerCause::Differential
+            });
+        }
 
-        let telemetry = TelemetryData {
-            brake: Some(0.0),
-            throttle: Some(0.0),
-            steering_pct: Some(0.3),
-            ..Default::default()
+        let window = CornerWindow {
+            start_lap_distance_pct: 0.4,
+            end_lap_distance_pct: 0.5,
+        };
+        let in_corner = finding.within(&window).unwrap();
+        assert_eq!(in_corner.occurrence_count, 2);
+        assert_eq!(in_corner.last_detected, 400);
+        assert_eq!(in_corner.occurrence_timestamps, VecDeque::from([200, 400]));
+        assert_eq!(
+            in_corner.cause_tally,
+            CauseTally {
+                differential: 2,
+                aero: 0
+            }
+        );
+
+        let elsewhere = CornerWindow {
+            start_lap_distance_pct: 0.7,
+            end_lap_distance_pct: 0.8,
         };
+        assert!(finding.within(&elsewhere).is_none());
 
-        let phase = SetupAssistant::classify_corner_phase(&telemetry);
-        assert_eq!(phase, CornerPhase::Mid);
+        let mut merged = in_corner.clone();
+        merged.merge(&in_corner);
+        assert_eq!(merged.occurrence_count, 4);
+        assert_eq!(merged.occurrence_lap_distances.len(), 4);
+    }
+
+    /// Points of a 500ms corner at `speed_mps` with `annotation` on every point, followed
+    /// by a point on the straight that ends it
+    fn corner_with(
+        start_ms: u128,
+        speed_mps: f32,
+        annotation: crate::telemetry::TelemetryAnnotation,
+    ) -> Vec<TelemetryData> {
+        let mut points: Vec<_> = (0..5)
+            .map(|i| TelemetryData {
+                timestamp_ms: start_ms + i * 100,
+                speed_mps: Some(speed_mps),
+                brake: Some(0.5),
+                steering_pct: Some(0.4),
+                annotations: vec![annotation.clone()],
+                ..Default::default()
+            })
+            .collect();
+        points.push(TelemetryData {
+            timestamp_ms: start_ms + 500,
+            speed_mps: Some(speed_mps),
+            ..Default::default()
+        });
+        points
     }
 
     #[test]
-    fn test_classify_corner_phase_straight() {
-        use crate::telemetry::TelemetryData;
+    fn test_recovered_slides_are_not_counted() {
+        use crate::telemetry::{
+            SessionInfo, TelemetryAnalyzer, entry_oversteer_analyzer::EntryOversteerAnalyzer,
+        };
 
-        let telemetry = TelemetryData {
-            brake: Some(0.0),
-            throttle: Some(0.8),
-            steering_pct: Some(0.01),
-            ..Default::default()
+        let mut analyzer = EntryOversteerAnalyzer::<10>::new(5);
+        let mut assistant = SetupAssistant::new();
+        let session_info = SessionInfo::default();
+        let mut timestamp_ms = 0;
+        // the next point, annotated by the analyzer
+        let mut drive = |steering_pct: f32, yaw_rate_rps: f32, speed_mps: f32| {
+            let mut point = TelemetryData {
+                timestamp_ms,
+                brake: Some(0.5),
+                steering_pct: Some(steering_pct),
+                yaw_rate_rps: Some(yaw_rate_rps),
+                speed_mps: Some(speed_mps),
+                ..TelemetryData::default()
+            };
+            point.annotations = analyzer.analyze(&point, &session_info);
+            timestamp_ms += 100;
+            point
         };
+        for _ in 0..5 {
+            assistant.process_telemetry(&drive(0.3, 0.15, 10.));
+        }
 
-        let phase = SetupAssistant::classify_corner_phase(&telemetry);
-        assert_eq!(phase, CornerPhase::Straight);
+        // the rear steps out, then the driver takes lock off and the rotation falls
+        assistant.process_telemetry(&drive(0.3, 0.3, 10.));
+        assistant.process_telemetry(&drive(0.2, 0.25, 10.));
+        assistant.process_telemetry(&drive(0.3, 0.15, 10.));
+        assert!(assistant.get_findings().is_empty());
+
+        // the rear steps out again and the car spins, scrubbing off speed
+        assistant.process_telemetry(&drive(0.3, 0.6, 10.));
+        assistant.process_telemetry(&drive(0.3, 0.7, 8.));
+        assistant.process_telemetry(&drive(0.3, 0.15, 8.));
+        let finding = &assistant.get_findings()[&FindingType::CornerEntryOversteer];
+        assert_eq!(finding.occurrence_count, 2);
     }
 
     #[test]
-    fn test_slip_classification_during_braking() {
-        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+    fn test_recommendations_grouped_by_corner_type() {
+        use crate::telemetry::TelemetryAnnotation;
 
-        let telemetry = TelemetryData {
+        let mut assistant = SetupAssistant::new();
+        let scrub = TelemetryAnnotation::Scrub {
+            avg_yaw_rate_change: 0.1,
+            cur_yaw_rate_change: 0.3,
+            is_scrubbing: true,
+        };
+        let oversteer = TelemetryAnnotation::EntryOversteer {
+            expected_yaw_rate: 0.2,
+            actual_yaw_rate: 0.4,
+            is_oversteer: true,
+            is_recovered: false,
+        };
+        let points = [
+            corner_with(0, 15., scrub.clone()),
+            corner_with(1000, 55., oversteer),
+            corner_with(2000, 18., scrub),
+        ]
+        .concat();
+        for point in &points {
+            assistant.process_telemetry(point);
+        }
+        assistant.toggle_confirmation(FindingType::CornerEntryUndersteer);
+        assistant.toggle_confirmation(FindingType::CornerEntryOversteer);
+
+        let groups = assistant.get_recommendations_by_corner_type();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].corner_type, Some(CornerType::Slow));
+        assert_eq!(groups[0].findings.len(), 1);
+        assert_eq!(
+            groups[0].findings[0].finding_type,
+            FindingType::CornerEntryUndersteer
+        );
+        assert_eq!(groups[0].findings[0].occurrence_count, 10);
+        assert!(!groups[0].recommendations.is_empty());
+        assert_eq!(groups[1].corner_type, Some(CornerType::Fast));
+        assert_eq!(
+            groups[1].findings[0].finding_type,
+            FindingType::CornerEntryOversteer
+        );
+        assert!(!groups[1].recommendations.is_empty());
+    }
+
+    #[test]
+    fn test_unclassified_findings_get_their_own_group() {
+        use crate::telemetry::TelemetryAnnotation;
+
+        let mut assistant = SetupAssistant::new();
+        let scrub = TelemetryAnnotation::Scrub {
+            avg_yaw_rate_change: 0.1,
+            cur_yaw_rate_change: 0.3,
+            is_scrubbing: true,
+        };
+        for point in &corner_with(0, 15., scrub) {
+            assistant.process_telemetry(point);
+        }
+        // bottoming on the straight is never attributed to a corner
+        let bottoming = Finding {
+            finding_type: FindingType::BottomingOut,
+            occurrence_count: 4,
+            corner_phase: CornerPhase::Straight,
+            last_detected: 0,
+            severity: 0.5,
+            occurrence_timestamps: VecDeque::new(),
+            occurrence_lap_distances: VecDeque::new(),
+            occurrence_causes: VecDeque::new(),
+            cause_tally: CauseTally::default(),
+            corner_type_tally: CornerTypeTally::default(),
+        };
+        let mut findings = assistant.get_findings().clone();
+        findings.insert(bottoming.finding_type.clone(), bottoming);
+        assistant.restore_findings(findings);
+        assistant.toggle_confirmation(FindingType::CornerEntryUndersteer);
+        assistant.toggle_confirmation(FindingType::BottomingOut);
+
+        let groups = assistant.get_recommendations_by_corner_type();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].corner_type, Some(CornerType::Slow));
+        assert_eq!(groups[1].corner_type, None);
+        assert_eq!(groups[1].findings.len(), 1);
+        assert_eq!(
+            groups[1].findings[0].finding_type,
+            FindingType::BottomingOut
+        );
+        assert_eq!(groups[1].findings[0].occurrence_count, 4);
+        assert!(!groups[1].recommendations.is_empty());
+    }
+
+    #[test]
+    fn test_recommendations_single_group_without_corner_types() {
+        let mut assistant = SetupAssistant::new();
+        assert!(assistant.get_recommendations_by_corner_type().is_empty());
+
+        // findings saved before corner types were tracked
+        let finding = Finding {
+            finding_type: FindingType::CornerEntryUndersteer,
+            occurrence_count: 5,
+            corner_phase: CornerPhase::Entry,
+            last_detected: 0,
+            severity: 0.5,
+            occurrence_timestamps: VecDeque::new(),
+            occurrence_lap_distances: VecDeque::new(),
+            occurrence_causes: VecDeque::new(),
+            cause_tally: CauseTally::default(),
+            corner_type_tally: CornerTypeTally::default(),
+        };
+        assistant.restore_findings(HashMap::from([(finding.finding_type.clone(), finding)]));
+        assistant.toggle_confirmation(FindingType::CornerEntryUndersteer);
+
+        let groups = assistant.get_recommendations_by_corner_type();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].corner_type, None);
+        assert_eq!(groups[0].findings[0].occurrence_count, 5);
+        assert_eq!(
+            groups[0].recommendations.len(),
+            assistant.get_processed_recommendations().len()
+        );
+    }
+
+    #[test]
+    fn test_classify_corner_phase_entry() {
+        use crate::telemetry::TelemetryData;
+
+        let telemetry = TelemetryData {
+            brake: Some(0.8),
+            throttle: Some(0.0),
+            steering_pct: Some(0.3),
+            ..Default::default()
+        };
+
+        let phase = classify_corner_phase(&telemetry);
+        assert_eq!(phase, CornerPhase::Entry);
+    }
+
+    #[test]
+    fn test_classify_corner_phase_exit() {
+        use crate::telemetry::TelemetryData;
+
+        let telemetry = TelemetryData {
+            brake: Some(0.0),
+            throttle: Some(0.7),
+            steering_pct: Some(0.3),
+            ..Default::default()
+        };
+
+        let phase = classify_corner_phase(&telemetry);
+        assert_eq!(phase, CornerPhase::Exit);
+    }
+
+    #[test]
+    fn test_classify_corner_phase_mid() {
+        use crate::telemetry::TelemetryData;
+
+        let telemetry = TelemetryData {
+            brake: Some(0.0),
+            throttle: Some(0.0),
+            steering_pct: Some(0.3),
+            ..Default::default()
+        };
+
+        let phase = classify_corner_phase(&telemetry);
+        assert_eq!(phase, CornerPhase::Mid);
+    }
+
+    #[test]
+    fn test_classify_corner_phase_straight() {
+        use crate::telemetry::TelemetryData;
+
+        let telemetry = TelemetryData {
+            brake: Some(0.0),
+            throttle: Some(0.8),
+            steering_pct: Some(0.01),
+            ..Default::default()
+        };
+
+        let phase = classify_corner_phase(&telemetry);
+        assert_eq!(phase, CornerPhase::Straight);
+    }
+
+    #[test]
+    fn test_slip_classification_during_braking() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let telemetry = TelemetryData {
             brake: Some(0.8),
             throttle: Some(0.0),
             ..Default::default()
@@ -806,12 +1884,298 @@ mod tests {
             prev_speed: 50.0,
             cur_speed: 48.0,
             is_slip: true,
+            slip_ratio: None,
         };
 
-        let finding_type = SetupAssistant::annotation_to_finding_type(&annotation, &telemetry);
+        let finding_type = SetupAssistant::annotation_to_finding_type(
+            &annotation,
+            &telemetry,
+            classify_corner_phase(&telemetry),
+        );
         assert_eq!(finding_type, Some(FindingType::CornerEntryUndersteer));
     }
 
+    #[test]
+    fn test_camber_imbalance_classification() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let telemetry = TelemetryData::default();
+
+        // Front outer edges hot, rears within the ideal range
+        let outer_hot_front = TelemetryAnnotation::CamberImbalance {
+            lf_spread: -10.0,
+            rf_spread: -8.0,
+            lr_spread: 5.0,
+            rr_spread: 6.0,
+            is_imbalanced: true,
+        };
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &outer_hot_front,
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::FrontCamberTooPositive)
+        );
+
+        // Rear inner edges much hotter than the outer edges
+        let inner_hot_rear = TelemetryAnnotation::CamberImbalance {
+            lf_spread: 5.0,
+            rf_spread: 6.0,
+            lr_spread: 20.0,
+            rr_spread: 18.0,
+            is_imbalanced: true,
+        };
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &inner_hot_rear,
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::RearCamberTooNegative)
+        );
+    }
+
+    #[test]
+    fn test_tire_pressure_imbalance_classification() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let telemetry = TelemetryData::default();
+
+        // Middle of the front left tread much hotter than its edges
+        let middle_hot_front = TelemetryAnnotation::TirePressureImbalance {
+            lf_spread: 8.0,
+            rf_spread: 2.0,
+            lr_spread: 1.0,
+            rr_spread: -4.0,
+            is_imbalanced: true,
+        };
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &middle_hot_front,
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::FrontTirePressureTooHigh)
+        );
+
+        // Rear edges hotter than the middle of the tread
+        let edges_hot_rear = TelemetryAnnotation::TirePressureImbalance {
+            lf_spread: 0.0,
+            rf_spread: 1.0,
+            lr_spread: -7.0,
+            rr_spread: -6.0,
+            is_imbalanced: true,
+        };
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &edges_hot_rear,
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::RearTirePressureTooLow)
+        );
+    }
+
+    #[test]
+    fn test_stomp_brake_lock_classification() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let telemetry = TelemetryData::default();
+
+        let stomp_lock = TelemetryAnnotation::FrontBrakeLock {
+            abs_activation_count: 1,
+            is_front_lock: true,
+            peak_application_rate: 30.0,
+            is_initial_application_lock: true,
+        };
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &stomp_lock,
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::BrakeStompLock)
+        );
+
+        let sustained_lock = TelemetryAnnotation::FrontBrakeLock {
+            abs_activation_count: 1,
+            is_front_lock: true,
+            peak_application_rate: 3.0,
+            is_initial_application_lock: false,
+        };
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &sustained_lock,
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::FrontBrakeLock)
+        );
+    }
+
+    #[test]
+    fn test_electronics_intervention_classification() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let telemetry = TelemetryData::default();
+        let intervention =
+            |is_excessive_abs, is_excessive_tc| TelemetryAnnotation::ElectronicsIntervention {
+                corner_abs_activations: 3,
+                corner_tc_activations: 3,
+                lap_abs_activations: 5,
+                lap_tc_activations: 5,
+                is_excessive_abs,
+                is_excessive_tc,
+            };
+
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &intervention(true, true),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::FrequentAbsIntervention)
+        );
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &intervention(false, true),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::FrequentTcIntervention)
+        );
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &intervention(false, false),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_load_transfer_points_damper_recommendations() {
+        use crate::telemetry::{LoadTransferAxis, TelemetryAnnotation, TelemetryData};
+
+        let transfer = |is_slow, is_abrupt| TelemetryAnnotation::LoadTransfer {
+            axis: LoadTransferAxis::Lateral,
+            transfer_time_ms: if is_slow { 900 } else { 350 },
+            peak_g: 1.5,
+            peak_rate_g_per_s: 3.0,
+            is_slow,
+            is_abrupt,
+        };
+        let telemetry = TelemetryData::default();
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &transfer(true, false),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            None
+        );
+
+        let mut assistant = SetupAssistant::new();
+        for (timestamp_ms, is_slow) in [(100, true), (200, true), (300, false)] {
+            assistant.process_telemetry(&TelemetryData {
+                timestamp_ms,
+                annotations: vec![transfer(is_slow, false)],
+                ..Default::default()
+            });
+        }
+        assert!(assistant.get_findings().is_empty());
+
+        // a lazy car moves the damper stiffening of entry understeer up
+        assistant.process_telemetry(&TelemetryData {
+            timestamp_ms: 400,
+            brake: Some(0.5),
+            steering_pct: Some(0.3),
+            annotations: vec![TelemetryAnnotation::Scrub {
+                avg_yaw_rate_change: 0.1,
+                cur_yaw_rate_change: 0.3,
+                is_scrubbing: true,
+            }],
+            ..Default::default()
+        });
+        assistant.toggle_confirmation(FindingType::CornerEntryUndersteer);
+        let priority = |parameter: &str| {
+            assistant
+                .get_processed_recommendations()
+                .iter()
+                .find(|p| p.recommendation.parameter == parameter)
+                .map(|p| p.recommendation.priority)
+                .unwrap()
+        };
+        assert_eq!(priority("Rear Rebound"), 4);
+        assert_eq!(priority("Front Bump"), 1);
+
+        assistant.clear_session();
+        assert_eq!(assistant.load_transfer, LoadTransferTally::default());
+    }
+
+    #[test]
+    fn test_downshift_instability_classification() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let telemetry = TelemetryData::default();
+        let downshift = |is_unstable| TelemetryAnnotation::DownshiftInstability {
+            from_gear: 4,
+            to_gear: 3,
+            rpm_spike: 1500.,
+            peak_yaw_excess_rps: if is_unstable { 0.4 } else { 0. },
+            is_unstable,
+        };
+
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &downshift(true),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::DownshiftInstability)
+        );
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &downshift(false),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_straight_line_instability_classification() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let telemetry = TelemetryData::default();
+        let instability = |is_unstable| TelemetryAnnotation::StraightLineInstability {
+            steering_std_dev: 0.015,
+            corrections: 12,
+            min_speed_mps: 60.0,
+            is_unstable,
+        };
+
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &instability(true),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::StraightLineInstability)
+        );
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &instability(false),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            None
+        );
+    }
+
     #[test]
     fn test_slip_classification_during_throttle() {
         use crate::telemetry::{TelemetryAnnotation, TelemetryData};
@@ -826,12 +2190,240 @@ mod tests {
             prev_speed: 50.0,
             cur_speed: 48.0,
             is_slip: true,
+            slip_ratio: None,
         };
 
-        let finding_type = SetupAssistant::annotation_to_finding_type(&annotation, &telemetry);
+        let finding_type = SetupAssistant::annotation_to_finding_type(
+            &annotation,
+            &telemetry,
+            classify_corner_phase(&telemetry),
+        );
         assert_eq!(finding_type, Some(FindingType::CornerExitUndersteer));
     }
 
+    #[test]
+    fn test_exit_throttle_lift_maps_to_exit_understeer() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let telemetry = TelemetryData {
+            throttle: Some(0.5),
+            steering_pct: Some(0.3),
+            ..Default::default()
+        };
+        let lift = |is_understeer_lift| TelemetryAnnotation::ExitThrottleLift {
+            dip_depth: 0.3,
+            dip_count: 1,
+            is_understeer_lift,
+            cause: ExitUndersteerCause::Unknown,
+        };
+
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &lift(true),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::CornerExitUndersteer)
+        );
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &lift(false),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_throttle_stab_classification() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let telemetry = TelemetryData {
+            throttle: Some(0.5),
+            steering_pct: Some(0.3),
+            ..Default::default()
+        };
+        let stab = |is_stabbing| TelemetryAnnotation::ThrottleStab {
+            reversals: 3,
+            is_stabbing,
+        };
+
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &stab(true),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::ThrottleStabbing)
+        );
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &stab(false),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_shift_point_classification() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let telemetry = TelemetryData {
+            gear: Some(3),
+            throttle: Some(1.0),
+            ..Default::default()
+        };
+        let shift = |is_over_revving, is_under_revving| TelemetryAnnotation::ShiftPoint {
+            from_gear: 2,
+            shift_rpm: 7000.0,
+            optimal_rpm: 6500.0,
+            is_power_curve_optimal: true,
+            is_over_revving,
+            is_under_revving,
+        };
+
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &shift(true, false),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::UpshiftOverRevving)
+        );
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &shift(false, true),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            Some(FindingType::UpshiftUnderRevving)
+        );
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &shift(false, false),
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_exit_understeer_cause_prioritizes_recommendations() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let mut assistant = SetupAssistant::new();
+        for (timestamp_ms, cause) in [
+            (100, ExitUndersteerCause::Aero),
+            (200, ExitUndersteerCause::Differential),
+            (300, ExitUndersteerCause::Aero),
+            (400, ExitUndersteerCause::Unknown),
+        ] {
+            assistant.process_telemetry(&TelemetryData {
+                timestamp_ms,
+                throttle: Some(0.5),
+                steering_pct: Some(0.3),
+                annotations: vec![TelemetryAnnotation::ExitThrottleLift {
+                    dip_depth: 0.3,
+                    dip_count: 1,
+                    is_understeer_lift: true,
+                    cause,
+                }],
+                ..Default::default()
+            });
+        }
+
+        let finding = &assistant.get_findings()[&FindingType::CornerExitUndersteer];
+        assert_eq!(finding.occurrence_count, 4);
+        assert_eq!(
+            finding.cause_tally,
+            CauseTally {
+                differential: 1,
+                aero: 2
+            }
+        );
+        assert_eq!(finding.likely_cause(), ExitUndersteerCause::Aero);
+
+        assistant.toggle_confirmation(FindingType::CornerExitUndersteer);
+        let processed = assistant.get_processed_recommendations();
+        assert_eq!(processed[0].recommendation.parameter, "Front Wing");
+    }
+
+    #[test]
+    fn test_pedal_overlap_is_not_a_finding() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let telemetry = TelemetryData {
+            throttle: Some(0.4),
+            brake: Some(0.9),
+            steering_pct: Some(0.1),
+            ..Default::default()
+        };
+        let overlap = TelemetryAnnotation::PedalOverlap {
+            duration_ms: 300,
+            max_throttle: 0.4,
+            max_brake: 0.9,
+            is_sustained_overlap: true,
+        };
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &overlap,
+                &telemetry,
+                classify_corner_phase(&telemetry)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_steering_reversals_are_not_a_finding() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let busy = TelemetryAnnotation::SteeringReversals {
+            reversals: 15,
+            reversals_per_s: 3.0,
+            is_busy: true,
+        };
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &busy,
+                &TelemetryData::default(),
+                classify_corner_phase(&TelemetryData::default())
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_transition_instability_is_not_entry_understeer() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let braking = TelemetryData {
+            brake: Some(0.6),
+            steering_pct: Some(-0.3),
+            ..Default::default()
+        };
+        let transition = TelemetryAnnotation::TransitionInstability {
+            avg_yaw_rate_change: 0.05,
+            cur_yaw_rate_change: 0.35,
+            brake: 0.6,
+            since_direction_change_ms: 200,
+            is_unstable: true,
+            slip_ratio: None,
+        };
+        assert_eq!(
+            SetupAssistant::annotation_to_finding_type(
+                &transition,
+                &braking,
+                classify_corner_phase(&braking)
+            ),
+            None
+        );
+    }
+
     #[test]
     fn test_slip_classification_during_coasting() {
         use crate::telemetry::{TelemetryAnnotation, TelemetryData};
@@ -846,9 +2438,14 @@ mod tests {
             prev_speed: 50.0,
             cur_speed: 48.0,
             is_slip: true,
+            slip_ratio: None,
         };
 
-        let finding_type = SetupAssistant::annotation_to_finding_type(&annotation, &telemetry);
+        let finding_type = SetupAssistant::annotation_to_finding_type(
+            &annotation,
+            &telemetry,
+            classify_corner_phase(&telemetry),
+        );
         assert_eq!(finding_type, Some(FindingType::MidCornerUndersteer));
     }
 
@@ -870,10 +2467,14 @@ mod tests {
             cur_gear: 2,
             cur_rpm_increase: 500.0,
             is_wheelspin: true,
+            slip_ratio: None,
         };
 
-        let finding_type =
-            SetupAssistant::annotation_to_finding_type(&annotation, &telemetry_corner_exit);
+        let finding_type = SetupAssistant::annotation_to_finding_type(
+            &annotation,
+            &telemetry_corner_exit,
+            classify_corner_phase(&telemetry_corner_exit),
+        );
         assert_eq!(finding_type, Some(FindingType::CornerExitPowerOversteer));
 
         // Test case 2: Wheelspin on straight (no steering) should NOT map to power oversteer
@@ -884,8 +2485,11 @@ mod tests {
             ..TelemetryData::default()
         };
 
-        let finding_type_straight =
-            SetupAssistant::annotation_to_finding_type(&annotation, &telemetry_straight);
+        let finding_type_straight = SetupAssistant::annotation_to_finding_type(
+            &annotation,
+            &telemetry_straight,
+            classify_corner_phase(&telemetry_straight),
+        );
         assert_eq!(finding_type_straight, None); // Should not be classified as oversteer
 
         // Test case 3: Wheelspin during braking (entry phase) should NOT map to power oversteer
@@ -896,8 +2500,11 @@ mod tests {
             ..TelemetryData::default()
         };
 
-        let finding_type_entry =
-            SetupAssistant::annotation_to_finding_type(&annotation, &telemetry_entry);
+        let finding_type_entry = SetupAssistant::annotation_to_finding_type(
+            &annotation,
+            &telemetry_entry,
+            classify_corner_phase(&telemetry_entry),
+        );
         assert_eq!(finding_type_entry, None); // Should not be classified as power oversteer
     }
 
@@ -964,6 +2571,7 @@ mod tests {
                     cur_gear: 2,
                     cur_rpm_increase: 500.0,
                     is_wheelspin: true,
+                    slip_ratio: None,
                 },
             ],
             ..Default::default()
@@ -1008,6 +2616,98 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_export_report_only_includes_confirmed_findings() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let mut assistant = SetupAssistant::new();
+        let telemetry = TelemetryData {
+            annotations: vec![
+                TelemetryAnnotation::Scrub {
+                    avg_yaw_rate_change: 0.5,
+                    cur_yaw_rate_change: 0.8,
+                    is_scrubbing: true,
+                },
+                TelemetryAnnotation::BottomingOut {
+                    pitch_change: 0.15,
+                    speed_loss: 3.0,
+                    is_bottoming: true,
+                },
+            ],
+            ..Default::default()
+        };
+        for _ in 0..3 {
+            assistant.process_telemetry(&telemetry);
+        }
+        assistant.toggle_confirmation(FindingType::CornerEntryUndersteer);
+
+        let report = assistant.export_report();
+        assert!(report.contains("Corner Entry Understeer"));
+        assert!(report.contains("seen 3 times"));
+        assert!(!report.contains("Bottoming Out"));
+        assert!(report.contains("Front Antirollbar"));
+    }
+
+    #[test]
+    fn test_actionable_findings_apply_occurrence_threshold() {
+        use crate::telemetry::{TelemetryAnnotation, TelemetryData};
+
+        let mut assistant = SetupAssistant::new();
+        let telemetry = TelemetryData {
+            annotations: vec![TelemetryAnnotation::Scrub {
+                avg_yaw_rate_change: 0.5,
+                cur_yaw_rate_change: 0.8,
+                is_scrubbing: true,
+            }],
+            ..Default::default()
+        };
+
+        for _ in 1..DEFAULT_MIN_OCCURRENCES {
+            assistant.process_telemetry(&telemetry);
+        }
+        assert_eq!(assistant.get_findings().len(), 1);
+        assert!(assistant.get_actionable_findings().is_empty());
+
+        assistant.process_telemetry(&telemetry);
+        let actionable = assistant.get_actionable_findings();
+        assert_eq!(actionable.len(), 1);
+        assert_eq!(
+            actionable[0].finding_type,
+            FindingType::CornerEntryUndersteer
+        );
+    }
+
+    #[test]
+    fn test_actionable_findings_apply_severity_threshold() {
+        let mut assistant = SetupAssistant::with_thresholds(1, 0.6);
+        let mut findings = HashMap::new();
+        for (finding_type, severity) in [
+            (FindingType::BottomingOut, 0.5),
+            (FindingType::TireCold, 0.8),
+        ] {
+            findings.insert(
+                finding_type.clone(),
+                Finding {
+                    finding_type,
+                    occurrence_count: 1,
+                    corner_phase: CornerPhase::Unknown,
+                    last_detected: 0,
+                    severity,
+                    occurrence_timestamps: VecDeque::new(),
+                    occurrence_lap_distances: VecDeque::new(),
+                    occurrence_causes: VecDeque::new(),
+                    cause_tally: CauseTally::default(),
+                    corner_type_tally: CornerTypeTally::default(),
+                },
+            );
+        }
+        assistant.restore_findings(findings);
+
+        let actionable = assistant.get_actionable_findings();
+        assert_eq!(actionable.len(), 1);
+        assert_eq!(actionable[0].finding_type, FindingType::TireCold);
+    }
+
     #[test]
     fn test_persistence_methods() {
         use crate::telemetry::{TelemetryAnnotation, TelemetryData};
@@ -1048,6 +2748,42 @@ mod tests {
         assert!(!new_assistant.get_recommendations().is_empty());
     }
 
+    #[test]
+    fn test_tried_recommendations_outlive_the_session() {
+        let mut assistant = SetupAssistant::new();
+        assistant.toggle_confirmation(FindingType::CornerEntryUndersteer);
+        let rec = assistant.get_processed_recommendations()[0]
+            .recommendation
+            .clone();
+        assert!(!assistant.is_tried(&rec));
+
+        assistant.set_tried(&rec, true);
+        assert!(assistant.is_tried(&rec));
+        let tried = assistant
+            .get_tried_recommendations_for_persistence()
+            .clone();
+        assert!(tried.contains(&TriedRecommendation {
+            finding_type: FindingType::CornerEntryUndersteer,
+            parameter: rec.parameter.clone(),
+        }));
+
+        // the issue comes back in a later session
+        let mut new_assistant = SetupAssistant::new();
+        new_assistant.restore_tried_recommendations(tried);
+        new_assistant.clear_session();
+        assert!(!new_assistant.is_tried(&rec));
+        new_assistant.toggle_confirmation(FindingType::CornerEntryUndersteer);
+        assert!(new_assistant.is_tried(&rec));
+
+        new_assistant.set_tried(&rec, false);
+        assert!(!new_assistant.is_tried(&rec));
+        assert!(
+            new_assistant
+                .get_tried_recommendations_for_persistence()
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_persistence_preserves_occurrence_count() {
         use crate::telemetry::{TelemetryAnnotation, TelemetryData};
@@ -1109,6 +2845,7 @@ mod tests {
                     cur_gear: 2,
                     cur_rpm_increase: 500.0,
                     is_wheelspin: true,
+                    slip_ratio: None,
                 },
             ],
             ..Default::default()
@@ -1162,6 +2899,7 @@ mod tests {
 mod proptests {
     use super::*;
     use crate::telemetry::{GameSource, TelemetryAnnotation, TelemetryData};
+    use corner_phase::classify_corner_phase;
     use proptest::prelude::*;
     use std::collections::HashMap;
 
@@ -1181,6 +2919,7 @@ mod proptests {
                     prev_speed,
                     cur_speed,
                     is_slip,
+                    slip_ratio: None,
                 }
             ),
             // Wheelspin
@@ -1190,6 +2929,7 @@ mod proptests {
                     cur_gear,
                     cur_rpm_increase,
                     is_wheelspin,
+                    slip_ratio: None,
                 }
             ),
             // EntryOversteer
@@ -1198,6 +2938,7 @@ mod proptests {
                     expected_yaw_rate: expected_yaw,
                     actual_yaw_rate: actual_yaw,
                     is_oversteer,
+                    is_recovered: false,
                 }
             ),
             // MidCornerUndersteer
@@ -1212,15 +2953,20 @@ mod proptests {
                 TelemetryAnnotation::MidCornerOversteer {
                     yaw_rate_excess,
                     is_oversteer,
+                    is_recovered: false,
                 }
             }),
             // FrontBrakeLock
-            (any::<usize>(), any::<bool>()).prop_map(|(abs_count, is_front_lock)| {
-                TelemetryAnnotation::FrontBrakeLock {
-                    abs_activation_count: abs_count,
-                    is_front_lock,
+            (any::<usize>(), any::<bool>(), any::<f32>(), any::<bool>()).prop_map(
+                |(abs_count, is_front_lock, peak_application_rate, is_initial_application_lock)| {
+                    TelemetryAnnotation::FrontBrakeLock {
+                        abs_activation_count: abs_count,
+                        is_front_lock,
+                        peak_application_rate,
+                        is_initial_application_lock,
+                    }
                 }
-            }),
+            ),
             // RearBrakeLock
             (any::<usize>(), any::<bool>()).prop_map(|(abs_count, is_rear_lock)| {
                 TelemetryAnnotation::RearBrakeLock {
@@ -1297,9 +3043,11 @@ mod proptests {
                 telemetry.annotations = vec![annotation.clone()];
                 assistant.process_telemetry(&telemetry);
             }
+            // oversteer is only counted once the slide ends
+            assistant.process_telemetry(&base_telemetry);
 
             // Get the finding type that should have been created
-            let finding_type_opt = SetupAssistant::annotation_to_finding_type(&annotation, &base_telemetry);
+            let finding_type_opt = SetupAssistant::annotation_to_finding_type(&annotation, &base_telemetry, classify_corner_phase(&base_telemetry));
 
             if let Some(finding_type) = finding_type_opt {
                 // Should have exactly one finding of this type
@@ -1334,7 +3082,7 @@ mod proptests {
             steering_pct: Some(steering_pct),
             .. Default::default() };
 
-            let phase = SetupAssistant::classify_corner_phase(&telemetry);
+            let phase = classify_corner_phase(&telemetry);
 
             const MIN_BRAKE_THRESHOLD: f32 = 0.1;
             const MIN_THROTTLE_THRESHOLD: f32 = 0.1;
@@ -1402,9 +3150,10 @@ mod proptests {
                 prev_speed,
                 cur_speed,
                 is_slip: true,
+                slip_ratio: None,
             };
 
-            let finding_type = SetupAssistant::annotation_to_finding_type(&annotation, &telemetry);
+            let finding_type = SetupAssistant::annotation_to_finding_type(&annotation, &telemetry, classify_corner_phase(&telemetry));
 
             const MIN_BRAKE_THRESHOLD: f32 = 0.1;
             const MIN_THROTTLE_THRESHOLD: f32 = 0.2; // Updated to match the new threshold
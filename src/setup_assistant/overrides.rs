@@ -0,0 +1,72 @@
+use std::{collections::HashMap, path::Path};
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use super::{FindingType, SetupRecommendation};
+
+const RECOMMENDATION_OVERRIDES_FILE_NAME: &str = "recommendation_overrides.json";
+
+/// Car-specific recommendations for a single finding type.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecommendationOverride {
+    /// Replace the built-in recommendations instead of adding to them
+    #[serde(default)]
+    pub replace: bool,
+    /// Recommendations to add, an entry for a parameter that already has a built-in
+    /// recommendation replaces it
+    pub recommendations: Vec<SetupRecommendation>,
+}
+
+/// Setup recommendations tuned for specific cars, keyed by the car name reported in
+/// `SessionInfo::car_name`.
+///
+/// Loaded from `recommendation_overrides.json` in the Ocypode config directory:
+///
+/// ```json
+/// {
+///   "Porsche 911 GT3 R": {
+///     "CornerExitPowerOversteer": {
+///       "replace": false,
+///       "recommendations": [{
+///         "category": "Drivetrain",
+///         "parameter": "Differential Preload",
+///         "adjustment": "Reduce",
+///         "description": "The rear-engined car is sensitive to preload on exit",
+///         "priority": 5
+///       }]
+///     }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CarRecommendationOverrides {
+    cars: HashMap<String, HashMap<FindingType, RecommendationOverride>>,
+}
+
+impl CarRecommendationOverrides {
+    /// Load the overrides file from the config directory, if there is one.
+    pub fn from_local_file() -> Option<Self> {
+        let path = dirs::config_dir()?
+            .join("ocypode")
+            .join(RECOMMENDATION_OVERRIDES_FILE_NAME);
+        Self::from_path(&path)
+    }
+
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let file = std::fs::File::open(path).ok()?;
+        serde_json::from_reader(file)
+            .inspect_err(|e| warn!("Could not parse recommendation overrides file: {}", e))
+            .ok()
+    }
+
+    /// Overrides for a car, car names are matched ignoring case and surrounding spaces.
+    pub fn for_car(&self, car_name: &str) -> Option<&HashMap<FindingType, RecommendationOverride>> {
+        let car_name = car_name.trim();
+        self.cars
+            .iter()
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case(car_name))
+            .map(|(_, overrides)| overrides)
+    }
+}
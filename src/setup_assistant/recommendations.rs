@@ -1,6 +1,14 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use super::FindingType;
+use super::{Finding, FindingType, overrides::CarRecommendationOverrides};
+use crate::telemetry::{ExitUndersteerCause, LoadTransferTiming};
+
+/// Occurrences after which a finding fully backs its recommendations
+pub const CONFIDENT_OCCURRENCES: usize = 10;
+/// Priority moved towards the category matching the likely cause of exit understeer,
+/// and away from the category of the other cause. Also moves the slow-speed damper
+/// changes by the timing of the load transfers.
+const CAUSE_PRIORITY_SHIFT: u8 = 2;
 
 /// Categories of car setup parameters that can be adjusted.
 ///
@@ -10,7 +18,7 @@ use super::FindingType;
 /// # Requirements
 ///
 /// Supports Requirement 4.4: Organize recommendations by setup category
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum SetupCategory {
     /// Aerodynamic adjustments (wings, ride height, splitter)
     Aerodynamics,
@@ -32,6 +40,21 @@ pub enum SetupCategory {
     TireManagement,
 }
 
+impl SetupCategory {
+    /// All setup categories, in the order they are presented to the user.
+    pub const ALL: [SetupCategory; 9] = [
+        SetupCategory::Aerodynamics,
+        SetupCategory::Suspension,
+        SetupCategory::AntiRollBar,
+        SetupCategory::Dampers,
+        SetupCategory::Brakes,
+        SetupCategory::Drivetrain,
+        SetupCategory::Electronics,
+        SetupCategory::Alignment,
+        SetupCategory::TireManagement,
+    ];
+}
+
 impl std::fmt::Display for SetupCategory {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -58,7 +81,7 @@ impl std::fmt::Display for SetupCategory {
 /// Supports Requirements 4.2, 4.3:
 /// - Shows parameter name, adjustment direction, and description
 /// - Contains all required fields for display
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SetupRecommendation {
     /// The category this recommendation belongs to
     pub category: SetupCategory,
@@ -70,10 +93,67 @@ pub struct SetupRecommendation {
     pub description: String,
     /// Priority level (1-5, where 5 is highest priority)
     pub priority: u8,
+    /// Ease of adjustment (1-5), 5 is a garage change of a few seconds such as brake
+    /// bias or an antirollbar click, 1 needs a rebuild such as springs or ride height.
+    /// 0 when not given, e.g. by an older car override
+    #[serde(default)]
+    pub ease: u8,
+    /// Why the adjustment works, the physics behind it. `None` for most
+    /// recommendations and for car overrides that do not give one
+    #[serde(default)]
+    pub rationale: Option<String>,
+}
+
+/// Order of the recommendations in the setup window
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum RecommendationOrder {
+    /// Highest priority first
+    #[default]
+    Impact,
+    /// Quickest change first, for a short practice session
+    Ease,
+    /// Highest sum of priority and ease first
+    Blend,
+}
+
+impl RecommendationOrder {
+    pub const ALL: [RecommendationOrder; 3] = [
+        RecommendationOrder::Impact,
+        RecommendationOrder::Ease,
+        RecommendationOrder::Blend,
+    ];
+
+    /// Sort `processed` in this order. Ties keep the priority order, then the parameter
+    /// name, so changes of the same ease still come most impactful first.
+    pub fn sort(self, processed: &mut [ProcessedRecommendation]) {
+        let key = |rec: &SetupRecommendation| match self {
+            RecommendationOrder::Impact => u16::from(rec.priority),
+            RecommendationOrder::Ease => u16::from(rec.ease),
+            // car overrides are not limited to 1 to 5, widen so the sum cannot overflow
+            RecommendationOrder::Blend => u16::from(rec.priority) + u16::from(rec.ease),
+        };
+        processed.sort_by(|a, b| {
+            let (a, b) = (&a.recommendation, &b.recommendation);
+            key(b)
+                .cmp(&key(a))
+                .then_with(|| b.priority.cmp(&a.priority))
+                .then_with(|| a.parameter.cmp(&b.parameter))
+        });
+    }
+}
+
+impl std::fmt::Display for RecommendationOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecommendationOrder::Impact => write!(f, "Impact"),
+            RecommendationOrder::Ease => write!(f, "Ease"),
+            RecommendationOrder::Blend => write!(f, "Blend"),
+        }
+    }
 }
 
 /// A processed recommendation with conflict information.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ProcessedRecommendation {
     /// The original recommendation
     pub recommendation: SetupRecommendation,
@@ -81,6 +161,17 @@ pub struct ProcessedRecommendation {
     pub conflicts: Vec<SetupRecommendation>,
     /// Whether this recommendation conflicts with others
     pub has_conflict: bool,
+    /// How strongly the findings back this recommendation, from 0.0 to 1.0. Set by
+    /// [`RecommendationEngine::score_confidence`], 0.0 until then.
+    pub confidence: f32,
+}
+
+/// A recommendation the driver has already tried, keyed by the finding it was given for
+/// and the setup parameter it changes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct TriedRecommendation {
+    pub finding_type: FindingType,
+    pub parameter: String,
 }
 
 /// Engine that maps findings to setup recommendations.
@@ -112,6 +203,34 @@ impl RecommendationEngine {
         }
     }
 
+    /// Create a RecommendationEngine with the overrides for `car_name` applied on top
+    /// of the generic recommendation map.
+    ///
+    /// An override marked `replace` swaps out every recommendation of its finding
+    /// type. Otherwise its recommendations are added, replacing the built-in entry for
+    /// the same parameter. Cars without overrides get the generic map.
+    pub fn for_car(car_name: &str, overrides: &CarRecommendationOverrides) -> Self {
+        let mut engine = Self::new();
+        let Some(car_overrides) = overrides.for_car(car_name) else {
+            return engine;
+        };
+
+        for (finding_type, finding_override) in car_overrides {
+            let recs = engine
+                .recommendation_map
+                .entry(finding_type.clone())
+                .or_default();
+            if finding_override.replace {
+                recs.clear();
+            }
+            for rec in &finding_override.recommendations {
+                recs.retain(|r| r.parameter != rec.parameter);
+                recs.push(rec.clone());
+            }
+        }
+        engine
+    }
+
     /// Build the complete recommendation map from the ACC Setup Guide.
     ///
     /// This method creates the mapping from each finding type to its
@@ -140,6 +259,11 @@ impl RecommendationEngine {
                         "Softer front anti-roll bar allows more front grip during corner entry"
                             .to_string(),
                     priority: 5, // Highest impact, easy to adjust
+                    ease: 4,
+                    rationale: Some(
+                        "The anti-roll bar links the left and right suspension, so a stiff bar moves more of the weight transfer onto the outside tire as the car rolls. A tire gives less extra grip for every extra kilo of load, so the more unevenly an axle is loaded, the less grip it has in total. Softening the front bar spreads the load more evenly across the front tires while the car turns in, giving the front axle more grip compared to the rear."
+                            .to_string(),
+                    ),
                 },
                 SetupRecommendation {
                     category: SetupCategory::Brakes,
@@ -149,6 +273,8 @@ impl RecommendationEngine {
                         "Moving brake bias rearward reduces front tire load during braking"
                             .to_string(),
                     priority: 4, // High impact, easy to adjust
+                    ease: 5,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -157,6 +283,8 @@ impl RecommendationEngine {
                     description: "Softer front springs improve mechanical grip during turn-in"
                         .to_string(),
                     priority: 4, // High impact
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Aerodynamics,
@@ -165,6 +293,8 @@ impl RecommendationEngine {
                     description: "Lowering front ride height increases front downforce and grip"
                         .to_string(),
                     priority: 3, // Medium impact, affects other parameters
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -173,6 +303,8 @@ impl RecommendationEngine {
                     description: "Stiffer rear springs reduce rear grip, shifting balance forward"
                         .to_string(),
                     priority: 3, // Medium impact
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Aerodynamics,
@@ -182,6 +314,8 @@ impl RecommendationEngine {
                         "Raising rear ride height reduces rear downforce, shifting balance forward"
                             .to_string(),
                     priority: 3, // Medium impact
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Dampers,
@@ -190,6 +324,8 @@ impl RecommendationEngine {
                     description: "Softer front bump damping allows weight transfer to front tires"
                         .to_string(),
                     priority: 2, // Lower impact, more complex
+                    ease: 3,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Dampers,
@@ -198,6 +334,8 @@ impl RecommendationEngine {
                     description: "Stiffer rear rebound keeps weight on front tires longer"
                         .to_string(),
                     priority: 2, // Lower impact, more complex
+                    ease: 3,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Alignment,
@@ -205,6 +343,8 @@ impl RecommendationEngine {
                     adjustment: "Increase Toe Out".to_string(),
                     description: "Toe out improves turn-in response and front grip".to_string(),
                     priority: 2, // Lower priority, affects tire wear
+                    ease: 2,
+                    rationale: None,
                 },
             ],
         );
@@ -220,6 +360,11 @@ impl RecommendationEngine {
                     description: "Moving brake bias forward increases rear stability under braking"
                         .to_string(),
                     priority: 5, // Highest impact, easy to adjust
+                    ease: 5,
+                    rationale: Some(
+                        "Braking shifts weight onto the front axle and unloads the rear, which then has less grip to spare. With the bias too far back, the rear tires spend most of their grip stopping the car and have little left to hold it in the corner, so the rear steps out as you turn in. Moving the bias forward asks less of the rear tires under braking, leaving them lateral grip to keep the car stable."
+                            .to_string(),
+                    ),
                 },
                 SetupRecommendation {
                     category: SetupCategory::Drivetrain,
@@ -228,6 +373,8 @@ impl RecommendationEngine {
                     description: "Higher preload locks differential on coast, stabilizing rear"
                         .to_string(),
                     priority: 4, // High impact
+                    ease: 3,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -235,6 +382,8 @@ impl RecommendationEngine {
                     adjustment: "Soften".to_string(),
                     description: "Softer rear springs improve rear mechanical grip".to_string(),
                     priority: 4, // High impact
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::AntiRollBar,
@@ -242,6 +391,8 @@ impl RecommendationEngine {
                     adjustment: "Stiffen".to_string(),
                     description: "Stiffer front anti-roll bar reduces front grip".to_string(),
                     priority: 3, // Medium impact
+                    ease: 4,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Aerodynamics,
@@ -250,6 +401,8 @@ impl RecommendationEngine {
                     description: "Lowering rear ride height increases rear downforce and stability"
                         .to_string(),
                     priority: 3, // Medium impact
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -258,6 +411,8 @@ impl RecommendationEngine {
                     description: "Stiffer front springs reduce front grip during turn-in"
                         .to_string(),
                     priority: 3, // Medium impact
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Aerodynamics,
@@ -265,6 +420,8 @@ impl RecommendationEngine {
                     adjustment: "Increase".to_string(),
                     description: "Raising front ride height reduces front downforce".to_string(),
                     priority: 2, // Lower priority
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Dampers,
@@ -272,6 +429,8 @@ impl RecommendationEngine {
                     adjustment: "Stiffen".to_string(),
                     description: "Stiffer front bump reduces weight transfer to front".to_string(),
                     priority: 2, // Lower impact
+                    ease: 3,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Dampers,
@@ -279,6 +438,8 @@ impl RecommendationEngine {
                     adjustment: "Soften".to_string(),
                     description: "Softer rear rebound allows rear to settle faster".to_string(),
                     priority: 2, // Lower impact
+                    ease: 3,
+                    rationale: None,
                 },
             ],
         );
@@ -293,6 +454,11 @@ impl RecommendationEngine {
                     adjustment: "Move Forward".to_string(),
                     description: "Forward brake bias stabilizes the rear under braking".to_string(),
                     priority: 5,
+                    ease: 5,
+                    rationale: Some(
+                        "A car wanders under braking when the rear tires are close to their limit, because the lightly loaded rear axle cannot brake and keep the car straight at the same time. Moving the bias forward puts more of the braking on the front tires, which carry the weight transferred forward, and lets the rear tires stabilize the car instead."
+                            .to_string(),
+                    ),
                 },
                 SetupRecommendation {
                     category: SetupCategory::Drivetrain,
@@ -301,6 +467,8 @@ impl RecommendationEngine {
                     description: "Higher preload provides more predictable rear behavior"
                         .to_string(),
                     priority: 4,
+                    ease: 3,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -309,6 +477,8 @@ impl RecommendationEngine {
                     description: "Stiffer front springs reduce pitch and improve stability"
                         .to_string(),
                     priority: 4,
+                    ease: 1,
+                    rationale: None,
                 },
             ],
         );
@@ -324,6 +494,11 @@ impl RecommendationEngine {
                     description: "Softer front Antirollbar allows more front grip mid-corner"
                         .to_string(),
                     priority: 5,
+                    ease: 4,
+                    rationale: Some(
+                        "In steady-state cornering the total weight transfer is set by the car's speed, mass and center of gravity height, the anti-roll bars only decide how it is split between the axles. The axle with the stiffer roll stiffness takes more of the transfer and loses more grip. Softening the front bar moves some of the transfer to the rear, so the front tires keep more grip through the middle of the corner."
+                            .to_string(),
+                    ),
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -331,6 +506,8 @@ impl RecommendationEngine {
                     adjustment: "Soften".to_string(),
                     description: "Softer front springs improve mechanical grip".to_string(),
                     priority: 4,
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::AntiRollBar,
@@ -340,6 +517,8 @@ impl RecommendationEngine {
                         "Stiffer rear Antirollbar reduces rear grip, shifting balance forward"
                             .to_string(),
                     priority: 4,
+                    ease: 4,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Aerodynamics,
@@ -347,6 +526,8 @@ impl RecommendationEngine {
                     adjustment: "Increase".to_string(),
                     description: "More front wing increases front downforce at apex".to_string(),
                     priority: 3,
+                    ease: 4,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Aerodynamics,
@@ -354,6 +535,8 @@ impl RecommendationEngine {
                     adjustment: "Increase".to_string(),
                     description: "More splitter increases front downforce".to_string(),
                     priority: 3,
+                    ease: 3,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -361,6 +544,8 @@ impl RecommendationEngine {
                     adjustment: "Stiffen".to_string(),
                     description: "Stiffer rear springs reduce rear grip".to_string(),
                     priority: 3,
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Alignment,
@@ -370,6 +555,8 @@ impl RecommendationEngine {
                         "More negative camber improves front tire contact patch mid-corner"
                             .to_string(),
                     priority: 3,
+                    ease: 2,
+                    rationale: None,
                 },
             ],
         );
@@ -385,6 +572,11 @@ impl RecommendationEngine {
                     description: "Softer rear Antirollbar allows more rear grip mid-corner"
                         .to_string(),
                     priority: 5,
+                    ease: 4,
+                    rationale: Some(
+                        "The axle that resists roll the most takes the biggest share of the weight transfer, and the most unevenly loaded axle has the least grip. A stiff rear bar loads the outside rear tire heavily and lets the rear run out of grip first. Softening it shifts some of the weight transfer to the front, trading some front grip for a more planted rear in long corners."
+                            .to_string(),
+                    ),
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -392,6 +584,8 @@ impl RecommendationEngine {
                     adjustment: "Soften".to_string(),
                     description: "Softer rear springs improve rear mechanical grip".to_string(),
                     priority: 4,
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::AntiRollBar,
@@ -399,6 +593,8 @@ impl RecommendationEngine {
                     adjustment: "Stiffen".to_string(),
                     description: "Stiffer front Antirollbar reduces front grip".to_string(),
                     priority: 4,
+                    ease: 4,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Aerodynamics,
@@ -407,6 +603,8 @@ impl RecommendationEngine {
                     description: "More rear wing increases rear downforce and stability"
                         .to_string(),
                     priority: 3,
+                    ease: 4,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -414,6 +612,8 @@ impl RecommendationEngine {
                     adjustment: "Stiffen".to_string(),
                     description: "Stiffer front springs reduce front grip".to_string(),
                     priority: 3,
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Alignment,
@@ -422,6 +622,8 @@ impl RecommendationEngine {
                     description: "More negative camber improves rear tire contact patch"
                         .to_string(),
                     priority: 3,
+                    ease: 2,
+                    rationale: None,
                 },
             ],
         );
@@ -436,6 +638,11 @@ impl RecommendationEngine {
                     adjustment: "Increase".to_string(),
                     description: "Higher preload helps rotate the car on power".to_string(),
                     priority: 5,
+                    ease: 3,
+                    rationale: Some(
+                        "A limited slip differential locks the rear wheels together with a force set by the preload and the ramps. An open differential lets the inside wheel spin up as you get on the power, so the car does not push itself into the corner. More preload keeps both wheels driving, which pushes the rear around and helps the car rotate as you unwind the wheel. Too much makes the car reluctant to turn at low speed."
+                            .to_string(),
+                    ),
                 },
                 SetupRecommendation {
                     category: SetupCategory::Drivetrain,
@@ -443,6 +650,8 @@ impl RecommendationEngine {
                     adjustment: "Increase".to_string(),
                     description: "More locking helps transfer power and rotate the car".to_string(),
                     priority: 4,
+                    ease: 3,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -450,6 +659,17 @@ impl RecommendationEngine {
                     adjustment: "Soften".to_string(),
                     description: "Softer front springs improve front grip on exit".to_string(),
                     priority: 4,
+                    ease: 1,
+                    rationale: None,
+                },
+                SetupRecommendation {
+                    category: SetupCategory::Aerodynamics,
+                    parameter: "Front Wing".to_string(),
+                    adjustment: "Increase".to_string(),
+                    description: "More front wing holds the nose in on fast exits".to_string(),
+                    priority: 3,
+                    ease: 4,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -458,6 +678,8 @@ impl RecommendationEngine {
                     description: "Stiffer rear springs reduce rear grip, helping rotation"
                         .to_string(),
                     priority: 3,
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Dampers,
@@ -466,6 +688,8 @@ impl RecommendationEngine {
                     description: "Stiffer rear slow bump reduces rear squat on acceleration"
                         .to_string(),
                     priority: 2,
+                    ease: 3,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Dampers,
@@ -474,6 +698,8 @@ impl RecommendationEngine {
                     description: "Softer front slow rebound allows front to settle faster"
                         .to_string(),
                     priority: 2,
+                    ease: 3,
+                    rationale: None,
                 },
             ],
         );
@@ -488,6 +714,11 @@ impl RecommendationEngine {
                     adjustment: "Increase".to_string(),
                     description: "Higher TC cuts power to prevent wheelspin".to_string(),
                     priority: 5,
+                    ease: 5,
+                    rationale: Some(
+                        "A tire can only deliver so much force, shared between accelerating and cornering. On exit, throttle asks the rear tires for longitudinal force while they are still holding the car laterally, and once they slip too much they lose grip in both directions. Traction control cuts power when the rear wheels spin faster than the fronts, keeping the rear tires in the slip range where they still grip."
+                            .to_string(),
+                    ),
                 },
                 SetupRecommendation {
                     category: SetupCategory::Drivetrain,
@@ -496,6 +727,8 @@ impl RecommendationEngine {
                     description: "Lower preload allows more rear slip, reducing wheelspin"
                         .to_string(),
                     priority: 4,
+                    ease: 3,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Drivetrain,
@@ -505,6 +738,8 @@ impl RecommendationEngine {
                         "Less locking allows wheels to spin independently, improving traction"
                             .to_string(),
                     priority: 4,
+                    ease: 3,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -512,6 +747,8 @@ impl RecommendationEngine {
                     adjustment: "Soften".to_string(),
                     description: "Softer rear springs improve rear mechanical grip".to_string(),
                     priority: 4,
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Aerodynamics,
@@ -520,6 +757,8 @@ impl RecommendationEngine {
                     description: "More rear wing increases rear downforce at high speeds"
                         .to_string(),
                     priority: 3,
+                    ease: 4,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -528,6 +767,8 @@ impl RecommendationEngine {
                     description: "Stiffer front springs reduce front grip, stabilizing rear"
                         .to_string(),
                     priority: 3,
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Dampers,
@@ -535,6 +776,8 @@ impl RecommendationEngine {
                     adjustment: "Soften".to_string(),
                     description: "Softer rear slow bump allows rear to settle and grip".to_string(),
                     priority: 2,
+                    ease: 3,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Dampers,
@@ -543,6 +786,8 @@ impl RecommendationEngine {
                     description: "Stiffer front slow rebound keeps weight on rear tires"
                         .to_string(),
                     priority: 2,
+                    ease: 3,
+                    rationale: None,
                 },
             ],
         );
@@ -557,6 +802,11 @@ impl RecommendationEngine {
                     adjustment: "Soften".to_string(),
                     description: "Softer rear Antirollbar allows more rear compliance".to_string(),
                     priority: 5,
+                    ease: 4,
+                    rationale: Some(
+                        "A snap on exit happens when the rear grip drops suddenly rather than progressively. A stiff rear bar overloads the outside rear tire, and the inside rear is too light to take up the slack once the outside one lets go. Softening the rear bar shares the load more evenly between the rear tires, so they give up grip more gradually and the slide is easier to catch."
+                            .to_string(),
+                    ),
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -564,6 +814,8 @@ impl RecommendationEngine {
                     adjustment: "Soften".to_string(),
                     description: "Softer rear springs prevent sudden rear grip loss".to_string(),
                     priority: 4,
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Dampers,
@@ -571,6 +823,8 @@ impl RecommendationEngine {
                     adjustment: "Soften".to_string(),
                     description: "Softer rear fast bump prevents sudden compression".to_string(),
                     priority: 2,
+                    ease: 3,
+                    rationale: None,
                 },
             ],
         );
@@ -585,6 +839,11 @@ impl RecommendationEngine {
                     adjustment: "Move Rearward".to_string(),
                     description: "Moving brake bias rearward reduces front brake force".to_string(),
                     priority: 5,
+                    ease: 5,
+                    rationale: Some(
+                        "Brake bias sets the share of the braking force sent to the front brakes. Under braking weight moves forward, but the front tires still lock when they are asked for more braking than their grip allows. Moving the bias rearward takes braking force off the fronts and puts it on the rears, which have grip to spare as long as the rear does not lock or get nervous in turn."
+                            .to_string(),
+                    ),
                 },
                 SetupRecommendation {
                     category: SetupCategory::Brakes,
@@ -592,6 +851,8 @@ impl RecommendationEngine {
                     adjustment: "Reduce".to_string(),
                     description: "Lower brake pressure reduces overall braking force".to_string(),
                     priority: 4,
+                    ease: 5,
+                    rationale: None,
                 },
             ],
         );
@@ -605,9 +866,267 @@ impl RecommendationEngine {
                 adjustment: "Move Forward".to_string(),
                 description: "Moving brake bias forward reduces rear brake force".to_string(),
                 priority: 5,
+                ease: 5,
+                rationale: Some(
+                    "As the car brakes, weight moves off the rear axle, so the rear tires have less grip to brake with the harder you stop. With the bias too far back they lock first, which makes the car unstable since a sliding rear tire cannot hold the car straight. Moving the bias forward sends more of the braking to the heavily loaded front tires."
+                        .to_string(),
+                ),
             }],
         );
 
+        // Brake Stomp Lock
+        map.insert(
+            FindingType::BrakeStompLock,
+            vec![
+                SetupRecommendation {
+                    category: SetupCategory::Brakes,
+                    parameter: "Brake Application".to_string(),
+                    adjustment: "Brake More Progressively".to_string(),
+                    description: "The wheels lock as the pedal is stomped, squeeze the brake on over a few tenths so the load can transfer to the front tires".to_string(),
+                    priority: 5,
+                    ease: 5,
+                    rationale: Some(
+                        "Weight takes a moment to move forward when you hit the brakes. Stamping on the pedal asks for full braking force before the front tires carry the load they need to deliver it, and they lock. A quick but progressive squeeze lets the weight transfer build up with the pressure, so the tires can take more braking by the time you reach the peak."
+                            .to_string(),
+                    ),
+                },
+                SetupRecommendation {
+                    category: SetupCategory::Brakes,
+                    parameter: "Brake Pressure".to_string(),
+                    adjustment: "Reduce".to_string(),
+                    description: "Lower brake pressure makes the pedal more forgiving of fast application".to_string(),
+                    priority: 2,
+                    ease: 5,
+                    rationale: None,
+                },
+            ],
+        );
+
+        // Frequent ABS Intervention
+        map.insert(
+            FindingType::FrequentAbsIntervention,
+            vec![
+                SetupRecommendation {
+                    category: SetupCategory::Brakes,
+                    parameter: "Brake Application".to_string(),
+                    adjustment: "Ease Off The Peak Pressure".to_string(),
+                    description: "ABS keeps stepping in, brake just below the limit so the tires keep some grip for turning".to_string(),
+                    priority: 5,
+                    ease: 5,
+                    rationale: Some(
+                        "ABS releases the brake pressure every time a wheel starts to lock, so braking on ABS all the time is braking at a constantly interrupted pressure rather than at the limit. The shortest stop comes from the tires running just below the lock point. Easing off the peak pressure slightly keeps the tires there, and keeps ABS as a safety net rather than the way you brake."
+                            .to_string(),
+                    ),
+                },
+                SetupRecommendation {
+                    category: SetupCategory::Brakes,
+                    parameter: "Brake Pressure".to_string(),
+                    adjustment: "Reduce".to_string(),
+                    description: "Lower brake pressure keeps the tires below the ABS threshold at the same pedal travel".to_string(),
+                    priority: 4,
+                    ease: 5,
+                    rationale: None,
+                },
+            ],
+        );
+
+        // Throttle Stabbing
+        map.insert(
+            FindingType::ThrottleStabbing,
+            vec![
+                SetupRecommendation {
+                    category: SetupCategory::Electronics,
+                    parameter: "Throttle Application".to_string(),
+                    adjustment: "Squeeze On Progressively".to_string(),
+                    description: "The throttle goes on and off on exit, feed it in once and keep adding as the steering unwinds".to_string(),
+                    priority: 5,
+                    ease: 5,
+                    rationale: Some(
+                        "Every time the throttle goes on the weight moves onto the rear tires, and every time it comes off the weight moves back to the front. Stabbing at the pedal rocks the car between the axles, so the rear tires never settle under a steady load and the front keeps gaining and losing grip. A single progressive squeeze loads the rear once and lets the car take a set for the exit."
+                            .to_string(),
+                    ),
+                },
+                SetupRecommendation {
+                    category: SetupCategory::Electronics,
+                    parameter: "Traction Control".to_string(),
+                    adjustment: "Increase".to_string(),
+                    description: "Higher TC catches the spikes of wheelspin from each stab while working on a smoother application".to_string(),
+                    priority: 2,
+                    ease: 5,
+                    rationale: None,
+                },
+            ],
+        );
+
+        // Upshift Over-Revving
+        map.insert(
+            FindingType::UpshiftOverRevving,
+            vec![
+                SetupRecommendation {
+                    category: SetupCategory::Drivetrain,
+                    parameter: "Shift Timing".to_string(),
+                    adjustment: "Upshift Earlier".to_string(),
+                    description: "The upshift comes after the point where the next gear pulls harder, change up at the RPM shown with the finding".to_string(),
+                    priority: 5,
+                    ease: 5,
+                    rationale: Some(
+                        "Past its peak the engine makes less power with every extra RPM, while the next gear lands it back in the strong part of the curve. Holding the gear longer feels fast because of the noise, but the car accelerates less than it would in the next gear. Shifting where the two gears pull equally keeps the acceleration at its highest through the change."
+                            .to_string(),
+                    ),
+                },
+                SetupRecommendation {
+                    category: SetupCategory::Drivetrain,
+                    parameter: "Gear Ratios".to_string(),
+                    adjustment: "Lengthen".to_string(),
+                    description: "If the gear runs out before the braking point, a longer ratio saves a late upshift at the end of the straight".to_string(),
+                    priority: 2,
+                    ease: 2,
+                    rationale: None,
+                },
+            ],
+        );
+
+        // Upshift Under-Revving
+        map.insert(
+            FindingType::UpshiftUnderRevving,
+            vec![
+                SetupRecommendation {
+                    category: SetupCategory::Drivetrain,
+                    parameter: "Shift Timing".to_string(),
+                    adjustment: "Upshift Later".to_string(),
+                    description: "The upshift comes before the current gear stops pulling harder than the next one, hold it to the RPM shown with the finding".to_string(),
+                    priority: 5,
+                    ease: 5,
+                    rationale: Some(
+                        "Changing up early drops the engine below the strong part of its curve, so the next gear accelerates less than the current one still would. Short shifting has its place to calm wheelspin on a slippery exit, but on a straight it only costs time. Holding the gear until the two gears pull equally keeps the acceleration at its highest."
+                            .to_string(),
+                    ),
+                },
+                SetupRecommendation {
+                    category: SetupCategory::Electronics,
+                    parameter: "Traction Control".to_string(),
+                    adjustment: "Increase".to_string(),
+                    description: "If the early upshifts are to tame wheelspin, more TC lets you hold the gear".to_string(),
+                    priority: 2,
+                    ease: 5,
+                    rationale: None,
+                },
+            ],
+        );
+
+        // Frequent TC Intervention
+        map.insert(
+            FindingType::FrequentTcIntervention,
+            vec![
+                SetupRecommendation {
+                    category: SetupCategory::Drivetrain,
+                    parameter: "Differential Preload".to_string(),
+                    adjustment: "Reduce".to_string(),
+                    description: "Lower preload lets the inside rear wheel turn freely on exit so less power is cut".to_string(),
+                    priority: 4,
+                    ease: 3,
+                    rationale: None,
+                },
+                SetupRecommendation {
+                    category: SetupCategory::Electronics,
+                    parameter: "Throttle Application".to_string(),
+                    adjustment: "Squeeze On Progressively".to_string(),
+                    description: "Traction control keeps cutting power, feed the throttle in as the steering unwinds".to_string(),
+                    priority: 4,
+                    ease: 5,
+                    rationale: None,
+                },
+                SetupRecommendation {
+                    category: SetupCategory::Suspension,
+                    parameter: "Rear Springs".to_string(),
+                    adjustment: "Soften".to_string(),
+                    description: "Softer rear springs improve mechanical grip when putting the power down".to_string(),
+                    priority: 3,
+                    ease: 1,
+                    rationale: None,
+                },
+            ],
+        );
+
+        // Straight-Line Instability
+        map.insert(
+            FindingType::StraightLineInstability,
+            vec![
+                SetupRecommendation {
+                    category: SetupCategory::Aerodynamics,
+                    parameter: "Rear Wing".to_string(),
+                    adjustment: "Increase".to_string(),
+                    description:
+                        "More rear downforce keeps the car planted and tracking straight at speed"
+                            .to_string(),
+                    priority: 5,
+                    ease: 4,
+                    rationale: Some(
+                        "Downforce grows with the square of the speed, so it matters most on the straights and in fast corners. More rear wing pushes the rear tires into the track and moves the aerodynamic balance rearward, which makes the car more stable at speed, at the cost of some drag and top speed."
+                            .to_string(),
+                    ),
+                },
+                SetupRecommendation {
+                    category: SetupCategory::Alignment,
+                    parameter: "Rear Toe".to_string(),
+                    adjustment: "Increase Toe In".to_string(),
+                    description:
+                        "Rear toe in settles the rear axle so it stops wandering on the straights"
+                            .to_string(),
+                    priority: 4,
+                    ease: 2,
+                    rationale: None,
+                },
+                SetupRecommendation {
+                    category: SetupCategory::Alignment,
+                    parameter: "Front Toe".to_string(),
+                    adjustment: "Reduce Toe Out".to_string(),
+                    description: "Less front toe out calms the steering around the center"
+                        .to_string(),
+                    priority: 3,
+                    ease: 2,
+                    rationale: None,
+                },
+            ],
+        );
+
+        // Downshift Instability
+        map.insert(
+            FindingType::DownshiftInstability,
+            vec![
+                SetupRecommendation {
+                    category: SetupCategory::Drivetrain,
+                    parameter: "Downshifts".to_string(),
+                    adjustment: "Blip And Space Them Out".to_string(),
+                    description: "The rear steps out as the gear goes in, blip the throttle and wait for the speed to drop before the next downshift".to_string(),
+                    priority: 5,
+                    ease: 5,
+                    rationale: Some(
+                        "Each downshift spins the engine up through the driven wheels. Without a blip, the rear tires briefly slow down more than the car, which acts like a rear brake and can unsettle or lock the rear while it is already light under braking. Blipping the throttle matches the engine speed to the lower gear, and spacing the shifts out gives the rear tires time to recover between them."
+                            .to_string(),
+                    ),
+                },
+                SetupRecommendation {
+                    category: SetupCategory::Electronics,
+                    parameter: "Engine Braking".to_string(),
+                    adjustment: "Reduce".to_string(),
+                    description: "Less engine braking keeps the rear tires from being dragged past their grip after a downshift".to_string(),
+                    priority: 4,
+                    ease: 5,
+                    rationale: None,
+                },
+                SetupRecommendation {
+                    category: SetupCategory::Drivetrain,
+                    parameter: "Differential Coast Locking".to_string(),
+                    adjustment: "Increase".to_string(),
+                    description: "More coast locking keeps the rear wheels together off throttle and stabilizes the car on entry".to_string(),
+                    priority: 3,
+                    ease: 3,
+                    rationale: None,
+                },
+            ],
+        );
+
         // Braking Instability
         map.insert(
             FindingType::BrakingInstability,
@@ -618,6 +1137,8 @@ impl RecommendationEngine {
                     adjustment: "Stiffen".to_string(),
                     description: "Stiffer front springs reduce brake dive".to_string(),
                     priority: 4,
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Aerodynamics,
@@ -626,6 +1147,8 @@ impl RecommendationEngine {
                     description: "Lower rear ride height increases rear stability under braking"
                         .to_string(),
                     priority: 3,
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Dampers,
@@ -634,6 +1157,8 @@ impl RecommendationEngine {
                     description: "Stiffer front bump controls weight transfer under braking"
                         .to_string(),
                     priority: 2,
+                    ease: 3,
+                    rationale: None,
                 },
             ],
         );
@@ -648,6 +1173,11 @@ impl RecommendationEngine {
                     adjustment: "Open".to_string(),
                     description: "Opening brake ducts increases cooling to tires".to_string(),
                     priority: 5,
+                    ease: 4,
+                    rationale: Some(
+                        "Brakes turn speed into heat, and a lot of it soaks through the wheel rims into the tires. Opening the brake ducts sends more air over the brakes, running them and the rims cooler, so less heat reaches the tires. It is the quickest way to bring tire temperatures down without touching the mechanical balance of the car."
+                            .to_string(),
+                    ),
                 },
                 SetupRecommendation {
                     category: SetupCategory::AntiRollBar,
@@ -655,6 +1185,8 @@ impl RecommendationEngine {
                     adjustment: "Soften".to_string(),
                     description: "Softer Antirollbars reduce tire stress".to_string(),
                     priority: 4,
+                    ease: 4,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -662,6 +1194,8 @@ impl RecommendationEngine {
                     adjustment: "Soften".to_string(),
                     description: "Softer suspension reduces energy transfer to tires".to_string(),
                     priority: 4,
+                    ease: 1,
+                    rationale: None,
                 },
             ],
         );
@@ -676,6 +1210,11 @@ impl RecommendationEngine {
                     adjustment: "Close".to_string(),
                     description: "Closing brake ducts retains heat in tires".to_string(),
                     priority: 5,
+                    ease: 4,
+                    rationale: Some(
+                        "Tires only reach their peak grip inside a temperature window, and below it the rubber is too stiff to key into the track. Closing the brake ducts keeps more brake heat in the rims, which warms the tires from the inside. This works best on tracks with heavy braking zones."
+                            .to_string(),
+                    ),
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -683,6 +1222,8 @@ impl RecommendationEngine {
                     adjustment: "Stiffen".to_string(),
                     description: "Stiffer suspension generates more tire heat".to_string(),
                     priority: 4,
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Alignment,
@@ -690,6 +1231,8 @@ impl RecommendationEngine {
                     adjustment: "Increase".to_string(),
                     description: "More toe generates friction heat in tires".to_string(),
                     priority: 2,
+                    ease: 2,
+                    rationale: None,
                 },
             ],
         );
@@ -704,6 +1247,11 @@ impl RecommendationEngine {
                     adjustment: "Increase".to_string(),
                     description: "Higher ride height prevents suspension bottoming".to_string(),
                     priority: 5,
+                    ease: 1,
+                    rationale: Some(
+                        "When the car bottoms out, the floor or the bump stops take load away from the tires and the suspension stops working. Grip drops suddenly, usually over bumps, kerbs or at the bottom of compressions. Raising the ride height gives the suspension the travel it needs before anything touches, at the cost of a bit of downforce on cars sensitive to rake."
+                            .to_string(),
+                    ),
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -711,6 +1259,8 @@ impl RecommendationEngine {
                     adjustment: "Stiffen".to_string(),
                     description: "Stiffer springs resist compression over bumps".to_string(),
                     priority: 4,
+                    ease: 1,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Dampers,
@@ -719,6 +1269,8 @@ impl RecommendationEngine {
                     description: "Stiffer fast bump damping controls compression on impacts"
                         .to_string(),
                     priority: 2,
+                    ease: 3,
+                    rationale: None,
                 },
             ],
         );
@@ -734,6 +1286,11 @@ impl RecommendationEngine {
                     description: "Forward brake bias reduces rear instability during trail braking"
                         .to_string(),
                     priority: 5,
+                    ease: 5,
+                    rationale: Some(
+                        "Trail braking keeps some brake pressure on while turning in, asking the tires to brake and corner at the same time. With the bias too far back, the rear tires take too large a share of that combined load and the rear slides. Moving the bias forward keeps the rear tires free to hold the car while you carry the brake into the corner."
+                            .to_string(),
+                    ),
                 },
                 SetupRecommendation {
                     category: SetupCategory::Drivetrain,
@@ -741,6 +1298,8 @@ impl RecommendationEngine {
                     adjustment: "Increase".to_string(),
                     description: "Higher preload stabilizes rear during coast".to_string(),
                     priority: 4,
+                    ease: 3,
+                    rationale: None,
                 },
                 SetupRecommendation {
                     category: SetupCategory::Suspension,
@@ -748,10 +1307,182 @@ impl RecommendationEngine {
                     adjustment: "Soften".to_string(),
                     description: "Softer rear springs improve rear stability".to_string(),
                     priority: 4,
+                    ease: 1,
+                    rationale: None,
+                },
+            ],
+        );
+
+        // Camber imbalance, from inner vs outer tire surface temperatures
+        map.insert(
+            FindingType::FrontCamberTooPositive,
+            vec![
+                SetupRecommendation {
+                    category: SetupCategory::Alignment,
+                    parameter: "Front Camber".to_string(),
+                    adjustment: "Increase Negative".to_string(),
+                    description:
+                        "Hot outer edges mean the front tires roll onto their shoulders in corners"
+                            .to_string(),
+                    priority: 5,
+                    ease: 2,
+                    rationale: Some(
+                        "The car rolls in the corner and the outside tire leans with it, lifting the inside edge of the tread off the track. Negative camber tilts the top of the tire inward at rest, so that under load the tread sits flat on the track. A hotter outside edge means the tire is rolling onto its shoulder, more negative camber puts more rubber on the track."
+                            .to_string(),
+                    ),
+                },
+                SetupRecommendation {
+                    category: SetupCategory::AntiRollBar,
+                    parameter: "Front Antirollbar".to_string(),
+                    adjustment: "Stiffen".to_string(),
+                    description: "Stiffer front Antirollbar reduces body roll onto the outer edge"
+                        .to_string(),
+                    priority: 2,
+                    ease: 4,
+                    rationale: None,
                 },
             ],
         );
 
+        map.insert(
+            FindingType::FrontCamberTooNegative,
+            vec![SetupRecommendation {
+                category: SetupCategory::Alignment,
+                parameter: "Front Camber".to_string(),
+                adjustment: "Reduce Negative".to_string(),
+                description:
+                    "Hot inner edges mean the front tires lose contact patch under braking"
+                        .to_string(),
+                priority: 5,
+                ease: 2,
+                rationale: Some(
+                    "Negative camber helps the tire lie flat in corners, but too much leaves only the inside edge of the tread working on the straights and under braking, which overheats that edge and cuts the braking grip. A hotter inside edge means there is more camber than the corners need, reducing it spreads the load across the whole tread."
+                        .to_string(),
+                ),
+            }],
+        );
+
+        map.insert(
+            FindingType::RearCamberTooPositive,
+            vec![
+                SetupRecommendation {
+                    category: SetupCategory::Alignment,
+                    parameter: "Rear Camber".to_string(),
+                    adjustment: "Increase Negative".to_string(),
+                    description:
+                        "Hot outer edges mean the rear tires roll onto their shoulders in corners"
+                            .to_string(),
+                    priority: 5,
+                    ease: 2,
+                    rationale: Some(
+                        "As the car rolls, the outside rear tire leans onto its outer shoulder unless negative camber tilts it in at rest. A hotter outside edge means the tread is not flat in the corners, and the rear gives up lateral grip. More negative camber puts the whole tread on the track in the corners."
+                            .to_string(),
+                    ),
+                },
+                SetupRecommendation {
+                    category: SetupCategory::AntiRollBar,
+                    parameter: "Rear Antirollbar".to_string(),
+                    adjustment: "Stiffen".to_string(),
+                    description: "Stiffer rear Antirollbar reduces body roll onto the outer edge"
+                        .to_string(),
+                    priority: 2,
+                    ease: 4,
+                    rationale: None,
+                },
+            ],
+        );
+
+        map.insert(
+            FindingType::RearCamberTooNegative,
+            vec![SetupRecommendation {
+                category: SetupCategory::Alignment,
+                parameter: "Rear Camber".to_string(),
+                adjustment: "Reduce Negative".to_string(),
+                description:
+                    "Hot inner edges mean the rear tires lose contact patch under traction"
+                        .to_string(),
+                priority: 5,
+                ease: 2,
+                rationale: Some(
+                    "The rear tires spend a lot of their time putting the power down in a straight line, where negative camber leaves only the inside edge of the tread on the track. Too much overheats that edge and costs traction on exit. Reducing rear camber gives a flatter contact patch under acceleration."
+                        .to_string(),
+                ),
+            }],
+        );
+
+        // Tire pressure out of window, from the middle vs edges tire surface temperatures
+        map.insert(
+            FindingType::FrontTirePressureTooHigh,
+            vec![SetupRecommendation {
+                category: SetupCategory::TireManagement,
+                parameter: "Front Tire Pressure".to_string(),
+                adjustment: "Reduce".to_string(),
+                description:
+                    "A hot middle of the tread means the front tires bulge and run on a narrow contact patch"
+                        .to_string(),
+                priority: 5,
+                ease: 4,
+                rationale: Some(
+                    "Air pressure rises as the tire heats up, and an overinflated tire bulges so the middle of the tread carries most of the load. The contact patch shrinks and the tire loses grip, which shows up as a hot center of the tread. Lowering the cold pressure brings the hot pressure back into the window where the whole tread touches the track."
+                        .to_string(),
+                ),
+            }],
+        );
+
+        map.insert(
+            FindingType::FrontTirePressureTooLow,
+            vec![SetupRecommendation {
+                category: SetupCategory::TireManagement,
+                parameter: "Front Tire Pressure".to_string(),
+                adjustment: "Increase".to_string(),
+                description:
+                    "Hot edges mean the front tires sag and the sidewalls flex, making the steering vague"
+                        .to_string(),
+                priority: 5,
+                ease: 4,
+                rationale: Some(
+                    "An underinflated tire sags in the middle, so the edges of the tread carry the load while the center barely touches the track. The sidewall flexes too much, which makes the steering vague and builds heat. Raising the cold pressure brings the hot pressure back into the window, flattening the contact patch."
+                        .to_string(),
+                ),
+            }],
+        );
+
+        map.insert(
+            FindingType::RearTirePressureTooHigh,
+            vec![SetupRecommendation {
+                category: SetupCategory::TireManagement,
+                parameter: "Rear Tire Pressure".to_string(),
+                adjustment: "Reduce".to_string(),
+                description:
+                    "A hot middle of the tread means the rear tires bulge and run on a narrow contact patch"
+                        .to_string(),
+                priority: 5,
+                ease: 4,
+                rationale: Some(
+                    "An overinflated tire bulges in the middle, shrinking the contact patch to the center of the tread and costing grip and traction. A hot tread center is the sign. Lowering the cold pressure lets the whole tread touch the track once the tire is up to temperature."
+                        .to_string(),
+                ),
+            }],
+        );
+
+        map.insert(
+            FindingType::RearTirePressureTooLow,
+            vec![SetupRecommendation {
+                category: SetupCategory::TireManagement,
+                parameter: "Rear Tire Pressure".to_string(),
+                adjustment: "Increase".to_string(),
+                description:
+                    "Hot edges mean the rear tires sag and the sidewalls flex, making the rear lazy to respond"
+                        .to_string(),
+                priority: 5,
+                ease: 4,
+                rationale: Some(
+                    "An underinflated tire carries the load on the edges of the tread while the center lifts, and its soft sidewall lets the rear move around under load. Raising the cold pressure brings the hot pressure into the window and stiffens the sidewall, making the rear more stable on exit."
+                        .to_string(),
+                ),
+            }],
+        );
+
         map
     }
 
@@ -771,6 +1502,118 @@ impl RecommendationEngine {
             .unwrap_or_default()
     }
 
+    /// Finding types among `finding_types` that recommend the same parameter change as
+    /// `recommendation`.
+    pub fn findings_recommending<'a>(
+        &self,
+        finding_types: impl IntoIterator<Item = &'a FindingType>,
+        recommendation: &SetupRecommendation,
+    ) -> Vec<FindingType> {
+        finding_types
+            .into_iter()
+            .filter(|finding_type| {
+                self.recommendation_map
+                    .get(finding_type)
+                    .is_some_and(|recs| {
+                        recs.iter().any(|r| {
+                            r.parameter == recommendation.parameter
+                                && r.adjustment == recommendation.adjustment
+                        })
+                    })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Processed recommendations for a set of findings, the canonical way to get
+    /// recommendations out of the engine.
+    ///
+    /// Gathers the recommendations of every finding type and runs them through
+    /// [`process_recommendations`](Self::process_recommendations), so the result is
+    /// prioritized with conflicts resolved exactly as in the setup window. No telemetry
+    /// or [`SetupAssistant`](super::SetupAssistant) is needed, which makes it suitable
+    /// for trying out hypothetical finding combinations. Confidence is left at 0.0, use
+    /// [`score_confidence`](Self::score_confidence) with actual findings to set it.
+    pub fn recommend(&self, finding_types: &HashSet<FindingType>) -> Vec<ProcessedRecommendation> {
+        self.recommend_for_cause(
+            finding_types,
+            ExitUndersteerCause::Unknown,
+            LoadTransferTiming::Unknown,
+        )
+    }
+
+    /// Like [`recommend`](Self::recommend), with the corner exit understeer
+    /// recommendations prioritized for its likely cause and the damper recommendations
+    /// for the timing of the load transfers.
+    ///
+    /// Differential understeer moves the drivetrain changes up and the aero changes
+    /// down by [`CAUSE_PRIORITY_SHIFT`], aero understeer does the opposite. Slow load
+    /// transfers move the slow-speed damper stiffening up and the softening down,
+    /// abrupt ones do the opposite. Unknown causes and timings keep the priorities of
+    /// the recommendation map.
+    pub fn recommend_for_cause(
+        &self,
+        finding_types: &HashSet<FindingType>,
+        exit_understeer_cause: ExitUndersteerCause,
+        load_transfer: LoadTransferTiming,
+    ) -> Vec<ProcessedRecommendation> {
+        let recommendations = finding_types
+            .iter()
+            .flat_map(|finding_type| {
+                let mut recs = self.get_recommendations(finding_type);
+                if *finding_type == FindingType::CornerExitUndersteer {
+                    Self::prioritize_for_cause(&mut recs, exit_understeer_cause);
+                }
+                Self::prioritize_for_load_transfer(&mut recs, load_transfer);
+                recs
+            })
+            .collect();
+        self.process_recommendations(recommendations)
+    }
+
+    fn prioritize_for_cause(
+        recommendations: &mut [SetupRecommendation],
+        cause: ExitUndersteerCause,
+    ) {
+        let (favored, disfavored) = match cause {
+            ExitUndersteerCause::Differential => {
+                (SetupCategory::Drivetrain, SetupCategory::Aerodynamics)
+            }
+            ExitUndersteerCause::Aero => (SetupCategory::Aerodynamics, SetupCategory::Drivetrain),
+            ExitUndersteerCause::Unknown => return,
+        };
+        for rec in recommendations {
+            if rec.category == favored {
+                rec.priority = (rec.priority + CAUSE_PRIORITY_SHIFT).min(5);
+            } else if rec.category == disfavored {
+                rec.priority = rec.priority.saturating_sub(CAUSE_PRIORITY_SHIFT).max(1);
+            }
+        }
+    }
+
+    fn prioritize_for_load_transfer(
+        recommendations: &mut [SetupRecommendation],
+        timing: LoadTransferTiming,
+    ) {
+        let favored = match timing {
+            LoadTransferTiming::Slow => "Stiffen",
+            LoadTransferTiming::Abrupt => "Soften",
+            LoadTransferTiming::Unknown => return,
+        };
+        // fast bump and rebound react to kerbs and bumps, not to the weight transfer
+        for rec in recommendations
+            .iter_mut()
+            .filter(|rec| rec.category == SetupCategory::Dampers)
+            .filter(|rec| !rec.parameter.contains("Fast"))
+        {
+            if rec.adjustment == favored {
+                rec.priority = (rec.priority + CAUSE_PRIORITY_SHIFT).min(5);
+            } else {
+                rec.priority = rec.priority.saturating_sub(CAUSE_PRIORITY_SHIFT).max(1);
+            }
+        }
+    }
+
     /// Process and prioritize recommendations, detecting conflicts.
     ///
     /// Takes a list of recommendations from multiple confirmed findings and:
@@ -807,6 +1650,7 @@ impl RecommendationEngine {
                     recommendation: rec,
                     conflicts: Vec::new(),
                     has_conflict: false,
+                    confidence: 0.0,
                 });
             } else {
                 // Multiple recommendations for same parameter - check for conflicts
@@ -820,6 +1664,7 @@ impl RecommendationEngine {
                         recommendation: rec,
                         conflicts: Vec::new(),
                         has_conflict: false,
+                        confidence: 0.0,
                     });
                 } else {
                     // Conflicting adjustments - include all with conflict markers
@@ -835,6 +1680,7 @@ impl RecommendationEngine {
                             recommendation: rec,
                             conflicts: other_conflicts,
                             has_conflict: true,
+                            confidence: 0.0,
                         });
                     }
                 }
@@ -842,16 +1688,58 @@ impl RecommendationEngine {
         }
 
         // Sort by priority (highest first), then by parameter name for stable ordering
-        processed.sort_by(|a, b| {
-            b.recommendation
-                .priority
-                .cmp(&a.recommendation.priority)
-                .then_with(|| a.recommendation.parameter.cmp(&b.recommendation.parameter))
-        });
+        RecommendationOrder::Impact.sort(&mut processed);
 
         processed
     }
 
+    /// Score how confident each processed recommendation is given the findings behind it.
+    ///
+    /// Every finding that recommends the same parameter and adjustment adds evidence of
+    /// `severity * min(occurrences / CONFIDENT_OCCURRENCES, 1)`, and the evidence of
+    /// several findings combines as independent signals, `1 - Π(1 - evidence)`. A
+    /// conflicting recommendation keeps only its share of the evidence for and against
+    /// the change, so a marginal change opposed by an equally backed one scores low.
+    pub fn score_confidence(
+        &self,
+        processed: &mut [ProcessedRecommendation],
+        findings: &[Finding],
+    ) {
+        for proc_rec in processed.iter_mut() {
+            let support =
+                self.evidence_for(findings, std::slice::from_ref(&proc_rec.recommendation));
+            let opposition = self.evidence_for(findings, &proc_rec.conflicts);
+            proc_rec.confidence = if support + opposition > 0.0 {
+                support * support / (support + opposition)
+            } else {
+                0.0
+            };
+        }
+    }
+
+    /// Combined evidence of the findings recommending any of `recs`.
+    fn evidence_for(&self, findings: &[Finding], recs: &[SetupRecommendation]) -> f32 {
+        let doubt: f32 = findings
+            .iter()
+            .filter(|finding| {
+                self.recommendation_map
+                    .get(&finding.finding_type)
+                    .is_some_and(|finding_recs| {
+                        finding_recs.iter().any(|f| {
+                            recs.iter()
+                                .any(|r| f.parameter == r.parameter && f.adjustment == r.adjustment)
+                        })
+                    })
+            })
+            .map(|finding| {
+                let occurrences =
+                    (finding.occurrence_count as f32 / CONFIDENT_OCCURRENCES as f32).min(1.0);
+                1.0 - finding.severity.clamp(0.0, 1.0) * occurrences
+            })
+            .product();
+        1.0 - doubt
+    }
+
     /// Detect conflicting adjustments within a group of recommendations.
     ///
     /// Returns recommendations that have conflicting adjustment directions.
@@ -934,6 +1822,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_recommend_for_hypothetical_findings() {
+        let engine = RecommendationEngine::new();
+        assert!(engine.recommend(&HashSet::new()).is_empty());
+
+        let findings = HashSet::from([FindingType::TireOverheating, FindingType::TireCold]);
+        let processed = engine.recommend(&findings);
+        let raw = engine
+            .get_recommendations(&FindingType::TireOverheating)
+            .into_iter()
+            .chain(engine.get_recommendations(&FindingType::TireCold))
+            .collect();
+        assert_eq!(processed.len(), engine.process_recommendations(raw).len());
+        assert!(
+            processed
+                .iter()
+                .any(|p| p.recommendation.parameter.contains("Brake Ducts") && p.has_conflict)
+        );
+        assert!(
+            processed
+                .windows(2)
+                .all(|w| w[0].recommendation.priority >= w[1].recommendation.priority)
+        );
+    }
+
+    #[test]
+    fn test_exit_understeer_prioritized_for_cause() {
+        let engine = RecommendationEngine::new();
+        let findings = HashSet::from([FindingType::CornerExitUndersteer]);
+        let priority = |processed: &[ProcessedRecommendation], parameter: &str| {
+            processed
+                .iter()
+                .find(|p| p.recommendation.parameter == parameter)
+                .map(|p| p.recommendation.priority)
+                .unwrap()
+        };
+
+        let unknown = engine.recommend(&findings);
+        assert_eq!(priority(&unknown, "Differential Preload"), 5);
+        assert_eq!(priority(&unknown, "Front Wing"), 3);
+
+        let diff = engine.recommend_for_cause(
+            &findings,
+            ExitUndersteerCause::Differential,
+            LoadTransferTiming::Unknown,
+        );
+        assert_eq!(priority(&diff, "Differential Locking"), 5);
+        assert_eq!(priority(&diff, "Front Wing"), 1);
+
+        let aero = engine.recommend_for_cause(
+            &findings,
+            ExitUndersteerCause::Aero,
+            LoadTransferTiming::Unknown,
+        );
+        assert_eq!(aero[0].recommendation.parameter, "Front Wing");
+        assert_eq!(priority(&aero, "Front Wing"), 5);
+        assert_eq!(priority(&aero, "Differential Preload"), 3);
+        assert_eq!(priority(&aero, "Front Springs"), 4);
+    }
+
+    #[test]
+    fn test_load_transfer_timing_prioritizes_dampers() {
+        let engine = RecommendationEngine::new();
+        let findings = HashSet::from([FindingType::CornerExitUndersteer]);
+        let priority = |processed: &[ProcessedRecommendation], parameter: &str| {
+            processed
+                .iter()
+                .find(|p| p.recommendation.parameter == parameter)
+                .map(|p| p.recommendation.priority)
+                .unwrap()
+        };
+        let recommend =
+            |timing| engine.recommend_for_cause(&findings, ExitUndersteerCause::Unknown, timing);
+
+        let unknown = recommend(LoadTransferTiming::Unknown);
+        let slow = recommend(LoadTransferTiming::Slow);
+        let abrupt = recommend(LoadTransferTiming::Abrupt);
+        // stiffening the rear slow bump speeds the transfer up, softening the front
+        // slow rebound slows it down
+        assert_eq!(
+            priority(&slow, "Rear Slow Bump"),
+            (priority(&unknown, "Rear Slow Bump") + 2).min(5)
+        );
+        assert_eq!(priority(&slow, "Front Slow Rebound"), 1);
+        assert_eq!(priority(&abrupt, "Rear Slow Bump"), 1);
+        assert_eq!(
+            priority(&abrupt, "Front Slow Rebound"),
+            (priority(&unknown, "Front Slow Rebound") + 2).min(5)
+        );
+        // other categories keep their priority
+        assert_eq!(
+            priority(&slow, "Differential Preload"),
+            priority(&unknown, "Differential Preload")
+        );
+    }
+
     #[test]
     fn test_setup_category_equality() {
         assert_eq!(SetupCategory::Aerodynamics, SetupCategory::Aerodynamics);
@@ -961,6 +1945,8 @@ mod tests {
             adjustment: "Reduce".to_string(),
             description: "Lowering front ride height increases front downforce".to_string(),
             priority: 3,
+            ease: 1,
+            rationale: None,
         };
 
         assert_eq!(rec.category, SetupCategory::Aerodynamics);
@@ -978,6 +1964,8 @@ mod tests {
             adjustment: "Soften".to_string(),
             description: "Softer springs improve mechanical grip".to_string(),
             priority: 4,
+            ease: 1,
+            rationale: None,
         };
 
         let cloned = rec.clone();
@@ -1009,6 +1997,22 @@ mod tests {
             FindingType::TireCold,
             FindingType::BottomingOut,
             FindingType::ExcessiveTrailbraking,
+            FindingType::FrontCamberTooPositive,
+            FindingType::FrontCamberTooNegative,
+            FindingType::RearCamberTooPositive,
+            FindingType::RearCamberTooNegative,
+            FindingType::BrakeStompLock,
+            FindingType::FrequentAbsIntervention,
+            FindingType::FrequentTcIntervention,
+            FindingType::StraightLineInstability,
+            FindingType::DownshiftInstability,
+            FindingType::FrontTirePressureTooHigh,
+            FindingType::FrontTirePressureTooLow,
+            FindingType::RearTirePressureTooHigh,
+            FindingType::RearTirePressureTooLow,
+            FindingType::ThrottleStabbing,
+            FindingType::UpshiftOverRevving,
+            FindingType::UpshiftUnderRevving,
         ];
 
         for finding_type in finding_types {
@@ -1028,14 +2032,182 @@ mod tests {
         // Verify the map is not empty
         assert!(!engine.recommendation_map.is_empty());
 
-        // Verify we have recommendations for all 15 finding types
+        // Verify we have recommendations for all 31 finding types
         assert_eq!(
             engine.recommendation_map.len(),
-            15,
-            "Should have recommendations for all 15 finding types"
+            31,
+            "Should have recommendations for all 31 finding types"
+        );
+    }
+
+    fn overrides(json: &str) -> CarRecommendationOverrides {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_car_override_adds_and_replaces_parameter() {
+        let overrides = overrides(
+            r#"{"Porsche 911 GT3 R": {"CornerExitPowerOversteer": {"recommendations": [
+                {"category": "Drivetrain", "parameter": "Differential Preload", "adjustment": "Reduce",
+                 "description": "Rear-engined car", "priority": 5},
+                {"category": "Aerodynamics", "parameter": "Rear Wing Gurney", "adjustment": "Increase",
+                 "description": "More rear downforce", "priority": 2}
+            ]}}}"#,
+        );
+        let generic = RecommendationEngine::new()
+            .get_recommendations(&FindingType::CornerExitPowerOversteer)
+            .len();
+
+        let engine = RecommendationEngine::for_car("porsche 911 gt3 r", &overrides);
+        let recs = engine.get_recommendations(&FindingType::CornerExitPowerOversteer);
+
+        let preload: Vec<_> = recs
+            .iter()
+            .filter(|r| r.parameter == "Differential Preload")
+            .collect();
+        assert_eq!(preload.len(), 1);
+        assert_eq!(preload[0].description, "Rear-engined car");
+        assert!(recs.iter().any(|r| r.parameter == "Rear Wing Gurney"));
+        assert_eq!(recs.len(), generic + 1);
+    }
+
+    #[test]
+    fn test_car_override_replaces_finding_recommendations() {
+        let overrides = overrides(
+            r#"{"BMW M4 GT3": {"TireCold": {"replace": true, "recommendations": [
+                {"category": "TireManagement", "parameter": "Tire Pressure", "adjustment": "Increase",
+                 "description": "Run higher pressures", "priority": 5}
+            ]}}}"#,
+        );
+
+        let engine = RecommendationEngine::for_car("BMW M4 GT3", &overrides);
+        let recs = engine.get_recommendations(&FindingType::TireCold);
+        assert_eq!(recs.len(), 1);
+        assert_eq!(recs[0].category, SetupCategory::TireManagement);
+
+        // other cars and other findings keep the generic recommendations
+        let other = RecommendationEngine::for_car("Audi R8 LMS", &overrides);
+        assert!(other.get_recommendations(&FindingType::TireCold).len() > 1);
+        assert_eq!(
+            engine
+                .get_recommendations(&FindingType::TireOverheating)
+                .len(),
+            RecommendationEngine::new()
+                .get_recommendations(&FindingType::TireOverheating)
+                .len()
+        );
+    }
+
+    fn finding(finding_type: FindingType, occurrence_count: usize, severity: f32) -> Finding {
+        Finding {
+            finding_type,
+            occurrence_count,
+            corner_phase: crate::setup_assistant::CornerPhase::Entry,
+            last_detected: 0,
+            severity,
+            occurrence_timestamps: Default::default(),
+            occurrence_lap_distances: Default::default(),
+            occurrence_causes: Default::default(),
+            cause_tally: Default::default(),
+            corner_type_tally: Default::default(),
+        }
+    }
+
+    /// Processes and scores the recommendations of `findings`
+    fn scored(findings: &[Finding]) -> Vec<ProcessedRecommendation> {
+        let engine = RecommendationEngine::new();
+        let recs = findings
+            .iter()
+            .flat_map(|f| engine.get_recommendations(&f.finding_type))
+            .collect();
+        let mut processed = engine.process_recommendations(recs);
+        engine.score_confidence(&mut processed, findings);
+        processed
+    }
+
+    fn confidence_of(
+        processed: &[ProcessedRecommendation],
+        parameter: &str,
+        adjustment: &str,
+    ) -> f32 {
+        processed
+            .iter()
+            .find(|p| {
+                p.recommendation.parameter == parameter && p.recommendation.adjustment == adjustment
+            })
+            .map(|p| p.confidence)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_confidence_high_for_frequent_severe_finding() {
+        let processed = scored(&[finding(FindingType::FrontBrakeLock, 25, 0.9)]);
+
+        for proc_rec in &processed {
+            assert!(!proc_rec.has_conflict);
+            assert!((proc_rec.confidence - 0.9).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_confidence_scales_with_occurrences() {
+        let rare = scored(&[finding(FindingType::FrontBrakeLock, 2, 0.9)]);
+        let frequent = scored(&[finding(FindingType::FrontBrakeLock, 8, 0.9)]);
+
+        assert!(
+            confidence_of(&rare, "Brake Bias", "Move Rearward")
+                < confidence_of(&frequent, "Brake Bias", "Move Rearward")
+        );
+        assert_eq!(
+            scored(&[finding(FindingType::FrontBrakeLock, 0, 0.9)])[0].confidence,
+            0.0
         );
     }
 
+    #[test]
+    fn test_confidence_combines_agreeing_findings() {
+        // Both findings recommend reducing the brake pressure
+        let processed = scored(&[
+            finding(FindingType::FrontBrakeLock, 10, 0.5),
+            finding(FindingType::BrakeStompLock, 10, 0.5),
+        ]);
+
+        assert!((confidence_of(&processed, "Brake Pressure", "Reduce") - 0.75).abs() < 1e-6);
+        assert!((confidence_of(&processed, "Brake Bias", "Move Rearward") - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_confidence_low_for_marginal_conflict() {
+        let processed = scored(&[
+            finding(FindingType::TireOverheating, 2, 0.3),
+            finding(FindingType::TireCold, 2, 0.3),
+        ]);
+
+        let conflicting: Vec<_> = processed.iter().filter(|p| p.has_conflict).collect();
+        assert!(!conflicting.is_empty());
+        for proc_rec in conflicting {
+            assert!(proc_rec.confidence < 0.05);
+        }
+    }
+
+    #[test]
+    fn test_confidence_favors_better_backed_side_of_conflict() {
+        let processed = scored(&[
+            finding(FindingType::TireOverheating, 20, 0.9),
+            finding(FindingType::TireCold, 2, 0.3),
+        ]);
+
+        // Opening the ducts and softening the springs cool the tires
+        for (parameter, cooling, warming) in [
+            ("Brake Ducts", "Open", "Close"),
+            ("Springs", "Soften", "Stiffen"),
+        ] {
+            let cooling_confidence = confidence_of(&processed, parameter, cooling);
+            assert!(cooling_confidence > 0.5);
+            assert!(cooling_confidence > confidence_of(&processed, parameter, warming));
+        }
+    }
+
     #[test]
     fn test_category_grouping() {
         use std::collections::HashMap;
@@ -1082,6 +2254,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_every_recommendation_has_ease() {
+        let engine = RecommendationEngine::new();
+        for recs in engine.recommendation_map.values() {
+            for rec in recs {
+                assert!(
+                    (1..=5).contains(&rec.ease),
+                    "{} has no ease of adjustment",
+                    rec.parameter
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_highest_priority_recommendations_have_rationale() {
+        let engine = RecommendationEngine::new();
+        for recs in engine.recommendation_map.values() {
+            for rec in recs.iter().filter(|rec| rec.priority == 5) {
+                assert!(
+                    rec.rationale.as_ref().is_some_and(|r| !r.is_empty()),
+                    "{} has no rationale",
+                    rec.parameter
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_by_ease_and_blend() {
+        let engine = RecommendationEngine::new();
+        let mut processed = engine.recommend(&HashSet::from([FindingType::CornerEntryUndersteer]));
+        let order = |processed: &[ProcessedRecommendation]| {
+            processed
+                .iter()
+                .map(|p| p.recommendation.parameter.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        // the quick brake bias change comes before the higher priority antirollbar
+        RecommendationOrder::Ease.sort(&mut processed);
+        assert_eq!(order(&processed)[..2], ["Brake Bias", "Front Antirollbar"]);
+        assert_eq!(processed.last().unwrap().recommendation.ease, 1);
+
+        // both score 9 when blended, the antirollbar wins on priority
+        RecommendationOrder::Blend.sort(&mut processed);
+        assert_eq!(order(&processed)[..2], ["Front Antirollbar", "Brake Bias"]);
+
+        RecommendationOrder::Impact.sort(&mut processed);
+        assert_eq!(order(&processed)[0], "Front Antirollbar");
+
+        // overridden scores beyond 5 add up past the range of a u8
+        processed[1].recommendation.priority = 200;
+        processed[1].recommendation.ease = 100;
+        let parameter = processed[1].recommendation.parameter.clone();
+        RecommendationOrder::Blend.sort(&mut processed);
+        assert_eq!(order(&processed)[0], parameter);
+    }
+
     #[test]
     fn test_corner_entry_understeer_recommendations() {
         let engine = RecommendationEngine::new();
@@ -1173,6 +2404,22 @@ mod proptests {
             Just(FindingType::TireCold),
             Just(FindingType::BottomingOut),
             Just(FindingType::ExcessiveTrailbraking),
+            Just(FindingType::FrontCamberTooPositive),
+            Just(FindingType::FrontCamberTooNegative),
+            Just(FindingType::RearCamberTooPositive),
+            Just(FindingType::RearCamberTooNegative),
+            Just(FindingType::BrakeStompLock),
+            Just(FindingType::FrequentAbsIntervention),
+            Just(FindingType::FrequentTcIntervention),
+            Just(FindingType::StraightLineInstability),
+            Just(FindingType::DownshiftInstability),
+            Just(FindingType::FrontTirePressureTooHigh),
+            Just(FindingType::FrontTirePressureTooLow),
+            Just(FindingType::RearTirePressureTooHigh),
+            Just(FindingType::RearTirePressureTooLow),
+            Just(FindingType::ThrottleStabbing),
+            Just(FindingType::UpshiftOverRevving),
+            Just(FindingType::UpshiftUnderRevving),
         ]
     }
 
@@ -0,0 +1,188 @@
+use std::fmt::Write;
+
+use super::Finding;
+use super::recommendations::{ProcessedRecommendation, SetupCategory};
+
+/// Render confirmed findings and their processed recommendations as a markdown report.
+///
+/// The report lists each finding with its occurrence count, followed by the
+/// recommendations grouped by `SetupCategory`. Within each category recommendations
+/// keep the priority ordering produced by `RecommendationEngine::process_recommendations`,
+/// and conflicting recommendations are flagged with a warning line.
+///
+/// The output is plain markdown so it can be pasted into chat tools or saved to a file.
+pub fn render_markdown_report(
+    findings: &[Finding],
+    recommendations: &[ProcessedRecommendation],
+) -> String {
+    let mut report = String::new();
+
+    let _ = writeln!(report, "# Ocypode Setup Report");
+    let _ = writeln!(report);
+
+    let _ = writeln!(report, "## Confirmed Issues");
+    let _ = writeln!(report);
+    if findings.is_empty() {
+        let _ = writeln!(report, "_No confirmed issues._");
+    } else {
+        for finding in findings {
+            let _ = writeln!(
+                report,
+                "- **{}** ({}) - seen {} {}",
+                finding.finding_type,
+                finding.corner_phase,
+                finding.occurrence_count,
+                if finding.occurrence_count == 1 {
+                    "time"
+                } else {
+                    "times"
+                }
+            );
+        }
+    }
+    let _ = writeln!(report);
+
+    let _ = writeln!(report, "## Recommended Changes");
+    if recommendations.is_empty() {
+        let _ = writeln!(report);
+        let _ = writeln!(report, "_No recommendations._");
+        return report;
+    }
+
+    for category in SetupCategory::ALL {
+        let in_category: Vec<_> = recommendations
+            .iter()
+            .filter(|r| r.recommendation.category == category)
+            .collect();
+        if in_category.is_empty() {
+            continue;
+        }
+
+        let _ = writeln!(report);
+        let _ = writeln!(report, "### {}", category);
+        let _ = writeln!(report);
+        for proc_rec in in_category {
+            let rec = &proc_rec.recommendation;
+            let _ = writeln!(
+                report,
+                "- **P{}** {}: {} - {}",
+                rec.priority, rec.parameter, rec.adjustment, rec.description
+            );
+            if proc_rec.has_conflict && !proc_rec.conflicts.is_empty() {
+                let conflict_text = proc_rec
+                    .conflicts
+                    .iter()
+                    .map(|c| format!("{} ({})", c.parameter, c.adjustment))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let _ = writeln!(report, "  - ⚠️ Conflicts with: {}", conflict_text);
+            }
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::setup_assistant::recommendations::SetupRecommendation;
+    use crate::setup_assistant::{CornerPhase, FindingType};
+
+    fn finding(finding_type: FindingType, occurrence_count: usize) -> Finding {
+        Finding {
+            finding_type,
+            occurrence_count,
+            corner_phase: CornerPhase::Entry,
+            last_detected: 0,
+            severity: 0.5,
+            occurrence_timestamps: Default::default(),
+            occurrence_lap_distances: Default::default(),
+            occurrence_causes: Default::default(),
+            cause_tally: Default::default(),
+            corner_type_tally: Default::default(),
+        }
+    }
+
+    fn processed(
+        category: SetupCategory,
+        parameter: &str,
+        adjustment: &str,
+        priority: u8,
+    ) -> ProcessedRecommendation {
+        ProcessedRecommendation {
+            recommendation: SetupRecommendation {
+                category,
+                parameter: parameter.to_string(),
+                adjustment: adjustment.to_string(),
+                description: "test description".to_string(),
+                priority,
+                ease: 3,
+                rationale: None,
+            },
+            conflicts: Vec::new(),
+            has_conflict: false,
+            confidence: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_report_includes_occurrence_counts() {
+        let report = render_markdown_report(
+            &[finding(FindingType::CornerEntryUndersteer, 12)],
+            &[processed(
+                SetupCategory::AntiRollBar,
+                "Front Antirollbar",
+                "Soften",
+                5,
+            )],
+        );
+
+        assert!(report.contains("**Corner Entry Understeer** (Entry) - seen 12 times"));
+        assert!(report.contains("### Antirollbar"));
+        assert!(report.contains("- **P5** Front Antirollbar: Soften - test description"));
+    }
+
+    #[test]
+    fn test_report_groups_by_category() {
+        let report = render_markdown_report(
+            &[finding(FindingType::TireOverheating, 1)],
+            &[
+                processed(SetupCategory::TireManagement, "Tire Pressure", "Reduce", 5),
+                processed(SetupCategory::Aerodynamics, "Rear Wing", "Reduce", 3),
+                processed(SetupCategory::TireManagement, "Brake Ducts", "Open", 4),
+            ],
+        );
+
+        // Categories follow the SetupCategory declaration order
+        let aero_pos = report.find("### Aero").unwrap();
+        let tire_pos = report.find("### Tire Mgmt").unwrap();
+        assert!(aero_pos < tire_pos);
+
+        // Priority order is preserved within a category
+        let pressure_pos = report.find("Tire Pressure").unwrap();
+        let ducts_pos = report.find("Brake Ducts").unwrap();
+        assert!(pressure_pos < ducts_pos);
+        assert!(report.contains("seen 1 time\n"));
+    }
+
+    #[test]
+    fn test_report_marks_conflicts() {
+        let mut soften = processed(SetupCategory::Suspension, "Front Springs", "Soften", 4);
+        let stiffen = processed(SetupCategory::Suspension, "Front Springs", "Stiffen", 3);
+        soften.has_conflict = true;
+        soften.conflicts = vec![stiffen.recommendation.clone()];
+
+        let report = render_markdown_report(&[], &[soften, stiffen]);
+
+        assert!(report.contains("_No confirmed issues._"));
+        assert!(report.contains("⚠️ Conflicts with: Front Springs (Stiffen)"));
+    }
+
+    #[test]
+    fn test_empty_report() {
+        let report = render_markdown_report(&[], &[]);
+        assert!(report.starts_with("# Ocypode Setup Report"));
+        assert!(report.contains("_No recommendations._"));
+    }
+}
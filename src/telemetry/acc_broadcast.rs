@@ -0,0 +1,567 @@
+// The CLI only starts the client for live ACC sessions, which are Windows only
+#![cfg_attr(not(windows), allow(dead_code))]
+
+use std::{
+    collections::HashMap,
+    net::UdpSocket,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use log::{debug, error, info};
+
+use crate::OcypodeError;
+
+/// Address of the ACC broadcasting API with the default `broadcasting.json`
+pub const DEFAULT_BROADCAST_ADDRESS: &str = "127.0.0.1:9000";
+/// Connection password ACC ships with in `broadcasting.json`
+pub const DEFAULT_CONNECTION_PASSWORD: &str = "asd";
+
+const BROADCASTING_PROTOCOL_VERSION: u8 = 4;
+const REALTIME_UPDATE_INTERVAL_MS: i32 = 250;
+const SOCKET_READ_TIMEOUT_MS: u64 = 500;
+/// Time between registration requests until ACC answers, it may not be listening yet
+const REGISTRATION_RETRY_MS: u64 = 1000;
+const MAX_PACKET_SIZE: usize = 32 * 1024;
+
+// Outbound message types
+const REGISTER_COMMAND_APPLICATION: u8 = 1;
+const UNREGISTER_COMMAND_APPLICATION: u8 = 9;
+const REQUEST_ENTRY_LIST: u8 = 10;
+const REQUEST_TRACK_DATA: u8 = 11;
+
+// Inbound message types
+const REGISTRATION_RESULT: u8 = 1;
+const REALTIME_UPDATE: u8 = 2;
+const REALTIME_CAR_UPDATE: u8 = 3;
+const TRACK_DATA: u8 = 5;
+const BROADCASTING_EVENT: u8 = 7;
+
+/// Connection settings for the ACC broadcasting API, they must match the game's
+/// `Documents/Assetto Corsa Competizione/Config/broadcasting.json`.
+#[derive(Clone, Debug)]
+pub struct AccBroadcastConfig {
+    pub address: String,
+    pub display_name: String,
+    pub connection_password: String,
+    pub command_password: String,
+}
+
+impl Default for AccBroadcastConfig {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_BROADCAST_ADDRESS.to_string(),
+            display_name: "Ocypode".to_string(),
+            connection_password: DEFAULT_CONNECTION_PASSWORD.to_string(),
+            command_password: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SessionPhase {
+    #[default]
+    None,
+    Starting,
+    PreFormation,
+    FormationLap,
+    PreSession,
+    Session,
+    SessionOver,
+    PostSession,
+    ResultUi,
+}
+
+impl From<u8> for SessionPhase {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => SessionPhase::Starting,
+            2 => SessionPhase::PreFormation,
+            3 => SessionPhase::FormationLap,
+            4 => SessionPhase::PreSession,
+            5 => SessionPhase::Session,
+            6 => SessionPhase::SessionOver,
+            7 => SessionPhase::PostSession,
+            8 => SessionPhase::ResultUi,
+            _ => SessionPhase::None,
+        }
+    }
+}
+
+/// Race control events reported through broadcasting events
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RaceFlag {
+    Green,
+    Chequered,
+    Accident,
+    /// Penalty for the focused car
+    Penalty,
+}
+
+/// Multiplayer race state for the car focused in ACC, usually the player's car.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RaceStandings {
+    pub position: Option<u16>,
+    pub car_count: usize,
+    /// Estimated time to the car ahead, from the track distance and the current speed
+    pub gap_ahead_ms: Option<f32>,
+    /// Estimated time to the car behind, from the track distance and the current speed
+    pub gap_behind_ms: Option<f32>,
+    pub session_phase: SessionPhase,
+    pub last_flag: Option<RaceFlag>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct CarState {
+    position: u16,
+    laps: u16,
+    spline_position: f32,
+    speed_kmh: u16,
+}
+
+impl CarState {
+    fn lap_progress(&self) -> f32 {
+        self.laps as f32 + self.spline_position
+    }
+}
+
+/// State accumulated from the broadcasting messages
+#[derive(Default)]
+struct BroadcastState {
+    connection_id: Option<i32>,
+    focused_car: Option<u16>,
+    track_meters: Option<i32>,
+    session_phase: SessionPhase,
+    last_flag: Option<RaceFlag>,
+    cars: HashMap<u16, CarState>,
+}
+
+impl BroadcastState {
+    /// Apply an inbound packet, returns the message type when it was understood
+    fn handle_packet(&mut self, packet: &[u8]) -> Option<u8> {
+        let mut reader = PacketReader::new(packet);
+        let message_type = reader.u8()?;
+        match message_type {
+            REGISTRATION_RESULT => {
+                let connection_id = reader.i32()?;
+                let success = reader.u8()? > 0;
+                let _is_readonly = reader.u8()?;
+                let error_message = reader.string()?;
+                if success {
+                    self.connection_id = Some(connection_id);
+                } else {
+                    error!("ACC broadcast: registration refused: {}", error_message);
+                }
+            }
+            REALTIME_UPDATE => {
+                let _event_index = reader.u16()?;
+                let _session_index = reader.u16()?;
+                let _session_type = reader.u8()?;
+                self.session_phase = SessionPhase::from(reader.u8()?);
+                let _session_time = reader.f32()?;
+                let _session_end_time = reader.f32()?;
+                self.focused_car = u16::try_from(reader.i32()?).ok();
+            }
+            REALTIME_CAR_UPDATE => {
+                let car_index = reader.u16()?;
+                let _driver_index = reader.u16()?;
+                let _driver_count = reader.u8()?;
+                let _gear = reader.u8()?;
+                let _world_pos_x = reader.f32()?;
+                let _world_pos_y = reader.f32()?;
+                let _yaw = reader.f32()?;
+                let _car_location = reader.u8()?;
+                let speed_kmh = reader.u16()?;
+                let position = reader.u16()?;
+                let _cup_position = reader.u16()?;
+                let _track_position = reader.u16()?;
+                let spline_position = reader.f32()?;
+                let laps = reader.u16()?;
+                self.cars.insert(
+                    car_index,
+                    CarState {
+                        position,
+                        laps,
+                        spline_position,
+                        speed_kmh,
+                    },
+                );
+            }
+            TRACK_DATA => {
+                let _connection_id = reader.i32()?;
+                let _track_name = reader.string()?;
+                let _track_id = reader.i32()?;
+                self.track_meters = Some(reader.i32()?);
+            }
+            BROADCASTING_EVENT => {
+                let event_type = reader.u8()?;
+                let _message = reader.string()?;
+                let _time_ms = reader.i32()?;
+                let car_id = reader.i32()?;
+                let flag = match event_type {
+                    1 => Some(RaceFlag::Green),
+                    2 => Some(RaceFlag::Chequered),
+                    3 if u16::try_from(car_id).ok() == self.focused_car => Some(RaceFlag::Penalty),
+                    4 => Some(RaceFlag::Accident),
+                    _ => None,
+                };
+                if flag.is_some() {
+                    self.last_flag = flag;
+                }
+            }
+            _ => return None,
+        }
+        Some(message_type)
+    }
+
+    fn standings(&self) -> RaceStandings {
+        let mut standings = RaceStandings {
+            car_count: self.cars.len(),
+            session_phase: self.session_phase,
+            last_flag: self.last_flag,
+            ..RaceStandings::default()
+        };
+        let Some(focused) = self.focused_car.and_then(|id| self.cars.get(&id)) else {
+            return standings;
+        };
+        standings.position = Some(focused.position);
+
+        let gap_to = |position: u16| -> Option<f32> {
+            let other = self.cars.values().find(|c| c.position == position)?;
+            let track_meters = self.track_meters? as f32;
+            // Avoid huge gaps while the focused car is stationary
+            let speed_mps = (focused.speed_kmh as f32 / 3.6).max(1.0);
+            let distance_m = (other.lap_progress() - focused.lap_progress()).abs() * track_meters;
+            Some(distance_m / speed_mps * 1000.)
+        };
+        standings.gap_ahead_ms = focused.position.checked_sub(1).and_then(gap_to);
+        standings.gap_behind_ms = gap_to(focused.position + 1);
+        standings
+    }
+}
+
+/// Little endian reader for broadcasting packets
+struct PacketReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> PacketReader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn bytes<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let bytes = self.buf.get(self.pos..self.pos + N)?.try_into().ok()?;
+        self.pos += N;
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.bytes::<1>().map(|b| b[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.bytes().map(u16::from_le_bytes)
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        self.bytes().map(i32::from_le_bytes)
+    }
+
+    fn f32(&mut self) -> Option<f32> {
+        self.bytes().map(f32::from_le_bytes)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let len = self.u16()? as usize;
+        let bytes = self.buf.get(self.pos..self.pos + len)?;
+        self.pos += len;
+        Some(String::from_utf8_lossy(bytes).into_owned())
+    }
+}
+
+fn write_string(packet: &mut Vec<u8>, value: &str) {
+    packet.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    packet.extend_from_slice(value.as_bytes());
+}
+
+fn register_packet(config: &AccBroadcastConfig) -> Vec<u8> {
+    let mut packet = vec![REGISTER_COMMAND_APPLICATION, BROADCASTING_PROTOCOL_VERSION];
+    write_string(&mut packet, &config.display_name);
+    write_string(&mut packet, &config.connection_password);
+    packet.extend_from_slice(&REALTIME_UPDATE_INTERVAL_MS.to_le_bytes());
+    write_string(&mut packet, &config.command_password);
+    packet
+}
+
+fn connection_packet(message_type: u8, connection_id: i32) -> Vec<u8> {
+    let mut packet = vec![message_type];
+    packet.extend_from_slice(&connection_id.to_le_bytes());
+    packet
+}
+
+/// Client for ACC's UDP broadcasting API.
+///
+/// The broadcasting API exposes multiplayer data such as positions and race control
+/// events that are not available in the physics shared memory. The client runs on its
+/// own thread next to the telemetry producer and keeps the latest `RaceStandings`,
+/// so reading them never blocks the telemetry loop.
+pub struct AccBroadcastClient {
+    standings: Arc<Mutex<RaceStandings>>,
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl AccBroadcastClient {
+    /// Register with the broadcasting API and start listening for updates. The
+    /// registration is sent again every second until ACC accepts it, so the client can
+    /// be started before the game.
+    pub fn start(config: AccBroadcastConfig) -> Result<Self, OcypodeError> {
+        let socket = UdpSocket::bind("0.0.0.0:0")
+            .map_err(|e| OcypodeError::BroadcastClientError { source: e })?;
+        socket
+            .connect(&config.address)
+            .map_err(|e| OcypodeError::BroadcastClientError { source: e })?;
+        socket
+            .set_read_timeout(Some(Duration::from_millis(SOCKET_READ_TIMEOUT_MS)))
+            .map_err(|e| OcypodeError::BroadcastClientError { source: e })?;
+        let register = register_packet(&config);
+        socket
+            .send(&register)
+            .map_err(|e| OcypodeError::BroadcastClientError { source: e })?;
+        info!("ACC broadcast: registering with {}", config.address);
+
+        let standings = Arc::new(Mutex::new(RaceStandings::default()));
+        let running = Arc::new(AtomicBool::new(true));
+        let handle = {
+            let standings = standings.clone();
+            let running = running.clone();
+            thread::spawn(move || receive_loop(socket, register, standings, running))
+        };
+
+        Ok(Self {
+            standings,
+            running,
+            handle: Some(handle),
+        })
+    }
+
+    /// Latest race standings received from ACC
+    pub fn standings(&self) -> RaceStandings {
+        self.standings.lock().map(|s| s.clone()).unwrap_or_default()
+    }
+}
+
+impl Drop for AccBroadcastClient {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn receive_loop(
+    socket: UdpSocket,
+    register: Vec<u8>,
+    standings: Arc<Mutex<RaceStandings>>,
+    running: Arc<AtomicBool>,
+) {
+    let mut state = BroadcastState::default();
+    let mut buf = vec![0u8; MAX_PACKET_SIZE];
+    let mut registered_at = Instant::now();
+
+    while running.load(Ordering::Relaxed) {
+        if state.connection_id.is_none()
+            && registered_at.elapsed() >= Duration::from_millis(REGISTRATION_RETRY_MS)
+        {
+            debug!("ACC broadcast: no registration result yet, registering again");
+            if let Err(e) = socket.send(&register) {
+                debug!("ACC broadcast: could not send registration: {}", e);
+            }
+            registered_at = Instant::now();
+        }
+
+        let len = match socket.recv(&mut buf) {
+            Ok(len) => len,
+            // Timeouts let us check the running flag
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                ) =>
+            {
+                continue;
+            }
+            // ACC is not started yet and the port refuses the registration, wait as long
+            // as a timeout would before trying again
+            Err(_) => {
+                thread::sleep(Duration::from_millis(SOCKET_READ_TIMEOUT_MS));
+                continue;
+            }
+        };
+
+        let was_registered = state.connection_id.is_some();
+        match state.handle_packet(&buf[..len]) {
+            Some(REGISTRATION_RESULT) if !was_registered => {
+                if let Some(connection_id) = state.connection_id {
+                    info!(
+                        "ACC broadcast: registered with connection id {}",
+                        connection_id
+                    );
+                    for message_type in [REQUEST_ENTRY_LIST, REQUEST_TRACK_DATA] {
+                        if let Err(e) = socket.send(&connection_packet(message_type, connection_id))
+                        {
+                            error!("ACC broadcast: could not send request: {}", e);
+                        }
+                    }
+                }
+            }
+            Some(_) => {}
+            None => debug!("ACC broadcast: ignoring packet of {} bytes", len),
+        }
+
+        let latest = state.standings();
+        if let Ok(mut current) = standings.lock() {
+            if current.position != latest.position || current.last_flag != latest.last_flag {
+                info!(
+                    "ACC broadcast: position {:?} of {}, flag {:?}",
+                    latest.position, latest.car_count, latest.last_flag
+                );
+            }
+            *current = latest;
+        }
+    }
+
+    if let Some(connection_id) = state.connection_id {
+        let _ = socket.send(&connection_packet(
+            UNREGISTER_COMMAND_APPLICATION,
+            connection_id,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn realtime_update(focused_car: i32, phase: u8) -> Vec<u8> {
+        let mut packet = vec![REALTIME_UPDATE];
+        packet.extend_from_slice(&1u16.to_le_bytes());
+        packet.extend_from_slice(&1u16.to_le_bytes());
+        packet.push(10);
+        packet.push(phase);
+        packet.extend_from_slice(&0f32.to_le_bytes());
+        packet.extend_from_slice(&0f32.to_le_bytes());
+        packet.extend_from_slice(&focused_car.to_le_bytes());
+        packet
+    }
+
+    fn car_update(car_index: u16, position: u16, laps: u16, spline: f32, kmh: u16) -> Vec<u8> {
+        let mut packet = vec![REALTIME_CAR_UPDATE];
+        packet.extend_from_slice(&car_index.to_le_bytes());
+        packet.extend_from_slice(&0u16.to_le_bytes());
+        packet.push(1);
+        packet.push(4);
+        for _ in 0..3 {
+            packet.extend_from_slice(&0f32.to_le_bytes());
+        }
+        packet.push(1);
+        packet.extend_from_slice(&kmh.to_le_bytes());
+        packet.extend_from_slice(&position.to_le_bytes());
+        packet.extend_from_slice(&position.to_le_bytes());
+        packet.extend_from_slice(&position.to_le_bytes());
+        packet.extend_from_slice(&spline.to_le_bytes());
+        packet.extend_from_slice(&laps.to_le_bytes());
+        packet
+    }
+
+    fn track_data(track_meters: i32) -> Vec<u8> {
+        let mut packet = vec![TRACK_DATA];
+        packet.extend_from_slice(&1i32.to_le_bytes());
+        write_string(&mut packet, "spa");
+        packet.extend_from_slice(&5i32.to_le_bytes());
+        packet.extend_from_slice(&track_meters.to_le_bytes());
+        packet
+    }
+
+    #[test]
+    fn test_register_packet_layout() {
+        let packet = register_packet(&AccBroadcastConfig::default());
+        let mut reader = PacketReader::new(&packet);
+        assert_eq!(reader.u8(), Some(REGISTER_COMMAND_APPLICATION));
+        assert_eq!(reader.u8(), Some(BROADCASTING_PROTOCOL_VERSION));
+        assert_eq!(reader.string().as_deref(), Some("Ocypode"));
+        assert_eq!(
+            reader.string().as_deref(),
+            Some(DEFAULT_CONNECTION_PASSWORD)
+        );
+        assert_eq!(reader.i32(), Some(REALTIME_UPDATE_INTERVAL_MS));
+        assert_eq!(reader.string().as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_registration_is_retried_until_acc_answers() {
+        let acc = UdpSocket::bind("127.0.0.1:0").unwrap();
+        acc.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        let client = AccBroadcastClient::start(AccBroadcastConfig {
+            address: acc.local_addr().unwrap().to_string(),
+            ..AccBroadcastConfig::default()
+        })
+        .unwrap();
+
+        // nothing answers the first request, as when the game is not running yet
+        let mut buf = [0u8; 1024];
+        for _ in 0..2 {
+            acc.recv(&mut buf).unwrap();
+            assert_eq!(buf[0], REGISTER_COMMAND_APPLICATION);
+        }
+        drop(client);
+    }
+
+    #[test]
+    fn test_standings_with_gaps() {
+        let mut state = BroadcastState::default();
+        state.handle_packet(&track_data(7000));
+        state.handle_packet(&realtime_update(2, 5));
+        state.handle_packet(&car_update(1, 1, 3, 0.6, 180));
+        state.handle_packet(&car_update(2, 2, 3, 0.5, 180));
+        state.handle_packet(&car_update(3, 3, 3, 0.45, 180));
+
+        let standings = state.standings();
+
+        assert_eq!(standings.position, Some(2));
+        assert_eq!(standings.car_count, 3);
+        assert_eq!(standings.session_phase, SessionPhase::Session);
+        // 700m ahead at 50m/s
+        assert!((standings.gap_ahead_ms.unwrap() - 14_000.).abs() < 1.);
+        // 350m behind at 50m/s
+        assert!((standings.gap_behind_ms.unwrap() - 7_000.).abs() < 1.);
+    }
+
+    #[test]
+    fn test_leader_has_no_gap_ahead() {
+        let mut state = BroadcastState::default();
+        state.handle_packet(&track_data(7000));
+        state.handle_packet(&realtime_update(1, 5));
+        state.handle_packet(&car_update(1, 1, 3, 0.6, 180));
+
+        let standings = state.standings();
+        assert_eq!(standings.position, Some(1));
+        assert_eq!(standings.gap_ahead_ms, None);
+        assert_eq!(standings.gap_behind_ms, None);
+    }
+
+    #[test]
+    fn test_truncated_packet_ignored() {
+        let mut state = BroadcastState::default();
+        let packet = car_update(1, 1, 3, 0.6, 180);
+        assert_eq!(state.handle_packet(&packet[..10]), None);
+        assert!(state.cars.is_empty());
+    }
+}
@@ -0,0 +1,477 @@
+use std::{collections::HashSet, fmt::Display, path::PathBuf};
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::OcypodeError;
+
+use super::{
+    DEFAULT_STEERING_FREE_PLAY_PCT, DEFAULT_STEERING_LINEARITY, DEFAULT_YAW_RATE_SMOOTHING_SAMPLES,
+    SessionInfo, SteeringFreePlay, TelemetryAnalyzer, TelemetryData,
+    bottoming_out_analyzer::BottomingOutAnalyzer,
+    brake_lock_analyzer::BrakeLockAnalyzer,
+    camber_analyzer::CamberAnalyzer,
+    clutch_analyzer::ClutchAnalyzer,
+    coasting::CoastingDetection,
+    corners::CornerDetection,
+    downshift_instability_analyzer::DownshiftInstabilityAnalyzer,
+    electronics_intervention_analyzer::ElectronicsInterventionAnalyzer,
+    entry_oversteer_analyzer::EntryOversteerAnalyzer,
+    entry_steering_analyzer::EntrySteeringAnalyzer,
+    exit_throttle_lift_analyzer::ExitThrottleLiftAnalyzer,
+    kerb_strike_analyzer::KerbStrikeAnalyzer,
+    load_transfer_analyzer::{
+        DEFAULT_ABRUPT_LOAD_TRANSFER_MS, DEFAULT_SLOW_LOAD_TRANSFER_MS, LoadTransferAnalyzer,
+    },
+    mid_corner_analyzer::MidCornerAnalyzer,
+    over_rev_analyzer::{DEFAULT_OVER_REV_MARGIN_RPM, OverRevAnalyzer},
+    pedal_overlap_analyzer::{
+        DEFAULT_PEDAL_OVERLAP_MIN_DURATION_MS, DEFAULT_PEDAL_OVERLAP_MIN_PCT, PedalOverlapAnalyzer,
+    },
+    scrub_analyzer::{SCRUB_TEMP_THRESHOLD, ScrubAnalyzer},
+    shift_point_advisor::{DEFAULT_SHIFT_RPM_TOLERANCE, ShiftPointAdvisor},
+    short_shifting_analyzer::{DEFAULT_SHORT_SHIFT_SENSITIVITY, ShortShiftingAnalyzer},
+    slip_analyzer::{MIN_SLIP_SPEED_LOSS_MPS, STEERING_ANGLE_DEADZONE_RAD, SlipAnalyzer},
+    steering_reversal_analyzer::SteeringReversalAnalyzer,
+    straight_line_stability_analyzer::StraightLineStabilityAnalyzer,
+    throttle_stab_analyzer::ThrottleStabAnalyzer,
+    tire_pressure_analyzer::TirePressureAnalyzer,
+    tire_temperature_analyzer::TireTemperatureAnalyzer,
+    trailbrake_steering_analyzer::{
+        MAX_BRAKE_RELEASE_RATE, MAX_TRAILBRAKING_STEERING_ANGLE, MIN_TRAILBRAKING_PCT,
+        TrailbrakeSteeringAnalyzer,
+    },
+    wheelspin_analyzer::WheelspinAnalyzer,
+};
+
+const ANALYZER_CONFIG_FILE_NAME: &str = "analyzer_config.json";
+
+const MIN_WHEELSPIN_POINTS: usize = 100;
+const SCRUB_WINDOW_SIZE: usize = 100;
+const ENTRY_OVERSTEER_WINDOW_SIZE: usize = 100;
+const ENTRY_OVERSTEER_MIN_POINTS: usize = 50;
+const MID_CORNER_WINDOW_SIZE: usize = 100;
+const MID_CORNER_MIN_POINTS: usize = 50;
+
+/// Analyzers run on every telemetry point, in the order they run.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnalyzerKind {
+    Wheelspin,
+    TrailbrakeSteering,
+    ShortShifting,
+    Slip,
+    Scrub,
+    EntryOversteer,
+    MidCorner,
+    BrakeLock,
+    TireTemperature,
+    BottomingOut,
+    Camber,
+    ElectronicsIntervention,
+    StraightLineStability,
+    Clutch,
+    ShiftPoint,
+    EntrySteering,
+    KerbStrike,
+    ExitThrottleLift,
+    PedalOverlap,
+    LoadTransfer,
+    DownshiftInstability,
+    TirePressure,
+    SteeringReversal,
+    OverRev,
+    ThrottleStab,
+}
+
+impl AnalyzerKind {
+    pub const ALL: [AnalyzerKind; 25] = [
+        AnalyzerKind::Wheelspin,
+        AnalyzerKind::TrailbrakeSteering,
+        AnalyzerKind::ShortShifting,
+        AnalyzerKind::Slip,
+        AnalyzerKind::Scrub,
+        AnalyzerKind::EntryOversteer,
+        AnalyzerKind::MidCorner,
+        AnalyzerKind::BrakeLock,
+        AnalyzerKind::TireTemperature,
+        AnalyzerKind::BottomingOut,
+        AnalyzerKind::Camber,
+        AnalyzerKind::ElectronicsIntervention,
+        AnalyzerKind::StraightLineStability,
+        AnalyzerKind::Clutch,
+        AnalyzerKind::ShiftPoint,
+        AnalyzerKind::EntrySteering,
+        AnalyzerKind::KerbStrike,
+        AnalyzerKind::ExitThrottleLift,
+        AnalyzerKind::PedalOverlap,
+        AnalyzerKind::LoadTransfer,
+        AnalyzerKind::DownshiftInstability,
+        AnalyzerKind::TirePressure,
+        AnalyzerKind::SteeringReversal,
+        AnalyzerKind::OverRev,
+        AnalyzerKind::ThrottleStab,
+    ];
+}
+
+impl Display for AnalyzerKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnalyzerKind::Wheelspin => write!(f, "Wheelspin"),
+            AnalyzerKind::TrailbrakeSteering => write!(f, "Trail braking"),
+            AnalyzerKind::ShortShifting => write!(f, "Short shifting"),
+            AnalyzerKind::Slip => write!(f, "Slip"),
+            AnalyzerKind::Scrub => write!(f, "Scrub"),
+            AnalyzerKind::EntryOversteer => write!(f, "Entry oversteer"),
+            AnalyzerKind::MidCorner => write!(f, "Mid-corner balance"),
+            AnalyzerKind::BrakeLock => write!(f, "Brake lock"),
+            AnalyzerKind::TireTemperature => write!(f, "Tire temperature"),
+            AnalyzerKind::BottomingOut => write!(f, "Bottoming out"),
+            AnalyzerKind::Camber => write!(f, "Camber"),
+            AnalyzerKind::ElectronicsIntervention => write!(f, "ABS/TC intervention"),
+            AnalyzerKind::StraightLineStability => write!(f, "Straight-line stability"),
+            AnalyzerKind::Clutch => write!(f, "Clutch"),
+            AnalyzerKind::ShiftPoint => write!(f, "Shift point"),
+            AnalyzerKind::EntrySteering => write!(f, "Entry steering"),
+            AnalyzerKind::KerbStrike => write!(f, "Kerb strikes"),
+            AnalyzerKind::ExitThrottleLift => write!(f, "Exit throttle lifts"),
+            AnalyzerKind::PedalOverlap => write!(f, "Pedal overlap"),
+            AnalyzerKind::LoadTransfer => write!(f, "Load transfer"),
+            AnalyzerKind::DownshiftInstability => write!(f, "Downshift instability"),
+            AnalyzerKind::TirePressure => write!(f, "Tire pressure"),
+            AnalyzerKind::SteeringReversal => write!(f, "Steering reversals"),
+            AnalyzerKind::OverRev => write!(f, "Over-rev"),
+            AnalyzerKind::ThrottleStab => write!(f, "Throttle stabbing"),
+        }
+    }
+}
+
+/// Detection thresholds of the analyzers that can be tuned without rebuilding.
+///
+/// Defaults are the values the analyzers were calibrated with. A tuned config
+/// exported from the analysis window is loaded by live collection on start.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct AnalyzerConfig {
+    /// Steering input (0.0 to 1.0 of full lock) around the center ignored by slip and scrub
+    pub steering_free_play_pct: f32,
+    /// Exponent applied to the normalized steering input, above 1.0 shrinks small inputs
+    pub steering_linearity: f32,
+    /// Steering angle (rad) below which speed loss is not considered slip
+    pub slip_steering_deadzone_rad: f32,
+    /// Speed loss (m/s) between two points required to report slip
+    pub slip_min_speed_loss_mps: f32,
+    /// Cornering samples collected before scrub is reported
+    pub scrub_min_points: usize,
+    /// Tire temperature rise (°C) above the baseline that indicates scrub (ACC)
+    pub scrub_temp_threshold: f32,
+    /// Yaw rate samples averaged by the scrub and oversteer analyzers, 1 uses the raw signal
+    pub yaw_rate_smoothing_samples: usize,
+    /// Margin (0.1 = 10%) above the learned RPM growth before reporting wheelspin
+    pub wheelspin_rpm_growth_margin: f32,
+    /// Steering angle (rad) above which trail braking is analyzed
+    pub trailbrake_max_steering_angle: f32,
+    /// Brake pressure (0.0 to 1.0) considered trail braking
+    pub trailbrake_min_brake_pct: f32,
+    /// Brake release rate (pedal fraction per second) reported as abrupt
+    pub trailbrake_max_brake_release_rate: f32,
+    /// RPM below the sim shift point reported as a short shift
+    pub short_shift_sensitivity_rpm: f32,
+    /// RPM from the optimal shift point still considered on target
+    pub shift_rpm_tolerance: f32,
+    /// Throttle and brake application (0.0 to 1.0) above which the pedals overlap
+    pub pedal_overlap_min_pct: f32,
+    /// Overlap (ms) shorter than this is a pedal crossover and not reported
+    pub pedal_overlap_min_duration_ms: u64,
+    /// Load transfer (ms) slower than this is reported as lazy
+    pub load_transfer_slow_ms: u64,
+    /// Load transfer (ms) faster than this is reported as abrupt
+    pub load_transfer_abrupt_ms: u64,
+    /// RPM above the max engine RPM tolerated before reporting an over-rev
+    pub over_rev_margin_rpm: f32,
+    /// Combined g that counts as full grip usage, `None` uses the peak observed in the lap
+    pub grip_peak_g: Option<f32>,
+    /// How corners are found for the per-corner readouts, tracks have no corner metadata
+    pub corner_detection: CornerDetection,
+    /// What counts as coasting in the per-lap coasting stats
+    pub coasting: CoastingDetection,
+    /// Analyzers that are not created at all, so they produce no annotations
+    pub disabled_analyzers: HashSet<AnalyzerKind>,
+}
+
+impl Default for AnalyzerConfig {
+    fn default() -> Self {
+        Self {
+            steering_free_play_pct: DEFAULT_STEERING_FREE_PLAY_PCT,
+            steering_linearity: DEFAULT_STEERING_LINEARITY,
+            slip_steering_deadzone_rad: STEERING_ANGLE_DEADZONE_RAD,
+            slip_min_speed_loss_mps: MIN_SLIP_SPEED_LOSS_MPS,
+            scrub_min_points: SCRUB_WINDOW_SIZE,
+            scrub_temp_threshold: SCRUB_TEMP_THRESHOLD,
+            yaw_rate_smoothing_samples: DEFAULT_YAW_RATE_SMOOTHING_SAMPLES,
+            wheelspin_rpm_growth_margin: 0.,
+            trailbrake_max_steering_angle: MAX_TRAILBRAKING_STEERING_ANGLE,
+            trailbrake_min_brake_pct: MIN_TRAILBRAKING_PCT,
+            trailbrake_max_brake_release_rate: MAX_BRAKE_RELEASE_RATE,
+            short_shift_sensitivity_rpm: DEFAULT_SHORT_SHIFT_SENSITIVITY,
+            shift_rpm_tolerance: DEFAULT_SHIFT_RPM_TOLERANCE,
+            pedal_overlap_min_pct: DEFAULT_PEDAL_OVERLAP_MIN_PCT,
+            pedal_overlap_min_duration_ms: DEFAULT_PEDAL_OVERLAP_MIN_DURATION_MS,
+            load_transfer_slow_ms: DEFAULT_SLOW_LOAD_TRANSFER_MS,
+            load_transfer_abrupt_ms: DEFAULT_ABRUPT_LOAD_TRANSFER_MS,
+            over_rev_margin_rpm: DEFAULT_OVER_REV_MARGIN_RPM,
+            grip_peak_g: None,
+            corner_detection: CornerDetection::default(),
+            coasting: CoastingDetection::default(),
+            disabled_analyzers: HashSet::new(),
+        }
+    }
+}
+
+impl AnalyzerConfig {
+    pub fn is_enabled(&self, kind: AnalyzerKind) -> bool {
+        !self.disabled_analyzers.contains(&kind)
+    }
+
+    pub fn set_enabled(&mut self, kind: AnalyzerKind, enabled: bool) {
+        if enabled {
+            self.disabled_analyzers.remove(&kind);
+        } else {
+            self.disabled_analyzers.insert(kind);
+        }
+    }
+
+    /// A fresh set of every enabled analyzer, configured with these thresholds.
+    pub(crate) fn analyzers(&self) -> Vec<Box<dyn TelemetryAnalyzer>> {
+        AnalyzerKind::ALL
+            .into_iter()
+            .filter(|kind| self.runs(*kind))
+            .map(|kind| self.analyzer(kind))
+            .collect()
+    }
+
+    /// Whether `kind` is enabled and not covered by another enabled analyzer. The shift
+    /// point advisor reports short shifts as under-revving upshifts, so the short
+    /// shifting analyzer only runs when the advisor is disabled.
+    fn runs(&self, kind: AnalyzerKind) -> bool {
+        self.is_enabled(kind)
+            && !(kind == AnalyzerKind::ShortShifting && self.is_enabled(AnalyzerKind::ShiftPoint))
+    }
+
+    fn analyzer(&self, kind: AnalyzerKind) -> Box<dyn TelemetryAnalyzer> {
+        match kind {
+            AnalyzerKind::Wheelspin => Box::new(
+                WheelspinAnalyzer::<MIN_WHEELSPIN_POINTS>::with_rpm_growth_margin(
+                    self.wheelspin_rpm_growth_margin,
+                ),
+            ),
+            AnalyzerKind::TrailbrakeSteering => Box::new(TrailbrakeSteeringAnalyzer::new(
+                self.trailbrake_max_steering_angle,
+                self.trailbrake_min_brake_pct,
+                self.trailbrake_max_brake_release_rate,
+            )),
+            AnalyzerKind::ShortShifting => {
+                Box::new(ShortShiftingAnalyzer::new(self.short_shift_sensitivity_rpm))
+            }
+            AnalyzerKind::Slip => Box::new(SlipAnalyzer::new(
+                self.slip_steering_deadzone_rad,
+                self.slip_min_speed_loss_mps,
+                self.steering_free_play(),
+            )),
+            // TODO: The maximum number of points should be dynamic based on the length of the track
+            AnalyzerKind::Scrub => Box::new(ScrubAnalyzer::<SCRUB_WINDOW_SIZE>::with_thresholds(
+                self.scrub_min_points,
+                self.scrub_temp_threshold,
+                self.steering_free_play(),
+                self.yaw_rate_smoothing_samples,
+            )),
+            AnalyzerKind::EntryOversteer => Box::new(EntryOversteerAnalyzer::<
+                ENTRY_OVERSTEER_WINDOW_SIZE,
+            >::with_yaw_rate_smoothing(
+                ENTRY_OVERSTEER_MIN_POINTS,
+                self.yaw_rate_smoothing_samples,
+            )),
+            AnalyzerKind::MidCorner => Box::new(
+                MidCornerAnalyzer::<MID_CORNER_WINDOW_SIZE>::with_yaw_rate_smoothing(
+                    MID_CORNER_MIN_POINTS,
+                    self.yaw_rate_smoothing_samples,
+                ),
+            ),
+            AnalyzerKind::BrakeLock => Box::new(BrakeLockAnalyzer::new()),
+            AnalyzerKind::TireTemperature => Box::new(TireTemperatureAnalyzer::new()),
+            AnalyzerKind::BottomingOut => Box::new(BottomingOutAnalyzer::new()),
+            AnalyzerKind::Camber => Box::new(CamberAnalyzer::new()),
+            AnalyzerKind::ElectronicsIntervention => Box::new(
+                ElectronicsInterventionAnalyzer::new(self.steering_free_play()),
+            ),
+            AnalyzerKind::StraightLineStability => Box::new(StraightLineStabilityAnalyzer::new()),
+            AnalyzerKind::Clutch => Box::new(ClutchAnalyzer::new()),
+            AnalyzerKind::ShiftPoint => Box::new(ShiftPointAdvisor::new(self.shift_rpm_tolerance)),
+            AnalyzerKind::EntrySteering => Box::new(EntrySteeringAnalyzer::new()),
+            AnalyzerKind::KerbStrike => Box::new(KerbStrikeAnalyzer::new()),
+            AnalyzerKind::ExitThrottleLift => Box::new(ExitThrottleLiftAnalyzer::new()),
+            AnalyzerKind::PedalOverlap => Box::new(PedalOverlapAnalyzer::new(
+                self.pedal_overlap_min_pct,
+                self.pedal_overlap_min_duration_ms,
+            )),
+            AnalyzerKind::LoadTransfer => Box::new(LoadTransferAnalyzer::new(
+                self.load_transfer_slow_ms,
+                self.load_transfer_abrupt_ms,
+            )),
+            AnalyzerKind::DownshiftInstability => Box::new(DownshiftInstabilityAnalyzer::new()),
+            AnalyzerKind::TirePressure => Box::new(TirePressureAnalyzer::new()),
+            AnalyzerKind::SteeringReversal => Box::new(SteeringReversalAnalyzer::new()),
+            AnalyzerKind::OverRev => Box::new(OverRevAnalyzer::new(self.over_rev_margin_rpm)),
+            AnalyzerKind::ThrottleStab => Box::new(ThrottleStabAnalyzer::new()),
+        }
+    }
+
+    fn steering_free_play(&self) -> SteeringFreePlay {
+        SteeringFreePlay {
+            free_play_pct: self.steering_free_play_pct,
+            linearity: self.steering_linearity,
+        }
+    }
+
+    /// Replace the annotations of recorded points with the output of freshly
+    /// configured analyzers, as if the points had been collected live.
+    pub fn annotate(&self, points: &mut [TelemetryData], session_info: &SessionInfo) {
+        let mut analyzers = self.analyzers();
+        for point in points {
+            point.annotations = analyzers
+                .iter_mut()
+                .flat_map(|analyzer| analyzer.analyze(point, session_info))
+                .collect();
+        }
+    }
+
+    fn config_path() -> Option<PathBuf> {
+        Some(
+            dirs::config_dir()?
+                .join("ocypode")
+                .join(ANALYZER_CONFIG_FILE_NAME),
+        )
+    }
+
+    /// Load the config exported from the analysis window, if there is one.
+    pub fn from_local_file() -> Option<Self> {
+        let file = std::fs::File::open(Self::config_path()?).ok()?;
+        serde_json::from_reader(file)
+            .inspect_err(|e| warn!("Could not parse analyzer config file: {}", e))
+            .ok()
+    }
+
+    /// Save the config next to the app config, returning the path of the file.
+    pub fn save(&self) -> Result<PathBuf, OcypodeError> {
+        let config_path = Self::config_path().ok_or(OcypodeError::NoConfigDir)?;
+        if let Some(parent) = config_path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| OcypodeError::ConfigIOError { source: e })?;
+        }
+
+        let file = std::fs::File::create(&config_path)
+            .map_err(|e| OcypodeError::ConfigIOError { source: e })?;
+        serde_json::to_writer_pretty(file, self)
+            .map_err(|e| OcypodeError::ConfigSerializeError { source: e })?;
+        Ok(config_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::TelemetryAnnotation;
+
+    /// Coasting with light steering and losing speed, slip only if the deadzone allows it
+    fn slipping_points() -> Vec<TelemetryData> {
+        (0..5)
+            .map(|i| TelemetryData {
+                point_no: i,
+                timestamp_ms: i as u128 * 100,
+                speed_mps: Some(40. - i as f32),
+                throttle: Some(0.2),
+                brake: Some(0.),
+                steering_angle_rad: Some(0.08),
+                annotations: vec![TelemetryAnnotation::Scrub {
+                    avg_yaw_rate_change: 0.,
+                    cur_yaw_rate_change: 0.,
+                    is_scrubbing: true,
+                }],
+                ..TelemetryData::default()
+            })
+            .collect()
+    }
+
+    fn slip_count(points: &[TelemetryData]) -> usize {
+        points
+            .iter()
+            .flat_map(|p| &p.annotations)
+            .filter(|a| matches!(a, TelemetryAnnotation::Slip { .. }))
+            .count()
+    }
+
+    #[test]
+    fn test_annotate_uses_thresholds() {
+        let session_info = SessionInfo::default();
+
+        let mut points = slipping_points();
+        AnalyzerConfig::default().annotate(&mut points, &session_info);
+        assert_eq!(slip_count(&points), 0);
+        // recorded annotations are replaced
+        assert!(points.iter().all(|p| p.annotations.is_empty()));
+
+        let tuned = AnalyzerConfig {
+            slip_steering_deadzone_rad: 0.05,
+            ..AnalyzerConfig::default()
+        };
+        let mut points = slipping_points();
+        tuned.annotate(&mut points, &session_info);
+        // the first point has no previous speed to compare to
+        assert_eq!(slip_count(&points), 4);
+    }
+
+    #[test]
+    fn test_disabled_analyzers_are_not_created() {
+        let mut config = AnalyzerConfig::default();
+        // short shifts are reported by the shift point advisor
+        assert_eq!(config.analyzers().len(), AnalyzerKind::ALL.len() - 1);
+
+        config.set_enabled(AnalyzerKind::Slip, false);
+        assert!(!config.is_enabled(AnalyzerKind::Slip));
+        assert_eq!(config.analyzers().len(), AnalyzerKind::ALL.len() - 2);
+
+        config.set_enabled(AnalyzerKind::Slip, true);
+        assert_eq!(config.analyzers().len(), AnalyzerKind::ALL.len() - 1);
+
+        // without the advisor the short shifting analyzer takes over
+        config.set_enabled(AnalyzerKind::ShiftPoint, false);
+        assert!(config.runs(AnalyzerKind::ShortShifting));
+        assert_eq!(config.analyzers().len(), AnalyzerKind::ALL.len() - 1);
+    }
+
+    #[test]
+    fn test_disabled_analyzer_produces_no_annotations() {
+        let session_info = SessionInfo::default();
+        let mut tuned = AnalyzerConfig {
+            slip_steering_deadzone_rad: 0.05,
+            ..AnalyzerConfig::default()
+        };
+        tuned.set_enabled(AnalyzerKind::Slip, false);
+
+        let mut points = slipping_points();
+        tuned.annotate(&mut points, &session_info);
+        assert_eq!(slip_count(&points), 0);
+    }
+
+    #[test]
+    fn test_missing_fields_use_defaults() {
+        let config: AnalyzerConfig =
+            serde_json::from_str(r#"{"slip_min_speed_loss_mps": 1.0}"#).unwrap();
+        assert_eq!(config.slip_min_speed_loss_mps, 1.0);
+        assert_eq!(
+            config.shift_rpm_tolerance,
+            AnalyzerConfig::default().shift_rpm_tolerance
+        );
+        assert_eq!(config.grip_peak_g, None);
+        assert!(config.disabled_analyzers.is_empty());
+    }
+}
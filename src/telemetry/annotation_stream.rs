@@ -0,0 +1,88 @@
+//! Annotations pushed to a consumer as the collector analyzes each point.
+//!
+//! [`collect_telemetry`](super::collect_telemetry) sends every annotated point to its
+//! sinks. Tools that only want to react to what the analyzers detect, such as a live
+//! coaching app, register a sink from [`annotation_sink`] or [`annotation_channel`]
+//! and get one [`AnnotationEvent`] per active annotation, as soon as its point is
+//! analyzed, without polling the points or running the analyzers themselves.
+
+use std::{
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{TelemetryAnnotation, TelemetryData, TelemetryOutput};
+
+/// An active annotation and the point it was raised on.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[allow(dead_code, reason = "library API, unused by the CLI")]
+pub struct AnnotationEvent {
+    pub point_no: usize,
+    pub timestamp_ms: u128,
+    pub lap_number: Option<u32>,
+    pub lap_distance_pct: Option<f32>,
+    pub annotation: TelemetryAnnotation,
+}
+
+#[allow(dead_code, reason = "library API, unused by the CLI")]
+impl AnnotationEvent {
+    /// Events for the active annotations of `point`, in the order the analyzers raised them
+    pub fn from_point(point: &TelemetryData) -> impl Iterator<Item = AnnotationEvent> + '_ {
+        point
+            .annotations
+            .iter()
+            .filter(|annotation| annotation.is_active())
+            .map(|annotation| AnnotationEvent {
+                point_no: point.point_no,
+                timestamp_ms: point.timestamp_ms,
+                lap_number: point.lap_number,
+                lap_distance_pct: point.lap_distance_pct,
+                annotation: annotation.clone(),
+            })
+    }
+}
+
+/// Sink for [`collect_telemetry`](super::collect_telemetry) that calls `on_annotation`
+/// for every active annotation, from a thread of its own. The thread stops when the
+/// collector stops.
+#[allow(dead_code, reason = "library API, unused by the CLI")]
+pub fn annotation_sink(
+    mut on_annotation: impl FnMut(AnnotationEvent) + Send + 'static,
+) -> Sender<TelemetryOutput> {
+    let (outputs_tx, outputs_rx) = mpsc::channel();
+    thread::spawn(move || {
+        for event in annotation_events(outputs_rx) {
+            on_annotation(event);
+        }
+    });
+    outputs_tx
+}
+
+/// Sink for [`collect_telemetry`](super::collect_telemetry) and the receiving end of
+/// the annotations it forwards. Once the receiver is dropped the sink disconnects at
+/// the next annotation, and the collector carries on with its other sinks.
+#[allow(dead_code, reason = "library API, unused by the CLI")]
+pub fn annotation_channel() -> (Sender<TelemetryOutput>, Receiver<AnnotationEvent>) {
+    let (outputs_tx, outputs_rx) = mpsc::channel();
+    let (events_tx, events_rx) = mpsc::channel();
+    thread::spawn(move || {
+        for event in annotation_events(outputs_rx) {
+            if events_tx.send(event).is_err() {
+                break;
+            }
+        }
+    });
+    (outputs_tx, events_rx)
+}
+
+#[allow(dead_code, reason = "library API, unused by the CLI")]
+fn annotation_events(outputs: Receiver<TelemetryOutput>) -> impl Iterator<Item = AnnotationEvent> {
+    outputs.into_iter().flat_map(|output| match output {
+        TelemetryOutput::DataPoint(point) => {
+            AnnotationEvent::from_point(&point).collect::<Vec<_>>()
+        }
+        TelemetryOutput::SessionChange(_) => Vec::new(),
+    })
+}
@@ -5,10 +5,22 @@ use super::{TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
 /// Minimum brake percentage to consider for brake lock detection
 const MIN_BRAKE_PCT: f32 = 0.3;
 
+/// Brake application rate (pedal fraction per second) above which the pedal was stomped
+const STOMP_APPLICATION_RATE: f32 = 10.0;
+
+/// Locks within this time from the start of the braking zone happen during the initial application
+const INITIAL_APPLICATION_MS: u128 = 300;
+
 pub(crate) struct BrakeLockAnalyzer {
     abs_activation_count: usize,
     in_braking_zone: bool,
     prev_brake: f32,
+    prev_timestamp_ms: u128,
+    braking_zone_start_ms: u128,
+    /// Highest brake application rate since the last braking zone ended
+    peak_application_rate: f32,
+    /// Whether the first lock of the current braking zone was caused by the initial application
+    is_initial_application_lock: Option<bool>,
 }
 
 impl BrakeLockAnalyzer {
@@ -17,6 +29,10 @@ impl BrakeLockAnalyzer {
             abs_activation_count: 0,
             in_braking_zone: false,
             prev_brake: 0.0,
+            prev_timestamp_ms: 0,
+            braking_zone_start_ms: 0,
+            peak_application_rate: 0.0,
+            is_initial_application_lock: None,
         }
     }
 }
@@ -34,61 +50,65 @@ impl TelemetryAnalyzer for BrakeLockAnalyzer {
             return output;
         }
 
-        // Extract data from TelemetryData
-        let brake = telemetry.brake.unwrap_or(0.0);
+        // Extract data from TelemetryData. Line pressure is what actually locks the
+        // wheels, the pedal input is only used for sims that do not report it
+        let brake = telemetry
+            .brake_pressure_pct
+            .or(telemetry.brake)
+            .unwrap_or(0.0);
         let is_abs_active = telemetry.is_abs_active.unwrap_or(false);
 
+        // Track how fast the pedal is being applied
+        let elapsed_ms = telemetry
+            .timestamp_ms
+            .saturating_sub(self.prev_timestamp_ms);
+        if elapsed_ms > 0 && brake > self.prev_brake {
+            let application_rate = (brake - self.prev_brake) / (elapsed_ms as f32 / 1000.);
+            self.peak_application_rate = self.peak_application_rate.max(application_rate);
+        }
+
         // Detect braking zone entry and exit
         if brake > MIN_BRAKE_PCT && self.prev_brake <= MIN_BRAKE_PCT {
             // Entering braking zone
             self.in_braking_zone = true;
             self.abs_activation_count = 0;
+            self.braking_zone_start_ms = telemetry.timestamp_ms;
+            self.is_initial_application_lock = None;
         } else if brake <= MIN_BRAKE_PCT && self.prev_brake > MIN_BRAKE_PCT {
             // Exiting braking zone
             self.in_braking_zone = false;
             self.abs_activation_count = 0;
+            self.peak_application_rate = 0.0;
         }
 
         // Track ABS activations during braking
         if self.in_braking_zone && is_abs_active {
             self.abs_activation_count += 1;
 
-            // Detect brake locking when ABS is active
-            // Try to classify as front or rear lock based on tire slip data if available
-            // Note: Currently tire slip data is not available in TelemetryData
-            // So we'll create a general brake lock annotation
-
-            // Check if we have tire info that could indicate slip
-            // (In the future, if tire slip data becomes available, we can use it here)
-            let has_tire_data = telemetry.lf_tire_info.is_some()
-                && telemetry.rf_tire_info.is_some()
-                && telemetry.lr_tire_info.is_some()
-                && telemetry.rr_tire_info.is_some();
-
-            if has_tire_data {
-                // For now, we don't have actual tire slip data in TireInfo
-                // TireInfo only contains temperature data
-                // So we'll create a general annotation without front/rear classification
-                // When tire slip data becomes available, we can enhance this logic
-
-                // Placeholder for future tire slip-based classification
-                // For now, we'll just detect that brake lock occurred
-                output.push(TelemetryAnnotation::FrontBrakeLock {
-                    abs_activation_count: self.abs_activation_count,
-                    is_front_lock: false, // Cannot determine without slip data
-                });
-            } else {
-                // No tire data available, create general brake lock annotation
-                // We'll use FrontBrakeLock with is_front_lock = false to indicate
-                // we detected brake lock but cannot classify it
-                output.push(TelemetryAnnotation::FrontBrakeLock {
-                    abs_activation_count: self.abs_activation_count,
-                    is_front_lock: false,
-                });
-            }
+            // A lock right after a fast application comes from stomping the pedal rather
+            // than from the brake bias. The first lock of the zone decides for the whole zone.
+            let is_initial_application_lock = *self.is_initial_application_lock.get_or_insert(
+                telemetry
+                    .timestamp_ms
+                    .saturating_sub(self.braking_zone_start_ms)
+                    <= INITIAL_APPLICATION_MS
+                    && self.peak_application_rate >= STOMP_APPLICATION_RATE,
+            );
+
+            // TireInfo only contains temperature data, so we cannot tell front and rear
+            // locks apart yet. We use FrontBrakeLock with is_front_lock = false to indicate
+            // we detected brake lock but cannot classify it. When tire slip data becomes
+            // available, we can enhance this logic.
+            output.push(TelemetryAnnotation::FrontBrakeLock {
+                abs_activation_count: self.abs_activation_count,
+                is_front_lock: false,
+                peak_application_rate: self.peak_application_rate,
+                is_initial_application_lock,
+            });
         }
 
         self.prev_brake = brake;
+        self.prev_timestamp_ms = telemetry.timestamp_ms;
         output
     }
 }
@@ -127,6 +147,7 @@ mod tests {
             TelemetryAnnotation::FrontBrakeLock {
                 abs_activation_count,
                 is_front_lock: _,
+                ..
             } => {
                 assert_eq!(*abs_activation_count, 1);
             }
@@ -195,6 +216,7 @@ mod tests {
                 TelemetryAnnotation::FrontBrakeLock {
                     abs_activation_count,
                     is_front_lock: _,
+                    ..
                 } => {
                     assert_eq!(*abs_activation_count, i);
                 }
@@ -258,6 +280,7 @@ mod tests {
             TelemetryAnnotation::FrontBrakeLock {
                 abs_activation_count,
                 is_front_lock: _,
+                ..
             } => {
                 assert_eq!(*abs_activation_count, 1); // Reset to 1
             }
@@ -322,6 +345,156 @@ mod tests {
         assert!(output.is_empty());
     }
 
+    fn brake_point(timestamp_ms: u128, brake: f32, is_abs_active: bool) -> TelemetryData {
+        TelemetryData {
+            timestamp_ms,
+            brake: Some(brake),
+            is_abs_active: Some(is_abs_active),
+            speed_mps: Some(40.),
+            ..TelemetryData::default()
+        }
+    }
+
+    fn run_brake_trace(trace: &[(u128, f32, bool)]) -> Vec<TelemetryAnnotation> {
+        let mut analyzer = BrakeLockAnalyzer::new();
+        let session_info = SessionInfo::default();
+        trace
+            .iter()
+            .flat_map(|(timestamp_ms, brake, is_abs_active)| {
+                analyzer.analyze(
+                    &brake_point(*timestamp_ms, *brake, *is_abs_active),
+                    &session_info,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_stomp_lock_detected_on_sharp_initial_spike() {
+        // Pedal goes from 0 to 95% in 40ms and ABS kicks in straight away
+        let output = run_brake_trace(&[
+            (1000, 0.0, false),
+            (1020, 0.6, false),
+            (1040, 0.95, false),
+            (1060, 0.95, true),
+            (1080, 0.9, true),
+        ]);
+
+        assert_eq!(output.len(), 2);
+        for annotation in &output {
+            match annotation {
+                TelemetryAnnotation::FrontBrakeLock {
+                    peak_application_rate,
+                    is_initial_application_lock,
+                    ..
+                } => {
+                    assert!((*peak_application_rate - 30.).abs() < 0.01);
+                    assert!(*is_initial_application_lock);
+                }
+                _ => panic!("Expected FrontBrakeLock annotation"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_progressive_application_lock_is_sustained() {
+        // Pedal squeezed on over 400ms
+        let output = run_brake_trace(&[
+            (1000, 0.0, false),
+            (1100, 0.25, false),
+            (1200, 0.5, false),
+            (1300, 0.75, false),
+            (1400, 0.95, false),
+            (1500, 0.95, true),
+        ]);
+
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::FrontBrakeLock {
+                peak_application_rate,
+                is_initial_application_lock,
+                ..
+            } => {
+                assert!(*peak_application_rate < STOMP_APPLICATION_RATE);
+                assert!(!*is_initial_application_lock);
+            }
+            _ => panic!("Expected FrontBrakeLock annotation"),
+        }
+    }
+
+    #[test]
+    fn test_late_lock_after_stomp_is_sustained() {
+        // Stomped pedal, but the lock only happens deep into the braking zone
+        let output = run_brake_trace(&[
+            (1000, 0.0, false),
+            (1020, 0.95, false),
+            (1500, 0.95, false),
+            (1520, 0.95, true),
+        ]);
+
+        assert_eq!(output.len(), 1);
+        assert!(matches!(
+            output[0],
+            TelemetryAnnotation::FrontBrakeLock {
+                is_initial_application_lock: false,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_brake_pressure_preferred_over_input() {
+        let mut analyzer = BrakeLockAnalyzer::new();
+        let session_info = SessionInfo::default();
+
+        // Pedal is pressed hard but the line pressure stays below the braking threshold
+        let output: Vec<_> = [(1000, false), (1100, true)]
+            .iter()
+            .flat_map(|(timestamp_ms, is_abs_active)| {
+                let telemetry = TelemetryData {
+                    brake_pressure_pct: Some(0.2),
+                    ..brake_point(*timestamp_ms, 0.9, *is_abs_active)
+                };
+                analyzer.analyze(&telemetry, &session_info)
+            })
+            .collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_stomp_rate_uses_brake_pressure() {
+        let mut analyzer = BrakeLockAnalyzer::new();
+        let session_info = SessionInfo::default();
+
+        // Pedal is stomped, but the pressure builds up over 400ms
+        let output: Vec<_> = [
+            (1000, 0.0, 0.0, false),
+            (1020, 0.95, 0.1, false),
+            (1100, 0.95, 0.35, false),
+            (1200, 0.95, 0.6, false),
+            (1300, 0.95, 0.85, false),
+            (1320, 0.95, 0.9, true),
+        ]
+        .iter()
+        .flat_map(|(timestamp_ms, brake, pressure, is_abs_active)| {
+            let telemetry = TelemetryData {
+                brake_pressure_pct: Some(*pressure),
+                ..brake_point(*timestamp_ms, *brake, *is_abs_active)
+            };
+            analyzer.analyze(&telemetry, &session_info)
+        })
+        .collect();
+
+        assert_eq!(output.len(), 1);
+        assert!(matches!(
+            output[0],
+            TelemetryAnnotation::FrontBrakeLock {
+                is_initial_application_lock: false,
+                ..
+            }
+        ));
+    }
+
     // **Feature: setup-assistant, Property 16: Brake lock detection**
     // **Validates: Requirements 13.1**
     proptest! {
@@ -360,6 +533,7 @@ mod tests {
                 TelemetryAnnotation::FrontBrakeLock {
                     abs_activation_count,
                     is_front_lock: _,
+                    ..
                 } => {
                     prop_assert!(*abs_activation_count >= 1);
                 }
@@ -0,0 +1,236 @@
+use super::{GameSource, TelemetryAnnotation, TelemetryData, TireInfo};
+
+/// Fluent builder for `TelemetryData`, for tests and integrations that only care
+/// about a handful of fields.
+///
+/// Fields that are not set keep their `TelemetryData::default()` value.
+///
+/// ```
+/// use ocypode::TelemetryDataBuilder;
+///
+/// let point = TelemetryDataBuilder::new()
+///     .point_no(42)
+///     .speed_mps(55.0)
+///     .throttle(1.0)
+///     .brake(0.0)
+///     .tire_temp(85.0)
+///     .build();
+///
+/// assert_eq!(point.throttle, Some(1.0));
+/// assert_eq!(point.lf_tire_info.unwrap().middle_surface_temp, 85.0);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[allow(dead_code, reason = "library API, unused by the CLI")]
+pub struct TelemetryDataBuilder {
+    data: TelemetryData,
+}
+
+#[allow(dead_code, reason = "library API, unused by the CLI")]
+impl TelemetryDataBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn point_no(mut self, point_no: usize) -> Self {
+        self.data.point_no = point_no;
+        self
+    }
+
+    pub fn timestamp_ms(mut self, timestamp_ms: u128) -> Self {
+        self.data.timestamp_ms = timestamp_ms;
+        self
+    }
+
+    pub fn game_source(mut self, game_source: GameSource) -> Self {
+        self.data.game_source = game_source;
+        self
+    }
+
+    pub fn gear(mut self, gear: i8) -> Self {
+        self.data.gear = Some(gear);
+        self
+    }
+
+    pub fn speed_mps(mut self, speed_mps: f32) -> Self {
+        self.data.speed_mps = Some(speed_mps);
+        self
+    }
+
+    pub fn engine_rpm(mut self, engine_rpm: f32) -> Self {
+        self.data.engine_rpm = Some(engine_rpm);
+        self
+    }
+
+    pub fn shift_point_rpm(mut self, shift_point_rpm: f32) -> Self {
+        self.data.shift_point_rpm = Some(shift_point_rpm);
+        self
+    }
+
+    /// Throttle pedal position from 0.0 to 1.0
+    pub fn throttle(mut self, throttle: f32) -> Self {
+        self.data.throttle = Some(throttle);
+        self
+    }
+
+    /// Brake pedal position from 0.0 to 1.0
+    pub fn brake(mut self, brake: f32) -> Self {
+        self.data.brake = Some(brake);
+        self
+    }
+
+    /// Clutch pedal position from 0.0 released to 1.0 pressed
+    pub fn clutch(mut self, clutch: f32) -> Self {
+        self.data.clutch = Some(clutch);
+        self
+    }
+
+    /// Steering input from -1.0 to 1.0
+    pub fn steering_pct(mut self, steering_pct: f32) -> Self {
+        self.data.steering_pct = Some(steering_pct);
+        self
+    }
+
+    pub fn steering_angle_rad(mut self, steering_angle_rad: f32) -> Self {
+        self.data.steering_angle_rad = Some(steering_angle_rad);
+        self
+    }
+
+    pub fn lap_number(mut self, lap_number: u32) -> Self {
+        self.data.lap_number = Some(lap_number);
+        self
+    }
+
+    /// Lap distance from 0.0 to 1.0
+    pub fn lap_distance_pct(mut self, lap_distance_pct: f32) -> Self {
+        self.data.lap_distance_pct = Some(lap_distance_pct);
+        self
+    }
+
+    pub fn yaw_rate_rps(mut self, yaw_rate_rps: f32) -> Self {
+        self.data.yaw_rate_rps = Some(yaw_rate_rps);
+        self
+    }
+
+    pub fn lateral_accel_mps2(mut self, lateral_accel_mps2: f32) -> Self {
+        self.data.lateral_accel_mps2 = Some(lateral_accel_mps2);
+        self
+    }
+
+    pub fn is_pit_limiter_engaged(mut self, is_pit_limiter_engaged: bool) -> Self {
+        self.data.is_pit_limiter_engaged = Some(is_pit_limiter_engaged);
+        self
+    }
+
+    /// Same temperature on every surface and carcass sensor of all four tires
+    pub fn tire_temp(self, temp: f32) -> Self {
+        let tire_info = TireInfo {
+            left_carcass_temp: temp,
+            middle_carcass_temp: temp,
+            right_carcass_temp: temp,
+            left_surface_temp: temp,
+            middle_surface_temp: temp,
+            right_surface_temp: temp,
+        };
+        self.tire_info(
+            tire_info.clone(),
+            tire_info.clone(),
+            tire_info.clone(),
+            tire_info,
+        )
+    }
+
+    /// Tire temperatures of the left front, right front, left rear and right rear tires
+    pub fn tire_info(mut self, lf: TireInfo, rf: TireInfo, lr: TireInfo, rr: TireInfo) -> Self {
+        self.data.lf_tire_info = Some(lf);
+        self.data.rf_tire_info = Some(rf);
+        self.data.lr_tire_info = Some(lr);
+        self.data.rr_tire_info = Some(rr);
+        self
+    }
+
+    /// Wheel speeds of the left front, right front, left rear and right rear wheels
+    pub fn wheel_speeds_mps(mut self, lf: f32, rf: f32, lr: f32, rr: f32) -> Self {
+        self.data.lf_wheel_speed_mps = Some(lf);
+        self.data.rf_wheel_speed_mps = Some(rf);
+        self.data.lr_wheel_speed_mps = Some(lr);
+        self.data.rr_wheel_speed_mps = Some(rr);
+        self
+    }
+
+    /// Slip ratios computed by the sim for the left front, right front, left rear and
+    /// right rear wheels
+    pub fn slip_ratios(mut self, lf: f32, rf: f32, lr: f32, rr: f32) -> Self {
+        self.data.lf_slip_ratio = Some(lf);
+        self.data.rf_slip_ratio = Some(rf);
+        self.data.lr_slip_ratio = Some(lr);
+        self.data.rr_slip_ratio = Some(rr);
+        self
+    }
+
+    /// Add an annotation, can be called multiple times
+    pub fn annotation(mut self, annotation: TelemetryAnnotation) -> Self {
+        self.data.annotations.push(annotation);
+        self
+    }
+
+    pub fn build(self) -> TelemetryData {
+        self.data
+    }
+}
+
+#[allow(dead_code, reason = "library API, unused by the CLI")]
+impl TelemetryData {
+    /// Start building a `TelemetryData` from the default values.
+    pub fn builder() -> TelemetryDataBuilder {
+        TelemetryDataBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unset_fields_keep_defaults() {
+        let point = TelemetryDataBuilder::new().throttle(0.5).build();
+
+        assert_eq!(point.throttle, Some(0.5));
+        assert_eq!(point.brake, None);
+        assert_eq!(point.game_source, GameSource::IRacing);
+        assert!(point.annotations.is_empty());
+    }
+
+    #[test]
+    fn test_tire_temp_sets_all_tires() {
+        let point = TelemetryData::builder().tire_temp(90.0).build();
+
+        for tire in [
+            point.lf_tire_info,
+            point.rf_tire_info,
+            point.lr_tire_info,
+            point.rr_tire_info,
+        ] {
+            let tire = tire.unwrap();
+            assert_eq!(tire.left_surface_temp, 90.0);
+            assert_eq!(tire.right_carcass_temp, 90.0);
+        }
+    }
+
+    #[test]
+    fn test_annotations_accumulate() {
+        let point = TelemetryData::builder()
+            .annotation(TelemetryAnnotation::Slip {
+                prev_speed: 40.0,
+                cur_speed: 39.0,
+                is_slip: true,
+                slip_ratio: None,
+            })
+            .annotation(TelemetryAnnotation::TrailbrakeSteering {
+                cur_trailbrake_steering: 0.2,
+                is_excessive_trailbrake_steering: false,
+            })
+            .build();
+
+        assert_eq!(point.annotations.len(), 2);
+    }
+}
@@ -0,0 +1,232 @@
+use simple_moving_average::{SMA, SumTreeSMA};
+
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{TelemetryAnalyzer, TelemetryAnnotation, TelemetryData, TireInfo};
+
+/// Inner minus outer surface temperature (Celsius) below which the outer edge is
+/// doing too much work, meaning the tire runs too little negative camber
+pub(crate) const MIN_INNER_OUTER_SPREAD: f32 = 0.0;
+/// Inner minus outer surface temperature (Celsius) above which the inner edge is
+/// overloaded, meaning the tire runs too much negative camber
+pub(crate) const MAX_INNER_OUTER_SPREAD: f32 = 12.0;
+
+/// Sample every N telemetry points, temperatures change slowly
+const SAMPLE_INTERVAL: usize = 60;
+
+/// Number of samples averaged before a spread is evaluated
+const WINDOW_SIZE: usize = 30;
+
+/// Spread between the inner and outer edge of a tire, positive when the inner edge
+/// is hotter.
+///
+/// `left`/`right` temperatures are reported from the driver's perspective, so the
+/// inner edge of a left-side tire is its right edge and vice versa.
+fn inner_outer_spread(tire: &TireInfo, is_left_side: bool) -> f32 {
+    if is_left_side {
+        tire.right_surface_temp - tire.left_surface_temp
+    } else {
+        tire.left_surface_temp - tire.right_surface_temp
+    }
+}
+
+/// Returns true when the average spread falls outside the ideal camber range.
+pub(crate) fn is_spread_imbalanced(spread: f32) -> bool {
+    !(MIN_INNER_OUTER_SPREAD..=MAX_INNER_OUTER_SPREAD).contains(&spread)
+}
+
+/// Detects camber settings that load one edge of the tires.
+///
+/// A correctly set camber leaves the inner edge slightly hotter than the outer
+/// edge. An outer edge hotter than the inner one means the tire rolls onto its
+/// shoulder in corners (too little negative camber), while a much hotter inner
+/// edge means the tire only uses its inner shoulder (too much negative camber).
+pub(crate) struct CamberAnalyzer {
+    lf_spread: SumTreeSMA<f32, f32, WINDOW_SIZE>,
+    rf_spread: SumTreeSMA<f32, f32, WINDOW_SIZE>,
+    lr_spread: SumTreeSMA<f32, f32, WINDOW_SIZE>,
+    rr_spread: SumTreeSMA<f32, f32, WINDOW_SIZE>,
+    sample_counter: usize,
+}
+
+impl CamberAnalyzer {
+    pub(crate) fn new() -> Self {
+        Self {
+            lf_spread: SumTreeSMA::new(),
+            rf_spread: SumTreeSMA::new(),
+            lr_spread: SumTreeSMA::new(),
+            rr_spread: SumTreeSMA::new(),
+            sample_counter: 0,
+        }
+    }
+}
+
+impl TelemetryAnalyzer for CamberAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        _session_info: &super::SessionInfo,
+    ) -> Vec<super::TelemetryAnnotation> {
+        let mut output = Vec::new();
+
+        if !is_telemetry_point_analyzable(telemetry) {
+            return output;
+        }
+
+        self.sample_counter += 1;
+        if !self.sample_counter.is_multiple_of(SAMPLE_INTERVAL) {
+            return output;
+        }
+
+        let (Some(lf), Some(rf), Some(lr), Some(rr)) = (
+            telemetry.lf_tire_info.as_ref(),
+            telemetry.rf_tire_info.as_ref(),
+            telemetry.lr_tire_info.as_ref(),
+            telemetry.rr_tire_info.as_ref(),
+        ) else {
+            return output;
+        };
+
+        self.lf_spread.add_sample(inner_outer_spread(lf, true));
+        self.rf_spread.add_sample(inner_outer_spread(rf, false));
+        self.lr_spread.add_sample(inner_outer_spread(lr, true));
+        self.rr_spread.add_sample(inner_outer_spread(rr, false));
+
+        if self.lf_spread.get_num_samples() < WINDOW_SIZE {
+            return output;
+        }
+
+        let lf_spread = self.lf_spread.get_average();
+        let rf_spread = self.rf_spread.get_average();
+        let lr_spread = self.lr_spread.get_average();
+        let rr_spread = self.rr_spread.get_average();
+
+        // Camber is set per axle, so judge the average of the two tires on each axle
+        let is_imbalanced = is_spread_imbalanced((lf_spread + rf_spread) / 2.)
+            || is_spread_imbalanced((lr_spread + rr_spread) / 2.);
+
+        if is_imbalanced {
+            output.push(TelemetryAnnotation::CamberImbalance {
+                lf_spread,
+                rf_spread,
+                lr_spread,
+                rr_spread,
+                is_imbalanced,
+            });
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::SessionInfo;
+
+    fn tire(left: f32, middle: f32, right: f32) -> TireInfo {
+        TireInfo {
+            left_carcass_temp: middle,
+            middle_carcass_temp: middle,
+            right_carcass_temp: middle,
+            left_surface_temp: left,
+            middle_surface_temp: middle,
+            right_surface_temp: right,
+        }
+    }
+
+    fn run(analyzer: &mut CamberAnalyzer, telemetry: &TelemetryData) -> Vec<TelemetryAnnotation> {
+        let session_info = SessionInfo::default();
+        let mut output = Vec::new();
+        for _ in 0..SAMPLE_INTERVAL * WINDOW_SIZE {
+            output = analyzer.analyze(telemetry, &session_info);
+        }
+        output
+    }
+
+    fn telemetry(lf: TireInfo, rf: TireInfo, lr: TireInfo, rr: TireInfo) -> TelemetryData {
+        TelemetryData {
+            speed_mps: Some(40.),
+            lf_tire_info: Some(lf),
+            rf_tire_info: Some(rf),
+            lr_tire_info: Some(lr),
+            rr_tire_info: Some(rr),
+            ..TelemetryData::default()
+        }
+    }
+
+    #[test]
+    fn test_outer_hot_front_flagged() {
+        let mut analyzer = CamberAnalyzer::new();
+        // Front outer edges 10C hotter than the inner edges, rears are fine
+        let telemetry = telemetry(
+            tire(95., 88., 85.),
+            tire(85., 88., 95.),
+            tire(82., 85., 88.),
+            tire(88., 85., 82.),
+        );
+
+        let output = run(&mut analyzer, &telemetry);
+
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::CamberImbalance {
+                lf_spread,
+                rf_spread,
+                lr_spread,
+                rr_spread,
+                is_imbalanced,
+            } => {
+                assert!(*is_imbalanced);
+                assert_eq!(*lf_spread, -10.);
+                assert_eq!(*rf_spread, -10.);
+                assert_eq!(*lr_spread, 6.);
+                assert_eq!(*rr_spread, 6.);
+            }
+            _ => panic!("Expected CamberImbalance annotation"),
+        }
+    }
+
+    #[test]
+    fn test_ideal_spread_not_flagged() {
+        let mut analyzer = CamberAnalyzer::new();
+        let ideal = telemetry(
+            tire(82., 85., 88.),
+            tire(88., 85., 82.),
+            tire(82., 85., 88.),
+            tire(88., 85., 82.),
+        );
+        assert!(run(&mut analyzer, &ideal).is_empty());
+    }
+
+    #[test]
+    fn test_inner_hot_rear_flagged() {
+        let mut analyzer = CamberAnalyzer::new();
+        let telemetry = telemetry(
+            tire(82., 85., 88.),
+            tire(88., 85., 82.),
+            tire(75., 85., 95.),
+            tire(95., 85., 75.),
+        );
+
+        let output = run(&mut analyzer, &telemetry);
+
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::CamberImbalance { rr_spread, .. } => {
+                assert!(*rr_spread > MAX_INNER_OUTER_SPREAD);
+            }
+            _ => panic!("Expected CamberImbalance annotation"),
+        }
+    }
+
+    #[test]
+    fn test_missing_tire_data_ignored() {
+        let mut analyzer = CamberAnalyzer::new();
+        let telemetry = TelemetryData {
+            speed_mps: Some(40.),
+            ..TelemetryData::default()
+        };
+        assert!(run(&mut analyzer, &telemetry).is_empty());
+    }
+}
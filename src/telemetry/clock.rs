@@ -0,0 +1,60 @@
+//! Source of the current time for timestamp dependent code.
+//!
+//! Telemetry points are stamped and alerts expire against the wall clock, which makes
+//! that behavior depend on how fast a test runs. Code that needs the current time takes
+//! a [`Clock`], [`SystemClock`] in production and a `ManualClock` moved by hand in
+//! tests.
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub(crate) trait Clock: Send + Sync {
+    /// Milliseconds since the unix epoch
+    fn now_ms(&self) -> u128;
+}
+
+/// The wall clock.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> u128 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+    }
+}
+
+/// Clock that only moves when told to. Clones share the same time, so a test can keep
+/// one and hand the other to the code under test.
+#[cfg(test)]
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ManualClock {
+    now_ms: Arc<AtomicU64>,
+}
+
+#[cfg(test)]
+impl ManualClock {
+    pub(crate) fn new(now_ms: u64) -> Self {
+        Self {
+            now_ms: Arc::new(AtomicU64::new(now_ms)),
+        }
+    }
+
+    pub(crate) fn advance(&self, ms: u64) {
+        self.now_ms.fetch_add(ms, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+impl Clock for ManualClock {
+    fn now_ms(&self) -> u128 {
+        u128::from(self.now_ms.load(Ordering::SeqCst))
+    }
+}
@@ -0,0 +1,292 @@
+use super::{TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+
+/// Clutch pedal position above which the clutch is considered disengaged
+const CLUTCH_DISENGAGED_PCT: f32 = 0.5;
+/// Clutch pedal position below which the clutch is considered fully engaged
+const CLUTCH_ENGAGED_PCT: f32 = 0.1;
+/// Minimum throttle for an engagement to count as a launch or corner exit
+const MIN_LAUNCH_THROTTLE: f32 = 0.3;
+/// Number of points to observe after the clutch engages
+const ENGAGEMENT_WINDOW_POINTS: usize = 10;
+/// RPM drop (as a fraction of the engagement RPM) that indicates the engine bogged down
+const BOG_RPM_DROP_PCT: f32 = 0.3;
+/// Clutch releases faster than this are considered dumped
+const KICK_MAX_RELEASE_MS: u128 = 150;
+/// Increase in the RPM to speed ratio that indicates the driven wheels broke traction
+const KICK_RATIO_INCREASE_PCT: f32 = 0.15;
+/// Minimum speed (m/s) before the RPM to speed ratio is considered meaningful
+const MIN_RATIO_SPEED_MPS: f32 = 2.0;
+
+struct ClutchEngagement {
+    engagement_rpm: f32,
+    release_time_ms: u128,
+    min_rpm: f32,
+    min_rpm_speed_ratio: Option<f32>,
+    max_rpm_speed_ratio: Option<f32>,
+    points_observed: usize,
+}
+
+/// Detects poorly timed clutch engagements on standing starts and corner exits.
+///
+/// When the clutch is released with too few revs the engine bogs down, and when it
+/// is dumped with too many revs the driven wheels break traction (a clutch kick).
+/// The analyzer stays inactive until it sees the clutch pedal used, so cars with
+/// paddle shifters, which always report a clutch position of 0, never trigger it.
+pub(crate) struct ClutchAnalyzer {
+    clutch_seen: bool,
+    prev_clutch: f32,
+    last_disengaged_ms: u128,
+    last_disengaged_rpm: f32,
+    engagement: Option<ClutchEngagement>,
+}
+
+impl ClutchAnalyzer {
+    pub(crate) fn new() -> Self {
+        Self {
+            clutch_seen: false,
+            prev_clutch: 0.0,
+            last_disengaged_ms: 0,
+            last_disengaged_rpm: 0.0,
+            engagement: None,
+        }
+    }
+}
+
+impl TelemetryAnalyzer for ClutchAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        _session_info: &super::SessionInfo,
+    ) -> Vec<super::TelemetryAnnotation> {
+        let mut output = Vec::new();
+
+        // Standing starts happen at zero speed, so only the pit limiter check applies here
+        if telemetry.is_pit_limiter_engaged.unwrap_or(false) {
+            return output;
+        }
+
+        let clutch = telemetry.clutch.unwrap_or(0.0);
+        let rpm = telemetry.engine_rpm.unwrap_or(0.0);
+        let throttle = telemetry.throttle.unwrap_or(0.0);
+        let speed = telemetry.speed_mps.unwrap_or(0.0);
+
+        // Paddle-shift cars never touch the clutch, keep the analyzer disabled for them
+        if clutch > CLUTCH_DISENGAGED_PCT {
+            self.clutch_seen = true;
+        }
+        if !self.clutch_seen {
+            return output;
+        }
+
+        if clutch > CLUTCH_DISENGAGED_PCT {
+            // Clutch pressed, remember the revs the driver is holding
+            self.last_disengaged_ms = telemetry.timestamp_ms;
+            self.last_disengaged_rpm = rpm;
+            self.engagement = None;
+        } else if clutch <= CLUTCH_ENGAGED_PCT
+            && self.prev_clutch > CLUTCH_ENGAGED_PCT
+            && self.last_disengaged_rpm > 0.0
+            && throttle > MIN_LAUNCH_THROTTLE
+        {
+            // Clutch just engaged under throttle, start observing the result
+            self.engagement = Some(ClutchEngagement {
+                engagement_rpm: self.last_disengaged_rpm,
+                release_time_ms: telemetry
+                    .timestamp_ms
+                    .saturating_sub(self.last_disengaged_ms),
+                min_rpm: rpm,
+                min_rpm_speed_ratio: None,
+                max_rpm_speed_ratio: None,
+                points_observed: 0,
+            });
+        }
+
+        if clutch <= CLUTCH_ENGAGED_PCT
+            && let Some(engagement) = self.engagement.as_mut()
+        {
+            if throttle < MIN_LAUNCH_THROTTLE {
+                // Driver lifted, this was not a launch or an exit
+                self.engagement = None;
+            } else {
+                engagement.min_rpm = engagement.min_rpm.min(rpm);
+                if speed > MIN_RATIO_SPEED_MPS {
+                    let ratio = rpm / speed;
+                    engagement.min_rpm_speed_ratio = Some(
+                        engagement
+                            .min_rpm_speed_ratio
+                            .map_or(ratio, |r| r.min(ratio)),
+                    );
+                    engagement.max_rpm_speed_ratio = Some(
+                        engagement
+                            .max_rpm_speed_ratio
+                            .map_or(ratio, |r| r.max(ratio)),
+                    );
+                }
+                engagement.points_observed += 1;
+
+                if engagement.points_observed >= ENGAGEMENT_WINDOW_POINTS {
+                    let rpm_drop = engagement.engagement_rpm - engagement.min_rpm;
+                    let is_bog = rpm_drop > engagement.engagement_rpm * BOG_RPM_DROP_PCT;
+                    let is_clutch_kick = engagement.release_time_ms <= KICK_MAX_RELEASE_MS
+                        && match (
+                            engagement.min_rpm_speed_ratio,
+                            engagement.max_rpm_speed_ratio,
+                        ) {
+                            (Some(min_ratio), Some(max_ratio)) => {
+                                max_ratio > min_ratio * (1.0 + KICK_RATIO_INCREASE_PCT)
+                            }
+                            _ => false,
+                        };
+
+                    if is_bog || is_clutch_kick {
+                        output.push(TelemetryAnnotation::ClutchEngagement {
+                            engagement_rpm: engagement.engagement_rpm,
+                            rpm_drop,
+                            release_time_ms: engagement.release_time_ms as f32,
+                            is_bog,
+                            is_clutch_kick,
+                        });
+                    }
+                    self.engagement = None;
+                }
+            }
+        }
+
+        self.prev_clutch = clutch;
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::{SessionInfo, TelemetryData};
+
+    fn point(
+        timestamp_ms: u128,
+        clutch: f32,
+        rpm: f32,
+        throttle: f32,
+        speed: f32,
+    ) -> TelemetryData {
+        TelemetryData {
+            timestamp_ms,
+            clutch: Some(clutch),
+            engine_rpm: Some(rpm),
+            throttle: Some(throttle),
+            speed_mps: Some(speed),
+            gear: Some(1),
+            ..TelemetryData::default()
+        }
+    }
+
+    fn run(analyzer: &mut ClutchAnalyzer, points: &[TelemetryData]) -> Vec<TelemetryAnnotation> {
+        let session_info = SessionInfo::default();
+        points
+            .iter()
+            .flat_map(|p| analyzer.analyze(p, &session_info))
+            .collect()
+    }
+
+    #[test]
+    fn test_disabled_for_paddle_shift_cars() {
+        let mut analyzer = ClutchAnalyzer::new();
+        // Clutch never leaves 0, revs collapse after launch
+        let mut points = vec![point(0, 0.0, 4000.0, 1.0, 0.0)];
+        for i in 1..=20 {
+            points.push(point(i * 100, 0.0, 1500.0, 1.0, i as f32));
+        }
+        assert!(run(&mut analyzer, &points).is_empty());
+    }
+
+    #[test]
+    fn test_bog_detected_on_standing_start() {
+        let mut analyzer = ClutchAnalyzer::new();
+        let mut points = vec![
+            point(0, 1.0, 2500.0, 0.5, 0.0),
+            point(100, 1.0, 2500.0, 0.5, 0.0),
+            point(300, 0.6, 2400.0, 0.5, 0.0),
+        ];
+        // Clutch engages, engine drops well below the launch revs
+        for i in 0..ENGAGEMENT_WINDOW_POINTS {
+            points.push(point(400 + i as u128 * 100, 0.0, 1200.0, 0.6, 1.0));
+        }
+
+        let output = run(&mut analyzer, &points);
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::ClutchEngagement {
+                engagement_rpm,
+                rpm_drop,
+                is_bog,
+                is_clutch_kick,
+                ..
+            } => {
+                assert!(*is_bog);
+                assert!(!*is_clutch_kick);
+                assert_eq!(*engagement_rpm, 2400.0);
+                assert!(*rpm_drop > 1000.0);
+            }
+            _ => panic!("Expected ClutchEngagement annotation"),
+        }
+    }
+
+    #[test]
+    fn test_clutch_kick_detected() {
+        let mut analyzer = ClutchAnalyzer::new();
+        let mut points = vec![point(0, 1.0, 7000.0, 1.0, 0.0)];
+        // Clutch dumped in a single sample, wheels spin up faster than the car
+        let speeds = [3.0, 4.0, 4.5, 5.0, 5.5, 6.0, 6.5, 7.0, 7.5, 8.0];
+        let rpms = [
+            6000.0, 6500.0, 7000.0, 7000.0, 7000.0, 7000.0, 7000.0, 7000.0, 7000.0, 7000.0,
+        ];
+        for (i, (rpm, speed)) in rpms.iter().zip(speeds.iter()).enumerate() {
+            points.push(point(100 + i as u128 * 100, 0.0, *rpm, 1.0, *speed));
+        }
+
+        let output = run(&mut analyzer, &points);
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::ClutchEngagement {
+                is_clutch_kick,
+                release_time_ms,
+                ..
+            } => {
+                assert!(*is_clutch_kick);
+                assert!(*release_time_ms <= KICK_MAX_RELEASE_MS as f32);
+            }
+            _ => panic!("Expected ClutchEngagement annotation"),
+        }
+    }
+
+    #[test]
+    fn test_clean_engagement_not_flagged() {
+        let mut analyzer = ClutchAnalyzer::new();
+        let mut points = vec![
+            point(0, 1.0, 3500.0, 0.6, 0.0),
+            point(300, 0.5, 3400.0, 0.6, 0.5),
+            point(600, 0.2, 3300.0, 0.7, 1.5),
+        ];
+        // Smooth release, revs build steadily with road speed
+        for i in 0..ENGAGEMENT_WINDOW_POINTS {
+            let rpm = 3000.0 + 100.0 * i as f32;
+            let speed = 3.0 + i as f32;
+            points.push(point(700 + i as u128 * 100, 0.0, rpm, 0.8, speed));
+        }
+        assert!(run(&mut analyzer, &points).is_empty());
+    }
+
+    #[test]
+    fn test_lift_cancels_engagement() {
+        let mut analyzer = ClutchAnalyzer::new();
+        let mut points = vec![
+            point(0, 1.0, 2500.0, 0.5, 0.0),
+            point(100, 0.0, 2000.0, 0.5, 0.5),
+            point(200, 0.0, 1000.0, 0.0, 0.5),
+        ];
+        for i in 0..ENGAGEMENT_WINDOW_POINTS {
+            points.push(point(300 + i as u128 * 100, 0.0, 1000.0, 0.5, 1.0));
+        }
+        assert!(run(&mut analyzer, &points).is_empty());
+    }
+}
@@ -0,0 +1,264 @@
+use serde::{Deserialize, Serialize};
+
+use super::TelemetryData;
+
+/// Throttle position below which the car is considered off throttle
+pub const COASTING_MAX_THROTTLE: f32 = 0.05;
+/// Brake position below which the car is considered off brake
+pub const COASTING_MAX_BRAKE: f32 = 0.05;
+/// Minimum speed (m/s) for coasting to count, excludes standing still and pit stops
+pub const COASTING_MIN_SPEED_MPS: f32 = 5.0;
+/// Coasting shorter than this is a normal brake-to-throttle transition around the apex
+pub const MAX_APEX_TRANSITION_MS: u128 = 400;
+/// Steering input above which a point is considered part of a corner
+pub const CORNER_MIN_STEERING_PCT: f32 = 0.1;
+
+/// Thresholds that decide what counts as coasting, defaults to the constants above.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct CoastingDetection {
+    /// Throttle position below which the car is considered off throttle
+    pub max_throttle: f32,
+    /// Brake position below which the car is considered off brake
+    pub max_brake: f32,
+    /// Minimum speed (m/s) for coasting to count
+    pub min_speed_mps: f32,
+    /// Coasting stretches longer than this (ms) are excessive
+    pub max_apex_transition_ms: u64,
+    /// Steering input above which a point is considered part of a corner
+    pub corner_min_steering_pct: f32,
+}
+
+impl Default for CoastingDetection {
+    fn default() -> Self {
+        Self {
+            max_throttle: COASTING_MAX_THROTTLE,
+            max_brake: COASTING_MAX_BRAKE,
+            min_speed_mps: COASTING_MIN_SPEED_MPS,
+            max_apex_transition_ms: MAX_APEX_TRANSITION_MS as u64,
+            corner_min_steering_pct: CORNER_MIN_STEERING_PCT,
+        }
+    }
+}
+
+impl CoastingDetection {
+    fn is_coasting(&self, point: &TelemetryData) -> bool {
+        point.speed_mps.unwrap_or(0.) > self.min_speed_mps
+            && !point.is_pit_limiter_engaged.unwrap_or(false)
+            && point.throttle.unwrap_or(0.) < self.max_throttle
+            && point.brake.unwrap_or(0.) < self.max_brake
+    }
+}
+
+/// Coasting measured through a single corner
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CornerCoasting {
+    /// Index of the first telemetry point of the corner within the lap
+    pub start_point: usize,
+    /// Index of the last telemetry point of the corner within the lap
+    pub end_point: usize,
+    pub duration_ms: u128,
+    pub coasting_ms: u128,
+    /// Coasting from stretches longer than the apex transition
+    pub excessive_coasting_ms: u128,
+}
+
+impl CornerCoasting {
+    pub fn coasting_pct(&self) -> f32 {
+        pct(self.coasting_ms, self.duration_ms)
+    }
+
+    /// Returns true when the driver coasted for longer than a normal apex transition
+    pub fn is_excessive(&self) -> bool {
+        self.excessive_coasting_ms > 0
+    }
+}
+
+/// Time spent coasting, with neither throttle nor brake applied, over a lap.
+///
+/// Short coasting stretches are expected when switching from brake to throttle
+/// around the apex, so only stretches longer than
+/// `CoastingDetection::max_apex_transition_ms` are counted as excessive.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CoastingStats {
+    pub lap_time_ms: u128,
+    pub coasting_ms: u128,
+    pub excessive_coasting_ms: u128,
+    pub corners: Vec<CornerCoasting>,
+}
+
+impl CoastingStats {
+    pub fn from_lap(points: &[TelemetryData], detection: &CoastingDetection) -> Self {
+        let mut stats = CoastingStats::default();
+        if points.len() < 2 {
+            return stats;
+        }
+
+        // Time until the next point is attributed to the current point
+        let durations: Vec<u128> = points
+            .windows(2)
+            .map(|w| w[1].timestamp_ms.saturating_sub(w[0].timestamp_ms))
+            .chain(std::iter::once(0))
+            .collect();
+        let coasting: Vec<bool> = points
+            .iter()
+            .map(|point| detection.is_coasting(point))
+            .collect();
+
+        // Mark points that belong to a coasting stretch longer than an apex transition
+        let mut excessive = vec![false; points.len()];
+        let mut stretch_start = 0;
+        for i in 0..=points.len() {
+            if i < points.len() && coasting[i] {
+                continue;
+            }
+            let stretch_ms: u128 = durations[stretch_start..i].iter().sum();
+            if stretch_ms > u128::from(detection.max_apex_transition_ms) {
+                excessive[stretch_start..i].fill(true);
+            }
+            stretch_start = i + 1;
+        }
+
+        for i in 0..points.len() {
+            stats.lap_time_ms += durations[i];
+            if coasting[i] {
+                stats.coasting_ms += durations[i];
+            }
+            if excessive[i] {
+                stats.excessive_coasting_ms += durations[i];
+            }
+
+            if points[i].steering_pct.unwrap_or(0.).abs() <= detection.corner_min_steering_pct {
+                continue;
+            }
+            let continues_corner = i > 0
+                && stats
+                    .corners
+                    .last()
+                    .is_some_and(|corner| corner.end_point == i - 1);
+            if !continues_corner {
+                stats.corners.push(CornerCoasting {
+                    start_point: i,
+                    end_point: i,
+                    ..CornerCoasting::default()
+                });
+            }
+            let corner = stats.corners.last_mut().expect("corner was just pushed");
+            corner.end_point = i;
+            corner.duration_ms += durations[i];
+            if coasting[i] {
+                corner.coasting_ms += durations[i];
+            }
+            if excessive[i] {
+                corner.excessive_coasting_ms += durations[i];
+            }
+        }
+
+        stats
+    }
+
+    pub fn coasting_pct(&self) -> f32 {
+        pct(self.coasting_ms, self.lap_time_ms)
+    }
+
+    pub fn excessive_coasting_pct(&self) -> f32 {
+        pct(self.excessive_coasting_ms, self.lap_time_ms)
+    }
+}
+
+fn pct(part_ms: u128, total_ms: u128) -> f32 {
+    if total_ms == 0 {
+        return 0.;
+    }
+    part_ms as f32 / total_ms as f32 * 100.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(timestamp_ms: u128, throttle: f32, brake: f32, steering_pct: f32) -> TelemetryData {
+        TelemetryData {
+            timestamp_ms,
+            throttle: Some(throttle),
+            brake: Some(brake),
+            steering_pct: Some(steering_pct),
+            speed_mps: Some(30.),
+            ..TelemetryData::default()
+        }
+    }
+
+    #[test]
+    fn test_coasting_percentage_per_lap() {
+        // 10 points at 100ms: 2 coasting, apex transition length
+        let points: Vec<_> = (0..10)
+            .map(|i| {
+                let throttle = if i == 4 || i == 5 { 0. } else { 1. };
+                point(i * 100, throttle, 0., 0.)
+            })
+            .collect();
+
+        let stats = CoastingStats::from_lap(&points, &CoastingDetection::default());
+
+        assert_eq!(stats.lap_time_ms, 900);
+        assert_eq!(stats.coasting_ms, 200);
+        assert_eq!(stats.excessive_coasting_ms, 0);
+        assert!((stats.coasting_pct() - 22.22).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_long_mid_corner_coast_is_excessive() {
+        let mut points = vec![point(0, 0., 0.8, 0.)];
+        // Corner with 600ms of coasting before getting back on throttle
+        for i in 1..=6 {
+            points.push(point(i * 100, 0., 0., 0.3));
+        }
+        for i in 7..=9 {
+            points.push(point(i * 100, 0.8, 0., 0.3));
+        }
+        points.push(point(1000, 1., 0., 0.));
+
+        let stats = CoastingStats::from_lap(&points, &CoastingDetection::default());
+
+        assert_eq!(stats.excessive_coasting_ms, 600);
+        assert_eq!(stats.corners.len(), 1);
+        let corner = &stats.corners[0];
+        assert_eq!(corner.start_point, 1);
+        assert_eq!(corner.end_point, 9);
+        assert!(corner.is_excessive());
+        assert!((corner.coasting_pct() - 66.67).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_stationary_car_not_coasting() {
+        let points: Vec<_> = (0..10)
+            .map(|i| TelemetryData {
+                timestamp_ms: i * 100,
+                speed_mps: Some(0.),
+                ..TelemetryData::default()
+            })
+            .collect();
+
+        let stats = CoastingStats::from_lap(&points, &CoastingDetection::default());
+        assert_eq!(stats.coasting_ms, 0);
+        assert!(stats.corners.is_empty());
+    }
+
+    #[test]
+    fn test_longer_apex_transition_is_not_excessive() {
+        let mut points = vec![point(0, 0., 0.8, 0.)];
+        for i in 1..=6 {
+            points.push(point(i * 100, 0., 0., 0.3));
+        }
+        points.push(point(700, 1., 0., 0.));
+        let detection = CoastingDetection {
+            max_apex_transition_ms: 800,
+            ..CoastingDetection::default()
+        };
+
+        let stats = CoastingStats::from_lap(&points, &detection);
+
+        assert_eq!(stats.coasting_ms, 600);
+        assert_eq!(stats.excessive_coasting_ms, 0);
+    }
+}
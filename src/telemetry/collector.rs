@@ -1,5 +1,5 @@
 use std::{
-    sync::mpsc::Sender,
+    sync::mpsc::{SendError, Sender},
     thread,
     time::{Duration, SystemTime},
 };
@@ -7,38 +7,25 @@ use std::{
 use crate::OcypodeError;
 
 use super::{
-    TelemetryAnalyzer, TelemetryAnnotation, TelemetryOutput,
-    bottoming_out_analyzer::BottomingOutAnalyzer,
-    brake_lock_analyzer::BrakeLockAnalyzer,
-    entry_oversteer_analyzer::EntryOversteerAnalyzer,
-    mid_corner_analyzer::MidCornerAnalyzer,
+    SessionInfo, TelemetryAnnotation, TelemetryOutput,
+    analyzer_config::AnalyzerConfig,
     producer::{CONN_RETRY_MAX_WAIT_S, TelemetryProducer},
-    scrub_analyzer::ScrubAnalyzer,
-    short_shifting_analyzer::ShortShiftingAnalyzer,
-    slip_analyzer::SlipAnalyzer,
-    tire_temperature_analyzer::TireTemperatureAnalyzer,
-    trailbrake_steering_analyzer::{
-        MAX_TRAILBRAKING_STEERING_ANGLE, MIN_TRAILBRAKING_PCT, TrailbrakeSteeringAnalyzer,
-    },
-    wheelspin_analyzer::WheelspinAnalyzer,
 };
 
 const REFRESH_RATE_MS: u64 = 100;
-const MIN_WHEELSPIN_POINTS: usize = 100;
 const SESSION_UPDATE_TIME_MS: u128 = 2000;
 
-// Configuration for new analyzers
-const ENTRY_OVERSTEER_WINDOW_SIZE: usize = 100;
-const ENTRY_OVERSTEER_MIN_POINTS: usize = 50;
-const MID_CORNER_WINDOW_SIZE: usize = 100;
-const MID_CORNER_MIN_POINTS: usize = 50;
-
+/// Read telemetry from `producer`, annotate it, and send it to every sink.
+///
+/// Sinks are typically the UI, a file recorder and other consumers, each fed by its own
+/// channel. A sink whose receiver is gone is dropped and collection carries on with the
+/// others. Collection stops with `TelemetryBroadcastError` once no sink is left, or with
+/// the producer error when the sim stops sending data.
 pub fn collect_telemetry(
     mut producer: impl TelemetryProducer,
-    telemetry_sender: Sender<TelemetryOutput>,
-    telemetry_writer_sender: Option<Sender<TelemetryOutput>>,
+    mut sinks: Vec<Sender<TelemetryOutput>>,
 ) -> Result<(), OcypodeError> {
-    use log::{debug, info};
+    use log::{debug, info, warn};
 
     info!("Telemetry collector: Starting producer...");
     producer.start()?;
@@ -47,41 +34,23 @@ pub fn collect_telemetry(
     wait_for_session(&mut producer)?;
     info!("Telemetry collector: Active session detected, beginning data collection...");
 
-    let mut analyzers: Vec<Box<dyn TelemetryAnalyzer>> = vec![
-        // Existing analyzers
-        Box::new(WheelspinAnalyzer::<MIN_WHEELSPIN_POINTS>::new()),
-        Box::new(TrailbrakeSteeringAnalyzer::new(
-            MAX_TRAILBRAKING_STEERING_ANGLE,
-            MIN_TRAILBRAKING_PCT,
-        )),
-        Box::new(ShortShiftingAnalyzer::default()),
-        Box::new(SlipAnalyzer::default()),
-        Box::new(ScrubAnalyzer::<100>::new(100)), // TODO: The maximum number of points should be dynamic based on the length of the track
-        // New analyzers for Setup Assistant
-        Box::new(EntryOversteerAnalyzer::<ENTRY_OVERSTEER_WINDOW_SIZE>::new(
-            ENTRY_OVERSTEER_MIN_POINTS,
-        )),
-        Box::new(MidCornerAnalyzer::<MID_CORNER_WINDOW_SIZE>::new(
-            MID_CORNER_MIN_POINTS,
-        )),
-        Box::new(BrakeLockAnalyzer::new()),
-        Box::new(TireTemperatureAnalyzer::new()),
-        Box::new(BottomingOutAnalyzer::new()),
-    ];
-
-    // if we cannot fetch session info at this point something has gone really wrong.
-    // I'll just let it fail.
+    let analyzer_config = AnalyzerConfig::from_local_file().unwrap_or_default();
+    debug!("Telemetry collector: Analyzer config {:?}", analyzer_config);
+    let mut analyzers = analyzer_config.analyzers();
+
+    // the session was just detected, a failure here is transient so collection carries on
+    // with partial session info until the next periodic check picks up the real one
     let mut last_session_info_check_time = SystemTime::now();
-    let mut last_session_info = producer.session_info().unwrap();
+    let mut last_session_info = session_info_or_default(&mut producer);
 
     info!(
         "Telemetry collector: Sending initial session info (track: {})",
         last_session_info.track_name
     );
-    telemetry_sender.send(TelemetryOutput::SessionChange(last_session_info.clone()))?;
-    if let Some(ref writer_sender) = telemetry_writer_sender {
-        writer_sender.send(TelemetryOutput::SessionChange(last_session_info.clone()))?;
-    }
+    broadcast(
+        &mut sinks,
+        TelemetryOutput::SessionChange(last_session_info.clone()),
+    )?;
 
     info!("Telemetry collector: Entering main collection loop...");
     let mut points_collected = 0;
@@ -103,22 +72,25 @@ pub fn collect_telemetry(
                     || session_info.track_name != last_session_info.track_name;
 
                 if session_changed {
+                    info!(
+                        "Telemetry collector: Session changed (track: {})",
+                        session_info.track_name
+                    );
                     last_session_info = session_info.clone();
-                    telemetry_sender.send(TelemetryOutput::SessionChange(session_info.clone()))?;
-                    if let Some(ref writer_sender) = telemetry_writer_sender {
-                        writer_sender.send(TelemetryOutput::SessionChange(session_info.clone()))?;
-                    }
+                    broadcast(&mut sinks, TelemetryOutput::SessionChange(session_info))?;
                 }
             } else {
                 // we may be changing sessions... let's wait
+                warn!("Telemetry collector: Session lost, waiting for a new session...");
                 wait_for_session(&mut producer)?;
                 continue;
             }
             last_session_info_check_time = SystemTime::now();
         }
 
-        // Get telemetry as TelemetryData
-        let mut telemetry_data = producer.telemetry()?;
+        // Get telemetry as TelemetryData, producers other than the sims can still hand over
+        // non-finite readings so clean them up before they reach the analyzers
+        let mut telemetry_data = producer.telemetry()?.sanitized();
         points_collected += 1;
 
         if points_collected == 1 {
@@ -139,11 +111,45 @@ pub fn collect_telemetry(
             telemetry_data.annotations = annotations;
         }
 
-        // Box the telemetry data once and clone the Box (cheaper than cloning the data)
-        let boxed_data = Box::new(telemetry_data);
-        telemetry_sender.send(TelemetryOutput::DataPoint(boxed_data.clone()))?;
-        if let Some(ref writer_sender) = telemetry_writer_sender {
-            writer_sender.send(TelemetryOutput::DataPoint(boxed_data))?;
+        broadcast(
+            &mut sinks,
+            TelemetryOutput::DataPoint(Box::new(telemetry_data)),
+        )?;
+    }
+}
+
+/// Send `output` to every sink, dropping the sinks whose receiver has gone away.
+/// Fails once no sink is left to send to.
+fn broadcast(
+    sinks: &mut Vec<Sender<TelemetryOutput>>,
+    output: TelemetryOutput,
+) -> Result<(), OcypodeError> {
+    sinks.retain(|sink| match sink.send(output.clone()) {
+        Ok(()) => true,
+        Err(_) => {
+            log::info!("Telemetry collector: A telemetry sink disconnected, dropping it");
+            false
+        }
+    });
+    if sinks.is_empty() {
+        return Err(SendError(output).into());
+    }
+    Ok(())
+}
+
+/// Current session info, or the defaults for the producer's game when it cannot be fetched.
+fn session_info_or_default(producer: &mut impl TelemetryProducer) -> SessionInfo {
+    match producer.session_info() {
+        Ok(session_info) => session_info,
+        Err(e) => {
+            log::warn!(
+                "Telemetry collector: {}, continuing with default session info",
+                e
+            );
+            SessionInfo {
+                game_source: producer.game_source(),
+                ..SessionInfo::default()
+            }
         }
     }
 }
@@ -247,7 +253,7 @@ mod tests {
         mock_producer.max_steering_angle = 720.0;
 
         let handle = thread::spawn(move || {
-            let _ = collect_telemetry(mock_producer, telemetry_sender, Some(writer_sender));
+            let _ = collect_telemetry(mock_producer, vec![telemetry_sender, writer_sender]);
         });
 
         thread::sleep(Duration::from_millis(REFRESH_RATE_MS * 3));
@@ -332,7 +338,7 @@ mod tests {
         mock_producer.max_steering_angle = 720.0;
 
         let handle = thread::spawn(move || {
-            let _ = collect_telemetry(mock_producer, telemetry_sender, None);
+            let _ = collect_telemetry(mock_producer, vec![telemetry_sender]);
         });
 
         // Check if session change was sent
@@ -356,4 +362,120 @@ mod tests {
 
         handle.join().unwrap();
     }
+
+    /// Mock producer whose session info is only available while waiting for the session
+    struct FlakySessionProducer {
+        inner: MockTelemetryProducer,
+        session_info_calls: usize,
+    }
+
+    impl TelemetryProducer for FlakySessionProducer {
+        fn start(&mut self) -> Result<(), OcypodeError> {
+            self.inner.start()
+        }
+
+        fn session_info(&mut self) -> Result<SessionInfo, OcypodeError> {
+            self.session_info_calls += 1;
+            if self.session_info_calls > 1 {
+                return Err(OcypodeError::SessionInfoUnavailable {
+                    game: GameSource::ACC,
+                    reason: "state not available".to_string(),
+                });
+            }
+            self.inner.session_info()
+        }
+
+        fn telemetry(&mut self) -> Result<TelemetryData, OcypodeError> {
+            self.inner.telemetry()
+        }
+
+        fn game_source(&self) -> GameSource {
+            GameSource::ACC
+        }
+    }
+
+    #[test]
+    fn test_collect_telemetry_survives_session_info_error() {
+        let (telemetry_sender, telemetry_receiver) = mpsc::channel();
+
+        let points = (0..3)
+            .map(|i| TelemetryData {
+                point_no: i,
+                timestamp_ms: i as u128 * 100,
+                game_source: GameSource::ACC,
+                speed_mps: Some(50.0),
+                ..Default::default()
+            })
+            .collect();
+        let mut inner = MockTelemetryProducer::from_points(points);
+        inner.track_name = "Monza".to_string();
+        let producer = FlakySessionProducer {
+            inner,
+            session_info_calls: 0,
+        };
+
+        let handle = thread::spawn(move || collect_telemetry(producer, vec![telemetry_sender]));
+
+        match telemetry_receiver.recv().unwrap() {
+            TelemetryOutput::SessionChange(session_info) => {
+                // the fallback, not the track of the mock
+                assert_eq!(session_info.game_source, GameSource::ACC);
+                assert_eq!(session_info.track_name, "Unknown");
+            }
+            other => panic!("Expected SessionChange, got {:?}", other),
+        }
+        for i in 0..3 {
+            match telemetry_receiver.recv().unwrap() {
+                TelemetryOutput::DataPoint(point) => assert_eq!(point.point_no, i),
+                other => panic!("Expected DataPoint, got {:?}", other),
+            }
+        }
+
+        // the mock producer running out of points is what stops the collector
+        assert!(matches!(
+            handle.join().unwrap(),
+            Err(OcypodeError::TelemetryProducerError { .. })
+        ));
+    }
+
+    fn monza_producer() -> MockTelemetryProducer {
+        let points = (0..3)
+            .map(|i| TelemetryData {
+                point_no: i,
+                timestamp_ms: i as u128 * 100,
+                speed_mps: Some(50.0),
+                ..Default::default()
+            })
+            .collect();
+        let mut producer = MockTelemetryProducer::from_points(points);
+        producer.track_name = "Monza".to_string();
+        producer
+    }
+
+    #[test]
+    fn test_disconnected_sink_is_dropped() {
+        let (ui_sender, ui_receiver) = mpsc::channel();
+        let (closed_sender, closed_receiver) = mpsc::channel();
+        drop(closed_receiver);
+
+        // the mock running out of points stops the collector, not the closed sink
+        let result = collect_telemetry(monza_producer(), vec![closed_sender, ui_sender]);
+        assert!(matches!(
+            result,
+            Err(OcypodeError::TelemetryProducerError { .. })
+        ));
+        // the session change and the three points
+        assert_eq!(ui_receiver.try_iter().count(), 4);
+    }
+
+    #[test]
+    fn test_collection_stops_without_sinks() {
+        let (sender, receiver) = mpsc::channel();
+        drop(receiver);
+
+        assert!(matches!(
+            collect_telemetry(monza_producer(), vec![sender]),
+            Err(OcypodeError::TelemetryBroadcastError { .. })
+        ));
+    }
 }
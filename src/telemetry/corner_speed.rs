@@ -0,0 +1,224 @@
+use super::{
+    TelemetryAnnotation, TelemetryData,
+    corners::{CornerDetection, CornerWindow},
+};
+
+/// Apex speed below the reference corner (m/s) that counts as over-slowing, about 7 km/h
+pub const OVER_SLOWING_MIN_DEFICIT_MPS: f32 = 2.0;
+
+/// Lowest speed through a single corner
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CornerMinSpeed {
+    /// Index of the first telemetry point of the corner within the lap
+    pub start_point: usize,
+    /// Index of the last telemetry point of the corner within the lap
+    pub end_point: usize,
+    /// Index of the slowest point of the corner within the lap
+    pub apex_point: usize,
+    pub min_speed_mps: f32,
+    /// Lap distance of the slowest point
+    pub apex_lap_distance_pct: Option<f32>,
+    /// Lap distance covered by the corner, `None` when the lap has no lap distance
+    pub window: Option<CornerWindow>,
+    /// Slip or understeer was detected in the corner
+    pub has_understeer: bool,
+}
+
+/// Minimum speed of a corner compared with the same corner on the reference lap
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CornerSpeedDeficit {
+    pub corner: CornerMinSpeed,
+    pub reference_min_speed_mps: f32,
+}
+
+impl CornerSpeedDeficit {
+    /// Apex speed lost to the reference lap, negative when the corner was faster
+    pub fn deficit_mps(&self) -> f32 {
+        self.reference_min_speed_mps - self.corner.min_speed_mps
+    }
+
+    /// Returns true when the apex was much slower than the reference without the car
+    /// pushing wide, which points at braking too much rather than at understeer
+    pub fn is_over_slowed(&self) -> bool {
+        !self.corner.has_understeer && self.deficit_mps() >= OVER_SLOWING_MIN_DEFICIT_MPS
+    }
+}
+
+/// Minimum corner speeds of a lap against a reference lap, usually the best lap.
+///
+/// Corners are found the same way in both laps and matched by the lap distance of
+/// their slowest point, so both laps need lap distance data. Corners of the lap without
+/// a matching reference corner are left out of `corners`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OverSlowing {
+    pub corners: Vec<CornerSpeedDeficit>,
+}
+
+impl OverSlowing {
+    pub fn from_laps(
+        points: &[TelemetryData],
+        reference: &[TelemetryData],
+        detection: &CornerDetection,
+    ) -> Self {
+        let reference_corners = corner_min_speeds(reference, detection);
+        let corners = corner_min_speeds(points, detection)
+            .into_iter()
+            .filter_map(|corner| {
+                let apex_pct = corner.apex_lap_distance_pct?;
+                let reference_corner = reference_corners
+                    .iter()
+                    .find(|c| c.window.is_some_and(|window| window.contains(apex_pct)))?;
+                Some(CornerSpeedDeficit {
+                    reference_min_speed_mps: reference_corner.min_speed_mps,
+                    corner,
+                })
+            })
+            .collect();
+        OverSlowing { corners }
+    }
+
+    pub fn over_slowed_corners(&self) -> impl Iterator<Item = &CornerSpeedDeficit> {
+        self.corners.iter().filter(|c| c.is_over_slowed())
+    }
+
+    /// Largest apex speed deficit of the over-slowed corners
+    pub fn max_deficit_mps(&self) -> Option<f32> {
+        self.over_slowed_corners()
+            .map(CornerSpeedDeficit::deficit_mps)
+            .max_by(f32::total_cmp)
+    }
+}
+
+/// Slowest point of every corner of a lap
+pub fn corner_min_speeds(
+    points: &[TelemetryData],
+    detection: &CornerDetection,
+) -> Vec<CornerMinSpeed> {
+    detection
+        .detect(points)
+        .into_iter()
+        .filter_map(|corner| {
+            let corner_points = corner.start_point..=corner.end_point;
+            let (apex_point, min_speed_mps) = corner_points
+                .clone()
+                .filter_map(|p| points[p].speed_mps.map(|speed| (p, speed)))
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))?;
+            Some(CornerMinSpeed {
+                start_point: corner.start_point,
+                end_point: corner.end_point,
+                apex_point,
+                min_speed_mps,
+                apex_lap_distance_pct: points[apex_point].lap_distance_pct,
+                window: corner.window(),
+                has_understeer: points[corner_points].iter().any(has_understeer_annotation),
+            })
+        })
+        .collect()
+}
+
+fn has_understeer_annotation(point: &TelemetryData) -> bool {
+    point.annotations.iter().any(|a| {
+        matches!(
+            a,
+            TelemetryAnnotation::Slip { is_slip: true, .. }
+                | TelemetryAnnotation::Scrub {
+                    is_scrubbing: true,
+                    ..
+                }
+                | TelemetryAnnotation::MidCornerUndersteer {
+                    is_understeer: true,
+                    ..
+                }
+                | TelemetryAnnotation::ExitThrottleLift {
+                    is_understeer_lift: true,
+                    ..
+                }
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A lap with two corners, at 20-30% and 60-70% of the lap, with the given apex speeds
+    fn lap(first_apex_mps: f32, second_apex_mps: f32) -> Vec<TelemetryData> {
+        (0..100)
+            .map(|i| {
+                let lap_distance_pct = i as f32 / 100.;
+                let (steering_pct, speed_mps) = match i {
+                    20..=30 => (0.3, first_apex_mps + (25 - i as i32).abs() as f32),
+                    60..=70 => (-0.3, second_apex_mps + (65 - i as i32).abs() as f32),
+                    _ => (0., 60.),
+                };
+                TelemetryData {
+                    timestamp_ms: i * 100,
+                    speed_mps: Some(speed_mps),
+                    steering_pct: Some(steering_pct),
+                    lap_distance_pct: Some(lap_distance_pct),
+                    ..TelemetryData::default()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_corner_min_speeds() {
+        let corners = corner_min_speeds(&lap(20., 30.), &CornerDetection::default());
+        assert_eq!(corners.len(), 2);
+        assert_eq!(corners[0].start_point, 20);
+        assert_eq!(corners[0].end_point, 30);
+        assert_eq!(corners[0].apex_point, 25);
+        assert_eq!(corners[0].min_speed_mps, 20.);
+        assert_eq!(corners[1].apex_lap_distance_pct, Some(0.65));
+    }
+
+    #[test]
+    fn test_flags_corners_slower_than_the_reference() {
+        let over_slowing =
+            OverSlowing::from_laps(&lap(20., 25.), &lap(23., 26.), &CornerDetection::default());
+
+        assert_eq!(over_slowing.corners.len(), 2);
+        assert!((over_slowing.corners[0].deficit_mps() - 3.).abs() < 1e-5);
+        assert!(over_slowing.corners[0].is_over_slowed());
+        assert!(!over_slowing.corners[1].is_over_slowed());
+        assert_eq!(over_slowing.over_slowed_corners().count(), 1);
+        assert_eq!(over_slowing.max_deficit_mps(), Some(3.));
+    }
+
+    #[test]
+    fn test_understeer_is_not_over_slowing() {
+        let mut points = lap(20., 30.);
+        points[24]
+            .annotations
+            .push(TelemetryAnnotation::MidCornerUndersteer {
+                speed_loss: 2.,
+                is_understeer: true,
+            });
+
+        let over_slowing =
+            OverSlowing::from_laps(&points, &lap(25., 30.), &CornerDetection::default());
+        assert!(over_slowing.corners[0].corner.has_understeer);
+        assert!(!over_slowing.corners[0].is_over_slowed());
+    }
+
+    #[test]
+    fn test_requires_lap_distance() {
+        let without_distance: Vec<_> = lap(20., 30.)
+            .into_iter()
+            .map(|p| TelemetryData {
+                lap_distance_pct: None,
+                ..p
+            })
+            .collect();
+        assert!(
+            OverSlowing::from_laps(
+                &without_distance,
+                &lap(25., 35.),
+                &CornerDetection::default()
+            )
+            .corners
+            .is_empty()
+        );
+    }
+}
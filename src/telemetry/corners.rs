@@ -0,0 +1,265 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    TelemetryData,
+    coasting::{COASTING_MIN_SPEED_MPS, CORNER_MIN_STEERING_PCT},
+    grip_usage::STANDARD_GRAVITY_MPS2,
+};
+
+/// Lateral acceleration (g) above which a point is part of a corner regardless of steering
+pub const DEFAULT_CORNER_MIN_LATERAL_G: f32 = 0.5;
+/// Cornering shorter than this is a steering correction or a kink, not a corner
+pub const DEFAULT_CORNER_MIN_DURATION_MS: u64 = 300;
+/// Corners taken slower than this at the apex (m/s, about 90 km/h) are slow corners
+pub const SLOW_CORNER_MAX_APEX_MPS: f32 = 25.;
+/// Corners taken faster than this at the apex (m/s, about 150 km/h) are fast corners
+pub const FAST_CORNER_MIN_APEX_MPS: f32 = 42.;
+
+/// Speed class of a corner, a car can understeer in slow corners and oversteer in fast
+/// ones and need a different compromise for each.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CornerType {
+    Slow,
+    Medium,
+    Fast,
+}
+
+impl CornerType {
+    pub const ALL: [CornerType; 3] = [CornerType::Slow, CornerType::Medium, CornerType::Fast];
+
+    /// Speed class of a corner from the lowest speed reached in it
+    pub fn from_apex_speed(min_speed_mps: f32) -> Self {
+        if min_speed_mps < SLOW_CORNER_MAX_APEX_MPS {
+            CornerType::Slow
+        } else if min_speed_mps > FAST_CORNER_MIN_APEX_MPS {
+            CornerType::Fast
+        } else {
+            CornerType::Medium
+        }
+    }
+}
+
+impl std::fmt::Display for CornerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CornerType::Slow => write!(f, "Slow"),
+            CornerType::Medium => write!(f, "Medium"),
+            CornerType::Fast => write!(f, "Fast"),
+        }
+    }
+}
+
+/// How corners are found in a lap when the track has no corner metadata.
+///
+/// A point is cornering when the steering input or the lateral acceleration is above
+/// its threshold, and a corner is a run of cornering points lasting at least
+/// `min_duration_ms`. Lateral acceleration comes from the sim when it reports it, and
+/// from speed times yaw rate otherwise.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct CornerDetection {
+    /// Steering input (0.0 to 1.0 of full lock) above which a point is cornering
+    pub min_steering_pct: f32,
+    /// Lateral acceleration (g) above which a point is cornering
+    pub min_lateral_g: f32,
+    /// Shortest run of cornering points (ms) that counts as a corner
+    pub min_duration_ms: u64,
+}
+
+impl Default for CornerDetection {
+    fn default() -> Self {
+        Self {
+            min_steering_pct: CORNER_MIN_STEERING_PCT,
+            min_lateral_g: DEFAULT_CORNER_MIN_LATERAL_G,
+            min_duration_ms: DEFAULT_CORNER_MIN_DURATION_MS,
+        }
+    }
+}
+
+/// A corner found in a lap, usable as a synthetic corner window by per-corner analyses
+/// and as a starting point for real corner annotations.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DetectedCorner {
+    /// Index of the first telemetry point of the corner within the lap
+    pub start_point: usize,
+    /// Index of the last telemetry point of the corner within the lap
+    pub end_point: usize,
+    /// Lap distance of the first point of the corner
+    pub start_lap_distance_pct: Option<f32>,
+    /// Lap distance of the last point of the corner
+    pub end_lap_distance_pct: Option<f32>,
+    pub duration_ms: u128,
+}
+
+impl DetectedCorner {
+    /// Returns true when the lap distance falls within the corner
+    pub fn contains_lap_distance(&self, lap_distance_pct: f32) -> bool {
+        self.window()
+            .is_some_and(|window| window.contains(lap_distance_pct))
+    }
+
+    /// Lap distance window of the corner, `None` when the lap has no lap distance
+    pub fn window(&self) -> Option<CornerWindow> {
+        Some(CornerWindow {
+            start_lap_distance_pct: self.start_lap_distance_pct?,
+            end_lap_distance_pct: self.end_lap_distance_pct?,
+        })
+    }
+}
+
+/// Stretch of the lap a corner covers, the same on every lap of the track, so it can
+/// pick the corner out of laps other than the one it was found in.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CornerWindow {
+    pub start_lap_distance_pct: f32,
+    pub end_lap_distance_pct: f32,
+}
+
+impl CornerWindow {
+    /// Returns true when the lap distance falls within the window
+    pub fn contains(&self, lap_distance_pct: f32) -> bool {
+        self.start_lap_distance_pct <= lap_distance_pct
+            && lap_distance_pct <= self.end_lap_distance_pct
+    }
+}
+
+impl CornerDetection {
+    /// Corners of a lap, in the order they were driven.
+    pub fn detect(&self, points: &[TelemetryData]) -> Vec<DetectedCorner> {
+        let mut corners = Vec::new();
+        let mut i = 0;
+        while i < points.len() {
+            if !self.is_cornering(&points[i]) {
+                i += 1;
+                continue;
+            }
+            let start_point = i;
+            while i + 1 < points.len() && self.is_cornering(&points[i + 1]) {
+                i += 1;
+            }
+            let duration_ms = points[i]
+                .timestamp_ms
+                .saturating_sub(points[start_point].timestamp_ms);
+            if duration_ms >= u128::from(self.min_duration_ms) {
+                corners.push(DetectedCorner {
+                    start_point,
+                    end_point: i,
+                    start_lap_distance_pct: points[start_point].lap_distance_pct,
+                    end_lap_distance_pct: points[i].lap_distance_pct,
+                    duration_ms,
+                });
+            }
+            i += 1;
+        }
+        corners
+    }
+
+    /// Returns true when the steering or the lateral acceleration is above its threshold
+    pub(crate) fn is_cornering(&self, point: &TelemetryData) -> bool {
+        point.steering_pct.unwrap_or(0.).abs() > self.min_steering_pct
+            || lateral_g(point).is_some_and(|g| g.abs() > self.min_lateral_g)
+    }
+}
+
+/// Lateral acceleration (g) from the sim or from speed times yaw rate, `None` when the
+/// car is slower than [`COASTING_MIN_SPEED_MPS`]
+pub(crate) fn lateral_g(point: &TelemetryData) -> Option<f32> {
+    if point.speed_mps.unwrap_or(0.) < COASTING_MIN_SPEED_MPS {
+        return None;
+    }
+    let lateral_mps2 = point
+        .lateral_accel_mps2
+        .or_else(|| Some(point.speed_mps? * point.yaw_rate_rps?))?;
+    Some(lateral_mps2 / STANDARD_GRAVITY_MPS2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 100 point lap at 100ms intervals with three corners: a right hander on steering
+    /// at 10-25%, a left hander at 40-50% and a fast sweeper at 70-85% taken with almost
+    /// no steering, plus a short correction at 60%.
+    fn lap() -> Vec<TelemetryData> {
+        (0..100)
+            .map(|i| {
+                let (steering_pct, lateral_g) = match i {
+                    10..=25 => (0.4, 1.5),
+                    40..=50 => (-0.5, -1.8),
+                    60..=61 => (0.2, 0.1),
+                    70..=85 => (0.05, 1.2),
+                    _ => (0., 0.),
+                };
+                TelemetryData {
+                    timestamp_ms: i * 100,
+                    speed_mps: Some(40.),
+                    steering_pct: Some(steering_pct),
+                    lateral_accel_mps2: Some(lateral_g * STANDARD_GRAVITY_MPS2),
+                    lap_distance_pct: Some(i as f32 / 100.),
+                    ..TelemetryData::default()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_three_corners() {
+        let corners = CornerDetection::default().detect(&lap());
+
+        let windows: Vec<_> = corners
+            .iter()
+            .map(|c| (c.start_point, c.end_point))
+            .collect();
+        assert_eq!(windows, vec![(10, 25), (40, 50), (70, 85)]);
+        assert_eq!(corners[0].duration_ms, 1500);
+        assert_eq!(corners[1].start_lap_distance_pct, Some(0.4));
+        assert_eq!(corners[1].end_lap_distance_pct, Some(0.5));
+        assert!(corners[2].contains_lap_distance(0.8));
+        assert!(!corners[2].contains_lap_distance(0.9));
+        assert_eq!(
+            corners[1].window(),
+            Some(CornerWindow {
+                start_lap_distance_pct: 0.4,
+                end_lap_distance_pct: 0.5
+            })
+        );
+    }
+
+    #[test]
+    fn test_lateral_g_from_yaw_rate() {
+        // no lateral acceleration channel, 40 m/s at 0.3 rad/s is about 1.2 g
+        let points: Vec<_> = lap()
+            .into_iter()
+            .map(|p| TelemetryData {
+                lateral_accel_mps2: None,
+                yaw_rate_rps: Some(if (70..=85).contains(&p.timestamp_ms / 100) {
+                    0.3
+                } else {
+                    0.
+                }),
+                ..p
+            })
+            .collect();
+
+        let corners = CornerDetection::default().detect(&points);
+        assert_eq!(corners.len(), 3);
+        assert_eq!((corners[2].start_point, corners[2].end_point), (70, 85));
+    }
+
+    #[test]
+    fn test_thresholds_are_configurable() {
+        let steering_only = CornerDetection {
+            min_lateral_g: f32::INFINITY,
+            ..CornerDetection::default()
+        };
+        assert_eq!(steering_only.detect(&lap()).len(), 2);
+
+        let long_corners_only = CornerDetection {
+            min_duration_ms: 1200,
+            ..CornerDetection::default()
+        };
+        let corners = long_corners_only.detect(&lap());
+        assert_eq!(corners.len(), 2);
+        assert_eq!(corners[1].start_point, 70);
+    }
+}
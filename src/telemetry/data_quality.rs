@@ -0,0 +1,189 @@
+use super::{GameSource, TelemetryData};
+
+/// Names of the optional `TelemetryData` fields and how to check if a point has them
+macro_rules! optional_fields {
+    ($($field:ident),* $(,)?) => {
+        const OPTIONAL_FIELDS: &[(&str, fn(&TelemetryData) -> bool)] =
+            &[$((stringify!($field), |p| p.$field.is_some())),*];
+    };
+}
+
+optional_fields!(
+    gear,
+    speed_mps,
+    engine_rpm,
+    max_engine_rpm,
+    shift_point_rpm,
+    throttle,
+    brake,
+    brake_pressure_pct,
+    clutch,
+    steering_angle_rad,
+    steering_pct,
+    lap_distance_m,
+    lap_distance_pct,
+    lap_number,
+    world_position_x,
+    world_position_y,
+    world_position_z,
+    world_velocity_x,
+    world_velocity_y,
+    world_velocity_z,
+    track_position_pct,
+    track_sector,
+    last_lap_time_s,
+    best_lap_time_s,
+    is_pit_limiter_engaged,
+    is_in_pit_lane,
+    is_abs_active,
+    is_tc_active,
+    latitude_deg,
+    longitude_deg,
+    lateral_accel_mps2,
+    longitudinal_accel_mps2,
+    pitch_rad,
+    pitch_rate_rps,
+    roll_rad,
+    roll_rate_rps,
+    yaw_rad,
+    yaw_rate_rps,
+    lf_tire_info,
+    rf_tire_info,
+    lr_tire_info,
+    rr_tire_info,
+    lf_wheel_speed_mps,
+    rf_wheel_speed_mps,
+    lr_wheel_speed_mps,
+    rr_wheel_speed_mps,
+    lf_slip_ratio,
+    rf_slip_ratio,
+    lr_slip_ratio,
+    rr_slip_ratio,
+);
+
+/// Share of points that have a value for a single telemetry field
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldCoverage {
+    pub field: &'static str,
+    /// Percentage (0.0 to 100.0) of points where the field is not `None`
+    pub present_pct: f32,
+}
+
+/// Which telemetry fields are actually present in a recording.
+///
+/// Different sims, and different cars within a sim, populate different fields.
+/// Checking the report before an analysis shows which analyzers have the data
+/// they need, for example that tire temperatures are missing from a capture.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DataQualityReport {
+    /// Game source of the first point, `None` for an empty recording
+    pub game_source: Option<GameSource>,
+    pub point_count: usize,
+    /// Coverage of every optional field, in `TelemetryData` declaration order
+    pub fields: Vec<FieldCoverage>,
+}
+
+impl DataQualityReport {
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a TelemetryData>) -> Self {
+        let mut game_source = None;
+        let mut point_count = 0;
+        let mut present_counts = vec![0usize; OPTIONAL_FIELDS.len()];
+
+        for point in points {
+            game_source.get_or_insert(point.game_source);
+            point_count += 1;
+            for (count, (_, is_present)) in present_counts.iter_mut().zip(OPTIONAL_FIELDS) {
+                if is_present(point) {
+                    *count += 1;
+                }
+            }
+        }
+
+        let fields = OPTIONAL_FIELDS
+            .iter()
+            .zip(present_counts)
+            .map(|((field, _), count)| FieldCoverage {
+                field: *field,
+                present_pct: if point_count == 0 {
+                    0.
+                } else {
+                    count as f32 / point_count as f32 * 100.
+                },
+            })
+            .collect();
+
+        Self {
+            game_source,
+            point_count,
+            fields,
+        }
+    }
+
+    /// Fields that are not present in any point
+    pub fn missing_fields(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.fields
+            .iter()
+            .filter(|f| f.present_pct == 0.)
+            .map(|f| f.field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::TireInfo;
+
+    fn coverage(report: &DataQualityReport, field: &str) -> f32 {
+        report
+            .fields
+            .iter()
+            .find(|f| f.field == field)
+            .unwrap()
+            .present_pct
+    }
+
+    #[test]
+    fn test_field_coverage() {
+        let tire = TireInfo {
+            left_carcass_temp: 80.,
+            middle_carcass_temp: 80.,
+            right_carcass_temp: 80.,
+            left_surface_temp: 80.,
+            middle_surface_temp: 80.,
+            right_surface_temp: 80.,
+        };
+        let points: Vec<_> = (0..4)
+            .map(|i| TelemetryData {
+                game_source: GameSource::ACC,
+                speed_mps: Some(30.),
+                throttle: (i % 2 == 0).then_some(1.),
+                lf_tire_info: (i == 0).then(|| tire.clone()),
+                ..TelemetryData::default()
+            })
+            .collect();
+
+        let report = DataQualityReport::from_points(&points);
+
+        assert_eq!(report.game_source, Some(GameSource::ACC));
+        assert_eq!(report.point_count, 4);
+        assert_eq!(report.fields.len(), OPTIONAL_FIELDS.len());
+        assert_eq!(coverage(&report, "speed_mps"), 100.);
+        assert_eq!(coverage(&report, "throttle"), 50.);
+        assert_eq!(coverage(&report, "lf_tire_info"), 25.);
+        assert_eq!(coverage(&report, "brake"), 0.);
+
+        let missing: Vec<_> = report.missing_fields().collect();
+        assert!(missing.contains(&"brake"));
+        assert!(missing.contains(&"rr_tire_info"));
+        assert!(!missing.contains(&"lf_tire_info"));
+    }
+
+    #[test]
+    fn test_empty_recording() {
+        let report = DataQualityReport::from_points(std::iter::empty());
+
+        assert_eq!(report.game_source, None);
+        assert_eq!(report.point_count, 0);
+        assert!(report.fields.iter().all(|f| f.present_pct == 0.));
+    }
+}
@@ -0,0 +1,233 @@
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{SessionInfo, TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+
+/// Minimum brake percentage for a downshift to count as an entry downshift
+const MIN_BRAKE_PCT: f32 = 0.3;
+/// Time after the downshift (ms) the rear of the car is watched
+const DOWNSHIFT_WINDOW_MS: u128 = 600;
+/// Below this speed the yaw rate expected from the lateral acceleration is unreliable
+const MIN_SPEED_MPS: f32 = 10.;
+/// Yaw rate (rad/s) beyond what the lateral acceleration explains that counts as the
+/// rear stepping out
+const MIN_YAW_EXCESS_RPS: f32 = 0.15;
+
+/// Downshift being watched for rear instability
+struct Downshift {
+    from_gear: i8,
+    to_gear: i8,
+    start_ms: u128,
+    rpm_before: Option<f32>,
+    peak_rpm: Option<f32>,
+    peak_yaw_excess_rps: f32,
+}
+
+impl Downshift {
+    fn track(&mut self, telemetry: &TelemetryData) {
+        if let Some(rpm) = telemetry.engine_rpm {
+            self.peak_rpm = Some(self.peak_rpm.map_or(rpm, |peak| peak.max(rpm)));
+        }
+        if let Some(yaw_excess_rps) = yaw_excess_rps(telemetry) {
+            self.peak_yaw_excess_rps = self.peak_yaw_excess_rps.max(yaw_excess_rps);
+        }
+    }
+
+    fn annotation(self) -> TelemetryAnnotation {
+        TelemetryAnnotation::DownshiftInstability {
+            from_gear: self.from_gear,
+            to_gear: self.to_gear,
+            rpm_spike: self
+                .peak_rpm
+                .zip(self.rpm_before)
+                .map_or(0., |(peak, before)| (peak - before).max(0.)),
+            peak_yaw_excess_rps: self.peak_yaw_excess_rps,
+            is_unstable: self.peak_yaw_excess_rps > MIN_YAW_EXCESS_RPS,
+        }
+    }
+}
+
+/// Yaw rate the car has on top of the one its lateral acceleration accounts for. A car
+/// gripping on all four tires yaws at lateral acceleration over speed, anything more is
+/// the rear sliding. Magnitudes are compared so the sims' sign conventions do not matter.
+fn yaw_excess_rps(telemetry: &TelemetryData) -> Option<f32> {
+    let speed_mps = telemetry
+        .speed_mps
+        .filter(|speed| *speed >= MIN_SPEED_MPS)?;
+    let gripping_yaw_rps = telemetry.lateral_accel_mps2? / speed_mps;
+    Some(telemetry.yaw_rate_rps?.abs() - gripping_yaw_rps.abs())
+}
+
+/// Detects the rear stepping out after a downshift on corner entry.
+///
+/// Downshifting without enough of a blip drags the engine speed up through the rear
+/// wheels, which can lock them or break them loose. Every downshift made while braking
+/// opens a window of 600ms in which the RPM spike and the yaw rate beyond what the
+/// lateral acceleration explains are tracked. One annotation is emitted per downshift,
+/// flagged unstable only when the rear actually slid: cars with an automatic blip spike
+/// the RPM just as much, but stay settled and are not flagged.
+pub(crate) struct DownshiftInstabilityAnalyzer {
+    prev_gear: Option<i8>,
+    prev_rpm: Option<f32>,
+    downshift: Option<Downshift>,
+}
+
+impl DownshiftInstabilityAnalyzer {
+    pub(crate) fn new() -> Self {
+        Self {
+            prev_gear: None,
+            prev_rpm: None,
+            downshift: None,
+        }
+    }
+}
+
+impl TelemetryAnalyzer for DownshiftInstabilityAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        _session_info: &SessionInfo,
+    ) -> Vec<TelemetryAnnotation> {
+        let mut output = Vec::new();
+
+        if !is_telemetry_point_analyzable(telemetry) {
+            self.prev_gear = None;
+            self.prev_rpm = None;
+            self.downshift = None;
+            return output;
+        }
+
+        if let Some(downshift) = &self.downshift
+            && telemetry.timestamp_ms.saturating_sub(downshift.start_ms) > DOWNSHIFT_WINDOW_MS
+        {
+            output.extend(self.downshift.take().map(Downshift::annotation));
+        }
+
+        let is_braking = telemetry.brake.unwrap_or(0.) > MIN_BRAKE_PCT;
+        if let (Some(from_gear), Some(to_gear)) = (self.prev_gear, telemetry.gear)
+            && is_braking
+            && to_gear >= 1
+            && to_gear < from_gear
+        {
+            // a quick second downshift ends the window of the first one
+            output.extend(self.downshift.take().map(Downshift::annotation));
+            self.downshift = Some(Downshift {
+                from_gear,
+                to_gear,
+                start_ms: telemetry.timestamp_ms,
+                rpm_before: self.prev_rpm,
+                peak_rpm: None,
+                peak_yaw_excess_rps: 0.,
+            });
+        }
+
+        if let Some(downshift) = self.downshift.as_mut() {
+            downshift.track(telemetry);
+        }
+
+        self.prev_gear = telemetry.gear;
+        self.prev_rpm = telemetry.engine_rpm;
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Braking from 4th to 3rd at 500ms with the engine spiking to 7500 RPM, then the
+    /// yaw rate set by `yaw_rate_rps` while the car has no lateral acceleration
+    fn downshift(yaw_rate_rps: impl Fn(u128) -> f32) -> Vec<TelemetryData> {
+        (0..20)
+            .map(|i| {
+                let timestamp_ms = i * 100;
+                let after_downshift = timestamp_ms >= 500;
+                TelemetryData {
+                    timestamp_ms,
+                    speed_mps: Some(40.),
+                    brake: Some(0.8),
+                    gear: Some(if after_downshift { 3 } else { 4 }),
+                    engine_rpm: Some(if timestamp_ms == 500 { 7500. } else { 6000. }),
+                    lateral_accel_mps2: Some(0.),
+                    yaw_rate_rps: Some(yaw_rate_rps(timestamp_ms)),
+                    ..TelemetryData::default()
+                }
+            })
+            .collect()
+    }
+
+    fn run(points: &[TelemetryData]) -> Vec<TelemetryAnnotation> {
+        let mut analyzer = DownshiftInstabilityAnalyzer::new();
+        let session_info = SessionInfo::default();
+        points
+            .iter()
+            .flat_map(|point| analyzer.analyze(point, &session_info))
+            .collect()
+    }
+
+    #[test]
+    fn test_rear_stepping_out_after_downshift() {
+        let output = run(&downshift(|timestamp_ms| {
+            if (600..=800).contains(&timestamp_ms) {
+                0.4
+            } else {
+                0.
+            }
+        }));
+
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::DownshiftInstability {
+                from_gear,
+                to_gear,
+                rpm_spike,
+                peak_yaw_excess_rps,
+                is_unstable,
+            } => {
+                assert_eq!((*from_gear, *to_gear), (4, 3));
+                assert!((rpm_spike - 1500.).abs() < 1e-3);
+                assert!((peak_yaw_excess_rps - 0.4).abs() < 1e-4);
+                assert!(*is_unstable);
+            }
+            _ => panic!("Expected DownshiftInstability annotation"),
+        }
+    }
+
+    #[test]
+    fn test_auto_blip_spike_without_instability_is_not_flagged() {
+        let output = run(&downshift(|_| 0.));
+        assert!(matches!(
+            output[..],
+            [TelemetryAnnotation::DownshiftInstability {
+                is_unstable: false,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn test_yaw_from_cornering_is_not_instability() {
+        // 40 m/s at 1.2 g yaws at about 0.29 rad/s without any slide
+        let points: Vec<_> = downshift(|_| 0.29)
+            .into_iter()
+            .map(|p| TelemetryData {
+                lateral_accel_mps2: Some(-1.2 * 9.81),
+                ..p
+            })
+            .collect();
+
+        assert!(run(&points).iter().all(|a| !a.is_active()));
+    }
+
+    #[test]
+    fn test_ignores_downshifts_off_the_brakes() {
+        let points: Vec<_> = downshift(|_| 0.4)
+            .into_iter()
+            .map(|p| TelemetryData {
+                brake: Some(0.),
+                ..p
+            })
+            .collect();
+
+        assert!(run(&points).is_empty());
+    }
+}
@@ -0,0 +1,301 @@
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{SessionInfo, SteeringFreePlay, TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+
+/// Minimum brake application for a point to be part of a corner
+const MIN_BRAKE_PCT: f32 = 0.1;
+/// Time an aid has to stay inactive before its next activation is counted separately
+const ACTIVATION_DEBOUNCE_MS: u128 = 250;
+/// Activations of the same aid within a single corner that are flagged as excessive
+const MAX_CORNER_ACTIVATIONS: usize = 3;
+/// Activations of the same aid within a lap above which every intervention is flagged
+const MAX_LAP_ACTIVATIONS: usize = 8;
+
+/// Counts distinct activations of a driver aid, merging flickers of the active flag.
+#[derive(Default)]
+struct ActivationCounter {
+    last_active_ms: Option<u128>,
+    corner: usize,
+    lap: usize,
+}
+
+impl ActivationCounter {
+    fn update(&mut self, is_active: bool, timestamp_ms: u128) {
+        if !is_active {
+            return;
+        }
+        let is_new_activation = self
+            .last_active_ms
+            .is_none_or(|last| timestamp_ms.saturating_sub(last) > ACTIVATION_DEBOUNCE_MS);
+        if is_new_activation {
+            self.corner += 1;
+            self.lap += 1;
+        }
+        self.last_active_ms = Some(timestamp_ms);
+    }
+
+    fn is_excessive(&self) -> bool {
+        self.corner > 0 && (self.corner >= MAX_CORNER_ACTIVATIONS || self.lap > MAX_LAP_ACTIVATIONS)
+    }
+}
+
+/// Counts ABS and traction control activations per corner and per lap.
+///
+/// The brake lock analyzer reports ABS while braking, this looks at how often the
+/// electronics step in overall. A car that keeps leaning on ABS or TC is usually
+/// asking for a setup change or a gentler application of the pedals. A corner lasts
+/// while the driver brakes or steers past the free play, and an annotation is
+/// emitted when a corner with at least one intervention ends.
+pub(crate) struct ElectronicsInterventionAnalyzer {
+    steering_free_play: SteeringFreePlay,
+    lap_number: Option<u32>,
+    in_corner: bool,
+    abs: ActivationCounter,
+    tc: ActivationCounter,
+}
+
+impl ElectronicsInterventionAnalyzer {
+    pub(crate) fn new(steering_free_play: SteeringFreePlay) -> Self {
+        Self {
+            steering_free_play,
+            lap_number: None,
+            in_corner: false,
+            abs: ActivationCounter::default(),
+            tc: ActivationCounter::default(),
+        }
+    }
+
+    fn end_corner(&mut self) -> Option<TelemetryAnnotation> {
+        let annotation = (self.abs.corner > 0 || self.tc.corner > 0).then(|| {
+            TelemetryAnnotation::ElectronicsIntervention {
+                corner_abs_activations: self.abs.corner,
+                corner_tc_activations: self.tc.corner,
+                lap_abs_activations: self.abs.lap,
+                lap_tc_activations: self.tc.lap,
+                is_excessive_abs: self.abs.is_excessive(),
+                is_excessive_tc: self.tc.is_excessive(),
+            }
+        });
+        self.in_corner = false;
+        self.abs.corner = 0;
+        self.tc.corner = 0;
+        annotation
+    }
+}
+
+impl TelemetryAnalyzer for ElectronicsInterventionAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        session_info: &SessionInfo,
+    ) -> Vec<TelemetryAnnotation> {
+        let mut output = Vec::new();
+
+        if telemetry.lap_number != self.lap_number {
+            self.lap_number = telemetry.lap_number;
+            self.abs.lap = 0;
+            self.tc.lap = 0;
+        }
+
+        if !is_telemetry_point_analyzable(telemetry) {
+            self.in_corner = false;
+            self.abs.corner = 0;
+            self.tc.corner = 0;
+            return output;
+        }
+
+        let is_cornering = telemetry.brake.unwrap_or(0.0) > MIN_BRAKE_PCT
+            || self
+                .steering_free_play
+                .is_steering(telemetry, session_info)
+                .unwrap_or(false);
+
+        // Interventions outside of a corner still count towards the lap
+        self.abs.update(
+            telemetry.is_abs_active.unwrap_or(false),
+            telemetry.timestamp_ms,
+        );
+        self.tc.update(
+            telemetry.is_tc_active.unwrap_or(false),
+            telemetry.timestamp_ms,
+        );
+
+        if is_cornering {
+            self.in_corner = true;
+        } else if self.in_corner {
+            output.extend(self.end_corner());
+        } else {
+            self.abs.corner = 0;
+            self.tc.corner = 0;
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(
+        timestamp_ms: u128,
+        brake: f32,
+        steering_pct: f32,
+        abs: bool,
+        tc: bool,
+    ) -> TelemetryData {
+        TelemetryData {
+            timestamp_ms,
+            lap_number: Some(1),
+            speed_mps: Some(30.0),
+            brake: Some(brake),
+            steering_pct: Some(steering_pct),
+            is_abs_active: Some(abs),
+            is_tc_active: Some(tc),
+            ..TelemetryData::default()
+        }
+    }
+
+    fn run(points: &[TelemetryData]) -> Vec<TelemetryAnnotation> {
+        let mut analyzer = ElectronicsInterventionAnalyzer::new(SteeringFreePlay::default());
+        let session_info = SessionInfo::default();
+        points
+            .iter()
+            .flat_map(|p| analyzer.analyze(p, &session_info))
+            .collect()
+    }
+
+    /// Braking zone with ABS pulsing on and off every 400ms, then a straight
+    fn braking_zone(start_ms: u128, abs_pulses: usize) -> Vec<TelemetryData> {
+        let mut points: Vec<_> = (0..abs_pulses * 4)
+            .map(|i| point(start_ms + i as u128 * 100, 0.8, 0.0, i % 4 < 2, false))
+            .collect();
+        let end_ms = start_ms + points.len() as u128 * 100;
+        points.push(point(end_ms, 0.0, 0.0, false, false));
+        points
+    }
+
+    #[test]
+    fn test_counts_distinct_abs_activations() {
+        let output = run(&braking_zone(0, 2));
+
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::ElectronicsIntervention {
+                corner_abs_activations,
+                corner_tc_activations,
+                lap_abs_activations,
+                is_excessive_abs,
+                ..
+            } => {
+                assert_eq!(*corner_abs_activations, 2);
+                assert_eq!(*corner_tc_activations, 0);
+                assert_eq!(*lap_abs_activations, 2);
+                assert!(!*is_excessive_abs);
+            }
+            _ => panic!("Expected ElectronicsIntervention annotation"),
+        }
+    }
+
+    #[test]
+    fn test_flicker_is_one_activation() {
+        // ABS flag drops for a single 100ms sample, within the debounce
+        let points = [
+            point(0, 0.8, 0.0, true, false),
+            point(100, 0.8, 0.0, false, false),
+            point(200, 0.8, 0.0, true, false),
+            point(300, 0.0, 0.0, false, false),
+        ];
+        let output = run(&points);
+
+        assert!(matches!(
+            output[0],
+            TelemetryAnnotation::ElectronicsIntervention {
+                corner_abs_activations: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_flags_excessive_abs_in_corner() {
+        let output = run(&braking_zone(0, 3));
+
+        assert!(matches!(
+            output[0],
+            TelemetryAnnotation::ElectronicsIntervention {
+                is_excessive_abs: true,
+                is_excessive_tc: false,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_flags_excessive_tc_over_lap() {
+        // Nine corner exits with a single TC cut each
+        let points: Vec<_> = (0..9)
+            .flat_map(|corner| {
+                let start_ms = corner * 1000;
+                [
+                    point(start_ms, 0.0, 0.4, false, true),
+                    point(start_ms + 100, 0.0, 0.0, false, false),
+                ]
+            })
+            .collect();
+        let output = run(&points);
+
+        assert_eq!(output.len(), 9);
+        assert!(output[..8].iter().all(|a| matches!(
+            a,
+            TelemetryAnnotation::ElectronicsIntervention {
+                is_excessive_tc: false,
+                ..
+            }
+        )));
+        assert!(matches!(
+            output[8],
+            TelemetryAnnotation::ElectronicsIntervention {
+                lap_tc_activations: 9,
+                is_excessive_tc: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_lap_count_resets_on_new_lap() {
+        let mut points = braking_zone(0, 2);
+        let mut next_lap = braking_zone(10_000, 1);
+        for p in next_lap.iter_mut() {
+            p.lap_number = Some(2);
+        }
+        points.extend(next_lap);
+        let output = run(&points);
+
+        assert_eq!(output.len(), 2);
+        assert!(matches!(
+            output[1],
+            TelemetryAnnotation::ElectronicsIntervention {
+                lap_abs_activations: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_no_annotation_without_intervention() {
+        let points = [
+            point(0, 0.8, 0.3, false, false),
+            point(100, 0.0, 0.0, false, false),
+        ];
+        assert!(run(&points).is_empty());
+
+        let missing = TelemetryData {
+            is_abs_active: None,
+            is_tc_active: None,
+            ..point(0, 0.8, 0.0, false, false)
+        };
+        assert!(run(&[missing, point(100, 0.0, 0.0, false, false)]).is_empty());
+    }
+}
@@ -2,7 +2,10 @@ use simple_moving_average::{SMA, SumTreeSMA};
 
 use crate::telemetry::is_telemetry_point_analyzable;
 
-use super::{TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+use super::{
+    TelemetryAnalyzer, TelemetryAnnotation, TelemetryData, YawRateFilter,
+    slide_recovery::SlideRecovery,
+};
 
 /// Minimum brake percentage to consider for entry oversteer detection
 const MIN_BRAKE_PCT: f32 = 0.3;
@@ -14,13 +17,26 @@ const OVERSTEER_THRESHOLD: f32 = 1.5;
 pub(crate) struct EntryOversteerAnalyzer<const WINDOW_SIZE: usize> {
     yaw_to_steering_window: SumTreeSMA<f32, f32, WINDOW_SIZE>,
     min_points: usize,
+    yaw_rate_filter: YawRateFilter,
+    slide_recovery: SlideRecovery,
 }
 
 impl<const WINDOW_SIZE: usize> EntryOversteerAnalyzer<WINDOW_SIZE> {
+    /// Analyzer comparing the raw yaw rate
     pub(crate) fn new(min_points: usize) -> Self {
+        Self::with_yaw_rate_smoothing(min_points, 1)
+    }
+
+    /// Analyzer averaging the yaw rate over `yaw_rate_smoothing_samples` points
+    pub(crate) fn with_yaw_rate_smoothing(
+        min_points: usize,
+        yaw_rate_smoothing_samples: usize,
+    ) -> Self {
         Self {
             yaw_to_steering_window: SumTreeSMA::new(),
             min_points,
+            yaw_rate_filter: YawRateFilter::new(yaw_rate_smoothing_samples),
+            slide_recovery: SlideRecovery::default(),
         }
     }
 }
@@ -35,6 +51,8 @@ impl<const WINDOW_SIZE: usize> TelemetryAnalyzer for EntryOversteerAnalyzer<WIND
 
         // Skip analysis if doesn't meet requirements
         if !is_telemetry_point_analyzable(telemetry) {
+            self.yaw_rate_filter.reset();
+            self.slide_recovery.end();
             return output;
         }
 
@@ -47,9 +65,15 @@ impl<const WINDOW_SIZE: usize> TelemetryAnalyzer for EntryOversteerAnalyzer<WIND
             Some(rate) => rate,
             None => {
                 // If yaw rate is not available, we cannot perform entry oversteer analysis
+                self.yaw_rate_filter.reset();
+                self.slide_recovery.end();
                 return output;
             }
         };
+        // Wait for enough samples to smooth out the yaw rate noise
+        let Some(yaw_rate) = self.yaw_rate_filter.filter(yaw_rate) else {
+            return output;
+        };
 
         // Only analyze during braking with steering input (corner entry phase)
         if brake > MIN_BRAKE_PCT && steering_pct.abs() > MIN_STEERING_PCT {
@@ -66,10 +90,12 @@ impl<const WINDOW_SIZE: usize> TelemetryAnalyzer for EntryOversteerAnalyzer<WIND
 
                 // Detect oversteer: actual yaw rate significantly exceeds expected
                 if actual_yaw_rate > expected_yaw_rate * OVERSTEER_THRESHOLD {
+                    let is_recovered = self.slide_recovery.track(telemetry, actual_yaw_rate);
                     output.push(TelemetryAnnotation::EntryOversteer {
                         expected_yaw_rate,
                         actual_yaw_rate,
                         is_oversteer: true,
+                        is_recovered,
                     });
                 }
             }
@@ -79,6 +105,9 @@ impl<const WINDOW_SIZE: usize> TelemetryAnalyzer for EntryOversteerAnalyzer<WIND
                 .add_sample(yaw_to_steering_ratio);
         }
 
+        if output.is_empty() {
+            self.slide_recovery.end();
+        }
         output
     }
 }
@@ -122,7 +151,9 @@ mod tests {
                 expected_yaw_rate,
                 actual_yaw_rate,
                 is_oversteer,
+                is_recovered,
             } => {
+                assert!(!*is_recovered);
                 assert!(*is_oversteer);
                 assert!(*actual_yaw_rate > *expected_yaw_rate);
             }
@@ -130,6 +161,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_caught_slide_is_recovered() {
+        let mut analyzer = EntryOversteerAnalyzer::<10>::new(5);
+        let session_info = SessionInfo::default();
+        let point = |steering_pct, yaw_rate_rps| TelemetryData {
+            brake: Some(0.5),
+            steering_pct: Some(steering_pct),
+            yaw_rate_rps: Some(yaw_rate_rps),
+            speed_mps: Some(10.),
+            ..TelemetryData::default()
+        };
+        for _ in 0..5 {
+            analyzer.analyze(&point(0.3, 0.15), &session_info);
+        }
+
+        let is_recovered = |output: &[TelemetryAnnotation]| match output {
+            [TelemetryAnnotation::EntryOversteer { is_recovered, .. }] => *is_recovered,
+            _ => panic!("Expected EntryOversteer annotation"),
+        };
+        // the rear steps out, then the driver takes lock off and the rotation falls
+        let output = analyzer.analyze(&point(0.3, 0.3), &session_info);
+        assert!(!is_recovered(&output));
+        let output = analyzer.analyze(&point(0.2, 0.25), &session_info);
+        assert!(is_recovered(&output));
+    }
+
     #[test]
     fn test_no_oversteer_with_normal_yaw() {
         let mut analyzer = EntryOversteerAnalyzer::<10>::new(5);
@@ -341,6 +398,7 @@ mod tests {
                     expected_yaw_rate,
                     actual_yaw_rate,
                     is_oversteer,
+                    ..
                 } => {
                     prop_assert!(*is_oversteer);
                     prop_assert!(*actual_yaw_rate > *expected_yaw_rate);
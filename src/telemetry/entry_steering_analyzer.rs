@@ -0,0 +1,206 @@
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+
+/// Minimum brake application for a point to be part of a corner entry
+const MIN_BRAKE_PCT: f32 = 0.1;
+/// Steering input (as a fraction of full lock) that starts tracking a turn-in
+const MIN_STEERING_PCT: f32 = 0.1;
+/// Steering rate (fraction of full lock per second) that makes the turn-in a spike
+const MIN_STEERING_RATE: f32 = 1.0;
+/// Fraction of the peak steering that has to be unwound while braking
+const MIN_UNWIND_FRACTION: f32 = 0.25;
+/// Lateral acceleration (m/s^2, ~1 g) that puts the car near the limit of grip
+const NEAR_LIMIT_LATERAL_ACCEL_MPS2: f32 = 9.8;
+
+struct TurnIn {
+    /// +1 for turning right, -1 for turning left
+    direction: f32,
+    peak_steering_pct: f32,
+    peak_steering_rate: f32,
+    peak_lateral_accel_mps2: f32,
+    is_reported: bool,
+}
+
+/// Detects too much steering on corner entry that then has to be unwound.
+///
+/// Snapping the wheel past the angle the corner needs while braking overloads the
+/// front tires, the driver then takes steering back out and the grip spent on the
+/// excess lock is wasted. Unlike the trailbrake steering analyzer, which flags a
+/// large steering angle under braking, this looks for the overshoot and correction.
+/// Deliberate flicks at low grip are ignored by requiring the car to be close to the
+/// limit, so the analyzer never fires without lateral acceleration data.
+pub(crate) struct EntrySteeringAnalyzer {
+    prev_steering_pct: f32,
+    prev_timestamp_ms: Option<u128>,
+    turn_in: Option<TurnIn>,
+}
+
+impl EntrySteeringAnalyzer {
+    pub(crate) fn new() -> Self {
+        Self {
+            prev_steering_pct: 0.0,
+            prev_timestamp_ms: None,
+            turn_in: None,
+        }
+    }
+}
+
+impl TelemetryAnalyzer for EntrySteeringAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        _session_info: &super::SessionInfo,
+    ) -> Vec<super::TelemetryAnnotation> {
+        let mut output = Vec::new();
+
+        let steering_pct = telemetry.steering_pct.unwrap_or(0.0);
+        let prev_steering_pct = std::mem::replace(&mut self.prev_steering_pct, steering_pct);
+        let prev_timestamp_ms = self.prev_timestamp_ms.replace(telemetry.timestamp_ms);
+
+        if !is_telemetry_point_analyzable(telemetry)
+            || telemetry.brake.unwrap_or(0.0) < MIN_BRAKE_PCT
+        {
+            self.turn_in = None;
+            return output;
+        }
+
+        let steering = steering_pct.abs();
+        let lateral_accel = telemetry.lateral_accel_mps2.unwrap_or(0.0).abs();
+        let steering_rate =
+            match prev_timestamp_ms.and_then(|prev| telemetry.timestamp_ms.checked_sub(prev)) {
+                Some(elapsed_ms) if elapsed_ms > 0 => {
+                    (steering - prev_steering_pct.abs()) / (elapsed_ms as f32 / 1000.)
+                }
+                _ => 0.0,
+            };
+
+        // Counter-steering is a different correction, start over
+        if self
+            .turn_in
+            .as_ref()
+            .is_some_and(|t| steering_pct * t.direction < 0.0)
+        {
+            self.turn_in = None;
+        }
+
+        let Some(turn_in) = self.turn_in.as_mut() else {
+            if steering > MIN_STEERING_PCT {
+                self.turn_in = Some(TurnIn {
+                    direction: steering_pct.signum(),
+                    peak_steering_pct: steering,
+                    peak_steering_rate: steering_rate.max(0.0),
+                    peak_lateral_accel_mps2: lateral_accel,
+                    is_reported: false,
+                });
+            }
+            return output;
+        };
+
+        turn_in.peak_lateral_accel_mps2 = turn_in.peak_lateral_accel_mps2.max(lateral_accel);
+        if steering >= turn_in.peak_steering_pct {
+            turn_in.peak_steering_pct = steering;
+            turn_in.peak_steering_rate = turn_in.peak_steering_rate.max(steering_rate);
+            return output;
+        }
+
+        let unwound = turn_in.peak_steering_pct - steering;
+        if !turn_in.is_reported
+            && unwound >= turn_in.peak_steering_pct * MIN_UNWIND_FRACTION
+            && turn_in.peak_steering_rate >= MIN_STEERING_RATE
+            && turn_in.peak_lateral_accel_mps2 >= NEAR_LIMIT_LATERAL_ACCEL_MPS2
+        {
+            turn_in.is_reported = true;
+            output.push(TelemetryAnnotation::EntrySteeringOvershoot {
+                peak_steering_pct: turn_in.peak_steering_pct,
+                unwound_steering_pct: unwound,
+                lateral_accel_mps2: turn_in.peak_lateral_accel_mps2,
+                is_overshoot: true,
+            });
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::SessionInfo;
+
+    fn entry_point(timestamp_ms: u128, steering_pct: f32, lateral_accel: f32) -> TelemetryData {
+        TelemetryData {
+            timestamp_ms,
+            speed_mps: Some(40.0),
+            brake: Some(0.5),
+            throttle: Some(0.0),
+            steering_pct: Some(steering_pct),
+            lateral_accel_mps2: Some(lateral_accel),
+            ..TelemetryData::default()
+        }
+    }
+
+    fn run(points: &[TelemetryData]) -> Vec<TelemetryAnnotation> {
+        let mut analyzer = EntrySteeringAnalyzer::new();
+        let session_info = SessionInfo::default();
+        points
+            .iter()
+            .flat_map(|p| analyzer.analyze(p, &session_info))
+            .collect()
+    }
+
+    /// Snap to 50% lock in 200ms, then unwind to 25% while still braking
+    fn overshoot(lateral_accel: f32) -> Vec<TelemetryData> {
+        [0.0, 0.25, 0.5, 0.45, 0.35, 0.25, 0.25]
+            .iter()
+            .enumerate()
+            .map(|(i, steering)| entry_point(i as u128 * 100, -steering, lateral_accel))
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_overshoot_near_limit() {
+        let output = run(&overshoot(12.0));
+
+        // reported once per turn-in
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::EntrySteeringOvershoot {
+                peak_steering_pct,
+                unwound_steering_pct,
+                is_overshoot,
+                ..
+            } => {
+                assert_eq!(*peak_steering_pct, 0.5);
+                assert!((*unwound_steering_pct - 0.15).abs() < 1e-5);
+                assert!(*is_overshoot);
+            }
+            _ => panic!("Expected EntrySteeringOvershoot annotation"),
+        }
+    }
+
+    #[test]
+    fn test_ignores_flick_away_from_limit() {
+        assert!(run(&overshoot(4.0)).is_empty());
+    }
+
+    #[test]
+    fn test_ignores_progressive_turn_in() {
+        // 0.05 of lock per 100ms is 0.5/s, too slow to be a spike
+        let points: Vec<_> = [0.0, 0.05, 0.1, 0.15, 0.2, 0.25, 0.3, 0.2]
+            .iter()
+            .enumerate()
+            .map(|(i, steering)| entry_point(i as u128 * 100, *steering, 12.0))
+            .collect();
+        assert!(run(&points).is_empty());
+    }
+
+    #[test]
+    fn test_ignores_unwind_after_braking() {
+        let mut points = overshoot(12.0);
+        for point in points.iter_mut().skip(3) {
+            point.brake = Some(0.0);
+        }
+        assert!(run(&points).is_empty());
+    }
+}
@@ -0,0 +1,208 @@
+use super::{
+    TelemetryData,
+    corner_speed::{CornerMinSpeed, corner_min_speeds},
+    corners::CornerDetection,
+};
+use crate::setup_assistant::{CornerPhase, CornerPhaseClassifier};
+
+/// Exit efficiency (0.0 to 1.0) below which a corner exit is low on its own
+pub const LOW_EXIT_EFFICIENCY: f32 = 0.5;
+/// Exit efficiency below the reference corner that counts as a low exit, 10 points
+pub const LOW_EXIT_EFFICIENCY_DEFICIT: f32 = 0.1;
+
+/// Throttle applied over the exit of a single corner
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CornerExit {
+    pub corner: CornerMinSpeed,
+    /// Index of the first telemetry point of the exit phase within the lap
+    pub exit_point: usize,
+    /// Area under the throttle trace over the exit phase, over the area of full
+    /// throttle for as long. 1.0 is flat out through the whole exit.
+    pub efficiency: f32,
+}
+
+/// Exit efficiency of a corner compared with the same corner on the reference lap
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CornerExitEfficiency {
+    pub exit: CornerExit,
+    /// `None` without a reference lap or a matching corner in it
+    pub reference_efficiency: Option<f32>,
+}
+
+impl CornerExitEfficiency {
+    /// Efficiency lost to the reference lap, negative when the exit was better
+    pub fn deficit(&self) -> Option<f32> {
+        self.reference_efficiency
+            .map(|reference| reference - self.exit.efficiency)
+    }
+
+    /// Returns true when the driver got back to full throttle late or gently, either
+    /// against the reference lap or, without one, against full throttle through the exit
+    pub fn is_low(&self) -> bool {
+        match self.deficit() {
+            Some(deficit) => deficit >= LOW_EXIT_EFFICIENCY_DEFICIT,
+            None => self.exit.efficiency < LOW_EXIT_EFFICIENCY,
+        }
+    }
+}
+
+/// How early and how hard the driver got back on the throttle out of each corner of a
+/// lap, optionally against a reference lap, usually the best lap.
+///
+/// The exit of a corner is the part of it the [`CornerPhaseClassifier`] puts in the
+/// exit phase, or from its slowest point when the driver never got on the throttle
+/// with the wheel turned. Dividing the area under the throttle trace by the duration
+/// of the exit makes corners comparable across laps whatever the time spent in them.
+/// Corners are matched with the reference lap by the lap distance of their slowest
+/// point, like [`super::corner_speed::OverSlowing`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExitEfficiency {
+    pub corners: Vec<CornerExitEfficiency>,
+}
+
+impl ExitEfficiency {
+    pub fn from_laps(
+        points: &[TelemetryData],
+        reference: Option<&[TelemetryData]>,
+        detection: &CornerDetection,
+    ) -> Self {
+        let reference_exits = reference
+            .map(|reference| corner_exits(reference, detection))
+            .unwrap_or_default();
+        let corners = corner_exits(points, detection)
+            .into_iter()
+            .map(|exit| {
+                let reference_efficiency = exit.corner.apex_lap_distance_pct.and_then(|apex| {
+                    reference_exits
+                        .iter()
+                        .find(|r| r.corner.window.is_some_and(|w| w.contains(apex)))
+                        .map(|r| r.efficiency)
+                });
+                CornerExitEfficiency {
+                    exit,
+                    reference_efficiency,
+                }
+            })
+            .collect();
+        ExitEfficiency { corners }
+    }
+
+    pub fn low_corners(&self) -> impl Iterator<Item = &CornerExitEfficiency> {
+        self.corners.iter().filter(|c| c.is_low())
+    }
+
+    /// Average exit efficiency over the corners of the lap
+    pub fn mean_efficiency(&self) -> Option<f32> {
+        if self.corners.is_empty() {
+            return None;
+        }
+        Some(
+            self.corners.iter().map(|c| c.exit.efficiency).sum::<f32>() / self.corners.len() as f32,
+        )
+    }
+}
+
+/// Exit efficiency of every corner of a lap. Corners without throttle data or with an
+/// exit on their last point are left out.
+pub fn corner_exits(points: &[TelemetryData], detection: &CornerDetection) -> Vec<CornerExit> {
+    corner_min_speeds(points, detection)
+        .into_iter()
+        .filter_map(|corner| {
+            let exit_point = exit_phase_start(points, &corner);
+            let efficiency = throttle_efficiency(&points[exit_point..=corner.end_point])?;
+            Some(CornerExit {
+                corner,
+                exit_point,
+                efficiency,
+            })
+        })
+        .collect()
+}
+
+/// First point of the corner the classifier puts in the exit phase, the slowest point
+/// when it never gets there
+fn exit_phase_start(points: &[TelemetryData], corner: &CornerMinSpeed) -> usize {
+    let mut classifier = CornerPhaseClassifier::new();
+    (corner.start_point..=corner.end_point)
+        .find(|&i| classifier.update(&points[i]) == CornerPhase::Exit)
+        .unwrap_or(corner.apex_point)
+}
+
+/// Area under the throttle trace over the area of full throttle, integrated over the
+/// timestamps with the trapezoidal rule
+fn throttle_efficiency(points: &[TelemetryData]) -> Option<f32> {
+    let mut area = 0.;
+    let mut duration_ms = 0;
+    for pair in points.windows(2) {
+        let (Some(a), Some(b)) = (pair[0].throttle, pair[1].throttle) else {
+            continue;
+        };
+        let dt = pair[1].timestamp_ms.saturating_sub(pair[0].timestamp_ms);
+        area += (a + b) / 2. * dt as f32;
+        duration_ms += dt;
+    }
+    if duration_ms == 0 {
+        return None;
+    }
+    Some(area / duration_ms as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 100 point lap at 100ms intervals with a corner at 20-30%, apex at 25%, where
+    /// the throttle goes from 0 at the apex to full after `pickup_points`. The exit
+    /// phase starts at 27%, once the throttle held past the classifier debounce.
+    fn lap(pickup_points: usize) -> Vec<TelemetryData> {
+        (0..100)
+            .map(|i: usize| {
+                let (steering_pct, speed_mps, throttle) = match i {
+                    20..=24 => (0.3, 30. + (25 - i) as f32, 0.),
+                    25..=30 => (
+                        0.3,
+                        30. + (i - 25) as f32,
+                        ((i - 25) as f32 / pickup_points as f32).min(1.),
+                    ),
+                    _ => (0., 50., 1.),
+                };
+                TelemetryData {
+                    timestamp_ms: i as u128 * 100,
+                    speed_mps: Some(speed_mps),
+                    steering_pct: Some(steering_pct),
+                    throttle: Some(throttle),
+                    lap_distance_pct: Some(i as f32 / 100.),
+                    ..TelemetryData::default()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_exit_efficiency_against_reference() {
+        let best = lap(1);
+        let late = lap(5);
+        let efficiency =
+            ExitEfficiency::from_laps(&late, Some(best.as_slice()), &CornerDetection::default());
+
+        assert_eq!(efficiency.corners.len(), 1);
+        let corner = &efficiency.corners[0];
+        assert_eq!(corner.exit.corner.apex_point, 25);
+        assert_eq!(corner.exit.exit_point, 27);
+        // flat out through the whole exit
+        assert!((corner.reference_efficiency.unwrap() - 1.0).abs() < 1e-4);
+        // still ramping up from 40% to full
+        assert!((corner.exit.efficiency - 0.7).abs() < 1e-4);
+        assert!(corner.is_low());
+        assert_eq!(efficiency.low_corners().count(), 1);
+    }
+
+    #[test]
+    fn test_exit_efficiency_without_reference() {
+        let efficiency = ExitEfficiency::from_laps(&lap(1), None, &CornerDetection::default());
+        let corner = &efficiency.corners[0];
+        assert_eq!(corner.reference_efficiency, None);
+        assert!(!corner.is_low());
+        assert!((efficiency.mean_efficiency().unwrap() - 1.0).abs() < 1e-4);
+    }
+}
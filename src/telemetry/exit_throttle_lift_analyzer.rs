@@ -0,0 +1,309 @@
+use serde::{Deserialize, Serialize};
+
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+
+/// Throttle application that starts tracking a corner exit
+const MIN_THROTTLE_PCT: f32 = 0.1;
+/// Steering input (as a fraction of full lock) that keeps the car in the corner
+const MIN_STEERING_PCT: f32 = 0.15;
+/// Brake application that ends the exit
+const MIN_BRAKE_PCT: f32 = 0.1;
+/// Throttle taken back out from the peak of the exit to count as a lift
+const MIN_DIP_DEPTH: f32 = 0.1;
+/// Throttle reapplied from the bottom of a lift to count as a recovery
+const MIN_RECOVERY: f32 = 0.05;
+/// Understeer showing up within this time (ms) of picking up the throttle follows the
+/// torque going through the differential
+const MAX_DIFF_ONSET_MS: u128 = 800;
+/// Speed (m/s) gained on throttle before the understeer shows up for it to be put down
+/// to the balance of the aero shifting with speed
+const MIN_AERO_SPEED_GAIN_MPS: f32 = 8.0;
+
+/// Likely cause of the understeer behind an exit throttle lift
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ExitUndersteerCause {
+    /// Neither immediate nor building with speed, e.g. mid-corner balance or tires
+    #[default]
+    Unknown,
+    /// The front washes wide as soon as the throttle goes in
+    Differential,
+    /// The front washes wide as the speed builds on the exit
+    Aero,
+}
+
+impl ExitUndersteerCause {
+    /// Classify the understeer from the time and the speed gained between picking up
+    /// the throttle and the peak throttle of the exit, where the driver starts lifting.
+    pub(crate) fn classify(onset_ms: u128, speed_gain_mps: f32) -> Self {
+        if speed_gain_mps >= MIN_AERO_SPEED_GAIN_MPS {
+            ExitUndersteerCause::Aero
+        } else if onset_ms <= MAX_DIFF_ONSET_MS {
+            ExitUndersteerCause::Differential
+        } else {
+            ExitUndersteerCause::Unknown
+        }
+    }
+}
+
+impl std::fmt::Display for ExitUndersteerCause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExitUndersteerCause::Unknown => write!(f, "unknown"),
+            ExitUndersteerCause::Differential => write!(f, "differential"),
+            ExitUndersteerCause::Aero => write!(f, "aero"),
+        }
+    }
+}
+
+struct Exit {
+    /// +1 for turning right, -1 for turning left
+    direction: f32,
+    /// Timestamp and speed when the throttle was picked up
+    start_ms: u128,
+    start_speed_mps: f32,
+    peak_throttle: f32,
+    /// Timestamp and speed at the peak throttle
+    peak_ms: u128,
+    peak_speed_mps: f32,
+    /// Lowest throttle since the peak
+    trough_throttle: f32,
+    dip_count: usize,
+    /// Cause classified on the first lift, later lifts of the exit share it
+    cause: Option<ExitUndersteerCause>,
+}
+
+/// Detects the driver lifting to tighten the line on corner exit.
+///
+/// When the front washes wide on exit, drivers come off the throttle to bring the
+/// nose back in and then pick it up again, which shows as dips and recoveries of the
+/// throttle trace while the steering is still wound on. This confirms exit understeer
+/// independently of the slip analyzer. Lifts caught with counter-steer are the car
+/// stepping out under power instead, so the exit is abandoned when the steering
+/// changes direction.
+///
+/// The first lift of an exit also guesses the cause of the understeer. The driver
+/// lifts in reaction to the front washing wide, so the peak throttle before the lift
+/// marks its onset. Understeer that shows up right after picking up the throttle
+/// follows the torque through the differential, while understeer that only shows up
+/// once the car has gained speed follows the aero balance.
+pub(crate) struct ExitThrottleLiftAnalyzer {
+    exit: Option<Exit>,
+}
+
+impl ExitThrottleLiftAnalyzer {
+    pub(crate) fn new() -> Self {
+        Self { exit: None }
+    }
+}
+
+impl TelemetryAnalyzer for ExitThrottleLiftAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        _session_info: &super::SessionInfo,
+    ) -> Vec<super::TelemetryAnnotation> {
+        let mut output = Vec::new();
+
+        let steering_pct = telemetry.steering_pct.unwrap_or(0.0);
+        let throttle = telemetry.throttle.unwrap_or(0.0);
+        let speed_mps = telemetry.speed_mps.unwrap_or(0.0);
+        if !is_telemetry_point_analyzable(telemetry)
+            || telemetry.brake.unwrap_or(0.0) >= MIN_BRAKE_PCT
+            || steering_pct.abs() < MIN_STEERING_PCT
+        {
+            self.exit = None;
+            return output;
+        }
+
+        // Counter-steering is power oversteer, not understeer
+        if self
+            .exit
+            .as_ref()
+            .is_some_and(|e| steering_pct * e.direction < 0.0)
+        {
+            self.exit = None;
+        }
+
+        let Some(exit) = self.exit.as_mut() else {
+            if throttle > MIN_THROTTLE_PCT {
+                self.exit = Some(Exit {
+                    direction: steering_pct.signum(),
+                    start_ms: telemetry.timestamp_ms,
+                    start_speed_mps: speed_mps,
+                    peak_throttle: throttle,
+                    peak_ms: telemetry.timestamp_ms,
+                    peak_speed_mps: speed_mps,
+                    trough_throttle: throttle,
+                    dip_count: 0,
+                    cause: None,
+                });
+            }
+            return output;
+        };
+
+        let dip_depth = exit.peak_throttle - exit.trough_throttle;
+        if dip_depth >= MIN_DIP_DEPTH && throttle - exit.trough_throttle >= MIN_RECOVERY {
+            exit.dip_count += 1;
+            let cause = *exit.cause.get_or_insert_with(|| {
+                ExitUndersteerCause::classify(
+                    exit.peak_ms.saturating_sub(exit.start_ms),
+                    exit.peak_speed_mps - exit.start_speed_mps,
+                )
+            });
+            output.push(TelemetryAnnotation::ExitThrottleLift {
+                dip_depth,
+                dip_count: exit.dip_count,
+                is_understeer_lift: true,
+                cause,
+            });
+            // the next lift is measured from the recovered throttle
+            exit.peak_throttle = throttle;
+            exit.trough_throttle = throttle;
+        } else if dip_depth < MIN_DIP_DEPTH && throttle >= exit.peak_throttle {
+            exit.peak_throttle = throttle;
+            exit.peak_ms = telemetry.timestamp_ms;
+            exit.peak_speed_mps = speed_mps;
+            exit.trough_throttle = throttle;
+        } else {
+            exit.trough_throttle = exit.trough_throttle.min(throttle);
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::SessionInfo;
+
+    fn exit_point(
+        timestamp_ms: u128,
+        speed_mps: f32,
+        throttle: f32,
+        steering_pct: f32,
+    ) -> TelemetryData {
+        TelemetryData {
+            timestamp_ms,
+            speed_mps: Some(speed_mps),
+            brake: Some(0.0),
+            throttle: Some(throttle),
+            steering_pct: Some(steering_pct),
+            ..TelemetryData::default()
+        }
+    }
+
+    fn run(throttle: &[f32], steering_pct: &[f32]) -> Vec<TelemetryAnnotation> {
+        run_with_speed(throttle, steering_pct, |_| 30.0)
+    }
+
+    fn run_with_speed(
+        throttle: &[f32],
+        steering_pct: &[f32],
+        speed_mps: impl Fn(usize) -> f32,
+    ) -> Vec<TelemetryAnnotation> {
+        let mut analyzer = ExitThrottleLiftAnalyzer::new();
+        let session_info = SessionInfo::default();
+        throttle
+            .iter()
+            .zip(steering_pct)
+            .enumerate()
+            .flat_map(|(i, (throttle, steering))| {
+                analyzer.analyze(
+                    &exit_point(i as u128 * 100, speed_mps(i), *throttle, *steering),
+                    &session_info,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_lifts_with_steering_wound_on() {
+        let throttle = [0.2, 0.5, 0.6, 0.4, 0.3, 0.5, 0.7, 0.5, 0.6];
+        let output = run(&throttle, &[0.3; 9]);
+
+        assert_eq!(output.len(), 2);
+        match (&output[0], &output[1]) {
+            (
+                TelemetryAnnotation::ExitThrottleLift {
+                    dip_depth: first_depth,
+                    dip_count: 1,
+                    is_understeer_lift: true,
+                    ..
+                },
+                TelemetryAnnotation::ExitThrottleLift {
+                    dip_depth: second_depth,
+                    dip_count: 2,
+                    is_understeer_lift: true,
+                    ..
+                },
+            ) => {
+                assert!((first_depth - 0.3).abs() < 1e-5);
+                assert!((second_depth - 0.2).abs() < 1e-5);
+            }
+            _ => panic!("Expected two ExitThrottleLift annotations"),
+        }
+    }
+
+    #[test]
+    fn test_ignores_smooth_throttle_application() {
+        let throttle = [0.2, 0.3, 0.4, 0.38, 0.5, 0.7, 0.9, 1.0];
+        assert!(run(&throttle, &[0.3; 8]).is_empty());
+    }
+
+    #[test]
+    fn test_ignores_lift_with_counter_steer() {
+        // the rear steps out, the driver lifts and catches it with opposite lock
+        let throttle = [0.3, 0.6, 0.8, 0.4, 0.3, 0.6];
+        let steering = [0.3, 0.3, 0.2, -0.2, -0.2, 0.2];
+        assert!(run(&throttle, &steering).is_empty());
+    }
+
+    #[test]
+    fn test_ignores_lift_after_unwinding() {
+        // steering already straight when the throttle is modulated
+        let throttle = [0.3, 0.6, 0.8, 0.4, 0.3, 0.6];
+        let steering = [0.3, 0.3, 0.1, 0.05, 0.05, 0.05];
+        assert!(run(&throttle, &steering).is_empty());
+    }
+
+    fn causes(output: &[TelemetryAnnotation]) -> Vec<ExitUndersteerCause> {
+        output
+            .iter()
+            .filter_map(|a| match a {
+                TelemetryAnnotation::ExitThrottleLift { cause, .. } => Some(*cause),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_immediate_understeer_is_differential() {
+        // lifts 200ms after picking up the throttle, the second lift keeps the cause
+        let throttle = [0.2, 0.5, 0.6, 0.4, 0.3, 0.5, 0.7, 0.5, 0.6];
+        let output = run_with_speed(&throttle, &[0.3; 9], |i| 20.0 + i as f32 * 2.0);
+        assert_eq!(causes(&output), vec![ExitUndersteerCause::Differential; 2]);
+    }
+
+    #[test]
+    fn test_understeer_building_with_speed_is_aero() {
+        // throttle fed in over 1.5s while the car gains 12 m/s before the lift
+        let throttle = [
+            0.2, 0.3, 0.35, 0.4, 0.45, 0.5, 0.55, 0.6, 0.65, 0.7, 0.75, 0.8, 0.85, 0.9, 0.95, 1.0,
+            0.8, 0.7, 0.9,
+        ];
+        let output = run_with_speed(&throttle, &[0.3; 19], |i| 40.0 + i.min(15) as f32 * 0.8);
+        assert_eq!(causes(&output), vec![ExitUndersteerCause::Aero]);
+    }
+
+    #[test]
+    fn test_late_understeer_without_speed_gain_is_unknown() {
+        let throttle = [
+            0.2, 0.25, 0.3, 0.35, 0.4, 0.45, 0.5, 0.55, 0.6, 0.65, 0.5, 0.4, 0.6,
+        ];
+        let output = run(&throttle, &[0.3; 13]);
+        assert_eq!(causes(&output), vec![ExitUndersteerCause::Unknown]);
+    }
+}
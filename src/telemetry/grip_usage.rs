@@ -0,0 +1,281 @@
+use std::f32::consts::PI;
+
+use super::{
+    TelemetryData,
+    coasting::{COASTING_MIN_SPEED_MPS, CORNER_MIN_STEERING_PCT},
+};
+
+/// Standard gravity, combined acceleration is expressed in g
+pub const STANDARD_GRAVITY_MPS2: f32 = 9.80665;
+/// Percentile of the lap's combined g taken as the observed peak, keeps kerb and
+/// contact spikes from setting the reference
+pub const OBSERVED_PEAK_PERCENTILE: f32 = 0.98;
+/// Corners averaging less than this share of the peak leave grip unused
+pub const UNDER_USED_GRIP_PCT: f32 = 80.0;
+/// Starting point when a peak is configured, typical of a GT car on slicks
+pub const DEFAULT_CONFIGURED_PEAK_G: f32 = 1.8;
+/// Points further apart than this are not used to derive accelerations
+const MAX_SAMPLE_GAP_MS: u128 = 250;
+
+/// Grip used through a single corner
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CornerGripUsage {
+    /// Index of the first telemetry point of the corner within the lap
+    pub start_point: usize,
+    /// Index of the last telemetry point of the corner within the lap
+    pub end_point: usize,
+    /// Average combined g of the corner as a percentage of the peak
+    pub mean_usage_pct: f32,
+    /// Highest combined g of the corner as a percentage of the peak
+    pub peak_usage_pct: f32,
+}
+
+impl CornerGripUsage {
+    /// Returns true when the driver stayed well inside the friction circle
+    pub fn is_under_used(&self) -> bool {
+        self.mean_usage_pct < UNDER_USED_GRIP_PCT
+    }
+}
+
+/// Combined lateral and longitudinal acceleration over a lap, relative to the grip peak.
+///
+/// The peak is either configured, for a car whose limit is known, or observed as a
+/// high percentile of the lap itself. Sims without native acceleration channels, like
+/// ACC, get longitudinal acceleration from the change of speed and lateral acceleration
+/// from speed times yaw rate, the yaw rate itself derived from the heading when needed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GripUsage {
+    /// Reference combined g that counts as 100% usage
+    pub peak_g: f32,
+    /// True when `peak_g` comes from the lap rather than the configuration
+    pub is_peak_observed: bool,
+    /// Combined g of every point of the lap, `None` when it cannot be computed
+    pub combined_g: Vec<Option<f32>>,
+    pub corners: Vec<CornerGripUsage>,
+}
+
+impl GripUsage {
+    pub fn from_lap(points: &[TelemetryData], configured_peak_g: Option<f32>) -> Self {
+        let combined_g = combined_g(points);
+        let (peak_g, is_peak_observed) = match configured_peak_g.filter(|g| *g > 0.) {
+            Some(peak_g) => (peak_g, false),
+            None => (observed_peak_g(&combined_g), true),
+        };
+        let mut usage = GripUsage {
+            peak_g,
+            is_peak_observed,
+            combined_g,
+            corners: Vec::new(),
+        };
+
+        let mut i = 0;
+        while i < points.len() {
+            if !is_cornering(&points[i]) {
+                i += 1;
+                continue;
+            }
+            let start_point = i;
+            while i + 1 < points.len() && is_cornering(&points[i + 1]) {
+                i += 1;
+            }
+            let corner_usage: Vec<f32> = (start_point..=i)
+                .filter_map(|p| usage.usage_pct(p))
+                .collect();
+            if !corner_usage.is_empty() {
+                usage.corners.push(CornerGripUsage {
+                    start_point,
+                    end_point: i,
+                    mean_usage_pct: corner_usage.iter().sum::<f32>() / corner_usage.len() as f32,
+                    peak_usage_pct: corner_usage.iter().copied().fold(0., f32::max),
+                });
+            }
+            i += 1;
+        }
+
+        usage
+    }
+
+    /// Combined g of a point as a percentage of the peak
+    pub fn usage_pct(&self, point: usize) -> Option<f32> {
+        if self.peak_g <= 0. {
+            return None;
+        }
+        let combined_g = (*self.combined_g.get(point)?)?;
+        Some(combined_g / self.peak_g * 100.)
+    }
+
+    /// Average usage over the cornering points of the lap
+    pub fn mean_corner_usage_pct(&self) -> f32 {
+        let (total, points) = self
+            .corners
+            .iter()
+            .flat_map(|c| c.start_point..=c.end_point)
+            .filter_map(|p| self.usage_pct(p))
+            .fold((0., 0), |(total, points), usage| {
+                (total + usage, points + 1)
+            });
+        if points == 0 {
+            return 0.;
+        }
+        total / points as f32
+    }
+
+    pub fn under_used_corners(&self) -> usize {
+        self.corners.iter().filter(|c| c.is_under_used()).count()
+    }
+}
+
+/// Combined g of every point, from the reported accelerations or derived ones
+pub fn combined_g(points: &[TelemetryData]) -> Vec<Option<f32>> {
+    (0..points.len())
+        .map(|i| {
+            let point = &points[i];
+            if point.speed_mps.unwrap_or(0.) < COASTING_MIN_SPEED_MPS {
+                return None;
+            }
+            // derive from the previous point, the first point of a lap looks ahead instead
+            let neighbour = if i > 0 {
+                Some((&points[i - 1], point))
+            } else {
+                points.get(1).map(|next| (point, next))
+            };
+            let neighbour = neighbour.and_then(|(prev, next)| {
+                let dt_ms = next.timestamp_ms.checked_sub(prev.timestamp_ms)?;
+                (dt_ms > 0 && dt_ms <= MAX_SAMPLE_GAP_MS).then_some((
+                    prev,
+                    next,
+                    dt_ms as f32 / 1000.,
+                ))
+            });
+
+            let longitudinal = point.longitudinal_accel_mps2.or_else(|| {
+                let (prev, next, dt_s) = neighbour?;
+                Some((next.speed_mps? - prev.speed_mps?) / dt_s)
+            })?;
+            let lateral = point.lateral_accel_mps2.or_else(|| {
+                let yaw_rate_rps = point.yaw_rate_rps.or_else(|| {
+                    let (prev, next, dt_s) = neighbour?;
+                    Some(wrap_angle(next.yaw_rad? - prev.yaw_rad?) / dt_s)
+                })?;
+                Some(point.speed_mps? * yaw_rate_rps)
+            })?;
+
+            Some(longitudinal.hypot(lateral) / STANDARD_GRAVITY_MPS2)
+        })
+        .collect()
+}
+
+fn observed_peak_g(combined_g: &[Option<f32>]) -> f32 {
+    let mut values: Vec<f32> = combined_g.iter().flatten().copied().collect();
+    if values.is_empty() {
+        return 0.;
+    }
+    values.sort_by(f32::total_cmp);
+    let index = ((values.len() - 1) as f32 * OBSERVED_PEAK_PERCENTILE).round() as usize;
+    values[index]
+}
+
+fn is_cornering(point: &TelemetryData) -> bool {
+    point.steering_pct.unwrap_or(0.).abs() > CORNER_MIN_STEERING_PCT
+}
+
+/// Heading change wrapped to -π..π, the heading jumps when crossing ±π
+fn wrap_angle(angle_rad: f32) -> f32 {
+    (angle_rad + PI).rem_euclid(2. * PI) - PI
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(
+        timestamp_ms: u128,
+        steering_pct: f32,
+        lateral_g: f32,
+        longitudinal_g: f32,
+    ) -> TelemetryData {
+        TelemetryData {
+            timestamp_ms,
+            speed_mps: Some(40.),
+            steering_pct: Some(steering_pct),
+            lateral_accel_mps2: Some(lateral_g * STANDARD_GRAVITY_MPS2),
+            longitudinal_accel_mps2: Some(longitudinal_g * STANDARD_GRAVITY_MPS2),
+            ..TelemetryData::default()
+        }
+    }
+
+    #[test]
+    fn test_combines_lateral_and_longitudinal_g() {
+        let points = vec![point(0, 0.3, 0.6, 0.8)];
+        let combined = combined_g(&points);
+        assert!((combined[0].unwrap() - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_usage_against_configured_peak() {
+        let points = vec![point(0, 0.3, 1.0, 0.), point(100, 0.3, 2.0, 0.)];
+        let usage = GripUsage::from_lap(&points, Some(2.0));
+
+        assert!(!usage.is_peak_observed);
+        assert!((usage.usage_pct(0).unwrap() - 50.).abs() < 1e-3);
+        assert_eq!(usage.corners.len(), 1);
+        assert!((usage.corners[0].mean_usage_pct - 75.).abs() < 1e-3);
+        assert!((usage.corners[0].peak_usage_pct - 100.).abs() < 1e-3);
+        assert!(usage.corners[0].is_under_used());
+    }
+
+    #[test]
+    fn test_flags_corners_under_the_observed_peak() {
+        // first corner at the limit, second corner well inside it
+        let mut points = Vec::new();
+        for i in 0..10 {
+            points.push(point(i * 100, 0.4, 2.0, 0.));
+        }
+        points.push(point(1000, 0., 0., 0.5));
+        for i in 11..20 {
+            points.push(point(i * 100, -0.4, 1.2, 0.));
+        }
+
+        let usage = GripUsage::from_lap(&points, None);
+        assert!(usage.is_peak_observed);
+        assert!((usage.peak_g - 2.0).abs() < 1e-3);
+        assert_eq!(usage.corners.len(), 2);
+        assert!(!usage.corners[0].is_under_used());
+        assert!(usage.corners[1].is_under_used());
+        assert_eq!(usage.under_used_corners(), 1);
+    }
+
+    #[test]
+    fn test_derives_acceleration_without_native_channels() {
+        // ACC: speed gains 1 m/s every 100ms while turning at 0.25 rad/s
+        let points: Vec<_> = (0..3)
+            .map(|i| TelemetryData {
+                timestamp_ms: i * 100,
+                speed_mps: Some(40. + i as f32),
+                yaw_rad: Some(wrap_angle(PI - 0.01 + i as f32 * 0.025)),
+                steering_pct: Some(0.3),
+                ..TelemetryData::default()
+            })
+            .collect();
+
+        let combined = combined_g(&points);
+        // 10 m/s² longitudinal and 41 m/s * 0.25 rad/s lateral, across the ±π heading wrap
+        let expected = 10f32.hypot(41. * 0.25) / STANDARD_GRAVITY_MPS2;
+        assert!((combined[1].unwrap() - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_slow_or_unknown_points_are_skipped() {
+        let slow = TelemetryData {
+            speed_mps: Some(2.),
+            ..point(0, 0.3, 1.0, 0.)
+        };
+        let unknown = TelemetryData {
+            timestamp_ms: 100,
+            speed_mps: Some(40.),
+            ..TelemetryData::default()
+        };
+        assert_eq!(combined_g(&[slow, unknown]), vec![None, None]);
+        assert_eq!(GripUsage::from_lap(&[], None).peak_g, 0.);
+    }
+}
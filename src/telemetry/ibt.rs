@@ -0,0 +1,713 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, ErrorKind, Read, Seek, SeekFrom},
+    path::Path,
+};
+
+use crate::OcypodeError;
+
+use super::{
+    GameSource, SessionInfo, TELEMETRY_FORMAT_VERSION, TelemetryData, TelemetryOutput,
+    TireCompound, TireInfo, TrackConditions,
+};
+
+/// Size of the main header, the variable buffer descriptors included
+const HEADER_LEN: usize = 112;
+/// Size of the disk sub-header that follows the main header in `.ibt` files
+const DISK_SUB_HEADER_LEN: usize = 32;
+const VAR_HEADER_LEN: usize = 144;
+const VAR_NAME_LEN: usize = 32;
+/// `EngineWarnings` bit set while the pit speed limiter is on
+const PIT_SPEED_LIMITER_WARNING: i64 = 0x10;
+
+/// Type of a telemetry variable, as stored in the variable header
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum VarType {
+    Char,
+    Bool,
+    Int,
+    BitField,
+    Float,
+    Double,
+}
+
+impl VarType {
+    fn from_id(id: i32) -> Option<Self> {
+        match id {
+            0 => Some(VarType::Char),
+            1 => Some(VarType::Bool),
+            2 => Some(VarType::Int),
+            3 => Some(VarType::BitField),
+            4 => Some(VarType::Float),
+            5 => Some(VarType::Double),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Var {
+    var_type: VarType,
+    /// Offset of the value within a record
+    offset: usize,
+}
+
+impl Var {
+    fn read(&self, record: &[u8]) -> Option<f64> {
+        let bytes = |len: usize| record.get(self.offset..self.offset + len);
+        Some(match self.var_type {
+            VarType::Char | VarType::Bool => *bytes(1)?.first()? as f64,
+            VarType::Int | VarType::BitField => {
+                i32::from_le_bytes(bytes(4)?.try_into().ok()?) as f64
+            }
+            VarType::Float => f32::from_le_bytes(bytes(4)?.try_into().ok()?) as f64,
+            VarType::Double => f64::from_le_bytes(bytes(8)?.try_into().ok()?),
+        })
+    }
+}
+
+/// Tire temperature variables of one corner of the car
+struct TireVars {
+    carcass: [Option<Var>; 3],
+    surface: [Option<Var>; 3],
+}
+
+impl TireVars {
+    fn new(vars: &HashMap<String, Var>, corner: &str) -> Self {
+        let var = |suffix: &str| vars.get(&format!("{}{}", corner, suffix)).copied();
+        Self {
+            carcass: [var("tempCL"), var("tempCM"), var("tempCR")],
+            surface: [var("tempL"), var("tempM"), var("tempR")],
+        }
+    }
+
+    fn read(&self, record: &[u8]) -> Option<TireInfo> {
+        let read = |vars: &[Option<Var>; 3]| -> Option<[f32; 3]> {
+            let mut temps = [0.; 3];
+            for (temp, var) in temps.iter_mut().zip(vars) {
+                *temp = var.as_ref()?.read(record)? as f32;
+            }
+            Some(temps)
+        };
+        // carcass temperatures are recorded to disk, surface ones only while live
+        let carcass = read(&self.carcass);
+        let surface = read(&self.surface);
+        let carcass = carcass.or(surface)?;
+        let surface = surface.unwrap_or(carcass);
+        Some(TireInfo {
+            left_carcass_temp: carcass[0],
+            middle_carcass_temp: carcass[1],
+            right_carcass_temp: carcass[2],
+            left_surface_temp: surface[0],
+            middle_surface_temp: surface[1],
+            right_surface_temp: surface[2],
+        })
+    }
+}
+
+/// Variables mapped to `TelemetryData`, resolved once from the variable headers
+struct Channels {
+    session_time: Option<Var>,
+    gear: Option<Var>,
+    speed: Option<Var>,
+    rpm: Option<Var>,
+    throttle: Option<Var>,
+    brake: Option<Var>,
+    clutch: Option<Var>,
+    steering_angle: Option<Var>,
+    steering_angle_max: Option<Var>,
+    lap_distance: Option<Var>,
+    lap_distance_pct: Option<Var>,
+    lap: Option<Var>,
+    last_lap_time: Option<Var>,
+    best_lap_time: Option<Var>,
+    on_pit_road: Option<Var>,
+    engine_warnings: Option<Var>,
+    abs_active: Option<Var>,
+    latitude: Option<Var>,
+    longitude: Option<Var>,
+    lateral_accel: Option<Var>,
+    longitudinal_accel: Option<Var>,
+    pitch: Option<Var>,
+    pitch_rate: Option<Var>,
+    roll: Option<Var>,
+    roll_rate: Option<Var>,
+    yaw: Option<Var>,
+    yaw_rate: Option<Var>,
+    tires: [TireVars; 4],
+}
+
+impl Channels {
+    fn new(vars: &HashMap<String, Var>) -> Self {
+        let var = |name: &str| vars.get(name).copied();
+        Self {
+            session_time: var("SessionTime"),
+            gear: var("Gear"),
+            speed: var("Speed"),
+            rpm: var("RPM"),
+            throttle: var("Throttle"),
+            brake: var("Brake"),
+            clutch: var("Clutch"),
+            steering_angle: var("SteeringWheelAngle"),
+            steering_angle_max: var("SteeringWheelAngleMax"),
+            lap_distance: var("LapDist"),
+            lap_distance_pct: var("LapDistPct"),
+            lap: var("Lap"),
+            last_lap_time: var("LapLastLapTime"),
+            best_lap_time: var("LapBestLapTime"),
+            on_pit_road: var("OnPitRoad"),
+            engine_warnings: var("EngineWarnings"),
+            abs_active: var("BrakeABSactive"),
+            latitude: var("Lat"),
+            longitude: var("Lon"),
+            lateral_accel: var("LatAccel"),
+            longitudinal_accel: var("LongAccel"),
+            pitch: var("Pitch"),
+            pitch_rate: var("PitchRate"),
+            roll: var("Roll"),
+            roll_rate: var("RollRate"),
+            yaw: var("Yaw"),
+            yaw_rate: var("YawRate"),
+            tires: ["LF", "RF", "LR", "RR"].map(|corner| TireVars::new(vars, corner)),
+        }
+    }
+}
+
+/// Telemetry of an iRacing `.ibt` file, a single session.
+#[derive(Clone, Debug)]
+pub struct IbtTelemetry {
+    pub session_info: SessionInfo,
+    pub points: Vec<TelemetryData>,
+}
+
+impl IbtTelemetry {
+    /// The session as the records a live capture would have written.
+    pub fn into_outputs(self) -> Vec<TelemetryOutput> {
+        std::iter::once(TelemetryOutput::SessionChange(self.session_info))
+            .chain(
+                self.points
+                    .into_iter()
+                    .map(|p| TelemetryOutput::DataPoint(Box::new(p))),
+            )
+            .collect()
+    }
+}
+
+/// Read an iRacing `.ibt` telemetry file.
+///
+/// See [`read_ibt_with_progress`].
+pub fn read_ibt(path: &Path) -> Result<IbtTelemetry, OcypodeError> {
+    read_ibt_with_progress(path, |_| {})
+}
+
+/// Read an iRacing `.ibt` telemetry file, reporting the number of bytes read after
+/// each record.
+///
+/// iRacing records every variable at the tick rate of the sim, including the
+/// steering, GPS, orientation and tire temperature channels the live capture cannot
+/// access. Points come without annotations, run them through
+/// [`AnalyzerConfig::annotate`](super::analyzer_config::AnalyzerConfig::annotate).
+pub fn read_ibt_with_progress(
+    path: &Path,
+    mut on_progress: impl FnMut(u64),
+) -> Result<IbtTelemetry, OcypodeError> {
+    let file = File::open(path).map_err(|e| OcypodeError::TelemetryLoaderError { source: e })?;
+    let file_len = file
+        .metadata()
+        .map_err(|e| OcypodeError::TelemetryLoaderError { source: e })?
+        .len();
+    let mut reader = BufReader::new(file);
+
+    let mut header = [0u8; HEADER_LEN + DISK_SUB_HEADER_LEN];
+    read_exact(&mut reader, &mut header, "file is too short for the header")?;
+    let int = |offset: usize| i32::from_le_bytes(header[offset..offset + 4].try_into().unwrap());
+    let session_info_len = int(16);
+    let session_info_offset = int(20);
+    let num_vars = int(24);
+    let var_header_offset = int(28);
+    let buf_len = int(36);
+    // the first variable buffer descriptor holds the offset of the first record
+    let records_offset = int(52);
+    let session_start_date_s =
+        i64::from_le_bytes(header[HEADER_LEN..HEADER_LEN + 8].try_into().unwrap());
+    let record_count = int(HEADER_LEN + 28);
+    if num_vars <= 0 || buf_len <= 0 || records_offset <= 0 {
+        return Err(invalid("no telemetry variables in the header"));
+    }
+
+    // sizes come from the header, check them against the file before allocating
+    let var_headers_len = (num_vars as u64)
+        .checked_mul(VAR_HEADER_LEN as u64)
+        .ok_or_else(|| invalid("variable headers past the end of the file"))?;
+    check_block(
+        file_len,
+        var_header_offset,
+        var_headers_len,
+        "variable headers past the end of the file",
+    )?;
+    let mut var_headers = vec![0u8; var_headers_len as usize];
+    seek(&mut reader, var_header_offset)?;
+    read_exact(&mut reader, &mut var_headers, "truncated variable headers")?;
+    let vars: HashMap<String, Var> = var_headers
+        .chunks_exact(VAR_HEADER_LEN)
+        .filter_map(|h| {
+            let var_type = VarType::from_id(i32::from_le_bytes(h[0..4].try_into().unwrap()))?;
+            let offset = i32::from_le_bytes(h[4..8].try_into().unwrap());
+            let name = &h[16..16 + VAR_NAME_LEN];
+            let name = &name[..name.iter().position(|b| *b == 0).unwrap_or(VAR_NAME_LEN)];
+            Some((
+                String::from_utf8_lossy(name).to_string(),
+                Var {
+                    var_type,
+                    offset: usize::try_from(offset).ok()?,
+                },
+            ))
+        })
+        .collect();
+    let channels = Channels::new(&vars);
+
+    let session_info_len = session_info_len.max(0) as u64;
+    check_block(
+        file_len,
+        session_info_offset,
+        session_info_len,
+        "session info past the end of the file",
+    )?;
+    let mut session_yaml = vec![0u8; session_info_len as usize];
+    seek(&mut reader, session_info_offset)?;
+    read_exact(&mut reader, &mut session_yaml, "truncated session info")?;
+    let session_yaml = String::from_utf8_lossy(&session_yaml);
+
+    check_block(
+        file_len,
+        records_offset,
+        buf_len as u64,
+        "record size past the end of the file",
+    )?;
+    let records_len = (record_count.max(0) as u64)
+        .checked_mul(buf_len as u64)
+        .ok_or_else(|| invalid("record count past the end of the file"))?;
+    check_block(
+        file_len,
+        records_offset,
+        records_len,
+        "record count past the end of the file",
+    )?;
+    seek(&mut reader, records_offset)?;
+    let mut record = vec![0u8; buf_len as usize];
+    let mut bytes_read = records_offset as u64;
+    let mut points = Vec::with_capacity(record_count.max(0) as usize);
+    let mut max_steering_angle = None;
+    loop {
+        // the record count is left at zero when iRacing did not close the file
+        if record_count > 0 && points.len() >= record_count as usize {
+            break;
+        }
+        match reader.read_exact(&mut record) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(OcypodeError::TelemetryLoaderError { source: e }),
+        }
+        bytes_read += record.len() as u64;
+        on_progress(bytes_read);
+
+        if max_steering_angle.is_none() {
+            max_steering_angle = read(&channels.steering_angle_max, &record).map(|a| a as f32);
+        }
+        points.push(telemetry_point(
+            &channels,
+            &record,
+            points.len(),
+            session_start_date_s,
+            max_steering_angle,
+        ));
+    }
+
+    Ok(IbtTelemetry {
+        session_info: session_info(&session_yaml, max_steering_angle),
+        points,
+    })
+}
+
+fn telemetry_point(
+    channels: &Channels,
+    record: &[u8],
+    point_no: usize,
+    session_start_date_s: i64,
+    max_steering_angle: Option<f32>,
+) -> TelemetryData {
+    let f32_of = |var: &Option<Var>| read(var, record).map(|v| v as f32);
+    let bool_of = |var: &Option<Var>| read(var, record).map(|v| v != 0.);
+
+    let session_time_s = read(&channels.session_time, record).unwrap_or(0.);
+    let timestamp_ms =
+        (session_start_date_s.max(0) as f64 * 1000. + session_time_s * 1000.).max(0.) as u128;
+    let lap_distance_pct = f32_of(&channels.lap_distance_pct);
+    let [lf_tire_info, rf_tire_info, lr_tire_info, rr_tire_info] =
+        channels.tires.each_ref().map(|tire| tire.read(record));
+
+    TelemetryData {
+        point_no,
+        timestamp_ms,
+        game_source: GameSource::IRacing,
+        gear: read(&channels.gear, record).map(|g| g as i8),
+        speed_mps: f32_of(&channels.speed),
+        engine_rpm: f32_of(&channels.rpm),
+        throttle: f32_of(&channels.throttle),
+        brake: f32_of(&channels.brake),
+        // 1.0 is fully engaged in iRacing, the pedal position is the opposite
+        clutch: f32_of(&channels.clutch).map(|c| 1. - c),
+        steering_angle_rad: f32_of(&channels.steering_angle),
+        lap_distance_m: f32_of(&channels.lap_distance),
+        lap_distance_pct,
+        lap_number: read(&channels.lap, record).map(|l| l.max(0.) as u32),
+        track_position_pct: lap_distance_pct,
+        last_lap_time_s: f32_of(&channels.last_lap_time).filter(|t| *t > 0.),
+        best_lap_time_s: f32_of(&channels.best_lap_time).filter(|t| *t > 0.),
+        is_pit_limiter_engaged: read(&channels.engine_warnings, record)
+            .map(|w| w as i64 & PIT_SPEED_LIMITER_WARNING != 0),
+        is_in_pit_lane: bool_of(&channels.on_pit_road),
+        is_abs_active: bool_of(&channels.abs_active),
+        latitude_deg: f32_of(&channels.latitude),
+        longitude_deg: f32_of(&channels.longitude),
+        lateral_accel_mps2: f32_of(&channels.lateral_accel),
+        longitudinal_accel_mps2: f32_of(&channels.longitudinal_accel),
+        pitch_rad: f32_of(&channels.pitch),
+        pitch_rate_rps: f32_of(&channels.pitch_rate),
+        roll_rad: f32_of(&channels.roll),
+        roll_rate_rps: f32_of(&channels.roll_rate),
+        yaw_rad: f32_of(&channels.yaw),
+        yaw_rate_rps: f32_of(&channels.yaw_rate),
+        lf_tire_info,
+        rf_tire_info,
+        lr_tire_info,
+        rr_tire_info,
+        ..TelemetryData::default()
+    }
+    .sanitized()
+    .calibrated_steering(max_steering_angle.unwrap_or(0.))
+}
+
+fn session_info(yaml: &str, max_steering_angle: Option<f32>) -> SessionInfo {
+    let weekend = |key: &str| session_info_value(yaml, "WeekendInfo", key);
+    let weekend_id = |key: &str| weekend(key).and_then(|v| v.parse::<i32>().ok());
+    let car_name = session_info_value(yaml, "DriverInfo", "DriverCarIdx")
+        .and_then(|idx| driver_value(yaml, idx, "CarScreenName"))
+        .map(|name| name.to_string());
+
+    SessionInfo {
+        track_name: weekend("TrackDisplayName").unwrap_or("Unknown").to_string(),
+        track_configuration: weekend("TrackConfigName").unwrap_or("").to_string(),
+        max_steering_angle: max_steering_angle.unwrap_or(std::f32::consts::PI),
+        track_length: weekend("TrackLength").unwrap_or("").to_string(),
+        game_source: GameSource::IRacing,
+        we_series_id: weekend_id("SeriesID"),
+        we_session_id: weekend_id("SessionID"),
+        we_season_id: weekend_id("SeasonID"),
+        we_sub_session_id: weekend_id("SubSessionID"),
+        we_league_id: weekend_id("LeagueID"),
+        tire_compound: TireCompound::Unknown,
+        tire_compound_name: None,
+        car_name,
+        track_conditions: weekend("TrackPrecipitation")
+            .and_then(percent_value)
+            .map(TrackConditions::from_precipitation_pct)
+            .unwrap_or_default(),
+        relative_humidity_pct: weekend("TrackRelativeHumidity").and_then(percent_value),
+        format_version: TELEMETRY_FORMAT_VERSION,
+    }
+}
+
+/// Value of a direct child of a top-level section of the session info YAML.
+///
+/// The session info is a flat, one-space indented YAML document, scanning lines is
+/// enough for the handful of values we need.
+fn session_info_value<'a>(yaml: &'a str, section: &str, key: &str) -> Option<&'a str> {
+    section_lines(yaml, section).find_map(|line| {
+        let value = line
+            .strip_prefix(' ')?
+            .strip_prefix(key)?
+            .strip_prefix(':')?;
+        Some(yaml_scalar(value))
+    })
+}
+
+/// Value of a field of the driver entry for `car_idx` in `DriverInfo.Drivers`
+fn driver_value<'a>(yaml: &'a str, car_idx: &str, key: &str) -> Option<&'a str> {
+    let mut in_driver = false;
+    for line in section_lines(yaml, "DriverInfo") {
+        let trimmed = line.trim_start();
+        if let Some(entry) = trimmed.strip_prefix("- ") {
+            in_driver = entry
+                .strip_prefix("CarIdx:")
+                .is_some_and(|idx| yaml_scalar(idx) == car_idx);
+            continue;
+        }
+        // fields of a list entry are indented past the dash
+        if !line.starts_with("   ") {
+            in_driver = false;
+        }
+        if in_driver
+            && let Some(value) = trimmed.strip_prefix(key).and_then(|v| v.strip_prefix(':'))
+        {
+            return Some(yaml_scalar(value));
+        }
+    }
+    None
+}
+
+fn section_lines<'a>(yaml: &'a str, section: &str) -> impl Iterator<Item = &'a str> {
+    let header = format!("{}:", section);
+    yaml.lines()
+        .skip_while(move |line| line.trim_end() != header)
+        .skip(1)
+        .take_while(|line| line.is_empty() || line.starts_with(' '))
+}
+
+fn yaml_scalar(value: &str) -> &str {
+    value.trim().trim_matches('"')
+}
+
+/// Number of a session info percentage such as `55 %`
+pub(crate) fn percent_value(value: &str) -> Option<f32> {
+    value.trim().trim_end_matches('%').trim().parse().ok()
+}
+
+fn read(var: &Option<Var>, record: &[u8]) -> Option<f64> {
+    var.as_ref()?.read(record)
+}
+
+fn invalid(reason: &str) -> OcypodeError {
+    OcypodeError::InvalidIbtFile {
+        reason: reason.to_string(),
+    }
+}
+
+fn seek(reader: &mut BufReader<File>, offset: i32) -> Result<(), OcypodeError> {
+    let offset = u64::try_from(offset).map_err(|_| invalid("negative offset in the header"))?;
+    reader
+        .seek(SeekFrom::Start(offset))
+        .map_err(|e| OcypodeError::TelemetryLoaderError { source: e })?;
+    Ok(())
+}
+
+/// Fails when the `len` bytes at `offset` do not fit in a file of `file_len` bytes.
+fn check_block(file_len: u64, offset: i32, len: u64, reason: &str) -> Result<(), OcypodeError> {
+    let offset = u64::try_from(offset).map_err(|_| invalid("negative offset in the header"))?;
+    match offset.checked_add(len) {
+        Some(end) if end <= file_len => Ok(()),
+        _ => Err(invalid(reason)),
+    }
+}
+
+fn read_exact(
+    reader: &mut BufReader<File>,
+    buf: &mut [u8],
+    reason: &str,
+) -> Result<(), OcypodeError> {
+    reader.read_exact(buf).map_err(|e| {
+        if e.kind() == ErrorKind::UnexpectedEof {
+            invalid(reason)
+        } else {
+            OcypodeError::TelemetryLoaderError { source: e }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    const SESSION_YAML: &str = "---
+WeekendInfo:
+ TrackName: okayama full
+ TrackDisplayName: Okayama International Circuit
+ TrackConfigName: Full Course
+ TrackLength: 3.70 km
+ TrackRelativeHumidity: 83 %
+ TrackPrecipitation: 5 %
+ SeriesID: 0
+ SubSessionID: 12345
+ WeekendOptions:
+  NumStarters: 0
+DriverInfo:
+ DriverCarIdx: 1
+ Drivers:
+ - CarIdx: 0
+   UserName: Pace Car
+   CarScreenName: safety pcporsche911cup
+ - CarIdx: 1
+   UserName: Test Driver
+   CarScreenName: \"Porsche 911 GT3 R\"
+...
+";
+
+    /// Write an `.ibt` file with float variables, one record per entry of `records`
+    fn write_ibt(names: &[&str], records: &[Vec<f32>], record_count: i32) -> NamedTempFile {
+        let var_header_offset = HEADER_LEN + DISK_SUB_HEADER_LEN;
+        let session_info_offset = var_header_offset + names.len() * VAR_HEADER_LEN;
+        let records_offset = session_info_offset + SESSION_YAML.len();
+        let buf_len = names.len() * 4;
+
+        let mut bytes = vec![0u8; records_offset];
+        let mut put = |offset: usize, value: &[u8]| {
+            bytes[offset..offset + value.len()].copy_from_slice(value)
+        };
+        put(0, &2i32.to_le_bytes());
+        put(8, &60i32.to_le_bytes());
+        put(16, &(SESSION_YAML.len() as i32).to_le_bytes());
+        put(20, &(session_info_offset as i32).to_le_bytes());
+        put(24, &(names.len() as i32).to_le_bytes());
+        put(28, &(var_header_offset as i32).to_le_bytes());
+        put(32, &1i32.to_le_bytes());
+        put(36, &(buf_len as i32).to_le_bytes());
+        put(52, &(records_offset as i32).to_le_bytes());
+        put(HEADER_LEN, &1_700_000_000i64.to_le_bytes());
+        put(HEADER_LEN + 28, &record_count.to_le_bytes());
+        for (i, name) in names.iter().enumerate() {
+            let header = var_header_offset + i * VAR_HEADER_LEN;
+            put(header, &4i32.to_le_bytes());
+            put(header + 4, &(i as i32 * 4).to_le_bytes());
+            put(header + 8, &1i32.to_le_bytes());
+            put(header + 16, name.as_bytes());
+        }
+        put(session_info_offset, SESSION_YAML.as_bytes());
+        for record in records {
+            for value in record {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        let mut file = NamedTempFile::with_suffix(".ibt").unwrap();
+        file.write_all(&bytes).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_reads_records_and_session_info() {
+        let names = [
+            "SessionTime",
+            "Speed",
+            "Throttle",
+            "Clutch",
+            "SteeringWheelAngle",
+            "SteeringWheelAngleMax",
+            "Lap",
+            "LapDistPct",
+        ];
+        let records = vec![
+            vec![0.5, 40.0, 1.0, 1.0, 1.5, 6.0, 1.0, 0.1],
+            vec![0.6, 41.0, 0.8, 0.75, -3.0, 6.0, 2.0, 0.0],
+        ];
+        let file = write_ibt(&names, &records, 2);
+
+        let mut progress = Vec::new();
+        let telemetry = read_ibt_with_progress(file.path(), |bytes| progress.push(bytes)).unwrap();
+
+        assert_eq!(telemetry.points.len(), 2);
+        assert_eq!(progress.len(), 2);
+        let point = &telemetry.points[1];
+        assert_eq!(point.point_no, 1);
+        assert_eq!(point.timestamp_ms, 1_700_000_000_600);
+        assert_eq!(point.speed_mps, Some(41.0));
+        assert_eq!(point.throttle, Some(0.8));
+        assert_eq!(point.clutch, Some(0.25));
+        assert_eq!(point.steering_pct, Some(-0.5));
+        assert_eq!(point.lap_number, Some(2));
+        assert_eq!(point.lap_distance_pct, Some(0.0));
+        assert_eq!(point.brake, None);
+        assert_eq!(point.lf_tire_info, None);
+
+        let info = &telemetry.session_info;
+        assert_eq!(info.track_name, "Okayama International Circuit");
+        assert_eq!(info.track_configuration, "Full Course");
+        assert_eq!(info.track_length, "3.70 km");
+        assert_eq!(info.max_steering_angle, 6.0);
+        assert_eq!(info.we_sub_session_id, Some(12345));
+        assert_eq!(info.car_name.as_deref(), Some("Porsche 911 GT3 R"));
+        assert!(info.validate().is_ok());
+    }
+
+    #[test]
+    fn test_reads_tire_temperatures() {
+        let names = ["LFtempCL", "LFtempCM", "LFtempCR"];
+        let file = write_ibt(&names, &[vec![80.0, 82.0, 84.0]], 1);
+
+        let point = &read_ibt(file.path()).unwrap().points[0];
+        let lf = point.lf_tire_info.as_ref().unwrap();
+        assert_eq!(lf.left_carcass_temp, 80.0);
+        assert_eq!(lf.right_surface_temp, 84.0);
+        assert_eq!(point.rf_tire_info, None);
+    }
+
+    #[test]
+    fn test_reads_until_end_of_unclosed_file() {
+        // iRacing leaves the record count at zero when it did not finish the file
+        let records = vec![vec![0.1], vec![0.2], vec![0.3]];
+        let file = write_ibt(&["SessionTime"], &records, 0);
+        assert_eq!(read_ibt(file.path()).unwrap().points.len(), 3);
+    }
+
+    #[test]
+    fn test_rejects_truncated_file() {
+        let mut file = NamedTempFile::with_suffix(".ibt").unwrap();
+        file.write_all(&[0u8; 16]).unwrap();
+        assert!(matches!(
+            read_ibt(file.path()),
+            Err(OcypodeError::InvalidIbtFile { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rejects_sizes_past_the_end_of_the_file() {
+        let records = vec![vec![0.1], vec![0.2]];
+        // a corrupt header must not make the reader allocate what the file cannot hold
+        for (offset, value) in [(24, i32::MAX), (16, i32::MAX), (36, i32::MAX)] {
+            let file = write_ibt(&["SessionTime"], &records, 2);
+            let mut bytes = std::fs::read(file.path()).unwrap();
+            bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+            std::fs::write(file.path(), bytes).unwrap();
+            assert!(matches!(
+                read_ibt(file.path()),
+                Err(OcypodeError::InvalidIbtFile { .. })
+            ));
+        }
+        let file = write_ibt(&["SessionTime"], &records, 3);
+        assert!(matches!(
+            read_ibt(file.path()),
+            Err(OcypodeError::InvalidIbtFile { .. })
+        ));
+    }
+
+    #[test]
+    fn test_weather_from_session_info() {
+        let info = session_info(SESSION_YAML, None);
+        assert_eq!(info.track_conditions, TrackConditions::Damp);
+        assert_eq!(info.relative_humidity_pct, Some(83.));
+
+        let dry = SESSION_YAML.replace("TrackPrecipitation: 5 %", "TrackPrecipitation: 0 %");
+        assert_eq!(
+            session_info(&dry, None).track_conditions,
+            TrackConditions::Dry
+        );
+    }
+
+    #[test]
+    fn test_session_info_value_ignores_nested_keys() {
+        assert_eq!(
+            session_info_value(SESSION_YAML, "WeekendInfo", "NumStarters"),
+            None
+        );
+        assert_eq!(
+            session_info_value(SESSION_YAML, "DriverInfo", "DriverCarIdx"),
+            Some("1")
+        );
+        assert_eq!(
+            driver_value(SESSION_YAML, "0", "UserName"),
+            Some("Pace Car")
+        );
+    }
+}
@@ -0,0 +1,408 @@
+use std::collections::VecDeque;
+
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{SessionInfo, TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+
+/// Speed below which suspension events are ignored, keeps the pit box and garage out
+const MIN_SPEED_MPS: f32 = 10.0;
+/// Change in pitch or roll rate per second that counts as a strike (rad/s²)
+const MIN_IMPACT_RAD_S2: f32 = 5.0;
+/// Points further apart than this are not compared, the rates between them are meaningless
+const MAX_SAMPLE_GAP_MS: u128 = 250;
+/// Strikes closer together than this belong to the same kerb
+const STRIKE_COOLDOWN_MS: u128 = 500;
+/// Lap distance (fraction of the lap) within which two strikes are at the same spot
+const SAME_SPOT_LAP_PCT: f32 = 0.005;
+/// Strikes at the same spot on earlier laps before the spot is flagged as repeated
+const MIN_PRIOR_STRIKES: usize = 2;
+/// Strike locations remembered, older ones are dropped first
+const MAX_STRIKE_LOCATIONS: usize = 500;
+/// Drop in lap distance that means the car crossed the start/finish line
+const LAP_WRAP_PCT: f32 = 0.5;
+
+/// Pitch and roll of the previous point, with the rates reported by the sim or
+/// derived from the angles
+struct MotionSample {
+    timestamp_ms: u128,
+    pitch_rad: Option<f32>,
+    roll_rad: Option<f32>,
+    pitch_rate_rps: Option<f32>,
+    roll_rate_rps: Option<f32>,
+}
+
+/// Detects kerb strikes and bumps that unsettle the car.
+///
+/// A kerb or a bump kicks the chassis, so the pitch or roll rate changes much faster
+/// than it does under braking or turn-in. Sims that do not report the rates, like ACC,
+/// get them derived from consecutive pitch and roll angles. Each strike is annotated
+/// with its impact and lap distance, and strikes that keep happening at the same spot
+/// lap after lap are flagged as repeated, pointing at the line or kerb usage there.
+pub(crate) struct KerbStrikeAnalyzer {
+    prev_sample: Option<MotionSample>,
+    last_strike_ms: Option<u128>,
+    /// Lap number and lap distance of the previous point with a lap distance
+    prev_lap_position: Option<(Option<u32>, f32)>,
+    /// Laps started since the analyzer was created, counted from lap number changes
+    /// or, when the sim reports no lap number, from the lap distance wrapping
+    lap_index: usize,
+    /// Lap index and lap distance of the strikes seen so far
+    strike_locations: VecDeque<(usize, f32)>,
+}
+
+impl KerbStrikeAnalyzer {
+    pub(crate) fn new() -> Self {
+        Self {
+            prev_sample: None,
+            last_strike_ms: None,
+            prev_lap_position: None,
+            lap_index: 0,
+            strike_locations: VecDeque::new(),
+        }
+    }
+
+    /// Advances the lap index when the lap number changes, or when the lap distance
+    /// wraps around the start/finish line if the sim reports no lap number.
+    fn track_lap(&mut self, lap_number: Option<u32>, lap_distance_pct: Option<f32>) {
+        let Some(pct) = lap_distance_pct else {
+            return;
+        };
+        if let Some((prev_lap_number, prev_pct)) = self.prev_lap_position {
+            let new_lap = match (prev_lap_number, lap_number) {
+                (Some(prev), Some(current)) => prev != current,
+                _ => prev_pct - pct > LAP_WRAP_PCT,
+            };
+            if new_lap {
+                self.lap_index += 1;
+            }
+        }
+        self.prev_lap_position = Some((lap_number, pct));
+    }
+
+    /// Strikes on other laps within `SAME_SPOT_LAP_PCT` of `lap_distance_pct`.
+    fn prior_strikes_at(&self, lap_distance_pct: f32) -> usize {
+        self.strike_locations
+            .iter()
+            .filter(|(lap, pct)| {
+                *lap != self.lap_index && lap_distance(*pct, lap_distance_pct) <= SAME_SPOT_LAP_PCT
+            })
+            .count()
+    }
+}
+
+/// Distance between two lap distance percentages, wrapping around the start/finish line
+fn lap_distance(a: f32, b: f32) -> f32 {
+    let distance = (a - b).abs();
+    distance.min(1.0 - distance)
+}
+
+/// Rate reported by the sim, or the change of the angle since the previous point
+fn rate(
+    reported_rps: Option<f32>,
+    angle_rad: Option<f32>,
+    prev_angle_rad: Option<f32>,
+    dt_s: f32,
+) -> Option<f32> {
+    reported_rps.or_else(|| Some((angle_rad? - prev_angle_rad?) / dt_s))
+}
+
+/// Change of rate per second, `None` when either rate is unknown
+fn rate_change(rate_rps: Option<f32>, prev_rate_rps: Option<f32>, dt_s: f32) -> Option<f32> {
+    Some(((rate_rps? - prev_rate_rps?) / dt_s).abs())
+}
+
+impl TelemetryAnalyzer for KerbStrikeAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        _session_info: &SessionInfo,
+    ) -> Vec<TelemetryAnnotation> {
+        let mut output = Vec::new();
+        self.track_lap(telemetry.lap_number, telemetry.lap_distance_pct);
+
+        if !is_telemetry_point_analyzable(telemetry)
+            || telemetry.speed_mps.unwrap_or(0.0) < MIN_SPEED_MPS
+        {
+            self.prev_sample = None;
+            return output;
+        }
+
+        let prev_sample = self.prev_sample.take().filter(|prev| {
+            telemetry.timestamp_ms > prev.timestamp_ms
+                && telemetry.timestamp_ms - prev.timestamp_ms <= MAX_SAMPLE_GAP_MS
+        });
+        let mut sample = MotionSample {
+            timestamp_ms: telemetry.timestamp_ms,
+            pitch_rad: telemetry.pitch_rad,
+            roll_rad: telemetry.roll_rad,
+            pitch_rate_rps: telemetry.pitch_rate_rps,
+            roll_rate_rps: telemetry.roll_rate_rps,
+        };
+
+        if let Some(prev) = prev_sample {
+            let dt_s = (sample.timestamp_ms - prev.timestamp_ms) as f32 / 1000.0;
+            sample.pitch_rate_rps = rate(
+                sample.pitch_rate_rps,
+                sample.pitch_rad,
+                prev.pitch_rad,
+                dt_s,
+            );
+            sample.roll_rate_rps = rate(sample.roll_rate_rps, sample.roll_rad, prev.roll_rad, dt_s);
+
+            let impact = rate_change(sample.pitch_rate_rps, prev.pitch_rate_rps, dt_s)
+                .into_iter()
+                .chain(rate_change(sample.roll_rate_rps, prev.roll_rate_rps, dt_s))
+                .reduce(f32::max);
+            let in_cooldown = self
+                .last_strike_ms
+                .is_some_and(|last| sample.timestamp_ms.saturating_sub(last) < STRIKE_COOLDOWN_MS);
+
+            if let Some(impact_rad_s2) = impact
+                && impact_rad_s2 >= MIN_IMPACT_RAD_S2
+                && !in_cooldown
+            {
+                self.last_strike_ms = Some(sample.timestamp_ms);
+
+                let prior_strikes_at_location = match telemetry.lap_distance_pct {
+                    Some(pct) => {
+                        let prior = self.prior_strikes_at(pct);
+                        if self.strike_locations.len() >= MAX_STRIKE_LOCATIONS {
+                            self.strike_locations.pop_front();
+                        }
+                        self.strike_locations.push_back((self.lap_index, pct));
+                        prior
+                    }
+                    None => 0,
+                };
+
+                output.push(TelemetryAnnotation::KerbStrike {
+                    impact_rad_s2,
+                    lap_distance_pct: telemetry.lap_distance_pct,
+                    prior_strikes_at_location,
+                    is_repeated: prior_strikes_at_location >= MIN_PRIOR_STRIKES,
+                });
+            }
+        }
+
+        self.prev_sample = Some(sample);
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(timestamp_ms: u128, pitch_rate_rps: f32) -> TelemetryData {
+        TelemetryData {
+            timestamp_ms,
+            speed_mps: Some(40.0),
+            pitch_rate_rps: Some(pitch_rate_rps),
+            roll_rate_rps: Some(0.0),
+            lap_distance_pct: Some(0.25),
+            lap_number: Some(1),
+            ..TelemetryData::default()
+        }
+    }
+
+    /// Smooth running with a single pitch spike in the middle
+    fn pitch_spike(start_ms: u128, lap_number: u32) -> Vec<TelemetryData> {
+        (0..10)
+            .map(|i| {
+                let pitch_rate = if i == 5 { 1.0 } else { 0.02 };
+                TelemetryData {
+                    lap_number: Some(lap_number),
+                    ..point(start_ms + i * 100, pitch_rate)
+                }
+            })
+            .collect()
+    }
+
+    fn run(
+        analyzer: &mut KerbStrikeAnalyzer,
+        points: &[TelemetryData],
+    ) -> Vec<TelemetryAnnotation> {
+        let session_info = SessionInfo::default();
+        points
+            .iter()
+            .flat_map(|p| analyzer.analyze(p, &session_info))
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_pitch_spike() {
+        let mut analyzer = KerbStrikeAnalyzer::new();
+        let output = run(&mut analyzer, &pitch_spike(0, 1));
+
+        // the way up and down of the spike are the same strike
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::KerbStrike {
+                impact_rad_s2,
+                lap_distance_pct,
+                prior_strikes_at_location,
+                is_repeated,
+            } => {
+                assert!((*impact_rad_s2 - 9.8).abs() < 1e-3);
+                assert_eq!(*lap_distance_pct, Some(0.25));
+                assert_eq!(*prior_strikes_at_location, 0);
+                assert!(!*is_repeated);
+            }
+            _ => panic!("Expected KerbStrike annotation"),
+        }
+    }
+
+    #[test]
+    fn test_ignores_smooth_pitch_changes() {
+        let mut analyzer = KerbStrikeAnalyzer::new();
+        // pitching forward under braking builds up over several points
+        let braking: Vec<_> = (0..10).map(|i| point(i * 100, i as f32 * 0.05)).collect();
+        assert!(run(&mut analyzer, &braking).is_empty());
+    }
+
+    #[test]
+    fn test_derives_rates_from_angles() {
+        let mut analyzer = KerbStrikeAnalyzer::new();
+        // ACC reports the angles only
+        let points: Vec<_> = [0.0, 0.0, 0.0, 0.08, 0.0, 0.0]
+            .into_iter()
+            .enumerate()
+            .map(|(i, roll)| TelemetryData {
+                pitch_rate_rps: None,
+                roll_rate_rps: None,
+                pitch_rad: Some(0.0),
+                roll_rad: Some(roll),
+                ..point(i as u128 * 100, 0.0)
+            })
+            .collect();
+
+        let output = run(&mut analyzer, &points);
+        assert_eq!(output.len(), 1);
+        assert!(matches!(
+            output[0],
+            TelemetryAnnotation::KerbStrike { impact_rad_s2, .. } if (impact_rad_s2 - 8.0).abs() < 1e-3
+        ));
+    }
+
+    #[test]
+    fn test_flags_repeated_strikes_at_same_spot() {
+        let mut analyzer = KerbStrikeAnalyzer::new();
+        let mut output = Vec::new();
+        for lap in 1..=3 {
+            output.extend(run(&mut analyzer, &pitch_spike(lap as u128 * 100_000, lap)));
+        }
+
+        let repeated: Vec<_> = output
+            .iter()
+            .map(|a| match a {
+                TelemetryAnnotation::KerbStrike {
+                    prior_strikes_at_location,
+                    is_repeated,
+                    ..
+                } => (*prior_strikes_at_location, *is_repeated),
+                _ => panic!("Expected KerbStrike annotation"),
+            })
+            .collect();
+        assert_eq!(repeated, vec![(0, false), (1, false), (2, true)]);
+    }
+
+    #[test]
+    fn test_strikes_on_the_same_lap_without_lap_number_are_not_prior() {
+        let mut analyzer = KerbStrikeAnalyzer::new();
+        let without_lap_number = |points: Vec<TelemetryData>| -> Vec<TelemetryData> {
+            points
+                .into_iter()
+                .map(|p| TelemetryData {
+                    lap_number: None,
+                    ..p
+                })
+                .collect()
+        };
+
+        // two strikes at the same spot on the first lap
+        let mut output = run(&mut analyzer, &without_lap_number(pitch_spike(0, 1)));
+        output.extend(run(
+            &mut analyzer,
+            &without_lap_number(pitch_spike(10_000, 1)),
+        ));
+        // the car crosses the line and strikes the same spot again
+        output.extend(run(
+            &mut analyzer,
+            &without_lap_number(vec![
+                TelemetryData {
+                    lap_distance_pct: Some(0.9),
+                    ..point(20_000, 0.02)
+                },
+                TelemetryData {
+                    lap_distance_pct: Some(0.01),
+                    ..point(25_000, 0.02)
+                },
+            ]),
+        ));
+        output.extend(run(
+            &mut analyzer,
+            &without_lap_number(pitch_spike(30_000, 2)),
+        ));
+
+        let prior: Vec<_> = output
+            .iter()
+            .map(|a| match a {
+                TelemetryAnnotation::KerbStrike {
+                    prior_strikes_at_location,
+                    ..
+                } => *prior_strikes_at_location,
+                _ => panic!("Expected KerbStrike annotation"),
+            })
+            .collect();
+        assert_eq!(prior, vec![0, 0, 2]);
+    }
+
+    #[test]
+    fn test_strikes_elsewhere_are_not_repeated() {
+        let mut analyzer = KerbStrikeAnalyzer::new();
+        let mut output = Vec::new();
+        for lap in 1..=3 {
+            let points: Vec<_> = pitch_spike(lap as u128 * 100_000, lap)
+                .into_iter()
+                .map(|p| TelemetryData {
+                    lap_distance_pct: Some(0.1 * lap as f32),
+                    ..p
+                })
+                .collect();
+            output.extend(run(&mut analyzer, &points));
+        }
+
+        assert_eq!(output.len(), 3);
+        assert!(output.iter().all(|a| matches!(
+            a,
+            TelemetryAnnotation::KerbStrike {
+                prior_strikes_at_location: 0,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_ignores_slow_points_and_gaps() {
+        let mut analyzer = KerbStrikeAnalyzer::new();
+        let slow: Vec<_> = pitch_spike(0, 1)
+            .into_iter()
+            .map(|p| TelemetryData {
+                speed_mps: Some(5.0),
+                ..p
+            })
+            .collect();
+        assert!(run(&mut analyzer, &slow).is_empty());
+
+        // the sim paused between the two points
+        let mut analyzer = KerbStrikeAnalyzer::new();
+        let gap = vec![point(0, 0.0), point(1_000, 1.0)];
+        assert!(run(&mut analyzer, &gap).is_empty());
+    }
+
+    #[test]
+    fn test_lap_distance_wraps_around_start_finish() {
+        assert!((lap_distance(0.998, 0.001) - 0.003).abs() < 1e-5);
+        assert!((lap_distance(0.2, 0.3) - 0.1).abs() < 1e-5);
+    }
+}
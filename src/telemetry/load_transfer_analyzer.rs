@@ -0,0 +1,313 @@
+use serde::{Deserialize, Serialize};
+
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{
+    SessionInfo, TelemetryAnalyzer, TelemetryAnnotation, TelemetryData, corners::lateral_g,
+    grip_usage::STANDARD_GRAVITY_MPS2,
+};
+
+/// Acceleration (g) the build-up is timed from, below it the car is settled or coasting
+const TRANSFER_START_G: f32 = 0.2;
+/// Peak acceleration (g) a build-up needs to count as a load transfer, lower peaks are
+/// gentle direction changes that do not load the dampers
+const MIN_TRANSFER_PEAK_G: f32 = 0.8;
+/// Share of the peak at which the load transfer is considered done
+const TRANSFER_DONE_PCT: f32 = 0.9;
+/// Drop below the peak that ends the build-up
+const PEAK_DROP_PCT: f32 = 0.95;
+/// Build-ups longer than this are not a single transient, the car is already cornering
+const MAX_TRANSFER_MS: u128 = 2000;
+/// Transfers slower than this (ms) leave the car lazy to take a set
+pub const DEFAULT_SLOW_LOAD_TRANSFER_MS: u64 = 700;
+/// Transfers faster than this (ms) hit the tires all at once
+pub const DEFAULT_ABRUPT_LOAD_TRANSFER_MS: u64 = 150;
+
+/// Direction of the load transfer
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LoadTransferAxis {
+    /// Side to side, on turn-in
+    Lateral,
+    /// Front to rear, under braking and acceleration
+    Longitudinal,
+}
+
+/// Which way the load transfers of a session lean, the slow-speed dampers speed the
+/// transfer up when stiffened and slow it down when softened
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LoadTransferTiming {
+    /// As many slow transfers as abrupt ones, or none of either
+    #[default]
+    Unknown,
+    /// The car is lazy to take a set
+    Slow,
+    /// The weight slams onto the loaded tires
+    Abrupt,
+}
+
+/// Acceleration build-up being timed on one axis
+struct Transfer {
+    /// Last settled sample followed by the build-up, as timestamp and acceleration (g)
+    samples: Vec<(u128, f32)>,
+    peak_g: f32,
+    peak_rate_g_per_s: f32,
+}
+
+impl Transfer {
+    fn new(settled: (u128, f32)) -> Self {
+        Self {
+            samples: vec![settled],
+            peak_g: settled.1,
+            peak_rate_g_per_s: 0.,
+        }
+    }
+
+    /// Adds a sample, returns true once the build-up is over
+    fn push(&mut self, timestamp_ms: u128, accel_g: f32) -> bool {
+        if let Some((prev_ms, prev_g)) = self.samples.last().copied() {
+            let dt_s = timestamp_ms.saturating_sub(prev_ms) as f32 / 1000.;
+            if dt_s > 0. {
+                self.peak_rate_g_per_s = self.peak_rate_g_per_s.max((accel_g - prev_g) / dt_s);
+            }
+        }
+        self.samples.push((timestamp_ms, accel_g));
+        self.peak_g = self.peak_g.max(accel_g);
+
+        accel_g < self.peak_g * PEAK_DROP_PCT
+            || timestamp_ms.saturating_sub(self.samples[0].0) > MAX_TRANSFER_MS
+    }
+
+    /// Time from the last settled sample until the acceleration reached most of its peak
+    fn transfer_time_ms(&self) -> Option<u128> {
+        let start_ms = self.samples.first()?.0;
+        let done_ms = self
+            .samples
+            .iter()
+            .find(|(_, accel_g)| *accel_g >= self.peak_g * TRANSFER_DONE_PCT)?
+            .0;
+        Some(done_ms.saturating_sub(start_ms))
+    }
+}
+
+/// Build-up tracking for one axis
+#[derive(Default)]
+struct AxisTracker {
+    /// Latest sample below the start threshold, a build-up is only timed from there
+    settled: Option<(u128, f32)>,
+    transfer: Option<Transfer>,
+}
+
+impl AxisTracker {
+    /// Feeds one sample, returns the build-up that just ended
+    fn track(&mut self, timestamp_ms: u128, accel_g: Option<f32>) -> Option<Transfer> {
+        let Some(accel_g) = accel_g.map(f32::abs) else {
+            *self = Self::default();
+            return None;
+        };
+        if let Some(transfer) = self.transfer.as_mut() {
+            if !transfer.push(timestamp_ms, accel_g) {
+                return None;
+            }
+            return self.transfer.take();
+        }
+        if accel_g < TRANSFER_START_G {
+            self.settled = Some((timestamp_ms, accel_g));
+        } else if let Some(settled) = self.settled.take() {
+            let mut transfer = Transfer::new(settled);
+            transfer.push(timestamp_ms, accel_g);
+            self.transfer = Some(transfer);
+        }
+        None
+    }
+}
+
+/// Times how quickly the load moves across and along the car.
+///
+/// The dampers control how fast weight transfers once the driver turns in or brakes:
+/// a car that builds lateral or longitudinal acceleration slowly feels lazy and needs
+/// stiffer slow-speed damping, one that builds it almost instantly overloads the tires
+/// and needs softer damping. Every build-up from below 0.2 g to a peak of at least
+/// 0.8 g is timed to 90% of its peak and reported once it ends, with its peak rate of
+/// change. Lateral acceleration comes from the sim or from speed times yaw rate.
+pub(crate) struct LoadTransferAnalyzer {
+    slow_transfer_ms: u64,
+    abrupt_transfer_ms: u64,
+    lateral: AxisTracker,
+    longitudinal: AxisTracker,
+}
+
+impl LoadTransferAnalyzer {
+    pub(crate) fn new(slow_transfer_ms: u64, abrupt_transfer_ms: u64) -> Self {
+        Self {
+            slow_transfer_ms,
+            abrupt_transfer_ms,
+            lateral: AxisTracker::default(),
+            longitudinal: AxisTracker::default(),
+        }
+    }
+
+    fn annotation(
+        &self,
+        axis: LoadTransferAxis,
+        transfer: Transfer,
+    ) -> Option<TelemetryAnnotation> {
+        if transfer.peak_g < MIN_TRANSFER_PEAK_G {
+            return None;
+        }
+        let transfer_time_ms = transfer.transfer_time_ms()?;
+        Some(TelemetryAnnotation::LoadTransfer {
+            axis,
+            transfer_time_ms,
+            peak_g: transfer.peak_g,
+            peak_rate_g_per_s: transfer.peak_rate_g_per_s,
+            is_slow: transfer_time_ms > u128::from(self.slow_transfer_ms),
+            is_abrupt: transfer_time_ms < u128::from(self.abrupt_transfer_ms),
+        })
+    }
+}
+
+impl TelemetryAnalyzer for LoadTransferAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        _session_info: &SessionInfo,
+    ) -> Vec<TelemetryAnnotation> {
+        if !is_telemetry_point_analyzable(telemetry) {
+            self.lateral = AxisTracker::default();
+            self.longitudinal = AxisTracker::default();
+            return Vec::new();
+        }
+
+        let lateral_g = lateral_g(telemetry);
+        let longitudinal_g = telemetry
+            .longitudinal_accel_mps2
+            .map(|accel| accel / STANDARD_GRAVITY_MPS2);
+
+        let lateral = self.lateral.track(telemetry.timestamp_ms, lateral_g);
+        let longitudinal = self
+            .longitudinal
+            .track(telemetry.timestamp_ms, longitudinal_g);
+        [
+            lateral.and_then(|t| self.annotation(LoadTransferAxis::Lateral, t)),
+            longitudinal.and_then(|t| self.annotation(LoadTransferAxis::Longitudinal, t)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Settled car, then lateral g building up to `peak_g` over `build_up_ms` and held
+    fn turn_in(build_up_ms: u128, peak_g: f32) -> Vec<TelemetryData> {
+        (0..40)
+            .map(|i| {
+                let timestamp_ms = i * 50;
+                let since_turn_in = timestamp_ms.saturating_sub(500);
+                let lateral_g = if timestamp_ms <= 500 {
+                    0.
+                } else if since_turn_in < build_up_ms {
+                    peak_g * since_turn_in as f32 / build_up_ms as f32
+                } else if timestamp_ms < 1700 {
+                    peak_g
+                } else {
+                    0.
+                };
+                TelemetryData {
+                    timestamp_ms,
+                    speed_mps: Some(40.),
+                    lateral_accel_mps2: Some(lateral_g * STANDARD_GRAVITY_MPS2),
+                    longitudinal_accel_mps2: Some(0.),
+                    ..TelemetryData::default()
+                }
+            })
+            .collect()
+    }
+
+    fn run(points: &[TelemetryData]) -> Vec<TelemetryAnnotation> {
+        let mut analyzer = LoadTransferAnalyzer::new(
+            DEFAULT_SLOW_LOAD_TRANSFER_MS,
+            DEFAULT_ABRUPT_LOAD_TRANSFER_MS,
+        );
+        let session_info = SessionInfo::default();
+        points
+            .iter()
+            .flat_map(|point| analyzer.analyze(point, &session_info))
+            .collect()
+    }
+
+    #[test]
+    fn test_times_lateral_transfer() {
+        let output = run(&turn_in(400, 1.5));
+
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::LoadTransfer {
+                axis,
+                transfer_time_ms,
+                peak_g,
+                peak_rate_g_per_s,
+                is_slow,
+                is_abrupt,
+            } => {
+                assert_eq!(*axis, LoadTransferAxis::Lateral);
+                // from the last point below 0.2 g at 550ms to 90% of the peak at 900ms
+                assert_eq!(*transfer_time_ms, 350);
+                assert!((peak_g - 1.5).abs() < 1e-4);
+                assert!((peak_rate_g_per_s - 3.75).abs() < 1e-3);
+                assert!(!is_slow);
+                assert!(!is_abrupt);
+            }
+            _ => panic!("Expected LoadTransfer annotation"),
+        }
+    }
+
+    #[test]
+    fn test_flags_slow_and_abrupt_transfers() {
+        let slow = run(&turn_in(1000, 1.5));
+        assert!(matches!(
+            slow[..],
+            [TelemetryAnnotation::LoadTransfer { is_slow: true, .. }]
+        ));
+
+        let abrupt = run(&turn_in(50, 1.5));
+        assert!(matches!(
+            abrupt[..],
+            [TelemetryAnnotation::LoadTransfer {
+                is_abrupt: true,
+                transfer_time_ms: 50,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn test_ignores_gentle_direction_changes() {
+        assert!(run(&turn_in(400, 0.5)).is_empty());
+    }
+
+    #[test]
+    fn test_times_braking_transfer() {
+        let points: Vec<_> = turn_in(200, 1.2)
+            .into_iter()
+            .map(|p| TelemetryData {
+                lateral_accel_mps2: Some(0.),
+                longitudinal_accel_mps2: p.lateral_accel_mps2.map(|accel| -accel),
+                ..p
+            })
+            .collect();
+
+        let output = run(&points);
+        assert!(matches!(
+            output[..],
+            [TelemetryAnnotation::LoadTransfer {
+                axis: LoadTransferAxis::Longitudinal,
+                transfer_time_ms: 200,
+                ..
+            }]
+        ));
+    }
+}
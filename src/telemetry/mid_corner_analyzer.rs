@@ -2,7 +2,10 @@ use simple_moving_average::{SMA, SumTreeSMA};
 
 use crate::telemetry::is_telemetry_point_analyzable;
 
-use super::{TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+use super::{
+    TelemetryAnalyzer, TelemetryAnnotation, TelemetryData, YawRateFilter,
+    slide_recovery::SlideRecovery,
+};
 
 /// Maximum throttle percentage to consider for mid-corner coasting detection
 const MAX_COASTING_THROTTLE: f32 = 0.15;
@@ -19,14 +22,27 @@ pub(crate) struct MidCornerAnalyzer<const WINDOW_SIZE: usize> {
     prev_speed: f32,
     yaw_to_steering_baseline: SumTreeSMA<f32, f32, WINDOW_SIZE>,
     min_points: usize,
+    yaw_rate_filter: YawRateFilter,
+    slide_recovery: SlideRecovery,
 }
 
 impl<const WINDOW_SIZE: usize> MidCornerAnalyzer<WINDOW_SIZE> {
+    /// Analyzer comparing the raw yaw rate
     pub(crate) fn new(min_points: usize) -> Self {
+        Self::with_yaw_rate_smoothing(min_points, 1)
+    }
+
+    /// Analyzer averaging the yaw rate over `yaw_rate_smoothing_samples` points
+    pub(crate) fn with_yaw_rate_smoothing(
+        min_points: usize,
+        yaw_rate_smoothing_samples: usize,
+    ) -> Self {
         Self {
             prev_speed: 0.0,
             yaw_to_steering_baseline: SumTreeSMA::new(),
             min_points,
+            yaw_rate_filter: YawRateFilter::new(yaw_rate_smoothing_samples),
+            slide_recovery: SlideRecovery::default(),
         }
     }
 }
@@ -38,9 +54,12 @@ impl<const WINDOW_SIZE: usize> TelemetryAnalyzer for MidCornerAnalyzer<WINDOW_SI
         _session_info: &super::SessionInfo,
     ) -> Vec<super::TelemetryAnnotation> {
         let mut output = Vec::new();
+        let mut is_sliding = false;
 
         // Skip analysis if doesn't meet requirements
         if !is_telemetry_point_analyzable(telemetry) {
+            self.yaw_rate_filter.reset();
+            self.slide_recovery.end();
             return output;
         }
 
@@ -56,9 +75,13 @@ impl<const WINDOW_SIZE: usize> TelemetryAnalyzer for MidCornerAnalyzer<WINDOW_SI
             None => {
                 // Update previous speed for next iteration
                 self.prev_speed = cur_speed;
+                self.yaw_rate_filter.reset();
+                self.slide_recovery.end();
                 return output;
             }
         };
+        // None until there are enough samples to smooth out the yaw rate noise
+        let yaw_rate = self.yaw_rate_filter.filter(yaw_rate);
 
         // Only analyze during mid-corner coasting phase (minimal throttle/brake with steering)
         if throttle < MAX_COASTING_THROTTLE
@@ -78,27 +101,36 @@ impl<const WINDOW_SIZE: usize> TelemetryAnalyzer for MidCornerAnalyzer<WINDOW_SI
 
             // Detect oversteer: excessive yaw rate compared to steering input
             // Calculate the ratio of yaw rate to steering input
-            let yaw_to_steering_ratio = yaw_rate.abs() / steering_pct.abs();
-
-            // Once we have enough samples, check if current yaw rate exceeds expected
-            if self.yaw_to_steering_baseline.get_num_samples() >= self.min_points {
-                let expected_ratio = self.yaw_to_steering_baseline.get_average();
-                let expected_yaw_rate = steering_pct.abs() * expected_ratio;
-                let actual_yaw_rate = yaw_rate.abs();
-
-                // Detect oversteer: actual yaw rate significantly exceeds expected
-                if actual_yaw_rate > expected_yaw_rate * OVERSTEER_THRESHOLD {
-                    let yaw_rate_excess = actual_yaw_rate - expected_yaw_rate;
-                    output.push(TelemetryAnnotation::MidCornerOversteer {
-                        yaw_rate_excess,
-                        is_oversteer: true,
-                    });
+            if let Some(yaw_rate) = yaw_rate {
+                let yaw_to_steering_ratio = yaw_rate.abs() / steering_pct.abs();
+
+                // Once we have enough samples, check if current yaw rate exceeds expected
+                if self.yaw_to_steering_baseline.get_num_samples() >= self.min_points {
+                    let expected_ratio = self.yaw_to_steering_baseline.get_average();
+                    let expected_yaw_rate = steering_pct.abs() * expected_ratio;
+                    let actual_yaw_rate = yaw_rate.abs();
+
+                    // Detect oversteer: actual yaw rate significantly exceeds expected
+                    if actual_yaw_rate > expected_yaw_rate * OVERSTEER_THRESHOLD {
+                        let yaw_rate_excess = actual_yaw_rate - expected_yaw_rate;
+                        let is_recovered = self.slide_recovery.track(telemetry, actual_yaw_rate);
+                        output.push(TelemetryAnnotation::MidCornerOversteer {
+                            yaw_rate_excess,
+                            is_oversteer: true,
+                            is_recovered,
+                        });
+                        is_sliding = true;
+                    }
                 }
+
+                // Add sample after detection to maintain clean baseline
+                self.yaw_to_steering_baseline
+                    .add_sample(yaw_to_steering_ratio);
             }
+        }
 
-            // Add sample after detection to maintain clean baseline
-            self.yaw_to_steering_baseline
-                .add_sample(yaw_to_steering_ratio);
+        if !is_sliding {
+            self.slide_recovery.end();
         }
 
         // Update previous speed for next iteration
@@ -180,7 +212,9 @@ mod tests {
             TelemetryAnnotation::MidCornerOversteer {
                 yaw_rate_excess,
                 is_oversteer,
+                is_recovered,
             } => {
+                assert!(!*is_recovered);
                 assert!(*is_oversteer);
                 assert!(*yaw_rate_excess > 0.0);
             }
@@ -0,0 +1,160 @@
+use serde_json::Value;
+
+use super::TelemetryOutput;
+
+/// Version of the recorded telemetry format written by this build, stored in the
+/// `format_version` of every session.
+///
+/// Fields added to [`TelemetryData`](super::TelemetryData) or
+/// [`SessionInfo`](super::SessionInfo) fall back to their default in older recordings
+/// and need no new version. Bump it together with a [`Migration`] in [`MIGRATIONS`]
+/// for changes a default cannot cover, such as a renamed field or a changed unit.
+pub const TELEMETRY_FORMAT_VERSION: u32 = 1;
+
+/// Upgrade of a raw record to `to_version` from the version before it.
+pub(crate) struct Migration {
+    pub(crate) to_version: u32,
+    pub(crate) upgrade: fn(&mut Value),
+}
+
+/// Upgrades applied to the records of older recordings, oldest first. Version 0 covers
+/// recordings made before the format was versioned, which only lack newer fields.
+pub(crate) const MIGRATIONS: &[Migration] = &[];
+
+/// Parses the lines of a recording, upgrading the records of older format versions.
+///
+/// The format version is taken from the last session header read, records before the
+/// first header are taken as version 0. Records that no migration applies to are parsed
+/// straight into [`TelemetryOutput`], the rest go through a [`Value`] to be upgraded.
+pub(crate) struct RecordMigrator {
+    version: u32,
+    migrations: &'static [Migration],
+}
+
+impl RecordMigrator {
+    pub(crate) fn new() -> Self {
+        Self::with_migrations(MIGRATIONS)
+    }
+
+    pub(crate) fn with_migrations(migrations: &'static [Migration]) -> Self {
+        Self {
+            version: 0,
+            migrations,
+        }
+    }
+
+    /// Parse one line of the recording.
+    pub(crate) fn parse(&mut self, line: &str) -> Result<TelemetryOutput, serde_json::Error> {
+        let is_session_change = line.contains("\"SessionChange\"");
+        if !is_session_change && !self.needs_upgrade() {
+            return serde_json::from_str(line);
+        }
+
+        let mut record: Value = serde_json::from_str(line)?;
+        if let Some(session) = record.get("SessionChange") {
+            self.version = session
+                .get("format_version")
+                .and_then(Value::as_u64)
+                .map_or(0, |version| version as u32);
+        }
+        for migration in self.pending_migrations() {
+            (migration.upgrade)(&mut record);
+        }
+        serde_json::from_value(record)
+    }
+
+    fn pending_migrations(&self) -> impl Iterator<Item = &Migration> {
+        let version = self.version;
+        self.migrations
+            .iter()
+            .filter(move |m| m.to_version > version)
+    }
+
+    fn needs_upgrade(&self) -> bool {
+        self.pending_migrations().next().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::SessionInfo;
+
+    /// A change of the speed unit in a hypothetical version 2, from km/h to m/s
+    const SPEED_IN_MPS: &[Migration] = &[Migration {
+        to_version: 2,
+        upgrade: |record| {
+            if let Some(point) = record.get_mut("DataPoint")
+                && let Some(speed) = point.get("speed_kph").and_then(Value::as_f64)
+            {
+                point["speed_mps"] = Value::from(speed / 3.6);
+            }
+        },
+    }];
+
+    #[test]
+    fn test_current_version_round_trips() {
+        let mut migrator = RecordMigrator::new();
+        let session = TelemetryOutput::SessionChange(SessionInfo {
+            car_name: Some("BMW M4 GT3".to_string()),
+            ..SessionInfo::default()
+        });
+
+        let TelemetryOutput::SessionChange(parsed) = migrator
+            .parse(&serde_json::to_string(&session).unwrap())
+            .unwrap()
+        else {
+            panic!("Expected a session change");
+        };
+        assert_eq!(parsed.format_version, TELEMETRY_FORMAT_VERSION);
+        assert_eq!(parsed.car_name.as_deref(), Some("BMW M4 GT3"));
+    }
+
+    #[test]
+    fn test_unversioned_recording_loads() {
+        let mut migrator = RecordMigrator::new();
+
+        let session = migrator
+            .parse(r#"{"SessionChange":{"track_name":"Spa","track_configuration":"GP","max_steering_angle":7.85,"track_length":"7.00 km","game_source":"ACC","we_series_id":null,"we_session_id":null,"we_season_id":null,"we_sub_session_id":null,"we_league_id":null}}"#)
+            .unwrap();
+        let TelemetryOutput::SessionChange(session) = session else {
+            panic!("Expected a session change");
+        };
+        assert_eq!(session.format_version, 0);
+        assert_eq!(session.car_name, None);
+
+        // no annotations, brake pressure or TC flag, which were added later
+        let point = migrator
+            .parse(r#"{"DataPoint":{"point_no":1,"timestamp_ms":1000,"game_source":"ACC","speed_mps":30.5,"throttle":0.8}}"#)
+            .unwrap();
+        let TelemetryOutput::DataPoint(point) = point else {
+            panic!("Expected a data point");
+        };
+        assert_eq!(point.speed_mps, Some(30.5));
+        assert_eq!(point.brake_pressure_pct, None);
+        assert!(point.annotations.is_empty());
+    }
+
+    #[test]
+    fn test_older_records_are_upgraded() {
+        let mut migrator = RecordMigrator::with_migrations(SPEED_IN_MPS);
+        let point = r#"{"DataPoint":{"point_no":1,"timestamp_ms":1000,"game_source":"IRacing","speed_kph":108.0}}"#;
+
+        migrator
+            .parse(r#"{"SessionChange":{"track_name":"Spa","track_configuration":"GP","max_steering_angle":7.85,"track_length":"7.00 km","game_source":"IRacing","format_version":1}}"#)
+            .unwrap();
+        let TelemetryOutput::DataPoint(upgraded) = migrator.parse(point).unwrap() else {
+            panic!("Expected a data point");
+        };
+        assert!((upgraded.speed_mps.unwrap() - 30.).abs() < 1e-4);
+
+        // records of the current version are left alone
+        migrator
+            .parse(r#"{"SessionChange":{"track_name":"Spa","track_configuration":"GP","max_steering_angle":7.85,"track_length":"7.00 km","game_source":"IRacing","format_version":2}}"#)
+            .unwrap();
+        let TelemetryOutput::DataPoint(current) = migrator.parse(point).unwrap() else {
+            panic!("Expected a data point");
+        };
+        assert_eq!(current.speed_mps, None);
+    }
+}
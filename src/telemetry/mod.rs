@@ -1,36 +1,79 @@
+#[cfg(feature = "acc-broadcast")]
+pub(crate) mod acc_broadcast;
+pub(crate) mod analyzer_config;
+pub(crate) mod annotation_stream;
 pub(crate) mod bottoming_out_analyzer;
 pub(crate) mod brake_lock_analyzer;
+pub(crate) mod builder;
+pub(crate) mod camber_analyzer;
+pub(crate) mod clock;
+pub(crate) mod clutch_analyzer;
+pub(crate) mod coasting;
 pub(crate) mod collector;
+pub(crate) mod corner_speed;
+pub(crate) mod corners;
+pub(crate) mod data_quality;
+pub(crate) mod downshift_instability_analyzer;
+pub(crate) mod electronics_intervention_analyzer;
 pub(crate) mod entry_oversteer_analyzer;
+pub(crate) mod entry_steering_analyzer;
+pub(crate) mod exit_efficiency;
+pub(crate) mod exit_throttle_lift_analyzer;
+pub(crate) mod grip_usage;
+pub(crate) mod ibt;
+pub(crate) mod kerb_strike_analyzer;
+pub(crate) mod load_transfer_analyzer;
 pub(crate) mod mid_corner_analyzer;
+pub(crate) mod migration;
+pub(crate) mod over_rev_analyzer;
+pub(crate) mod pedal_overlap_analyzer;
 pub(crate) mod producer;
 pub(crate) mod scrub_analyzer;
+pub(crate) mod shift_point_advisor;
 pub(crate) mod short_shifting_analyzer;
+pub(crate) mod slide_recovery;
 pub(crate) mod slip_analyzer;
+pub(crate) mod steering_reversal_analyzer;
+pub(crate) mod steering_reversals;
+pub(crate) mod stint;
+pub(crate) mod straight_line_stability_analyzer;
+pub(crate) mod throttle_stab_analyzer;
+pub(crate) mod tire_pressure_analyzer;
 pub(crate) mod tire_temperature_analyzer;
+pub(crate) mod tire_warmup;
 pub(crate) mod trailbrake_steering_analyzer;
 pub(crate) mod wheelspin_analyzer;
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, VecDeque},
     fmt::Display,
-    time::{SystemTime, UNIX_EPOCH},
 };
 
+use clock::{Clock, SystemClock};
 pub use collector::collect_telemetry;
+pub use exit_throttle_lift_analyzer::ExitUndersteerCause;
+pub use load_transfer_analyzer::{LoadTransferAxis, LoadTransferTiming};
+pub use migration::TELEMETRY_FORMAT_VERSION;
 
 /// For ACC, estimate optimal shift point as a percentage of max RPM
 /// Most cars benefit from shifting around 85-92% of max RPM for optimal power
 const ACC_OPTIMAL_SHIFT_PCT: f32 = 0.92;
+/// Car speed below which wheel slip ratios are not computed, 5 m/s is 18 km/h
+pub(crate) const MIN_SLIP_RATIO_SPEED_MPS: f32 = 5.0;
 use serde::{Deserialize, Serialize};
 use simetry::Moment;
 
+use crate::OcypodeError;
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum TelemetryAnnotation {
     Slip {
         prev_speed: f32,
         cur_speed: f32,
         is_slip: bool,
+        /// Largest wheel slip ratio of the point, with wheel speeds only
+        #[serde(default)]
+        slip_ratio: Option<f32>,
     },
     Scrub {
         avg_yaw_rate_change: f32,
@@ -46,16 +89,26 @@ pub enum TelemetryAnnotation {
         cur_trailbrake_steering: f32,
         is_excessive_trailbrake_steering: bool,
     },
+    TrailbrakeRelease {
+        brake_release_rate: f32,
+        is_abrupt_release: bool,
+    },
     Wheelspin {
         avg_rpm_increase_per_gear: HashMap<u32, f32>,
         cur_gear: u32,
         cur_rpm_increase: f32,
         is_wheelspin: bool,
+        /// Largest wheel slip ratio of the point, with wheel speeds only
+        #[serde(default)]
+        slip_ratio: Option<f32>,
     },
     EntryOversteer {
         expected_yaw_rate: f32,
         actual_yaw_rate: f32,
         is_oversteer: bool,
+        /// The driver is catching the slide rather than spinning or going off
+        #[serde(default)]
+        is_recovered: bool,
     },
     MidCornerUndersteer {
         speed_loss: f32,
@@ -64,10 +117,19 @@ pub enum TelemetryAnnotation {
     MidCornerOversteer {
         yaw_rate_excess: f32,
         is_oversteer: bool,
+        /// The driver is catching the slide rather than spinning or going off
+        #[serde(default)]
+        is_recovered: bool,
     },
     FrontBrakeLock {
         abs_activation_count: usize,
         is_front_lock: bool,
+        /// Highest brake application rate (pedal fraction per second) before the lock
+        #[serde(default)]
+        peak_application_rate: f32,
+        /// Lock caused by stomping the pedal rather than by sustained pressure
+        #[serde(default)]
+        is_initial_application_lock: bool,
     },
     RearBrakeLock {
         abs_activation_count: usize,
@@ -88,6 +150,212 @@ pub enum TelemetryAnnotation {
         speed_loss: f32,
         is_bottoming: bool,
     },
+    ClutchEngagement {
+        engagement_rpm: f32,
+        rpm_drop: f32,
+        release_time_ms: f32,
+        is_bog: bool,
+        is_clutch_kick: bool,
+    },
+    CamberImbalance {
+        lf_spread: f32,
+        rf_spread: f32,
+        lr_spread: f32,
+        rr_spread: f32,
+        is_imbalanced: bool,
+    },
+    ShiftPoint {
+        from_gear: i8,
+        shift_rpm: f32,
+        optimal_rpm: f32,
+        is_power_curve_optimal: bool,
+        is_over_revving: bool,
+        is_under_revving: bool,
+    },
+    EntrySteeringOvershoot {
+        /// Largest steering input (fraction of full lock) during the turn-in
+        peak_steering_pct: f32,
+        /// Steering taken back out from the peak while still braking
+        unwound_steering_pct: f32,
+        lateral_accel_mps2: f32,
+        is_overshoot: bool,
+    },
+    ElectronicsIntervention {
+        /// Distinct ABS activations in the corner that just ended
+        corner_abs_activations: usize,
+        /// Distinct traction control activations in the corner that just ended
+        corner_tc_activations: usize,
+        /// ABS activations so far in the current lap
+        lap_abs_activations: usize,
+        /// Traction control activations so far in the current lap
+        lap_tc_activations: usize,
+        is_excessive_abs: bool,
+        is_excessive_tc: bool,
+    },
+    StraightLineInstability {
+        /// Standard deviation of the steering input (fraction of full lock) over the window
+        steering_std_dev: f32,
+        /// Number of times the steering changed direction over the window
+        corrections: usize,
+        /// Lowest speed over the window
+        min_speed_mps: f32,
+        is_unstable: bool,
+    },
+    KerbStrike {
+        /// Largest change of pitch or roll rate per second at the strike (rad/s²)
+        impact_rad_s2: f32,
+        lap_distance_pct: Option<f32>,
+        /// Strikes at the same lap distance on earlier laps of the session
+        prior_strikes_at_location: usize,
+        is_repeated: bool,
+    },
+    ExitThrottleLift {
+        /// Throttle taken out from the peak of the exit before it was reapplied
+        dip_depth: f32,
+        /// Lifts so far in the current corner exit, including this one
+        dip_count: usize,
+        is_understeer_lift: bool,
+        /// Likely cause of the understeer, guessed on the first lift of the exit
+        #[serde(default)]
+        cause: ExitUndersteerCause,
+    },
+    PedalOverlap {
+        /// Time both pedals were pressed past the threshold
+        duration_ms: u128,
+        max_throttle: f32,
+        max_brake: f32,
+        is_sustained_overlap: bool,
+    },
+    LoadTransfer {
+        axis: LoadTransferAxis,
+        /// Time from settled to 90% of the peak acceleration
+        transfer_time_ms: u128,
+        peak_g: f32,
+        /// Fastest change of acceleration during the build-up
+        peak_rate_g_per_s: f32,
+        is_slow: bool,
+        is_abrupt: bool,
+    },
+    DownshiftInstability {
+        from_gear: i8,
+        to_gear: i8,
+        /// Rise in engine speed from before the downshift to the peak after it
+        rpm_spike: f32,
+        /// Largest yaw rate beyond what the lateral acceleration explains
+        peak_yaw_excess_rps: f32,
+        is_unstable: bool,
+    },
+    /// Middle of the tread minus the average of its edges (Celsius) per tire, positive
+    /// when the middle is hotter from over-pressure
+    TirePressureImbalance {
+        lf_spread: f32,
+        rf_spread: f32,
+        lr_spread: f32,
+        rr_spread: f32,
+        is_imbalanced: bool,
+    },
+    SteeringReversals {
+        /// Steering direction changes over the window
+        reversals: usize,
+        reversals_per_s: f32,
+        is_busy: bool,
+    },
+    OverRev {
+        /// Highest engine speed while over the limit
+        peak_rpm: f32,
+        /// Max engine RPM reported by the sim plus the configured margin
+        limit_rpm: f32,
+        /// Time the engine spent over the limit so far
+        duration_ms: u128,
+        /// Started right after a downshift, the wheels drove the engine past the limiter
+        is_money_shift: bool,
+        is_over_rev: bool,
+    },
+    /// Scrub or wheel slip while braking for the next corner with the steering still
+    /// unwinding from the last one, in esses and other compound corners
+    TransitionInstability {
+        /// Yaw rate changes of the scrub, 0 when the wheels slipped
+        avg_yaw_rate_change: f32,
+        cur_yaw_rate_change: f32,
+        brake: f32,
+        /// Time since the steering crossed the centre into the new corner
+        since_direction_change_ms: u128,
+        is_unstable: bool,
+        /// Largest wheel slip ratio when the wheels slipped rather than the front scrubbed
+        #[serde(default)]
+        slip_ratio: Option<f32>,
+    },
+    ThrottleStab {
+        /// Throttle direction changes so far in the current corner exit
+        reversals: usize,
+        is_stabbing: bool,
+    },
+}
+
+impl TelemetryAnnotation {
+    /// Returns true when the annotation reports a detected issue, analyzers also emit
+    /// annotations that only carry measurements.
+    pub fn is_active(&self) -> bool {
+        match self {
+            TelemetryAnnotation::Slip { is_slip, .. } => *is_slip,
+            TelemetryAnnotation::Scrub { is_scrubbing, .. } => *is_scrubbing,
+            TelemetryAnnotation::ShortShifting {
+                is_short_shifting, ..
+            } => *is_short_shifting,
+            TelemetryAnnotation::TrailbrakeSteering {
+                is_excessive_trailbrake_steering,
+                ..
+            } => *is_excessive_trailbrake_steering,
+            TelemetryAnnotation::TrailbrakeRelease {
+                is_abrupt_release, ..
+            } => *is_abrupt_release,
+            TelemetryAnnotation::Wheelspin { is_wheelspin, .. } => *is_wheelspin,
+            TelemetryAnnotation::EntryOversteer { is_oversteer, .. } => *is_oversteer,
+            TelemetryAnnotation::MidCornerUndersteer { is_understeer, .. } => *is_understeer,
+            TelemetryAnnotation::MidCornerOversteer { is_oversteer, .. } => *is_oversteer,
+            TelemetryAnnotation::FrontBrakeLock { is_front_lock, .. } => *is_front_lock,
+            TelemetryAnnotation::RearBrakeLock { is_rear_lock, .. } => *is_rear_lock,
+            TelemetryAnnotation::TireOverheating { is_overheating, .. } => *is_overheating,
+            TelemetryAnnotation::TireCold { is_cold, .. } => *is_cold,
+            TelemetryAnnotation::BottomingOut { is_bottoming, .. } => *is_bottoming,
+            TelemetryAnnotation::ClutchEngagement {
+                is_bog,
+                is_clutch_kick,
+                ..
+            } => *is_bog || *is_clutch_kick,
+            TelemetryAnnotation::CamberImbalance { is_imbalanced, .. } => *is_imbalanced,
+            TelemetryAnnotation::ShiftPoint {
+                is_over_revving,
+                is_under_revving,
+                ..
+            } => *is_over_revving || *is_under_revving,
+            TelemetryAnnotation::EntrySteeringOvershoot { is_overshoot, .. } => *is_overshoot,
+            TelemetryAnnotation::ElectronicsIntervention {
+                is_excessive_abs,
+                is_excessive_tc,
+                ..
+            } => *is_excessive_abs || *is_excessive_tc,
+            TelemetryAnnotation::StraightLineInstability { is_unstable, .. } => *is_unstable,
+            TelemetryAnnotation::KerbStrike { is_repeated, .. } => *is_repeated,
+            TelemetryAnnotation::ExitThrottleLift {
+                is_understeer_lift, ..
+            } => *is_understeer_lift,
+            TelemetryAnnotation::PedalOverlap {
+                is_sustained_overlap,
+                ..
+            } => *is_sustained_overlap,
+            TelemetryAnnotation::LoadTransfer {
+                is_slow, is_abrupt, ..
+            } => *is_slow || *is_abrupt,
+            TelemetryAnnotation::DownshiftInstability { is_unstable, .. } => *is_unstable,
+            TelemetryAnnotation::TirePressureImbalance { is_imbalanced, .. } => *is_imbalanced,
+            TelemetryAnnotation::SteeringReversals { is_busy, .. } => *is_busy,
+            // any time over the limit risks the engine
+            TelemetryAnnotation::OverRev { is_over_rev, .. } => *is_over_rev,
+            TelemetryAnnotation::TransitionInstability { is_unstable, .. } => *is_unstable,
+            TelemetryAnnotation::ThrottleStab { is_stabbing, .. } => *is_stabbing,
+        }
+    }
 }
 
 impl Display for TelemetryAnnotation {
@@ -97,6 +365,7 @@ impl Display for TelemetryAnnotation {
                 prev_speed: _,
                 cur_speed: _,
                 is_slip: _,
+                slip_ratio: _,
             } => write!(f, "slip"),
             TelemetryAnnotation::Scrub {
                 avg_yaw_rate_change: _,
@@ -112,16 +381,22 @@ impl Display for TelemetryAnnotation {
                 cur_trailbrake_steering: _,
                 is_excessive_trailbrake_steering: _,
             } => write!(f, "trailbrake"),
+            TelemetryAnnotation::TrailbrakeRelease {
+                brake_release_rate: _,
+                is_abrupt_release: _,
+            } => write!(f, "trailbrake_release"),
             TelemetryAnnotation::Wheelspin {
                 avg_rpm_increase_per_gear: _,
                 cur_gear: _,
                 cur_rpm_increase: _,
                 is_wheelspin: _,
+                slip_ratio: _,
             } => write!(f, "wheelspin"),
             TelemetryAnnotation::EntryOversteer {
                 expected_yaw_rate: _,
                 actual_yaw_rate: _,
                 is_oversteer: _,
+                is_recovered: _,
             } => write!(f, "entry_oversteer"),
             TelemetryAnnotation::MidCornerUndersteer {
                 speed_loss: _,
@@ -130,10 +405,13 @@ impl Display for TelemetryAnnotation {
             TelemetryAnnotation::MidCornerOversteer {
                 yaw_rate_excess: _,
                 is_oversteer: _,
+                is_recovered: _,
             } => write!(f, "mid_corner_oversteer"),
             TelemetryAnnotation::FrontBrakeLock {
                 abs_activation_count: _,
                 is_front_lock: _,
+                peak_application_rate: _,
+                is_initial_application_lock: _,
             } => write!(f, "front_brake_lock"),
             TelemetryAnnotation::RearBrakeLock {
                 abs_activation_count: _,
@@ -154,6 +432,112 @@ impl Display for TelemetryAnnotation {
                 speed_loss: _,
                 is_bottoming: _,
             } => write!(f, "bottoming_out"),
+            TelemetryAnnotation::ClutchEngagement {
+                engagement_rpm: _,
+                rpm_drop: _,
+                release_time_ms: _,
+                is_bog: _,
+                is_clutch_kick: _,
+            } => write!(f, "clutch_engagement"),
+            TelemetryAnnotation::CamberImbalance {
+                lf_spread: _,
+                rf_spread: _,
+                lr_spread: _,
+                rr_spread: _,
+                is_imbalanced: _,
+            } => write!(f, "camber_imbalance"),
+            TelemetryAnnotation::ShiftPoint {
+                from_gear: _,
+                shift_rpm: _,
+                optimal_rpm: _,
+                is_power_curve_optimal: _,
+                is_over_revving: _,
+                is_under_revving: _,
+            } => write!(f, "shift_point"),
+            TelemetryAnnotation::EntrySteeringOvershoot {
+                peak_steering_pct: _,
+                unwound_steering_pct: _,
+                lateral_accel_mps2: _,
+                is_overshoot: _,
+            } => write!(f, "entry_steering_overshoot"),
+            TelemetryAnnotation::ElectronicsIntervention {
+                corner_abs_activations: _,
+                corner_tc_activations: _,
+                lap_abs_activations: _,
+                lap_tc_activations: _,
+                is_excessive_abs: _,
+                is_excessive_tc: _,
+            } => write!(f, "electronics_intervention"),
+            TelemetryAnnotation::StraightLineInstability {
+                steering_std_dev: _,
+                corrections: _,
+                min_speed_mps: _,
+                is_unstable: _,
+            } => write!(f, "straight_line_instability"),
+            TelemetryAnnotation::KerbStrike {
+                impact_rad_s2: _,
+                lap_distance_pct: _,
+                prior_strikes_at_location: _,
+                is_repeated: _,
+            } => write!(f, "kerb_strike"),
+            TelemetryAnnotation::ExitThrottleLift {
+                dip_depth: _,
+                dip_count: _,
+                is_understeer_lift: _,
+                cause: _,
+            } => write!(f, "exit_throttle_lift"),
+            TelemetryAnnotation::PedalOverlap {
+                duration_ms: _,
+                max_throttle: _,
+                max_brake: _,
+                is_sustained_overlap: _,
+            } => write!(f, "pedal_overlap"),
+            TelemetryAnnotation::LoadTransfer {
+                axis: _,
+                transfer_time_ms: _,
+                peak_g: _,
+                peak_rate_g_per_s: _,
+                is_slow: _,
+                is_abrupt: _,
+            } => write!(f, "load_transfer"),
+            TelemetryAnnotation::DownshiftInstability {
+                from_gear: _,
+                to_gear: _,
+                rpm_spike: _,
+                peak_yaw_excess_rps: _,
+                is_unstable: _,
+            } => write!(f, "downshift_instability"),
+            TelemetryAnnotation::TirePressureImbalance {
+                lf_spread: _,
+                rf_spread: _,
+                lr_spread: _,
+                rr_spread: _,
+                is_imbalanced: _,
+            } => write!(f, "tire_pressure_imbalance"),
+            TelemetryAnnotation::SteeringReversals {
+                reversals: _,
+                reversals_per_s: _,
+                is_busy: _,
+            } => write!(f, "steering_reversals"),
+            TelemetryAnnotation::OverRev {
+                peak_rpm: _,
+                limit_rpm: _,
+                duration_ms: _,
+                is_money_shift: _,
+                is_over_rev: _,
+            } => write!(f, "over_rev"),
+            TelemetryAnnotation::TransitionInstability {
+                avg_yaw_rate_change: _,
+                cur_yaw_rate_change: _,
+                brake: _,
+                since_direction_change_ms: _,
+                is_unstable: _,
+                slip_ratio: _,
+            } => write!(f, "transition_instability"),
+            TelemetryAnnotation::ThrottleStab {
+                reversals: _,
+                is_stabbing: _,
+            } => write!(f, "throttle_stab"),
         }
     }
 }
@@ -168,6 +552,21 @@ pub struct TireInfo {
     pub right_surface_temp: f32,
 }
 
+impl TireInfo {
+    fn is_finite(&self) -> bool {
+        [
+            self.left_carcass_temp,
+            self.middle_carcass_temp,
+            self.right_carcass_temp,
+            self.left_surface_temp,
+            self.middle_surface_temp,
+            self.right_surface_temp,
+        ]
+        .iter()
+        .all(|t| t.is_finite())
+    }
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum GameSource {
@@ -205,6 +604,8 @@ pub struct TelemetryData {
     // Inputs
     pub throttle: Option<f32>,
     pub brake: Option<f32>,
+    /// Brake line pressure from 0.0 to 1.0, may differ from the pedal input in `brake`
+    pub brake_pressure_pct: Option<f32>,
     pub clutch: Option<f32>,
     pub steering_angle_rad: Option<f32>,
     pub steering_pct: Option<f32>,
@@ -236,6 +637,8 @@ pub struct TelemetryData {
     pub is_pit_limiter_engaged: Option<bool>,
     pub is_in_pit_lane: Option<bool>,
     pub is_abs_active: Option<bool>,
+    /// Traction control is cutting power
+    pub is_tc_active: Option<bool>,
 
     // GPS coordinates (iRacing only)
     pub latitude_deg: Option<f32>,
@@ -259,18 +662,36 @@ pub struct TelemetryData {
     pub lr_tire_info: Option<TireInfo>,
     pub rr_tire_info: Option<TireInfo>,
 
+    // Wheel speeds, the distance each tire rolls per second
+    pub lf_wheel_speed_mps: Option<f32>,
+    pub rf_wheel_speed_mps: Option<f32>,
+    pub lr_wheel_speed_mps: Option<f32>,
+    pub rr_wheel_speed_mps: Option<f32>,
+
+    // Wheel slip ratios computed by the sim, for sims that do not report wheel speeds
+    pub lf_slip_ratio: Option<f32>,
+    pub rf_slip_ratio: Option<f32>,
+    pub lr_slip_ratio: Option<f32>,
+    pub rr_slip_ratio: Option<f32>,
+
     // Analyzer annotations
+    /// Older recordings may predate the annotations and load with none
+    #[serde(default)]
     pub annotations: Vec<TelemetryAnnotation>,
 }
 
 impl Default for TelemetryData {
     fn default() -> Self {
+        Self::now(&SystemClock)
+    }
+}
+
+impl TelemetryData {
+    /// Point without any measurement, stamped with the current time of `clock`.
+    pub(crate) fn now(clock: &dyn Clock) -> Self {
         Self {
             point_no: 0,
-            timestamp_ms: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_millis(),
+            timestamp_ms: clock.now_ms(),
             game_source: GameSource::IRacing,
             gear: None,
             speed_mps: None,
@@ -279,6 +700,7 @@ impl Default for TelemetryData {
             shift_point_rpm: None,
             throttle: None,
             brake: None,
+            brake_pressure_pct: None,
             clutch: None,
             steering_angle_rad: None,
             steering_pct: None,
@@ -298,6 +720,7 @@ impl Default for TelemetryData {
             is_pit_limiter_engaged: None,
             is_in_pit_lane: None,
             is_abs_active: None,
+            is_tc_active: None,
             latitude_deg: None,
             longitude_deg: None,
             lateral_accel_mps2: None,
@@ -312,12 +735,18 @@ impl Default for TelemetryData {
             rf_tire_info: None,
             lr_tire_info: None,
             rr_tire_info: None,
+            lf_wheel_speed_mps: None,
+            rf_wheel_speed_mps: None,
+            lr_wheel_speed_mps: None,
+            rr_wheel_speed_mps: None,
+            lf_slip_ratio: None,
+            rf_slip_ratio: None,
+            lr_slip_ratio: None,
+            rr_slip_ratio: None,
             annotations: Vec::new(),
         }
     }
-}
 
-impl TelemetryData {
     /// Convert iRacing SimState to TelemetryData.
     ///
     /// Extracts all available telemetry fields from iRacing. Currently, simetry 0.2.3
@@ -347,15 +776,15 @@ impl TelemetryData {
     /// 2. Extending the simetry library to expose these fields
     /// 3. Using a different approach to access the telemetry data
     #[cfg(windows)]
-    pub fn from_iracing_state(state: &simetry::iracing::SimState, point_no: usize) -> Self {
-        use std::time::{SystemTime, UNIX_EPOCH};
+    pub(crate) fn from_iracing_state(
+        state: &simetry::iracing::SimState,
+        point_no: usize,
+        clock: &dyn Clock,
+    ) -> Self {
         use uom::si::angular_velocity::revolution_per_minute;
         use uom::si::velocity::meter_per_second;
 
-        let timestamp_ms = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis();
+        let timestamp_ms = clock.now_ms();
 
         // Extract base fields from Moment trait
         let gear = state.vehicle_gear();
@@ -376,6 +805,8 @@ impl TelemetryData {
         let pedals = state.pedals();
         let throttle = pedals.as_ref().map(|p| p.throttle as f32);
         let brake = pedals.as_ref().map(|p| p.brake as f32);
+        // Brake line pressure is not accessible through simetry 0.2.3
+        let brake_pressure_pct = None;
         let clutch = pedals.as_ref().map(|p| p.clutch as f32);
 
         // Extract flags from Moment trait
@@ -403,6 +834,7 @@ impl TelemetryData {
         let last_lap_time_s = None;
         let best_lap_time_s = None;
         let is_abs_active = None;
+        let is_tc_active = None;
         let latitude_deg = None;
         let longitude_deg = None;
         let lateral_accel_mps2 = None;
@@ -429,6 +861,7 @@ impl TelemetryData {
             shift_point_rpm,
             throttle,
             brake,
+            brake_pressure_pct,
             clutch,
             steering_angle_rad,
             steering_pct,
@@ -448,6 +881,7 @@ impl TelemetryData {
             is_pit_limiter_engaged,
             is_in_pit_lane,
             is_abs_active,
+            is_tc_active,
             latitude_deg,
             longitude_deg,
             lateral_accel_mps2,
@@ -462,8 +896,17 @@ impl TelemetryData {
             rf_tire_info,
             lr_tire_info,
             rr_tire_info,
+            lf_wheel_speed_mps: None,
+            rf_wheel_speed_mps: None,
+            lr_wheel_speed_mps: None,
+            rr_wheel_speed_mps: None,
+            lf_slip_ratio: None,
+            rf_slip_ratio: None,
+            lr_slip_ratio: None,
+            rr_slip_ratio: None,
             annotations: Vec::new(),
         }
+        .sanitized()
     }
 
     /// Convert ACC SimState to TelemetryData.
@@ -497,19 +940,21 @@ impl TelemetryData {
     /// - World position coordinates (world_position_x, world_position_y, world_position_z) from car_coordinates
     /// - World velocity (world_velocity_x, world_velocity_y, world_velocity_z) from local_velocity
     /// - Track position percentage (track_position_pct) from normalized_car_position
+    /// - Brake line pressure (brake_pressure_pct) from the highest wheel brake_pressure
+    /// - Wheel slip ratios (lf_slip_ratio, ...) from the slip_ratio of each wheel
+    ///
+    /// ACC reports the steering input normalized to full lock, so it goes straight into
+    /// `steering_pct` and the wheel angle is left unknown.
     #[cfg(windows)]
-    pub fn from_acc_state(
+    pub(crate) fn from_acc_state(
         state: &simetry::assetto_corsa_competizione::SimState,
         point_no: usize,
+        clock: &dyn Clock,
     ) -> Self {
-        use std::time::{SystemTime, UNIX_EPOCH};
         use uom::si::angular_velocity::revolution_per_minute;
         use uom::si::velocity::meter_per_second;
 
-        let timestamp_ms = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis();
+        let timestamp_ms = clock.now_ms();
 
         // Extract base fields from Moment trait
         let gear = state.vehicle_gear();
@@ -535,9 +980,21 @@ impl TelemetryData {
         // ACC provides these directly rather than through the Moment trait's pedals() method
         let throttle = Some(state.physics.gas);
         let brake = Some(state.physics.brake);
+        // ACC reports the line pressure per wheel, the highest one drives lock detection
+        let wheels = &state.physics.wheels;
+        let brake_pressure_pct = Some(
+            wheels
+                .front_left
+                .brake_pressure
+                .max(wheels.front_right.brake_pressure)
+                .max(wheels.rear_left.brake_pressure)
+                .max(wheels.rear_right.brake_pressure)
+                .clamp(0.0, 1.0),
+        );
         let clutch = Some(state.physics.clutch);
-        let steering_angle_rad = Some(state.physics.steer_angle);
-        let steering_pct = Some(state.physics.steer_angle); // ACC uses normalized steering (-1.0 to 1.0)
+        // ACC uses normalized steering (-1.0 to 1.0), the angle of the wheel is not reported
+        let steering_angle_rad = None;
+        let steering_pct = Some(state.physics.steer_angle.clamp(-1., 1.));
 
         // Extract flags from Moment trait
         let is_pit_limiter_engaged = state.is_pit_limiter_engaged();
@@ -593,8 +1050,9 @@ impl TelemetryData {
             }
         };
 
-        // Extract ABS status from ACC physics
+        // Extract ABS and TC intervention from ACC physics
         let is_abs_active = Some(state.physics.abs > 0.0);
+        let is_tc_active = Some(state.physics.tc > 0.0);
 
         // GPS coordinates not available in ACC
         let latitude_deg = None;
@@ -667,6 +1125,7 @@ impl TelemetryData {
             shift_point_rpm,
             throttle,
             brake,
+            brake_pressure_pct,
             clutch,
             steering_angle_rad,
             steering_pct,
@@ -686,6 +1145,7 @@ impl TelemetryData {
             is_pit_limiter_engaged,
             is_in_pit_lane,
             is_abs_active,
+            is_tc_active,
             latitude_deg,
             longitude_deg,
             lateral_accel_mps2,
@@ -700,8 +1160,159 @@ impl TelemetryData {
             rf_tire_info,
             lr_tire_info,
             rr_tire_info,
+            // ACC reports the angular speed of the wheels but not the rolling radius of
+            // the tires, so only the slip ratio it computes for each wheel is kept
+            lf_wheel_speed_mps: None,
+            rf_wheel_speed_mps: None,
+            lr_wheel_speed_mps: None,
+            rr_wheel_speed_mps: None,
+            lf_slip_ratio: Some(wheels.front_left.slip_ratio),
+            rf_slip_ratio: Some(wheels.front_right.slip_ratio),
+            lr_slip_ratio: Some(wheels.rear_left.slip_ratio),
+            rr_slip_ratio: Some(wheels.rear_right.slip_ratio),
             annotations: Vec::new(),
         }
+        .sanitized()
+    }
+
+    /// Replace NaN and infinite readings with `None`.
+    ///
+    /// Sims occasionally report non-finite values, e.g. the speed while the car is
+    /// teleported to the pits, which would otherwise end up in analyzer averages and
+    /// chart bounds. A tire with any non-finite temperature is dropped as a whole.
+    pub fn sanitized(mut self) -> Self {
+        for value in [
+            &mut self.speed_mps,
+            &mut self.engine_rpm,
+            &mut self.max_engine_rpm,
+            &mut self.shift_point_rpm,
+            &mut self.throttle,
+            &mut self.brake,
+            &mut self.brake_pressure_pct,
+            &mut self.clutch,
+            &mut self.steering_angle_rad,
+            &mut self.steering_pct,
+            &mut self.lap_distance_m,
+            &mut self.lap_distance_pct,
+            &mut self.world_position_x,
+            &mut self.world_position_y,
+            &mut self.world_position_z,
+            &mut self.world_velocity_x,
+            &mut self.world_velocity_y,
+            &mut self.world_velocity_z,
+            &mut self.track_position_pct,
+            &mut self.last_lap_time_s,
+            &mut self.best_lap_time_s,
+            &mut self.latitude_deg,
+            &mut self.longitude_deg,
+            &mut self.lateral_accel_mps2,
+            &mut self.longitudinal_accel_mps2,
+            &mut self.pitch_rad,
+            &mut self.pitch_rate_rps,
+            &mut self.roll_rad,
+            &mut self.roll_rate_rps,
+            &mut self.yaw_rad,
+            &mut self.yaw_rate_rps,
+            &mut self.lf_wheel_speed_mps,
+            &mut self.rf_wheel_speed_mps,
+            &mut self.lr_wheel_speed_mps,
+            &mut self.rr_wheel_speed_mps,
+            &mut self.lf_slip_ratio,
+            &mut self.rf_slip_ratio,
+            &mut self.lr_slip_ratio,
+            &mut self.rr_slip_ratio,
+        ] {
+            if value.is_some_and(|v| !v.is_finite()) {
+                *value = None;
+            }
+        }
+        for tire in [
+            &mut self.lf_tire_info,
+            &mut self.rf_tire_info,
+            &mut self.lr_tire_info,
+            &mut self.rr_tire_info,
+        ] {
+            if tire.as_ref().is_some_and(|t| !t.is_finite()) {
+                *tire = None;
+            }
+        }
+        self
+    }
+
+    /// Derive `steering_pct` from the raw `steering_angle_rad` and the max lock of the car.
+    ///
+    /// Producers reporting the wheel angle run their points through here so analyzers
+    /// that threshold on the fraction of full lock behave the same whichever sim the data
+    /// came from. The point is left untouched when the angle is missing or the max lock
+    /// is not a positive angle.
+    pub fn calibrated_steering(mut self, max_steering_angle: f32) -> Self {
+        if let Some(steering_pct) = self.steering_from_angle(max_steering_angle) {
+            self.steering_pct = Some(steering_pct);
+        }
+        self
+    }
+
+    /// Steering input from -1.0 to 1.0, from `steering_pct` or the steering angle
+    /// normalized against `max_steering_angle`. `None` when neither is available.
+    pub fn signed_steering(&self, max_steering_angle: f32) -> Option<f32> {
+        self.steering_pct
+            .or_else(|| self.steering_from_angle(max_steering_angle))
+    }
+
+    /// Steering wheel angle in radians, from `steering_angle_rad` or, for sims reporting
+    /// only the fraction of full lock, `steering_pct` scaled by `max_steering_angle`.
+    pub fn steering_angle(&self, max_steering_angle: f32) -> Option<f32> {
+        self.steering_angle_rad.or_else(|| {
+            (max_steering_angle.is_finite() && max_steering_angle > 0.)
+                .then_some(self.steering_pct? * max_steering_angle)
+        })
+    }
+
+    /// `steering_angle_rad` as a fraction of `max_steering_angle`, `None` when the angle
+    /// is missing or the max lock is not a positive angle.
+    fn steering_from_angle(&self, max_steering_angle: f32) -> Option<f32> {
+        let steering_angle_rad = self.steering_angle_rad?;
+        (max_steering_angle.is_finite() && max_steering_angle > 0.)
+            .then(|| (steering_angle_rad / max_steering_angle).clamp(-1., 1.))
+    }
+
+    /// Clamp pedal inputs resting inside their deadzone to 0.
+    ///
+    /// Applied by the producers along with the steering calibration, so recordings and
+    /// every consumer see the same inputs.
+    pub(crate) fn with_pedal_deadzones(mut self, deadzones: &PedalDeadzones) -> Self {
+        self.throttle = PedalDeadzones::apply(deadzones.throttle, self.throttle);
+        self.brake = PedalDeadzones::apply(deadzones.brake, self.brake);
+        self.clutch = PedalDeadzones::apply(deadzones.clutch, self.clutch);
+        self
+    }
+
+    /// Slip ratio of the left front, right front, left rear and right rear wheels: how
+    /// much faster the tire rolls than the car moves, over the speed of the car. Positive
+    /// when the wheel spins, negative when it locks.
+    ///
+    /// The slip ratios computed by the sim when it reports them, computed from the wheel
+    /// speeds otherwise. `None` without either for all four wheels, or below
+    /// `MIN_SLIP_RATIO_SPEED_MPS` where the ratio of two small speeds is noise.
+    pub fn wheel_slip_ratios(&self) -> Option<[f32; 4]> {
+        let speed = self
+            .speed_mps
+            .filter(|speed| *speed >= MIN_SLIP_RATIO_SPEED_MPS)?;
+        if let (Some(lf), Some(rf), Some(lr), Some(rr)) = (
+            self.lf_slip_ratio,
+            self.rf_slip_ratio,
+            self.lr_slip_ratio,
+            self.rr_slip_ratio,
+        ) {
+            return Some([lf, rf, lr, rr]);
+        }
+        let wheel_speeds = [
+            self.lf_wheel_speed_mps?,
+            self.rf_wheel_speed_mps?,
+            self.lr_wheel_speed_mps?,
+            self.rr_wheel_speed_mps?,
+        ];
+        Some(wheel_speeds.map(|wheel_speed| (wheel_speed - speed) / speed))
     }
 }
 
@@ -711,6 +1322,58 @@ pub enum TelemetryOutput {
     SessionChange(SessionInfo),
 }
 
+/// Kind of tire fitted to the car, wet tires work in a much colder temperature window.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TireCompound {
+    /// The sim does not report the compound, analyzers assume dry tires
+    #[default]
+    Unknown,
+    Dry,
+    Wet,
+}
+
+/// Slip and scrub thresholds are raised by this factor on a damp track
+const DAMP_GRIP_THRESHOLD_SCALE: f32 = 1.25;
+/// Slip and scrub thresholds are raised by this factor on a wet track
+const WET_GRIP_THRESHOLD_SCALE: f32 = 1.5;
+/// Precipitation (0 to 100) up to which the track is damp rather than wet
+const DAMP_MAX_PRECIPITATION_PCT: f32 = 10.0;
+
+/// State of the track surface. The car moves around more on a damp or wet track for
+/// the same inputs, so the grip-dependent analyzers need a larger slide before they
+/// report one.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TrackConditions {
+    /// The sim does not report the conditions, analyzers assume a dry track
+    #[default]
+    Unknown,
+    Dry,
+    Damp,
+    Wet,
+}
+
+impl TrackConditions {
+    /// Conditions for the precipitation reported by iRacing, in percent
+    pub(crate) fn from_precipitation_pct(precipitation_pct: f32) -> Self {
+        if precipitation_pct <= 0.0 {
+            TrackConditions::Dry
+        } else if precipitation_pct <= DAMP_MAX_PRECIPITATION_PCT {
+            TrackConditions::Damp
+        } else {
+            TrackConditions::Wet
+        }
+    }
+
+    /// Factor applied to the slip speed loss and scrub thresholds in these conditions
+    pub fn grip_threshold_scale(&self) -> f32 {
+        match self {
+            TrackConditions::Unknown | TrackConditions::Dry => 1.0,
+            TrackConditions::Damp => DAMP_GRIP_THRESHOLD_SCALE,
+            TrackConditions::Wet => WET_GRIP_THRESHOLD_SCALE,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SessionInfo {
     pub track_name: String,
@@ -724,6 +1387,26 @@ pub struct SessionInfo {
     pub we_season_id: Option<i32>,
     pub we_sub_session_id: Option<i32>,
     pub we_league_id: Option<i32>,
+    /// Kind of tire fitted when the session started
+    #[serde(default)]
+    pub tire_compound: TireCompound,
+    /// Compound name as reported by the sim, e.g. "dry_compound" in ACC
+    #[serde(default)]
+    pub tire_compound_name: Option<String>,
+    /// Car driven in the session as reported by the sim, used to pick car-specific
+    /// setup recommendations
+    #[serde(default)]
+    pub car_name: Option<String>,
+    /// State of the track surface when the session started
+    #[serde(default)]
+    pub track_conditions: TrackConditions,
+    /// Relative humidity of the air (0 to 100) when the session started
+    #[serde(default)]
+    pub relative_humidity_pct: Option<f32>,
+    /// Version of the recorded format, 0 for recordings made before it was versioned.
+    /// See [`TELEMETRY_FORMAT_VERSION`]
+    #[serde(default)]
+    pub format_version: u32,
 }
 
 impl Default for SessionInfo {
@@ -739,7 +1422,38 @@ impl Default for SessionInfo {
             we_season_id: None,
             we_sub_session_id: None,
             we_league_id: None,
+            tire_compound: TireCompound::Unknown,
+            tire_compound_name: None,
+            car_name: None,
+            track_conditions: TrackConditions::Unknown,
+            relative_humidity_pct: None,
+            format_version: TELEMETRY_FORMAT_VERSION,
+        }
+    }
+}
+
+impl SessionInfo {
+    /// Check that the session metadata is usable by the analyzers.
+    ///
+    /// Returns `OcypodeError::InvalidSessionMetadata` naming the offending field when
+    /// the track name is empty or the max steering angle is negative or not finite.
+    pub fn validate(&self) -> Result<(), OcypodeError> {
+        if self.track_name.trim().is_empty() {
+            return Err(OcypodeError::InvalidSessionMetadata {
+                field: "track_name".to_string(),
+                reason: "track name is empty".to_string(),
+            });
+        }
+        if !self.max_steering_angle.is_finite() || self.max_steering_angle < 0. {
+            return Err(OcypodeError::InvalidSessionMetadata {
+                field: "max_steering_angle".to_string(),
+                reason: format!(
+                    "expected a non-negative angle, got {}",
+                    self.max_steering_angle
+                ),
+            });
         }
+        Ok(())
     }
 }
 
@@ -789,9 +1503,114 @@ pub(crate) fn is_telemetry_point_analyzable(data: &TelemetryData) -> bool {
     !data.is_pit_limiter_engaged.unwrap_or(false) && data.speed_mps.unwrap_or(0.) > 0.
 }
 
+/// Steering input (fraction of full lock) around the center that analyzers ignore
+pub(crate) const DEFAULT_STEERING_FREE_PLAY_PCT: f32 = 0.1;
+/// Exponent applied to the steering input, 1.0 keeps it linear
+pub(crate) const DEFAULT_STEERING_LINEARITY: f32 = 1.0;
+
+/// Steering free play and linearity shared by the analyzers that need to tell
+/// deliberate steering from small corrections around the center.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct SteeringFreePlay {
+    pub(crate) free_play_pct: f32,
+    /// Values above 1.0 shrink small inputs, like the steering linearity setting in the sims
+    pub(crate) linearity: f32,
+}
+
+impl Default for SteeringFreePlay {
+    fn default() -> Self {
+        Self {
+            free_play_pct: DEFAULT_STEERING_FREE_PLAY_PCT,
+            linearity: DEFAULT_STEERING_LINEARITY,
+        }
+    }
+}
+
+impl SteeringFreePlay {
+    /// Steering input from 0.0 to 1.0 after applying the linearity.
+    ///
+    /// Uses `steering_pct` when the game reports it, otherwise normalizes
+    /// `steering_angle_rad` against the max steering angle of the session. Returns
+    /// `None` when neither is available.
+    pub(crate) fn input(
+        &self,
+        telemetry: &TelemetryData,
+        session_info: &SessionInfo,
+    ) -> Option<f32> {
+        let steering = telemetry.signed_steering(session_info.max_steering_angle)?;
+        Some(steering.abs().min(1.).powf(self.linearity))
+    }
+
+    /// Returns true when the steering input is past the free play, `None` when the
+    /// input cannot be established.
+    pub(crate) fn is_steering(
+        &self,
+        telemetry: &TelemetryData,
+        session_info: &SessionInfo,
+    ) -> Option<bool> {
+        self.input(telemetry, session_info)
+            .map(|input| input > self.free_play_pct)
+    }
+}
+
+/// Pedal input (0.0 to 1.0) below which the producers report the pedal as released.
+///
+/// Some pedal sets report a few percent of throttle or brake at rest, which analyzers
+/// and the corner phase classifier read as the driver being on the pedal. All
+/// deadzones are 0 by default, leaving the inputs as the sim reports them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct PedalDeadzones {
+    pub(crate) throttle: f32,
+    pub(crate) brake: f32,
+    pub(crate) clutch: f32,
+}
+
+impl PedalDeadzones {
+    fn apply(deadzone: f32, input: Option<f32>) -> Option<f32> {
+        input.map(|value| if value < deadzone { 0. } else { value })
+    }
+}
+
+/// Yaw rate samples averaged by the scrub and oversteer analyzers before thresholding
+pub(crate) const DEFAULT_YAW_RATE_SMOOTHING_SAMPLES: usize = 4;
+
+/// Short moving average of the yaw rate, so sensor noise is not mistaken for the car
+/// rotating more or less than the steering asks for. A window of 0 or 1 sample passes
+/// the yaw rate through unchanged.
+pub(crate) struct YawRateFilter {
+    samples: usize,
+    window: VecDeque<f32>,
+}
+
+impl YawRateFilter {
+    pub(crate) fn new(samples: usize) -> Self {
+        Self {
+            samples: samples.max(1),
+            window: VecDeque::with_capacity(samples.max(1)),
+        }
+    }
+
+    /// Yaw rate averaged over the last samples, `None` until the window is full.
+    pub(crate) fn filter(&mut self, yaw_rate: f32) -> Option<f32> {
+        if self.window.len() == self.samples {
+            self.window.pop_front();
+        }
+        self.window.push_back(yaw_rate);
+        (self.window.len() == self.samples)
+            .then(|| self.window.iter().sum::<f32>() / self.samples as f32)
+    }
+
+    /// Forget the samples, used when the yaw rate signal is interrupted.
+    pub(crate) fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use analyzer_config::AnalyzerConfig;
 
     // Tests for TelemetryData serialization and deserialization
     // Requirements: 7.1, 7.2, 7.3
@@ -819,6 +1638,7 @@ mod tests {
             shift_point_rpm: Some(6500.0),
             throttle: Some(0.8),
             brake: Some(0.2),
+            brake_pressure_pct: Some(0.25),
             clutch: Some(0.0),
             steering_angle_rad: Some(0.5),
             steering_pct: Some(0.25),
@@ -838,6 +1658,7 @@ mod tests {
             is_pit_limiter_engaged: Some(false),
             is_in_pit_lane: Some(false),
             is_abs_active: Some(true),
+            is_tc_active: Some(false),
             latitude_deg: Some(37.7749),
             longitude_deg: Some(-122.4194),
             lateral_accel_mps2: Some(1.5),
@@ -874,6 +1695,10 @@ mod tests {
         assert_eq!(deserialized.engine_rpm, telemetry.engine_rpm);
         assert_eq!(deserialized.throttle, telemetry.throttle);
         assert_eq!(deserialized.brake, telemetry.brake);
+        assert_eq!(
+            deserialized.brake_pressure_pct,
+            telemetry.brake_pressure_pct
+        );
         assert_eq!(
             deserialized.steering_angle_rad,
             telemetry.steering_angle_rad
@@ -909,6 +1734,7 @@ mod tests {
             shift_point_rpm: None,
             throttle: None,
             brake: None,
+            brake_pressure_pct: None,
             clutch: None,
             steering_angle_rad: None,
             steering_pct: None,
@@ -928,6 +1754,7 @@ mod tests {
             is_pit_limiter_engaged: None,
             is_in_pit_lane: None,
             is_abs_active: None,
+            is_tc_active: None,
             latitude_deg: None,
             longitude_deg: None,
             lateral_accel_mps2: None,
@@ -942,6 +1769,14 @@ mod tests {
             rf_tire_info: None,
             lr_tire_info: None,
             rr_tire_info: None,
+            lf_wheel_speed_mps: None,
+            rf_wheel_speed_mps: None,
+            lr_wheel_speed_mps: None,
+            rr_wheel_speed_mps: None,
+            lf_slip_ratio: None,
+            rf_slip_ratio: None,
+            lr_slip_ratio: None,
+            rr_slip_ratio: None,
             annotations: Vec::new(),
         };
 
@@ -1012,6 +1847,7 @@ mod tests {
             "shift_point_rpm": null,
             "throttle": null,
             "brake": null,
+            "brake_pressure_pct": null,
             "clutch": null,
             "steering_angle_rad": null,
             "steering_pct": null,
@@ -1031,6 +1867,7 @@ mod tests {
             "is_pit_limiter_engaged": null,
             "is_in_pit_lane": null,
             "is_abs_active": null,
+            "is_tc_active": null,
             "latitude_deg": null,
             "longitude_deg": null,
             "lateral_accel_mps2": null,
@@ -1120,6 +1957,7 @@ mod tests {
             shift_point_rpm: None,
             throttle: Some(1.0),
             brake: None,
+            brake_pressure_pct: None,
             clutch: None,
             steering_angle_rad: Some(0.3),
             steering_pct: None,
@@ -1139,6 +1977,7 @@ mod tests {
             is_pit_limiter_engaged: Some(false),
             is_in_pit_lane: None,
             is_abs_active: None,
+            is_tc_active: None,
             latitude_deg: None,
             longitude_deg: None,
             lateral_accel_mps2: None,
@@ -1153,6 +1992,14 @@ mod tests {
             rf_tire_info: None,
             lr_tire_info: None,
             rr_tire_info: None,
+            lf_wheel_speed_mps: None,
+            rf_wheel_speed_mps: None,
+            lr_wheel_speed_mps: None,
+            rr_wheel_speed_mps: None,
+            lf_slip_ratio: None,
+            rf_slip_ratio: None,
+            lr_slip_ratio: None,
+            rr_slip_ratio: None,
             annotations: Vec::new(),
         };
 
@@ -1183,6 +2030,23 @@ mod tests {
         assert_eq!(deserialized.throttle, Some(1.0));
     }
 
+    #[test]
+    fn test_front_brake_lock_without_application_fields_deserializes() {
+        // Files recorded before stomp detection do not have the application fields
+        let json = r#"{"FrontBrakeLock":{"abs_activation_count":2,"is_front_lock":false}}"#;
+        let deserialized: TelemetryAnnotation =
+            serde_json::from_str(json).expect("Failed to deserialize");
+        assert_eq!(
+            deserialized,
+            TelemetryAnnotation::FrontBrakeLock {
+                abs_activation_count: 2,
+                is_front_lock: false,
+                peak_application_rate: 0.0,
+                is_initial_application_lock: false,
+            }
+        );
+    }
+
     #[test]
     fn test_new_annotation_types_serialization() {
         // Test EntryOversteer annotation
@@ -1190,6 +2054,7 @@ mod tests {
             expected_yaw_rate: 0.5,
             actual_yaw_rate: 0.8,
             is_oversteer: true,
+            is_recovered: false,
         };
         let json = serde_json::to_string(&entry_oversteer).expect("Failed to serialize");
         let deserialized: TelemetryAnnotation =
@@ -1210,6 +2075,7 @@ mod tests {
         let mid_oversteer = TelemetryAnnotation::MidCornerOversteer {
             yaw_rate_excess: 0.3,
             is_oversteer: true,
+            is_recovered: true,
         };
         let json = serde_json::to_string(&mid_oversteer).expect("Failed to serialize");
         let deserialized: TelemetryAnnotation =
@@ -1220,6 +2086,8 @@ mod tests {
         let front_lock = TelemetryAnnotation::FrontBrakeLock {
             abs_activation_count: 3,
             is_front_lock: true,
+            peak_application_rate: 12.5,
+            is_initial_application_lock: true,
         };
         let json = serde_json::to_string(&front_lock).expect("Failed to serialize");
         let deserialized: TelemetryAnnotation =
@@ -1280,6 +2148,7 @@ mod tests {
                     expected_yaw_rate: 0.5,
                     actual_yaw_rate: 0.8,
                     is_oversteer: true,
+                    is_recovered: false,
                 }
             ),
             "entry_oversteer"
@@ -1302,6 +2171,7 @@ mod tests {
                 TelemetryAnnotation::MidCornerOversteer {
                     yaw_rate_excess: 0.3,
                     is_oversteer: true,
+                    is_recovered: false,
                 }
             ),
             "mid_corner_oversteer"
@@ -1313,6 +2183,8 @@ mod tests {
                 TelemetryAnnotation::FrontBrakeLock {
                     abs_activation_count: 3,
                     is_front_lock: true,
+                    peak_application_rate: 4.0,
+                    is_initial_application_lock: false,
                 }
             ),
             "front_brake_lock"
@@ -1364,5 +2236,388 @@ mod tests {
             ),
             "bottoming_out"
         );
+
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::ClutchEngagement {
+                    engagement_rpm: 2500.0,
+                    rpm_drop: 1200.0,
+                    release_time_ms: 100.0,
+                    is_bog: true,
+                    is_clutch_kick: false,
+                }
+            ),
+            "clutch_engagement"
+        );
+
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::CamberImbalance {
+                    lf_spread: -8.0,
+                    rf_spread: -7.0,
+                    lr_spread: 5.0,
+                    rr_spread: 6.0,
+                    is_imbalanced: true,
+                }
+            ),
+            "camber_imbalance"
+        );
+
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::ShiftPoint {
+                    from_gear: 2,
+                    shift_rpm: 7800.0,
+                    optimal_rpm: 7200.0,
+                    is_power_curve_optimal: true,
+                    is_over_revving: true,
+                    is_under_revving: false,
+                }
+            ),
+            "shift_point"
+        );
+
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::EntrySteeringOvershoot {
+                    peak_steering_pct: 0.5,
+                    unwound_steering_pct: 0.15,
+                    lateral_accel_mps2: 12.0,
+                    is_overshoot: true,
+                }
+            ),
+            "entry_steering_overshoot"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::ElectronicsIntervention {
+                    corner_abs_activations: 3,
+                    corner_tc_activations: 0,
+                    lap_abs_activations: 9,
+                    lap_tc_activations: 2,
+                    is_excessive_abs: true,
+                    is_excessive_tc: false,
+                }
+            ),
+            "electronics_intervention"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::StraightLineInstability {
+                    steering_std_dev: 0.015,
+                    corrections: 12,
+                    min_speed_mps: 60.0,
+                    is_unstable: true,
+                }
+            ),
+            "straight_line_instability"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::KerbStrike {
+                    impact_rad_s2: 9.8,
+                    lap_distance_pct: Some(0.25),
+                    prior_strikes_at_location: 2,
+                    is_repeated: true,
+                }
+            ),
+            "kerb_strike"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::ExitThrottleLift {
+                    dip_depth: 0.3,
+                    dip_count: 2,
+                    is_understeer_lift: true,
+                    cause: ExitUndersteerCause::Differential,
+                }
+            ),
+            "exit_throttle_lift"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::PedalOverlap {
+                    duration_ms: 300,
+                    max_throttle: 0.4,
+                    max_brake: 0.9,
+                    is_sustained_overlap: true,
+                }
+            ),
+            "pedal_overlap"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::LoadTransfer {
+                    axis: LoadTransferAxis::Lateral,
+                    transfer_time_ms: 350,
+                    peak_g: 1.5,
+                    peak_rate_g_per_s: 3.75,
+                    is_slow: false,
+                    is_abrupt: false,
+                }
+            ),
+            "load_transfer"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::DownshiftInstability {
+                    from_gear: 4,
+                    to_gear: 3,
+                    rpm_spike: 1500.,
+                    peak_yaw_excess_rps: 0.4,
+                    is_unstable: true,
+                }
+            ),
+            "downshift_instability"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::TirePressureImbalance {
+                    lf_spread: 8.0,
+                    rf_spread: 7.0,
+                    lr_spread: 1.0,
+                    rr_spread: 0.5,
+                    is_imbalanced: true,
+                }
+            ),
+            "tire_pressure_imbalance"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::SteeringReversals {
+                    reversals: 12,
+                    reversals_per_s: 2.4,
+                    is_busy: true,
+                }
+            ),
+            "steering_reversals"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::OverRev {
+                    peak_rpm: 9500.,
+                    limit_rpm: 8100.,
+                    duration_ms: 200,
+                    is_money_shift: true,
+                    is_over_rev: true,
+                }
+            ),
+            "over_rev"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::TransitionInstability {
+                    avg_yaw_rate_change: 0.1,
+                    cur_yaw_rate_change: 0.3,
+                    brake: 0.6,
+                    since_direction_change_ms: 300,
+                    is_unstable: true,
+                    slip_ratio: None,
+                }
+            ),
+            "transition_instability"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                TelemetryAnnotation::ThrottleStab {
+                    reversals: 4,
+                    is_stabbing: true,
+                }
+            ),
+            "throttle_stab"
+        );
+    }
+
+    #[test]
+    fn test_annotation_is_active() {
+        assert!(
+            TelemetryAnnotation::Slip {
+                prev_speed: 40.,
+                cur_speed: 38.,
+                is_slip: true,
+                slip_ratio: None,
+            }
+            .is_active()
+        );
+        assert!(
+            !TelemetryAnnotation::Wheelspin {
+                avg_rpm_increase_per_gear: HashMap::new(),
+                cur_gear: 2,
+                cur_rpm_increase: 50.,
+                is_wheelspin: false,
+                slip_ratio: None,
+            }
+            .is_active()
+        );
+        assert!(
+            TelemetryAnnotation::ElectronicsIntervention {
+                corner_abs_activations: 0,
+                corner_tc_activations: 4,
+                lap_abs_activations: 0,
+                lap_tc_activations: 9,
+                is_excessive_abs: false,
+                is_excessive_tc: true,
+            }
+            .is_active()
+        );
+    }
+
+    fn tire(temp: f32) -> TireInfo {
+        TireInfo {
+            left_carcass_temp: 80.0,
+            middle_carcass_temp: 80.0,
+            right_carcass_temp: 80.0,
+            left_surface_temp: temp,
+            middle_surface_temp: 85.0,
+            right_surface_temp: 85.0,
+        }
+    }
+
+    /// A car at speed through a corner with a few readings gone bad
+    fn non_finite_point(point_no: usize, bad: f32) -> TelemetryData {
+        TelemetryData {
+            point_no,
+            timestamp_ms: point_no as u128 * 16,
+            gear: Some(3),
+            speed_mps: Some(if point_no % 3 == 0 { bad } else { 40.0 }),
+            engine_rpm: Some(if point_no % 4 == 0 { bad } else { 6000.0 }),
+            max_engine_rpm: Some(8000.0),
+            shift_point_rpm: Some(7500.0),
+            throttle: Some(if point_no % 5 == 0 { bad } else { 0.6 }),
+            brake: Some(0.2),
+            brake_pressure_pct: Some(bad),
+            clutch: Some(0.0),
+            steering_angle_rad: Some(bad),
+            steering_pct: Some(if point_no % 2 == 0 { bad } else { 0.3 }),
+            lap_distance_pct: Some(point_no as f32 / 1000.),
+            lateral_accel_mps2: Some(bad),
+            longitudinal_accel_mps2: Some(-5.0),
+            yaw_rad: Some(bad),
+            yaw_rate_rps: Some(0.4),
+            lf_tire_info: Some(tire(bad)),
+            rf_tire_info: Some(tire(85.0)),
+            ..TelemetryData::default()
+        }
+    }
+
+    #[test]
+    fn test_sanitized_replaces_non_finite_values() {
+        for bad in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            let point = non_finite_point(0, bad).sanitized();
+
+            assert_eq!(point.speed_mps, None);
+            assert_eq!(point.engine_rpm, None);
+            assert_eq!(point.throttle, None);
+            assert_eq!(point.brake_pressure_pct, None);
+            assert_eq!(point.steering_pct, None);
+            assert_eq!(point.lateral_accel_mps2, None);
+            assert_eq!(point.yaw_rad, None);
+            assert_eq!(point.lf_tire_info, None);
+            // finite readings are kept
+            assert_eq!(point.brake, Some(0.2));
+            assert_eq!(point.longitudinal_accel_mps2, Some(-5.0));
+            assert_eq!(point.rf_tire_info, Some(tire(85.0)));
+        }
+    }
+
+    #[test]
+    fn test_wheel_slip_ratios_prefer_sim_slip_ratios() {
+        let point = TelemetryData::builder()
+            .speed_mps(20.)
+            .wheel_speeds_mps(20., 20., 22., 22.)
+            .build();
+        assert_eq!(point.wheel_slip_ratios(), Some([0., 0., 0.1, 0.1]));
+
+        let point = TelemetryData {
+            lf_slip_ratio: Some(0.),
+            rf_slip_ratio: Some(-0.05),
+            lr_slip_ratio: Some(0.2),
+            rr_slip_ratio: Some(0.15),
+            ..point
+        };
+        assert_eq!(point.wheel_slip_ratios(), Some([0., -0.05, 0.2, 0.15]));
+
+        // too slow for the ratio to mean anything
+        let point = TelemetryData {
+            speed_mps: Some(1.),
+            ..point
+        };
+        assert_eq!(point.wheel_slip_ratios(), None);
+    }
+
+    #[test]
+    fn test_calibrated_steering() {
+        let steering = |angle: f32, max_steering_angle: f32| {
+            TelemetryData {
+                steering_angle_rad: Some(angle),
+                ..TelemetryData::default()
+            }
+            .calibrated_steering(max_steering_angle)
+            .steering_pct
+        };
+
+        // a quarter turn to the left on a car with 540 degrees of lock
+        let max_steering_angle = 540_f32.to_radians();
+        let pct = steering(-135_f32.to_radians(), max_steering_angle).unwrap();
+        assert!((pct + 0.25).abs() < 1e-6);
+        assert_eq!(steering(1.5, 6.0), Some(0.25));
+        // readings past the lock are clamped to full lock
+        assert_eq!(steering(7.0, 6.0), Some(1.0));
+        // without a max lock the point keeps what the sim reported
+        assert_eq!(steering(1.5, 0.), None);
+        assert_eq!(steering(1.5, f32::NAN), None);
+    }
+
+    #[test]
+    fn test_signed_steering() {
+        let point = TelemetryData {
+            steering_angle_rad: Some(-1.5),
+            ..TelemetryData::default()
+        };
+        // the raw angle is only usable against the max lock
+        assert_eq!(point.signed_steering(6.0), Some(-0.25));
+        assert_eq!(point.signed_steering(0.), None);
+        let point = TelemetryData {
+            steering_pct: Some(0.5),
+            ..point
+        };
+        assert_eq!(point.signed_steering(6.0), Some(0.5));
+    }
+
+    #[test]
+    fn test_analyzers_handle_non_finite_values() {
+        let session_info = SessionInfo::default();
+        for bad in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            // the collector only hands over sanitized points, but a raw one must not
+            // panic any analyzer either
+            let mut analyzers = AnalyzerConfig::default().analyzers();
+            let mut sanitized_analyzers = AnalyzerConfig::default().analyzers();
+            for point_no in 0..500 {
+                let point = non_finite_point(point_no, bad);
+                for analyzer in analyzers.iter_mut() {
+                    analyzer.analyze(&point, &session_info);
+                }
+                let point = point.sanitized();
+                for analyzer in sanitized_analyzers.iter_mut() {
+                    analyzer.analyze(&point, &session_info);
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,241 @@
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{SessionInfo, TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+
+/// RPM above the max engine RPM reported by the sim tolerated before an over-rev, so
+/// bouncing off the rev limiter is not reported
+pub(crate) const DEFAULT_OVER_REV_MARGIN_RPM: f32 = 100.;
+/// An over-rev starting this long (ms) after a downshift was caused by it
+const MONEY_SHIFT_WINDOW_MS: u128 = 500;
+
+/// Engine speed excursion over the limit being tracked
+struct OverRev {
+    start_ms: u128,
+    peak_rpm: f32,
+    limit_rpm: f32,
+    is_money_shift: bool,
+}
+
+/// Detects the engine spinning past its max RPM, typically a missed downshift.
+///
+/// The limit is the max engine RPM reported by the sim plus a configurable margin.
+/// Every point over the limit is annotated with the peak RPM and the time spent over it
+/// so far, so the live alert shows up straight away and the last point of the over-rev,
+/// or of the data when it ends mid over-rev, carries the whole of it. Over-revs starting
+/// within 500ms of a downshift are flagged as money shifts: the wheels drove the engine
+/// past the limiter, which damages it in sims that model engine wear.
+pub(crate) struct OverRevAnalyzer {
+    margin_rpm: f32,
+    prev_gear: Option<i8>,
+    last_downshift_ms: Option<u128>,
+    over_rev: Option<OverRev>,
+}
+
+impl OverRevAnalyzer {
+    pub(crate) fn new(margin_rpm: f32) -> Self {
+        Self {
+            margin_rpm,
+            prev_gear: None,
+            last_downshift_ms: None,
+            over_rev: None,
+        }
+    }
+}
+
+impl TelemetryAnalyzer for OverRevAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        _session_info: &SessionInfo,
+    ) -> Vec<TelemetryAnnotation> {
+        let mut output = Vec::new();
+
+        if !is_telemetry_point_analyzable(telemetry) {
+            self.prev_gear = None;
+            self.last_downshift_ms = None;
+            self.over_rev = None;
+            return output;
+        }
+
+        if let (Some(from_gear), Some(to_gear)) = (self.prev_gear, telemetry.gear)
+            && to_gear >= 1
+            && to_gear < from_gear
+        {
+            self.last_downshift_ms = Some(telemetry.timestamp_ms);
+        }
+        self.prev_gear = telemetry.gear;
+
+        let (Some(rpm), Some(max_rpm)) = (telemetry.engine_rpm, telemetry.max_engine_rpm) else {
+            return output;
+        };
+        let limit_rpm = max_rpm + self.margin_rpm;
+        if rpm <= limit_rpm {
+            self.over_rev = None;
+            return output;
+        }
+
+        let last_downshift_ms = self.last_downshift_ms;
+        let over_rev = self.over_rev.get_or_insert_with(|| OverRev {
+            start_ms: telemetry.timestamp_ms,
+            peak_rpm: rpm,
+            limit_rpm,
+            is_money_shift: last_downshift_ms.is_some_and(|downshift_ms| {
+                telemetry.timestamp_ms.saturating_sub(downshift_ms) <= MONEY_SHIFT_WINDOW_MS
+            }),
+        });
+        over_rev.peak_rpm = over_rev.peak_rpm.max(rpm);
+        output.push(TelemetryAnnotation::OverRev {
+            peak_rpm: over_rev.peak_rpm,
+            limit_rpm: over_rev.limit_rpm,
+            duration_ms: telemetry.timestamp_ms.saturating_sub(over_rev.start_ms),
+            is_money_shift: over_rev.is_money_shift,
+            is_over_rev: true,
+        });
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Downshifting from `from_gear` to 2nd at 500ms, with the engine at `rpm_at` against
+    /// a max of 8000 RPM
+    fn downshift(from_gear: i8, rpm_at: impl Fn(u128) -> f32) -> Vec<TelemetryData> {
+        (0..20)
+            .map(|i| {
+                let timestamp_ms = i * 100;
+                TelemetryData {
+                    timestamp_ms,
+                    speed_mps: Some(40.),
+                    brake: Some(0.8),
+                    gear: Some(if timestamp_ms >= 500 { 2 } else { from_gear }),
+                    engine_rpm: Some(rpm_at(timestamp_ms)),
+                    max_engine_rpm: Some(8000.),
+                    ..TelemetryData::default()
+                }
+            })
+            .collect()
+    }
+
+    fn run(points: &[TelemetryData]) -> Vec<TelemetryAnnotation> {
+        let mut analyzer = OverRevAnalyzer::new(DEFAULT_OVER_REV_MARGIN_RPM);
+        let session_info = SessionInfo::default();
+        points
+            .iter()
+            .flat_map(|point| analyzer.analyze(point, &session_info))
+            .collect()
+    }
+
+    #[test]
+    fn test_money_shift_spikes_past_max_rpm() {
+        // skipping from 4th to 2nd spins the engine to 9500 RPM for two points
+        let output = run(&downshift(4, |timestamp_ms| match timestamp_ms {
+            500 => 9500.,
+            600 => 8600.,
+            _ => 6500.,
+        }));
+
+        // annotated as soon as the engine goes over the limit
+        assert_eq!(output.len(), 2);
+        match &output[1] {
+            TelemetryAnnotation::OverRev {
+                peak_rpm,
+                limit_rpm,
+                duration_ms,
+                is_money_shift,
+                is_over_rev,
+            } => {
+                assert!((peak_rpm - 9500.).abs() < 1e-3);
+                assert!((limit_rpm - 8100.).abs() < 1e-3);
+                assert_eq!(*duration_ms, 100);
+                assert!(*is_money_shift);
+                assert!(*is_over_rev);
+            }
+            _ => panic!("Expected OverRev annotation"),
+        }
+    }
+
+    #[test]
+    fn test_over_rev_at_end_of_data_is_reported() {
+        // the recording stops with the engine still over the limit
+        let points = downshift(
+            4,
+            |timestamp_ms| {
+                if timestamp_ms >= 1500 { 8500. } else { 6500. }
+            },
+        );
+
+        assert!(matches!(
+            run(&points).last(),
+            Some(TelemetryAnnotation::OverRev {
+                duration_ms: 400,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_over_rev_without_downshift_is_not_a_money_shift() {
+        // holding 2nd too long on the straight
+        let points: Vec<_> = downshift(2, |timestamp_ms| {
+            if (1000..1300).contains(&timestamp_ms) {
+                8300.
+            } else {
+                7000.
+            }
+        });
+
+        assert!(matches!(
+            run(&points)[..],
+            [
+                _,
+                _,
+                TelemetryAnnotation::OverRev {
+                    duration_ms: 200,
+                    is_money_shift: false,
+                    ..
+                }
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_rewound_timestamps_do_not_panic() {
+        // a replay rewound right after the downshift
+        let mut points = downshift(4, |_| 7000.);
+        points[6] = TelemetryData {
+            timestamp_ms: 100,
+            engine_rpm: Some(9500.),
+            ..points[6].clone()
+        };
+
+        assert!(matches!(
+            run(&points)[..],
+            [TelemetryAnnotation::OverRev {
+                is_money_shift: true,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn test_limiter_within_margin_is_ignored() {
+        let output = run(&downshift(3, |timestamp_ms| {
+            if timestamp_ms >= 500 { 8050. } else { 6500. }
+        }));
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_needs_max_rpm() {
+        let points: Vec<_> = downshift(4, |_| 12000.)
+            .into_iter()
+            .map(|p| TelemetryData {
+                max_engine_rpm: None,
+                ..p
+            })
+            .collect();
+        assert!(run(&points).is_empty());
+    }
+}
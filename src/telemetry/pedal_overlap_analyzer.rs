@@ -0,0 +1,150 @@
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{SessionInfo, TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+
+/// Pedal application (0.0 to 1.0) above which both pedals count as pressed
+pub const DEFAULT_PEDAL_OVERLAP_MIN_PCT: f32 = 0.1;
+/// Overlap shorter than this is a normal pedal crossover and is not reported
+pub const DEFAULT_PEDAL_OVERLAP_MIN_DURATION_MS: u64 = 200;
+
+struct Overlap {
+    start_ms: u128,
+    last_ms: u128,
+    max_throttle: f32,
+    max_brake: f32,
+}
+
+/// Detects throttle and brake pressed together for longer than a pedal crossover.
+///
+/// Dragging the throttle into the braking zone, or picking it up before the brake
+/// is released, costs braking performance and heats the brakes. Some drivers left-foot
+/// brake on purpose to settle the car, so the thresholds are configurable and the
+/// analyzer can be disabled. One annotation is emitted when each sustained overlap ends.
+pub(crate) struct PedalOverlapAnalyzer {
+    min_pedal_pct: f32,
+    min_duration_ms: u64,
+    overlap: Option<Overlap>,
+}
+
+impl PedalOverlapAnalyzer {
+    pub(crate) fn new(min_pedal_pct: f32, min_duration_ms: u64) -> Self {
+        Self {
+            min_pedal_pct,
+            min_duration_ms,
+            overlap: None,
+        }
+    }
+
+    fn end_overlap(&mut self) -> Option<TelemetryAnnotation> {
+        let overlap = self.overlap.take()?;
+        let duration_ms = overlap.last_ms.saturating_sub(overlap.start_ms);
+        (duration_ms >= u128::from(self.min_duration_ms)).then_some(
+            TelemetryAnnotation::PedalOverlap {
+                duration_ms,
+                max_throttle: overlap.max_throttle,
+                max_brake: overlap.max_brake,
+                is_sustained_overlap: true,
+            },
+        )
+    }
+}
+
+impl TelemetryAnalyzer for PedalOverlapAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        _session_info: &SessionInfo,
+    ) -> Vec<TelemetryAnnotation> {
+        let throttle = telemetry.throttle.unwrap_or(0.0);
+        let brake = telemetry.brake.unwrap_or(0.0);
+        if !is_telemetry_point_analyzable(telemetry)
+            || throttle <= self.min_pedal_pct
+            || brake <= self.min_pedal_pct
+        {
+            return self.end_overlap().into_iter().collect();
+        }
+
+        let overlap = self.overlap.get_or_insert(Overlap {
+            start_ms: telemetry.timestamp_ms,
+            last_ms: telemetry.timestamp_ms,
+            max_throttle: throttle,
+            max_brake: brake,
+        });
+        overlap.last_ms = telemetry.timestamp_ms;
+        overlap.max_throttle = overlap.max_throttle.max(throttle);
+        overlap.max_brake = overlap.max_brake.max(brake);
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(pedals: &[(f32, f32)]) -> Vec<TelemetryAnnotation> {
+        let mut analyzer = PedalOverlapAnalyzer::new(
+            DEFAULT_PEDAL_OVERLAP_MIN_PCT,
+            DEFAULT_PEDAL_OVERLAP_MIN_DURATION_MS,
+        );
+        let session_info = SessionInfo::default();
+        pedals
+            .iter()
+            .enumerate()
+            .flat_map(|(i, (throttle, brake))| {
+                let point = TelemetryData {
+                    timestamp_ms: i as u128 * 50,
+                    speed_mps: Some(40.0),
+                    throttle: Some(*throttle),
+                    brake: Some(*brake),
+                    ..TelemetryData::default()
+                };
+                analyzer.analyze(&point, &session_info)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_flags_sustained_overlap() {
+        // throttle dragged into the braking zone for 250ms
+        let pedals = [
+            (1.0, 0.0),
+            (0.6, 0.3),
+            (0.5, 0.8),
+            (0.4, 0.9),
+            (0.3, 0.9),
+            (0.2, 0.9),
+            (0.15, 0.9),
+            (0.0, 0.9),
+        ];
+        let output = run(&pedals);
+
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::PedalOverlap {
+                duration_ms,
+                max_throttle,
+                max_brake,
+                is_sustained_overlap,
+            } => {
+                assert_eq!(*duration_ms, 250);
+                assert_eq!(*max_throttle, 0.6);
+                assert_eq!(*max_brake, 0.9);
+                assert!(*is_sustained_overlap);
+            }
+            _ => panic!("Expected PedalOverlap annotation"),
+        }
+    }
+
+    #[test]
+    fn test_ignores_quick_crossover() {
+        let pedals = [(1.0, 0.0), (0.5, 0.5), (0.2, 0.9), (0.0, 1.0), (0.0, 0.8)];
+        assert!(run(&pedals).is_empty());
+    }
+
+    #[test]
+    fn test_ignores_light_pedal_contact() {
+        // heel resting on the throttle while braking
+        let pedals = [(0.05, 0.9); 20];
+        assert!(run(&pedals).is_empty());
+    }
+}
@@ -1,12 +1,25 @@
-use std::time::Duration;
-
-use log::error;
+use std::{
+    io::{BufRead, BufReader},
+    net::TcpStream,
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, Receiver, Sender},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use log::{debug, error, info, warn};
 
 use crate::OcypodeError;
 
-use super::{GameSource, SessionInfo, TelemetryData, TelemetryOutput};
+#[cfg(windows)]
+use super::clock::SystemClock;
+use super::{
+    GameSource, PedalDeadzones, SessionInfo, TELEMETRY_FORMAT_VERSION, TelemetryData,
+    TelemetryOutput, TireCompound, TrackConditions, migration::RecordMigrator,
+};
 
-#[allow(unused)]
 const CONN_RETRY_WAIT_MS: u64 = 200;
 #[allow(unused)]
 const MAX_STEERING_ANGLE_DEFAULT: f32 = std::f32::consts::PI;
@@ -49,7 +62,8 @@ pub trait TelemetryProducer {
     ///
     /// # Errors
     ///
-    /// Returns an error if the producer is not started or if session info cannot be retrieved.
+    /// Returns an error if the producer is not started, or `OcypodeError::SessionInfoUnavailable`
+    /// if session info cannot be retrieved.
     fn session_info(&mut self) -> Result<SessionInfo, OcypodeError>;
 
     /// Get the next telemetry data point from the game.
@@ -79,6 +93,9 @@ pub(crate) struct IRacingTelemetryProducer {
     retry_wait_ms: u64,
     _retry_timeout_s: u64,
     point_no: usize,
+    /// Max lock of the car in the current session, used to calibrate the steering
+    max_steering_angle: f32,
+    pedal_deadzones: PedalDeadzones,
 }
 
 #[cfg(windows)]
@@ -97,8 +114,16 @@ impl IRacingTelemetryProducer {
             retry_wait_ms,
             _retry_timeout_s: retry_timeout_s,
             point_no: 0,
+            max_steering_angle: MAX_STEERING_ANGLE_DEFAULT,
+            pedal_deadzones: PedalDeadzones::default(),
         }
     }
+
+    /// Report pedal inputs resting inside `pedal_deadzones` as released.
+    pub(crate) fn with_pedal_deadzones(mut self, pedal_deadzones: PedalDeadzones) -> Self {
+        self.pedal_deadzones = pedal_deadzones;
+        self
+    }
 }
 
 #[cfg(windows)]
@@ -116,9 +141,9 @@ impl TelemetryProducer for IRacingTelemetryProducer {
 
     fn session_info(&mut self) -> Result<SessionInfo, OcypodeError> {
         if self.client.is_none() {
-            return Err(OcypodeError::TelemetryProducerError {
-                description: "The iRacing connection is not initialized, call start() first."
-                    .to_string(),
+            return Err(OcypodeError::ProducerConnectError {
+                game: GameSource::IRacing,
+                reason: "connection is not initialized, call start() first".to_string(),
             });
         }
 
@@ -128,8 +153,9 @@ impl TelemetryProducer for IRacingTelemetryProducer {
         let state = tokio::runtime::Runtime::new()
             .unwrap()
             .block_on(client.next_sim_state())
-            .ok_or(OcypodeError::TelemetryProducerError {
-                description: "Could not retrieve iRacing state".to_string(),
+            .ok_or(OcypodeError::SessionInfoUnavailable {
+                game: GameSource::IRacing,
+                reason: "could not retrieve iRacing state".to_string(),
             })?;
 
         // Extract session info from the YAML
@@ -164,8 +190,27 @@ impl TelemetryProducer for IRacingTelemetryProducer {
             .as_i64()
             .map(|v| v as i32);
 
+        // The player's car is the driver entry at DriverCarIdx
+        let car_name = session_info["DriverInfo"]["DriverCarIdx"]
+            .as_i64()
+            .and_then(|idx| {
+                session_info["DriverInfo"]["Drivers"][idx as usize]["CarScreenName"].as_str()
+            })
+            .map(|name| name.to_string());
+
+        // Weather the session started with, the track wetness itself is a telemetry variable
+        let track_conditions = session_info["WeekendInfo"]["TrackPrecipitation"]
+            .as_str()
+            .and_then(super::ibt::percent_value)
+            .map(TrackConditions::from_precipitation_pct)
+            .unwrap_or_default();
+        let relative_humidity_pct = session_info["WeekendInfo"]["TrackRelativeHumidity"]
+            .as_str()
+            .and_then(super::ibt::percent_value);
+
         // Use default max steering angle (simetry 0.2.3 doesn't expose this in the Moment trait)
         let max_steering_angle = MAX_STEERING_ANGLE_DEFAULT;
+        self.max_steering_angle = max_steering_angle;
 
         Ok(SessionInfo {
             track_name,
@@ -178,14 +223,21 @@ impl TelemetryProducer for IRacingTelemetryProducer {
             we_season_id,
             we_sub_session_id,
             we_league_id,
+            // The fitted compound is a telemetry variable not accessible through simetry 0.2.3
+            tire_compound: TireCompound::Unknown,
+            tire_compound_name: None,
+            car_name,
+            track_conditions,
+            relative_humidity_pct,
+            format_version: TELEMETRY_FORMAT_VERSION,
         })
     }
 
     fn telemetry(&mut self) -> Result<TelemetryData, OcypodeError> {
         if self.client.is_none() {
-            return Err(OcypodeError::TelemetryProducerError {
-                description: "The iRacing connection is not initialized, call start() first."
-                    .to_string(),
+            return Err(OcypodeError::ProducerConnectError {
+                game: GameSource::IRacing,
+                reason: "connection is not initialized, call start() first".to_string(),
             });
         }
 
@@ -207,7 +259,11 @@ impl TelemetryProducer for IRacingTelemetryProducer {
                 description: "Could not retrieve iRacing telemetry".to_string(),
             })?;
 
-        Ok(TelemetryData::from_iracing_state(&state, self.point_no))
+        Ok(
+            TelemetryData::from_iracing_state(&state, self.point_no, &SystemClock)
+                .calibrated_steering(self.max_steering_angle)
+                .with_pedal_deadzones(&self.pedal_deadzones),
+        )
     }
 
     fn game_source(&self) -> GameSource {
@@ -222,6 +278,9 @@ pub(crate) struct ACCTelemetryProducer {
     retry_wait_ms: u64,
     _retry_timeout_s: u64,
     point_no: usize,
+    /// Max lock of the car in the current session, used to calibrate the steering
+    max_steering_angle: f32,
+    pedal_deadzones: PedalDeadzones,
 }
 
 #[cfg(windows)]
@@ -240,8 +299,16 @@ impl ACCTelemetryProducer {
             retry_wait_ms,
             _retry_timeout_s: retry_timeout_s,
             point_no: 0,
+            max_steering_angle: MAX_STEERING_ANGLE_DEFAULT,
+            pedal_deadzones: PedalDeadzones::default(),
         }
     }
+
+    /// Report pedal inputs resting inside `pedal_deadzones` as released.
+    pub(crate) fn with_pedal_deadzones(mut self, pedal_deadzones: PedalDeadzones) -> Self {
+        self.pedal_deadzones = pedal_deadzones;
+        self
+    }
 }
 
 #[cfg(windows)]
@@ -266,22 +333,23 @@ impl TelemetryProducer for ACCTelemetryProducer {
 
         if self.client.is_none() {
             error!("ACC: Client not initialized when requesting session info");
-            return Err(OcypodeError::TelemetryProducerError {
-                description: "The ACC connection is not initialized, call start() first."
-                    .to_string(),
+            return Err(OcypodeError::ProducerConnectError {
+                game: GameSource::ACC,
+                reason: "connection is not initialized, call start() first".to_string(),
             });
         }
 
         let client = self.client.as_mut().expect("Missing ACC connection");
 
         // In simetry 0.2.3, use next_sim_state() to get the current state
-        let _state = tokio::runtime::Runtime::new()
+        let state = tokio::runtime::Runtime::new()
             .unwrap()
             .block_on(client.next_sim_state())
             .ok_or_else(|| {
                 error!("ACC: Could not retrieve state - game may not be in an active session");
-                OcypodeError::TelemetryProducerError {
-                    description: "Could not retrieve ACC state".to_string(),
+                OcypodeError::SessionInfoUnavailable {
+                    game: GameSource::ACC,
+                    reason: "could not retrieve ACC state".to_string(),
                 }
             })?;
 
@@ -293,6 +361,16 @@ impl TelemetryProducer for ACCTelemetryProducer {
 
         // Use default max steering angle (simetry 0.2.3 doesn't expose this in the Moment trait)
         let max_steering_angle = MAX_STEERING_ANGLE_DEFAULT;
+        self.max_steering_angle = max_steering_angle;
+
+        // ACC reports whether rain tires are fitted alongside the compound name
+        let tire_compound = if state.graphics.rain_tyres {
+            TireCompound::Wet
+        } else {
+            TireCompound::Dry
+        };
+        let tire_compound_name =
+            Some(state.graphics.tyre_compound.clone()).filter(|name| !name.is_empty());
 
         // ACC doesn't have iRacing-specific session IDs, so all are None
         Ok(SessionInfo {
@@ -306,6 +384,14 @@ impl TelemetryProducer for ACCTelemetryProducer {
             we_season_id: None,
             we_sub_session_id: None,
             we_league_id: None,
+            tire_compound,
+            tire_compound_name,
+            car_name: Some(state.static_data.car_model.trim().to_string())
+                .filter(|name| !name.is_empty()),
+            // The track grip status and rain intensity are not exposed by simetry 0.2.3
+            track_conditions: TrackConditions::Unknown,
+            relative_humidity_pct: None,
+            format_version: TELEMETRY_FORMAT_VERSION,
         })
     }
 
@@ -314,17 +400,18 @@ impl TelemetryProducer for ACCTelemetryProducer {
 
         if self.client.is_none() {
             error!("ACC: Client not initialized when requesting telemetry");
-            return Err(OcypodeError::TelemetryProducerError {
-                description: "The ACC connection is not initialized, call start() first."
-                    .to_string(),
+            return Err(OcypodeError::ProducerConnectError {
+                game: GameSource::ACC,
+                reason: "connection is not initialized, call start() first".to_string(),
             });
         }
 
         let client = self
             .client
             .as_mut()
-            .ok_or(OcypodeError::TelemetryProducerError {
-                description: "Missing ACC session".to_string(),
+            .ok_or(OcypodeError::ProducerConnectError {
+                game: GameSource::ACC,
+                reason: "missing ACC session".to_string(),
             })?;
 
         if self.point_no == usize::MAX {
@@ -352,7 +439,10 @@ impl TelemetryProducer for ACCTelemetryProducer {
             );
         }
 
-        Ok(TelemetryData::from_acc_state(&state, self.point_no))
+        Ok(
+            TelemetryData::from_acc_state(&state, self.point_no, &SystemClock)
+                .with_pedal_deadzones(&self.pedal_deadzones),
+        )
     }
 
     fn game_source(&self) -> GameSource {
@@ -370,12 +460,17 @@ impl TelemetryProducer for ACCTelemetryProducer {
 /// - Unit testing of telemetry processing logic without requiring a running game
 /// - Offline analysis of previously recorded telemetry sessions
 /// - Reproducible test scenarios for analyzer validation
-pub(crate) struct MockTelemetryProducer {
+/// - Running the collector and analyzer pipeline end to end on any platform
+///
+/// Once every point has been replayed `telemetry()` returns an error, which stops
+/// `collect_telemetry`.
+pub struct MockTelemetryProducer {
     cur_tick: usize,
     points: Vec<TelemetryData>,
     pub track_name: String,
     pub max_steering_angle: f32,
     pub game_source: GameSource,
+    pub car_name: Option<String>,
 }
 
 impl Default for MockTelemetryProducer {
@@ -386,6 +481,7 @@ impl Default for MockTelemetryProducer {
             track_name: "Unknown".to_string(),
             max_steering_angle: 0.,
             game_source: GameSource::IRacing,
+            car_name: None,
         }
     }
 }
@@ -414,6 +510,7 @@ impl MockTelemetryProducer {
             track_name: "Unknown".to_string(),
             max_steering_angle: 0.,
             game_source,
+            car_name: None,
         }
     }
 
@@ -447,17 +544,20 @@ impl MockTelemetryProducer {
         let mut points = Vec::new();
         let mut track_name = "Unknown".to_string();
         let mut max_steering_angle = 0.0;
+        let mut car_name = None;
+        let mut migrator = RecordMigrator::new();
 
-        for line in reader.lines() {
+        for (line_no, line) in reader.lines().enumerate() {
             let line = line.map_err(|e| OcypodeError::TelemetryProducerError {
                 description: format!("Could not read line from file: {}", e),
             })?;
 
             // Parse as TelemetryOutput format
-            let output: TelemetryOutput = serde_json::from_str(&line).map_err(|e| {
-                error!("Could not parse JSON line: {}", e);
-                OcypodeError::TelemetryProducerError {
-                    description: format!("Could not parse JSON line: {}", e),
+            let output = migrator.parse(&line).map_err(|e| {
+                error!("Could not parse JSON line {}: {}", line_no + 1, e);
+                OcypodeError::TelemetryParseError {
+                    line: line_no + 1,
+                    source: e,
                 }
             })?;
 
@@ -466,8 +566,10 @@ impl MockTelemetryProducer {
                     points.push(*telemetry);
                 }
                 TelemetryOutput::SessionChange(session) => {
+                    session.validate()?;
                     track_name = session.track_name;
                     max_steering_angle = session.max_steering_angle;
+                    car_name = session.car_name;
                 }
             }
         }
@@ -484,6 +586,7 @@ impl MockTelemetryProducer {
             track_name,
             max_steering_angle,
             game_source,
+            car_name,
         })
     }
 }
@@ -506,6 +609,12 @@ impl TelemetryProducer for MockTelemetryProducer {
             we_season_id: Some(0),
             we_sub_session_id: Some(0),
             we_league_id: Some(0),
+            tire_compound: TireCompound::Unknown,
+            tire_compound_name: None,
+            car_name: self.car_name.clone(),
+            track_conditions: TrackConditions::Unknown,
+            relative_humidity_pct: None,
+            format_version: TELEMETRY_FORMAT_VERSION,
         })
     }
 
@@ -526,3 +635,143 @@ impl TelemetryProducer for MockTelemetryProducer {
         self.game_source
     }
 }
+
+/// Reads the telemetry another machine streams with `ocypode serve`.
+///
+/// The stream is the JSON Lines format of a recorded telemetry file, one `TelemetryOutput`
+/// per line, so the sim can run on one machine and the live windows on another, on any
+/// platform. Lines are read on a background thread and each call to `telemetry()` hands
+/// out the next point received, in order, so a recording of the remote stream keeps
+/// every point the sender recorded. Annotations made on the sending machine are dropped,
+/// the analyzers run again here with the local config.
+///
+/// `telemetry()` returns an error once the connection closes, which stops
+/// `collect_telemetry`.
+pub struct NetworkTelemetryProducer {
+    address: String,
+    retry_wait_ms: u64,
+    retry_timeout_s: u64,
+    /// Latest session sent by the remote machine, updated by the reader thread
+    session_info: Arc<Mutex<Option<SessionInfo>>>,
+    points: Option<Receiver<TelemetryData>>,
+}
+
+impl NetworkTelemetryProducer {
+    /// Create a producer reading from `address`, e.g. `192.168.1.20:7878`.
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            retry_wait_ms: CONN_RETRY_WAIT_MS,
+            retry_timeout_s: CONN_RETRY_MAX_WAIT_S,
+            session_info: Arc::new(Mutex::new(None)),
+            points: None,
+        }
+    }
+}
+
+impl TelemetryProducer for NetworkTelemetryProducer {
+    fn start(&mut self) -> Result<(), OcypodeError> {
+        // the sender may not be running yet, keep trying like the sim producers do
+        let connect_start = Instant::now();
+        let stream = loop {
+            match TcpStream::connect(&self.address) {
+                Ok(stream) => break stream,
+                Err(e) if connect_start.elapsed().as_secs() < self.retry_timeout_s => {
+                    debug!("Could not connect to {}: {}, retrying", self.address, e);
+                    thread::sleep(Duration::from_millis(self.retry_wait_ms));
+                }
+                Err(e) => {
+                    return Err(OcypodeError::NetworkTelemetryError {
+                        address: self.address.clone(),
+                        source: e,
+                    });
+                }
+            }
+        };
+        info!("Connected to telemetry stream at {}", self.address);
+
+        let (points_tx, points_rx) = mpsc::channel();
+        let session_info = Arc::clone(&self.session_info);
+        thread::spawn(move || {
+            read_telemetry_stream(BufReader::new(stream), &session_info, points_tx)
+        });
+        self.points = Some(points_rx);
+        Ok(())
+    }
+
+    fn session_info(&mut self) -> Result<SessionInfo, OcypodeError> {
+        if self.points.is_none() {
+            return Err(OcypodeError::TelemetryProducerError {
+                description: "connection is not initialized, call start() first".to_string(),
+            });
+        }
+        self.session_info
+            .lock()
+            .ok()
+            .and_then(|session_info| session_info.clone())
+            .ok_or_else(|| OcypodeError::SessionInfoUnavailable {
+                game: self.game_source(),
+                reason: format!("no session received from {} yet", self.address),
+            })
+    }
+
+    fn telemetry(&mut self) -> Result<TelemetryData, OcypodeError> {
+        let points = self
+            .points
+            .as_ref()
+            .ok_or_else(|| OcypodeError::TelemetryProducerError {
+                description: "connection is not initialized, call start() first".to_string(),
+            })?;
+        let mut telemetry = points
+            .recv()
+            .map_err(|_| OcypodeError::TelemetryProducerError {
+                description: format!("telemetry stream from {} closed", self.address),
+            })?;
+        telemetry.annotations.clear();
+        Ok(telemetry)
+    }
+
+    fn game_source(&self) -> GameSource {
+        self.session_info
+            .lock()
+            .ok()
+            .and_then(|session_info| session_info.as_ref().map(|s| s.game_source))
+            .unwrap_or(GameSource::IRacing)
+    }
+}
+
+/// Parse the telemetry stream until the connection closes or the producer is dropped.
+fn read_telemetry_stream(
+    reader: impl BufRead,
+    session_info: &Mutex<Option<SessionInfo>>,
+    points: Sender<TelemetryData>,
+) {
+    let mut migrator = RecordMigrator::new();
+    for (line_no, line) in reader.lines().enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                warn!("Telemetry stream closed: {}", e);
+                return;
+            }
+        };
+        match migrator.parse(&line) {
+            Ok(TelemetryOutput::DataPoint(telemetry)) => {
+                if points.send(*telemetry).is_err() {
+                    return;
+                }
+            }
+            Ok(TelemetryOutput::SessionChange(session)) => {
+                if let Ok(mut current) = session_info.lock() {
+                    *current = Some(session);
+                }
+            }
+            Err(e) => warn!(
+                "Skipping malformed telemetry at line {}: {}",
+                line_no + 1,
+                e
+            ),
+        }
+    }
+    info!("Telemetry stream ended");
+}
@@ -2,16 +2,74 @@ use simple_moving_average::{SMA, SumTreeSMA};
 
 use crate::telemetry::is_telemetry_point_analyzable;
 
-use super::{TelemetryAnalyzer, TelemetryAnnotation, TelemetryData, TireInfo};
+use super::{
+    SessionInfo, SteeringFreePlay, TelemetryAnalyzer, TelemetryAnnotation, TelemetryData, TireInfo,
+    YawRateFilter,
+};
 
-/// only look for scrubbing or collect data points when steering is > than this %
-const MIN_STEERING_PCT_MEASURE: f32 = 0.1;
 const MIN_BRAKE_PCT_MEASURE: f32 = 0.4;
 const MAX_THROTTLE_PCT_MEASURE: f32 = 0.4;
 /// Minimum speed to consider for scrub analysis (m/s)
 const MIN_SPEED_MPS: f32 = 5.0;
 /// Temperature difference threshold indicating scrubbing (°C)
-const SCRUB_TEMP_THRESHOLD: f32 = 5.0;
+pub(crate) const SCRUB_TEMP_THRESHOLD: f32 = 5.0;
+/// Scrub or slip this long (ms) after the steering crossed the centre happened while
+/// changing direction between two corners
+const TRANSITION_WINDOW_MS: u128 = 750;
+/// Brake input that makes scrub or slip in a direction change a transition
+const TRANSITION_MIN_BRAKE_PCT: f32 = 0.1;
+
+/// Remembers when the steering last crossed the centre, so the scrub and slip analyzers
+/// can tell braking through a direction change in compound corners from a plain corner
+/// entry.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct DirectionChange {
+    /// Side the steering was last wound to past the free play, true for positive inputs
+    steering_side: Option<bool>,
+    /// When the steering last crossed the centre from one side to the other
+    change_ms: Option<u128>,
+}
+
+impl DirectionChange {
+    /// Remember when the steering crosses the centre, small inputs within the free play
+    /// keep the side it was last wound to.
+    pub(crate) fn track(
+        &mut self,
+        telemetry: &TelemetryData,
+        session_info: &SessionInfo,
+        steering: &SteeringFreePlay,
+    ) {
+        let Some(signed) = telemetry.signed_steering(session_info.max_steering_angle) else {
+            return;
+        };
+        if steering.is_steering(telemetry, session_info) != Some(true) {
+            return;
+        }
+        let side = signed > 0.;
+        if self
+            .steering_side
+            .is_some_and(|prev_side| prev_side != side)
+        {
+            self.change_ms = Some(telemetry.timestamp_ms);
+        }
+        self.steering_side = Some(side);
+    }
+
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Time since the direction change when the driver is braking shortly after it, the
+    /// car is still loaded from the last corner and not understeering into the next one.
+    pub(crate) fn transition_ms(&self, telemetry: &TelemetryData, brake: f32) -> Option<u128> {
+        if brake < TRANSITION_MIN_BRAKE_PCT {
+            return None;
+        }
+        self.change_ms
+            .map(|change_ms| telemetry.timestamp_ms.saturating_sub(change_ms))
+            .filter(|since_ms| *since_ms <= TRANSITION_WINDOW_MS)
+    }
+}
 
 pub(crate) struct ScrubAnalyzer<const WINDOW_SIZE: usize> {
     // For yaw rate based analysis (when available)
@@ -19,61 +77,140 @@ pub(crate) struct ScrubAnalyzer<const WINDOW_SIZE: usize> {
     // For tire temperature based analysis (fallback for ACC)
     tire_temp_baseline: SumTreeSMA<f32, f32, WINDOW_SIZE>,
     min_points: usize,
+    temp_threshold: f32,
+    /// only look for scrubbing or collect data points when steering is past the free play
+    steering: SteeringFreePlay,
+    direction_change: DirectionChange,
+    yaw_rate_filter: YawRateFilter,
 }
 
 impl<const WINDOW_SIZE: usize> ScrubAnalyzer<WINDOW_SIZE> {
+    /// Scrub analyzer with the default thresholds, comparing the raw yaw rate
     pub(crate) fn new(min_points: usize) -> Self {
+        Self::with_thresholds(
+            min_points,
+            SCRUB_TEMP_THRESHOLD,
+            SteeringFreePlay::default(),
+            1,
+        )
+    }
+
+    pub(crate) fn with_thresholds(
+        min_points: usize,
+        temp_threshold: f32,
+        steering: SteeringFreePlay,
+        yaw_rate_smoothing_samples: usize,
+    ) -> Self {
         Self {
             steering_to_yaw_average: SumTreeSMA::new(),
             tire_temp_baseline: SumTreeSMA::new(),
             min_points,
+            temp_threshold,
+            steering,
+            direction_change: DirectionChange::default(),
+            yaw_rate_filter: YawRateFilter::new(yaw_rate_smoothing_samples),
         }
     }
+
+    /// Tag scrub while braking shortly after a direction change as a compound corner
+    /// transition.
+    fn tag_transition(
+        &self,
+        telemetry: &TelemetryData,
+        brake: f32,
+        output: Vec<TelemetryAnnotation>,
+    ) -> Vec<TelemetryAnnotation> {
+        let Some(since_direction_change_ms) = self.direction_change.transition_ms(telemetry, brake)
+        else {
+            return output;
+        };
+        output
+            .into_iter()
+            .map(|annotation| match annotation {
+                TelemetryAnnotation::Scrub {
+                    avg_yaw_rate_change,
+                    cur_yaw_rate_change,
+                    is_scrubbing,
+                } => TelemetryAnnotation::TransitionInstability {
+                    avg_yaw_rate_change,
+                    cur_yaw_rate_change,
+                    brake,
+                    since_direction_change_ms,
+                    is_unstable: is_scrubbing,
+                    slip_ratio: None,
+                },
+                other => other,
+            })
+            .collect()
+    }
 }
 
 impl<const WINDOW_SIZE: usize> TelemetryAnalyzer for ScrubAnalyzer<WINDOW_SIZE> {
     fn analyze(
         &mut self,
         telemetry: &TelemetryData,
-        _session_info: &super::SessionInfo,
+        session_info: &SessionInfo,
     ) -> Vec<super::TelemetryAnnotation> {
         let output = Vec::new();
 
         // Skip analysis if doesn't meet requirements
         if !is_telemetry_point_analyzable(telemetry) {
+            self.direction_change.reset();
+            self.yaw_rate_filter.reset();
             return output;
         }
 
         // Extract common data from TelemetryData
         let brake = telemetry.brake.unwrap_or(0.0);
         let throttle = telemetry.throttle.unwrap_or(0.0);
-        let steering_pct = telemetry.steering_pct.unwrap_or(0.0);
+        let steering_pct = self.steering.input(telemetry, session_info).unwrap_or(0.0);
         let speed_mps = telemetry.speed_mps.unwrap_or(0.0);
+        self.direction_change
+            .track(telemetry, session_info, &self.steering);
+
+        // the filter sees every point so the average stays continuous through the corner,
+        // points are skipped until it has enough samples
+        let yaw_rate = match telemetry.yaw_rate_rps {
+            Some(yaw_rate) => match self.yaw_rate_filter.filter(yaw_rate) {
+                Some(filtered) => Some(filtered),
+                None => return output,
+            },
+            None => {
+                self.yaw_rate_filter.reset();
+                None
+            }
+        };
 
         // Only analyze when conditions are right for scrub detection
-        if steering_pct.abs() <= MIN_STEERING_PCT_MEASURE
+        if steering_pct <= self.steering.free_play_pct
             || speed_mps < MIN_SPEED_MPS
             || (brake < MIN_BRAKE_PCT_MEASURE && throttle > MAX_THROTTLE_PCT_MEASURE)
         {
             return output;
         }
 
-        // Try yaw rate based analysis first (for iRacing)
-        if let Some(yaw_rate) = telemetry.yaw_rate_rps {
-            return self.analyze_with_yaw_rate(steering_pct, yaw_rate);
-        }
+        // the car moves around more on a wet track, only report the scrub that stands out
+        let threshold_scale = session_info.track_conditions.grip_threshold_scale();
 
-        // Fallback to tire temperature based analysis (for ACC)
-        self.analyze_with_tire_temperature(telemetry, steering_pct)
+        // Try yaw rate based analysis first (for iRacing)
+        let output = if let Some(yaw_rate) = yaw_rate {
+            self.analyze_with_yaw_rate(steering_pct, yaw_rate, threshold_scale)
+        } else {
+            // Fallback to tire temperature based analysis (for ACC)
+            self.analyze_with_tire_temperature(telemetry, steering_pct, threshold_scale)
+        };
+        self.tag_transition(telemetry, brake, output)
     }
 }
 
 impl<const WINDOW_SIZE: usize> ScrubAnalyzer<WINDOW_SIZE> {
-    /// Analyze scrubbing using yaw rate data (original method for iRacing)
+    /// Analyze scrubbing using yaw rate data (original method for iRacing). Scrub is
+    /// reported past the average by `threshold_scale - 1` times the average.
     fn analyze_with_yaw_rate(
         &mut self,
         steering_pct: f32,
         yaw_rate: f32,
+        threshold_scale: f32,
     ) -> Vec<super::TelemetryAnnotation> {
         let mut output = Vec::new();
 
@@ -85,7 +222,8 @@ impl<const WINDOW_SIZE: usize> ScrubAnalyzer<WINDOW_SIZE> {
         // we are collected enough points, let's see if we are scrubbing
         if self.steering_to_yaw_average.get_num_samples() >= self.min_points {
             let avg_steering_to_yaw_change = self.steering_to_yaw_average.get_average();
-            if yaw_rate_change > avg_steering_to_yaw_change {
+            let margin = (threshold_scale - 1.0) * avg_steering_to_yaw_change.abs();
+            if yaw_rate_change > avg_steering_to_yaw_change + margin {
                 output.push(TelemetryAnnotation::Scrub {
                     avg_yaw_rate_change: avg_steering_to_yaw_change,
                     cur_yaw_rate_change: yaw_rate_change,
@@ -102,6 +240,7 @@ impl<const WINDOW_SIZE: usize> ScrubAnalyzer<WINDOW_SIZE> {
         &mut self,
         telemetry: &TelemetryData,
         _steering_pct: f32,
+        threshold_scale: f32,
     ) -> Vec<super::TelemetryAnnotation> {
         let mut output = Vec::new();
 
@@ -120,7 +259,7 @@ impl<const WINDOW_SIZE: usize> ScrubAnalyzer<WINDOW_SIZE> {
             let temp_increase = avg_tire_temp - baseline_temp;
 
             // Detect scrubbing: significant temperature increase above baseline
-            if temp_increase > SCRUB_TEMP_THRESHOLD {
+            if temp_increase > self.temp_threshold * threshold_scale {
                 // Use temperature-based values for the annotation
                 // Map temperature increase to a yaw rate change equivalent for consistency
                 let simulated_yaw_change = temp_increase / 10.0; // Scale factor for display
@@ -172,7 +311,30 @@ impl<const WINDOW_SIZE: usize> ScrubAnalyzer<WINDOW_SIZE> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::telemetry::{SessionInfo, TelemetryData};
+    use crate::telemetry::{DEFAULT_YAW_RATE_SMOOTHING_SAMPLES, SessionInfo, TelemetryData};
+
+    /// Yaw rate noise that averages out over the default smoothing window
+    const YAW_RATE_NOISE: [f32; 4] = [0.125, -0.0625, -0.125, 0.0625];
+
+    fn braking_telemetry(yaw_rate_rps: f32) -> TelemetryData {
+        TelemetryData {
+            brake: Some(0.5),
+            throttle: Some(0.2),
+            steering_pct: Some(0.5),
+            yaw_rate_rps: Some(yaw_rate_rps),
+            speed_mps: Some(20.0),
+            ..TelemetryData::default()
+        }
+    }
+
+    fn smoothed_analyzer() -> ScrubAnalyzer<10> {
+        ScrubAnalyzer::with_thresholds(
+            3,
+            SCRUB_TEMP_THRESHOLD,
+            SteeringFreePlay::default(),
+            DEFAULT_YAW_RATE_SMOOTHING_SAMPLES,
+        )
+    }
 
     #[test]
     fn test_no_scrub_annotation_due_to_insufficient_points() {
@@ -276,7 +438,7 @@ mod tests {
         let baseline_telemetry = TelemetryData {
             brake: Some(0.5),                    // Above MIN_BRAKE_PCT_MEASURE (0.4)
             throttle: Some(0.2),                 // Below MAX_THROTTLE_PCT_MEASURE (0.4)
-            steering_pct: Some(0.2),             // Above MIN_STEERING_PCT_MEASURE (0.1)
+            steering_pct: Some(0.2),             // Above DEFAULT_STEERING_FREE_PLAY_PCT (0.1)
             yaw_rate_rps: Some(0.15),            // Yaw rate change = 0.2 - 0.15 = 0.05
             speed_mps: Some(20.0),               // Above MIN_SPEED_MPS (5.0)
             is_pit_limiter_engaged: Some(false), // Not in pit limiter
@@ -344,7 +506,7 @@ mod tests {
         let baseline_telemetry = TelemetryData {
             brake: Some(0.5),                    // Above MIN_BRAKE_PCT_MEASURE (0.4)
             throttle: Some(0.2),                 // Below MAX_THROTTLE_PCT_MEASURE (0.4)
-            steering_pct: Some(0.2),             // Above MIN_STEERING_PCT_MEASURE (0.1)
+            steering_pct: Some(0.2),             // Above DEFAULT_STEERING_FREE_PLAY_PCT (0.1)
             yaw_rate_rps: None,                  // No yaw rate data (ACC scenario)
             speed_mps: Some(20.0),               // Above MIN_SPEED_MPS (5.0)
             is_pit_limiter_engaged: Some(false), // Not in pit limiter
@@ -404,4 +566,185 @@ mod tests {
             _ => panic!("Expected Scrub annotation, got {:?}", output[0]),
         }
     }
+
+    /// iRacing style point, steering is only available as an angle
+    fn angle_telemetry(steering_angle_rad: f32, yaw_rate_rps: f32) -> TelemetryData {
+        TelemetryData {
+            brake: Some(0.5),
+            throttle: Some(0.2),
+            steering_angle_rad: Some(steering_angle_rad),
+            yaw_rate_rps: Some(yaw_rate_rps),
+            speed_mps: Some(20.0),
+            is_pit_limiter_engaged: Some(false),
+            ..TelemetryData::default()
+        }
+    }
+
+    fn angle_session_info() -> SessionInfo {
+        SessionInfo {
+            max_steering_angle: 4.0,
+            ..SessionInfo::default()
+        }
+    }
+
+    #[test]
+    fn test_scrub_annotation_produced_with_steering_angle() {
+        let mut analyzer = ScrubAnalyzer::<10>::new(3);
+        let session_info = angle_session_info();
+
+        // 0.8 rad of 4.0 is 20% lock, yaw rate change = 0.2 - 0.15 = 0.05
+        for _ in 0..3 {
+            analyzer.analyze(&angle_telemetry(0.8, 0.15), &session_info);
+        }
+
+        let output = analyzer.analyze(&angle_telemetry(1.6, 0.05), &session_info);
+        match output.as_slice() {
+            [
+                TelemetryAnnotation::Scrub {
+                    cur_yaw_rate_change,
+                    ..
+                },
+            ] => assert!((cur_yaw_rate_change - 0.35).abs() < 0.001),
+            _ => panic!("Expected Scrub annotation, got {:?}", output),
+        }
+    }
+
+    #[test]
+    fn test_no_scrub_annotation_near_center_steering() {
+        let mut analyzer = ScrubAnalyzer::<10>::new(3);
+        let session_info = angle_session_info();
+
+        for _ in 0..3 {
+            analyzer.analyze(&angle_telemetry(0.8, 0.15), &session_info);
+        }
+
+        // 0.3 rad is 7.5% lock, within the free play even though the yaw rate
+        // change of 0.075 is above the average
+        for _ in 0..10 {
+            let output = analyzer.analyze(&angle_telemetry(0.3, 0.0), &session_info);
+            assert!(output.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_steering_linearity_shrinks_small_inputs() {
+        let mut analyzer = ScrubAnalyzer::<10>::with_thresholds(
+            3,
+            SCRUB_TEMP_THRESHOLD,
+            SteeringFreePlay {
+                linearity: 2.0,
+                ..SteeringFreePlay::default()
+            },
+            1,
+        );
+        let session_info = angle_session_info();
+
+        // 20% lock squared is 4%, inside the free play so no samples are collected
+        for _ in 0..10 {
+            assert!(
+                analyzer
+                    .analyze(&angle_telemetry(0.8, 0.15), &session_info)
+                    .is_empty()
+            );
+        }
+        assert_eq!(analyzer.steering_to_yaw_average.get_num_samples(), 0);
+    }
+
+    #[test]
+    fn test_scrub_in_direction_change_is_a_transition() {
+        let mut analyzer = ScrubAnalyzer::<10>::new(3);
+        let session_info = angle_session_info();
+        let at = |timestamp_ms: u128, steering_angle_rad: f32, yaw_rate_rps: f32| TelemetryData {
+            timestamp_ms,
+            ..angle_telemetry(steering_angle_rad, yaw_rate_rps)
+        };
+
+        // turning left out of the first corner, then braking into the right hander
+        for i in 0..3 {
+            analyzer.analyze(&at(i * 100, 0.8, 0.15), &session_info);
+        }
+        assert!(
+            analyzer
+                .analyze(&at(400, -0.8, 0.15), &session_info)
+                .is_empty()
+        );
+        let output = analyzer.analyze(&at(600, -1.6, 0.05), &session_info);
+        match output.as_slice() {
+            [
+                TelemetryAnnotation::TransitionInstability {
+                    brake,
+                    since_direction_change_ms,
+                    is_unstable,
+                    ..
+                },
+            ] => {
+                assert_eq!(*brake, 0.5);
+                assert_eq!(*since_direction_change_ms, 200);
+                assert!(*is_unstable);
+            }
+            _ => panic!(
+                "Expected TransitionInstability annotation, got {:?}",
+                output
+            ),
+        }
+
+        // well into the second corner it is plain scrub again
+        let output = analyzer.analyze(&at(1500, -1.6, 0.05), &session_info);
+        assert!(matches!(
+            output.as_slice(),
+            [TelemetryAnnotation::Scrub { .. }]
+        ));
+    }
+
+    #[test]
+    fn test_noisy_stable_yaw_rate_is_not_scrub() {
+        let session_info = SessionInfo::default();
+        let noisy: Vec<_> = (0..40)
+            .map(|i| braking_telemetry(0.25 + YAW_RATE_NOISE[i % YAW_RATE_NOISE.len()]))
+            .collect();
+
+        let mut analyzer = smoothed_analyzer();
+        for point in &noisy {
+            assert!(analyzer.analyze(point, &session_info).is_empty());
+        }
+
+        // the raw signal reports scrub on every noise peak
+        let mut raw = ScrubAnalyzer::<10>::new(3);
+        assert!(
+            noisy
+                .iter()
+                .any(|point| !raw.analyze(point, &session_info).is_empty())
+        );
+    }
+
+    #[test]
+    fn test_yaw_rate_step_is_scrub_after_smoothing() {
+        let session_info = SessionInfo::default();
+        let mut analyzer = smoothed_analyzer();
+        for _ in 0..20 {
+            assert!(
+                analyzer
+                    .analyze(&braking_telemetry(0.25), &session_info)
+                    .is_empty()
+            );
+        }
+
+        // the front tires let go and the car stops rotating with the same steering
+        let output = analyzer.analyze(&braking_telemetry(0.05), &session_info);
+        match output.as_slice() {
+            [
+                TelemetryAnnotation::Scrub {
+                    avg_yaw_rate_change,
+                    cur_yaw_rate_change,
+                    is_scrubbing,
+                },
+            ] => {
+                assert!(*is_scrubbing);
+                // 0.5 steering against the average of 3 samples at 0.25 and one at 0.05
+                assert!((cur_yaw_rate_change - 0.3).abs() < 1e-3);
+                assert!(*cur_yaw_rate_change > *avg_yaw_rate_change);
+            }
+            _ => panic!("Expected Scrub annotation, got {:?}", output),
+        }
+    }
 }
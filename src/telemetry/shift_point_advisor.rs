@@ -0,0 +1,333 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{ACC_OPTIMAL_SHIFT_PCT, TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+
+/// Shifts within this many RPM of the optimal shift point are considered on target
+pub(crate) const DEFAULT_SHIFT_RPM_TOLERANCE: f32 = 200.;
+
+/// Width of the RPM buckets used to learn the acceleration curve of each gear
+const RPM_BUCKET_SIZE: f32 = 250.;
+/// Samples required before a bucket is trusted
+const MIN_BUCKET_SAMPLES: usize = 5;
+/// Throttle position above which acceleration samples are collected
+const FULL_THROTTLE: f32 = 0.95;
+/// Consecutive points further apart than this are not used to measure acceleration
+const MAX_SAMPLE_GAP_MS: u128 = 500;
+
+#[derive(Default)]
+struct Average {
+    sum: f32,
+    count: usize,
+}
+
+impl Average {
+    fn add(&mut self, value: f32) {
+        self.sum += value;
+        self.count += 1;
+    }
+
+    fn get(&self) -> Option<f32> {
+        (self.count >= MIN_BUCKET_SAMPLES).then(|| self.sum / self.count as f32)
+    }
+}
+
+/// Acceleration learned at full throttle for a single gear
+#[derive(Default)]
+struct GearCurve {
+    /// Speed (m/s) per engine RPM, the overall ratio of the gear
+    speed_per_rpm: Average,
+    /// Longitudinal acceleration (m/s^2) by RPM bucket
+    accel_by_rpm: BTreeMap<u32, Average>,
+}
+
+fn rpm_bucket(rpm: f32) -> u32 {
+    (rpm / RPM_BUCKET_SIZE) as u32
+}
+
+fn bucket_center_rpm(bucket: u32) -> f32 {
+    (bucket as f32 + 0.5) * RPM_BUCKET_SIZE
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct PrevPoint {
+    gear: i8,
+    rpm: f32,
+    speed_mps: f32,
+    timestamp_ms: u128,
+}
+
+/// Acceleration of each gear at full throttle, learned from the points of a session.
+///
+/// Shared by the shift analyzers so they judge upshifts against the same optimal RPM.
+/// Once two consecutive gears are learned, the optimal upshift is the lowest RPM where
+/// the next gear, at the RPM it lands on after the shift, accelerates at least as hard
+/// as the current one.
+#[derive(Default)]
+pub(crate) struct AccelerationCurves {
+    gears: HashMap<i8, GearCurve>,
+    prev: Option<PrevPoint>,
+}
+
+impl AccelerationCurves {
+    fn learn(&mut self, gear: i8, rpm: f32, speed_mps: f32, accel_mps2: f32) {
+        let curve = self.gears.entry(gear).or_default();
+        curve.speed_per_rpm.add(speed_mps / rpm);
+        curve
+            .accel_by_rpm
+            .entry(rpm_bucket(rpm))
+            .or_default()
+            .add(accel_mps2);
+    }
+
+    /// Learn from the acceleration since the previous point, returns the previous point
+    /// in gear. Neutral is skipped so double-clutching is seen as a single upshift.
+    pub(crate) fn observe(&mut self, telemetry: &TelemetryData) -> Option<PrevPoint> {
+        let cur = PrevPoint {
+            gear: telemetry.gear.unwrap_or(0),
+            rpm: telemetry.engine_rpm.unwrap_or(0.),
+            speed_mps: telemetry.speed_mps.unwrap_or(0.),
+            timestamp_ms: telemetry.timestamp_ms,
+        };
+        let prev = self.prev;
+
+        if let Some(prev) = prev {
+            let elapsed_ms = cur.timestamp_ms.saturating_sub(prev.timestamp_ms);
+            if cur.gear == prev.gear
+                && cur.rpm > 0.
+                && elapsed_ms > 0
+                && elapsed_ms <= MAX_SAMPLE_GAP_MS
+                && telemetry.throttle.unwrap_or(0.) > FULL_THROTTLE
+                && telemetry.brake.unwrap_or(0.) == 0.
+            {
+                let accel = (cur.speed_mps - prev.speed_mps) / (elapsed_ms as f32 / 1000.);
+                self.learn(cur.gear, (cur.rpm + prev.rpm) / 2., cur.speed_mps, accel);
+            }
+        }
+
+        if cur.gear > 0 {
+            self.prev = Some(cur);
+        }
+        prev
+    }
+
+    /// RPM that maximizes acceleration when upshifting from `gear`, if both `gear`
+    /// and the next gear have been learned.
+    pub(crate) fn optimal_shift_rpm(&self, gear: i8) -> Option<f32> {
+        let cur = self.gears.get(&gear)?;
+        let next = self.gears.get(&(gear + 1))?;
+        // RPM after the upshift is proportional to the ratio between the two gears
+        let drop_ratio = cur.speed_per_rpm.get()? / next.speed_per_rpm.get()?;
+
+        cur.accel_by_rpm
+            .iter()
+            .filter_map(|(bucket, accel)| Some((bucket_center_rpm(*bucket), accel.get()?)))
+            .find(|(rpm, accel)| {
+                next.accel_by_rpm
+                    .get(&rpm_bucket(rpm * drop_ratio))
+                    .and_then(Average::get)
+                    .is_some_and(|next_accel| next_accel >= *accel)
+            })
+            .map(|(rpm, _)| rpm)
+    }
+
+    /// Curves of gears 2 and 3, gear 2 covers 0.005 m/s per RPM and gear 3 0.0065 m/s
+    /// per RPM. The two gears cross at roughly 4875 RPM in gear 2.
+    #[cfg(test)]
+    pub(crate) fn learned_gears_2_and_3() -> Self {
+        let mut curves = Self::default();
+        for rpm in (2000..8000).step_by(50) {
+            let rpm = rpm as f32;
+            for _ in 0..MIN_BUCKET_SAMPLES {
+                curves.learn(2, rpm, rpm * 0.005, 10. - rpm / 1000.);
+                curves.learn(3, rpm, rpm * 0.0065, 7. - rpm / 2000.);
+            }
+        }
+        curves
+    }
+}
+
+/// Compares upshift RPMs against the RPM that maximizes acceleration.
+///
+/// Like the wheelspin analyzer, the advisor learns how the car accelerates in each
+/// gear at full throttle, see [`AccelerationCurves`]. Until two consecutive gears are
+/// learned it falls back to the sim shift point (iRacing) or a percentage of the max
+/// RPM (ACC).
+pub(crate) struct ShiftPointAdvisor {
+    curves: AccelerationCurves,
+    tolerance_rpm: f32,
+}
+
+impl ShiftPointAdvisor {
+    pub(crate) fn new(tolerance_rpm: f32) -> Self {
+        Self {
+            curves: AccelerationCurves::default(),
+            tolerance_rpm,
+        }
+    }
+
+    fn fallback_shift_rpm(telemetry: &TelemetryData) -> Option<f32> {
+        telemetry.shift_point_rpm.or_else(|| {
+            telemetry
+                .max_engine_rpm
+                .map(|max_rpm| max_rpm * ACC_OPTIMAL_SHIFT_PCT)
+        })
+    }
+}
+
+impl TelemetryAnalyzer for ShiftPointAdvisor {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        _session_info: &super::SessionInfo,
+    ) -> Vec<super::TelemetryAnnotation> {
+        let mut output = Vec::new();
+
+        if !is_telemetry_point_analyzable(telemetry) {
+            return output;
+        }
+
+        let cur_gear = telemetry.gear.unwrap_or(0);
+        if let Some(prev) = self.curves.observe(telemetry)
+            && prev.gear > 0
+            && cur_gear == prev.gear + 1
+        {
+            let learned = self.curves.optimal_shift_rpm(prev.gear);
+            let is_power_curve_optimal = learned.is_some();
+            if let Some(optimal_rpm) = learned.or_else(|| Self::fallback_shift_rpm(telemetry))
+                && optimal_rpm > 0.
+            {
+                let is_over_revving = prev.rpm > optimal_rpm + self.tolerance_rpm;
+                let is_under_revving = prev.rpm < optimal_rpm - self.tolerance_rpm;
+                if is_over_revving || is_under_revving {
+                    output.push(TelemetryAnnotation::ShiftPoint {
+                        from_gear: prev.gear,
+                        shift_rpm: prev.rpm,
+                        optimal_rpm,
+                        is_power_curve_optimal,
+                        is_over_revving,
+                        is_under_revving,
+                    });
+                }
+            }
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::SessionInfo;
+
+    fn point(gear: i8, rpm: f32, timestamp_ms: u128) -> TelemetryData {
+        TelemetryData {
+            gear: Some(gear),
+            engine_rpm: Some(rpm),
+            speed_mps: Some(30.),
+            throttle: Some(1.),
+            brake: Some(0.),
+            timestamp_ms,
+            ..TelemetryData::default()
+        }
+    }
+
+    fn shift(
+        advisor: &mut ShiftPointAdvisor,
+        mut before: TelemetryData,
+        mut after: TelemetryData,
+    ) -> Vec<TelemetryAnnotation> {
+        let session_info = SessionInfo::default();
+        // no time gap so the shift does not feed the learned curves
+        before.throttle = Some(0.);
+        after.throttle = Some(0.);
+        advisor.analyze(&before, &session_info);
+        advisor.analyze(&after, &session_info)
+    }
+
+    #[test]
+    fn test_optimal_shift_rpm_from_learned_curves() {
+        assert_eq!(AccelerationCurves::default().optimal_shift_rpm(2), None);
+
+        let curves = AccelerationCurves::learned_gears_2_and_3();
+        let optimal = curves.optimal_shift_rpm(2).unwrap();
+        assert!((optimal - 4875.).abs() <= RPM_BUCKET_SIZE);
+        // gear 4 was never learned
+        assert_eq!(curves.optimal_shift_rpm(3), None);
+    }
+
+    #[test]
+    fn test_over_revving_against_learned_curve() {
+        let mut advisor = ShiftPointAdvisor::new(DEFAULT_SHIFT_RPM_TOLERANCE);
+        advisor.curves = AccelerationCurves::learned_gears_2_and_3();
+
+        let mut before = point(2, 7500., 0);
+        // sim shift point agrees with the over-rev, learned curve must take priority
+        before.shift_point_rpm = Some(7600.);
+        let output = shift(&mut advisor, before, point(3, 5800., 0));
+
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::ShiftPoint {
+                from_gear,
+                is_power_curve_optimal,
+                is_over_revving,
+                is_under_revving,
+                ..
+            } => {
+                assert_eq!(*from_gear, 2);
+                assert!(*is_power_curve_optimal);
+                assert!(*is_over_revving);
+                assert!(!*is_under_revving);
+            }
+            _ => panic!("Expected ShiftPoint annotation"),
+        }
+    }
+
+    #[test]
+    fn test_falls_back_to_sim_shift_point() {
+        let mut advisor = ShiftPointAdvisor::new(DEFAULT_SHIFT_RPM_TOLERANCE);
+        let mut before = point(2, 5000., 0);
+        before.shift_point_rpm = Some(6500.);
+        let mut after = point(3, 4000., 0);
+        after.shift_point_rpm = Some(6500.);
+
+        let output = shift(&mut advisor, before, after);
+
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::ShiftPoint {
+                optimal_rpm,
+                is_power_curve_optimal,
+                is_under_revving,
+                ..
+            } => {
+                assert_eq!(*optimal_rpm, 6500.);
+                assert!(!*is_power_curve_optimal);
+                assert!(*is_under_revving);
+            }
+            _ => panic!("Expected ShiftPoint annotation"),
+        }
+    }
+
+    #[test]
+    fn test_falls_back_to_max_rpm_percentage() {
+        let mut advisor = ShiftPointAdvisor::new(DEFAULT_SHIFT_RPM_TOLERANCE);
+        let mut after = point(3, 5000., 0);
+        after.max_engine_rpm = Some(8000.);
+
+        // 8000 * 0.92 = 7360, a 7300 RPM shift is within tolerance
+        assert!(shift(&mut advisor, point(2, 7300., 0), after.clone()).is_empty());
+
+        let output = shift(&mut advisor, point(2, 7900., 0), after);
+        assert!(matches!(
+            output.as_slice(),
+            [TelemetryAnnotation::ShiftPoint {
+                is_over_revving: true,
+                ..
+            }]
+        ));
+    }
+}
@@ -2,26 +2,39 @@ use std::default;
 
 use crate::telemetry::is_telemetry_point_analyzable;
 
-use super::{TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+use super::{
+    TelemetryAnalyzer, TelemetryAnnotation, TelemetryData, shift_point_advisor::AccelerationCurves,
+};
 
-const DEFAULT_SHORT_SHIFT_SENSITIVITY: f32 = 100.;
+pub(crate) const DEFAULT_SHORT_SHIFT_SENSITIVITY: f32 = 100.;
 
 pub(crate) struct ShortShiftingAnalyzer {
     prev_rpm: f32,
     prev_gear: i8,
     sensitivity: f32,
+    curves: AccelerationCurves,
 }
 
-impl default::Default for ShortShiftingAnalyzer {
-    fn default() -> Self {
+impl ShortShiftingAnalyzer {
+    /// Upshifts more than `sensitivity` RPM below the optimal shift point are short
+    /// shifts. The optimal point comes from the learned acceleration curves once the
+    /// gear and the next one are learned, and from the sim shift point until then.
+    pub(crate) fn new(sensitivity: f32) -> Self {
         Self {
             prev_gear: 0,
             prev_rpm: 0.,
-            sensitivity: DEFAULT_SHORT_SHIFT_SENSITIVITY,
+            sensitivity,
+            curves: AccelerationCurves::default(),
         }
     }
 }
 
+impl default::Default for ShortShiftingAnalyzer {
+    fn default() -> Self {
+        Self::new(DEFAULT_SHORT_SHIFT_SENSITIVITY)
+    }
+}
+
 impl TelemetryAnalyzer for ShortShiftingAnalyzer {
     fn analyze(
         &mut self,
@@ -38,7 +51,13 @@ impl TelemetryAnalyzer for ShortShiftingAnalyzer {
         // Extract data from TelemetryData
         let cur_gear = telemetry.gear.unwrap_or(0);
         let cur_rpm = telemetry.engine_rpm.unwrap_or(0.0);
-        let shift_point_rpm = telemetry.shift_point_rpm.unwrap_or(0.0);
+        // the sim shift point is not always where the car accelerates hardest
+        self.curves.observe(telemetry);
+        let shift_point_rpm = self
+            .curves
+            .optimal_shift_rpm(self.prev_gear)
+            .or(telemetry.shift_point_rpm)
+            .unwrap_or(0.0);
 
         if self.prev_rpm > 0.
             && self.prev_gear > 0
@@ -124,6 +143,31 @@ mod tests {
         assert!(output.is_empty());
     }
 
+    #[test]
+    fn test_learned_curve_overrides_sim_shift_point() {
+        let mut analyzer = ShortShiftingAnalyzer::default();
+        // gears 2 and 3 cross at roughly 4875 RPM
+        analyzer.curves = AccelerationCurves::learned_gears_2_and_3();
+        let session_info = SessionInfo::default();
+
+        let before = TelemetryData {
+            gear: Some(2),
+            engine_rpm: Some(5000.0),
+            shift_point_rpm: Some(6500.0),
+            speed_mps: Some(10.),
+            ..create_default_telemetry()
+        };
+        analyzer.analyze(&before, &session_info);
+
+        let after = TelemetryData {
+            gear: Some(3),
+            engine_rpm: Some(3900.0),
+            ..before.clone()
+        };
+        // well below the sim shift point, but past the power curve optimum
+        assert!(analyzer.analyze(&after, &session_info).is_empty());
+    }
+
     #[test]
     fn test_acc_short_shift_annotation_with_estimated_shift_point() {
         let mut analyzer = ShortShiftingAnalyzer::default();
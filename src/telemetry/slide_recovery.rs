@@ -0,0 +1,140 @@
+use super::TelemetryData;
+
+/// Speed lost since the start of a slide, as a fraction of the speed it started at,
+/// beyond which the slide counts as lost
+const MAX_RECOVERED_SPEED_LOSS: f32 = 0.1;
+/// A slide still going after this long is a spin rather than a caught slide
+const MAX_RECOVERED_SLIDE_MS: u128 = 1500;
+/// Steering lock the driver must take off, as a fraction of the lock at the start of
+/// the slide, to count as a correction
+const MIN_STEERING_CORRECTION: f32 = 0.1;
+/// Throttle the driver may lift while catching the slide
+const MAX_THROTTLE_LIFT: f32 = 0.05;
+
+#[derive(Clone, Copy, Debug)]
+struct Slide {
+    start_ms: u128,
+    start_speed_mps: f32,
+    start_steering: f32,
+    start_throttle: f32,
+    peak_yaw_rate: f32,
+    is_caught: bool,
+    is_lost: bool,
+}
+
+/// Follows an oversteer slide point by point to tell a slide the driver is catching
+/// from one that turns into a spin or an off.
+///
+/// A slide is caught once the yaw rate drops from its peak while the driver takes
+/// lock off and holds the throttle, and stays caught until it ends. A slide that loses
+/// too much speed or runs for too long is lost and stays lost, so a spin is never
+/// tagged as recovered on its way out, even if the driver caught it at first. The
+/// outcome is only settled at the last point of the slide: the points before the
+/// driver reacts are not recovered yet, see [`crate::setup_assistant::SetupAssistant`]
+/// for how the occurrences of a slide are held back until it ends.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SlideRecovery {
+    slide: Option<Slide>,
+}
+
+impl SlideRecovery {
+    /// Records an oversteer point and returns whether the driver caught the slide so far
+    pub(crate) fn track(&mut self, telemetry: &TelemetryData, yaw_rate: f32) -> bool {
+        let speed_mps = telemetry.speed_mps.unwrap_or(0.0);
+        let steering = telemetry.steering_pct.unwrap_or(0.0).abs();
+        let throttle = telemetry.throttle.unwrap_or(0.0);
+        let yaw_rate = yaw_rate.abs();
+
+        let Some(slide) = self.slide.as_mut() else {
+            self.slide = Some(Slide {
+                start_ms: telemetry.timestamp_ms,
+                start_speed_mps: speed_mps,
+                start_steering: steering,
+                start_throttle: throttle,
+                peak_yaw_rate: yaw_rate,
+                is_caught: false,
+                is_lost: false,
+            });
+            return false;
+        };
+
+        let speed_loss = if slide.start_speed_mps > 0.0 {
+            (slide.start_speed_mps - speed_mps) / slide.start_speed_mps
+        } else {
+            0.0
+        };
+        let duration_ms = telemetry.timestamp_ms.saturating_sub(slide.start_ms);
+        if speed_loss > MAX_RECOVERED_SPEED_LOSS || duration_ms > MAX_RECOVERED_SLIDE_MS {
+            slide.is_lost = true;
+        }
+
+        let is_rotation_falling = yaw_rate < slide.peak_yaw_rate;
+        slide.peak_yaw_rate = slide.peak_yaw_rate.max(yaw_rate);
+        let is_correcting = steering <= slide.start_steering * (1.0 - MIN_STEERING_CORRECTION);
+        let is_holding_throttle = throttle >= slide.start_throttle - MAX_THROTTLE_LIFT;
+        slide.is_caught |= is_rotation_falling && is_correcting && is_holding_throttle;
+
+        slide.is_caught && !slide.is_lost
+    }
+
+    /// Ends the current slide, once a point no longer shows oversteer
+    pub(crate) fn end(&mut self) {
+        self.slide = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(timestamp_ms: u128, speed_mps: f32, steering_pct: f32) -> TelemetryData {
+        TelemetryData {
+            timestamp_ms,
+            speed_mps: Some(speed_mps),
+            steering_pct: Some(steering_pct),
+            throttle: Some(0.1),
+            ..TelemetryData::default()
+        }
+    }
+
+    #[test]
+    fn test_caught_slide_is_recovered() {
+        let mut recovery = SlideRecovery::default();
+        assert!(!recovery.track(&point(0, 40.0, 0.3), 0.6));
+        // more rotation and no correction yet
+        assert!(!recovery.track(&point(100, 39.8, 0.3), 0.7));
+        // lock off, the rotation falls and the car keeps its speed
+        assert!(recovery.track(&point(200, 39.5, 0.2), 0.5));
+        // a last flick of the tail after the correction does not undo the save
+        assert!(recovery.track(&point(300, 39.2, 0.25), 0.45));
+
+        recovery.end();
+        assert!(!recovery.track(&point(5000, 40.0, 0.3), 0.6));
+    }
+
+    #[test]
+    fn test_spin_is_not_recovered() {
+        let mut recovery = SlideRecovery::default();
+        recovery.track(&point(0, 40.0, 0.3), 0.6);
+        // the car scrubs off speed while the driver unwinds the wheel
+        assert!(!recovery.track(&point(200, 30.0, 0.1), 0.5));
+        // and stays lost even once the speed stops dropping
+        assert!(!recovery.track(&point(300, 30.0, 0.05), 0.3));
+    }
+
+    #[test]
+    fn test_caught_slide_turning_into_a_spin_is_not_recovered() {
+        let mut recovery = SlideRecovery::default();
+        recovery.track(&point(0, 40.0, 0.3), 0.6);
+        assert!(recovery.track(&point(100, 39.8, 0.2), 0.5));
+        // the rear comes round again and the car scrubs off speed
+        assert!(!recovery.track(&point(300, 33.0, 0.1), 0.8));
+    }
+
+    #[test]
+    fn test_long_slide_is_not_recovered() {
+        let mut recovery = SlideRecovery::default();
+        recovery.track(&point(0, 40.0, 0.3), 0.6);
+        assert!(!recovery.track(&point(2000, 39.5, 0.2), 0.5));
+    }
+}
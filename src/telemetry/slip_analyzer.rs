@@ -1,49 +1,121 @@
 use crate::telemetry::is_telemetry_point_analyzable;
 
-use super::{TelemetryAnalyzer, TelemetryData};
+use super::{SteeringFreePlay, TelemetryAnalyzer, TelemetryData, scrub_analyzer::DirectionChange};
 
 pub(crate) const STEERING_ANGLE_DEADZONE_RAD: f32 = 0.12; // Increased from 0.08 to reduce sensitivity
+/// Require more significant speed loss to reduce false positives
+pub(crate) const MIN_SLIP_SPEED_LOSS_MPS: f32 = 0.5; // ~1.8 km/h minimum speed loss
+/// Wheel slip ratio, spinning or locking, that counts as a slip when the sim reports
+/// wheel slip ratios or speeds
+pub(crate) const MIN_SLIP_RATIO: f32 = 0.08;
 
-#[derive(Default)]
 pub(crate) struct SlipAnalyzer {
+    steering_deadzone_rad: f32,
+    min_speed_loss_mps: f32,
+    /// Ignores steering within the free play when it can be normalized against full lock
+    steering: SteeringFreePlay,
+    direction_change: DirectionChange,
     prev_throttle: f32,
     prev_brake: f32,
     prev_steering_angle: f32,
     prev_speed: f32,
 }
 
+impl SlipAnalyzer {
+    pub(crate) fn new(
+        steering_deadzone_rad: f32,
+        min_speed_loss_mps: f32,
+        steering: SteeringFreePlay,
+    ) -> Self {
+        Self {
+            steering_deadzone_rad,
+            min_speed_loss_mps,
+            steering,
+            direction_change: DirectionChange::default(),
+            prev_throttle: 0.,
+            prev_brake: 0.,
+            prev_steering_angle: 0.,
+            prev_speed: 0.,
+        }
+    }
+}
+
+impl Default for SlipAnalyzer {
+    fn default() -> Self {
+        Self::new(
+            STEERING_ANGLE_DEADZONE_RAD,
+            MIN_SLIP_SPEED_LOSS_MPS,
+            SteeringFreePlay::default(),
+        )
+    }
+}
+
 impl TelemetryAnalyzer for SlipAnalyzer {
     fn analyze(
         &mut self,
         telemetry: &TelemetryData,
-        _session_info: &super::SessionInfo,
+        session_info: &super::SessionInfo,
     ) -> Vec<super::TelemetryAnnotation> {
         let mut output = Vec::new();
 
         // Skip analysis if doesn't meet requirements
         if !is_telemetry_point_analyzable(telemetry) {
+            self.direction_change.reset();
             return output;
         }
+        self.direction_change
+            .track(telemetry, session_info, &self.steering);
 
         // Extract data from TelemetryData
         let brake = telemetry.brake.unwrap_or(0.0);
         let throttle = telemetry.throttle.unwrap_or(0.0);
-        let steering = telemetry.steering_angle_rad.unwrap_or(0.0).abs();
+        let steering = telemetry
+            .steering_angle(session_info.max_steering_angle)
+            .unwrap_or(0.0)
+            .abs();
         let cur_speed = telemetry.speed_mps.unwrap_or(0.0);
+        // the car slides more on a wet track, only report the slides that stand out
+        let grip_threshold_scale = session_info.track_conditions.grip_threshold_scale();
+        // with wheel slip ratios the tires tell whether they slip, otherwise a slipping car is
+        // one losing speed while the driver holds the throttle
+        let slip_ratio = telemetry
+            .wheel_slip_ratios()
+            .map(|ratios| ratios.into_iter().map(f32::abs).fold(0., f32::max));
+        let is_slipping = match slip_ratio {
+            Some(slip_ratio) => slip_ratio >= MIN_SLIP_RATIO * grip_threshold_scale,
+            None => {
+                cur_speed < self.prev_speed
+                    && (self.prev_speed - cur_speed)
+                        >= self.min_speed_loss_mps * grip_threshold_scale
+            }
+        };
 
-        // Require more significant speed loss to reduce false positives
-        const MIN_SPEED_LOSS_MPS: f32 = 0.5; // ~1.8 km/h minimum speed loss
-
-        if brake == 0.
-            && throttle >= self.prev_throttle
-            && steering > STEERING_ANGLE_DEADZONE_RAD
-            && cur_speed < self.prev_speed
-            && (self.prev_speed - cur_speed) >= MIN_SPEED_LOSS_MPS
-        {
+        let is_steering = steering > self.steering_deadzone_rad
+            && self.steering.is_steering(telemetry, session_info) != Some(false);
+        if brake == 0. && throttle >= self.prev_throttle && is_steering && is_slipping {
             output.push(super::TelemetryAnnotation::Slip {
                 prev_speed: self.prev_speed,
                 cur_speed,
                 is_slip: true,
+                slip_ratio,
+            });
+        }
+
+        // braking through a direction change, the car is still loaded from the last
+        // corner. Only wheel slip ratios tell, losing speed is expected under braking.
+        if let Some(since_direction_change_ms) =
+            self.direction_change.transition_ms(telemetry, brake)
+            && is_steering
+            && is_slipping
+            && slip_ratio.is_some()
+        {
+            output.push(super::TelemetryAnnotation::TransitionInstability {
+                avg_yaw_rate_change: 0.,
+                cur_yaw_rate_change: 0.,
+                brake,
+                since_direction_change_ms,
+                is_unstable: true,
+                slip_ratio,
             });
         }
 
@@ -59,7 +131,7 @@ impl TelemetryAnalyzer for SlipAnalyzer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::telemetry::{SessionInfo, TelemetryAnnotation, TelemetryData};
+    use crate::telemetry::{SessionInfo, TelemetryAnnotation, TelemetryData, TrackConditions};
 
     #[test]
     fn test_slip_annotation_inserted() {
@@ -85,6 +157,7 @@ mod tests {
                 prev_speed,
                 cur_speed,
                 is_slip,
+                slip_ratio: _,
             } => {
                 assert_eq!(*prev_speed, 55.0);
                 assert_eq!(*cur_speed, 50.0);
@@ -94,6 +167,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_slip_with_lock_fraction_only() {
+        // ACC reports the steering as a fraction of full lock, without a wheel angle
+        let mut analyzer = SlipAnalyzer::default();
+        let telemetry_data = TelemetryData {
+            throttle: Some(0.5),
+            speed_mps: Some(50.0),
+            steering_pct: Some(0.2),
+            ..create_default_telemetry()
+        };
+        let session_info = SessionInfo {
+            max_steering_angle: 3.0,
+            ..SessionInfo::default()
+        };
+        analyzer.prev_throttle = 0.4;
+        analyzer.prev_speed = 55.0;
+
+        let output = analyzer.analyze(&telemetry_data, &session_info);
+        assert!(matches!(output[..], [TelemetryAnnotation::Slip { .. }]));
+    }
+
+    #[test]
+    fn test_slip_in_direction_change_is_a_transition() {
+        let mut analyzer = SlipAnalyzer::default();
+        let session_info = SessionInfo {
+            max_steering_angle: 3.0,
+            ..SessionInfo::default()
+        };
+        let at = |timestamp_ms: u128, steering_pct: f32, wheel_speed_mps: f32| TelemetryData {
+            timestamp_ms,
+            brake: Some(0.5),
+            speed_mps: Some(30.0),
+            steering_pct: Some(steering_pct),
+            lf_wheel_speed_mps: Some(wheel_speed_mps),
+            rf_wheel_speed_mps: Some(wheel_speed_mps),
+            lr_wheel_speed_mps: Some(30.0),
+            rr_wheel_speed_mps: Some(30.0),
+            ..create_default_telemetry()
+        };
+
+        // turning left out of the first corner, then locking the fronts into the right hander
+        assert!(
+            analyzer
+                .analyze(&at(0, 0.3, 30.0), &session_info)
+                .is_empty()
+        );
+        assert!(
+            analyzer
+                .analyze(&at(100, -0.3, 30.0), &session_info)
+                .is_empty()
+        );
+        let output = analyzer.analyze(&at(300, -0.3, 25.0), &session_info);
+        match output.as_slice() {
+            [
+                TelemetryAnnotation::TransitionInstability {
+                    since_direction_change_ms,
+                    slip_ratio: Some(slip_ratio),
+                    ..
+                },
+            ] => {
+                assert_eq!(*since_direction_change_ms, 200);
+                assert!(*slip_ratio > MIN_SLIP_RATIO);
+            }
+            _ => panic!(
+                "Expected TransitionInstability annotation, got {:?}",
+                output
+            ),
+        }
+
+        // well into the second corner the lock is no longer a transition
+        assert!(
+            analyzer
+                .analyze(&at(1500, -0.3, 25.0), &session_info)
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_no_slip_annotation_due_to_brake() {
         let mut analyzer = SlipAnalyzer::default();
@@ -154,6 +304,71 @@ mod tests {
         assert!(output.is_empty());
     }
 
+    #[test]
+    fn test_slip_threshold_raised_on_wet_track() {
+        // 0.6 m/s clears the dry threshold but not the wet one, 0.75 m/s
+        let telemetry_data = TelemetryData {
+            throttle: Some(0.5),
+            brake: Some(0.0),
+            speed_mps: Some(54.4),
+            steering_angle_rad: Some(0.15),
+            ..create_default_telemetry()
+        };
+        let slips = |track_conditions| {
+            let mut analyzer = SlipAnalyzer::default();
+            analyzer.prev_throttle = 0.4;
+            analyzer.prev_speed = 55.0;
+            let session_info = SessionInfo {
+                track_conditions,
+                ..SessionInfo::default()
+            };
+            !analyzer.analyze(&telemetry_data, &session_info).is_empty()
+        };
+
+        assert!(slips(TrackConditions::Unknown));
+        assert!(slips(TrackConditions::Dry));
+        assert!(!slips(TrackConditions::Wet));
+    }
+
+    #[test]
+    fn test_slip_from_wheel_speeds() {
+        let mut analyzer = SlipAnalyzer::default();
+        analyzer.prev_throttle = 0.4;
+        analyzer.prev_speed = 50.0;
+        // the car holds its speed, but the rear wheels turn 10% faster than it moves
+        let spinning = TelemetryData {
+            throttle: Some(0.5),
+            speed_mps: Some(50.0),
+            steering_angle_rad: Some(0.15),
+            lf_wheel_speed_mps: Some(50.0),
+            rf_wheel_speed_mps: Some(50.0),
+            lr_wheel_speed_mps: Some(55.0),
+            rr_wheel_speed_mps: Some(54.0),
+            ..create_default_telemetry()
+        };
+        let output = analyzer.analyze(&spinning, &SessionInfo::default());
+        assert_eq!(output.len(), 1);
+        let TelemetryAnnotation::Slip { slip_ratio, .. } = &output[0] else {
+            panic!("Expected Slip annotation");
+        };
+        assert!((slip_ratio.unwrap() - 0.1).abs() < 1e-4);
+
+        // the car loses speed, but the wheels roll with it
+        let gripping = TelemetryData {
+            speed_mps: Some(45.0),
+            lf_wheel_speed_mps: Some(45.0),
+            rf_wheel_speed_mps: Some(45.0),
+            lr_wheel_speed_mps: Some(45.5),
+            rr_wheel_speed_mps: Some(45.5),
+            ..spinning
+        };
+        assert!(
+            analyzer
+                .analyze(&gripping, &SessionInfo::default())
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_no_slip_annotation_due_to_low_steering() {
         let mut analyzer = SlipAnalyzer::default();
@@ -174,6 +389,28 @@ mod tests {
         assert!(output.is_empty());
     }
 
+    #[test]
+    fn test_no_slip_annotation_within_steering_free_play() {
+        let mut analyzer = SlipAnalyzer::default();
+        let telemetry_data = TelemetryData {
+            throttle: Some(0.5),
+            brake: Some(0.0),
+            speed_mps: Some(50.0),
+            steering_angle_rad: Some(0.15), // Above deadzone, but under 4% of full lock
+            ..create_default_telemetry()
+        };
+        let session_info = SessionInfo {
+            max_steering_angle: 4.0,
+            ..SessionInfo::default()
+        };
+
+        analyzer.prev_throttle = 0.4;
+        analyzer.prev_speed = 55.0;
+
+        let output = analyzer.analyze(&telemetry_data, &session_info);
+        assert!(output.is_empty());
+    }
+
     fn create_default_telemetry() -> TelemetryData {
         TelemetryData {
             gear: Some(1),
@@ -0,0 +1,158 @@
+use std::collections::VecDeque;
+
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{
+    SessionInfo, TelemetryAnalyzer, TelemetryAnnotation, TelemetryData,
+    steering_reversals::{REVERSAL_MIN_SPEED_MPS, ReversalCounter},
+};
+
+/// Points the reversal rate is measured over, 5 seconds at the collector refresh rate
+const WINDOW_POINTS: usize = 50;
+/// Reversals per second sustained over a whole window that count as busy hands
+pub(crate) const MAX_REVERSALS_PER_S: f32 = 1.5;
+
+/// Detects sustained high steering reversal rates, busy hands at the wheel.
+///
+/// Steering direction changes are counted over a sliding window of driving above
+/// `REVERSAL_MIN_SPEED_MPS`. A rate of `MAX_REVERSALS_PER_S` or more over the whole window
+/// is reported once and the window starts over. Unlike the countersteer checks of the
+/// oversteer analyzers, this looks at every correction, on straights and in corners.
+pub(crate) struct SteeringReversalAnalyzer {
+    counter: ReversalCounter,
+    /// Timestamp of each point in the window and whether it completed a reversal
+    window: VecDeque<(u128, bool)>,
+}
+
+impl SteeringReversalAnalyzer {
+    pub(crate) fn new() -> Self {
+        Self {
+            counter: ReversalCounter::default(),
+            window: VecDeque::with_capacity(WINDOW_POINTS),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.counter.reset();
+        self.window.clear();
+    }
+}
+
+impl TelemetryAnalyzer for SteeringReversalAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        session_info: &SessionInfo,
+    ) -> Vec<TelemetryAnnotation> {
+        let mut output = Vec::new();
+
+        let Some(steering) = telemetry.signed_steering(session_info.max_steering_angle) else {
+            self.reset();
+            return output;
+        };
+        if !is_telemetry_point_analyzable(telemetry)
+            || telemetry.speed_mps.unwrap_or(0.0) <= REVERSAL_MIN_SPEED_MPS
+        {
+            self.reset();
+            return output;
+        }
+
+        let is_reversal = self.counter.push(steering);
+        self.window.push_back((telemetry.timestamp_ms, is_reversal));
+        if self.window.len() < WINDOW_POINTS {
+            return output;
+        }
+
+        let reversals = self.window.iter().filter(|(_, r)| *r).count();
+        let duration_ms =
+            self.window.back().map_or(0, |(t, _)| *t) - self.window.front().map_or(0, |(t, _)| *t);
+        let reversals_per_s = if duration_ms > 0 {
+            reversals as f32 / (duration_ms as f32 / 1000.)
+        } else {
+            0.
+        };
+
+        if reversals_per_s >= MAX_REVERSALS_PER_S {
+            output.push(TelemetryAnnotation::SteeringReversals {
+                reversals,
+                reversals_per_s,
+                is_busy: true,
+            });
+            // report each busy stretch once per window
+            self.window.clear();
+        } else {
+            self.window.pop_front();
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(timestamp_ms: u128, speed_mps: f32, steering_pct: f32) -> TelemetryData {
+        TelemetryData {
+            timestamp_ms,
+            speed_mps: Some(speed_mps),
+            steering_pct: Some(steering_pct),
+            throttle: Some(1.0),
+            ..TelemetryData::default()
+        }
+    }
+
+    fn run(points: &[TelemetryData]) -> Vec<TelemetryAnnotation> {
+        let mut analyzer = SteeringReversalAnalyzer::new();
+        let session_info = SessionInfo::default();
+        points
+            .iter()
+            .flat_map(|p| analyzer.analyze(p, &session_info))
+            .collect()
+    }
+
+    /// Steering changing direction every `period` points, 5% of lock either way
+    fn corrections(speed_mps: f32, points: usize, period: usize) -> Vec<TelemetryData> {
+        (0..points)
+            .map(|i| {
+                let steering = if (i / period) % 2 == 0 { 0.2 } else { 0.25 };
+                point(i as u128 * 100, speed_mps, steering)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_busy_hands_reported_once_per_window() {
+        // a reversal every 300ms, over 3 per second
+        let output = run(&corrections(40., WINDOW_POINTS, 3));
+
+        match &output[..] {
+            [
+                TelemetryAnnotation::SteeringReversals {
+                    reversals,
+                    reversals_per_s,
+                    is_busy,
+                },
+            ] => {
+                assert!(*is_busy);
+                assert_eq!(*reversals, 15);
+                assert!(*reversals_per_s >= MAX_REVERSALS_PER_S);
+            }
+            _ => panic!(
+                "Expected one SteeringReversals annotation, got {:?}",
+                output
+            ),
+        }
+    }
+
+    #[test]
+    fn test_occasional_corrections_not_reported() {
+        // a reversal every 1.5 seconds
+        assert!(run(&corrections(40., WINDOW_POINTS * 3, 15)).is_empty());
+    }
+
+    #[test]
+    fn test_slow_speed_ignored() {
+        assert!(run(&corrections(5., WINDOW_POINTS * 2, 2)).is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+use super::TelemetryData;
+
+/// Steering movement (fraction of full lock) back from the furthest point in the current
+/// direction that counts as a reversal, smaller movements are noise or the wheel settling
+pub const REVERSAL_DEADBAND_PCT: f32 = 0.02;
+/// Minimum speed (m/s) for steering to count, excludes the pit lane and standing starts
+pub const REVERSAL_MIN_SPEED_MPS: f32 = 10.0;
+
+/// Counts the direction changes of the steering wheel, one input at a time.
+///
+/// The wheel has to come back by `REVERSAL_DEADBAND_PCT` from the furthest point it
+/// reached in one direction for a reversal to count, so slow drifts and sensor noise
+/// around a held angle are not reversals.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ReversalCounter {
+    /// Furthest steering input reached in the current direction
+    extreme: Option<f32>,
+    /// 1.0 or -1.0 once the wheel moved past the deadband, 0.0 before
+    direction: f32,
+}
+
+impl ReversalCounter {
+    /// Feed the next steering input, returns true when it completes a reversal.
+    pub(crate) fn push(&mut self, steering: f32) -> bool {
+        let Some(extreme) = self.extreme else {
+            self.extreme = Some(steering);
+            return false;
+        };
+        let delta = steering - extreme;
+        if self.direction == 0.0 {
+            if delta.abs() >= REVERSAL_DEADBAND_PCT {
+                self.direction = delta.signum();
+                self.extreme = Some(steering);
+            }
+            return false;
+        }
+        if delta * self.direction > 0.0 {
+            // still turning the same way, the reversal is measured from the new extreme
+            self.extreme = Some(steering);
+            return false;
+        }
+        if delta.abs() >= REVERSAL_DEADBAND_PCT {
+            self.direction = -self.direction;
+            self.extreme = Some(steering);
+            return true;
+        }
+        false
+    }
+
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Steering reversals over a lap, how busy the driver's hands were.
+///
+/// A smooth driver turns in, holds the wheel and unwinds it, with few corrections in
+/// between. Many reversals point at a nervous car or at sawing at the wheel. Only driving
+/// above `REVERSAL_MIN_SPEED_MPS` with steering data is counted, the steering is read
+/// like the live analyzer does, from the wheel angle when the sim only reports that.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SteeringReversals {
+    pub reversals: usize,
+    /// Time the reversals were counted over
+    pub duration_ms: u128,
+}
+
+impl SteeringReversals {
+    pub fn from_lap(points: &[TelemetryData], max_steering_angle: f32) -> Self {
+        let mut stats = SteeringReversals::default();
+        let mut counter = ReversalCounter::default();
+
+        for (i, point) in points.iter().enumerate() {
+            let steering = point
+                .signed_steering(max_steering_angle)
+                .filter(|_| point.speed_mps.unwrap_or(0.) > REVERSAL_MIN_SPEED_MPS);
+            let Some(steering) = steering else {
+                counter.reset();
+                continue;
+            };
+            if counter.push(steering) {
+                stats.reversals += 1;
+            }
+            // time until the next point is attributed to the current point
+            if let Some(next) = points.get(i + 1) {
+                stats.duration_ms += next.timestamp_ms.saturating_sub(point.timestamp_ms);
+            }
+        }
+
+        stats
+    }
+
+    pub fn reversals_per_s(&self) -> f32 {
+        if self.duration_ms == 0 {
+            return 0.;
+        }
+        self.reversals as f32 / (self.duration_ms as f32 / 1000.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(timestamp_ms: u128, steering_pct: f32) -> TelemetryData {
+        TelemetryData {
+            timestamp_ms,
+            steering_pct: Some(steering_pct),
+            speed_mps: Some(40.),
+            ..TelemetryData::default()
+        }
+    }
+
+    #[test]
+    fn test_smooth_corner_has_no_reversals() {
+        // turn in, hold and unwind: one direction change, from turning in to unwinding
+        let steering = [0., 0.1, 0.2, 0.3, 0.3, 0.3, 0.2, 0.1, 0.];
+        let points: Vec<_> = steering
+            .iter()
+            .enumerate()
+            .map(|(i, s)| point(i as u128 * 100, *s))
+            .collect();
+
+        let stats = SteeringReversals::from_lap(&points, 0.);
+
+        assert_eq!(stats.reversals, 1);
+        assert_eq!(stats.duration_ms, 800);
+    }
+
+    #[test]
+    fn test_sawing_counts_every_reversal() {
+        let points: Vec<_> = (0..21)
+            .map(|i| point(i * 100, if i % 2 == 0 { 0.1 } else { 0.15 }))
+            .collect();
+
+        let stats = SteeringReversals::from_lap(&points, 0.);
+
+        // the first movement sets the direction, every later one reverses it
+        assert_eq!(stats.reversals, 19);
+        assert!((stats.reversals_per_s() - 9.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_movements_inside_deadband_ignored() {
+        let points: Vec<_> = (0..20)
+            .map(|i| point(i * 100, if i % 2 == 0 { 0.2 } else { 0.21 }))
+            .collect();
+
+        assert_eq!(SteeringReversals::from_lap(&points, 0.).reversals, 0);
+    }
+
+    #[test]
+    fn test_reversals_from_steering_angle() {
+        // ACC reports the wheel angle only, 0.1 and 0.15 of a 6 rad lock
+        let points: Vec<_> = (0..21)
+            .map(|i| TelemetryData {
+                steering_pct: None,
+                steering_angle_rad: Some(if i % 2 == 0 { 0.6 } else { 0.9 }),
+                ..point(i * 100, 0.)
+            })
+            .collect();
+
+        assert_eq!(SteeringReversals::from_lap(&points, 6.).reversals, 19);
+        // without the max lock the angle cannot be read as a fraction of it
+        assert_eq!(SteeringReversals::from_lap(&points, 0.).reversals, 0);
+    }
+}
@@ -0,0 +1,436 @@
+use super::{TelemetryData, tire_temperature_analyzer::avg_tire_surface_temp};
+
+/// Minimum number of laps in a stint before a trend line is fitted
+pub const MIN_TREND_LAPS: usize = 3;
+/// Lap distance tolerance used when checking that a lap was driven from start to finish
+const LAP_COVERAGE_TOLERANCE: f32 = 0.05;
+/// Default number of recent valid laps the pace is computed over
+pub const DEFAULT_PACE_LAPS: usize = 5;
+/// Minimum number of laps for a pace, the spread needs at least two
+const MIN_PACE_LAPS: usize = 2;
+
+/// Least-squares line fitted over the laps of a stint, `x` being the lap within the stint
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Trend {
+    /// Change per lap
+    pub slope: f64,
+    /// Value at the first lap of the stint
+    pub intercept: f64,
+}
+
+impl Trend {
+    /// Fits a line through the samples, `None` with fewer than `MIN_TREND_LAPS` samples.
+    pub fn fit(samples: &[(f64, f64)]) -> Option<Self> {
+        if samples.len() < MIN_TREND_LAPS {
+            return None;
+        }
+        let n = samples.len() as f64;
+        let mean_x = samples.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = samples.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let covariance: f64 = samples
+            .iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum();
+        let variance: f64 = samples.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+        if variance == 0. {
+            return None;
+        }
+        let slope = covariance / variance;
+        Some(Self {
+            slope,
+            intercept: mean_y - slope * mean_x,
+        })
+    }
+
+    pub fn at(&self, x: f64) -> f64 {
+        self.intercept + self.slope * x
+    }
+}
+
+/// A valid lap driven as part of a stint
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StintLap {
+    /// Index of the lap within the session
+    pub lap_index: usize,
+    pub lap_time_ms: u128,
+    /// Average surface temperature of the four tires over the lap
+    pub avg_tire_temp: Option<f32>,
+}
+
+/// Consecutive valid laps between two pit stops
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stint {
+    pub laps: Vec<StintLap>,
+    /// Lap time in ms against the lap within the stint
+    pub lap_time_trend: Option<Trend>,
+    /// Average tire temperature in °C against the lap within the stint
+    pub tire_temp_trend: Option<Trend>,
+}
+
+impl Stint {
+    fn from_laps(laps: Vec<StintLap>) -> Self {
+        let lap_times: Vec<_> = laps
+            .iter()
+            .enumerate()
+            .map(|(i, lap)| (i as f64, lap.lap_time_ms as f64))
+            .collect();
+        let tire_temps: Vec<_> = laps
+            .iter()
+            .enumerate()
+            .filter_map(|(i, lap)| Some((i as f64, lap.avg_tire_temp? as f64)))
+            .collect();
+        Self {
+            lap_time_trend: Trend::fit(&lap_times),
+            tire_temp_trend: Trend::fit(&tire_temps),
+            laps,
+        }
+    }
+
+    /// Seconds lost per lap over the stint, negative when the driver got faster
+    pub fn degradation_s_per_lap(&self) -> Option<f64> {
+        self.lap_time_trend.map(|t| t.slope / 1000.)
+    }
+
+    /// Tire temperature change in °C per lap over the stint
+    pub fn tire_temp_change_per_lap(&self) -> Option<f64> {
+        self.tire_temp_trend.map(|t| t.slope)
+    }
+}
+
+/// Lap time and tire temperature trends of each stint in a session.
+///
+/// A lap that visits the pit lane ends the current stint and is not part of any
+/// stint, the same applies to laps that were not driven from start to finish, such
+/// as the out lap after a session starts on track.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StintSummary {
+    pub stints: Vec<Stint>,
+}
+
+impl StintSummary {
+    /// Builds the summary from the telemetry of every lap of a session, in order.
+    pub fn from_laps<'a>(laps: impl IntoIterator<Item = &'a [TelemetryData]>) -> Self {
+        let mut summary = StintSummary::default();
+        let mut cur_laps = Vec::new();
+        for (lap_index, points) in laps.into_iter().enumerate() {
+            if points.iter().any(|p| p.is_in_pit_lane == Some(true)) {
+                summary.push(std::mem::take(&mut cur_laps));
+                continue;
+            }
+            if let Some(lap) = stint_lap(lap_index, points) {
+                cur_laps.push(lap);
+            }
+        }
+        summary.push(cur_laps);
+        summary
+    }
+
+    /// Pace over the last `window` valid laps of the session, across stints
+    pub fn pace(&self, window: usize) -> Option<Pace> {
+        let lap_times_ms: Vec<_> = self
+            .stints
+            .iter()
+            .flat_map(|s| s.laps.iter().map(|l| l.lap_time_ms))
+            .collect();
+        Pace::from_lap_times(&lap_times_ms, window)
+    }
+
+    /// Fastest valid lap of the session
+    pub fn best_lap(&self) -> Option<&StintLap> {
+        self.stints
+            .iter()
+            .flat_map(|s| s.laps.iter())
+            .min_by_key(|l| l.lap_time_ms)
+    }
+
+    fn push(&mut self, laps: Vec<StintLap>) {
+        if !laps.is_empty() {
+            self.stints.push(Stint::from_laps(laps));
+        }
+    }
+}
+
+/// Average lap time and its spread over recent valid laps. A low standard deviation
+/// means a consistent race pace, whatever the fastest lap was.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pace {
+    /// Number of laps the pace was computed over
+    pub laps: usize,
+    pub avg_lap_time_s: f64,
+    /// Sample standard deviation of the lap times
+    pub std_dev_s: f64,
+}
+
+impl Pace {
+    /// Pace over the last `window` lap times, `None` with fewer than two laps.
+    pub fn from_lap_times(lap_times_ms: &[u128], window: usize) -> Option<Self> {
+        let recent = &lap_times_ms[lap_times_ms.len().saturating_sub(window)..];
+        if recent.len() < MIN_PACE_LAPS {
+            return None;
+        }
+        let n = recent.len() as f64;
+        let lap_times_s = recent.iter().map(|t| *t as f64 / 1000.);
+        let avg_lap_time_s = lap_times_s.clone().sum::<f64>() / n;
+        let variance = lap_times_s
+            .map(|t| (t - avg_lap_time_s).powi(2))
+            .sum::<f64>()
+            / (n - 1.);
+        Some(Self {
+            laps: recent.len(),
+            avg_lap_time_s,
+            std_dev_s: variance.sqrt(),
+        })
+    }
+}
+
+/// Lap being timed by the [`LapTimer`]
+#[derive(Debug)]
+struct TimedLap {
+    lap_number: u32,
+    start_ms: u128,
+    /// Last lap time the sim reported when the lap started
+    start_last_lap_time_s: Option<f32>,
+    /// Started at the line and has not been in the pit lane so far
+    is_valid: bool,
+}
+
+/// Times the valid laps of a live session for the pace readout.
+///
+/// A lap runs from one change of lap number to the next. Laps that visit the pit lane
+/// and the lap the stream joined partway through are left out, as in [`StintSummary`].
+/// The lap time is the one the sim reports once the lap is done, and the time between
+/// the two lap changes when the sim does not report one.
+#[derive(Debug, Default)]
+pub(crate) struct LapTimer {
+    lap_times_ms: Vec<u128>,
+    cur_lap: Option<TimedLap>,
+}
+
+impl LapTimer {
+    pub(crate) fn push(&mut self, point: &TelemetryData) {
+        let Some(lap_number) = point.lap_number else {
+            return;
+        };
+        let is_in_pit_lane = point.is_in_pit_lane == Some(true);
+        match self.cur_lap.as_mut() {
+            Some(lap) if lap.lap_number == lap_number => lap.is_valid &= !is_in_pit_lane,
+            Some(lap) => {
+                // a reported time still matching the one at the start of the lap has
+                // not been updated for this lap yet
+                let reported_ms = point
+                    .last_lap_time_s
+                    .filter(|t| *t > 0. && Some(*t) != lap.start_last_lap_time_s)
+                    .map(|t| (f64::from(t) * 1000.).round() as u128);
+                if lap.is_valid
+                    && lap_number == lap.lap_number + 1
+                    && let Some(lap_time_ms) =
+                        reported_ms.or_else(|| point.timestamp_ms.checked_sub(lap.start_ms))
+                    && lap_time_ms > 0
+                {
+                    self.lap_times_ms.push(lap_time_ms);
+                }
+                self.cur_lap = Some(TimedLap {
+                    lap_number,
+                    start_ms: point.timestamp_ms,
+                    start_last_lap_time_s: point.last_lap_time_s,
+                    is_valid: !is_in_pit_lane,
+                });
+            }
+            None => {
+                self.cur_lap = Some(TimedLap {
+                    lap_number,
+                    start_ms: point.timestamp_ms,
+                    start_last_lap_time_s: point.last_lap_time_s,
+                    is_valid: !is_in_pit_lane
+                        && point
+                            .lap_distance_pct
+                            .is_some_and(|pct| pct <= LAP_COVERAGE_TOLERANCE),
+                });
+            }
+        }
+    }
+
+    /// Pace over the last `window` valid laps timed so far
+    pub(crate) fn pace(&self, window: usize) -> Option<Pace> {
+        Pace::from_lap_times(&self.lap_times_ms, window)
+    }
+}
+
+/// Returns the lap time and tire temperature of a lap, if it was driven in full.
+fn stint_lap(lap_index: usize, points: &[TelemetryData]) -> Option<StintLap> {
+    let first = points.first()?;
+    let last = points.last()?;
+    if first
+        .lap_distance_pct
+        .is_some_and(|pct| pct > LAP_COVERAGE_TOLERANCE)
+        || last
+            .lap_distance_pct
+            .is_some_and(|pct| pct < 1. - LAP_COVERAGE_TOLERANCE)
+    {
+        return None;
+    }
+    let lap_time_ms = last.timestamp_ms.checked_sub(first.timestamp_ms)?;
+    if lap_time_ms == 0 {
+        return None;
+    }
+
+    let temps: Vec<f32> = points.iter().filter_map(avg_tire_surface_temp).collect();
+    let avg_tire_temp = (!temps.is_empty()).then(|| temps.iter().sum::<f32>() / temps.len() as f32);
+    Some(StintLap {
+        lap_index,
+        lap_time_ms,
+        avg_tire_temp,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::TireInfo;
+
+    /// Builds a full lap taking `lap_time_ms`, with every tire surface at `tire_temp`
+    fn lap(lap_time_ms: u128, tire_temp: f32, in_pit_lane: bool) -> Vec<TelemetryData> {
+        let tire = TireInfo {
+            left_carcass_temp: tire_temp,
+            middle_carcass_temp: tire_temp,
+            right_carcass_temp: tire_temp,
+            left_surface_temp: tire_temp,
+            middle_surface_temp: tire_temp,
+            right_surface_temp: tire_temp,
+        };
+        (0..=10)
+            .map(|step| TelemetryData {
+                timestamp_ms: lap_time_ms * step / 10,
+                lap_distance_pct: Some(step as f32 / 10.),
+                is_in_pit_lane: Some(in_pit_lane),
+                lf_tire_info: Some(tire.clone()),
+                rf_tire_info: Some(tire.clone()),
+                lr_tire_info: Some(tire.clone()),
+                rr_tire_info: Some(tire.clone()),
+                ..TelemetryData::default()
+            })
+            .collect()
+    }
+
+    fn summary(laps: &[Vec<TelemetryData>]) -> StintSummary {
+        StintSummary::from_laps(laps.iter().map(Vec::as_slice))
+    }
+
+    #[test]
+    fn test_degradation_trend() {
+        let laps = [
+            lap(90_000, 85., false),
+            lap(90_200, 87., false),
+            lap(90_400, 89., false),
+            lap(90_600, 91., false),
+        ];
+        let summary = summary(&laps);
+
+        assert_eq!(summary.stints.len(), 1);
+        let stint = &summary.stints[0];
+        assert_eq!(stint.laps.len(), 4);
+        assert!((stint.degradation_s_per_lap().unwrap() - 0.2).abs() < 1e-9);
+        assert!((stint.tire_temp_change_per_lap().unwrap() - 2.).abs() < 1e-6);
+        assert!((stint.lap_time_trend.unwrap().at(0.) - 90_000.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_pit_stop_splits_stints() {
+        let laps = [
+            lap(90_000, 85., false),
+            lap(91_000, 90., false),
+            lap(120_000, 70., true),
+            lap(89_000, 80., false),
+        ];
+        let summary = summary(&laps);
+
+        assert_eq!(summary.stints.len(), 2);
+        assert_eq!(summary.stints[0].laps.len(), 2);
+        assert_eq!(summary.stints[1].laps[0].lap_index, 3);
+        // not enough laps for a trend
+        assert_eq!(summary.stints[0].degradation_s_per_lap(), None);
+        // the pit lap is not a valid lap
+        assert_eq!(summary.best_lap().unwrap().lap_index, 3);
+    }
+
+    #[test]
+    fn test_pace_over_recent_laps() {
+        let pace = Pace::from_lap_times(&[95_000, 92_000, 92_400, 92_200], 3).unwrap();
+        assert_eq!(pace.laps, 3);
+        assert!((pace.avg_lap_time_s - 92.2).abs() < 1e-9);
+        assert!((pace.std_dev_s - 0.2).abs() < 1e-9);
+
+        assert_eq!(Pace::from_lap_times(&[92_000], 5), None);
+
+        // the pit lap is left out
+        let laps = [
+            lap(90_000, 85., false),
+            lap(120_000, 70., true),
+            lap(91_000, 85., false),
+        ];
+        assert!((summary(&laps).pace(5).unwrap().avg_lap_time_s - 90.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lap_timer_skips_out_and_pit_laps() {
+        let mut timer = LapTimer::default();
+        // joins halfway through lap 1, then drives laps 2 and 3 and pits on lap 4
+        let points = [
+            (0, 1, 0.5, false),
+            (45_000, 2, 0., false),
+            (135_000, 3, 0., false),
+            (226_000, 4, 0., false),
+            (300_000, 4, 0.9, true),
+            (330_000, 5, 0., true),
+        ];
+        for (timestamp_ms, lap_number, lap_distance_pct, in_pit_lane) in points {
+            timer.push(&TelemetryData {
+                timestamp_ms,
+                lap_number: Some(lap_number),
+                lap_distance_pct: Some(lap_distance_pct),
+                is_in_pit_lane: Some(in_pit_lane),
+                ..TelemetryData::default()
+            });
+        }
+
+        assert_eq!(timer.lap_times_ms, vec![90_000, 91_000]);
+        let pace = timer.pace(DEFAULT_PACE_LAPS).unwrap();
+        assert!((pace.avg_lap_time_s - 90.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lap_timer_prefers_reported_lap_times() {
+        let mut timer = LapTimer::default();
+        // the sim reports 89.5s for lap 1 as lap 2 starts, lap 2 ends before the sim
+        // updates its lap time so it is timed from the points
+        let points = [
+            (0, 1, None),
+            (90_000, 2, Some(89.5)),
+            (181_000, 3, Some(89.5)),
+            (183_000, 3, Some(91.2)),
+        ];
+        for (timestamp_ms, lap_number, last_lap_time_s) in points {
+            timer.push(&TelemetryData {
+                timestamp_ms,
+                lap_number: Some(lap_number),
+                lap_distance_pct: Some(0.),
+                last_lap_time_s,
+                ..TelemetryData::default()
+            });
+        }
+
+        assert_eq!(timer.lap_times_ms, vec![89_500, 91_000]);
+    }
+
+    #[test]
+    fn test_incomplete_laps_are_skipped() {
+        let mut out_lap = lap(60_000, 60., false);
+        out_lap.drain(..5);
+        let summary = summary(&[out_lap, lap(90_000, 85., false)]);
+
+        assert_eq!(summary.stints.len(), 1);
+        assert_eq!(summary.stints[0].laps.len(), 1);
+        assert_eq!(summary.stints[0].laps[0].lap_index, 1);
+        assert_eq!(summary.stints[0].laps[0].avg_tire_temp, Some(85.));
+    }
+}
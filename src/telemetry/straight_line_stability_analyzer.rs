@@ -0,0 +1,227 @@
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{SessionInfo, TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+
+/// Steering input (fraction of full lock) below which the car is on a straight, matches
+/// the Straight corner phase of the setup assistant
+const MAX_STRAIGHT_STEERING_PCT: f32 = 0.05;
+/// Speed the car has to hold for the whole window, keeps the pit lane and slow sections out
+const MIN_SPEED_MPS: f32 = 45.0;
+/// Points in a window, 2 seconds at the collector refresh rate
+const WINDOW_POINTS: usize = 20;
+/// Steering movement (fraction of full lock) between two points that counts as a correction
+const MIN_CORRECTION_PCT: f32 = 0.005;
+/// Direction changes within a window that make the corrections high-frequency
+const MIN_CORRECTIONS: usize = 6;
+/// Steering standard deviation within a window that rules out sensor noise
+const MIN_STEERING_STD_DEV: f32 = 0.008;
+
+/// Detects a car that needs constant small steering corrections on the straights.
+///
+/// A stable car runs straight with the wheel held still, while a car with too little
+/// rear downforce or bump steer keeps wandering and the driver saws at the wheel to
+/// hold the line. Windows of straight-line running at high speed are checked for
+/// steering that changes direction often, and each unstable window is reported once.
+pub(crate) struct StraightLineStabilityAnalyzer {
+    steering: Vec<f32>,
+    min_speed_mps: f32,
+}
+
+impl StraightLineStabilityAnalyzer {
+    pub(crate) fn new() -> Self {
+        Self {
+            steering: Vec::with_capacity(WINDOW_POINTS),
+            min_speed_mps: f32::MAX,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.steering.clear();
+        self.min_speed_mps = f32::MAX;
+    }
+}
+
+/// Number of direction changes among the steering movements larger than a correction
+fn count_corrections(steering: &[f32]) -> usize {
+    let mut corrections = 0;
+    let mut prev_direction = 0.0;
+    for pair in steering.windows(2) {
+        let delta = pair[1] - pair[0];
+        if delta.abs() < MIN_CORRECTION_PCT {
+            continue;
+        }
+        let direction = delta.signum();
+        if prev_direction != 0.0 && direction != prev_direction {
+            corrections += 1;
+        }
+        prev_direction = direction;
+    }
+    corrections
+}
+
+fn std_dev(values: &[f32]) -> f32 {
+    let mean = values.iter().sum::<f32>() / values.len() as f32;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / values.len() as f32;
+    variance.sqrt()
+}
+
+impl TelemetryAnalyzer for StraightLineStabilityAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        session_info: &SessionInfo,
+    ) -> Vec<TelemetryAnnotation> {
+        let mut output = Vec::new();
+
+        let speed_mps = telemetry.speed_mps.unwrap_or(0.0);
+        let Some(steering) = telemetry.signed_steering(session_info.max_steering_angle) else {
+            self.reset();
+            return output;
+        };
+        if !is_telemetry_point_analyzable(telemetry)
+            || speed_mps < MIN_SPEED_MPS
+            || steering.abs() >= MAX_STRAIGHT_STEERING_PCT
+        {
+            self.reset();
+            return output;
+        }
+
+        self.steering.push(steering);
+        self.min_speed_mps = self.min_speed_mps.min(speed_mps);
+        if self.steering.len() < WINDOW_POINTS {
+            return output;
+        }
+
+        let corrections = count_corrections(&self.steering);
+        let steering_std_dev = std_dev(&self.steering);
+        if corrections >= MIN_CORRECTIONS && steering_std_dev >= MIN_STEERING_STD_DEV {
+            output.push(TelemetryAnnotation::StraightLineInstability {
+                steering_std_dev,
+                corrections,
+                min_speed_mps: self.min_speed_mps,
+                is_unstable: true,
+            });
+        }
+        self.reset();
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn straight_point(speed_mps: f32, steering_pct: f32) -> TelemetryData {
+        TelemetryData {
+            speed_mps: Some(speed_mps),
+            steering_pct: Some(steering_pct),
+            throttle: Some(1.0),
+            ..TelemetryData::default()
+        }
+    }
+
+    fn run(points: &[TelemetryData]) -> Vec<TelemetryAnnotation> {
+        let mut analyzer = StraightLineStabilityAnalyzer::new();
+        let session_info = SessionInfo::default();
+        points
+            .iter()
+            .flat_map(|p| analyzer.analyze(p, &session_info))
+            .collect()
+    }
+
+    /// Steering sawing 2% of lock either side of center
+    fn sawing(speed_mps: f32, points: usize) -> Vec<TelemetryData> {
+        (0..points)
+            .map(|i| straight_point(speed_mps, if i % 2 == 0 { 0.02 } else { -0.02 }))
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_constant_corrections() {
+        let output = run(&sawing(60.0, WINDOW_POINTS));
+
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::StraightLineInstability {
+                steering_std_dev,
+                corrections,
+                min_speed_mps,
+                is_unstable,
+            } => {
+                assert!((*steering_std_dev - 0.02).abs() < 1e-5);
+                assert_eq!(*corrections, WINDOW_POINTS - 2);
+                assert_eq!(*min_speed_mps, 60.0);
+                assert!(*is_unstable);
+            }
+            _ => panic!("Expected StraightLineInstability annotation"),
+        }
+    }
+
+    #[test]
+    fn test_ignores_steady_and_drifting_steering() {
+        let steady: Vec<_> = (0..WINDOW_POINTS)
+            .map(|_| straight_point(60.0, 0.01))
+            .collect();
+        assert!(run(&steady).is_empty());
+
+        // Slowly feeding in lock for a kink is a single movement, not a correction
+        let drifting: Vec<_> = (0..WINDOW_POINTS)
+            .map(|i| straight_point(60.0, i as f32 * 0.002))
+            .collect();
+        assert!(run(&drifting).is_empty());
+    }
+
+    #[test]
+    fn test_ignores_noise_below_correction_size() {
+        let noise: Vec<_> = (0..WINDOW_POINTS)
+            .map(|i| straight_point(60.0, if i % 2 == 0 { 0.002 } else { -0.002 }))
+            .collect();
+        assert!(run(&noise).is_empty());
+    }
+
+    #[test]
+    fn test_requires_sustained_high_speed() {
+        assert!(run(&sawing(20.0, WINDOW_POINTS)).is_empty());
+
+        // A slow point in the middle of the window starts it over
+        let mut points = sawing(60.0, WINDOW_POINTS);
+        points[WINDOW_POINTS / 2].speed_mps = Some(20.0);
+        assert!(run(&points).is_empty());
+    }
+
+    #[test]
+    fn test_ignores_corners_and_pit_limiter() {
+        let mut points = sawing(60.0, WINDOW_POINTS);
+        points[5].steering_pct = Some(0.3);
+        assert!(run(&points).is_empty());
+
+        let limited: Vec<_> = sawing(60.0, WINDOW_POINTS)
+            .into_iter()
+            .map(|p| TelemetryData {
+                is_pit_limiter_engaged: Some(true),
+                ..p
+            })
+            .collect();
+        assert!(run(&limited).is_empty());
+    }
+
+    #[test]
+    fn test_uses_steering_angle_without_steering_pct() {
+        let mut analyzer = StraightLineStabilityAnalyzer::new();
+        let session_info = SessionInfo {
+            max_steering_angle: 4.0,
+            ..SessionInfo::default()
+        };
+        let output: Vec<_> = sawing(60.0, WINDOW_POINTS)
+            .into_iter()
+            .map(|p| TelemetryData {
+                steering_angle_rad: p.steering_pct.map(|pct| pct * 4.0),
+                steering_pct: None,
+                ..p
+            })
+            .flat_map(|p| analyzer.analyze(&p, &session_info))
+            .collect();
+        assert_eq!(output.len(), 1);
+    }
+}
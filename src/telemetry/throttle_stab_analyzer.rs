@@ -0,0 +1,155 @@
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{SessionInfo, TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+
+/// Throttle application that starts tracking a corner exit
+const MIN_THROTTLE_PCT: f32 = 0.1;
+/// Steering input (as a fraction of full lock) that keeps the car in the corner
+const MIN_STEERING_PCT: f32 = 0.15;
+/// Brake application that ends the exit
+const MIN_BRAKE_PCT: f32 = 0.1;
+/// Throttle movement back from the furthest point in the current direction that counts
+/// as a reversal, smaller movements are the driver feeding the throttle in
+const REVERSAL_DEADBAND_PCT: f32 = 0.1;
+/// Throttle reversals within one exit that count as stabbing: on, off, on and off again.
+/// A single lift and pick-up is the exit throttle lift analyzer's business.
+pub(crate) const MIN_STAB_REVERSALS: usize = 3;
+
+struct Exit {
+    /// Furthest throttle reached in the current direction
+    extreme: f32,
+    /// 1.0 while the throttle goes in, -1.0 while it comes out
+    direction: f32,
+    reversals: usize,
+}
+
+/// Detects the throttle being stabbed on and off on corner exit.
+///
+/// Each on-off-on of the throttle shifts the weight back and forth between the axles and
+/// asks the rear tires for traction they were just relieved of, which unsettles the car
+/// and wastes the exit. The exit starts when the throttle is picked up with the steering
+/// still wound on, and ends on the brakes or once the steering unwinds. Throttle
+/// direction changes larger than `REVERSAL_DEADBAND_PCT` are counted over the exit, and
+/// every reversal from `MIN_STAB_REVERSALS` on is reported with the count so far.
+///
+/// Unlike the smoothness score of a lap, this only looks at the throttle on exit, where
+/// the driver should be squeezing it in steadily.
+pub(crate) struct ThrottleStabAnalyzer {
+    exit: Option<Exit>,
+}
+
+impl ThrottleStabAnalyzer {
+    pub(crate) fn new() -> Self {
+        Self { exit: None }
+    }
+}
+
+impl TelemetryAnalyzer for ThrottleStabAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        _session_info: &SessionInfo,
+    ) -> Vec<TelemetryAnnotation> {
+        let mut output = Vec::new();
+
+        let throttle = telemetry.throttle.unwrap_or(0.0);
+        if !is_telemetry_point_analyzable(telemetry)
+            || telemetry.brake.unwrap_or(0.0) >= MIN_BRAKE_PCT
+            || telemetry.steering_pct.unwrap_or(0.0).abs() < MIN_STEERING_PCT
+        {
+            self.exit = None;
+            return output;
+        }
+
+        let Some(exit) = self.exit.as_mut() else {
+            if throttle > MIN_THROTTLE_PCT {
+                self.exit = Some(Exit {
+                    extreme: throttle,
+                    direction: 1.0,
+                    reversals: 0,
+                });
+            }
+            return output;
+        };
+
+        let delta = throttle - exit.extreme;
+        if delta * exit.direction >= 0.0 {
+            // still going the same way, the reversal is measured from the new extreme
+            exit.extreme = throttle;
+        } else if delta.abs() >= REVERSAL_DEADBAND_PCT {
+            exit.direction = -exit.direction;
+            exit.extreme = throttle;
+            exit.reversals += 1;
+            if exit.reversals >= MIN_STAB_REVERSALS {
+                output.push(TelemetryAnnotation::ThrottleStab {
+                    reversals: exit.reversals,
+                    is_stabbing: true,
+                });
+            }
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(throttle: &[f32], steering_pct: &[f32]) -> Vec<TelemetryAnnotation> {
+        let mut analyzer = ThrottleStabAnalyzer::new();
+        let session_info = SessionInfo::default();
+        throttle
+            .iter()
+            .zip(steering_pct)
+            .enumerate()
+            .flat_map(|(i, (throttle, steering_pct))| {
+                analyzer.analyze(
+                    &TelemetryData {
+                        timestamp_ms: i as u128 * 100,
+                        speed_mps: Some(30.0),
+                        brake: Some(0.0),
+                        throttle: Some(*throttle),
+                        steering_pct: Some(*steering_pct),
+                        ..TelemetryData::default()
+                    },
+                    &session_info,
+                )
+            })
+            .collect()
+    }
+
+    fn reversals(output: &[TelemetryAnnotation]) -> Vec<usize> {
+        output
+            .iter()
+            .filter_map(|a| match a {
+                TelemetryAnnotation::ThrottleStab { reversals, .. } => Some(*reversals),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_stabbing_on_exit() {
+        // on, off, on, off, on
+        let throttle = [0.2, 0.6, 0.1, 0.7, 0.2, 0.8, 0.9];
+        let output = run(&throttle, &[0.3; 7]);
+        assert_eq!(reversals(&output), vec![3, 4]);
+        assert!(output.iter().all(|a| a.is_active()));
+    }
+
+    #[test]
+    fn test_ignores_single_lift_and_smooth_application() {
+        // one lift and pick-up, then small wobbles while feeding the throttle in
+        let throttle = [0.2, 0.6, 0.3, 0.5, 0.55, 0.52, 0.7, 0.68, 0.9, 1.0];
+        assert!(run(&throttle, &[0.3; 10]).is_empty());
+    }
+
+    #[test]
+    fn test_reversals_reset_once_steering_unwinds() {
+        // two reversals in one exit and two in the next, after a straight
+        let throttle = [0.2, 0.6, 0.2, 0.6, 1.0, 0.2, 0.6, 0.2, 0.6];
+        let steering = [0.3, 0.3, 0.3, 0.3, 0.0, 0.3, 0.3, 0.3, 0.3];
+        assert!(run(&throttle, &steering).is_empty());
+    }
+}
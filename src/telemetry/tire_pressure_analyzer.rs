@@ -0,0 +1,255 @@
+use simple_moving_average::{SMA, SumTreeSMA};
+
+use crate::telemetry::is_telemetry_point_analyzable;
+
+use super::{TelemetryAnalyzer, TelemetryAnnotation, TelemetryData, TireInfo};
+
+/// Middle minus edges surface temperature (Celsius) below which the edges carry the
+/// load, meaning the tire runs too little pressure
+pub(crate) const MIN_MIDDLE_EDGE_SPREAD: f32 = -3.0;
+/// Middle minus edges surface temperature (Celsius) above which the middle of the tread
+/// carries the load, meaning the tire runs too much pressure
+pub(crate) const MAX_MIDDLE_EDGE_SPREAD: f32 = 3.0;
+
+/// Sample every N telemetry points, temperatures change slowly
+const SAMPLE_INTERVAL: usize = 60;
+
+/// Number of samples averaged before a spread is evaluated
+const WINDOW_SIZE: usize = 30;
+
+/// Pressure of a tire as read from the temperature across its tread
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum TirePressure {
+    Low,
+    Optimal,
+    High,
+}
+
+impl TirePressure {
+    /// Classify the spread between the middle of the tread and the average of its edges.
+    pub(crate) fn from_spread(spread: f32) -> Self {
+        if spread < MIN_MIDDLE_EDGE_SPREAD {
+            TirePressure::Low
+        } else if spread > MAX_MIDDLE_EDGE_SPREAD {
+            TirePressure::High
+        } else {
+            TirePressure::Optimal
+        }
+    }
+}
+
+impl std::fmt::Display for TirePressure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TirePressure::Low => write!(f, "low"),
+            TirePressure::Optimal => write!(f, "optimal"),
+            TirePressure::High => write!(f, "high"),
+        }
+    }
+}
+
+/// Spread between the middle of the tread and the average of its edges, positive when
+/// the middle is hotter. Averaging the edges keeps the camber spread out of it.
+fn middle_edge_spread(tire: &TireInfo) -> f32 {
+    tire.middle_surface_temp - (tire.left_surface_temp + tire.right_surface_temp) / 2.
+}
+
+/// Detects tire pressures out of their window from the temperature profile of the tread.
+///
+/// An over-inflated tire bulges and runs on the middle of its tread, which gets hotter
+/// than the edges. An under-inflated tire sags and runs on its edges, which get hotter
+/// than the middle. Works without a pressure channel, so it covers every sim reporting
+/// three surface temperatures per tire.
+pub(crate) struct TirePressureAnalyzer {
+    lf_spread: SumTreeSMA<f32, f32, WINDOW_SIZE>,
+    rf_spread: SumTreeSMA<f32, f32, WINDOW_SIZE>,
+    lr_spread: SumTreeSMA<f32, f32, WINDOW_SIZE>,
+    rr_spread: SumTreeSMA<f32, f32, WINDOW_SIZE>,
+    sample_counter: usize,
+}
+
+impl TirePressureAnalyzer {
+    pub(crate) fn new() -> Self {
+        Self {
+            lf_spread: SumTreeSMA::new(),
+            rf_spread: SumTreeSMA::new(),
+            lr_spread: SumTreeSMA::new(),
+            rr_spread: SumTreeSMA::new(),
+            sample_counter: 0,
+        }
+    }
+}
+
+impl TelemetryAnalyzer for TirePressureAnalyzer {
+    fn analyze(
+        &mut self,
+        telemetry: &TelemetryData,
+        _session_info: &super::SessionInfo,
+    ) -> Vec<super::TelemetryAnnotation> {
+        let mut output = Vec::new();
+
+        if !is_telemetry_point_analyzable(telemetry) {
+            return output;
+        }
+
+        self.sample_counter += 1;
+        if !self.sample_counter.is_multiple_of(SAMPLE_INTERVAL) {
+            return output;
+        }
+
+        let (Some(lf), Some(rf), Some(lr), Some(rr)) = (
+            telemetry.lf_tire_info.as_ref(),
+            telemetry.rf_tire_info.as_ref(),
+            telemetry.lr_tire_info.as_ref(),
+            telemetry.rr_tire_info.as_ref(),
+        ) else {
+            return output;
+        };
+
+        self.lf_spread.add_sample(middle_edge_spread(lf));
+        self.rf_spread.add_sample(middle_edge_spread(rf));
+        self.lr_spread.add_sample(middle_edge_spread(lr));
+        self.rr_spread.add_sample(middle_edge_spread(rr));
+
+        if self.lf_spread.get_num_samples() < WINDOW_SIZE {
+            return output;
+        }
+
+        let lf_spread = self.lf_spread.get_average();
+        let rf_spread = self.rf_spread.get_average();
+        let lr_spread = self.lr_spread.get_average();
+        let rr_spread = self.rr_spread.get_average();
+
+        let is_imbalanced = [lf_spread, rf_spread, lr_spread, rr_spread]
+            .into_iter()
+            .any(|spread| TirePressure::from_spread(spread) != TirePressure::Optimal);
+
+        if is_imbalanced {
+            output.push(TelemetryAnnotation::TirePressureImbalance {
+                lf_spread,
+                rf_spread,
+                lr_spread,
+                rr_spread,
+                is_imbalanced,
+            });
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::SessionInfo;
+
+    fn tire(left: f32, middle: f32, right: f32) -> TireInfo {
+        TireInfo {
+            left_carcass_temp: middle,
+            middle_carcass_temp: middle,
+            right_carcass_temp: middle,
+            left_surface_temp: left,
+            middle_surface_temp: middle,
+            right_surface_temp: right,
+        }
+    }
+
+    fn run(
+        analyzer: &mut TirePressureAnalyzer,
+        telemetry: &TelemetryData,
+    ) -> Vec<TelemetryAnnotation> {
+        let session_info = SessionInfo::default();
+        let mut output = Vec::new();
+        for _ in 0..SAMPLE_INTERVAL * WINDOW_SIZE {
+            output = analyzer.analyze(telemetry, &session_info);
+        }
+        output
+    }
+
+    fn telemetry(lf: TireInfo, rf: TireInfo, lr: TireInfo, rr: TireInfo) -> TelemetryData {
+        TelemetryData {
+            speed_mps: Some(40.),
+            lf_tire_info: Some(lf),
+            rf_tire_info: Some(rf),
+            lr_tire_info: Some(lr),
+            rr_tire_info: Some(rr),
+            ..TelemetryData::default()
+        }
+    }
+
+    #[test]
+    fn test_middle_hot_is_over_pressure() {
+        let mut analyzer = TirePressureAnalyzer::new();
+        // Front middles 8C hotter than their edges, rears even across the tread
+        let telemetry = telemetry(
+            tire(84., 94., 88.),
+            tire(88., 94., 84.),
+            tire(84., 86., 88.),
+            tire(88., 86., 84.),
+        );
+
+        let output = run(&mut analyzer, &telemetry);
+
+        assert_eq!(output.len(), 1);
+        match &output[0] {
+            TelemetryAnnotation::TirePressureImbalance {
+                lf_spread,
+                rf_spread,
+                lr_spread,
+                rr_spread,
+                is_imbalanced,
+            } => {
+                assert!(*is_imbalanced);
+                assert_eq!(*lf_spread, 8.);
+                assert_eq!(*rf_spread, 8.);
+                assert_eq!(TirePressure::from_spread(*lf_spread), TirePressure::High);
+                assert_eq!(TirePressure::from_spread(*lr_spread), TirePressure::Optimal);
+                assert_eq!(TirePressure::from_spread(*rr_spread), TirePressure::Optimal);
+            }
+            _ => panic!("Expected TirePressureImbalance annotation"),
+        }
+    }
+
+    #[test]
+    fn test_edges_hot_is_under_pressure() {
+        let mut analyzer = TirePressureAnalyzer::new();
+        let telemetry = telemetry(
+            tire(84., 86., 88.),
+            tire(88., 86., 84.),
+            tire(92., 82., 90.),
+            tire(90., 82., 92.),
+        );
+
+        let output = run(&mut analyzer, &telemetry);
+
+        match &output[..] {
+            [TelemetryAnnotation::TirePressureImbalance { rr_spread, .. }] => {
+                assert_eq!(TirePressure::from_spread(*rr_spread), TirePressure::Low);
+            }
+            _ => panic!("Expected TirePressureImbalance annotation"),
+        }
+    }
+
+    #[test]
+    fn test_camber_spread_is_not_a_pressure_issue() {
+        let mut analyzer = TirePressureAnalyzer::new();
+        // inner edges hotter from camber, the middle sits between the edges
+        let even = telemetry(
+            tire(80., 85., 90.),
+            tire(90., 85., 80.),
+            tire(80., 85., 90.),
+            tire(90., 85., 80.),
+        );
+        assert!(run(&mut analyzer, &even).is_empty());
+    }
+
+    #[test]
+    fn test_missing_tire_data_ignored() {
+        let mut analyzer = TirePressureAnalyzer::new();
+        let telemetry = TelemetryData {
+            speed_mps: Some(40.),
+            ..TelemetryData::default()
+        };
+        assert!(run(&mut analyzer, &telemetry).is_empty());
+    }
+}
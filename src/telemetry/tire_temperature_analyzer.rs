@@ -2,13 +2,18 @@ use std::collections::VecDeque;
 
 use crate::telemetry::is_telemetry_point_analyzable;
 
-use super::{TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
+use super::{TelemetryAnalyzer, TelemetryAnnotation, TelemetryData, TireCompound};
 
 /// Optimal tire temperature range (in Celsius)
 /// Based on typical GT3 tire operating temperatures
 const OPTIMAL_TEMP_MIN: f32 = 80.0;
 const OPTIMAL_TEMP_MAX: f32 = 95.0;
 
+/// Optimal temperature range (in Celsius) of wet tires, used when the sim reports
+/// them fitted. Wet compounds are soft and overheat well below the dry window
+const WET_OPTIMAL_TEMP_MIN: f32 = 40.0;
+const WET_OPTIMAL_TEMP_MAX: f32 = 65.0;
+
 /// Duration to track temperature history (in seconds)
 const HISTORY_DURATION_S: usize = 60;
 
@@ -18,6 +23,35 @@ const MIN_SAMPLES: usize = 10;
 /// Telemetry sample rate assumption (Hz)
 const SAMPLE_RATE_HZ: f32 = 60.0;
 
+/// Average surface temperature across all four tires, `None` unless every tire
+/// reports temperatures.
+///
+/// Surface temps are used as they're more representative of grip levels.
+pub(crate) fn avg_tire_surface_temp(telemetry: &TelemetryData) -> Option<f32> {
+    let lf = telemetry.lf_tire_info.as_ref()?;
+    let rf = telemetry.rf_tire_info.as_ref()?;
+    let lr = telemetry.lr_tire_info.as_ref()?;
+    let rr = telemetry.rr_tire_info.as_ref()?;
+
+    let temps = [
+        lf.left_surface_temp,
+        lf.middle_surface_temp,
+        lf.right_surface_temp,
+        rf.left_surface_temp,
+        rf.middle_surface_temp,
+        rf.right_surface_temp,
+        lr.left_surface_temp,
+        lr.middle_surface_temp,
+        lr.right_surface_temp,
+        rr.left_surface_temp,
+        rr.middle_surface_temp,
+        rr.right_surface_temp,
+    ];
+
+    let sum: f32 = temps.iter().sum();
+    Some(sum / temps.len() as f32)
+}
+
 #[derive(Clone, Debug)]
 struct TireTemperatureSnapshot {
     timestamp_ms: u128,
@@ -27,6 +61,9 @@ struct TireTemperatureSnapshot {
 pub(crate) struct TireTemperatureAnalyzer {
     temp_history: VecDeque<TireTemperatureSnapshot>,
     history_duration_s: usize,
+    /// Window used with dry tires or when the compound is unknown
+    dry_temp_range: (f32, f32),
+    /// Window of the compound currently fitted
     optimal_temp_range: (f32, f32),
     sample_counter: usize,
     sample_interval: usize,
@@ -45,38 +82,26 @@ impl TireTemperatureAnalyzer {
         Self {
             temp_history: VecDeque::new(),
             history_duration_s,
+            dry_temp_range: optimal_temp_range,
             optimal_temp_range,
             sample_counter: 0,
             sample_interval,
         }
     }
 
-    /// Calculate average temperature across all four tires
-    fn calculate_avg_tire_temp(&self, telemetry: &TelemetryData) -> Option<f32> {
-        let lf = telemetry.lf_tire_info.as_ref()?;
-        let rf = telemetry.rf_tire_info.as_ref()?;
-        let lr = telemetry.lr_tire_info.as_ref()?;
-        let rr = telemetry.rr_tire_info.as_ref()?;
-
-        // Average surface temperatures across all tires
-        // Using surface temps as they're more representative of grip levels
-        let temps = [
-            lf.left_surface_temp,
-            lf.middle_surface_temp,
-            lf.right_surface_temp,
-            rf.left_surface_temp,
-            rf.middle_surface_temp,
-            rf.right_surface_temp,
-            lr.left_surface_temp,
-            lr.middle_surface_temp,
-            lr.right_surface_temp,
-            rr.left_surface_temp,
-            rr.middle_surface_temp,
-            rr.right_surface_temp,
-        ];
-
-        let sum: f32 = temps.iter().sum();
-        Some(sum / temps.len() as f32)
+    /// Switch to the optimal window of the fitted compound.
+    ///
+    /// Samples collected against the other window no longer apply, so the history
+    /// starts over when the window changes.
+    fn select_compound(&mut self, tire_compound: TireCompound) {
+        let optimal_temp_range = match tire_compound {
+            TireCompound::Wet => (WET_OPTIMAL_TEMP_MIN, WET_OPTIMAL_TEMP_MAX),
+            TireCompound::Dry | TireCompound::Unknown => self.dry_temp_range,
+        };
+        if optimal_temp_range != self.optimal_temp_range {
+            self.optimal_temp_range = optimal_temp_range;
+            self.temp_history.clear();
+        }
     }
 
     /// Check if sustained overheating is occurring
@@ -128,10 +153,12 @@ impl TelemetryAnalyzer for TireTemperatureAnalyzer {
     fn analyze(
         &mut self,
         telemetry: &TelemetryData,
-        _session_info: &super::SessionInfo,
+        session_info: &super::SessionInfo,
     ) -> Vec<super::TelemetryAnnotation> {
         let mut output = Vec::new();
 
+        self.select_compound(session_info.tire_compound);
+
         // Skip analysis if doesn't meet requirements
         if !is_telemetry_point_analyzable(telemetry) {
             return output;
@@ -146,7 +173,7 @@ impl TelemetryAnalyzer for TireTemperatureAnalyzer {
         }
 
         // Calculate average tire temperature
-        let avg_temp = match self.calculate_avg_tire_temp(telemetry) {
+        let avg_temp = match avg_tire_surface_temp(telemetry) {
             Some(temp) => temp,
             None => return output, // No tire data available
         };
@@ -187,7 +214,7 @@ impl TelemetryAnalyzer for TireTemperatureAnalyzer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::telemetry::{SessionInfo, TelemetryData, TireInfo};
+    use crate::telemetry::{SessionInfo, TelemetryData, TireCompound, TireInfo};
     use proptest::prelude::*;
 
     fn create_tire_info(temp: f32) -> TireInfo {
@@ -202,16 +229,11 @@ mod tests {
     }
 
     fn create_telemetry_with_tire_temp(temp: f32, timestamp_ms: u128) -> TelemetryData {
-        let tire_info = create_tire_info(temp);
-        TelemetryData {
-            timestamp_ms,
-            lf_tire_info: Some(tire_info.clone()),
-            rf_tire_info: Some(tire_info.clone()),
-            lr_tire_info: Some(tire_info.clone()),
-            rr_tire_info: Some(tire_info),
-            speed_mps: Some(10.),
-            ..TelemetryData::default()
-        }
+        TelemetryData::builder()
+            .timestamp_ms(timestamp_ms)
+            .tire_temp(temp)
+            .speed_mps(10.)
+            .build()
     }
 
     #[test]
@@ -414,6 +436,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_wet_compound_uses_wet_window() {
+        let mut analyzer = TireTemperatureAnalyzer::with_config(15, (80.0, 95.0));
+        let session_info = SessionInfo {
+            tire_compound: TireCompound::Wet,
+            ..SessionInfo::default()
+        };
+
+        // Comfortably inside the dry window, too hot for wet tires
+        let temp = 85.0;
+        let mut timestamp_ms = 0u128;
+
+        for _ in 0..1000 {
+            let telemetry = create_telemetry_with_tire_temp(temp, timestamp_ms);
+            let output = analyzer.analyze(&telemetry, &session_info);
+
+            if let Some(TelemetryAnnotation::TireOverheating { optimal_max, .. }) = output.first() {
+                assert_eq!(*optimal_max, WET_OPTIMAL_TEMP_MAX);
+                return;
+            }
+
+            timestamp_ms += 16;
+        }
+        panic!("Failed to detect overheating wet tires");
+    }
+
+    #[test]
+    fn test_unknown_compound_keeps_dry_window() {
+        let mut analyzer = TireTemperatureAnalyzer::with_config(15, (80.0, 95.0));
+        let session_info = SessionInfo {
+            tire_compound: TireCompound::Unknown,
+            ..SessionInfo::default()
+        };
+
+        let mut timestamp_ms = 0u128;
+        for _ in 0..1000 {
+            let telemetry = create_telemetry_with_tire_temp(87.5, timestamp_ms);
+            assert!(analyzer.analyze(&telemetry, &session_info).is_empty());
+            timestamp_ms += 16;
+        }
+    }
+
+    #[test]
+    fn test_compound_change_resets_history() {
+        let mut analyzer = TireTemperatureAnalyzer::with_config(15, (80.0, 95.0));
+        let dry = SessionInfo {
+            tire_compound: TireCompound::Dry,
+            ..SessionInfo::default()
+        };
+        let wet = SessionInfo {
+            tire_compound: TireCompound::Wet,
+            ..SessionInfo::default()
+        };
+
+        let mut timestamp_ms = 0u128;
+        for _ in 0..1000 {
+            let telemetry = create_telemetry_with_tire_temp(87.5, timestamp_ms);
+            analyzer.analyze(&telemetry, &dry);
+            timestamp_ms += 16;
+        }
+        assert!(!analyzer.temp_history.is_empty());
+
+        let telemetry = create_telemetry_with_tire_temp(50.0, timestamp_ms);
+        analyzer.analyze(&telemetry, &wet);
+        assert!(analyzer.temp_history.len() <= 1);
+        assert_eq!(
+            analyzer.optimal_temp_range,
+            (WET_OPTIMAL_TEMP_MIN, WET_OPTIMAL_TEMP_MAX)
+        );
+    }
+
     // **Feature: setup-assistant, Property 18: Tire overheating detection**
     // **Validates: Requirements 14.1**
     proptest! {
@@ -0,0 +1,242 @@
+use super::{TelemetryAnnotation, TelemetryData, tire_temperature_analyzer::avg_tire_surface_temp};
+
+/// Grip issues per minute on cold tires, over the rate once warm, that count as pushing
+/// too hard before the tires were up to temperature
+const COLD_GRIP_ISSUE_RATIO: f32 = 1.5;
+
+/// Tire temperature and grip issues of one lap
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WarmupLap {
+    /// Index of the lap within the session
+    pub lap_index: usize,
+    /// Average surface temperature of the four tires over the lap
+    pub avg_tire_temp: f32,
+    /// Slip, scrub, wheelspin, lock and oversteer points of the lap
+    pub grip_issues: usize,
+    pub duration_ms: u128,
+}
+
+/// How many laps the tires took to reach their optimal temperature at the start of a
+/// session, and whether the driver lost grip while they were cold.
+///
+/// A lap is cold when its average tire surface temperature is below the bottom of the
+/// optimal range reported by the `TireCold` annotations, which depends on the compound.
+/// Only the laps before the first warm one are warm-up laps, tires cooling down later
+/// behind a safety car are not a warm-up issue.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TireWarmup {
+    /// Bottom of the optimal tire temperature range (°C)
+    pub optimal_min: f32,
+    /// Laps with tire temperatures, in the order they were driven
+    pub laps: Vec<WarmupLap>,
+}
+
+impl TireWarmup {
+    /// `None` when the tires were never cold or the laps have no tire temperatures.
+    pub fn from_laps<'a>(laps: impl IntoIterator<Item = &'a [TelemetryData]>) -> Option<Self> {
+        let laps: Vec<&[TelemetryData]> = laps.into_iter().collect();
+        let optimal_min = laps.iter().flat_map(|lap| lap.iter()).find_map(|point| {
+            point.annotations.iter().find_map(|a| match a {
+                TelemetryAnnotation::TireCold {
+                    optimal_min,
+                    is_cold: true,
+                    ..
+                } => Some(*optimal_min),
+                _ => None,
+            })
+        })?;
+
+        let laps = laps
+            .into_iter()
+            .enumerate()
+            .filter_map(|(lap_index, points)| {
+                let temps: Vec<f32> = points.iter().filter_map(avg_tire_surface_temp).collect();
+                if temps.is_empty() {
+                    return None;
+                }
+                Some(WarmupLap {
+                    lap_index,
+                    avg_tire_temp: temps.iter().sum::<f32>() / temps.len() as f32,
+                    grip_issues: points
+                        .iter()
+                        .filter(|p| p.annotations.iter().any(is_grip_issue))
+                        .count(),
+                    duration_ms: points
+                        .last()?
+                        .timestamp_ms
+                        .saturating_sub(points.first()?.timestamp_ms),
+                })
+            })
+            .collect();
+        Some(Self { optimal_min, laps })
+    }
+
+    /// Laps at the start of the session driven on cold tires
+    pub fn cold_laps(&self) -> &[WarmupLap] {
+        let warm = self
+            .laps
+            .iter()
+            .position(|lap| lap.avg_tire_temp >= self.optimal_min)
+            .unwrap_or(self.laps.len());
+        &self.laps[..warm]
+    }
+
+    /// First lap with the tires at optimal temperature, `None` when they never got there
+    pub fn warm_lap(&self) -> Option<usize> {
+        self.laps
+            .get(self.cold_laps().len())
+            .map(|lap| lap.lap_index)
+    }
+
+    /// Grip issues per minute on cold tires and once warm, `None` without laps of both
+    pub fn grip_issue_rates(&self) -> Option<(f32, f32)> {
+        let cold = self.cold_laps();
+        let warm = &self.laps[cold.len()..];
+        Some((rate(cold)?, rate(warm)?))
+    }
+
+    /// Returns true when the driver lost grip clearly more often on cold tires
+    pub fn is_pushing_on_cold_tires(&self) -> bool {
+        self.grip_issue_rates()
+            .is_some_and(|(cold, warm)| cold > warm * COLD_GRIP_ISSUE_RATIO && cold > 0.)
+    }
+
+    /// Coaching note on the warm-up, `None` when the tires were warm from the first lap.
+    pub fn coaching_note(&self) -> Option<String> {
+        if self.cold_laps().is_empty() {
+            return None;
+        }
+        let Some(warm_lap) = self.warm_lap() else {
+            return Some(format!(
+                "Tires stayed below {:.0}°C for the whole session",
+                self.optimal_min
+            ));
+        };
+        let mut note = format!("Tires reached optimal temp on lap {}", warm_lap);
+        if self.is_pushing_on_cold_tires()
+            && let Some((cold, warm)) = self.grip_issue_rates()
+        {
+            note.push_str(&format!(
+                ", {:.1} grip issues/min before against {:.1} after: take more time to warm them up",
+                cold, warm
+            ));
+        }
+        Some(note)
+    }
+}
+
+/// Grip issues per minute over `laps`, `None` when they have no duration
+fn rate(laps: &[WarmupLap]) -> Option<f32> {
+    let duration_ms: u128 = laps.iter().map(|lap| lap.duration_ms).sum();
+    if duration_ms == 0 {
+        return None;
+    }
+    let grip_issues: usize = laps.iter().map(|lap| lap.grip_issues).sum();
+    Some(grip_issues as f32 / (duration_ms as f32 / 60_000.))
+}
+
+fn is_grip_issue(annotation: &TelemetryAnnotation) -> bool {
+    annotation.is_active()
+        && matches!(
+            annotation,
+            TelemetryAnnotation::Slip { .. }
+                | TelemetryAnnotation::Scrub { .. }
+                | TelemetryAnnotation::Wheelspin { .. }
+                | TelemetryAnnotation::EntryOversteer { .. }
+                | TelemetryAnnotation::MidCornerOversteer { .. }
+                | TelemetryAnnotation::FrontBrakeLock { .. }
+                | TelemetryAnnotation::RearBrakeLock { .. }
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::TireInfo;
+
+    fn tire(temp: f32) -> Option<TireInfo> {
+        Some(TireInfo {
+            left_carcass_temp: temp,
+            middle_carcass_temp: temp,
+            right_carcass_temp: temp,
+            left_surface_temp: temp,
+            middle_surface_temp: temp,
+            right_surface_temp: temp,
+        })
+    }
+
+    /// A one minute lap at `temp` with `grip_issues` slipping points
+    fn lap(temp: f32, grip_issues: usize) -> Vec<TelemetryData> {
+        (0..=60)
+            .map(|i| {
+                let mut annotations = Vec::new();
+                if temp < 80. {
+                    annotations.push(TelemetryAnnotation::TireCold {
+                        avg_temp: temp,
+                        optimal_min: 80.,
+                        is_cold: true,
+                    });
+                }
+                if i < grip_issues {
+                    annotations.push(TelemetryAnnotation::Slip {
+                        prev_speed: 30.,
+                        cur_speed: 29.,
+                        is_slip: true,
+                        slip_ratio: None,
+                    });
+                }
+                TelemetryData {
+                    timestamp_ms: i as u128 * 1000,
+                    lf_tire_info: tire(temp),
+                    rf_tire_info: tire(temp),
+                    lr_tire_info: tire(temp),
+                    rr_tire_info: tire(temp),
+                    annotations,
+                    ..TelemetryData::default()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_pushing_on_cold_tires() {
+        let laps = [lap(60., 6), lap(72., 5), lap(85., 1), lap(88., 2)];
+        let warmup = TireWarmup::from_laps(laps.iter().map(Vec::as_slice)).unwrap();
+
+        assert_eq!(warmup.cold_laps().len(), 2);
+        assert_eq!(warmup.warm_lap(), Some(2));
+        let (cold, warm) = warmup.grip_issue_rates().unwrap();
+        assert!((cold - 5.5).abs() < 1e-3);
+        assert!((warm - 1.5).abs() < 1e-3);
+        assert!(warmup.is_pushing_on_cold_tires());
+        assert_eq!(
+            warmup.coaching_note().unwrap(),
+            "Tires reached optimal temp on lap 2, 5.5 grip issues/min before against 1.5 after: take more time to warm them up"
+        );
+    }
+
+    #[test]
+    fn test_careful_warm_up_has_no_advice() {
+        let laps = [lap(65., 1), lap(85., 2), lap(86., 2)];
+        let warmup = TireWarmup::from_laps(laps.iter().map(Vec::as_slice)).unwrap();
+        assert!(!warmup.is_pushing_on_cold_tires());
+        assert_eq!(
+            warmup.coaching_note().unwrap(),
+            "Tires reached optimal temp on lap 1"
+        );
+    }
+
+    #[test]
+    fn test_needs_cold_tires() {
+        let laps = [lap(85., 1), lap(86., 1)];
+        assert!(TireWarmup::from_laps(laps.iter().map(Vec::as_slice)).is_none());
+
+        let laps = [lap(60., 1), lap(70., 1)];
+        let warmup = TireWarmup::from_laps(laps.iter().map(Vec::as_slice)).unwrap();
+        assert_eq!(warmup.warm_lap(), None);
+        assert_eq!(
+            warmup.coaching_note().unwrap(),
+            "Tires stayed below 80°C for the whole session"
+        );
+    }
+}
@@ -1,22 +1,71 @@
 use crate::telemetry::is_telemetry_point_analyzable;
 
-use super::{SessionInfo, TelemetryAnalyzer, TelemetryData};
+use super::{SessionInfo, TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
 
 pub(crate) const MIN_TRAILBRAKING_PCT: f32 = 0.2;
 pub(crate) const MAX_TRAILBRAKING_STEERING_ANGLE: f32 = 0.1;
+/// Maximum brake release rate (pedal fraction per second) while turning in before
+/// the release is considered abrupt enough to upset the car
+pub(crate) const MAX_BRAKE_RELEASE_RATE: f32 = 2.5;
 
 pub struct TrailbrakeSteeringAnalyzer {
     max_trailbraking_steering_angle: f32,
     min_trailbraking_pct: f32,
+    max_brake_release_rate: f32,
+    prev_brake: f32,
+    prev_steering_pct: f32,
+    prev_timestamp_ms: Option<u128>,
 }
 
 impl TrailbrakeSteeringAnalyzer {
-    pub fn new(max_trailbraking_steering_angle: f32, min_trailbraking_pct: f32) -> Self {
+    pub fn new(
+        max_trailbraking_steering_angle: f32,
+        min_trailbraking_pct: f32,
+        max_brake_release_rate: f32,
+    ) -> Self {
         Self {
             max_trailbraking_steering_angle,
             min_trailbraking_pct,
+            max_brake_release_rate,
+            prev_brake: 0.0,
+            prev_steering_pct: 0.0,
+            prev_timestamp_ms: None,
         }
     }
+
+    /// Measure how quickly the brake is released while the steering is still winding on.
+    ///
+    /// Releasing the brake smoothly while turning in keeps the front loaded into the apex,
+    /// an abrupt release unloads the front and upsets the car.
+    fn analyze_brake_release(
+        &mut self,
+        telemetry: &TelemetryData,
+        brake: f32,
+        steering_pct: f32,
+    ) -> Option<TelemetryAnnotation> {
+        let prev_timestamp_ms = self.prev_timestamp_ms.replace(telemetry.timestamp_ms);
+        let prev_brake = self.prev_brake;
+        let prev_steering_pct = self.prev_steering_pct;
+        self.prev_brake = brake;
+        self.prev_steering_pct = steering_pct;
+
+        let elapsed_ms = telemetry.timestamp_ms.checked_sub(prev_timestamp_ms?)?;
+        if elapsed_ms == 0 {
+            return None;
+        }
+
+        let is_steering_increasing = steering_pct.abs() > prev_steering_pct.abs();
+        let is_releasing = prev_brake > self.min_trailbraking_pct && brake < prev_brake;
+        if !is_steering_increasing || !is_releasing {
+            return None;
+        }
+
+        let brake_release_rate = (prev_brake - brake) / (elapsed_ms as f32 / 1000.);
+        Some(TelemetryAnnotation::TrailbrakeRelease {
+            brake_release_rate,
+            is_abrupt_release: brake_release_rate > self.max_brake_release_rate,
+        })
+    }
 }
 
 impl TelemetryAnalyzer for TrailbrakeSteeringAnalyzer {
@@ -24,7 +73,7 @@ impl TelemetryAnalyzer for TrailbrakeSteeringAnalyzer {
         &mut self,
         telemetry: &TelemetryData,
         session_info: &SessionInfo,
-    ) -> Vec<super::TelemetryAnnotation> {
+    ) -> Vec<TelemetryAnnotation> {
         let mut output = Vec::new();
 
         // Skip analysis if doesn't meet requirements
@@ -37,6 +86,11 @@ impl TelemetryAnalyzer for TrailbrakeSteeringAnalyzer {
         let steering_angle_rad = telemetry.steering_angle_rad.unwrap_or(0.0);
         let steering_pct = telemetry.steering_pct.unwrap_or(0.0);
 
+        // release rate only needs pedal and steering inputs, track it on every point
+        if let Some(release) = self.analyze_brake_release(telemetry, brake, steering_pct) {
+            output.push(release);
+        }
+
         // nothing to process here if we cannot establish the current steering pct
         if session_info.max_steering_angle == 0. {
             return output;
@@ -49,7 +103,7 @@ impl TelemetryAnalyzer for TrailbrakeSteeringAnalyzer {
         // we are braking... measure steering angle
         if brake > self.min_trailbraking_pct && steering_pct > self.max_trailbraking_steering_angle
         {
-            output.push(super::TelemetryAnnotation::TrailbrakeSteering {
+            output.push(TelemetryAnnotation::TrailbrakeSteering {
                 cur_trailbrake_steering: steering_pct,
                 is_excessive_trailbrake_steering: true,
             });
@@ -65,7 +119,7 @@ mod tests {
     use super::*;
 
     fn default_analyzer() -> TrailbrakeSteeringAnalyzer {
-        TrailbrakeSteeringAnalyzer::new(0.1, 0.2)
+        TrailbrakeSteeringAnalyzer::new(0.1, 0.2, 2.5)
     }
 
     #[test]
@@ -90,6 +144,64 @@ mod tests {
         assert!(analyzer.analyze(&telemetry_data, &session_info).is_empty());
     }
 
+    fn release_point(timestamp_ms: u128, brake: f32, steering_pct: f32) -> TelemetryData {
+        TelemetryData {
+            timestamp_ms,
+            brake: Some(brake),
+            steering_pct: Some(steering_pct),
+            speed_mps: Some(30.0),
+            ..create_default_telemetry()
+        }
+    }
+
+    fn release_annotation(annotations: &[TelemetryAnnotation]) -> Option<(f32, bool)> {
+        annotations.iter().find_map(|a| match a {
+            TelemetryAnnotation::TrailbrakeRelease {
+                brake_release_rate,
+                is_abrupt_release,
+            } => Some((*brake_release_rate, *is_abrupt_release)),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn test_abrupt_brake_release_flagged() {
+        let mut analyzer = default_analyzer();
+        let session_info = SessionInfo::default();
+
+        analyzer.analyze(&release_point(0, 0.8, 0.05), &session_info);
+        // Brake drops from 80% to 20% in 100ms while steering winds on
+        let output = analyzer.analyze(&release_point(100, 0.2, 0.15), &session_info);
+
+        let (rate, is_abrupt) = release_annotation(&output).expect("Expected release annotation");
+        assert!((rate - 6.0).abs() < 0.01);
+        assert!(is_abrupt);
+    }
+
+    #[test]
+    fn test_smooth_brake_release_not_flagged() {
+        let mut analyzer = default_analyzer();
+        let session_info = SessionInfo::default();
+
+        analyzer.analyze(&release_point(0, 0.8, 0.05), &session_info);
+        let output = analyzer.analyze(&release_point(100, 0.7, 0.1), &session_info);
+
+        let (rate, is_abrupt) = release_annotation(&output).expect("Expected release annotation");
+        assert!((rate - 1.0).abs() < 0.01);
+        assert!(!is_abrupt);
+    }
+
+    #[test]
+    fn test_release_ignored_when_steering_unwinding() {
+        let mut analyzer = default_analyzer();
+        let session_info = SessionInfo::default();
+
+        analyzer.analyze(&release_point(0, 0.8, 0.3), &session_info);
+        let output = analyzer.analyze(&release_point(100, 0.1, 0.2), &session_info);
+
+        assert!(release_annotation(&output).is_none());
+    }
+
     fn create_default_telemetry() -> TelemetryData {
         TelemetryData {
             gear: Some(1),
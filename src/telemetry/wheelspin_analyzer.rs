@@ -7,22 +7,34 @@ use crate::telemetry::is_telemetry_point_analyzable;
 
 use super::{SessionInfo, TelemetryAnalyzer, TelemetryAnnotation, TelemetryData};
 
+/// Slip ratio of the fastest turning wheel that counts as wheelspin, when the sim
+/// reports wheel slip ratios or speeds
+pub(crate) const MIN_WHEELSPIN_SLIP_RATIO: f32 = 0.1;
+
 pub struct WheelspinAnalyzer<const WINDOW_SIZE: usize> {
     cur_averages: HashMap<u32, f32>,
     telemetry_window: HashMap<u32, SumTreeSMA<f32, f32, WINDOW_SIZE>>,
     prev_gear: u32,
     prev_rpm: f32,
     cur_gear_points: HashMap<u32, usize>,
+    rpm_growth_margin: f32,
 }
 
 impl<const WINDOW_SIZE: usize> WheelspinAnalyzer<WINDOW_SIZE> {
     pub fn new() -> Self {
+        Self::with_rpm_growth_margin(0.)
+    }
+
+    /// Wheelspin is only reported when the RPM growth exceeds the learned growth for
+    /// the gear by more than `rpm_growth_margin` (0.1 = 10%).
+    pub fn with_rpm_growth_margin(rpm_growth_margin: f32) -> Self {
         Self {
             cur_averages: HashMap::new(),
             telemetry_window: HashMap::new(),
             prev_gear: 0,
             prev_rpm: 0.,
             cur_gear_points: HashMap::new(),
+            rpm_growth_margin,
         }
     }
 }
@@ -42,6 +54,25 @@ impl<const WINDOW_SIZE: usize> TelemetryAnalyzer for WheelspinAnalyzer<WINDOW_SI
         let cur_rpm = telemetry.engine_rpm.unwrap_or(0.0);
         let throttle = telemetry.throttle.unwrap_or(0.0);
         let brake = telemetry.brake.unwrap_or(0.0);
+        // with wheel slip ratios the spinning wheel shows up on its own, the RPM growth is
+        // only a stand-in for it
+        let slip_ratio = telemetry
+            .wheel_slip_ratios()
+            .map(|ratios| ratios.into_iter().fold(f32::MIN, f32::max));
+
+        if let Some(slip_ratio) = slip_ratio
+            && slip_ratio >= MIN_WHEELSPIN_SLIP_RATIO
+            && throttle > 0.
+            && brake == 0.
+        {
+            output.push(TelemetryAnnotation::Wheelspin {
+                avg_rpm_increase_per_gear: self.cur_averages.clone(),
+                cur_gear,
+                cur_rpm_increase: (cur_rpm - self.prev_rpm).max(0.),
+                is_wheelspin: true,
+                slip_ratio: Some(slip_ratio),
+            });
+        }
 
         if cur_gear != self.prev_gear {
             self.prev_gear = cur_gear;
@@ -49,8 +80,9 @@ impl<const WINDOW_SIZE: usize> TelemetryAnalyzer for WheelspinAnalyzer<WINDOW_SI
             if cur_rpm > self.prev_rpm && cur_gear > 0 {
                 let rpm_growth = cur_rpm - self.prev_rpm;
 
-                if let Some(cur_average) = self.cur_averages.get(&cur_gear)
-                    && rpm_growth > *cur_average
+                if slip_ratio.is_none()
+                    && let Some(cur_average) = self.cur_averages.get(&cur_gear)
+                    && rpm_growth > *cur_average * (1. + self.rpm_growth_margin)
                     && *self.cur_gear_points.entry(cur_gear).or_insert(0) >= WINDOW_SIZE
                 {
                     output.push(TelemetryAnnotation::Wheelspin {
@@ -58,6 +90,7 @@ impl<const WINDOW_SIZE: usize> TelemetryAnalyzer for WheelspinAnalyzer<WINDOW_SI
                         cur_gear,
                         cur_rpm_increase: rpm_growth,
                         is_wheelspin: true,
+                        slip_ratio: None,
                     });
                 }
 
@@ -76,7 +109,7 @@ impl<const WINDOW_SIZE: usize> TelemetryAnalyzer for WheelspinAnalyzer<WINDOW_SI
                             .get(&cur_gear)
                             .unwrap()
                             .get_sample_window_iter()
-                            .sorted_by(|a, b| a.partial_cmp(b).unwrap())
+                            .sorted_by(|a, b| a.total_cmp(b))
                             .nth((WINDOW_SIZE as f32 * 0.9) as usize)
                             .unwrap();
                     }
@@ -88,3 +121,40 @@ impl<const WINDOW_SIZE: usize> TelemetryAnalyzer for WheelspinAnalyzer<WINDOW_SI
         output
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wheelspin_from_wheel_speeds() {
+        // no RPM history, the wheel speeds alone show the rear wheels spinning up
+        let mut analyzer = WheelspinAnalyzer::<10>::new();
+        let point = TelemetryData::builder()
+            .gear(2)
+            .speed_mps(20.)
+            .engine_rpm(5000.)
+            .throttle(1.)
+            .brake(0.)
+            .wheel_speeds_mps(20., 20., 23., 22.)
+            .build();
+
+        let output = analyzer.analyze(&point, &SessionInfo::default());
+        assert_eq!(output.len(), 1);
+        let TelemetryAnnotation::Wheelspin { slip_ratio, .. } = &output[0] else {
+            panic!("Expected Wheelspin annotation");
+        };
+        assert!((slip_ratio.unwrap() - 0.15).abs() < 1e-4);
+
+        // the wheels roll with the car
+        let point = TelemetryData::builder()
+            .gear(2)
+            .speed_mps(20.)
+            .engine_rpm(5100.)
+            .throttle(1.)
+            .brake(0.)
+            .wheel_speeds_mps(20., 20., 20.5, 20.5)
+            .build();
+        assert!(analyzer.analyze(&point, &SessionInfo::default()).is_empty());
+    }
+}
@@ -0,0 +1,372 @@
+use std::sync::Arc;
+
+use egui::Color32;
+use egui_plot::{Line, LineStyle, PlotPoints};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    telemetry::{TelemetryData, TireInfo, grip_usage::STANDARD_GRAVITY_MPS2},
+    ui::{live::config::SpeedUnit, stroke_shade, theme::Theme},
+};
+
+/// Telemetry channels the analysis chart can plot. Each one is scaled to the 0-100 range
+/// of the chart on its own, channels with a unit get their own right-hand axis.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum ChartChannel {
+    Throttle,
+    Brake,
+    /// Brake line pressure, reported by ACC
+    BrakePressure,
+    Clutch,
+    /// Fraction of full lock, centred on the middle of the chart
+    Steering,
+    /// Steering wheel angle, the max lock of the car at the edges of the chart
+    SteeringAngle,
+    Speed,
+    EngineRpm,
+    Gear,
+    LateralG,
+    LongitudinalG,
+    YawRate,
+    /// Average surface temperature of each tire
+    LfTireTemp,
+    RfTireTemp,
+    LrTireTemp,
+    RrTireTemp,
+}
+
+/// Channels plotted until others are picked
+pub(crate) const DEFAULT_CHART_CHANNELS: [ChartChannel; 4] = [
+    ChartChannel::Throttle,
+    ChartChannel::Brake,
+    ChartChannel::Steering,
+    ChartChannel::Speed,
+];
+
+/// How the values of a channel map to the 0-100 range of the chart
+enum ChannelRange {
+    /// Fraction from 0 to 1
+    Unit,
+    /// Fraction from -1 to 1, centred on 50
+    CenteredUnit,
+    /// From 0 to the largest value of the plotted laps
+    FromZero,
+    /// Centred on 50, the largest magnitude of the plotted laps at 0 and 100
+    Centered,
+    /// From the smallest to the largest value of the plotted laps
+    Observed,
+}
+
+impl ChartChannel {
+    pub(crate) const ALL: [ChartChannel; 16] = [
+        ChartChannel::Throttle,
+        ChartChannel::Brake,
+        ChartChannel::BrakePressure,
+        ChartChannel::Clutch,
+        ChartChannel::Steering,
+        ChartChannel::SteeringAngle,
+        ChartChannel::Speed,
+        ChartChannel::EngineRpm,
+        ChartChannel::Gear,
+        ChartChannel::LateralG,
+        ChartChannel::LongitudinalG,
+        ChartChannel::YawRate,
+        ChartChannel::LfTireTemp,
+        ChartChannel::RfTireTemp,
+        ChartChannel::LrTireTemp,
+        ChartChannel::RrTireTemp,
+    ];
+
+    /// Value of the channel at `point` in the unit of its axis, `None` when the sim did
+    /// not report it.
+    pub(super) fn value(self, point: &TelemetryData, speed_unit: SpeedUnit) -> Option<f64> {
+        let tire_temp = |tire: &Option<TireInfo>| {
+            tire.as_ref()
+                .map(|t| (t.left_surface_temp + t.middle_surface_temp + t.right_surface_temp) / 3.)
+        };
+        let value = match self {
+            Self::Throttle => point.throttle?,
+            Self::Brake => point.brake?,
+            Self::BrakePressure => point.brake_pressure_pct?,
+            Self::Clutch => point.clutch?,
+            Self::Steering => point.steering_pct?,
+            Self::SteeringAngle => point.steering_angle_rad?.to_degrees(),
+            Self::Speed => speed_unit.speed_from_mps(point.speed_mps?),
+            Self::EngineRpm => point.engine_rpm?,
+            Self::Gear => point.gear? as f32,
+            Self::LateralG => point.lateral_accel_mps2? / STANDARD_GRAVITY_MPS2,
+            Self::LongitudinalG => point.longitudinal_accel_mps2? / STANDARD_GRAVITY_MPS2,
+            Self::YawRate => point.yaw_rate_rps?.to_degrees(),
+            Self::LfTireTemp => tire_temp(&point.lf_tire_info)?,
+            Self::RfTireTemp => tire_temp(&point.rf_tire_info)?,
+            Self::LrTireTemp => tire_temp(&point.lr_tire_info)?,
+            Self::RrTireTemp => tire_temp(&point.rr_tire_info)?,
+        };
+        Some(value as f64)
+    }
+
+    /// Returns true when the sim reported the channel for any point.
+    pub(super) fn is_available(self, points: &[TelemetryData]) -> bool {
+        points
+            .iter()
+            .any(|p| self.value(p, SpeedUnit::default()).is_some())
+    }
+
+    /// Label of the right-hand axis, `None` for the channels read on the 0-100 axis.
+    pub(super) fn axis_label(self, speed_unit: SpeedUnit) -> Option<String> {
+        let label = match self {
+            Self::Throttle | Self::Brake | Self::BrakePressure | Self::Clutch | Self::Steering => {
+                return None;
+            }
+            Self::SteeringAngle => "Steering (°)".to_string(),
+            Self::Speed => format!("Speed ({})", speed_unit),
+            Self::EngineRpm | Self::Gear => self.to_string(),
+            Self::LateralG => "Lateral (g)".to_string(),
+            Self::LongitudinalG => "Longitudinal (g)".to_string(),
+            Self::YawRate => "Yaw rate (°/s)".to_string(),
+            Self::LfTireTemp | Self::RfTireTemp | Self::LrTireTemp | Self::RrTireTemp => {
+                format!("{} (°C)", self)
+            }
+        };
+        Some(label)
+    }
+
+    /// Axis mark for a value of the channel.
+    pub(super) fn format_value(self, value: f64) -> String {
+        match self {
+            Self::LateralG | Self::LongitudinalG => format!("{:.1}", value),
+            _ => format!("{:.0}", value),
+        }
+    }
+
+    fn range(self) -> ChannelRange {
+        match self {
+            Self::Throttle | Self::Brake | Self::BrakePressure | Self::Clutch => ChannelRange::Unit,
+            Self::Steering => ChannelRange::CenteredUnit,
+            Self::Speed | Self::EngineRpm | Self::Gear => ChannelRange::FromZero,
+            Self::SteeringAngle | Self::LateralG | Self::LongitudinalG | Self::YawRate => {
+                ChannelRange::Centered
+            }
+            Self::LfTireTemp | Self::RfTireTemp | Self::LrTireTemp | Self::RrTireTemp => {
+                ChannelRange::Observed
+            }
+        }
+    }
+
+    /// Color of the channel for the selected lap, the comparison lap uses the comparison
+    /// colors of the theme or a dashed line.
+    fn color(self, theme: &Theme, comparison: bool) -> Color32 {
+        match (self, comparison) {
+            (Self::Throttle, false) => theme.throttle,
+            (Self::Throttle, true) => theme.comparison_throttle,
+            (Self::Brake | Self::BrakePressure, false) => theme.brake,
+            (Self::Brake | Self::BrakePressure, true) => theme.comparison_brake,
+            (Self::Steering | Self::SteeringAngle, false) => theme.steering,
+            (Self::Steering | Self::SteeringAngle, true) => theme.comparison_steering,
+            (Self::Speed, false) => theme.speed,
+            (Self::Speed, true) => theme.comparison_speed,
+            (Self::Clutch, _) => Color32::from_rgb(0x9c, 0x6a, 0xde),
+            (Self::EngineRpm, _) => Color32::from_rgb(0xff, 0x8c, 0x1a),
+            (Self::Gear, _) => Color32::from_rgb(0xe0, 0xe0, 0xe0),
+            (Self::LateralG, _) => Color32::from_rgb(0x4d, 0xd0, 0xe1),
+            (Self::LongitudinalG, _) => Color32::from_rgb(0xf0, 0x62, 0x92),
+            (Self::YawRate, _) => Color32::from_rgb(0xae, 0xd5, 0x81),
+            (Self::LfTireTemp, _) => Color32::from_rgb(0xff, 0xd5, 0x4f),
+            (Self::RfTireTemp, _) => Color32::from_rgb(0xff, 0xa7, 0x26),
+            (Self::LrTireTemp, _) => Color32::from_rgb(0xa1, 0x88, 0x7f),
+            (Self::RrTireTemp, _) => Color32::from_rgb(0x90, 0xa4, 0xae),
+        }
+    }
+
+    /// Chart line of the channel, `comparison` for the traces of the comparison lap.
+    pub(super) fn line(
+        self,
+        name: String,
+        points: PlotPoints<'static>,
+        theme: &Theme,
+        comparison: bool,
+    ) -> Line<'static> {
+        let line = Line::new(name, points).color(self.color(theme, comparison));
+        match (self, comparison) {
+            (Self::Throttle, false) => line.fill(0.),
+            (Self::Brake, false) => {
+                let (light, full) = (theme.brake_light, theme.brake);
+                line.gradient_color(
+                    Arc::new(move |point| stroke_shade(light, full, (point.y / 100.) as f32)),
+                    true,
+                )
+                .color(full)
+                .fill(0.)
+            }
+            (Self::BrakePressure, _) => line.style(LineStyle::dashed_dense()),
+            (
+                Self::Throttle | Self::Brake | Self::Steering | Self::SteeringAngle | Self::Speed,
+                _,
+            )
+            | (_, false) => line,
+            (_, true) => line.style(LineStyle::dashed_loose()),
+        }
+    }
+}
+
+impl std::fmt::Display for ChartChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Throttle => write!(f, "Throttle"),
+            Self::Brake => write!(f, "Brake"),
+            Self::BrakePressure => write!(f, "Brake Pressure"),
+            Self::Clutch => write!(f, "Clutch"),
+            Self::Steering => write!(f, "Steering"),
+            Self::SteeringAngle => write!(f, "Steering (°)"),
+            Self::Speed => write!(f, "Speed"),
+            Self::EngineRpm => write!(f, "RPM"),
+            Self::Gear => write!(f, "Gear"),
+            Self::LateralG => write!(f, "Lateral G"),
+            Self::LongitudinalG => write!(f, "Longitudinal G"),
+            Self::YawRate => write!(f, "Yaw rate"),
+            Self::LfTireTemp => write!(f, "LF tire temp"),
+            Self::RfTireTemp => write!(f, "RF tire temp"),
+            Self::LrTireTemp => write!(f, "LR tire temp"),
+            Self::RrTireTemp => write!(f, "RR tire temp"),
+        }
+    }
+}
+
+/// Maps the values of a channel to the 0-100 range of the chart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct ChannelScale {
+    min: f64,
+    max: f64,
+}
+
+impl ChannelScale {
+    /// Scale of `channel` over the points of the plotted laps. The steering angle uses the
+    /// max lock of the car, `max_steering_angle` (rad), when the sim reported it.
+    pub(super) fn fit<'p>(
+        channel: ChartChannel,
+        max_steering_angle: f32,
+        speed_unit: SpeedUnit,
+        points: impl Iterator<Item = &'p TelemetryData> + Clone,
+    ) -> Self {
+        let values = points.clone().filter_map(|p| channel.value(p, speed_unit));
+        let (min, max) = match channel.range() {
+            ChannelRange::Unit => (0., 1.),
+            ChannelRange::CenteredUnit => (-1., 1.),
+            ChannelRange::FromZero => {
+                let max = values.fold(0., f64::max);
+                (0., if max > 0. { max } else { 1. })
+            }
+            ChannelRange::Centered => {
+                let magnitude = if channel == ChartChannel::SteeringAngle {
+                    steering_scale_rad(max_steering_angle, points).to_degrees()
+                } else {
+                    values.map(f64::abs).fold(0., f64::max)
+                };
+                let magnitude = if magnitude > 0. { magnitude } else { 1. };
+                (-magnitude, magnitude)
+            }
+            ChannelRange::Observed => {
+                let (min, max) = values
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                        (min.min(v), max.max(v))
+                    });
+                if min < max {
+                    (min, max)
+                } else if min.is_finite() {
+                    (min - 1., min + 1.)
+                } else {
+                    (0., 1.)
+                }
+            }
+        };
+        Self { min, max }
+    }
+
+    pub(super) fn to_chart(self, value: f64) -> f64 {
+        (value - self.min) / (self.max - self.min) * 100.
+    }
+
+    pub(super) fn from_chart(self, y: f64) -> f64 {
+        self.min + y / 100. * (self.max - self.min)
+    }
+}
+
+/// Steering angle (rad) plotted at the edges of the chart: the max lock of the car, or the
+/// largest angle of the plotted points when the sim did not report it.
+fn steering_scale_rad<'p>(
+    max_steering_angle: f32,
+    points: impl Iterator<Item = &'p TelemetryData>,
+) -> f64 {
+    if max_steering_angle.is_finite() && max_steering_angle > 0. {
+        return max_steering_angle as f64;
+    }
+    points
+        .filter_map(|p| p.steering_angle_rad)
+        .map(f32::abs)
+        .fold(0., f32::max)
+        .max(f32::EPSILON) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_available() {
+        assert!(!ChartChannel::BrakePressure.is_available(&[TelemetryData::default()]));
+        assert!(ChartChannel::BrakePressure.is_available(&[
+            TelemetryData::default(),
+            TelemetryData {
+                brake_pressure_pct: Some(0.5),
+                ..TelemetryData::default()
+            }
+        ]));
+    }
+
+    #[test]
+    fn test_steering_scale_rad() {
+        let angle = |steering_angle_rad: f32| TelemetryData {
+            steering_angle_rad: Some(steering_angle_rad),
+            ..TelemetryData::default()
+        };
+        let points = [angle(-1.5), angle(0.5), TelemetryData::default()];
+
+        // the max lock of the car puts 270 degrees at the edge of the chart
+        let scale = steering_scale_rad(270_f32.to_radians(), points.iter());
+        assert!((scale.to_degrees() - 270.).abs() < 1e-3);
+        // without it the largest angle used does
+        assert_eq!(steering_scale_rad(0., points.iter()), 1.5);
+        assert!(ChartChannel::SteeringAngle.is_available(&points));
+        assert!(!ChartChannel::SteeringAngle.is_available(&[TelemetryData::default()]));
+    }
+
+    #[test]
+    fn test_channel_scales() {
+        let point = |speed_mps: f32, lateral_accel_mps2: f32| TelemetryData {
+            throttle: Some(0.5),
+            speed_mps: Some(speed_mps),
+            lateral_accel_mps2: Some(lateral_accel_mps2),
+            ..TelemetryData::default()
+        };
+        let points = [point(25., -9.80665), point(50., 4.9)];
+        let fit = |channel| ChannelScale::fit(channel, 0., SpeedUnit::Kmh, points.iter());
+
+        // pedals keep their fixed 0-100 range
+        let throttle = fit(ChartChannel::Throttle);
+        assert_eq!(throttle.to_chart(0.5), 50.);
+
+        // the fastest point reaches the top, in the unit of the axis
+        let speed = fit(ChartChannel::Speed);
+        assert!((speed.to_chart(180.) - 100.).abs() < 1e-6);
+        assert!((speed.from_chart(50.) - 90.).abs() < 1e-6);
+
+        // lateral g is centred, the hardest cornering reaches the edge
+        let lateral = fit(ChartChannel::LateralG);
+        assert!((lateral.to_chart(0.) - 50.).abs() < 1e-6);
+        assert!(lateral.to_chart(-1.).abs() < 1e-6);
+
+        // a channel without data still gets a usable range
+        let rpm = fit(ChartChannel::EngineRpm);
+        assert_eq!(rpm.from_chart(100.), 1.);
+    }
+}
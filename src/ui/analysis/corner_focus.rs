@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use egui::{Color32, RichText, Ui};
+
+use crate::{
+    setup_assistant::{
+        DEFAULT_MIN_OCCURRENCES, Finding, FindingType, SetupAssistant,
+        recommendations::ProcessedRecommendation,
+    },
+    telemetry::{
+        TelemetryData,
+        corners::{CornerDetection, CornerWindow},
+    },
+};
+
+use super::Lap;
+
+/// Focus selector option that shows the whole lap again
+pub(super) const ALL_CORNERS_LABEL: &str = "All corners";
+/// Recommendations listed for the focused corner, the rest are left to the setup window
+const MAX_FOCUS_RECOMMENDATIONS: usize = 8;
+
+/// Corners offered by the focus selector, labelled in the order they were driven in
+/// `reference`. Corners of a lap without lap distance have no window and are left out.
+pub(super) fn focus_corners(
+    reference: &[TelemetryData],
+    detection: &CornerDetection,
+) -> Vec<(String, CornerWindow)> {
+    detection
+        .detect(reference)
+        .iter()
+        .filter_map(|corner| corner.window())
+        .enumerate()
+        .map(|(i, window)| {
+            (
+                format!(
+                    "T{} ({:.0}-{:.0}%)",
+                    i + 1,
+                    window.start_lap_distance_pct * 100.,
+                    window.end_lap_distance_pct * 100.
+                ),
+                window,
+            )
+        })
+        .collect()
+}
+
+/// Setup findings of one corner gathered across every lap of a session, with the
+/// recommendations they lead to.
+pub(super) struct CornerFocus {
+    /// Track name of the session and label of the corner the findings were gathered for
+    pub(super) key: (String, String),
+    /// Laps with at least one finding in the corner
+    pub(super) laps_with_findings: usize,
+    /// Findings that passed the occurrence threshold, most frequent first
+    pub(super) findings: Vec<Finding>,
+    pub(super) recommendations: Vec<ProcessedRecommendation>,
+}
+
+impl CornerFocus {
+    /// Gather the findings within `window` on every lap.
+    ///
+    /// Laps are analyzed one at a time, so the occurrence history of a finding covers
+    /// the whole lap and every detection in the corner is kept.
+    pub(super) fn from_laps(key: (String, String), laps: &[Lap], window: &CornerWindow) -> Self {
+        let mut by_type: HashMap<FindingType, Finding> = HashMap::new();
+        let mut laps_with_findings = 0;
+        for lap in laps {
+            let mut setup_assistant = SetupAssistant::new();
+            for point in &lap.telemetry {
+                setup_assistant.process_telemetry(point);
+            }
+            let findings = setup_assistant.findings_within(window);
+            if !findings.is_empty() {
+                laps_with_findings += 1;
+            }
+            for finding in findings {
+                match by_type.get_mut(&finding.finding_type) {
+                    Some(merged) => merged.merge(&finding),
+                    None => {
+                        by_type.insert(finding.finding_type.clone(), finding);
+                    }
+                }
+            }
+        }
+
+        let mut findings: Vec<Finding> = by_type
+            .into_values()
+            .filter(|f| f.occurrence_count >= DEFAULT_MIN_OCCURRENCES)
+            .collect();
+        findings.sort_by(|a, b| b.occurrence_count.cmp(&a.occurrence_count));
+        let mut recommendations = SetupAssistant::new().recommend_for_findings(&findings);
+        recommendations.truncate(MAX_FOCUS_RECOMMENDATIONS);
+
+        Self {
+            key,
+            laps_with_findings,
+            findings,
+            recommendations,
+        }
+    }
+
+    pub(super) fn show(&self, ui: &mut Ui) {
+        ui.label(
+            RichText::new(format!(
+                "{}: findings on {} laps",
+                self.key.1, self.laps_with_findings
+            ))
+            .color(Color32::WHITE)
+            .strong(),
+        );
+        if self.findings.is_empty() {
+            ui.label(RichText::new("No setup issues in this corner").color(Color32::GRAY));
+            return;
+        }
+        for finding in &self.findings {
+            ui.label(
+                RichText::new(format!(
+                    "{} ({}), seen {} times",
+                    finding.finding_type, finding.corner_phase, finding.occurrence_count
+                ))
+                .color(Color32::WHITE),
+            );
+        }
+        ui.label(RichText::new("Recommendations").color(Color32::GRAY));
+        for processed in &self.recommendations {
+            let recommendation = &processed.recommendation;
+            ui.label(
+                RichText::new(format!(
+                    "{} {} ({:.0}% confidence)",
+                    recommendation.adjustment,
+                    recommendation.parameter,
+                    processed.confidence * 100.
+                ))
+                .color(Color32::WHITE),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::{ExitUndersteerCause, TelemetryAnnotation};
+
+    /// A 100 point lap with exit throttle lifts at the given lap distances
+    fn lap(lift_at: &[usize]) -> Lap {
+        let telemetry = (0..100)
+            .map(|i| TelemetryData {
+                timestamp_ms: i as u128 * 100,
+                speed_mps: Some(30.),
+                throttle: Some(0.5),
+                steering_pct: Some(0.3),
+                lap_distance_pct: Some(i as f32 / 100.),
+                annotations: if lift_at.contains(&i) {
+                    vec![TelemetryAnnotation::ExitThrottleLift {
+                        dip_depth: 0.2,
+                        dip_count: 1,
+                        is_understeer_lift: true,
+                        cause: ExitUndersteerCause::Aero,
+                    }]
+                } else {
+                    Vec::new()
+                },
+                ..TelemetryData::default()
+            })
+            .collect();
+        Lap {
+            telemetry,
+            ..Lap::default()
+        }
+    }
+
+    #[test]
+    fn test_gathers_findings_of_the_corner_across_laps() {
+        let window = CornerWindow {
+            start_lap_distance_pct: 0.4,
+            end_lap_distance_pct: 0.5,
+        };
+        let laps = [lap(&[12, 44, 47]), lap(&[45]), lap(&[80])];
+
+        let focus = CornerFocus::from_laps(("Spa".into(), "T3".into()), &laps, &window);
+
+        assert_eq!(focus.laps_with_findings, 2);
+        assert_eq!(focus.findings.len(), 1);
+        assert_eq!(
+            focus.findings[0].finding_type,
+            FindingType::CornerExitUndersteer
+        );
+        assert_eq!(focus.findings[0].occurrence_count, 3);
+        // the aero cause moves the front wing to the top
+        assert_eq!(
+            focus.recommendations[0].recommendation.parameter,
+            "Front Wing"
+        );
+    }
+
+    #[test]
+    fn test_focus_corners_are_labelled_in_lap_order() {
+        let reference: Vec<_> = (0..100)
+            .map(|i| TelemetryData {
+                timestamp_ms: i * 100,
+                speed_mps: Some(40.),
+                steering_pct: Some(if (20..=30).contains(&i) { 0.4 } else { 0. }),
+                lap_distance_pct: Some(i as f32 / 100.),
+                ..TelemetryData::default()
+            })
+            .collect();
+
+        let corners = focus_corners(&reference, &CornerDetection::default());
+
+        assert_eq!(corners.len(), 1);
+        assert_eq!(corners[0].0, "T1 (20-30%)");
+        assert!(corners[0].1.contains(0.25));
+    }
+}
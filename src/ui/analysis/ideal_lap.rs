@@ -0,0 +1,257 @@
+use crate::telemetry::TelemetryData;
+
+use super::Lap;
+
+/// Number of sectors a lap is split into
+pub(super) const SECTOR_COUNT: u8 = 3;
+/// Lap distance tolerance used when checking that a lap covered a whole sector
+const SECTOR_COVERAGE_TOLERANCE: f32 = 0.02;
+
+/// The lap that produced the best time for a sector
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct IdealSector {
+    pub(super) lap: usize,
+    pub(super) time_ms: u128,
+}
+
+/// Theoretical best lap stitched together from the best time in each sector.
+///
+/// The telemetry trace of each sector is copied from the lap that set the best
+/// sector time, so the result is synthetic and never matches a lap that was driven.
+/// Sectors without a valid lap are `None` and make the ideal lap incomplete.
+#[derive(Default, Clone, Debug)]
+pub(super) struct IdealLap {
+    pub(super) sectors: Vec<Option<IdealSector>>,
+    pub(super) telemetry: Vec<TelemetryData>,
+}
+
+impl IdealLap {
+    pub(super) fn from_laps(laps: &[Lap]) -> Self {
+        let mut ideal_lap = IdealLap::default();
+
+        for sector in 0..SECTOR_COUNT {
+            let best = laps
+                .iter()
+                .enumerate()
+                .filter_map(|(lap_no, lap)| {
+                    split_sector(lap, sector).map(|(time_ms, points)| (lap_no, time_ms, points))
+                })
+                .min_by_key(|(_, time_ms, _)| *time_ms);
+
+            match best {
+                Some((lap, time_ms, points)) => {
+                    ideal_lap.telemetry.extend_from_slice(points);
+                    ideal_lap.sectors.push(Some(IdealSector { lap, time_ms }));
+                }
+                None => ideal_lap.sectors.push(None),
+            }
+        }
+
+        ideal_lap
+    }
+
+    pub(super) fn is_complete(&self) -> bool {
+        !self.sectors.is_empty() && self.sectors.iter().all(Option::is_some)
+    }
+
+    /// Sum of the best sector times, only available when every sector has a valid lap
+    pub(super) fn time_ms(&self) -> Option<u128> {
+        self.sectors
+            .iter()
+            .map(|s| s.as_ref().map(|s| s.time_ms))
+            .sum()
+    }
+
+    /// 1-based numbers of the sectors without a valid lap
+    pub(super) fn missing_sectors(&self) -> Vec<usize> {
+        self.sectors
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.is_none())
+            .map(|(i, _)| i + 1)
+            .collect()
+    }
+}
+
+/// Returns the 0-based sector of a telemetry point.
+///
+/// Uses the sector reported by the game when available, otherwise splits the lap
+/// into `SECTOR_COUNT` equal parts by lap distance.
+pub(super) fn sector_of(point: &TelemetryData) -> Option<u8> {
+    if let Some(sector) = point.track_sector {
+        return Some(sector.saturating_sub(1).min(SECTOR_COUNT - 1));
+    }
+    let pct = point.lap_distance_pct?;
+    Some(((pct.clamp(0., 1.) * SECTOR_COUNT as f32) as u8).min(SECTOR_COUNT - 1))
+}
+
+/// Returns the time spent in a sector and its telemetry points, if the lap
+/// covered the whole sector.
+pub(super) fn split_sector(lap: &Lap, sector: u8) -> Option<(u128, &[TelemetryData])> {
+    let start = lap
+        .telemetry
+        .iter()
+        .position(|p| sector_of(p) == Some(sector))?;
+    let len = lap.telemetry[start..]
+        .iter()
+        .take_while(|p| sector_of(p) == Some(sector))
+        .count();
+    let points = &lap.telemetry[start..start + len];
+    let first = points.first()?;
+    let last = points.last()?;
+
+    // Make sure the lap did not start or end mid-sector
+    if first.track_sector.is_none() {
+        let sector_start = sector as f32 / SECTOR_COUNT as f32;
+        let sector_end = (sector + 1) as f32 / SECTOR_COUNT as f32;
+        if first.lap_distance_pct? > sector_start + SECTOR_COVERAGE_TOLERANCE
+            || last.lap_distance_pct? < sector_end - SECTOR_COVERAGE_TOLERANCE
+        {
+            return None;
+        }
+    } else {
+        // Game-reported sectors have no fixed bounds, a sector entered and left within
+        // the lap is whole, otherwise the lap has to start or end on the line
+        let lap_starts_in_sector = start == 0;
+        let lap_ends_in_sector = start + len == lap.telemetry.len();
+        if (lap_starts_in_sector && first.lap_distance_pct? > SECTOR_COVERAGE_TOLERANCE)
+            || (lap_ends_in_sector && last.lap_distance_pct? < 1. - SECTOR_COVERAGE_TOLERANCE)
+        {
+            return None;
+        }
+    }
+
+    let time_ms = last.timestamp_ms.checked_sub(first.timestamp_ms)?;
+    if time_ms == 0 {
+        return None;
+    }
+    Some((time_ms, points))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a full lap where each sector takes the given time in ms
+    fn lap(sector_times_ms: [u128; 3], throttle: f32) -> Lap {
+        let mut telemetry = Vec::new();
+        let mut timestamp_ms = 0;
+        for (sector, time_ms) in sector_times_ms.iter().enumerate() {
+            for step in 0..=10 {
+                let pct = (sector as f32 + step as f32 / 10. * 0.999) / 3.;
+                telemetry.push(TelemetryData {
+                    timestamp_ms: timestamp_ms + time_ms * step / 10,
+                    lap_distance_pct: Some(pct),
+                    throttle: Some(throttle),
+                    ..TelemetryData::default()
+                });
+            }
+            timestamp_ms += time_ms;
+        }
+        Lap {
+            telemetry,
+            ..Lap::default()
+        }
+    }
+
+    #[test]
+    fn test_ideal_lap_uses_best_sectors() {
+        let laps = vec![
+            lap([30_000, 40_000, 35_000], 0.1),
+            lap([31_000, 38_000, 36_000], 0.2),
+            lap([32_000, 39_000, 34_000], 0.3),
+        ];
+
+        let ideal_lap = IdealLap::from_laps(&laps);
+
+        assert!(ideal_lap.is_complete());
+        assert_eq!(
+            ideal_lap.sectors,
+            vec![
+                Some(IdealSector {
+                    lap: 0,
+                    time_ms: 30_000
+                }),
+                Some(IdealSector {
+                    lap: 1,
+                    time_ms: 38_000
+                }),
+                Some(IdealSector {
+                    lap: 2,
+                    time_ms: 34_000
+                }),
+            ]
+        );
+        assert_eq!(ideal_lap.time_ms(), Some(102_000));
+
+        // Each sector's trace comes from the lap that set the sector time
+        assert_eq!(ideal_lap.telemetry.len(), 33);
+        assert_eq!(ideal_lap.telemetry[0].throttle, Some(0.1));
+        assert_eq!(ideal_lap.telemetry[11].throttle, Some(0.2));
+        assert_eq!(ideal_lap.telemetry[22].throttle, Some(0.3));
+    }
+
+    #[test]
+    fn test_partial_laps_mark_ideal_incomplete() {
+        // Lap that starts mid-way through the first sector and stops in the second
+        let mut partial = lap([30_000, 40_000, 35_000], 0.5);
+        partial.telemetry.retain(|p| {
+            let pct = p.lap_distance_pct.unwrap();
+            pct > 0.2 && pct < 0.5
+        });
+
+        let ideal_lap = IdealLap::from_laps(&[partial]);
+
+        assert!(!ideal_lap.is_complete());
+        assert_eq!(ideal_lap.time_ms(), None);
+        assert_eq!(ideal_lap.missing_sectors(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_partial_game_reported_sectors_are_skipped() {
+        let with_game_sectors = |mut lap: Lap| {
+            for point in lap.telemetry.iter_mut() {
+                point.track_sector = Some(sector_of(point).unwrap() + 1);
+            }
+            lap
+        };
+        // Lap joined mid-way through the first sector
+        let mut partial = with_game_sectors(lap([20_000, 40_000, 35_000], 0.5));
+        partial
+            .telemetry
+            .retain(|p| p.lap_distance_pct.unwrap() > 0.2);
+        assert_eq!(
+            IdealLap::from_laps(std::slice::from_ref(&partial)).missing_sectors(),
+            vec![1]
+        );
+
+        // The shorter partial sector does not beat the full one
+        let full = with_game_sectors(lap([30_000, 40_000, 35_000], 0.1));
+        let ideal_lap = IdealLap::from_laps(&[partial, full]);
+        assert!(ideal_lap.is_complete());
+        assert_eq!(
+            ideal_lap.sectors[0],
+            Some(IdealSector {
+                lap: 1,
+                time_ms: 30_000
+            })
+        );
+    }
+
+    #[test]
+    fn test_game_reported_sectors_take_precedence() {
+        let point = TelemetryData {
+            track_sector: Some(3),
+            lap_distance_pct: Some(0.1),
+            ..TelemetryData::default()
+        };
+        assert_eq!(sector_of(&point), Some(2));
+
+        let point = TelemetryData {
+            lap_distance_pct: Some(0.5),
+            ..TelemetryData::default()
+        };
+        assert_eq!(sector_of(&point), Some(1));
+        assert_eq!(sector_of(&TelemetryData::default()), None);
+    }
+}
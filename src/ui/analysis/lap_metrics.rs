@@ -0,0 +1,49 @@
+use crate::telemetry::{
+    analyzer_config::AnalyzerConfig, coasting::CoastingStats, corner_speed::OverSlowing,
+    exit_efficiency::ExitEfficiency, grip_usage::GripUsage, steering_reversals::SteeringReversals,
+};
+
+use super::Lap;
+
+/// Readouts of the selected lap shown next to the lap selector, computed when the lap or
+/// the analyzer thresholds change instead of on every frame.
+pub(super) struct LapMetrics {
+    /// Track name of the session and index of the lap the metrics were computed for
+    pub(super) key: (String, usize),
+    pub(super) coasting: CoastingStats,
+    pub(super) grip: GripUsage,
+    pub(super) reversals: SteeringReversals,
+    /// Index of the best lap the corners are compared with, `None` on the best lap itself
+    pub(super) best_lap_index: Option<usize>,
+    /// `None` without a best lap to compare with
+    pub(super) over_slowing: Option<OverSlowing>,
+    pub(super) exit_efficiency: ExitEfficiency,
+}
+
+impl LapMetrics {
+    /// Measure `lap`, comparing its corners with `best` when it is another lap.
+    pub(super) fn from_lap(
+        key: (String, usize),
+        lap: &Lap,
+        best: Option<(&Lap, usize)>,
+        max_steering_angle: f32,
+        config: &AnalyzerConfig,
+    ) -> Self {
+        let detection = &config.corner_detection;
+        Self {
+            key,
+            coasting: CoastingStats::from_lap(&lap.telemetry, &config.coasting),
+            grip: GripUsage::from_lap(&lap.telemetry, config.grip_peak_g),
+            reversals: SteeringReversals::from_lap(&lap.telemetry, max_steering_angle),
+            best_lap_index: best.map(|(_, index)| index),
+            over_slowing: best.map(|(best, _)| {
+                OverSlowing::from_laps(&lap.telemetry, &best.telemetry, detection)
+            }),
+            exit_efficiency: ExitEfficiency::from_laps(
+                &lap.telemetry,
+                best.map(|(best, _)| best.telemetry.as_slice()),
+                detection,
+            ),
+        }
+    }
+}
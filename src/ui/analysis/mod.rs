@@ -1,35 +1,92 @@
-use std::{path::PathBuf, sync::Arc};
-
-use egui::{
-    Align, Color32, Direction, Frame, Label, Layout, Margin, RichText, Ui, Vec2b, Visuals,
-    style::Widgets,
+use std::{
+    io::{BufRead, BufReader},
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+        mpsc::{Receiver, TryRecvError},
+    },
+    thread,
+    time::{Duration, Instant},
 };
+
+use egui::{Align, Color32, Direction, Frame, Label, Layout, Margin, RichText, Ui, Vec2b};
 use egui_dropdown::DropDownBox;
-use egui_plot::{Legend, Line, PlotPoints, Points};
+use egui_plot::{AxisHints, HPlacement, Legend, Line, LineStyle, PlotPoints, Points, VLine};
 use itertools::Itertools;
 
 use crate::{
     OcypodeError,
-    telemetry::{SessionInfo, TelemetryAnnotation, TelemetryData, TelemetryOutput},
-    ui::live::{PALETTE_BLACK, PALETTE_BROWN, PALETTE_MAROON, PALETTE_ORANGE},
+    session_analysis::{LapFilter, SessionAnalysis, count_issues, group_sessions},
+    session_diff::{FindingChange, SessionDiff},
+    session_notes::SessionNotes,
+    telemetry::{
+        SessionInfo, TelemetryAnnotation, TelemetryData, TelemetryOutput, TireCompound,
+        TrackConditions,
+        analyzer_config::{AnalyzerConfig, AnalyzerKind},
+        corner_speed::OverSlowing,
+        corners::CornerWindow,
+        data_quality::DataQualityReport,
+        exit_efficiency::ExitEfficiency,
+        grip_usage::{DEFAULT_CONFIGURED_PEAK_G, GripUsage},
+        ibt::read_ibt_with_progress,
+        migration::RecordMigrator,
+        stint::{Stint, StintSummary},
+        tire_warmup::TireWarmup,
+    },
+    ui::{
+        live::{
+            config::{AppConfig, ChartLayout, ChartXAxis},
+            telemetry_view::format_pace,
+        },
+        theme::Theme,
+    },
 };
 
-use super::{Alert, DefaultAlert, ScrubSlipAlert, stroke_shade};
+use super::{Alert, DefaultAlert, ScrubSlipAlert, frame_stats::FrameStats};
+
+mod channels;
+mod corner_focus;
+mod ideal_lap;
+mod lap_metrics;
+mod playback;
+mod report;
+pub(crate) mod trends;
+
+use channels::ChannelScale;
+pub(crate) use channels::{ChartChannel, DEFAULT_CHART_CHANNELS};
+use corner_focus::{ALL_CORNERS_LABEL, CornerFocus, focus_corners};
+use ideal_lap::IdealLap;
+use lap_metrics::LapMetrics;
+use playback::Playback;
+use report::{LapReport, PendingReport, report_file_path, save_png};
+
+/// Comparison lap option for the synthetic lap built from the best sectors
+const IDEAL_LAP_LABEL: &str = "Ideal (synthetic)";
+/// Frames to wait for the screenshot of a lap report before giving up
+const MAX_REPORT_FRAMES: usize = 60;
 
 #[derive(Default, Clone, Debug)]
 struct TelemetryFile {
     sessions: Vec<Session>,
+    data_quality: DataQualityReport,
 }
 
 #[derive(Default, Clone, Debug)]
 struct Lap {
     telemetry: Vec<TelemetryData>,
+    /// Issues the analyzers reported in the lap, counted when the file is loaded
+    issue_count: usize,
 }
 
 #[derive(Default, Clone, Debug)]
 struct Session {
     info: SessionInfo,
     laps: Vec<Lap>,
+    ideal_lap: IdealLap,
+    stints: StintSummary,
+    /// `None` without cold tires, which needs tire temperatures
+    tire_warmup: Option<TireWarmup>,
 }
 
 #[derive(Clone)]
@@ -39,45 +96,159 @@ enum UiState {
     Display { session: Session },
 }
 
+/// Telemetry file being parsed on a background thread
+struct TelemetryLoader {
+    bytes_read: Arc<AtomicU64>,
+    total_bytes: u64,
+    result_receiver: Receiver<Result<TelemetryFile, OcypodeError>>,
+}
+
+impl TelemetryLoader {
+    fn start(source_file: PathBuf, lap_filter: LapFilter) -> Self {
+        let total_bytes = std::fs::metadata(&source_file)
+            .map(|m| m.len())
+            .unwrap_or(0);
+        let bytes_read = Arc::new(AtomicU64::new(0));
+        let (result_sender, result_receiver) = std::sync::mpsc::channel();
+
+        let thread_bytes_read = bytes_read.clone();
+        thread::spawn(move || {
+            let result = load_telemetry_file_with_progress(&source_file, &lap_filter, |bytes| {
+                thread_bytes_read.store(bytes, Ordering::Relaxed)
+            });
+            // the app may have been closed while loading
+            let _ = result_sender.send(result);
+        });
+
+        Self {
+            bytes_read,
+            total_bytes,
+            result_receiver,
+        }
+    }
+
+    /// Fraction of the file parsed so far, `None` if the file size is unknown
+    fn progress(&self) -> Option<f32> {
+        if self.total_bytes == 0 {
+            return None;
+        }
+        Some((self.bytes_read.load(Ordering::Relaxed) as f32 / self.total_bytes as f32).min(1.))
+    }
+}
+
 pub(crate) struct TelemetryAnalysisApp<'file> {
     source_file: &'file PathBuf,
+    app_config: AppConfig,
+    theme: Theme,
     ui_state: UiState,
+    loader: Option<TelemetryLoader>,
     data: Option<TelemetryFile>,
     selected_session: String,
     selected_lap: String,
     comparison_lap: String,
     selected_annotation_content: String,
     selected_x: Option<usize>,
+    /// Plays the selected lap back, moving `selected_x`
+    playback: Playback,
+    analyzer_config: AnalyzerConfig,
+    show_tuning: bool,
+    /// Selected lap re-annotated with `analyzer_config` while tuning
+    tuned_lap: Option<(usize, Lap)>,
+    tuning_status: String,
+    show_stints: bool,
+    /// Plot the combined g as a share of the grip peak
+    show_grip_usage: bool,
+    /// List the laps with the most issues first in the lap selector
+    sort_laps_by_issues: bool,
+    /// Label of the corner the chart and findings are narrowed to, empty for the whole lap
+    focus_corner: String,
+    /// Corners of the focus selector, keyed by track name and index of the lap they were
+    /// detected in
+    focus_corners: Option<((String, usize), Vec<(String, CornerWindow)>)>,
+    /// Findings of the focused corner across the laps of the session
+    corner_focus: Option<CornerFocus>,
+    /// Readouts of the selected lap
+    lap_metrics: Option<LapMetrics>,
+    /// Lap report drawn instead of the analysis panels until it is saved
+    pending_report: Option<PendingReport>,
+    report_status: String,
+    /// Notes of the sessions of the file, saved next to it
+    session_notes: SessionNotes,
+    /// Earlier session file being analyzed on a background thread
+    baseline_loader: Option<Receiver<Result<Vec<SessionAnalysis>, OcypodeError>>>,
+    /// Sessions of the earlier file the findings are compared with
+    baseline: Vec<SessionAnalysis>,
+    baseline_status: String,
+    /// Findings of the selected session against the baseline, keyed by track name
+    session_diff: Option<(String, SessionDiff)>,
+    frame_stats: FrameStats,
 }
 
 impl<'file> TelemetryAnalysisApp<'file> {
-    pub(crate) fn from_file(input: &'file PathBuf, cc: &eframe::CreationContext<'_>) -> Self {
-        let default_visuals = Visuals {
-            dark_mode: true,
-            hyperlink_color: PALETTE_MAROON,
-            faint_bg_color: PALETTE_BLACK,
-            extreme_bg_color: PALETTE_BROWN,
-            panel_fill: PALETTE_BLACK,
-            button_frame: true,
-            window_fill: PALETTE_BLACK,
-            widgets: Widgets::dark(),
-            striped: false,
-            ..Default::default()
-        };
-        cc.egui_ctx.set_visuals(default_visuals);
+    pub(crate) fn from_file(
+        input: &'file PathBuf,
+        app_config: AppConfig,
+        cc: &eframe::CreationContext<'_>,
+    ) -> Self {
+        let theme = app_config.theme.theme();
+        cc.egui_ctx.set_visuals(theme.visuals(theme.background));
+        let session_notes = SessionNotes::load(input).unwrap_or_else(|e| {
+            log::error!("Could not read the session notes of {:?}: {}", input, e);
+            SessionNotes::default()
+        });
         Self {
             source_file: input,
+            app_config,
+            theme,
             ui_state: UiState::Loading,
+            loader: None,
             data: None,
             selected_session: "".to_string(),
             selected_lap: "".to_string(),
             comparison_lap: "".to_string(),
             selected_annotation_content: "".to_string(),
             selected_x: None,
+            playback: Playback::default(),
+            analyzer_config: AnalyzerConfig::from_local_file().unwrap_or_default(),
+            show_tuning: false,
+            tuned_lap: None,
+            tuning_status: "".to_string(),
+            show_stints: false,
+            show_grip_usage: false,
+            sort_laps_by_issues: false,
+            focus_corner: "".to_string(),
+            focus_corners: None,
+            corner_focus: None,
+            lap_metrics: None,
+            pending_report: None,
+            report_status: "".to_string(),
+            session_notes,
+            baseline_loader: None,
+            baseline: Vec::new(),
+            baseline_status: "".to_string(),
+            session_diff: None,
+            frame_stats: FrameStats::default(),
         }
     }
 
-    fn show_selectors(&mut self, ui: &mut Ui) {
+    /// Compare the findings of the loaded sessions with the sessions of `baseline`, an
+    /// earlier telemetry file, analyzed on a background thread.
+    pub(crate) fn with_baseline(mut self, baseline: PathBuf) -> Self {
+        let (result_sender, result_receiver) = std::sync::mpsc::channel();
+        let lap_filter = self.app_config.lap_filter;
+        thread::spawn(move || {
+            let result = SessionAnalysis::from_file_with_lap_filter(&baseline, &lap_filter);
+            // the app may have been closed while loading
+            let _ = result_sender.send(result);
+        });
+        self.baseline_loader = Some(result_receiver);
+        self.baseline_status = "Analyzing the baseline...".to_string();
+        self
+    }
+
+    /// Session and lap selectors, returns true when a report of the selected lap was requested.
+    fn show_selectors(&mut self, ui: &mut Ui) -> bool {
+        let mut export_report = false;
         ui.with_layout(Layout::left_to_right(egui::Align::Center), |ui| {
             let sessions = self
                 .data
@@ -107,18 +278,128 @@ impl<'file> TelemetryAnalysisApp<'file> {
             {
                 ui.separator();
                 ui.label(RichText::new("Lap: ").color(Color32::WHITE));
-                let laps_iter = (0..selected_session.laps.len())
-                    .map(|l| l.to_string())
+                let laps = &selected_session.laps;
+                let max_issues = laps.iter().map(|l| l.issue_count).max().unwrap_or(0);
+                // rejected laps are left empty to keep the lap indexes, they are not listed
+                let mut lap_order = (0..laps.len())
+                    .filter(|l| !laps[*l].telemetry.is_empty())
                     .collect_vec();
+                if self.sort_laps_by_issues {
+                    lap_order.sort_by_key(|l| std::cmp::Reverse(laps[*l].issue_count));
+                }
+                let laps_iter = lap_order.iter().map(|l| l.to_string()).collect_vec();
+                let theme = self.theme;
                 ui.add(
                     DropDownBox::from_iter(
                         laps_iter,
                         "lap_dropbox",
                         &mut self.selected_lap,
-                        |ui, text| ui.selectable_label(false, text),
+                        |ui, text| lap_entry(ui, text, laps, max_issues, &theme),
                     )
                     .filter_by_input(false),
                 );
+                ui.toggle_value(&mut self.sort_laps_by_issues, "⚠ Sort")
+                    .on_hover_text("List the laps with the most issues first");
+
+                let corners = self
+                    .focus_corners
+                    .as_ref()
+                    .filter(|((track_name, _), _)| *track_name == selected_session.info.track_name)
+                    .map(|(_, corners)| corners.as_slice())
+                    .unwrap_or_default();
+                if !corners.is_empty() {
+                    ui.separator();
+                    ui.label(RichText::new("Focus: ").color(Color32::WHITE));
+                    let corner_labels = std::iter::once(ALL_CORNERS_LABEL.to_string())
+                        .chain(corners.iter().map(|(label, _)| label.clone()))
+                        .collect_vec();
+                    ui.add(
+                        DropDownBox::from_iter(
+                            corner_labels,
+                            "focus_corner_dropbox",
+                            &mut self.focus_corner,
+                            |ui, text| ui.selectable_label(false, text),
+                        )
+                        .filter_by_input(false),
+                    )
+                    .on_hover_text(
+                        "Narrow the chart and the setup findings to one corner across all laps",
+                    );
+                }
+
+                if let Ok(selected_lap) = self.selected_lap.parse::<usize>()
+                    && let Some(metrics) = self.cached_lap_metrics(selected_session, selected_lap)
+                {
+                    let coasting = &metrics.coasting;
+                    let excessive_corners =
+                        coasting.corners.iter().filter(|c| c.is_excessive()).count();
+                    ui.separator();
+                    ui.label(
+                        RichText::new(format!(
+                            "Coasting: {:.1}% ({:.1}% excessive, {} of {} corners)",
+                            coasting.coasting_pct(),
+                            coasting.excessive_coasting_pct(),
+                            excessive_corners,
+                            coasting.corners.len()
+                        ))
+                        .color(Color32::WHITE),
+                    );
+
+                    let reversals = &metrics.reversals;
+                    if reversals.duration_ms > 0 {
+                        ui.separator();
+                        ui.label(
+                            RichText::new(format!(
+                                "Steering reversals: {} ({:.1}/s)",
+                                reversals.reversals,
+                                reversals.reversals_per_s()
+                            ))
+                            .color(Color32::WHITE),
+                        )
+                        .on_hover_text(
+                            "Direction changes of the steering wheel, few reversals mean smooth hands",
+                        );
+                    }
+
+                    let grip = &metrics.grip;
+                    if grip.peak_g > 0. {
+                        ui.separator();
+                        ui.label(
+                            RichText::new(format!(
+                                "Grip: {:.0}% of {:.2} g {} ({} of {} corners under-used)",
+                                grip.mean_corner_usage_pct(),
+                                grip.peak_g,
+                                if grip.is_peak_observed {
+                                    "observed"
+                                } else {
+                                    "configured"
+                                },
+                                grip.under_used_corners(),
+                                grip.corners.len()
+                            ))
+                            .color(Color32::WHITE),
+                        );
+                    }
+
+                    if let Some(over_slowing) = &metrics.over_slowing
+                        && let Some(best_lap_index) = metrics.best_lap_index
+                    {
+                        self.show_over_slowing(ui, over_slowing, best_lap_index);
+                    }
+                    self.show_exit_efficiency(ui, &metrics.exit_efficiency, metrics.best_lap_index);
+                }
+
+                if let Some(note) = selected_session
+                    .tire_warmup
+                    .as_ref()
+                    .and_then(TireWarmup::coaching_note)
+                {
+                    ui.separator();
+                    ui.label(RichText::new(note).color(Color32::WHITE))
+                        .on_hover_text(
+                            "Laps before the average tire temperature reached the bottom of the optimal range, with the slip, scrub, wheelspin, lock and oversteer rate on them compared to the warm laps",
+                        );
+                }
             }
 
             if let Some(selected_session) = self
@@ -131,9 +412,13 @@ impl<'file> TelemetryAnalysisApp<'file> {
             {
                 ui.separator();
                 ui.label(RichText::new("Comparison lap: ").color(Color32::WHITE));
-                let laps_iter = (0..selected_session.laps.len())
+                let mut laps_iter = (0..selected_session.laps.len())
+                    .filter(|l| !selected_session.laps[*l].telemetry.is_empty())
                     .map(|l| l.to_string())
                     .collect_vec();
+                if !selected_session.ideal_lap.telemetry.is_empty() {
+                    laps_iter.push(IDEAL_LAP_LABEL.to_string());
+                }
                 ui.add(
                     DropDownBox::from_iter(
                         laps_iter,
@@ -143,140 +428,1202 @@ impl<'file> TelemetryAnalysisApp<'file> {
                     )
                     .filter_by_input(false),
                 );
+
+                if self.comparison_lap == IDEAL_LAP_LABEL {
+                    let ideal_lap = &selected_session.ideal_lap;
+                    let ideal_text = match ideal_lap.time_ms() {
+                        Some(time_ms) => format!(
+                            "Ideal lap: {:.3}s from laps {}",
+                            time_ms as f64 / 1000.,
+                            ideal_lap
+                                .sectors
+                                .iter()
+                                .flatten()
+                                .map(|s| s.lap.to_string())
+                                .join(", ")
+                        ),
+                        None => format!(
+                            "Ideal lap incomplete, no valid lap for sector {}",
+                            ideal_lap.missing_sectors().iter().join(", ")
+                        ),
+                    };
+                    ui.label(RichText::new(ideal_text).color(Color32::WHITE));
+                }
+
+                let selected_lap = self
+                    .selected_lap
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|lap_no| selected_session.laps.get(lap_no));
+                let lap_distance_available =
+                    selected_lap.is_some_and(|lap| has_lap_distance(&lap.telemetry));
+                ui.separator();
+                ui.label(RichText::new("X axis: ").color(Color32::WHITE));
+                let prev_x_axis = self.app_config.analysis_x_axis;
+                ui.selectable_value(
+                    &mut self.app_config.analysis_x_axis,
+                    ChartXAxis::PointNumber,
+                    "Point",
+                );
+                ui.add_enabled_ui(lap_distance_available, |ui| {
+                    ui.selectable_value(
+                        &mut self.app_config.analysis_x_axis,
+                        ChartXAxis::LapDistance,
+                        "Lap distance",
+                    )
+                    .on_disabled_hover_text("The selected lap has no lap distance data");
+                });
+                if self.app_config.analysis_x_axis != prev_x_axis
+                    && let Err(e) = self.app_config.save()
+                {
+                    log::error!("Failed to save config after changing the chart axis: {}", e);
+                }
+
+                ui.separator();
+                ui.label(RichText::new("Layout: ").color(Color32::WHITE));
+                let prev_layout = self.app_config.analysis_chart_layout;
+                ui.selectable_value(
+                    &mut self.app_config.analysis_chart_layout,
+                    ChartLayout::Overlay,
+                    "Overlay",
+                );
+                ui.add_enabled_ui(!self.comparison_lap.is_empty(), |ui| {
+                    ui.selectable_value(
+                        &mut self.app_config.analysis_chart_layout,
+                        ChartLayout::Split,
+                        "Split",
+                    )
+                    .on_disabled_hover_text("Select a comparison lap to split the chart");
+                });
+                if self.app_config.analysis_chart_layout != prev_layout
+                    && let Err(e) = self.app_config.save()
+                {
+                    log::error!(
+                        "Failed to save config after changing the chart layout: {}",
+                        e
+                    );
+                }
+
+                ui.separator();
+                let prev_channels = self.app_config.analysis_chart_channels.clone();
+                ui.menu_button("Channels", |ui| {
+                    for channel in ChartChannel::ALL {
+                        let available =
+                            selected_lap.is_some_and(|lap| channel.is_available(&lap.telemetry));
+                        let mut shown =
+                            self.app_config.analysis_chart_channels.contains(&channel);
+                        let response = ui
+                            .add_enabled(available, egui::Checkbox::new(&mut shown, channel.to_string()))
+                            .on_disabled_hover_text(format!(
+                                "The selected lap has no {} data",
+                                channel.to_string().to_lowercase()
+                            ));
+                        if response.changed() {
+                            // keep the channels in the order of the picker
+                            let mut channels = prev_channels.clone();
+                            channels.retain(|c| *c != channel);
+                            if shown {
+                                channels.push(channel);
+                            }
+                            self.app_config.analysis_chart_channels = ChartChannel::ALL
+                                .into_iter()
+                                .filter(|c| channels.contains(c))
+                                .collect();
+                        }
+                    }
+                });
+                if self.app_config.analysis_chart_channels != prev_channels
+                    && let Err(e) = self.app_config.save()
+                {
+                    log::error!(
+                        "Failed to save config after changing the chart channels: {}",
+                        e
+                    );
+                }
+                ui.toggle_value(&mut self.show_grip_usage, "Grip usage");
+            }
+
+            ui.separator();
+            ui.toggle_value(&mut self.show_stints, "Stint view");
+            ui.toggle_value(&mut self.show_tuning, "Tune analyzers");
+
+            ui.separator();
+            let lap_selected = self.selected_lap.parse::<usize>().is_ok();
+            export_report = ui
+                .add_enabled(lap_selected, egui::Button::new("Export report"))
+                .on_hover_text("Save a one page summary of the selected lap as a PNG image")
+                .on_disabled_hover_text("Select a lap to export its report")
+                .clicked();
+            if !self.report_status.is_empty() {
+                ui.label(RichText::new(&self.report_status).color(Color32::WHITE));
+            }
+        });
+        export_report
+    }
+
+    /// Build the report of the selected lap, it is drawn and saved over the next frames.
+    fn start_report_export(&mut self, session: &Session) {
+        let Some((lap_no, lap)) = self
+            .selected_lap
+            .parse::<usize>()
+            .ok()
+            .and_then(|lap_no| Some((lap_no, session.laps.get(lap_no)?)))
+        else {
+            return;
+        };
+        let lap_time_ms = session
+            .stints
+            .stints
+            .iter()
+            .flat_map(|stint| stint.laps.iter())
+            .find(|l| l.lap_index == lap_no)
+            .map(|l| l.lap_time_ms);
+        let best_lap = session.stints.best_lap().and_then(|best| {
+            let best_lap = session.laps.get(best.lap_index)?;
+            Some((best.lap_index, best_lap.telemetry.as_slice()))
+        });
+        self.report_status = "Exporting report...".to_string();
+        self.pending_report = Some(PendingReport {
+            report: LapReport::from_lap(
+                lap_no,
+                &lap.telemetry,
+                lap_time_ms,
+                best_lap,
+                &session.info,
+                &self.analyzer_config,
+            ),
+            path: report_file_path(self.source_file, lap_no),
+            frames_drawn: 0,
+        });
+    }
+
+    /// Draw the pending report as a single page and save the screenshot of it once it
+    /// comes back, returns the report if it is still waiting for the screenshot.
+    fn show_pending_report(
+        &mut self,
+        ctx: &egui::Context,
+        mut pending: PendingReport,
+        session: &Session,
+    ) -> Option<PendingReport> {
+        let screenshot = ctx.input(|i| {
+            i.raw.events.iter().find_map(|event| match event {
+                egui::Event::Screenshot { image, .. } => Some(image.clone()),
+                _ => None,
+            })
+        });
+        if let Some(image) = screenshot {
+            self.report_status = match save_png(&image, &pending.path) {
+                Ok(()) => format!("Report saved to {}", pending.path.display()),
+                Err(e) => {
+                    log::error!("Could not export the lap report: {}", e);
+                    format!("Could not export the report: {}", e)
+                }
+            };
+            return None;
+        }
+
+        egui::CentralPanel::default()
+            .frame(
+                Frame::default()
+                    .fill(ctx.style().visuals.panel_fill)
+                    .inner_margin(Margin::same(10)),
+            )
+            .show(ctx, |ui| {
+                show_session_header(&session.info, ui);
+                ui.separator();
+                pending.report.show(ui, self.app_config.speed_unit);
+                let lap = pending.report.lap;
+                self.show_telemetry_chart(lap, session, ui);
+            });
+
+        // the first frame lays the page out, capture the second one
+        pending.frames_drawn += 1;
+        if pending.frames_drawn == 2 {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot(Default::default()));
+        } else if pending.frames_drawn > MAX_REPORT_FRAMES {
+            self.report_status = "Could not export the report: no screenshot received".to_string();
+            return None;
+        }
+        ctx.request_repaint();
+        Some(pending)
+    }
+
+    /// Editable notes of the selected session, saved next to the file when the editor
+    /// loses focus.
+    fn show_session_notes(&mut self, ui: &mut Ui) {
+        let session = self
+            .data
+            .as_ref()
+            .and_then(|data| {
+                data.sessions
+                    .iter()
+                    .position(|s| s.info.track_name == self.selected_session)
+            })
+            .unwrap_or(0);
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Notes:").color(Color32::GRAY));
+            let notes = ui.add(
+                egui::TextEdit::multiline(self.session_notes.get_mut(session))
+                    .desired_rows(1)
+                    .desired_width(f32::INFINITY)
+                    .hint_text("Setup, fuel, track conditions..."),
+            );
+            if notes.lost_focus()
+                && let Err(e) = self.session_notes.save(self.source_file)
+            {
+                log::error!("Could not save the session notes: {}", e);
+            }
+        });
+    }
+
+    /// Developer window to adjust analyzer thresholds, returns true if any changed.
+    fn show_tuning_window(&mut self, ctx: &egui::Context) -> bool {
+        let mut open = self.show_tuning;
+        let mut changed = false;
+        egui::Window::new("Analyzer tuning")
+            .open(&mut open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                changed = self.show_tuning_controls(ui);
+            });
+        self.show_tuning = open;
+        changed
+    }
+
+    fn show_tuning_controls(&mut self, ui: &mut Ui) -> bool {
+        let config = &mut self.analyzer_config;
+        let mut changed = false;
+        ui.label("Annotations of the selected lap are recomputed with these thresholds");
+        egui::Grid::new("analyzer_tuning")
+            .num_columns(2)
+            .show(ui, |ui| {
+                let mut row = |ui: &mut Ui, label: &str, slider: egui::Slider| {
+                    ui.label(label);
+                    changed |= ui.add(slider).changed();
+                    ui.end_row();
+                };
+                row(
+                    ui,
+                    "Steering free play (of full lock)",
+                    egui::Slider::new(&mut config.steering_free_play_pct, 0.0..=0.5),
+                );
+                row(
+                    ui,
+                    "Steering linearity",
+                    egui::Slider::new(&mut config.steering_linearity, 0.5..=3.0),
+                );
+                row(
+                    ui,
+                    "Slip steering deadzone (rad)",
+                    egui::Slider::new(&mut config.slip_steering_deadzone_rad, 0.0..=0.5),
+                );
+                row(
+                    ui,
+                    "Slip min speed loss (m/s)",
+                    egui::Slider::new(&mut config.slip_min_speed_loss_mps, 0.0..=3.0),
+                );
+                row(
+                    ui,
+                    "Scrub min points",
+                    egui::Slider::new(&mut config.scrub_min_points, 10..=500),
+                );
+                row(
+                    ui,
+                    "Scrub temperature rise (°C)",
+                    egui::Slider::new(&mut config.scrub_temp_threshold, 0.0..=20.0),
+                );
+                row(
+                    ui,
+                    "Yaw rate smoothing (samples)",
+                    egui::Slider::new(&mut config.yaw_rate_smoothing_samples, 1..=20),
+                );
+                row(
+                    ui,
+                    "Wheelspin RPM growth margin",
+                    egui::Slider::new(&mut config.wheelspin_rpm_growth_margin, 0.0..=1.0),
+                );
+                row(
+                    ui,
+                    "Trail braking max steering (rad)",
+                    egui::Slider::new(&mut config.trailbrake_max_steering_angle, 0.0..=0.5),
+                );
+                row(
+                    ui,
+                    "Trail braking min brake",
+                    egui::Slider::new(&mut config.trailbrake_min_brake_pct, 0.0..=1.0),
+                );
+                row(
+                    ui,
+                    "Max brake release rate (/s)",
+                    egui::Slider::new(&mut config.trailbrake_max_brake_release_rate, 0.0..=10.0),
+                );
+                row(
+                    ui,
+                    "Short shift sensitivity (RPM)",
+                    egui::Slider::new(&mut config.short_shift_sensitivity_rpm, 0.0..=1000.0),
+                );
+                row(
+                    ui,
+                    "Shift point tolerance (RPM)",
+                    egui::Slider::new(&mut config.shift_rpm_tolerance, 0.0..=1000.0),
+                );
+                row(
+                    ui,
+                    "Pedal overlap min pedal",
+                    egui::Slider::new(&mut config.pedal_overlap_min_pct, 0.0..=1.0),
+                );
+                row(
+                    ui,
+                    "Pedal overlap min duration (ms)",
+                    egui::Slider::new(&mut config.pedal_overlap_min_duration_ms, 0..=2000),
+                );
+                row(
+                    ui,
+                    "Slow load transfer (ms)",
+                    egui::Slider::new(&mut config.load_transfer_slow_ms, 100..=2000),
+                );
+                row(
+                    ui,
+                    "Abrupt load transfer (ms)",
+                    egui::Slider::new(&mut config.load_transfer_abrupt_ms, 0..=500),
+                );
+                row(
+                    ui,
+                    "Over-rev margin (RPM)",
+                    egui::Slider::new(&mut config.over_rev_margin_rpm, 0.0..=1000.0),
+                );
+            });
+
+        // only the grip usage readout depends on the peak, annotations are unchanged
+        ui.horizontal(|ui| {
+            let mut configured = config.grip_peak_g.is_some();
+            if ui
+                .checkbox(&mut configured, "Grip peak (g)")
+                .on_hover_text("Unchecked uses the peak observed in the selected lap")
+                .changed()
+            {
+                config.grip_peak_g = configured.then_some(DEFAULT_CONFIGURED_PEAK_G);
+                changed = true;
+            }
+            if let Some(peak_g) = config.grip_peak_g.as_mut() {
+                changed |= ui.add(egui::Slider::new(peak_g, 0.5..=5.0)).changed();
+            }
+        });
+
+        // corners are only used by the per-corner readouts, annotations are unchanged
+        ui.collapsing("Corner detection", |ui| {
+            let detection = &mut config.corner_detection;
+            egui::Grid::new("corner_detection")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    let mut row = |ui: &mut Ui, label: &str, slider: egui::Slider| {
+                        ui.label(label);
+                        changed |= ui.add(slider).changed();
+                        ui.end_row();
+                    };
+                    row(
+                        ui,
+                        "Min steering",
+                        egui::Slider::new(&mut detection.min_steering_pct, 0.0..=1.0),
+                    );
+                    row(
+                        ui,
+                        "Min lateral g",
+                        egui::Slider::new(&mut detection.min_lateral_g, 0.1..=3.0),
+                    );
+                    row(
+                        ui,
+                        "Min duration (ms)",
+                        egui::Slider::new(&mut detection.min_duration_ms, 0..=3000),
+                    );
+                });
+        });
+
+        // coasting is only used by the lap readouts, annotations are unchanged
+        ui.collapsing("Coasting", |ui| {
+            let coasting = &mut config.coasting;
+            egui::Grid::new("coasting_detection")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    let mut row = |ui: &mut Ui, label: &str, slider: egui::Slider| {
+                        ui.label(label);
+                        changed |= ui.add(slider).changed();
+                        ui.end_row();
+                    };
+                    row(
+                        ui,
+                        "Max throttle",
+                        egui::Slider::new(&mut coasting.max_throttle, 0.0..=0.5),
+                    );
+                    row(
+                        ui,
+                        "Max brake",
+                        egui::Slider::new(&mut coasting.max_brake, 0.0..=0.5),
+                    );
+                    row(
+                        ui,
+                        "Min speed (m/s)",
+                        egui::Slider::new(&mut coasting.min_speed_mps, 0.0..=30.0),
+                    );
+                    row(
+                        ui,
+                        "Apex transition (ms)",
+                        egui::Slider::new(&mut coasting.max_apex_transition_ms, 0..=2000),
+                    );
+                    row(
+                        ui,
+                        "Corner min steering",
+                        egui::Slider::new(&mut coasting.corner_min_steering_pct, 0.0..=1.0),
+                    );
+                });
+        });
+
+        ui.collapsing("Enabled analyzers", |ui| {
+            egui::Grid::new("analyzer_toggles")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    for (i, kind) in AnalyzerKind::ALL.into_iter().enumerate() {
+                        let mut enabled = config.is_enabled(kind);
+                        if ui.checkbox(&mut enabled, kind.to_string()).changed() {
+                            config.set_enabled(kind, enabled);
+                            changed = true;
+                        }
+                        if i % 2 == 1 {
+                            ui.end_row();
+                        }
+                    }
+                });
+        });
+
+        ui.horizontal(|ui| {
+            if ui.button("Reset to defaults").clicked() {
+                self.analyzer_config = AnalyzerConfig::default();
+                changed = true;
+            }
+            if ui.button("Export").clicked() {
+                self.tuning_status = match self.analyzer_config.save() {
+                    Ok(path) => {
+                        format!("Saved to {}, used by the next live session", path.display())
+                    }
+                    Err(e) => format!("Could not save analyzer config: {}", e),
+                };
+            }
+        });
+        if !self.tuning_status.is_empty() {
+            ui.label(&self.tuning_status);
+        }
+        changed
+    }
+
+    /// Corners where the apex was much slower than on the best lap, with coaching hints
+    /// for each of them on hover.
+    fn show_over_slowing(&self, ui: &mut Ui, over_slowing: &OverSlowing, best_lap_index: usize) {
+        if over_slowing.corners.is_empty() {
+            return;
+        }
+        let speed_unit = self.app_config.speed_unit;
+        let over_slowed = over_slowing.over_slowed_corners().collect_vec();
+        let summary = match over_slowing.max_deficit_mps() {
+            Some(max_deficit_mps) => format!(
+                "Over-slowing vs lap {}: {} of {} corners, up to {:.0} {} at apex",
+                best_lap_index,
+                over_slowed.len(),
+                over_slowing.corners.len(),
+                speed_unit.speed_from_mps(max_deficit_mps),
+                speed_unit
+            ),
+            None => format!("No over-slowing vs lap {}", best_lap_index),
+        };
+
+        ui.separator();
+        let response = ui.label(RichText::new(summary).color(Color32::WHITE));
+        if !over_slowed.is_empty() {
+            let hints = over_slowed
+                .iter()
+                .map(|c| {
+                    format!(
+                        "{:.0}% of the lap: apex {:.0} {} slower, brake less or release earlier",
+                        c.corner.apex_lap_distance_pct.unwrap_or(0.) * 100.,
+                        speed_unit.speed_from_mps(c.deficit_mps()),
+                        speed_unit
+                    )
+                })
+                .join("\n");
+            response.on_hover_text(hints);
+        }
+    }
+
+    /// Exit efficiency of the lap's corners, against the best lap unless it is the lap
+    /// itself. Hover for each corner, low exits are marked.
+    fn show_exit_efficiency(
+        &self,
+        ui: &mut Ui,
+        efficiency: &ExitEfficiency,
+        best_lap_index: Option<usize>,
+    ) {
+        let Some(mean_efficiency) = efficiency.mean_efficiency() else {
+            return;
+        };
+        let low_corners = efficiency.low_corners().count();
+        let summary = match best_lap_index {
+            Some(best_lap_index) => format!(
+                "Exit efficiency: {:.0}% ({} of {} corners low vs lap {})",
+                mean_efficiency * 100.,
+                low_corners,
+                efficiency.corners.len(),
+                best_lap_index
+            ),
+            None => format!(
+                "Exit efficiency: {:.0}% ({} of {} corners low)",
+                mean_efficiency * 100.,
+                low_corners,
+                efficiency.corners.len()
+            ),
+        };
+        let hints = efficiency
+            .corners
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let mut hint = format!(
+                    "{}T{} at {:.0}% of the lap: {:.0}%",
+                    if c.is_low() { "⚠ " } else { "" },
+                    i + 1,
+                    c.exit.corner.apex_lap_distance_pct.unwrap_or(0.) * 100.,
+                    c.exit.efficiency * 100.
+                );
+                if let Some(reference_efficiency) = c.reference_efficiency {
+                    hint.push_str(&format!(
+                        " against {:.0}% on the best lap",
+                        reference_efficiency * 100.
+                    ));
+                }
+                hint
+            })
+            .join("\n");
+
+        ui.separator();
+        ui.label(RichText::new(summary).color(Color32::WHITE))
+            .on_hover_text(format!(
+                "Throttle over the exit phase of each corner, 100% is flat out through the exit\n{}",
+                hints
+            ));
+    }
+
+    /// Re-run the analyzers over the selected lap when tuning starts, the lap changes,
+    /// or a threshold changes.
+    fn refresh_tuned_lap(&mut self, session: &Session, config_changed: bool) {
+        let Some(lap_no) = self
+            .selected_lap
+            .parse::<usize>()
+            .ok()
+            .filter(|_| self.show_tuning)
+        else {
+            self.tuned_lap = None;
+            return;
+        };
+        if !config_changed && self.tuned_lap.as_ref().is_some_and(|(n, _)| *n == lap_no) {
+            return;
+        }
+
+        self.tuned_lap = session.laps.get(lap_no).map(|lap| {
+            let mut lap = lap.clone();
+            self.analyzer_config
+                .annotate(&mut lap.telemetry, &session.info);
+            (lap_no, lap)
+        });
+    }
+
+    /// Measure the selected lap when the lap, the session or the analyzer thresholds
+    /// change.
+    fn refresh_lap_metrics(&mut self, session: &Session, config_changed: bool) {
+        let Some((lap_no, lap)) = self
+            .selected_lap
+            .parse::<usize>()
+            .ok()
+            .and_then(|lap_no| Some((lap_no, session.laps.get(lap_no)?)))
+        else {
+            self.lap_metrics = None;
+            return;
+        };
+        let key = (session.info.track_name.clone(), lap_no);
+        if !config_changed && self.lap_metrics.as_ref().is_some_and(|m| m.key == key) {
+            return;
+        }
+        let best = session
+            .stints
+            .best_lap()
+            .filter(|best_lap| best_lap.lap_index != lap_no)
+            .and_then(|best_lap| Some((session.laps.get(best_lap.lap_index)?, best_lap.lap_index)));
+        self.lap_metrics = Some(LapMetrics::from_lap(
+            key,
+            lap,
+            best,
+            session.info.max_steering_angle,
+            &self.analyzer_config,
+        ));
+    }
+
+    /// Metrics of `lap_no` in `session`, when they are the ones cached for the selected lap.
+    fn cached_lap_metrics(&self, session: &Session, lap_no: usize) -> Option<&LapMetrics> {
+        self.lap_metrics
+            .as_ref()
+            .filter(|m| m.key.0 == session.info.track_name && m.key.1 == lap_no)
+    }
+
+    /// Window of the corner picked in the focus selector, `None` when the whole lap is shown.
+    fn focused_corner(&self, session: &Session) -> Option<CornerWindow> {
+        if self.focus_corner.is_empty() || self.focus_corner == ALL_CORNERS_LABEL {
+            return None;
+        }
+        let ((track_name, _), corners) = self.focus_corners.as_ref()?;
+        if *track_name != session.info.track_name {
+            return None;
+        }
+        corners
+            .iter()
+            .find(|(label, _)| *label == self.focus_corner)
+            .map(|(_, window)| *window)
+    }
+
+    /// Detect the corners of the focus selector when the reference lap, the session or the
+    /// analyzer thresholds change.
+    fn refresh_focus_corners(&mut self, session: &Session, config_changed: bool) {
+        let Some((lap_no, lap)) = focus_reference_lap(session, &self.selected_lap) else {
+            self.focus_corners = None;
+            return;
+        };
+        let key = (session.info.track_name.clone(), lap_no);
+        if !config_changed
+            && self
+                .focus_corners
+                .as_ref()
+                .is_some_and(|(cached, _)| *cached == key)
+        {
+            return;
+        }
+        let corners = focus_corners(&lap.telemetry, &self.analyzer_config.corner_detection);
+        self.focus_corners = Some((key, corners));
+        // the same label can be a different corner of the new reference lap
+        self.corner_focus = None;
+    }
+
+    /// Gather the findings of the focused corner when the focus, the session or the
+    /// analyzer thresholds change.
+    fn refresh_corner_focus(&mut self, session: &Session, config_changed: bool) {
+        let Some(window) = self.focused_corner(session) else {
+            self.corner_focus = None;
+            return;
+        };
+        let key = (session.info.track_name.clone(), self.focus_corner.clone());
+        if !config_changed && self.corner_focus.as_ref().is_some_and(|f| f.key == key) {
+            return;
+        }
+        self.corner_focus = Some(CornerFocus::from_laps(key, &session.laps, &window));
+    }
+
+    /// Pick up the analysis of the baseline once it is ready, and compare the findings of
+    /// the session with it when the baseline arrives or the session changes.
+    fn refresh_session_diff(&mut self, session: &Session) {
+        if let Some(receiver) = &self.baseline_loader {
+            match receiver.try_recv() {
+                Ok(Ok(baseline)) => {
+                    self.baseline_loader = None;
+                    self.baseline_status = "".to_string();
+                    self.baseline = baseline;
+                    self.session_diff = None;
+                }
+                Ok(Err(e)) => {
+                    self.baseline_loader = None;
+                    self.baseline_status = format!("Could not analyze the baseline: {}", e);
+                }
+                Err(TryRecvError::Disconnected) => {
+                    self.baseline_loader = None;
+                    self.baseline_status =
+                        "Could not analyze the baseline: loader stopped unexpectedly".to_string();
+                }
+                Err(TryRecvError::Empty) => {}
             }
+        }
+        if self.baseline.is_empty()
+            || self
+                .session_diff
+                .as_ref()
+                .is_some_and(|(key, _)| *key == session.info.track_name)
+        {
+            return;
+        }
+        let laps = session
+            .laps
+            .iter()
+            .map(|lap| lap.telemetry.clone())
+            .collect_vec();
+        let analysis =
+            SessionAnalysis::from_laps(session.info.clone(), &laps, &self.analyzer_config);
+        self.session_diff = SessionDiff::against_baseline(&self.baseline, &analysis)
+            .map(|diff| (session.info.track_name.clone(), diff));
+    }
+
+    /// Collapsible list of the findings resolved, decreased or appeared since the baseline.
+    fn show_session_diff(&self, ui: &mut Ui) {
+        if !self.baseline_status.is_empty() {
+            ui.label(&self.baseline_status);
+            return;
+        }
+        let Some((_, diff)) = &self.session_diff else {
+            return;
+        };
+        egui::CollapsingHeader::new(format!(
+            "Findings vs baseline ({} improved, {} worsened)",
+            diff.improved().count(),
+            diff.worsened().count()
+        ))
+        .show(ui, |ui| {
+            egui::Grid::new("session_diff_findings")
+                .num_columns(3)
+                .striped(true)
+                .show(ui, |ui| {
+                    for delta in &diff.findings {
+                        let color = match delta.change {
+                            FindingChange::Resolved | FindingChange::Decreased => Color32::GREEN,
+                            FindingChange::Unchanged => Color32::WHITE,
+                            FindingChange::Increased | FindingChange::Appeared => Color32::RED,
+                        };
+                        ui.label(delta.finding_type.to_string());
+                        ui.label(RichText::new(delta.change.to_string()).color(color));
+                        ui.label(format!(
+                            "{:.1} -> {:.1} per lap",
+                            delta.before_per_lap, delta.after_per_lap
+                        ));
+                        ui.end_row();
+                    }
+                });
         });
     }
 
+    /// Collapsible summary of which telemetry fields are present in the loaded file.
+    fn show_data_quality(&self, ui: &mut Ui) {
+        let Some(report) = self.data.as_ref().map(|d| &d.data_quality) else {
+            return;
+        };
+        egui::CollapsingHeader::new("Data quality").show(ui, |ui| {
+            ui.label(format!(
+                "Game: {}, {} points",
+                report
+                    .game_source
+                    .map(|g| format!("{:?}", g))
+                    .unwrap_or_else(|| "unknown".to_string()),
+                report.point_count
+            ));
+            egui::ScrollArea::vertical()
+                .max_height(200.)
+                .show(ui, |ui| {
+                    egui::Grid::new("data_quality_fields")
+                        .num_columns(2)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for coverage in &report.fields {
+                                let color = if coverage.present_pct == 0. {
+                                    Color32::RED
+                                } else if coverage.present_pct < 100. {
+                                    Color32::ORANGE
+                                } else {
+                                    Color32::WHITE
+                                };
+                                ui.label(coverage.field);
+                                ui.label(
+                                    RichText::new(format!("{:.1}%", coverage.present_pct))
+                                        .color(color),
+                                );
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+    }
+
+    /// Resolve the selected comparison lap to its telemetry and a legend label.
+    fn comparison_telemetry<'s>(
+        &self,
+        session: &'s Session,
+    ) -> Option<(&'s [TelemetryData], &'static str)> {
+        if self.comparison_lap == IDEAL_LAP_LABEL {
+            let label = if session.ideal_lap.is_complete() {
+                "Synthetic Ideal"
+            } else {
+                "Synthetic Ideal (incomplete)"
+            };
+            Some((session.ideal_lap.telemetry.as_slice(), label))
+        } else {
+            self.comparison_lap
+                .parse::<usize>()
+                .ok()
+                .and_then(|lap_no| session.laps.get(lap_no))
+                .map(|lap| (lap.telemetry.as_slice(), "Comparison"))
+        }
+    }
+
+    /// Lap times and tire temperatures of each stint with their trend lines.
+    fn show_stint_chart(&self, session: &Session, ui: &mut Ui) {
+        if session.stints.stints.is_empty() {
+            ui.label(RichText::new("No complete laps outside the pit lane").color(Color32::WHITE));
+            return;
+        }
+
+        if let Some(pace) = session.stints.pace(self.app_config.pace_laps) {
+            ui.label(
+                RichText::new(format!(
+                    "Pace over the last {} valid laps: {}",
+                    pace.laps,
+                    format_pace(&pace)
+                ))
+                .color(Color32::WHITE),
+            );
+        }
+
+        for (stint_no, stint) in session.stints.stints.iter().enumerate() {
+            let rate = |value: Option<f64>, unit: &str| match value {
+                Some(value) => format!("{:+.3}{}/lap", value, unit),
+                None => "n/a".to_string(),
+            };
+            ui.label(
+                RichText::new(format!(
+                    "Stint {}: laps {}-{}, {} valid, lap time {}, tire temp {}",
+                    stint_no + 1,
+                    stint.laps.first().map_or(0, |l| l.lap_index),
+                    stint.laps.last().map_or(0, |l| l.lap_index),
+                    stint.laps.len(),
+                    rate(stint.degradation_s_per_lap(), "s"),
+                    rate(stint.tire_temp_change_per_lap(), "°C"),
+                ))
+                .color(Color32::WHITE),
+            );
+        }
+
+        let theme = self.theme;
+        let plot_height = ui.available_height() / 2.;
+        egui_plot::Plot::new("stint_lap_times")
+            .height(plot_height)
+            .show_background(false)
+            .legend(Legend::default())
+            .y_axis_label("Lap time (s)")
+            .show(ui, |plot_ui| {
+                for (stint_no, stint) in session.stints.stints.iter().enumerate() {
+                    plot_ui.points(
+                        Points::new(
+                            format!("Stint {} lap time", stint_no + 1),
+                            PlotPoints::new(
+                                stint
+                                    .laps
+                                    .iter()
+                                    .map(|l| [l.lap_index as f64, l.lap_time_ms as f64 / 1000.])
+                                    .collect(),
+                            ),
+                        )
+                        .color(theme.throttle)
+                        .radius(4.),
+                    );
+                    if let Some(trend) = stint.lap_time_trend {
+                        plot_ui.line(
+                            Line::new(
+                                format!("Stint {} lap time trend", stint_no + 1),
+                                PlotPoints::new(trend_points(stint, |i| trend.at(i) / 1000.)),
+                            )
+                            .color(theme.comparison_throttle),
+                        );
+                    }
+                }
+            });
+        egui_plot::Plot::new("stint_tire_temps")
+            .show_background(false)
+            .legend(Legend::default())
+            .x_axis_label("Lap")
+            .y_axis_label("Avg tire temp (°C)")
+            .show(ui, |plot_ui| {
+                for (stint_no, stint) in session.stints.stints.iter().enumerate() {
+                    plot_ui.points(
+                        Points::new(
+                            format!("Stint {} tire temp", stint_no + 1),
+                            PlotPoints::new(
+                                stint
+                                    .laps
+                                    .iter()
+                                    .filter_map(|l| {
+                                        Some([l.lap_index as f64, l.avg_tire_temp? as f64])
+                                    })
+                                    .collect(),
+                            ),
+                        )
+                        .color(theme.brake)
+                        .radius(4.),
+                    );
+                    if let Some(trend) = stint.tire_temp_trend {
+                        plot_ui.line(
+                            Line::new(
+                                format!("Stint {} tire temp trend", stint_no + 1),
+                                PlotPoints::new(trend_points(stint, |i| trend.at(i))),
+                            )
+                            .color(theme.comparison_brake),
+                        );
+                    }
+                }
+            });
+    }
+
     fn show_telemetry_chart(&mut self, selected_lap: usize, session: &Session, ui: &mut Ui) {
         ui.with_layout(Layout::centered_and_justified(Direction::TopDown), |ui| {
-            let plot = egui_plot::Plot::new("measurements");
+            let theme = self.theme;
             //println!("Selected lap = {}", selected_lap);
             if let Some(lap) = session.laps.get(selected_lap) {
-                let mut throttle_vec = Vec::<[f64; 2]>::new();
-                let mut brake_vec = Vec::<[f64; 2]>::new();
-                let mut steering_vec = Vec::<[f64; 2]>::new();
-                let mut annotations_vec = Vec::<[f64; 2]>::new();
-
-                lap.telemetry.iter().enumerate().all(|p| {
-                    let throttle = p.1.throttle.unwrap_or(0.0);
-                    let brake = p.1.brake.unwrap_or(0.0);
-                    let steering_pct = p.1.steering_pct.unwrap_or(0.0);
-                    throttle_vec.push([p.0 as f64, throttle as f64 * 100.]);
-                    brake_vec.push([p.0 as f64, brake as f64 * 100.]);
-                    steering_vec.push([p.0 as f64, 50. + 50. * steering_pct as f64]);
-                    if !p.1.annotations.is_empty() {
-                        annotations_vec.push([p.0 as f64, 101.]);
+                let comparison = self.comparison_telemetry(session);
+                // Fall back to point numbers when any plotted lap is missing the lap distance
+                let use_lap_distance = self.app_config.analysis_x_axis == ChartXAxis::LapDistance
+                    && has_lap_distance(&lap.telemetry)
+                    && comparison.is_none_or(|(telemetry, _)| has_lap_distance(telemetry));
+                let x_of = |point_no: usize, point: &TelemetryData| {
+                    if use_lap_distance {
+                        point.lap_distance_pct.unwrap_or(0.) as f64 * 100.
+                    } else {
+                        point_no as f64
                     }
-                    true
-                });
+                };
+                // only the points of the focused corner are plotted, on every lap
+                let focus = self.focused_corner(session);
+                let in_focus = |point: &TelemetryData| {
+                    focus.is_none_or(|window| {
+                        point.lap_distance_pct.is_some_and(|d| window.contains(d))
+                    })
+                };
+                // separate plot ids so each axis and corner keeps its own bounds
+                let (plot_id, min_x, max_x) = match focus {
+                    Some(window) if use_lap_distance => (
+                        format!("measurements_lap_distance_{}", self.focus_corner),
+                        window.start_lap_distance_pct as f64 * 100.,
+                        window.end_lap_distance_pct as f64 * 100.,
+                    ),
+                    Some(_) => {
+                        let (first, last) = lap
+                            .telemetry
+                            .iter()
+                            .positions(in_focus)
+                            .minmax()
+                            .into_option()
+                            .unwrap_or((0, 0));
+                        (
+                            format!("measurements_{}", self.focus_corner),
+                            first as f64,
+                            last as f64,
+                        )
+                    }
+                    None if use_lap_distance => ("measurements_lap_distance".to_string(), 0., 100.),
+                    // TODO: make this dynamic based on window size
+                    None => ("measurements".to_string(), 0., 250.),
+                };
 
-                let throttle_points = PlotPoints::new(throttle_vec);
-                let brake_points = PlotPoints::new(brake_vec);
-                let steering_points = PlotPoints::new(steering_vec);
-                let annotation_points = PlotPoints::new(annotations_vec);
-
-                let plot_response = plot
-                    .show_background(false)
-                    .legend(Legend::default())
-                    .include_y(0.)
-                    .include_y(150.)
-                    .include_x(0.)
-                    .include_x(250.) // TODO: make this dynamic based on window size
-                    .auto_bounds(Vec2b::new(false, false))
-                    .show(ui, |plot_ui| {
-                        plot_ui.line(
-                            Line::new("Throttle", throttle_points)
-                                .color(Color32::GREEN)
-                                .fill(0.),
+                // every channel is scaled on its own over the points of both laps
+                let speed_unit = self.app_config.speed_unit;
+                let scales: Vec<(ChartChannel, ChannelScale)> = self
+                    .app_config
+                    .analysis_chart_channels
+                    .iter()
+                    .map(|channel| {
+                        let points = lap.telemetry.iter().chain(
+                            comparison
+                                .iter()
+                                .flat_map(|(telemetry, _)| telemetry.iter()),
                         );
-                        plot_ui.line(
-                            Line::new("Brake", brake_points)
-                                .gradient_color(
-                                    Arc::new(|point| {
-                                        stroke_shade(
-                                            PALETTE_ORANGE,
-                                            Color32::RED,
-                                            (point.y / 100.) as f32,
-                                        )
-                                    }),
-                                    true,
-                                )
-                                .color(Color32::RED)
-                                .fill(0.),
+                        let scale = ChannelScale::fit(
+                            *channel,
+                            session.info.max_steering_angle,
+                            speed_unit,
+                            points,
+                        );
+                        (*channel, scale)
+                    })
+                    .collect();
+                let chart = |id: &str| {
+                    let mut y_axes = vec![AxisHints::new_y()];
+                    for (channel, scale) in scales.iter().copied() {
+                        let Some(label) = channel.axis_label(speed_unit) else {
+                            continue;
+                        };
+                        y_axes.push(
+                            AxisHints::new_y()
+                                .label(label)
+                                .placement(HPlacement::Right)
+                                .formatter(move |mark, _range| {
+                                    if (0. ..=100.).contains(&mark.value) {
+                                        channel.format_value(scale.from_chart(mark.value))
+                                    } else {
+                                        String::new()
+                                    }
+                                }),
                         );
+                    }
+                    egui_plot::Plot::new(id)
+                        .show_background(false)
+                        .legend(Legend::default())
+                        .custom_y_axes(y_axes)
+                        .include_y(0.)
+                        .include_y(150.)
+                        .include_x(min_x)
+                        .include_x(max_x)
+                        .auto_bounds(Vec2b::new(false, false))
+                };
+                let channel_points =
+                    |telemetry: &[TelemetryData], channel: ChartChannel, scale: ChannelScale| {
+                        PlotPoints::new(
+                            telemetry
+                                .iter()
+                                .enumerate()
+                                .filter(|(_, p)| in_focus(p))
+                                .filter_map(|(i, p)| {
+                                    let value = channel.value(p, speed_unit)?;
+                                    Some([x_of(i, p), scale.to_chart(value)])
+                                })
+                                .collect(),
+                        )
+                    };
+
+                let annotation_points = PlotPoints::new(
+                    lap.telemetry
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, p)| in_focus(p) && !p.annotations.is_empty())
+                        .map(|(i, p)| [x_of(i, p), 101.])
+                        .collect(),
+                );
+
+                // grip usage shares the 0-100 scale, points of under-used corners are highlighted
+                // the report can chart a lap other than the selected one
+                let computed_grip;
+                let grip = match self.cached_lap_metrics(session, selected_lap) {
+                    Some(metrics) => &metrics.grip,
+                    None => {
+                        computed_grip =
+                            GripUsage::from_lap(&lap.telemetry, self.analyzer_config.grip_peak_g);
+                        &computed_grip
+                    }
+                };
+                let grip_usage_vec: Vec<[f64; 2]> = lap
+                    .telemetry
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| in_focus(*p))
+                    .filter_map(|(i, p)| Some([x_of(i, p), grip.usage_pct(i)? as f64]))
+                    .collect();
+                let under_used_vec: Vec<[f64; 2]> = grip
+                    .corners
+                    .iter()
+                    .filter(|c| c.is_under_used())
+                    .flat_map(|c| c.start_point..=c.end_point)
+                    .filter(|i| in_focus(&lap.telemetry[*i]))
+                    .filter_map(|i| Some([x_of(i, &lap.telemetry[i]), grip.usage_pct(i)? as f64]))
+                    .collect();
+                let show_grip_usage = self.show_grip_usage;
+                let playhead_x = self
+                    .selected_x
+                    .and_then(|x| Some(x_of(x, lap.telemetry.get(x)?)));
+                let follow_playhead = self.playback.is_playing();
+
+                let plot_lap = |plot_ui: &mut egui_plot::PlotUi| {
+                    for (channel, scale) in scales.iter().copied() {
+                        plot_ui.line(channel.line(
+                            channel.to_string(),
+                            channel_points(&lap.telemetry, channel, scale),
+                            &theme,
+                            false,
+                        ));
+                    }
+                    if show_grip_usage {
                         plot_ui.line(
-                            Line::new("Steering", steering_points).color(Color32::LIGHT_GRAY),
+                            Line::new("Grip usage", PlotPoints::new(grip_usage_vec))
+                                .color(theme.speed)
+                                .style(LineStyle::dotted_dense()),
                         );
                         plot_ui.points(
-                            Points::new("Annotation", annotation_points)
-                                .color(Color32::BLUE)
-                                .radius(10.),
+                            Points::new("Unused grip", PlotPoints::new(under_used_vec))
+                                .color(theme.speed)
+                                .radius(3.),
                         );
-
-                        if !self.comparison_lap.is_empty()
-                            && let Some(comparison_lap) = session
-                                .laps
-                                .get(self.comparison_lap.parse::<usize>().unwrap())
-                        {
-                            let comparison_throttle_points = PlotPoints::new(
-                                comparison_lap
-                                    .telemetry
-                                    .iter()
-                                    .enumerate()
-                                    .map(|t| {
-                                        let throttle = t.1.throttle.unwrap_or(0.0);
-                                        [t.0 as f64, throttle as f64 * 100.]
-                                    })
-                                    .collect(),
-                            );
-                            let comparison_brake_points = PlotPoints::new(
-                                comparison_lap
-                                    .telemetry
-                                    .iter()
-                                    .enumerate()
-                                    .map(|t| {
-                                        let brake = t.1.brake.unwrap_or(0.0);
-                                        [t.0 as f64, brake as f64 * 100.]
-                                    })
-                                    .collect(),
-                            );
-                            let comparison_steering_points = PlotPoints::new(
-                                comparison_lap
-                                    .telemetry
-                                    .iter()
-                                    .enumerate()
-                                    .map(|t| {
-                                        let steering_pct = t.1.steering_pct.unwrap_or(0.0);
-                                        [t.0 as f64, 50. + 50. * steering_pct as f64]
-                                    })
-                                    .collect(),
-                            );
-
-                            plot_ui.line(
-                                Line::new("Comparison Throttle", comparison_throttle_points)
-                                    .color(Color32::DARK_GREEN),
-                            );
-                            plot_ui.line(
-                                Line::new("Comparison Brake", comparison_brake_points)
-                                    .color(Color32::DARK_RED),
-                            );
-                            plot_ui.line(
-                                Line::new("Comparison Steering", comparison_steering_points)
-                                    .color(Color32::DARK_GRAY.gamma_multiply(0.3)),
-                            );
+                    }
+                    plot_ui.points(
+                        Points::new("Annotation", annotation_points)
+                            .color(theme.annotation)
+                            .radius(10.),
+                    );
+                    if let Some(x) = playhead_x {
+                        plot_ui.vline(VLine::new("Selected", x).color(Color32::WHITE));
+                        // keep the playhead in view while playing, a page at a time
+                        let bounds = plot_ui.plot_bounds();
+                        if follow_playhead && !bounds.range_x().contains(&x) {
+                            let width = bounds.width();
+                            plot_ui.set_plot_bounds_x(x - width * 0.1..=x + width * 0.9);
                         }
-                    });
-                if plot_response.response.clicked()
-                    && let Some(mouse_pos) = plot_response.response.interact_pointer_pos()
+                    }
+                };
+                let plot_comparison = |plot_ui: &mut egui_plot::PlotUi| {
+                    let Some((comparison_telemetry, comparison_label)) = comparison else {
+                        return;
+                    };
+                    for (channel, scale) in scales.iter().copied() {
+                        if !channel.is_available(comparison_telemetry) {
+                            continue;
+                        }
+                        plot_ui.line(channel.line(
+                            format!("{} {}", comparison_label, channel),
+                            channel_points(comparison_telemetry, channel, scale),
+                            &theme,
+                            true,
+                        ));
+                    }
+                };
+
+                let mut responses = Vec::new();
+                if self.app_config.analysis_chart_layout == ChartLayout::Split
+                    && comparison.is_some()
                 {
-                    self.selected_annotation_content = "".to_string();
-                    self.selected_x = Some(
-                        plot_response
-                            .transform
-                            .value_from_position(mouse_pos)
-                            .x
-                            .floor() as usize,
-                    );
+                    // both charts share the x-range, zoom, pan and cursor through the link group
+                    let link_group = egui::Id::new(&plot_id).with("split");
+                    let height = ui.available_height() / 2.;
+                    ui.with_layout(Layout::top_down_justified(Align::Min), |ui| {
+                        responses.push(
+                            chart(&plot_id)
+                                .height(height)
+                                .link_axis(link_group, Vec2b::new(true, false))
+                                .link_cursor(link_group, Vec2b::new(true, false))
+                                .show(ui, plot_lap),
+                        );
+                        responses.push(
+                            chart(&format!("{}_comparison", plot_id))
+                                .height(height)
+                                .link_axis(link_group, Vec2b::new(true, false))
+                                .link_cursor(link_group, Vec2b::new(true, false))
+                                .show(ui, plot_comparison),
+                        );
+                    });
+                } else {
+                    responses.push(chart(&plot_id).show(ui, |plot_ui| {
+                        plot_lap(plot_ui);
+                        plot_comparison(plot_ui);
+                    }));
+                }
+
+                for plot_response in responses {
+                    if plot_response.response.clicked()
+                        && let Some(mouse_pos) = plot_response.response.interact_pointer_pos()
+                    {
+                        self.selected_annotation_content = "".to_string();
+                        let x = plot_response.transform.value_from_position(mouse_pos).x;
+                        self.selected_x = if use_lap_distance {
+                            point_at_lap_distance(&lap.telemetry, x / 100.)
+                        } else {
+                            Some(x.floor() as usize)
+                        };
+                        if let Some(point) = self.selected_x {
+                            self.playback.seek(&lap.telemetry, point);
+                        }
+                    }
                 }
             }
         });
@@ -285,43 +1632,137 @@ impl<'file> TelemetryAnalysisApp<'file> {
 
 impl eframe::App for TelemetryAnalysisApp<'_> {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        let frame_start = Instant::now();
         egui_extras::install_image_loaders(ctx);
+        // input triggers a repaint on its own, pace the rest at the configured refresh rate
+        // so the loading progress and state changes show up without spinning the CPU
+        ctx.request_repaint_after(Duration::from_millis(
+            self.app_config.effective_refresh_rate_ms() as u64,
+        ));
         let cur_ui_state = self.ui_state.clone();
         match cur_ui_state {
             UiState::Loading => {
-                if self.data.is_none() {
-                    let telemetry_load_result = load_telemetry_jsonl(self.source_file);
-                    if telemetry_load_result.is_err() {
+                let loader = self.loader.get_or_insert_with(|| {
+                    TelemetryLoader::start(self.source_file.clone(), self.app_config.lap_filter)
+                });
+                match loader.result_receiver.try_recv() {
+                    Ok(Ok(telemetry_file)) => {
+                        self.loader = None;
+                        let Some(first_session) = telemetry_file.sessions.first().cloned() else {
+                            self.ui_state = UiState::Error {
+                                message: "Could not load telemetry: no sessions in file"
+                                    .to_string(),
+                            };
+                            ctx.request_repaint();
+                            return;
+                        };
+                        self.data = Some(telemetry_file);
+                        self.ui_state = UiState::Display {
+                            session: first_session,
+                        };
+                    }
+                    Ok(Err(e)) => {
+                        self.loader = None;
                         self.ui_state = UiState::Error {
-                            message: format!(
-                                "Could not load telemetry: {}",
-                                telemetry_load_result.err().unwrap()
-                            ),
+                            message: format!("Could not load telemetry: {}", e),
                         };
-                        return;
                     }
-                    self.data = Some(telemetry_load_result.unwrap());
-                    self.ui_state = UiState::Display {
-                        session: self
-                            .data
-                            .as_ref()
-                            .unwrap()
-                            .sessions
-                            .first()
-                            .unwrap()
-                            .clone(),
+                    Err(TryRecvError::Disconnected) => {
+                        self.loader = None;
+                        self.ui_state = UiState::Error {
+                            message: "Could not load telemetry: loader stopped unexpectedly"
+                                .to_string(),
+                        };
+                    }
+                    Err(TryRecvError::Empty) => {
+                        let progress = loader.progress();
+                        egui::CentralPanel::default().show(ctx, |ui| {
+                            ui.with_layout(Layout::top_down(Align::Center), |ui| {
+                                ui.add_space(ui.available_height() / 3.);
+                                ui.heading(
+                                    RichText::new("Loading telemetry...").color(Color32::WHITE),
+                                );
+                                ui.add_space(10.);
+                                match progress {
+                                    Some(progress) => {
+                                        ui.add(
+                                            egui::ProgressBar::new(progress)
+                                                .desired_width(ui.available_width() / 2.)
+                                                .show_percentage(),
+                                        );
+                                    }
+                                    None => {
+                                        ui.spinner();
+                                    }
+                                }
+                            });
+                        });
+                        // the loader thread does not wake the UI, poll it until it is done
+                        ctx.request_repaint();
                     }
                 }
             }
-            UiState::Display { session } => {
-                egui::TopBottomPanel::top("SessionSelector")
+            UiState::Display { mut session } => {
+                // the selectors pick a session by its track, the first one until then
+                if let Some(selected) = self.data.as_ref().and_then(|data| {
+                    data.sessions
+                        .iter()
+                        .find(|s| s.info.track_name == self.selected_session)
+                }) {
+                    session = selected.clone();
+                }
+                // the tuning window would cover the report
+                let tuning_changed = self.pending_report.is_none() && self.show_tuning_window(ctx);
+                self.refresh_tuned_lap(&session, tuning_changed);
+                if let Some((lap_no, lap)) = &self.tuned_lap
+                    && let Some(recorded_lap) = session.laps.get_mut(*lap_no)
+                {
+                    *recorded_lap = lap.clone();
+                }
+                self.refresh_lap_metrics(&session, tuning_changed);
+                self.refresh_focus_corners(&session, tuning_changed);
+                self.refresh_corner_focus(&session, tuning_changed);
+                self.refresh_session_diff(&session);
+                let playback_lap = self
+                    .selected_lap
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|_| !self.show_stints)
+                    .and_then(|lap_no| Some((lap_no, session.laps.get(lap_no)?)));
+                if let Some((lap_no, lap)) = playback_lap
+                    && let Some(point) = self.playback.tick(lap_no, &lap.telemetry, Instant::now())
+                {
+                    self.selected_x = Some(point);
+                }
+                if self.playback.is_playing() {
+                    ctx.request_repaint();
+                }
+
+                if let Some(pending) = self.pending_report.take() {
+                    self.pending_report = self.show_pending_report(ctx, pending, &session);
+                    return;
+                }
+
+                let export_report = egui::TopBottomPanel::top("SessionSelector")
+                    .frame(
+                        Frame::default()
+                            .fill(Color32::TRANSPARENT)
+                            .inner_margin(Margin::same(5)),
+                    )
+                    .show(ctx, |local_ui| self.show_selectors(local_ui))
+                    .inner;
+                if export_report {
+                    self.start_report_export(&session);
+                }
+                egui::TopBottomPanel::top("SessionHeader")
                     .frame(
                         Frame::default()
                             .fill(Color32::TRANSPARENT)
                             .inner_margin(Margin::same(5)),
                     )
                     .show(ctx, |local_ui| {
-                        self.show_selectors(local_ui);
+                        show_session_header(&session.info, local_ui);
+                        self.show_session_notes(local_ui);
                     });
                 egui::SidePanel::right("AnnotationDetail")
                     .frame(
@@ -333,27 +1774,47 @@ impl eframe::App for TelemetryAnalysisApp<'_> {
                     .min_width(ctx.available_rect().width() * 0.3)
                     .max_width(ctx.available_rect().height() / 7.)
                     .show(ctx, |local_ui| {
+                        if let Some(corner_focus) = &self.corner_focus {
+                            corner_focus.show(local_ui);
+                            local_ui.separator();
+                        }
                         if let Ok(selected_lap) = self.selected_lap.parse::<usize>() {
                             if let Some(x_point) = self.selected_x && let Some(lap) = session.laps.get(selected_lap) && let Some(telemetry) = lap.telemetry.get(x_point) {
                                         let mut abs_alert = DefaultAlert::abs().button();
                                         let mut shift_alert = DefaultAlert::shift().button();
                                         let mut traction_alert = DefaultAlert::traction().button();
                                         let mut trailbrake_steering_alert = DefaultAlert::trailbrake_steering().button();
-                                        let mut slip_alert = ScrubSlipAlert::default().button();
+                                        let mut slip_alert = ScrubSlipAlert::default().button().with_priority(self.app_config.annotation_priority.clone());
 
                                         let _ = abs_alert.update_state(telemetry);
                                         let _ = shift_alert.update_state(telemetry);
                                         let _ = traction_alert.update_state(telemetry);
                                         let _ = trailbrake_steering_alert.update_state(telemetry);
                                         let _ = slip_alert.update_state(telemetry);
+                                        let most_important = self.app_config.annotation_priority.most_important(&telemetry.annotations);
+                                        let accent = self.theme.accent;
 
                                         local_ui.with_layout(Layout::top_down(Align::Center), |ui| {
-                                            if abs_alert.show(ui, Align::Center).clicked() {
+                                            if abs_alert.show_prioritized(ui, Align::Center, most_important, accent).clicked() {
                                                 let brake = telemetry.brake.unwrap_or(0.0);
                                                 self.selected_annotation_content = format!("brake force: {:.2}", brake);
+                                                if let Some(TelemetryAnnotation::FrontBrakeLock { peak_application_rate, is_initial_application_lock, .. }) =
+                                                    telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::FrontBrakeLock { .. })) {
+                                                        self.selected_annotation_content.push_str(&format!(
+                                                            "\nPeak application rate: {:.1}/s\n{}",
+                                                            peak_application_rate,
+                                                            if *is_initial_application_lock {
+                                                                "Locked while stomping the pedal, brake more progressively"
+                                                            } else {
+                                                                "Locked under sustained pressure"
+                                                            }
+                                                        ));
+                                                }
                                             };
                                             ui.separator();
-                                            if shift_alert.show(ui, Align::Center).clicked() && let Some(TelemetryAnnotation::ShortShifting { gear_change_rpm, optimal_rpm, .. }) =
+                                            if shift_alert.show_prioritized(ui, Align::Center, most_important, accent).clicked() {
+                                                self.selected_annotation_content.clear();
+                                                if let Some(TelemetryAnnotation::ShortShifting { gear_change_rpm, optimal_rpm, .. }) =
                                                     telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::ShortShifting { .. })) {
                                                         let cur_gear = telemetry.gear.unwrap_or(0);
                                                         self.selected_annotation_content = format!(
@@ -363,20 +1824,58 @@ impl eframe::App for TelemetryAnalysisApp<'_> {
                                                             optimal_rpm,
                                                             gear_change_rpm
                                                         )
+                                                }
+                                                if let Some(TelemetryAnnotation::ShiftPoint { from_gear, shift_rpm, optimal_rpm, is_power_curve_optimal, is_over_revving, .. }) =
+                                                    telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::ShiftPoint { .. })) {
+                                                        if !self.selected_annotation_content.is_empty() {
+                                                            self.selected_annotation_content.push('\n');
+                                                        }
+                                                        self.selected_annotation_content.push_str(&format!(
+                                                            "Upshift from gear: {}\nOptimal RPM ({}): {:.0}\nShift RPM: {:.0}\n{}",
+                                                            from_gear,
+                                                            if *is_power_curve_optimal { "power curve" } else { "estimated" },
+                                                            optimal_rpm,
+                                                            shift_rpm,
+                                                            if *is_over_revving { "Over-revving" } else { "Under-revving" }
+                                                        ));
+                                                }
+                                                if let Some(TelemetryAnnotation::OverRev { peak_rpm, limit_rpm, duration_ms, is_money_shift, .. }) =
+                                                    telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::OverRev { .. })) {
+                                                        if !self.selected_annotation_content.is_empty() {
+                                                            self.selected_annotation_content.push('\n');
+                                                        }
+                                                        self.selected_annotation_content.push_str(&format!(
+                                                            "Over-rev: {:.0} RPM, {:.0} over the limit for {}ms\n{}",
+                                                            peak_rpm,
+                                                            peak_rpm - limit_rpm,
+                                                            duration_ms,
+                                                            if *is_money_shift {
+                                                                "Money shift, the downshift came too early and risks the engine"
+                                                            } else {
+                                                                "Upshift earlier to stay off the limiter"
+                                                            }
+                                                        ));
+                                                }
                                             }
                                             ui.separator();
-                                            if traction_alert.show(ui, Align::Center).clicked() && let Some(TelemetryAnnotation::Wheelspin { avg_rpm_increase_per_gear, cur_gear, cur_rpm_increase, .. }) =
+                                            if traction_alert.show_prioritized(ui, Align::Center, most_important, accent).clicked() && let Some(TelemetryAnnotation::Wheelspin { avg_rpm_increase_per_gear, cur_gear, cur_rpm_increase, slip_ratio, .. }) =
                                                     telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::Wheelspin { .. })) {
+                                                        // detected from the wheel slip ratios, the gear may not have an RPM history yet
                                                         self.selected_annotation_content = format!(
-                                                            "Gear: {}\nRPM increase: {:.1}\np90 RPM increase: {:.1}\nRPM increase per gear:\n{}",
+                                                            "Gear: {}\nRPM increase: {:.1}\np90 RPM increase: {}\nRPM increase per gear:\n{}",
                                                             cur_gear,
                                                             cur_rpm_increase,
-                                                            avg_rpm_increase_per_gear.get(cur_gear).unwrap(),
+                                                            avg_rpm_increase_per_gear.get(cur_gear).map_or("-".to_string(), |avg| format!("{:.1}", avg)),
                                                             serde_json::to_string_pretty(avg_rpm_increase_per_gear).unwrap()
                                                         );
+                                                        if let Some(slip_ratio) = slip_ratio {
+                                                            self.selected_annotation_content += &format!("\nSlip ratio: {:.1}%", slip_ratio * 100.);
+                                                        }
                                             }
                                             ui.separator();
-                                            if trailbrake_steering_alert.show(ui, Align::Center).clicked() && let Some(TelemetryAnnotation::TrailbrakeSteering { cur_trailbrake_steering, .. }) =
+                                            if trailbrake_steering_alert.show_prioritized(ui, Align::Center, most_important, accent).clicked() {
+                                                self.selected_annotation_content.clear();
+                                                if let Some(TelemetryAnnotation::TrailbrakeSteering { cur_trailbrake_steering, .. }) =
                                                     telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::TrailbrakeSteering { .. })) {
                                                         let steering = telemetry.steering_angle_rad.unwrap_or(0.0);
                                                         self.selected_annotation_content = format!(
@@ -384,9 +1883,74 @@ impl eframe::App for TelemetryAnalysisApp<'_> {
                                                             cur_trailbrake_steering,
                                                             steering
                                                         );
+                                                }
+                                                if let Some(TelemetryAnnotation::TrailbrakeRelease { brake_release_rate, is_abrupt_release }) =
+                                                    telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::TrailbrakeRelease { .. })) {
+                                                        if !self.selected_annotation_content.is_empty() {
+                                                            self.selected_annotation_content.push('\n');
+                                                        }
+                                                        self.selected_annotation_content.push_str(&format!(
+                                                            "Brake release rate: {:.2}/s\n{}",
+                                                            brake_release_rate,
+                                                            if *is_abrupt_release {
+                                                                "Abrupt release, trail off the brake more smoothly into the apex"
+                                                            } else {
+                                                                "Smooth release"
+                                                            }
+                                                        ));
+                                                }
+                                                if let Some(TelemetryAnnotation::EntrySteeringOvershoot { peak_steering_pct, unwound_steering_pct, lateral_accel_mps2, .. }) =
+                                                    telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::EntrySteeringOvershoot { .. })) {
+                                                        if !self.selected_annotation_content.is_empty() {
+                                                            self.selected_annotation_content.push('\n');
+                                                        }
+                                                        self.selected_annotation_content.push_str(&format!(
+                                                            "Steering overshoot: peak {:.0}% lock, unwound {:.0}% at {:.1} m/s2\nTurn in to the angle the corner needs instead of correcting afterwards",
+                                                            peak_steering_pct * 100.,
+                                                            unwound_steering_pct * 100.,
+                                                            lateral_accel_mps2
+                                                        ));
+                                                }
+                                                if let Some(TelemetryAnnotation::PedalOverlap { duration_ms, max_throttle, max_brake, .. }) =
+                                                    telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::PedalOverlap { .. })) {
+                                                        if !self.selected_annotation_content.is_empty() {
+                                                            self.selected_annotation_content.push('\n');
+                                                        }
+                                                        self.selected_annotation_content.push_str(&format!(
+                                                            "Pedal overlap: {}ms with up to {:.0}% throttle and {:.0}% brake\nCome fully off the throttle before braking, unless left-foot braking on purpose",
+                                                            duration_ms,
+                                                            max_throttle * 100.,
+                                                            max_brake * 100.
+                                                        ));
+                                                }
+                                                if let Some(TelemetryAnnotation::SteeringReversals { reversals, reversals_per_s, .. }) =
+                                                    telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::SteeringReversals { .. })) {
+                                                        if !self.selected_annotation_content.is_empty() {
+                                                            self.selected_annotation_content.push('\n');
+                                                        }
+                                                        self.selected_annotation_content.push_str(&format!(
+                                                            "Busy hands: {} steering reversals, {:.1} per second\nHold the wheel still through the corner, or look for a nervous car",
+                                                            reversals,
+                                                            reversals_per_s
+                                                        ));
+                                                }
+                                                if let Some(TelemetryAnnotation::DownshiftInstability { from_gear, to_gear, rpm_spike, peak_yaw_excess_rps, .. }) =
+                                                    telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::DownshiftInstability { .. })) {
+                                                        if !self.selected_annotation_content.is_empty() {
+                                                            self.selected_annotation_content.push('\n');
+                                                        }
+                                                        self.selected_annotation_content.push_str(&format!(
+                                                            "Downshift {} to {}: RPM up {:.0}, rear yawing {:.2} rad/s past the grip\nBlip the throttle and space the downshifts out",
+                                                            from_gear,
+                                                            to_gear,
+                                                            rpm_spike,
+                                                            peak_yaw_excess_rps
+                                                        ));
+                                                }
                                             }
                                             ui.separator();
-                                            if slip_alert.show(ui, Align::Center).clicked() {
+                                            if slip_alert.show_prioritized(ui, Align::Center, most_important, accent).clicked() {
+                                                self.selected_annotation_content.clear();
                                                 if let Some(TelemetryAnnotation::Scrub { avg_yaw_rate_change, cur_yaw_rate_change, .. }) =
                                                     telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::Scrub { .. })) {
                                                         let steering = telemetry.steering_angle_rad.unwrap_or(0.0);
@@ -399,7 +1963,20 @@ impl eframe::App for TelemetryAnalysisApp<'_> {
                                                             speed
                                                         );
                                                 }
-                                                if let Some(TelemetryAnnotation::Slip { prev_speed, cur_speed, .. }) =
+                                                if let Some(TelemetryAnnotation::TransitionInstability { cur_yaw_rate_change, avg_yaw_rate_change, brake, since_direction_change_ms, slip_ratio, .. }) =
+                                                    telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::TransitionInstability { .. })) {
+                                                        let measure = match slip_ratio {
+                                                            Some(slip_ratio) => format!("Wheel slip: {:.0}%", slip_ratio * 100.),
+                                                            None => format!("Yaw change: {:.2}\nAvg yaw change: {:.2}", cur_yaw_rate_change, avg_yaw_rate_change),
+                                                        };
+                                                        self.selected_annotation_content = format!(
+                                                            "Compound corner transition: braking {:.0}ms after the steering crossed the centre\n{}\nBrake: {:.0}%\nLet the car settle from the last corner before braking, not an entry understeer fix",
+                                                            since_direction_change_ms,
+                                                            measure,
+                                                            brake * 100.
+                                                        );
+                                                }
+                                                if let Some(TelemetryAnnotation::Slip { prev_speed, cur_speed, slip_ratio, .. }) =
                                                     telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::Slip { .. })) {
                                                         let throttle = telemetry.throttle.unwrap_or(0.0);
                                                         let steering = telemetry.steering_angle_rad.unwrap_or(0.0);
@@ -410,6 +1987,31 @@ impl eframe::App for TelemetryAnalysisApp<'_> {
                                                             throttle,
                                                             steering
                                                         );
+                                                        if let Some(slip_ratio) = slip_ratio {
+                                                            self.selected_annotation_content += &format!("\nSlip ratio: {:.1}%", slip_ratio * 100.);
+                                                        }
+                                                }
+                                                if let Some(TelemetryAnnotation::ExitThrottleLift { dip_depth, dip_count, cause, .. }) =
+                                                    telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::ExitThrottleLift { .. })) {
+                                                        if !self.selected_annotation_content.is_empty() {
+                                                            self.selected_annotation_content.push('\n');
+                                                        }
+                                                        self.selected_annotation_content.push_str(&format!(
+                                                            "Exit throttle lift: {:.0}% dip, {} in this exit\nLifting with the steering wound on points at exit understeer\nLikely cause: {}",
+                                                            dip_depth * 100.,
+                                                            dip_count,
+                                                            cause
+                                                        ));
+                                                }
+                                                if let Some(TelemetryAnnotation::ThrottleStab { reversals, .. }) =
+                                                    telemetry.annotations.iter().find(|p| matches!(p, TelemetryAnnotation::ThrottleStab { .. })) {
+                                                        if !self.selected_annotation_content.is_empty() {
+                                                            self.selected_annotation_content.push('\n');
+                                                        }
+                                                        self.selected_annotation_content.push_str(&format!(
+                                                            "Throttle stabbing: {} throttle reversals in this exit\nSqueeze the throttle on progressively as the steering unwinds",
+                                                            reversals
+                                                        ));
                                                 }
                                             }
                                         });
@@ -431,6 +2033,31 @@ impl eframe::App for TelemetryAnalysisApp<'_> {
                                 );
                             }
                     });
+                egui::TopBottomPanel::bottom("DataQuality")
+                    .frame(
+                        Frame::default()
+                            .fill(Color32::TRANSPARENT)
+                            .inner_margin(Margin::same(5)),
+                    )
+                    .show(ctx, |local_ui| {
+                        self.show_session_diff(local_ui);
+                        self.show_data_quality(local_ui);
+                        if self.app_config.show_frame_stats {
+                            self.frame_stats.show(local_ui);
+                        }
+                    });
+                if let Some((_, lap)) = playback_lap {
+                    egui::TopBottomPanel::bottom("Playback")
+                        .frame(
+                            Frame::default()
+                                .fill(Color32::TRANSPARENT)
+                                .inner_margin(Margin::same(5)),
+                        )
+                        .show(ctx, |local_ui| {
+                            self.playback
+                                .show(local_ui, &lap.telemetry, &mut self.selected_x);
+                        });
+                }
                 egui::CentralPanel::default()
                     .frame(
                         Frame::default()
@@ -438,7 +2065,9 @@ impl eframe::App for TelemetryAnalysisApp<'_> {
                             .inner_margin(Margin::same(5)),
                     )
                     .show(ctx, |local_ui| {
-                        if let Ok(selected_lap) = self.selected_lap.parse::<usize>() {
+                        if self.show_stints {
+                            self.show_stint_chart(&session, local_ui);
+                        } else if let Ok(selected_lap) = self.selected_lap.parse::<usize>() {
                             self.show_telemetry_chart(selected_lap, &session, local_ui);
                         }
                     });
@@ -449,16 +2078,110 @@ impl eframe::App for TelemetryAnalysisApp<'_> {
                 });
             }
         }
-        ctx.request_repaint();
+        self.frame_stats.record_frame(frame_start, Instant::now());
     }
 }
 
+/// One line with the metadata of the session being analyzed.
+fn show_session_header(info: &SessionInfo, ui: &mut Ui) {
+    ui.horizontal_wrapped(|ui| {
+        for (i, (label, value)) in session_header_fields(info).into_iter().enumerate() {
+            if i > 0 {
+                ui.separator();
+            }
+            ui.label(RichText::new(format!("{}:", label)).color(Color32::GRAY));
+            ui.label(RichText::new(value).color(Color32::WHITE).strong());
+        }
+    });
+}
+
+/// Labelled session metadata, fields the sim did not report are left out.
+fn session_header_fields(info: &SessionInfo) -> Vec<(&'static str, String)> {
+    let reported = |value: &str| {
+        let value = value.trim();
+        (!value.is_empty() && value != "Unknown").then(|| value.to_string())
+    };
+
+    let mut fields = vec![("Game", format!("{:?}", info.game_source))];
+    if let Some(car) = info.car_name.as_deref().and_then(reported) {
+        fields.push(("Car", car));
+    }
+    if let Some(track) = reported(&info.track_name) {
+        fields.push(("Track", track));
+    }
+    if let Some(configuration) = reported(&info.track_configuration) {
+        fields.push(("Configuration", configuration));
+    }
+    if let Some(length) = reported(&info.track_length) {
+        fields.push(("Length", length));
+    }
+    let tires = info
+        .tire_compound_name
+        .as_deref()
+        .and_then(reported)
+        .or_else(|| match info.tire_compound {
+            TireCompound::Unknown => None,
+            compound => Some(format!("{:?}", compound)),
+        });
+    if let Some(tires) = tires {
+        fields.push(("Tires", tires));
+    }
+    if info.track_conditions != TrackConditions::Unknown {
+        fields.push(("Conditions", format!("{:?}", info.track_conditions)));
+    }
+    if let Some(humidity) = info.relative_humidity_pct {
+        fields.push(("Humidity", format!("{:.0}%", humidity)));
+    }
+    if let Some(series_id) = info.we_series_id.filter(|id| *id > 0) {
+        fields.push(("Series", series_id.to_string()));
+    }
+    if let Some(sub_session_id) = info.we_sub_session_id.filter(|id| *id > 0) {
+        fields.push(("Session", sub_session_id.to_string()));
+    }
+    fields
+}
+
+/// Trend line of a stint plotted against the session lap numbers.
+fn trend_points(stint: &Stint, value_at: impl Fn(f64) -> f64) -> Vec<[f64; 2]> {
+    stint
+        .laps
+        .iter()
+        .enumerate()
+        .map(|(i, l)| [l.lap_index as f64, value_at(i as f64)])
+        .collect()
+}
+
+/// Returns true when every point has a lap distance, so the chart can use it as x-axis.
+fn has_lap_distance(points: &[TelemetryData]) -> bool {
+    !points.is_empty() && points.iter().all(|p| p.lap_distance_pct.is_some())
+}
+
+/// Lap the focus corners are found in, with its index: the best lap of the session, or
+/// the selected lap when no lap was driven from start to finish.
+fn focus_reference_lap<'s>(session: &'s Session, selected_lap: &str) -> Option<(usize, &'s Lap)> {
+    let lap = |lap_no: usize| Some((lap_no, session.laps.get(lap_no)?));
+    session
+        .stints
+        .best_lap()
+        .and_then(|best| lap(best.lap_index))
+        .or_else(|| lap(selected_lap.parse::<usize>().ok()?))
+}
+
+/// Index of the point closest to `lap_distance_pct` (0.0 to 1.0).
+fn point_at_lap_distance(points: &[TelemetryData], lap_distance_pct: f64) -> Option<usize> {
+    points
+        .iter()
+        .enumerate()
+        .filter_map(|(i, p)| Some((i, (p.lap_distance_pct? as f64 - lap_distance_pct).abs())))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+}
+
 /// Detects if a telemetry file uses the legacy TelemetryPoint format
 /// by attempting to parse the first line as a raw JSON value and checking
 /// for the presence of legacy-specific fields.
-fn is_legacy_format(source_file: &PathBuf) -> bool {
+pub(crate) fn is_legacy_format(source_file: &PathBuf) -> bool {
     use std::fs::File;
-    use std::io::{BufRead, BufReader};
 
     // Try to read the first line of the file
     let file = match File::open(source_file) {
@@ -498,63 +2221,145 @@ fn is_legacy_format(source_file: &PathBuf) -> bool {
     false
 }
 
-fn load_telemetry_jsonl(source_file: &PathBuf) -> Result<TelemetryFile, OcypodeError> {
+/// Parse a telemetry file, iRacing `.ibt` files are recognized by their extension and
+/// everything else is read as JSONL.
+fn load_telemetry_file_with_progress(
+    source_file: &PathBuf,
+    lap_filter: &LapFilter,
+    on_progress: impl FnMut(u64),
+) -> Result<TelemetryFile, OcypodeError> {
+    let is_ibt = source_file
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("ibt"));
+    if !is_ibt {
+        return load_telemetry_jsonl_with_progress(source_file, lap_filter, on_progress);
+    }
+
+    let mut telemetry = read_ibt_with_progress(source_file, on_progress)?;
+    // .ibt files are written by the sim, annotate them as if they had been collected live
+    AnalyzerConfig::from_local_file()
+        .unwrap_or_default()
+        .annotate(&mut telemetry.points, &telemetry.session_info);
+    telemetry_file_from_outputs(telemetry.into_outputs(), lap_filter)
+}
+
+/// Parse a telemetry file, reporting the number of bytes read after each line.
+fn load_telemetry_jsonl_with_progress(
+    source_file: &PathBuf,
+    lap_filter: &LapFilter,
+    mut on_progress: impl FnMut(u64),
+) -> Result<TelemetryFile, OcypodeError> {
     // Check if this is a legacy format file before attempting to deserialize
     if is_legacy_format(source_file) {
         return Err(OcypodeError::LegacyTelemetryFormat);
     }
 
-    // TODO: Should probably load in a non-blocking way here
-    let telemetry_lines = serde_jsonlines::json_lines(source_file)
-        .map_err(|e| OcypodeError::TelemetryLoaderError { source: e })?
-        .collect::<Result<Vec<TelemetryOutput>, std::io::Error>>()
-        .map_err(|e| {
+    let file = std::fs::File::open(source_file)
+        .map_err(|e| OcypodeError::TelemetryLoaderError { source: e })?;
+    let mut telemetry_lines = Vec::new();
+    let mut bytes_read: u64 = 0;
+    let mut migrator = RecordMigrator::new();
+    for (line_no, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|e| OcypodeError::TelemetryLoaderError { source: e })?;
+        // lines() strips the newline
+        bytes_read += line.len() as u64 + 1;
+        on_progress(bytes_read);
+        if line.trim().is_empty() {
+            continue;
+        }
+        let output = migrator.parse(&line).map_err(|e| {
             // If deserialization fails, check if it might be a legacy format
             // that we didn't catch in the initial check
             if is_legacy_format(source_file) {
                 OcypodeError::LegacyTelemetryFormat
             } else {
-                OcypodeError::TelemetryLoaderError { source: e }
+                OcypodeError::TelemetryParseError {
+                    line: line_no + 1,
+                    source: e,
+                }
             }
         })?;
+        telemetry_lines.push(output);
+    }
 
+    telemetry_file_from_outputs(telemetry_lines, lap_filter)
+}
+
+/// Lap selector entry with the issue count of the lap, colored by how close the lap
+/// comes to the one with the most issues in the session.
+fn lap_entry(
+    ui: &mut Ui,
+    text: &str,
+    laps: &[Lap],
+    max_issues: usize,
+    theme: &Theme,
+) -> egui::Response {
+    let Some(lap) = text.parse::<usize>().ok().and_then(|l| laps.get(l)) else {
+        return ui.selectable_label(false, text);
+    };
+    let share = if max_issues == 0 {
+        0.
+    } else {
+        lap.issue_count as f32 / max_issues as f32
+    };
+    let color = if share > 2. / 3. {
+        theme.brake
+    } else if share > 1. / 3. {
+        theme.brake_light
+    } else {
+        Color32::WHITE
+    };
+    ui.selectable_label(
+        false,
+        RichText::new(format!("{}  ⚠ {}", text, lap.issue_count)).color(color),
+    )
+}
+
+/// Group telemetry records into sessions and laps, leaving the laps `lap_filter` rejects
+/// empty.
+fn telemetry_file_from_outputs(
+    telemetry_lines: Vec<TelemetryOutput>,
+    lap_filter: &LapFilter,
+) -> Result<TelemetryFile, OcypodeError> {
     let mut telemetry_data = TelemetryFile::default();
-    let mut cur_lap_no: u32 = 0;
-    let mut cur_session = Session::default();
-    let mut cur_lap = Lap::default();
-    for line in telemetry_lines {
-        match line {
-            TelemetryOutput::DataPoint(telemetry_point) => {
-                let lap_no = telemetry_point.lap_number.unwrap_or(0);
-                if lap_no != cur_lap_no {
-                    cur_session.laps.push(cur_lap.clone());
-                    cur_lap = Lap::default();
-                    cur_lap_no = lap_no;
-                }
-                cur_lap.telemetry.push(*telemetry_point);
-            }
-            TelemetryOutput::SessionChange(session_info) => {
-                if !cur_lap.telemetry.is_empty() {
-                    cur_session.laps.push(cur_lap);
-                }
-                // if we already have data points we are starting a new session
-                if !cur_session.laps.is_empty() {
-                    telemetry_data.sessions.push(cur_session.clone());
-                    cur_session = Session::default();
-                }
-                cur_lap = Lap::default();
-                cur_lap_no = 0;
-                cur_session.info = session_info;
-            }
+    for recorded in group_sessions(telemetry_lines, lap_filter)? {
+        telemetry_data.sessions.push(Session {
+            info: recorded.info,
+            laps: recorded
+                .laps
+                .into_iter()
+                .map(|telemetry| Lap {
+                    telemetry,
+                    ..Lap::default()
+                })
+                .collect(),
+            ..Session::default()
+        });
+    }
+    for session in telemetry_data.sessions.iter_mut() {
+        for lap in session.laps.iter_mut() {
+            lap.issue_count = count_issues(&lap.telemetry);
         }
+        session.ideal_lap = IdealLap::from_laps(&session.laps);
+        session.stints =
+            StintSummary::from_laps(session.laps.iter().map(|l| l.telemetry.as_slice()));
+        session.tire_warmup =
+            TireWarmup::from_laps(session.laps.iter().map(|l| l.telemetry.as_slice()));
     }
-    telemetry_data.sessions.push(cur_session);
+    telemetry_data.data_quality = DataQualityReport::from_points(
+        telemetry_data
+            .sessions
+            .iter()
+            .flat_map(|s| s.laps.iter())
+            .flat_map(|l| l.telemetry.iter()),
+    );
     Ok(telemetry_data)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::telemetry::GameSource;
     use std::io::Write;
     use tempfile::NamedTempFile;
 
@@ -597,7 +2402,11 @@ mod tests {
         legacy_file.flush().unwrap();
 
         // Test that loading legacy format returns the correct error
-        let result = load_telemetry_jsonl(&legacy_file.path().to_path_buf());
+        let result = load_telemetry_file_with_progress(
+            &legacy_file.path().to_path_buf(),
+            &LapFilter::default(),
+            |_| {},
+        );
         assert!(result.is_err());
         match result {
             Err(OcypodeError::LegacyTelemetryFormat) => {
@@ -606,4 +2415,219 @@ mod tests {
             _ => panic!("Expected LegacyTelemetryFormat error"),
         }
     }
+
+    #[test]
+    fn test_load_reports_parse_error_line() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"{{"SessionChange":{{"track_name":"lagunaseca","track_configuration":"","max_steering_angle":3.14,"track_length":"3.6","game_source":"IRacing","we_series_id":null,"we_session_id":null,"we_season_id":null,"we_sub_session_id":null,"we_league_id":null}}}}"#
+        )
+        .unwrap();
+        writeln!(file, "{{not json").unwrap();
+        file.flush().unwrap();
+
+        match load_telemetry_file_with_progress(
+            &file.path().to_path_buf(),
+            &LapFilter::default(),
+            |_| {},
+        ) {
+            Err(OcypodeError::TelemetryParseError { line, .. }) => assert_eq!(line, 2),
+            _ => panic!("Expected TelemetryParseError"),
+        }
+    }
+
+    #[test]
+    fn test_load_unversioned_recording() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"{{"SessionChange":{{"track_name":"lagunaseca","track_configuration":"","max_steering_angle":3.14,"track_length":"3.6","game_source":"IRacing","we_series_id":null,"we_session_id":null,"we_season_id":null,"we_sub_session_id":null,"we_league_id":null}}}}"#
+        )
+        .unwrap();
+        // points recorded before annotations were added
+        for lap_number in 1..=2 {
+            writeln!(
+                file,
+                r#"{{"DataPoint":{{"point_no":{lap_number},"timestamp_ms":1000,"game_source":"IRacing","lap_number":{lap_number},"speed_mps":40.0}}}}"#
+            )
+            .unwrap();
+        }
+        file.flush().unwrap();
+
+        let telemetry = load_telemetry_file_with_progress(
+            &file.path().to_path_buf(),
+            &LapFilter::default(),
+            |_| {},
+        )
+        .unwrap();
+        let session = &telemetry.sessions[0];
+        assert_eq!(session.info.format_version, 0);
+        let lap = &session.laps.last().unwrap().telemetry;
+        assert_eq!(lap.len(), 1);
+        assert_eq!(lap[0].speed_mps, Some(40.0));
+        assert!(lap[0].annotations.is_empty());
+    }
+
+    #[test]
+    fn test_load_rejects_invalid_session_metadata() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"{{"SessionChange":{{"track_name":"","track_configuration":"","max_steering_angle":3.14,"track_length":"3.6","game_source":"IRacing","we_series_id":null,"we_session_id":null,"we_season_id":null,"we_sub_session_id":null,"we_league_id":null}}}}"#
+        )
+        .unwrap();
+        file.flush().unwrap();
+
+        match load_telemetry_file_with_progress(
+            &file.path().to_path_buf(),
+            &LapFilter::default(),
+            |_| {},
+        ) {
+            Err(OcypodeError::InvalidSessionMetadata { field, .. }) => {
+                assert_eq!(field, "track_name")
+            }
+            _ => panic!("Expected InvalidSessionMetadata"),
+        }
+    }
+
+    #[test]
+    fn test_session_header_fields() {
+        let info = SessionInfo {
+            track_name: "Spa".to_string(),
+            track_configuration: "Grand Prix".to_string(),
+            track_length: "7.00 km".to_string(),
+            game_source: GameSource::ACC,
+            tire_compound: TireCompound::Wet,
+            car_name: Some("Ferrari 296 GT3".to_string()),
+            track_conditions: TrackConditions::Damp,
+            relative_humidity_pct: Some(82.6),
+            ..SessionInfo::default()
+        };
+        assert_eq!(
+            session_header_fields(&info),
+            vec![
+                ("Game", "ACC".to_string()),
+                ("Car", "Ferrari 296 GT3".to_string()),
+                ("Track", "Spa".to_string()),
+                ("Configuration", "Grand Prix".to_string()),
+                ("Length", "7.00 km".to_string()),
+                ("Tires", "Wet".to_string()),
+                ("Conditions", "Damp".to_string()),
+                ("Humidity", "83%".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_session_header_skips_unreported_fields() {
+        let info = SessionInfo {
+            track_configuration: String::new(),
+            we_sub_session_id: Some(12345),
+            ..SessionInfo::default()
+        };
+        assert_eq!(
+            session_header_fields(&info),
+            vec![
+                ("Game", "IRacing".to_string()),
+                ("Session", "12345".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_reports_bytes_read() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"{{"SessionChange":{{"track_name":"lagunaseca","track_configuration":"","max_steering_angle":3.14,"track_length":"3.6","game_source":"IRacing","we_series_id":null,"we_session_id":null,"we_season_id":null,"we_sub_session_id":null,"we_league_id":null}}}}"#
+        )
+        .unwrap();
+        writeln!(file).unwrap();
+        file.flush().unwrap();
+
+        let mut progress = Vec::new();
+        load_telemetry_jsonl_with_progress(
+            &file.path().to_path_buf(),
+            &LapFilter::default(),
+            |bytes| progress.push(bytes),
+        )
+        .unwrap();
+
+        let file_size = std::fs::metadata(file.path()).unwrap().len();
+        assert_eq!(progress.len(), 2);
+        assert!(progress[0] < progress[1]);
+        assert_eq!(*progress.last().unwrap(), file_size);
+    }
+
+    fn distance_point(lap_distance_pct: Option<f32>) -> TelemetryData {
+        TelemetryData {
+            lap_distance_pct,
+            ..TelemetryData::default()
+        }
+    }
+
+    #[test]
+    fn test_has_lap_distance() {
+        assert!(!has_lap_distance(&[]));
+        assert!(has_lap_distance(&[
+            distance_point(Some(0.1)),
+            distance_point(Some(0.2))
+        ]));
+        assert!(!has_lap_distance(&[
+            distance_point(Some(0.1)),
+            distance_point(None)
+        ]));
+    }
+
+    #[test]
+    fn test_count_issues() {
+        let point = |annotations: Vec<TelemetryAnnotation>| TelemetryData {
+            annotations,
+            ..TelemetryData::default()
+        };
+        let slip = |is_slip| TelemetryAnnotation::Slip {
+            prev_speed: 30.,
+            cur_speed: 29.,
+            is_slip,
+            slip_ratio: None,
+        };
+        let points = [
+            point(vec![slip(true)]),
+            // the same slide carries on
+            point(vec![slip(true)]),
+            point(vec![slip(false)]),
+            point(vec![]),
+            point(vec![slip(true)]),
+        ];
+        assert_eq!(count_issues(&points), 2);
+
+        // the lap is complete once the next one starts
+        let next_lap = TelemetryData {
+            lap_number: Some(1),
+            ..TelemetryData::default()
+        };
+        let file = telemetry_file_from_outputs(
+            points
+                .into_iter()
+                .chain([next_lap])
+                .map(|p| TelemetryOutput::DataPoint(Box::new(p)))
+                .collect(),
+            &LapFilter::default(),
+        )
+        .unwrap();
+        assert_eq!(file.sessions[0].laps[0].issue_count, 2);
+    }
+
+    #[test]
+    fn test_point_at_lap_distance() {
+        let points: Vec<_> = [0.0, 0.25, 0.5, 0.75]
+            .into_iter()
+            .map(|pct| distance_point(Some(pct)))
+            .collect();
+
+        assert_eq!(point_at_lap_distance(&points, 0.3), Some(1));
+        assert_eq!(point_at_lap_distance(&points, 0.9), Some(3));
+        assert_eq!(point_at_lap_distance(&[], 0.5), None);
+    }
 }
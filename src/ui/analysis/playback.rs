@@ -0,0 +1,208 @@
+use std::time::Instant;
+
+use egui::{Color32, RichText, Slider, Ui};
+
+use crate::telemetry::TelemetryData;
+
+/// Speeds offered by the playback controls, as multiples of the recorded time
+const PLAYBACK_SPEEDS: [f64; 4] = [0.5, 1., 2., 4.];
+
+/// Plays the selected lap back at the pace it was recorded, moving the selected point so
+/// the alerts light up as they did live.
+pub(super) struct Playback {
+    playing: bool,
+    speed: f64,
+    /// Lap the playhead belongs to, playback stops when another lap is selected
+    lap_no: Option<usize>,
+    /// Recorded time of the playhead since the first point of the lap
+    position_ms: f64,
+    last_frame: Option<Instant>,
+}
+
+impl Default for Playback {
+    fn default() -> Self {
+        Self {
+            playing: false,
+            speed: 1.,
+            lap_no: None,
+            position_ms: 0.,
+            last_frame: None,
+        }
+    }
+}
+
+impl Playback {
+    pub(super) fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Advance the playhead by the time since the last frame, returning the point it is
+    /// on while playing. Playback pauses on the last point of the lap.
+    pub(super) fn tick(
+        &mut self,
+        lap_no: usize,
+        lap: &[TelemetryData],
+        now: Instant,
+    ) -> Option<usize> {
+        if self.lap_no != Some(lap_no) {
+            self.pause();
+            self.lap_no = Some(lap_no);
+            self.position_ms = 0.;
+        }
+        if !self.playing || lap.is_empty() {
+            return None;
+        }
+        if let Some(last_frame) = self.last_frame {
+            self.position_ms += now.duration_since(last_frame).as_secs_f64() * 1000. * self.speed;
+        }
+        self.last_frame = Some(now);
+
+        let point = lap
+            .partition_point(|p| offset_ms(lap, p) <= self.position_ms)
+            .saturating_sub(1);
+        if point + 1 >= lap.len() {
+            self.pause();
+        }
+        Some(point)
+    }
+
+    /// Play from `selected_x`, or from the start when the playhead is at the end of the lap.
+    fn play(&mut self, lap: &[TelemetryData], selected_x: Option<usize>) {
+        let point = selected_x.filter(|x| x + 1 < lap.len()).unwrap_or(0);
+        self.seek(lap, point);
+        self.playing = true;
+    }
+
+    fn pause(&mut self) {
+        self.playing = false;
+        self.last_frame = None;
+    }
+
+    /// Move the playhead to `point`, playback carries on from there.
+    pub(super) fn seek(&mut self, lap: &[TelemetryData], point: usize) {
+        self.position_ms = lap.get(point).map_or(0., |p| offset_ms(lap, p));
+    }
+
+    /// Play/pause button, speed selector and a scrub bar over the points of `lap`.
+    pub(super) fn show(
+        &mut self,
+        ui: &mut Ui,
+        lap: &[TelemetryData],
+        selected_x: &mut Option<usize>,
+    ) {
+        let Some(last_point) = lap.len().checked_sub(1) else {
+            return;
+        };
+        ui.horizontal(|ui| {
+            let button = if self.playing { "⏸" } else { "▶" };
+            if ui
+                .button(button)
+                .on_hover_text("Play the lap back at the recorded pace")
+                .clicked()
+            {
+                if self.playing {
+                    self.pause();
+                } else {
+                    self.play(lap, *selected_x);
+                }
+            }
+            for speed in PLAYBACK_SPEEDS {
+                ui.selectable_value(&mut self.speed, speed, format!("{}x", speed));
+            }
+
+            let mut point = selected_x.unwrap_or(0).min(last_point);
+            ui.label(
+                RichText::new(format!(
+                    "{:.1} / {:.1} s",
+                    offset_ms(lap, &lap[point]) / 1000.,
+                    offset_ms(lap, &lap[last_point]) / 1000.
+                ))
+                .color(Color32::WHITE),
+            );
+            ui.spacing_mut().slider_width = ui.available_width();
+            if ui
+                .add(Slider::new(&mut point, 0..=last_point).show_value(false))
+                .changed()
+            {
+                *selected_x = Some(point);
+                self.seek(lap, point);
+            }
+        });
+    }
+}
+
+/// Recorded time of `point` since the first point of the lap
+fn offset_ms(lap: &[TelemetryData], point: &TelemetryData) -> f64 {
+    point.timestamp_ms.saturating_sub(lap[0].timestamp_ms) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// A lap with a point every 100ms
+    fn lap(points: usize) -> Vec<TelemetryData> {
+        (0..points)
+            .map(|i| TelemetryData {
+                point_no: i,
+                timestamp_ms: 5_000 + i as u128 * 100,
+                ..TelemetryData::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_plays_at_recorded_pace() {
+        let lap = lap(50);
+        let start = Instant::now();
+        let mut playback = Playback::default();
+        playback.tick(0, &lap, start);
+        playback.play(&lap, Some(10));
+
+        assert_eq!(playback.tick(0, &lap, start), Some(10));
+        assert_eq!(
+            playback.tick(0, &lap, start + Duration::from_millis(550)),
+            Some(15)
+        );
+
+        playback.speed = 2.;
+        assert_eq!(
+            playback.tick(0, &lap, start + Duration::from_millis(1050)),
+            Some(25)
+        );
+    }
+
+    #[test]
+    fn test_pauses_at_end_of_lap() {
+        let lap = lap(10);
+        let start = Instant::now();
+        let mut playback = Playback::default();
+        playback.tick(0, &lap, start);
+        playback.play(&lap, Some(8));
+
+        playback.tick(0, &lap, start);
+        assert_eq!(
+            playback.tick(0, &lap, start + Duration::from_secs(5)),
+            Some(9)
+        );
+        assert!(!playback.is_playing());
+
+        // playing again from the end restarts the lap
+        playback.play(&lap, Some(9));
+        assert_eq!(playback.tick(0, &lap, start), Some(0));
+    }
+
+    #[test]
+    fn test_stops_when_another_lap_is_selected() {
+        let lap = lap(10);
+        let start = Instant::now();
+        let mut playback = Playback::default();
+        playback.tick(0, &lap, start);
+        playback.play(&lap, Some(3));
+
+        assert_eq!(playback.tick(1, &lap, start), None);
+        assert!(!playback.is_playing());
+    }
+}
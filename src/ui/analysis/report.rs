@@ -0,0 +1,316 @@
+use std::path::{Path, PathBuf};
+
+use egui::{Color32, ColorImage, RichText, Ui};
+use snafu::ResultExt;
+
+use crate::{
+    OcypodeError,
+    errors::ReportExportSnafu,
+    setup_assistant::{Finding, SetupAssistant, recommendations::ProcessedRecommendation},
+    telemetry::{
+        SessionInfo, TelemetryData, analyzer_config::AnalyzerConfig, coasting::CoastingStats,
+        corner_speed::OverSlowing, grip_usage::GripUsage, steering_reversals::SteeringReversals,
+    },
+    ui::live::{config::SpeedUnit, telemetry_view::format_lap_time},
+};
+
+/// Recommendations listed in the report, the rest are left to the setup window
+const MAX_REPORT_RECOMMENDATIONS: usize = 8;
+
+/// Summary of one lap drawn as a single page and saved as an image.
+#[derive(Clone, Debug, Default)]
+pub(super) struct LapReport {
+    pub(super) lap: usize,
+    /// `None` for laps that were not driven from start to finish
+    pub(super) lap_time_ms: Option<u128>,
+    pub(super) top_speed_mps: Option<f32>,
+    pub(super) coasting: CoastingStats,
+    pub(super) steering_reversals: SteeringReversals,
+    pub(super) grip: GripUsage,
+    /// Over-slowed corners against the best lap, `None` for the best lap itself
+    pub(super) over_slowing: Option<(usize, OverSlowing)>,
+    /// Setup assistant findings of the lap that passed its thresholds, most frequent first
+    pub(super) findings: Vec<Finding>,
+    pub(super) recommendations: Vec<ProcessedRecommendation>,
+}
+
+impl LapReport {
+    pub(super) fn from_lap(
+        lap: usize,
+        points: &[TelemetryData],
+        lap_time_ms: Option<u128>,
+        best_lap: Option<(usize, &[TelemetryData])>,
+        session_info: &SessionInfo,
+        config: &AnalyzerConfig,
+    ) -> Self {
+        let mut setup_assistant = SetupAssistant::new();
+        setup_assistant.set_car_from_local_overrides(session_info.car_name.as_deref());
+        for point in points {
+            setup_assistant.process_telemetry(point);
+        }
+        let mut findings: Vec<Finding> = setup_assistant
+            .get_actionable_findings()
+            .into_iter()
+            .cloned()
+            .collect();
+        findings.sort_by(|a, b| b.occurrence_count.cmp(&a.occurrence_count));
+        // the report stands in for the driver confirming what the lap showed
+        for finding in &findings {
+            setup_assistant.toggle_confirmation(finding.finding_type.clone());
+        }
+        let mut recommendations = setup_assistant.get_processed_recommendations();
+        recommendations.truncate(MAX_REPORT_RECOMMENDATIONS);
+
+        Self {
+            lap,
+            lap_time_ms,
+            top_speed_mps: points
+                .iter()
+                .filter_map(|p| p.speed_mps)
+                .max_by(f32::total_cmp),
+            coasting: CoastingStats::from_lap(points, &config.coasting),
+            steering_reversals: SteeringReversals::from_lap(
+                points,
+                session_info.max_steering_angle,
+            ),
+            grip: GripUsage::from_lap(points, config.grip_peak_g),
+            over_slowing: best_lap.filter(|(best_lap, _)| *best_lap != lap).map(
+                |(best_lap, best)| {
+                    (
+                        best_lap,
+                        OverSlowing::from_laps(points, best, &config.corner_detection),
+                    )
+                },
+            ),
+            findings,
+            recommendations,
+        }
+    }
+
+    /// Labelled key figures of the lap, figures without data are left out.
+    pub(super) fn stats(&self, speed_unit: SpeedUnit) -> Vec<(&'static str, String)> {
+        let mut stats = vec![("Lap", self.lap.to_string())];
+        stats.push((
+            "Lap time",
+            self.lap_time_ms
+                .map(|ms| format_lap_time(ms as f32 / 1000.))
+                .unwrap_or_else(|| "incomplete".to_string()),
+        ));
+        if let Some(top_speed_mps) = self.top_speed_mps {
+            stats.push((
+                "Top speed",
+                format!(
+                    "{:.0} {}",
+                    speed_unit.speed_from_mps(top_speed_mps),
+                    speed_unit
+                ),
+            ));
+        }
+        stats.push((
+            "Coasting",
+            format!(
+                "{:.1}% ({:.1}% excessive)",
+                self.coasting.coasting_pct(),
+                self.coasting.excessive_coasting_pct()
+            ),
+        ));
+        if self.steering_reversals.duration_ms > 0 {
+            stats.push((
+                "Steering reversals",
+                format!(
+                    "{} ({:.1}/s)",
+                    self.steering_reversals.reversals,
+                    self.steering_reversals.reversals_per_s()
+                ),
+            ));
+        }
+        if self.grip.peak_g > 0. {
+            stats.push((
+                "Grip",
+                format!(
+                    "{:.0}% of {:.2} g",
+                    self.grip.mean_corner_usage_pct(),
+                    self.grip.peak_g
+                ),
+            ));
+        }
+        if let Some((best_lap, over_slowing)) = &self.over_slowing
+            && !over_slowing.corners.is_empty()
+        {
+            stats.push((
+                "Over-slowing",
+                format!(
+                    "{} of {} corners vs lap {}",
+                    over_slowing.over_slowed_corners().count(),
+                    over_slowing.corners.len(),
+                    best_lap
+                ),
+            ));
+        }
+        stats
+    }
+
+    /// Key figures, findings and recommendations, the chart is drawn by the caller.
+    pub(super) fn show(&self, ui: &mut Ui, speed_unit: SpeedUnit) {
+        ui.horizontal_wrapped(|ui| {
+            for (i, (label, value)) in self.stats(speed_unit).into_iter().enumerate() {
+                if i > 0 {
+                    ui.separator();
+                }
+                ui.label(RichText::new(format!("{}:", label)).color(Color32::GRAY));
+                ui.label(RichText::new(value).color(Color32::WHITE).strong());
+            }
+        });
+        ui.separator();
+        ui.columns(2, |columns| {
+            columns[0].label(RichText::new("Findings").color(Color32::WHITE).strong());
+            if self.findings.is_empty() {
+                columns[0].label(RichText::new("No setup issues detected").color(Color32::GRAY));
+            }
+            for finding in &self.findings {
+                columns[0].label(
+                    RichText::new(format!(
+                        "{} ({}), seen {} times",
+                        finding.finding_type, finding.corner_phase, finding.occurrence_count
+                    ))
+                    .color(Color32::WHITE),
+                );
+            }
+
+            columns[1].label(
+                RichText::new("Recommendations")
+                    .color(Color32::WHITE)
+                    .strong(),
+            );
+            if self.recommendations.is_empty() {
+                columns[1].label(RichText::new("No recommendations").color(Color32::GRAY));
+            }
+            for processed in &self.recommendations {
+                let recommendation = &processed.recommendation;
+                columns[1].label(
+                    RichText::new(format!(
+                        "{} {}{}",
+                        recommendation.adjustment,
+                        recommendation.parameter,
+                        if processed.has_conflict {
+                            " (conflicts with another change)"
+                        } else {
+                            ""
+                        }
+                    ))
+                    .color(Color32::WHITE),
+                );
+            }
+        });
+        ui.separator();
+    }
+}
+
+/// Report being drawn until the screenshot of it comes back from the renderer
+pub(super) struct PendingReport {
+    pub(super) report: LapReport,
+    pub(super) path: PathBuf,
+    /// Frames the report was drawn in, the screenshot is requested once the layout settled
+    pub(super) frames_drawn: usize,
+}
+
+/// `<telemetry file>_lap<N>_report.png`, next to the telemetry file.
+pub(super) fn report_file_path(source_file: &Path, lap: usize) -> PathBuf {
+    let stem = source_file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "ocypode".to_string());
+    source_file.with_file_name(format!("{}_lap{}_report.png", stem, lap))
+}
+
+pub(super) fn save_png(image: &ColorImage, path: &Path) -> Result<(), OcypodeError> {
+    let pixels: Vec<u8> = image
+        .pixels
+        .iter()
+        .flat_map(|p| p.to_srgba_unmultiplied())
+        .collect();
+    image::save_buffer(
+        path,
+        &pixels,
+        image.size[0] as u32,
+        image.size[1] as u32,
+        image::ColorType::Rgba8,
+    )
+    .context(ReportExportSnafu)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lap_points() -> Vec<TelemetryData> {
+        (0..50)
+            .map(|i| TelemetryData {
+                timestamp_ms: i * 100,
+                speed_mps: Some(30. + i as f32),
+                throttle: Some(1.),
+                steering_pct: Some(0.),
+                ..TelemetryData::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_report_file_path() {
+        assert_eq!(
+            report_file_path(Path::new("sessions/monza_20261016-143012.jsonl"), 3),
+            PathBuf::from("sessions/monza_20261016-143012_lap3_report.png")
+        );
+        assert_eq!(
+            report_file_path(Path::new("spa.ibt"), 0),
+            PathBuf::from("spa_lap0_report.png")
+        );
+    }
+
+    #[test]
+    fn test_report_stats() {
+        let points = lap_points();
+        let report = LapReport::from_lap(
+            2,
+            &points,
+            Some(92_456),
+            None,
+            &SessionInfo::default(),
+            &AnalyzerConfig::default(),
+        );
+        let stats = report.stats(SpeedUnit::Kmh);
+
+        assert_eq!(stats[0], ("Lap", "2".to_string()));
+        assert_eq!(stats[1], ("Lap time", "1:32.456".to_string()));
+        assert_eq!(stats[2], ("Top speed", "284 km/h".to_string()));
+        assert!(!stats.iter().any(|(label, _)| *label == "Over-slowing"));
+    }
+
+    #[test]
+    fn test_report_stats_incomplete_lap() {
+        let report = LapReport::from_lap(
+            0,
+            &[],
+            None,
+            None,
+            &SessionInfo::default(),
+            &AnalyzerConfig::default(),
+        );
+        let stats = report.stats(SpeedUnit::Mph);
+
+        assert_eq!(stats[1], ("Lap time", "incomplete".to_string()));
+        assert!(!stats.iter().any(|(label, _)| *label == "Top speed"));
+    }
+
+    #[test]
+    fn test_save_png() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("report.png");
+        let image = ColorImage::from_rgba_unmultiplied([4, 2], &[255, 0, 0, 255].repeat(8));
+
+        save_png(&image, &path).unwrap();
+        let saved = image::open(&path).unwrap().to_rgba8();
+        assert_eq!(saved.dimensions(), (4, 2));
+        assert_eq!(saved.get_pixel(0, 0).0, [255, 0, 0, 255]);
+    }
+}
@@ -0,0 +1,377 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use egui::{Color32, Frame, Margin, RichText, Ui};
+use egui_plot::{AxisHints, Legend, Line, PlotPoints};
+use itertools::Itertools;
+use log::error;
+
+use crate::{
+    OcypodeError,
+    session_analysis::LapFilter,
+    setup_assistant::{FindingType, SetupAssistant},
+    ui::{live::config::AppConfig, theme::Theme},
+    writer::{format_utc_timestamp, parse_utc_timestamp},
+};
+
+use super::{Session, load_telemetry_file_with_progress};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Best lap time and setup findings of one session in a project file
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct SessionTrend {
+    pub(super) path: PathBuf,
+    /// Seconds since the Unix epoch the file was recorded at
+    pub(super) timestamp_s: u64,
+    pub(super) track_name: String,
+    /// Fastest valid lap, `None` when no lap was driven from start to finish
+    pub(super) best_lap_ms: Option<u128>,
+    /// Setup assistant detections per recorded lap, by finding type
+    pub(super) findings_per_lap: HashMap<FindingType, f32>,
+}
+
+impl SessionTrend {
+    fn from_session(path: &Path, timestamp_s: u64, session: &Session) -> Self {
+        let mut setup_assistant = SetupAssistant::new();
+        setup_assistant.set_car_from_local_overrides(session.info.car_name.as_deref());
+        for point in session.laps.iter().flat_map(|l| l.telemetry.iter()) {
+            setup_assistant.process_telemetry(point);
+        }
+        let laps = session.laps.len().max(1) as f32;
+        Self {
+            path: path.to_path_buf(),
+            timestamp_s,
+            track_name: session.info.track_name.clone(),
+            best_lap_ms: session.stints.best_lap().map(|l| l.lap_time_ms),
+            findings_per_lap: setup_assistant
+                .get_findings()
+                .iter()
+                .map(|(finding_type, finding)| {
+                    (finding_type.clone(), finding.occurrence_count as f32 / laps)
+                })
+                .collect(),
+        }
+    }
+
+    fn days(&self) -> f64 {
+        self.timestamp_s as f64 / SECONDS_PER_DAY as f64
+    }
+}
+
+/// Recording time of a telemetry file, from the `<track>_<YYYYmmdd-HHMMSS>` name used
+/// by `--split-sessions` or, for other names, from the file modification time.
+pub(super) fn file_timestamp(path: &Path) -> Option<u64> {
+    let from_name = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(|stem| {
+            let (_, timestamp) = stem.rsplit_once('_')?;
+            // a `-<n>` suffix is added when two sessions start in the same second
+            parse_utc_timestamp(timestamp.get(..15)?)
+        });
+    from_name.or_else(|| {
+        let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+        Some(modified.duration_since(UNIX_EPOCH).ok()?.as_secs())
+    })
+}
+
+fn load_session_trends(
+    path: &Path,
+    lap_filter: &LapFilter,
+) -> Result<Vec<SessionTrend>, OcypodeError> {
+    let timestamp_s = file_timestamp(path).unwrap_or_default();
+    let telemetry_file =
+        load_telemetry_file_with_progress(&path.to_path_buf(), lap_filter, |_| {})?;
+    Ok(telemetry_file
+        .sessions
+        .iter()
+        .map(|session| SessionTrend::from_session(path, timestamp_s, session))
+        .collect())
+}
+
+/// Loads the project files one at a time on a background thread, sending each result
+/// as soon as it is ready.
+fn start_loading(
+    paths: Vec<PathBuf>,
+    lap_filter: LapFilter,
+) -> Receiver<(PathBuf, Result<Vec<SessionTrend>, OcypodeError>)> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for path in paths {
+            let result = load_session_trends(&path, &lap_filter);
+            // the app may have been closed while loading
+            if sender.send((path, result)).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+/// `YYYY-mm-dd` of a number of days since the Unix epoch
+fn format_day(days: f64) -> String {
+    let timestamp = format_utc_timestamp((days.max(0.) * SECONDS_PER_DAY as f64) as u64);
+    format!(
+        "{}-{}-{}",
+        &timestamp[..4],
+        &timestamp[4..6],
+        &timestamp[6..8]
+    )
+}
+
+/// Best lap times and setup findings across the telemetry files of the trend project,
+/// plotted against the time each file was recorded.
+pub(crate) struct TrendsApp {
+    app_config: AppConfig,
+    theme: Theme,
+    loader: Option<Receiver<(PathBuf, Result<Vec<SessionTrend>, OcypodeError>)>>,
+    /// Project files the loader has not reported on yet
+    pending_files: usize,
+    trends: Vec<SessionTrend>,
+    load_errors: Vec<(PathBuf, String)>,
+}
+
+impl TrendsApp {
+    pub(crate) fn new(app_config: AppConfig, cc: &eframe::CreationContext<'_>) -> Self {
+        let theme = app_config.theme.theme();
+        cc.egui_ctx.set_visuals(theme.visuals(theme.background));
+        let pending_files = app_config.trend_project.len();
+        let loader = Some(start_loading(
+            app_config.trend_project.clone(),
+            app_config.lap_filter,
+        ));
+        Self {
+            app_config,
+            theme,
+            loader,
+            pending_files,
+            trends: Vec::new(),
+            load_errors: Vec::new(),
+        }
+    }
+
+    fn receive_trends(&mut self) {
+        let Some(loader) = &self.loader else {
+            return;
+        };
+        loop {
+            match loader.try_recv() {
+                Ok((path, result)) => {
+                    self.pending_files = self.pending_files.saturating_sub(1);
+                    match result {
+                        Ok(trends) => self.trends.extend(trends),
+                        Err(e) => {
+                            error!("Could not load {:?} for the trend view: {}", path, e);
+                            self.load_errors.push((path, e.to_string()));
+                        }
+                    }
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.loader = None;
+                    self.pending_files = 0;
+                    break;
+                }
+            }
+        }
+        self.trends.sort_by_key(|t| t.timestamp_s);
+    }
+
+    /// Project files with a button to take each of them out of the project.
+    fn show_project(&mut self, ui: &mut Ui) {
+        let mut removed = None;
+        ui.label(
+            RichText::new(format!(
+                "Project: {} files{}",
+                self.app_config.trend_project.len(),
+                if self.pending_files > 0 {
+                    format!(", loading {} more", self.pending_files)
+                } else {
+                    String::new()
+                }
+            ))
+            .color(Color32::WHITE),
+        );
+        for (i, path) in self.app_config.trend_project.iter().enumerate() {
+            ui.horizontal(|ui| {
+                if ui
+                    .small_button("✖")
+                    .on_hover_text("Remove from the project")
+                    .clicked()
+                {
+                    removed = Some(i);
+                }
+                let error = self.load_errors.iter().find(|(p, _)| p == path);
+                let label = match error {
+                    Some((_, e)) => {
+                        RichText::new(format!("{} ({})", path.display(), e)).color(self.theme.brake)
+                    }
+                    None => RichText::new(path.display().to_string()).color(Color32::WHITE),
+                };
+                ui.label(label);
+            });
+        }
+
+        if let Some(i) = removed {
+            let path = self.app_config.trend_project.remove(i);
+            self.trends.retain(|t| t.path != path);
+            self.load_errors.retain(|(p, _)| *p != path);
+            if let Err(e) = self.app_config.save() {
+                error!("Could not save the trend project: {}", e);
+            }
+        }
+    }
+
+    fn show_charts(&self, ui: &mut Ui) {
+        if self.trends.is_empty() {
+            let message = if self.pending_files > 0 {
+                "Loading telemetry..."
+            } else {
+                "No sessions loaded, pass telemetry files to the trends command to add them"
+            };
+            ui.label(RichText::new(message).color(Color32::WHITE));
+            return;
+        }
+
+        let date_axis = || {
+            vec![AxisHints::new_x().formatter(|mark, _range| {
+                if mark.value.fract() == 0. {
+                    format_day(mark.value)
+                } else {
+                    String::new()
+                }
+            })]
+        };
+        let plot_height = ui.available_height() / 2.;
+        egui_plot::Plot::new("trend_best_lap")
+            .height(plot_height)
+            .show_background(false)
+            .legend(Legend::default())
+            .custom_x_axes(date_axis())
+            .y_axis_label("Best lap (s)")
+            .link_axis("trend_charts", [true, false])
+            .show(ui, |plot_ui| {
+                // lap times are only comparable on the same track, one line per track
+                let by_track = self
+                    .trends
+                    .iter()
+                    .into_group_map_by(|t| t.track_name.as_str())
+                    .into_iter()
+                    .sorted_by_key(|(track_name, _)| *track_name);
+                for (track_name, trends) in by_track {
+                    let points: Vec<[f64; 2]> = trends
+                        .iter()
+                        .filter_map(|t| Some([t.days(), t.best_lap_ms? as f64 / 1000.]))
+                        .collect();
+                    plot_ui.line(Line::new(track_name, PlotPoints::new(points)));
+                }
+            });
+
+        let finding_types = self
+            .trends
+            .iter()
+            .flat_map(|t| t.findings_per_lap.keys())
+            .unique()
+            .sorted_by_key(|t| t.to_string())
+            .collect_vec();
+        egui_plot::Plot::new("trend_findings")
+            .show_background(false)
+            .legend(Legend::default())
+            .custom_x_axes(date_axis())
+            .y_axis_label("Findings per lap")
+            .link_axis("trend_charts", [true, false])
+            .show(ui, |plot_ui| {
+                plot_ui.line(
+                    Line::new(
+                        "All findings",
+                        PlotPoints::new(
+                            self.trends
+                                .iter()
+                                .map(|t| {
+                                    [t.days(), t.findings_per_lap.values().sum::<f32>() as f64]
+                                })
+                                .collect(),
+                        ),
+                    )
+                    .color(self.theme.accent)
+                    .width(2.),
+                );
+                for finding_type in finding_types {
+                    let points: Vec<[f64; 2]> = self
+                        .trends
+                        .iter()
+                        .map(|t| {
+                            [
+                                t.days(),
+                                t.findings_per_lap.get(finding_type).copied().unwrap_or(0.) as f64,
+                            ]
+                        })
+                        .collect();
+                    plot_ui.line(Line::new(finding_type.to_string(), PlotPoints::new(points)));
+                }
+            });
+    }
+}
+
+impl eframe::App for TrendsApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        ctx.request_repaint_after(Duration::from_millis(
+            self.app_config.effective_refresh_rate_ms() as u64,
+        ));
+        self.receive_trends();
+
+        egui::TopBottomPanel::top("TrendProject")
+            .frame(
+                Frame::default()
+                    .fill(Color32::TRANSPARENT)
+                    .inner_margin(Margin::same(5)),
+            )
+            .show(ctx, |ui| self.show_project(ui));
+        egui::CentralPanel::default().show(ctx, |ui| self.show_charts(ui));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_timestamp_from_session_file_name() {
+        assert_eq!(
+            file_timestamp(Path::new("sessions/monza_20261016-143012.jsonl")),
+            Some(1_792_161_012)
+        );
+        assert_eq!(
+            file_timestamp(Path::new(
+                "sessions/spa_francorchamps_20261016-143012-1.jsonl"
+            )),
+            Some(1_792_161_012)
+        );
+    }
+
+    #[test]
+    fn test_file_timestamp_falls_back_to_modification_time() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let modified = std::fs::metadata(file.path())
+            .unwrap()
+            .modified()
+            .unwrap()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        assert_eq!(file_timestamp(file.path()), Some(modified));
+        assert_eq!(file_timestamp(Path::new("missing_file.jsonl")), None);
+    }
+
+    #[test]
+    fn test_format_day() {
+        assert_eq!(format_day(0.), "1970-01-01");
+        assert_eq!(format_day(1_792_161_012. / 86_400.), "2026-10-16");
+    }
+}
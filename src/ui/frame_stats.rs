@@ -0,0 +1,104 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use egui::{Color32, RichText, Ui};
+
+/// Frames older than this are dropped from the rolling stats
+const STATS_WINDOW: Duration = Duration::from_secs(1);
+
+/// Rolling frame rate and time spent in `update`, displayed on screen to debug performance.
+#[derive(Default)]
+pub(crate) struct FrameStats {
+    /// Start time and update duration of the frames in the last `STATS_WINDOW`
+    frames: VecDeque<(Instant, Duration)>,
+}
+
+impl FrameStats {
+    /// Record a frame whose update started at `start` and completed at `end`.
+    pub(crate) fn record_frame(&mut self, start: Instant, end: Instant) {
+        self.frames.push_back((start, end.duration_since(start)));
+        while let Some((frame_start, _)) = self.frames.front()
+            && end.duration_since(*frame_start) > STATS_WINDOW
+        {
+            self.frames.pop_front();
+        }
+    }
+
+    /// Frames per second over the rolling window, `None` until two frames were recorded.
+    pub(crate) fn fps(&self) -> Option<f32> {
+        let (first, _) = self.frames.front()?;
+        let (last, _) = self.frames.back()?;
+        let elapsed = last.duration_since(*first).as_secs_f32();
+        if elapsed <= 0. {
+            return None;
+        }
+        Some((self.frames.len() - 1) as f32 / elapsed)
+    }
+
+    /// Fraction (0.0 to 1.0) of the rolling window the UI thread spent updating frames.
+    pub(crate) fn busy_fraction(&self) -> Option<f32> {
+        let (first, _) = self.frames.front()?;
+        let (last, last_duration) = self.frames.back()?;
+        let elapsed = (last.duration_since(*first) + *last_duration).as_secs_f32();
+        if elapsed <= 0. {
+            return None;
+        }
+        let busy: Duration = self.frames.iter().map(|(_, duration)| *duration).sum();
+        Some((busy.as_secs_f32() / elapsed).min(1.))
+    }
+
+    pub(crate) fn show(&self, ui: &mut Ui) {
+        let text = match (self.fps(), self.busy_fraction()) {
+            (Some(fps), Some(busy)) => format!("{:.0} fps, {:.0}% busy", fps, busy * 100.),
+            _ => "-- fps".to_string(),
+        };
+        ui.label(RichText::new(text).color(Color32::WHITE).small());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_stats_before_two_frames() {
+        let mut stats = FrameStats::default();
+        assert!(stats.fps().is_none());
+
+        let start = Instant::now();
+        stats.record_frame(start, start);
+        assert!(stats.fps().is_none());
+    }
+
+    #[test]
+    fn test_fps_and_busy_time() {
+        let mut stats = FrameStats::default();
+        let start = Instant::now();
+        // 10 frames, 100ms apart, each taking 10ms to update
+        for i in 0..10 {
+            let frame_start = start + Duration::from_millis(i * 100);
+            stats.record_frame(frame_start, frame_start + Duration::from_millis(10));
+        }
+
+        assert!((stats.fps().unwrap() - 10.).abs() < 0.01);
+        assert!((stats.busy_fraction().unwrap() - 0.1).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_old_frames_are_dropped() {
+        let mut stats = FrameStats::default();
+        let start = Instant::now();
+        for i in 0..100 {
+            let frame_start = start + Duration::from_millis(i * 16);
+            stats.record_frame(frame_start, frame_start);
+        }
+        stats.record_frame(
+            start + Duration::from_secs(5),
+            start + Duration::from_secs(5),
+        );
+
+        assert_eq!(stats.frames.len(), 1);
+    }
+}
@@ -1,4 +1,4 @@
-use egui::{Align, Button, CornerRadius, Frame, Id, Layout, Sense, ViewportCommand};
+use egui::{Align, Button, CornerRadius, Frame, Layout};
 
 use crate::ui::{Alert, DefaultAlert};
 
@@ -14,15 +14,7 @@ impl LiveTelemetryApp {
                 ..Default::default()
             }))
             .show(ctx, |ui| {
-                let drag_sense = ui.interact(ui.max_rect(), Id::new("window-drag"), Sense::drag());
-                if drag_sense.dragged() {
-                    ui.ctx().send_viewport_cmd(ViewportCommand::StartDrag);
-                }
-                if drag_sense.drag_stopped()
-                    && let Some(outer_rect) = ui.input(|is| is.viewport().outer_rect)
-                {
-                    self.app_config.alert_window_position = outer_rect.min.into();
-                };
+                self.show_move_handle(ui, |config| &mut config.alert_window_position);
 
                 match self.app_config.alerts_layout {
                     AlertsLayout::Vertical => {
@@ -67,6 +59,32 @@ impl LiveTelemetryApp {
             });
     }
 
+    /// Display the alerts as a panel of the telemetry window, for the unified layout. The
+    /// vertical layout is a column on the left, the horizontal one a row at the bottom.
+    pub(crate) fn alerts_panel(&mut self, ctx: &egui::Context) {
+        match self.app_config.alerts_layout {
+            AlertsLayout::Vertical => {
+                egui::SidePanel::left("alerts_panel")
+                    .resizable(false)
+                    .frame(Frame::new())
+                    .show(ctx, |ui| {
+                        ui.with_layout(Layout::top_down(Align::TOP), |ui| {
+                            self.show_alerts(ui);
+                        });
+                    });
+            }
+            AlertsLayout::Horizontal => {
+                egui::TopBottomPanel::bottom("alerts_panel")
+                    .frame(Frame::new())
+                    .show(ctx, |ui| {
+                        ui.with_layout(Layout::left_to_right(Align::TOP), |ui| {
+                            self.show_alerts(ui);
+                        });
+                    });
+            }
+        }
+    }
+
     fn show_alerts(&mut self, ui: &mut egui::Ui) {
         // load warning based on telemetry data
         let mut abs_alert = DefaultAlert::abs();
@@ -86,14 +104,25 @@ impl LiveTelemetryApp {
             AlertsLayout::Vertical => Align::Center,
             AlertsLayout::Horizontal => Align::LEFT,
         };
-        abs_alert.show(ui, button_align);
-        ui.separator();
-        shift_alert.show(ui, button_align);
-        ui.separator();
-        traction_alert.show(ui, button_align);
-        ui.separator();
-        trailbrake_steering_alert.show(ui, button_align);
-        ui.separator();
-        self.scrub_slip_alert.show(ui, button_align);
+        // outline the alert of the most important issue when several fire together
+        let most_important = self.telemetry_points.back().and_then(|telemetry| {
+            self.app_config
+                .annotation_priority
+                .most_important(&telemetry.annotations)
+        });
+        let accent = self.app_config.theme.theme().accent;
+        let alerts: [&mut dyn Alert; 5] = [
+            &mut abs_alert,
+            &mut shift_alert,
+            &mut traction_alert,
+            &mut trailbrake_steering_alert,
+            &mut self.scrub_slip_alert,
+        ];
+        for (i, alert) in alerts.into_iter().enumerate() {
+            if i > 0 {
+                ui.separator();
+            }
+            alert.show_prioritized(ui, button_align, most_important, accent);
+        }
     }
 }
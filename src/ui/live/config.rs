@@ -1,13 +1,31 @@
-use egui::{Pos2, Vec2};
+use egui::{Pos2, Vec2, ViewportBuilder};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
 
 use crate::OcypodeError;
-use crate::setup_assistant::{Finding, FindingType};
+use crate::session_analysis::LapFilter;
+use crate::setup_assistant::{
+    DEFAULT_MIN_OCCURRENCES, DEFAULT_MIN_SEVERITY, Finding, FindingType, RecommendationOrder,
+    TriedRecommendation,
+};
+use crate::telemetry::{PedalDeadzones, TelemetryAnnotation, stint::DEFAULT_PACE_LAPS};
 
-use super::{HISTORY_SECONDS, REFRESH_RATE_MS};
+use crate::ui::analysis::{ChartChannel, DEFAULT_CHART_CHANNELS};
+use crate::ui::theme::ThemeName;
+use crate::writer::RecordingRetention;
+
+use super::{HISTORY_SECONDS, MAX_POINTS_PER_REFRESH, REFRESH_RATE_MS};
 
 const CONFIG_FILE_NAME: &str = "config.json";
+pub(crate) const MIN_REFRESH_RATE_MS: usize = 16;
+pub(crate) const MAX_REFRESH_RATE_MS: usize = 500;
+pub(crate) const MIN_WINDOW_SIZE_S: usize = 1;
+pub(crate) const MAX_WINDOW_SIZE_S: usize = 30;
+/// Default cap on the points kept by the live chart, ~10MB at ~1KB per point
+pub(crate) const DEFAULT_MAX_HISTORY_POINTS: usize = 10_000;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub(crate) enum AlertsLayout {
@@ -24,6 +42,159 @@ impl AlertsLayout {
     }
 }
 
+/// How the live views are arranged on screen
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum WindowLayout {
+    /// Telemetry, alerts and setup assistant in separate windows, for multi-monitor setups
+    #[default]
+    MultiWindow,
+    /// Alerts and setup assistant as panels of the telemetry window
+    Unified,
+}
+
+impl WindowLayout {
+    pub(crate) const ALL: [WindowLayout; 2] = [WindowLayout::MultiWindow, WindowLayout::Unified];
+
+    /// Size of the telemetry window, which holds every panel in the unified layout
+    pub(crate) fn main_window_size(self) -> Vec2 {
+        match self {
+            Self::MultiWindow => Vec2::new(500., 200.),
+            Self::Unified => Vec2::new(900., 600.),
+        }
+    }
+}
+
+impl std::fmt::Display for WindowLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MultiWindow => write!(f, "Multi-window"),
+            Self::Unified => write!(f, "Unified"),
+        }
+    }
+}
+
+/// Annotation types by display name, most important first. Damaging the engine comes
+/// first, then losing the car, time lost and setup hints.
+pub(crate) const DEFAULT_ANNOTATION_PRIORITY: [&str; 30] = [
+    "over_rev",
+    "front_brake_lock",
+    "rear_brake_lock",
+    "downshift_instability",
+    "transition_instability",
+    "slip",
+    "entry_oversteer",
+    "mid_corner_oversteer",
+    "wheelspin",
+    "scrub",
+    "mid_corner_understeer",
+    "exit_throttle_lift",
+    "throttle_stab",
+    "entry_steering_overshoot",
+    "trailbrake",
+    "trailbrake_release",
+    "pedal_overlap",
+    "steering_reversals",
+    "electronics_intervention",
+    "straight_line_instability",
+    "bottoming_out",
+    "load_transfer",
+    "kerb_strike",
+    "shift_point",
+    "short_shift",
+    "clutch_engagement",
+    "tire_overheating",
+    "tire_cold",
+    "camber_imbalance",
+    "tire_pressure_imbalance",
+];
+
+/// Ordering of annotation types that decides which alert is highlighted when several
+/// are active on the same telemetry point.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(transparent)]
+pub(crate) struct AnnotationPriority(pub(crate) Vec<String>);
+
+impl Default for AnnotationPriority {
+    fn default() -> Self {
+        Self(
+            DEFAULT_ANNOTATION_PRIORITY
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        )
+    }
+}
+
+impl AnnotationPriority {
+    /// Position of the annotation type in the ordering, types that are not listed rank
+    /// after all listed ones.
+    pub(crate) fn rank(&self, annotation: &TelemetryAnnotation) -> usize {
+        let name = annotation.to_string();
+        self.0
+            .iter()
+            .position(|n| *n == name)
+            .unwrap_or(self.0.len())
+    }
+
+    /// Most important active annotation, the first one wins between equal ranks.
+    pub(crate) fn most_important<'a>(
+        &self,
+        annotations: impl IntoIterator<Item = &'a TelemetryAnnotation>,
+    ) -> Option<&'a TelemetryAnnotation> {
+        annotations
+            .into_iter()
+            .filter(|a| a.is_active())
+            .min_by_key(|a| self.rank(a))
+    }
+}
+
+/// Values plotted on the x-axis of the analysis chart
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ChartXAxis {
+    #[default]
+    PointNumber,
+    /// Lap distance percentage, aligns laps of different durations
+    LapDistance,
+}
+
+/// Unit used to display speeds
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum SpeedUnit {
+    #[default]
+    Kmh,
+    Mph,
+}
+
+impl SpeedUnit {
+    pub(crate) const ALL: [SpeedUnit; 2] = [SpeedUnit::Kmh, SpeedUnit::Mph];
+
+    pub(crate) fn speed_from_mps(self, speed_mps: f32) -> f32 {
+        match self {
+            Self::Kmh => speed_mps * 3.6,
+            Self::Mph => speed_mps * 2.236_936,
+        }
+    }
+}
+
+impl std::fmt::Display for SpeedUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Kmh => write!(f, "km/h"),
+            Self::Mph => write!(f, "mph"),
+        }
+    }
+}
+
+/// How the analysis chart shows the comparison lap
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ChartLayout {
+    /// Comparison traces drawn over the selected lap
+    #[default]
+    Overlay,
+    /// Selected lap on top, comparison lap below, sharing the x-axis
+    Split,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub(crate) struct WindowPosition {
     pub(crate) x: f32,
@@ -56,6 +227,8 @@ impl From<Pos2> for WindowPosition {
 pub(crate) struct AppConfig {
     pub(crate) refresh_rate_ms: usize,
     pub(crate) window_size_s: usize,
+    /// Most telemetry points kept in memory by the live chart, regardless of the window size
+    pub(crate) max_history_points: usize,
     pub(crate) show_alerts: bool,
     pub(crate) alerts_layout: AlertsLayout,
     pub(crate) telemetry_window_position: WindowPosition,
@@ -64,6 +237,43 @@ pub(crate) struct AppConfig {
     pub(crate) setup_window_position: WindowPosition,
     pub(crate) setup_assistant_findings: HashMap<FindingType, Finding>,
     pub(crate) setup_assistant_confirmed_findings: HashSet<FindingType>,
+    /// Recommendations marked as tried in the setup window, kept across sessions
+    pub(crate) setup_assistant_tried_recommendations: HashSet<TriedRecommendation>,
+    /// Order of the recommendations in the setup window
+    pub(crate) setup_recommendation_order: RecommendationOrder,
+    /// Show the live windows as regular decorated windows instead of a transparent overlay
+    pub(crate) windowed: bool,
+    /// Separate windows for the alerts and setup assistant, or panels of a single window
+    pub(crate) window_layout: WindowLayout,
+    /// Occurrences required before a setup finding is shown as actionable
+    pub(crate) setup_min_occurrences: usize,
+    /// Severity (0.0 to 1.0) required before a setup finding is shown as actionable
+    pub(crate) setup_min_severity: f32,
+    /// Color palette used by the live and analysis windows
+    pub(crate) theme: ThemeName,
+    /// X-axis of the analysis chart
+    pub(crate) analysis_x_axis: ChartXAxis,
+    /// Layout of the analysis chart when comparing laps
+    pub(crate) analysis_chart_layout: ChartLayout,
+    /// Telemetry channels plotted by the analysis chart
+    pub(crate) analysis_chart_channels: Vec<ChartChannel>,
+    /// Show the frame rate and UI thread load, for debugging performance
+    pub(crate) show_frame_stats: bool,
+    /// Show the latest speed, gear, RPM, pedals and lap times as numbers above the chart
+    pub(crate) show_readout: bool,
+    pub(crate) speed_unit: SpeedUnit,
+    /// Recent valid laps averaged by the pace readout, live and in the stint view
+    pub(crate) pace_laps: usize,
+    /// Telemetry files compared in the trend view
+    pub(crate) trend_project: Vec<PathBuf>,
+    /// Which alert wins when several annotations are active on the same point
+    pub(crate) annotation_priority: AnnotationPriority,
+    /// Old `--split-sessions` recordings deleted when the live command starts, off by default
+    pub(crate) recording_retention: RecordingRetention,
+    /// Pedal inputs reported as released while resting below these values, off by default
+    pub(crate) pedal_deadzones: PedalDeadzones,
+    /// Laps shorter than this are dropped when a recording is loaded, off by default
+    pub(crate) lap_filter: LapFilter,
 }
 
 impl Default for AppConfig {
@@ -71,6 +281,7 @@ impl Default for AppConfig {
         Self {
             refresh_rate_ms: REFRESH_RATE_MS,
             window_size_s: HISTORY_SECONDS,
+            max_history_points: DEFAULT_MAX_HISTORY_POINTS,
             show_alerts: false,
             alerts_layout: AlertsLayout::Vertical,
             telemetry_window_position: WindowPosition::default(),
@@ -79,11 +290,70 @@ impl Default for AppConfig {
             setup_window_position: WindowPosition::default(),
             setup_assistant_findings: HashMap::new(),
             setup_assistant_confirmed_findings: HashSet::new(),
+            setup_assistant_tried_recommendations: HashSet::new(),
+            setup_recommendation_order: RecommendationOrder::default(),
+            windowed: false,
+            window_layout: WindowLayout::default(),
+            setup_min_occurrences: DEFAULT_MIN_OCCURRENCES,
+            setup_min_severity: DEFAULT_MIN_SEVERITY,
+            theme: ThemeName::default(),
+            analysis_x_axis: ChartXAxis::default(),
+            analysis_chart_layout: ChartLayout::default(),
+            analysis_chart_channels: DEFAULT_CHART_CHANNELS.to_vec(),
+            show_frame_stats: false,
+            show_readout: false,
+            speed_unit: SpeedUnit::default(),
+            pace_laps: DEFAULT_PACE_LAPS,
+            trend_project: Vec::new(),
+            annotation_priority: AnnotationPriority::default(),
+            recording_retention: RecordingRetention::default(),
+            pedal_deadzones: PedalDeadzones::default(),
+            lap_filter: LapFilter::default(),
         }
     }
 }
 
 impl AppConfig {
+    /// Refresh rate clamped to the range supported by the live view.
+    pub(crate) fn effective_refresh_rate_ms(&self) -> usize {
+        self.refresh_rate_ms
+            .clamp(MIN_REFRESH_RATE_MS, MAX_REFRESH_RATE_MS)
+    }
+
+    /// Number of telemetry points that fit in the chart window.
+    ///
+    /// The time window is capped by `max_history_points`, so a misconfigured refresh
+    /// rate cannot grow the history without bound.
+    pub(crate) fn window_size_points(&self) -> usize {
+        window_size_points(self.window_size_s, self.effective_refresh_rate_ms())
+            .min(self.max_history_points.max(1))
+    }
+
+    /// Maximum number of telemetry points drained from the channel on each refresh.
+    ///
+    /// Slower refresh rates drain proportionally more points per frame so the UI
+    /// keeps up with the collector.
+    pub(crate) fn max_points_per_refresh(&self) -> usize {
+        (MAX_POINTS_PER_REFRESH * self.effective_refresh_rate_ms() / REFRESH_RATE_MS)
+            .max(MAX_POINTS_PER_REFRESH)
+    }
+
+    /// Apply the configured window mode to a viewport.
+    ///
+    /// The overlay mode is always on top, undecorated and transparent so it can sit
+    /// over the game. The windowed mode uses a regular window, which is easier to
+    /// work with on a single monitor.
+    pub(crate) fn apply_window_mode(&self, viewport: ViewportBuilder) -> ViewportBuilder {
+        if self.windowed {
+            viewport.with_decorations(true).with_transparent(false)
+        } else {
+            viewport
+                .with_always_on_top()
+                .with_decorations(false)
+                .with_transparent(true)
+        }
+    }
+
     pub(crate) fn from_local_file() -> Option<Self> {
         let config_path = dirs::config_dir()?.join("ocypode").join(CONFIG_FILE_NAME);
 
@@ -112,3 +382,162 @@ impl AppConfig {
             .map_err(|e| OcypodeError::ConfigSerializeError { source: e })
     }
 }
+
+/// Compute how many telemetry points are displayed for a window of `window_size_s`
+/// seconds when the UI refreshes every `refresh_rate_ms` milliseconds.
+pub(crate) fn window_size_points(window_size_s: usize, refresh_rate_ms: usize) -> usize {
+    window_size_s * 1000 / refresh_rate_ms.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_size_points_formula() {
+        assert_eq!(window_size_points(5, 100), 50);
+        assert_eq!(window_size_points(5, 50), 100);
+        assert_eq!(window_size_points(10, 250), 40);
+        assert_eq!(window_size_points(1, 16), 62);
+    }
+
+    #[test]
+    fn test_window_size_points_handles_zero_refresh_rate() {
+        assert_eq!(window_size_points(5, 0), 5000);
+    }
+
+    #[test]
+    fn test_config_window_size_points_recomputes() {
+        let mut config = AppConfig::default();
+        assert_eq!(
+            config.window_size_points(),
+            HISTORY_SECONDS * 1000 / REFRESH_RATE_MS
+        );
+
+        config.window_size_s = 10;
+        assert_eq!(config.window_size_points(), 100);
+
+        config.refresh_rate_ms = 200;
+        assert_eq!(config.window_size_points(), 50);
+    }
+
+    #[test]
+    fn test_window_size_points_respects_memory_cap() {
+        let mut config = AppConfig {
+            window_size_s: 30,
+            refresh_rate_ms: MIN_REFRESH_RATE_MS,
+            ..Default::default()
+        };
+        assert_eq!(config.window_size_points(), 1875);
+
+        config.max_history_points = 500;
+        assert_eq!(config.window_size_points(), 500);
+
+        // a zero cap still keeps the latest point
+        config.max_history_points = 0;
+        assert_eq!(config.window_size_points(), 1);
+    }
+
+    fn slip(is_slip: bool) -> TelemetryAnnotation {
+        TelemetryAnnotation::Slip {
+            prev_speed: 40.,
+            cur_speed: 38.,
+            is_slip,
+            slip_ratio: None,
+        }
+    }
+
+    fn wheelspin(is_wheelspin: bool) -> TelemetryAnnotation {
+        TelemetryAnnotation::Wheelspin {
+            avg_rpm_increase_per_gear: HashMap::new(),
+            cur_gear: 2,
+            cur_rpm_increase: 120.,
+            is_wheelspin,
+            slip_ratio: None,
+        }
+    }
+
+    #[test]
+    fn test_annotation_priority_picks_most_important_active() {
+        let priority = AnnotationPriority::default();
+        let annotations = [wheelspin(true), slip(true)];
+        assert_eq!(priority.most_important(&annotations), Some(&annotations[1]));
+
+        // inactive annotations never win
+        let annotations = [wheelspin(true), slip(false)];
+        assert_eq!(priority.most_important(&annotations), Some(&annotations[0]));
+        assert_eq!(priority.most_important(&[slip(false)]), None);
+    }
+
+    #[test]
+    fn test_custom_annotation_priority() {
+        let priority: AnnotationPriority = serde_json::from_str(r#"["wheelspin"]"#).unwrap();
+        let annotations = [slip(true), wheelspin(true)];
+        assert_eq!(priority.most_important(&annotations), Some(&annotations[1]));
+        // unlisted types rank last, in their original order
+        assert_eq!(priority.rank(&slip(true)), 1);
+        assert_eq!(
+            priority.most_important(&[slip(true), slip(true)]),
+            Some(&slip(true))
+        );
+    }
+
+    #[test]
+    fn test_default_priority_has_no_duplicates() {
+        let names: HashSet<_> = DEFAULT_ANNOTATION_PRIORITY.iter().collect();
+        assert_eq!(names.len(), DEFAULT_ANNOTATION_PRIORITY.len());
+    }
+
+    #[test]
+    fn test_speed_unit_conversion() {
+        assert!((SpeedUnit::Kmh.speed_from_mps(25.) - 90.).abs() < 1e-4);
+        assert!((SpeedUnit::Mph.speed_from_mps(44.704) - 100.).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_refresh_rate_is_clamped() {
+        let config = AppConfig {
+            refresh_rate_ms: 1,
+            ..Default::default()
+        };
+        assert_eq!(config.effective_refresh_rate_ms(), MIN_REFRESH_RATE_MS);
+
+        let config = AppConfig {
+            refresh_rate_ms: 10_000,
+            ..Default::default()
+        };
+        assert_eq!(config.effective_refresh_rate_ms(), MAX_REFRESH_RATE_MS);
+    }
+
+    #[test]
+    fn test_max_points_per_refresh_scales_with_refresh_rate() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.max_points_per_refresh(), MAX_POINTS_PER_REFRESH);
+
+        config.refresh_rate_ms = REFRESH_RATE_MS * 3;
+        assert_eq!(config.max_points_per_refresh(), MAX_POINTS_PER_REFRESH * 3);
+
+        config.refresh_rate_ms = MIN_REFRESH_RATE_MS;
+        assert_eq!(config.max_points_per_refresh(), MAX_POINTS_PER_REFRESH);
+    }
+
+    #[test]
+    fn test_draining_keeps_up_with_collector_at_any_refresh_rate() {
+        // rate at which the collector sends telemetry points
+        const TELEMETRY_RATE_HZ: usize = 60;
+
+        for refresh_rate_ms in MIN_REFRESH_RATE_MS..=MAX_REFRESH_RATE_MS {
+            let config = AppConfig {
+                refresh_rate_ms,
+                ..Default::default()
+            };
+            let drained_per_s = config.max_points_per_refresh() * 1000 / refresh_rate_ms;
+            assert!(
+                drained_per_s >= TELEMETRY_RATE_HZ,
+                "{}ms refresh drains only {} points/s",
+                refresh_rate_ms,
+                drained_per_s
+            );
+        }
+    }
+}
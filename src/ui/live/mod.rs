@@ -1,33 +1,42 @@
 mod alerts_view;
 pub(crate) mod config;
+mod move_handle;
 mod setup_window;
 pub(crate) mod telemetry_view;
 
-use std::{collections::VecDeque, sync::mpsc::Receiver, time::SystemTime};
+use std::{
+    collections::VecDeque,
+    sync::mpsc::Receiver,
+    time::{Instant, SystemTime},
+};
 
-use config::AppConfig;
-use egui::{Color32, ViewportBuilder, ViewportId, Visuals, style::Widgets};
+use config::{AppConfig, WindowLayout};
+use egui::{Color32, Key, KeyboardShortcut, Modifiers, ViewportBuilder, ViewportId, Visuals};
 use log::error;
+use setup_window::{SetupReset, SetupSnapshot};
 
-use crate::setup_assistant::SetupAssistant;
-use crate::telemetry::{TelemetryData, TelemetryOutput};
+#[cfg(feature = "acc-broadcast")]
+use crate::telemetry::acc_broadcast::AccBroadcastClient;
+use crate::telemetry::{
+    TelemetryData, TelemetryOutput, analyzer_config::AnalyzerConfig, stint::LapTimer,
+};
+use crate::{setup_assistant::SetupAssistant, writer::RecordingIndex};
 
-use super::ScrubSlipAlert;
+use super::{ScrubSlipAlert, frame_stats::FrameStats, theme::Theme};
 
 const REFRESH_RATE_MS: usize = 100;
 pub(crate) const HISTORY_SECONDS: usize = 5;
 const MAX_POINTS_PER_REFRESH: usize = 10;
 const MAX_TIME_PER_REFRESH_MS: u128 = 50;
 
-pub(crate) const PALETTE_BLACK: Color32 = Color32::from_rgb(12, 12, 12);
-pub(crate) const PALETTE_BROWN: Color32 = Color32::from_rgb(72, 30, 20);
-pub(crate) const PALETTE_MAROON: Color32 = Color32::from_rgb(155, 57, 34);
-pub(crate) const PALETTE_ORANGE: Color32 = Color32::from_rgb(242, 97, 63);
-
 const DEFAULT_BUTTON_CORNER_RADIUS: u8 = 4;
 const DEFAULT_WINDOW_CORNER_RADIUS: u8 = 10;
 const DEFAULT_WINDOW_TRANSPARENCY: u8 = 191;
 
+/// Toggles move mode, showing a drag handle on every overlay window
+pub(crate) const MOVE_MODE_SHORTCUT: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::CTRL, Key::M);
+
 /// `LiveTelemetryApp` is an application that displays live telemetry data in a graphical interface.
 ///
 /// # Fields
@@ -37,6 +46,17 @@ const DEFAULT_WINDOW_TRANSPARENCY: u8 = 191;
 /// * `window_size_points` - The size of the window in points.
 /// * `telemetry_points` - A deque that stores the telemetry points.
 /// * `setup_assistant` - The setup assistant for analyzing telemetry and providing recommendations.
+/// * `show_settings` - Whether the refresh rate and window size settings are visible.
+/// * `move_mode` - Whether the overlay windows show a handle to drag them around.
+/// * `frame_stats` - Rolling frame rate and update cost, shown when enabled in the config.
+/// * `pending_setup_reset` - Setup assistant reset waiting for the driver to confirm it.
+/// * `setup_snapshot` - Setup assistant state before a reset the driver has not kept yet.
+/// * `lap_timer` - Times the valid laps of the session for the pace readout.
+/// * `session_notes` - Notes about the current session, saved next to the recording.
+/// * `recording` - Where the sessions are recorded, `None` when not recording.
+/// * `session_no` - Number of the current session among the sessions with data, from 0.
+/// * `session_has_data` - Whether a point of the current session has been received.
+/// * `acc_broadcast` - ACC broadcasting client the race standings of the readout come from.
 ///
 /// # Methods
 ///
@@ -49,6 +69,18 @@ pub struct LiveTelemetryApp {
     app_config: AppConfig,
     scrub_slip_alert: ScrubSlipAlert,
     setup_assistant: SetupAssistant,
+    show_settings: bool,
+    move_mode: bool,
+    frame_stats: FrameStats,
+    pending_setup_reset: Option<SetupReset>,
+    setup_snapshot: Option<SetupSnapshot>,
+    lap_timer: LapTimer,
+    session_notes: String,
+    recording: Option<RecordingIndex>,
+    session_no: usize,
+    session_has_data: bool,
+    #[cfg(feature = "acc-broadcast")]
+    acc_broadcast: Option<AccBroadcastClient>,
 }
 
 impl LiveTelemetryApp {
@@ -57,53 +89,96 @@ impl LiveTelemetryApp {
         app_config: AppConfig,
         cc: &eframe::CreationContext<'_>,
     ) -> Self {
-        let default_visuals = Visuals {
-            dark_mode: true,
-            hyperlink_color: PALETTE_MAROON,
-            faint_bg_color: PALETTE_BLACK,
-            extreme_bg_color: PALETTE_BROWN,
-            panel_fill: PALETTE_BLACK,
-            button_frame: true,
-            window_fill: Color32::from_rgba_premultiplied(
-                PALETTE_BLACK.r(),
-                PALETTE_BLACK.g(),
-                PALETTE_BLACK.b(),
-                DEFAULT_WINDOW_TRANSPARENCY,
-            ),
-            widgets: Widgets::dark(),
-            striped: false,
-            ..Default::default()
-        };
-        cc.egui_ctx.set_visuals(default_visuals);
-
-        let window_size_points = app_config.window_size_s * (1000 / app_config.refresh_rate_ms);
+        cc.egui_ctx
+            .set_visuals(live_visuals(&app_config.theme.theme()));
+
+        let window_size_points = app_config.window_size_points();
 
         // Create setup assistant and restore persisted state
-        let mut setup_assistant = SetupAssistant::new();
+        let mut setup_assistant = SetupAssistant::with_thresholds(
+            app_config.setup_min_occurrences,
+            app_config.setup_min_severity,
+        );
+        setup_assistant.set_corner_detection(
+            AnalyzerConfig::from_local_file()
+                .unwrap_or_default()
+                .corner_detection,
+        );
         setup_assistant.restore_findings(app_config.setup_assistant_findings.clone());
         setup_assistant
             .restore_confirmed_findings(app_config.setup_assistant_confirmed_findings.clone());
+        setup_assistant.restore_tried_recommendations(
+            app_config.setup_assistant_tried_recommendations.clone(),
+        );
+
+        let scrub_slip_alert =
+            ScrubSlipAlert::default().with_priority(app_config.annotation_priority.clone());
 
         Self {
             telemetry_receiver,
             window_size_points,
             telemetry_points: VecDeque::new(),
             app_config,
-            scrub_slip_alert: ScrubSlipAlert::default(),
+            scrub_slip_alert,
             setup_assistant,
+            show_settings: false,
+            move_mode: false,
+            frame_stats: FrameStats::default(),
+            pending_setup_reset: None,
+            setup_snapshot: None,
+            lap_timer: LapTimer::default(),
+            session_notes: String::new(),
+            recording: None,
+            session_no: 0,
+            session_has_data: false,
+            #[cfg(feature = "acc-broadcast")]
+            acc_broadcast: None,
+        }
+    }
+
+    /// Save the session notes next to the sessions listed in `recording`.
+    pub(crate) fn with_recording(mut self, recording: Option<RecordingIndex>) -> Self {
+        self.recording = recording;
+        self
+    }
+
+    /// Show the race standings of `client` in the readout, the client is stopped when
+    /// the app closes.
+    #[cfg(feature = "acc-broadcast")]
+    pub fn with_acc_broadcast(mut self, client: Option<AccBroadcastClient>) -> Self {
+        self.acc_broadcast = client;
+        self
+    }
+
+    /// Recompute the chart window after the window size or refresh rate changed.
+    pub(crate) fn update_window_size_points(&mut self) {
+        self.window_size_points = self.app_config.window_size_points();
+        while self.telemetry_points.len() > self.window_size_points {
+            self.telemetry_points.pop_front();
         }
     }
 }
 
+/// Visuals for the live windows, with translucent windows so the overlay does not
+/// hide the game.
+pub(crate) fn live_visuals(theme: &Theme) -> Visuals {
+    let background = theme.background;
+    theme.visuals(Color32::from_rgba_premultiplied(
+        background.r(),
+        background.g(),
+        background.b(),
+        DEFAULT_WINDOW_TRANSPARENCY,
+    ))
+}
+
 impl eframe::App for LiveTelemetryApp {
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
         // Save setup assistant state to config before exiting
-        self.app_config.setup_assistant_findings =
-            self.setup_assistant.get_findings_for_persistence().clone();
-        self.app_config.setup_assistant_confirmed_findings = self
-            .setup_assistant
-            .get_confirmed_findings_for_persistence()
-            .clone();
+        self.persist_setup_state();
+        // notes being typed have not lost focus yet
+        if !self.session_notes.is_empty() {
+            self.save_session_notes();
+        }
 
         if let Err(e) = self.app_config.save() {
             error!("Error while saving config file: {}", e);
@@ -111,12 +186,19 @@ impl eframe::App for LiveTelemetryApp {
     }
 
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        let frame_start = Instant::now();
         egui_extras::install_image_loaders(ctx);
 
+        if ctx.input_mut(|input| input.consume_shortcut(&MOVE_MODE_SHORTCUT)) {
+            self.move_mode = !self.move_mode;
+        }
+
         // read telemetry to window
         let start_refresh = SystemTime::now();
         // consume a few telemetry points and then exit the loop to avoid blocking the UI
         let mut points_processed = 0;
+        let max_points_per_refresh = self.app_config.max_points_per_refresh();
+        let mut points_pending = false;
         while let Ok(output) = self.telemetry_receiver.try_recv() {
             match output {
                 TelemetryOutput::DataPoint(point) => {
@@ -128,6 +210,8 @@ impl eframe::App for LiveTelemetryApp {
 
                     // Process telemetry through setup assistant
                     self.setup_assistant.process_telemetry(&point);
+                    self.lap_timer.push(&point);
+                    self.session_has_data = true;
 
                     self.telemetry_points.push_back(*point);
 
@@ -139,33 +223,56 @@ impl eframe::App for LiveTelemetryApp {
                     points_processed += 1;
 
                     // Exit if we've processed enough points or taken too long
-                    if points_processed > MAX_POINTS_PER_REFRESH
+                    if points_processed > max_points_per_refresh
                         || SystemTime::now()
                             .duration_since(start_refresh)
                             .unwrap()
                             .as_millis()
                             >= MAX_TIME_PER_REFRESH_MS
                     {
+                        points_pending = true;
                         break;
                     }
                 }
-                TelemetryOutput::SessionChange(_session_info) => {
+                TelemetryOutput::SessionChange(session_info) => {
                     // Clear setup assistant findings when session changes
                     self.setup_assistant.clear_session();
+                    self.pending_setup_reset = None;
+                    self.setup_snapshot = None;
+                    self.lap_timer = LapTimer::default();
+                    // notes being typed belong to the session that just ended
+                    if !self.session_notes.is_empty() {
+                        self.save_session_notes();
+                    }
+                    self.session_notes.clear();
+                    // a session change only starts a new session once the current one
+                    // has data, as the recorder counts them
+                    if self.session_has_data {
+                        self.session_no += 1;
+                        self.session_has_data = false;
+                    }
+                    self.setup_assistant
+                        .set_car_from_local_overrides(session_info.car_name.as_deref());
                 }
             }
         }
 
+        // the collector got ahead of the paced refresh, catch up on the next frame
+        if points_pending {
+            ctx.request_repaint();
+        }
+
         self.telemetry_view(ctx, _frame);
 
+        // the unified layout draws alerts and setup assistant inside the telemetry window
+        let multi_window = self.app_config.window_layout == WindowLayout::MultiWindow;
+
         // open separate alerts viewport
-        if self.app_config.show_alerts {
+        if multi_window && self.app_config.show_alerts {
             ctx.show_viewport_immediate(
                 ViewportId::from_hash_of("alerts"),
-                ViewportBuilder::default()
-                    .with_always_on_top()
-                    .with_decorations(false)
-                    .with_transparent(true)
+                self.app_config
+                    .apply_window_mode(ViewportBuilder::default())
                     .with_position(self.app_config.alert_window_position.clone())
                     .with_inner_size(self.app_config.alerts_layout.window_size()),
                 |ctx, class| {
@@ -179,13 +286,11 @@ impl eframe::App for LiveTelemetryApp {
         }
 
         // open separate setup window viewport
-        if self.app_config.show_setup_window {
+        if multi_window && self.app_config.show_setup_window {
             ctx.show_viewport_immediate(
                 ViewportId::from_hash_of("setup_assistant"),
-                ViewportBuilder::default()
-                    .with_always_on_top()
-                    .with_decorations(false)
-                    .with_transparent(true)
+                self.app_config
+                    .apply_window_mode(ViewportBuilder::default())
                     .with_position(self.app_config.setup_window_position.clone())
                     .with_inner_size([400.0, 600.0]),
                 |ctx, class| {
@@ -197,5 +302,7 @@ impl eframe::App for LiveTelemetryApp {
                 },
             );
         }
+
+        self.frame_stats.record_frame(frame_start, Instant::now());
     }
 }
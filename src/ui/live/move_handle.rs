@@ -0,0 +1,116 @@
+use egui::{
+    Align2, CornerRadius, CursorIcon, FontId, Pos2, Response, Sense, Ui, Vec2, ViewportCommand,
+};
+use log::error;
+
+use super::{
+    DEFAULT_BUTTON_CORNER_RADIUS, LiveTelemetryApp,
+    config::{AppConfig, WindowPosition},
+};
+
+const HANDLE_HEIGHT: f32 = 22.;
+/// Distance (points) the window has to move before the new position is saved
+const MIN_MOVE_DISTANCE: f32 = 1.;
+
+impl LiveTelemetryApp {
+    /// Show the move handle while move mode is on and save the config once the
+    /// window has been dragged to a new place.
+    ///
+    /// `position` selects the config field that stores the position of the window.
+    pub(crate) fn show_move_handle(
+        &mut self,
+        ui: &mut Ui,
+        position: fn(&mut AppConfig) -> &mut WindowPosition,
+    ) {
+        if !self.move_mode {
+            return;
+        }
+        let handle = move_handle(ui);
+        if drag_viewport(ui, &handle, position(&mut self.app_config))
+            && let Err(e) = self.app_config.save()
+        {
+            error!("Failed to save config after moving window: {}", e);
+        }
+    }
+}
+
+/// Grab bar shown across the top of an overlay window while move mode is on.
+///
+/// Dragging the bar moves the whole viewport. The overlay windows have no
+/// decorations, so this is the only way to reposition them.
+fn move_handle(ui: &mut Ui) -> Response {
+    let (rect, response) = ui.allocate_exact_size(
+        Vec2::new(ui.available_width(), HANDLE_HEIGHT),
+        Sense::drag(),
+    );
+    let visuals = ui.visuals();
+    ui.painter().rect_filled(
+        rect,
+        CornerRadius::same(DEFAULT_BUTTON_CORNER_RADIUS),
+        visuals.selection.bg_fill,
+    );
+    ui.painter().text(
+        rect.center(),
+        Align2::CENTER_CENTER,
+        "✥ Move",
+        FontId::proportional(12.),
+        visuals.strong_text_color(),
+    );
+    response.on_hover_cursor(CursorIcon::Grab)
+}
+
+/// Move the viewport while `handle` is dragged and record where it ends up.
+///
+/// Once the move starts the OS owns the pointer, so egui does not reliably report
+/// the drag stopping. The move is considered over on the first frame without the
+/// primary button held down instead. Returns true when `position` was updated and
+/// the config should be saved.
+fn drag_viewport(ui: &Ui, handle: &Response, position: &mut WindowPosition) -> bool {
+    let moving_id = handle.id.with("moving");
+    if handle.drag_started() {
+        ui.ctx().send_viewport_cmd(ViewportCommand::StartDrag);
+        ui.data_mut(|data| data.insert_temp(moving_id, true));
+    }
+
+    let is_moving = ui.data(|data| data.get_temp::<bool>(moving_id).unwrap_or(false));
+    if !is_moving || ui.input(|input| input.pointer.primary_down()) {
+        return false;
+    }
+    ui.data_mut(|data| data.remove::<bool>(moving_id));
+
+    match ui.input(|input| input.viewport().outer_rect) {
+        Some(outer_rect) => update_position(position, outer_rect.min),
+        None => false,
+    }
+}
+
+/// Store `window_min` in `position` if the window moved noticeably.
+fn update_position(position: &mut WindowPosition, window_min: Pos2) -> bool {
+    if Pos2::from(position.clone()).distance(window_min) < MIN_MOVE_DISTANCE {
+        return false;
+    }
+    *position = window_min.into();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_position_saves_moved_window() {
+        let mut position = WindowPosition { x: 10., y: 20. };
+
+        assert!(update_position(&mut position, Pos2::new(300., 150.)));
+        assert_eq!(position.x, 300.);
+        assert_eq!(position.y, 150.);
+    }
+
+    #[test]
+    fn test_update_position_ignores_jitter() {
+        let mut position = WindowPosition { x: 10., y: 20. };
+
+        assert!(!update_position(&mut position, Pos2::new(10.5, 20.)));
+        assert_eq!(position.x, 10.);
+    }
+}
@@ -1,7 +1,88 @@
-use egui::{Align, Color32, CornerRadius, Frame, Id, Layout, RichText, Sense, ViewportCommand};
+use std::collections::{HashMap, HashSet};
+
+use egui::{Align, Color32, CornerRadius, Frame, Layout, RichText};
+use egui_plot::{AxisHints, Legend, PlotPoints, Points};
+
+use crate::setup_assistant::{
+    Finding, FindingType, ProcessedRecommendation, RecommendationOrder, SetupAssistant,
+};
+use crate::telemetry::ExitUndersteerCause;
 
 use super::{DEFAULT_WINDOW_CORNER_RADIUS, LiveTelemetryApp};
 
+/// Manual reset of the setup assistant, applied once the driver confirms it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum SetupReset {
+    /// Drop every finding and confirmation
+    ClearFindings,
+    /// Keep the findings, unconfirm all of them
+    ClearConfirmations,
+    /// Count findings afresh, typically after a setup change, confirmed issues stay
+    NewSegment,
+}
+
+impl SetupReset {
+    const ALL: [SetupReset; 3] = [
+        SetupReset::ClearFindings,
+        SetupReset::ClearConfirmations,
+        SetupReset::NewSegment,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            SetupReset::ClearFindings => "Clear Findings",
+            SetupReset::ClearConfirmations => "Clear Confirmations",
+            SetupReset::NewSegment => "New Segment",
+        }
+    }
+
+    fn prompt(self) -> &'static str {
+        match self {
+            SetupReset::ClearFindings => "Clear all findings and confirmations?",
+            SetupReset::ClearConfirmations => "Unconfirm all findings?",
+            SetupReset::NewSegment => "Start counting findings again?",
+        }
+    }
+
+    pub(super) fn apply(self, setup_assistant: &mut SetupAssistant) {
+        match self {
+            SetupReset::ClearFindings => setup_assistant.clear_session(),
+            SetupReset::ClearConfirmations => {
+                setup_assistant.restore_confirmed_findings(HashSet::new())
+            }
+            SetupReset::NewSegment => {
+                let confirmed = setup_assistant
+                    .get_confirmed_findings_for_persistence()
+                    .clone();
+                setup_assistant.clear_session();
+                setup_assistant.restore_confirmed_findings(confirmed);
+            }
+        }
+    }
+}
+
+/// Setup assistant state from before a reset, kept until the driver keeps or undoes it
+pub(super) struct SetupSnapshot {
+    findings: HashMap<FindingType, Finding>,
+    confirmed_findings: HashSet<FindingType>,
+}
+
+impl SetupSnapshot {
+    pub(super) fn of(setup_assistant: &SetupAssistant) -> Self {
+        Self {
+            findings: setup_assistant.get_findings_for_persistence().clone(),
+            confirmed_findings: setup_assistant
+                .get_confirmed_findings_for_persistence()
+                .clone(),
+        }
+    }
+
+    pub(super) fn restore(self, setup_assistant: &mut SetupAssistant) {
+        setup_assistant.restore_findings(self.findings);
+        setup_assistant.restore_confirmed_findings(self.confirmed_findings);
+    }
+}
+
 impl LiveTelemetryApp {
     /// Display the Setup Window viewport.
     ///
@@ -14,10 +95,10 @@ impl LiveTelemetryApp {
     /// Implements Requirements 2.1, 5.1, 10.1, 10.2, 10.3, 10.4:
     /// - Displays findings in a separate viewport
     /// - Uses same visual styling as alerts window
-    /// - Supports draggable repositioning
+    /// - Supports repositioning in move mode
     /// - Maintains consistent UI design
     pub(crate) fn setup_window(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Top panel with draggable area for repositioning
+        // Top panel with the move handle and window controls
         egui::TopBottomPanel::top("setup_controls")
             .min_height(30.)
             .frame(Frame::new().corner_radius(CornerRadius {
@@ -26,48 +107,8 @@ impl LiveTelemetryApp {
                 ..Default::default()
             }))
             .show(ctx, |ui| {
-                // Make the entire top panel draggable
-                let drag_sense =
-                    ui.interact(ui.max_rect(), Id::new("setup-window-drag"), Sense::drag());
-                if drag_sense.dragged() {
-                    ui.ctx().send_viewport_cmd(ViewportCommand::StartDrag);
-                }
-                // Save position when drag stops
-                if drag_sense.drag_stopped()
-                    && let Some(outer_rect) = ui.input(|is| is.viewport().outer_rect)
-                {
-                    self.app_config.setup_window_position = outer_rect.min.into();
-                    // Save config immediately to persist position
-                    if let Err(e) = self.app_config.save() {
-                        log::error!("Failed to save config after window drag: {}", e);
-                    }
-                }
-
-                // Window title and controls
-                ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                    ui.heading(RichText::new("Setup Assistant").color(Color32::WHITE));
-
-                    // Add spacing to push button to the right
-                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
-                        // Clear findings button
-                        if ui.button("Clear Findings").clicked() {
-                            self.setup_assistant.clear_session();
-
-                            // Save cleared state to config
-                            self.app_config.setup_assistant_findings =
-                                self.setup_assistant.get_findings_for_persistence().clone();
-                            self.app_config.setup_assistant_confirmed_findings = self
-                                .setup_assistant
-                                .get_confirmed_findings_for_persistence()
-                                .clone();
-
-                            // Save config immediately to persist cleared state
-                            if let Err(e) = self.app_config.save() {
-                                log::error!("Failed to save config after clearing findings: {}", e);
-                            }
-                        }
-                    });
-                });
+                self.show_move_handle(ui, |config| &mut config.setup_window_position);
+                self.show_setup_header(ui);
             });
 
         // Central panel with findings and recommendations
@@ -82,6 +123,105 @@ impl LiveTelemetryApp {
             });
     }
 
+    /// Display the setup assistant as a side panel of the telemetry window, for the
+    /// unified layout.
+    pub(crate) fn setup_panel(&mut self, ctx: &egui::Context) {
+        egui::SidePanel::right("setup_panel")
+            .default_width(400.)
+            .frame(Frame::new())
+            .show(ctx, |ui| {
+                self.show_setup_header(ui);
+                ui.separator();
+                self.show_findings_list(ui);
+            });
+    }
+
+    /// Display the title, the report button and the reset controls.
+    fn show_setup_header(&mut self, ui: &mut egui::Ui) {
+        ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
+            ui.heading(RichText::new("Setup Assistant").color(Color32::WHITE));
+
+            // Add spacing to push button to the right
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                // Copy a markdown summary of confirmed findings to the clipboard
+                if ui.button("Copy Report").clicked() {
+                    ui.ctx().copy_text(self.setup_assistant.export_report());
+                }
+            });
+        });
+
+        self.show_reset_controls(ui);
+    }
+
+    /// Display the reset buttons, the confirmation prompt for the chosen reset and,
+    /// once applied, the choice to keep or undo it.
+    ///
+    /// A reset only changes the findings on screen: the state saved in the config is
+    /// the one from before the reset until the driver clicks "Keep".
+    fn show_reset_controls(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if let Some(reset) = self.pending_setup_reset {
+                ui.label(reset.prompt());
+                if ui.button("Yes").clicked() {
+                    // keep the oldest snapshot, it is the state last saved
+                    self.setup_snapshot
+                        .get_or_insert_with(|| SetupSnapshot::of(&self.setup_assistant));
+                    reset.apply(&mut self.setup_assistant);
+                    self.pending_setup_reset = None;
+                }
+                if ui.button("Cancel").clicked() {
+                    self.pending_setup_reset = None;
+                }
+                return;
+            }
+
+            for reset in SetupReset::ALL {
+                if ui.small_button(reset.label()).clicked() {
+                    self.pending_setup_reset = Some(reset);
+                }
+            }
+        });
+
+        if self.setup_snapshot.is_some() {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new("Reset not saved").color(Color32::GRAY));
+                if ui.small_button("Keep").clicked() {
+                    self.setup_snapshot = None;
+                    self.persist_setup_state();
+                    if let Err(e) = self.app_config.save() {
+                        log::error!("Failed to save config after resetting findings: {}", e);
+                    }
+                }
+                if ui.small_button("Undo").clicked()
+                    && let Some(snapshot) = self.setup_snapshot.take()
+                {
+                    snapshot.restore(&mut self.setup_assistant);
+                }
+            });
+        }
+    }
+
+    /// Copy the setup assistant state into the config, without saving it to disk.
+    ///
+    /// While a reset has not been kept, the state from before the reset is copied.
+    pub(super) fn persist_setup_state(&mut self) {
+        let (findings, confirmed_findings) = match &self.setup_snapshot {
+            Some(snapshot) => (&snapshot.findings, &snapshot.confirmed_findings),
+            None => (
+                self.setup_assistant.get_findings_for_persistence(),
+                self.setup_assistant
+                    .get_confirmed_findings_for_persistence(),
+            ),
+        };
+        self.app_config.setup_assistant_findings = findings.clone();
+        self.app_config.setup_assistant_confirmed_findings = confirmed_findings.clone();
+        // resets leave the tried recommendations alone
+        self.app_config.setup_assistant_tried_recommendations = self
+            .setup_assistant
+            .get_tried_recommendations_for_persistence()
+            .clone();
+    }
+
     /// Display the list of detected findings.
     ///
     /// Shows each finding with its type, occurrence count, and corner phase.
@@ -94,7 +234,7 @@ impl LiveTelemetryApp {
     /// - Displays each finding with type, count, and corner phase
     /// - Makes findings clickable for confirmation
     /// - Visually distinguishes confirmed vs unconfirmed findings
-    /// - Shows "No issues detected" when findings list is empty
+    /// - Shows "No issues detected" when no finding passed the actionable thresholds
     /// - Updates findings list in real-time as new telemetry arrives
     /// - Maintains scroll position during updates
     ///
@@ -107,12 +247,14 @@ impl LiveTelemetryApp {
     fn show_findings_list(&mut self, ui: &mut egui::Ui) {
         // Clone findings to avoid borrow conflicts with the scroll area closure
         // This is efficient as findings are typically small (< 20 items)
+        // Only actionable findings are listed, one-off events stay tracked until they repeat
         let findings: Vec<_> = self
             .setup_assistant
-            .get_findings()
-            .iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
+            .get_actionable_findings()
+            .into_iter()
+            .map(|f| (f.finding_type.clone(), f.clone()))
             .collect();
+        let tracked_count = self.setup_assistant.get_findings().len() - findings.len();
 
         // Show "No issues detected" message when findings list is empty
         if findings.is_empty() {
@@ -124,6 +266,17 @@ impl LiveTelemetryApp {
                     egui::RichText::new("Drive a few laps to collect data")
                         .color(egui::Color32::GRAY),
                 );
+                if tracked_count > 0 {
+                    ui.add_space(8.0);
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{} possible issue(s) tracked, waiting for more occurrences",
+                            tracked_count
+                        ))
+                        .size(12.0)
+                        .color(egui::Color32::GRAY),
+                    );
+                }
                 ui.add_space(20.0);
             });
             return;
@@ -155,14 +308,18 @@ impl LiveTelemetryApp {
                 // Track which finding was clicked (if any)
                 let mut clicked_finding: Option<crate::setup_assistant::FindingType> = None;
 
-                for (finding_type, finding) in findings_vec {
-                    let is_confirmed = self.setup_assistant.is_confirmed(&finding_type);
+                for (finding_type, finding) in &findings_vec {
+                    let is_confirmed = self.setup_assistant.is_confirmed(finding_type);
 
                     // Create a selectable label for each finding
                     // Occurrence count updates in real-time as new telemetry is processed
+                    let cause = match finding.likely_cause() {
+                        ExitUndersteerCause::Unknown => String::new(),
+                        cause => format!(", likely {}", cause),
+                    };
                     let finding_text = RichText::new(format!(
-                        "{} ({}) - {}",
-                        finding_type, finding.occurrence_count, finding.corner_phase
+                        "{} ({}) - {}{}",
+                        finding_type, finding.occurrence_count, finding.corner_phase, cause
                     ))
                     .color(Color32::WHITE);
 
@@ -184,19 +341,18 @@ impl LiveTelemetryApp {
                     ui.add_space(6.0);
                 }
 
+                egui::CollapsingHeader::new("Timeline")
+                    .id_salt("setup_findings_timeline")
+                    .show(ui, |ui| {
+                        Self::show_findings_timeline(ui, &findings_vec);
+                    });
+
                 // Toggle confirmation after the loop to avoid borrow conflicts
                 if let Some(finding_type) = clicked_finding {
                     self.setup_assistant.toggle_confirmation(finding_type);
 
-                    // Save setup assistant state to config after confirmation change
-                    self.app_config.setup_assistant_findings =
-                        self.setup_assistant.get_findings_for_persistence().clone();
-                    self.app_config.setup_assistant_confirmed_findings = self
-                        .setup_assistant
-                        .get_confirmed_findings_for_persistence()
-                        .clone();
-
                     // Save config immediately to persist confirmation state
+                    self.persist_setup_state();
                     if let Err(e) = self.app_config.save() {
                         log::error!("Failed to save config after confirmation toggle: {}", e);
                     }
@@ -210,6 +366,61 @@ impl LiveTelemetryApp {
             });
     }
 
+    /// Plot when each finding occurred during the session.
+    ///
+    /// One row per finding, with a point for every recorded occurrence against the
+    /// minutes since the first one, so an issue that gets worse as the tires wear
+    /// shows as a row that gets denser towards the right.
+    fn show_findings_timeline(ui: &mut egui::Ui, findings: &[(FindingType, Finding)]) {
+        let Some(session_start_ms) = findings
+            .iter()
+            .filter_map(|(_, f)| f.occurrence_timestamps.front())
+            .min()
+            .copied()
+        else {
+            ui.label(
+                RichText::new("No occurrence history recorded yet")
+                    .size(12.0)
+                    .color(Color32::GRAY),
+            );
+            return;
+        };
+
+        let names: Vec<String> = findings.iter().map(|(t, _)| t.to_string()).collect();
+        egui_plot::Plot::new("setup_findings_timeline_plot")
+            .height(30. + 20. * findings.len() as f32)
+            .show_background(false)
+            .legend(Legend::default())
+            .allow_scroll(false)
+            .custom_y_axes(vec![AxisHints::new_y().formatter(move |mark, _range| {
+                let row = mark.value.round();
+                if (mark.value - row).abs() < f64::EPSILON && row >= 0. {
+                    names.get(row as usize).cloned().unwrap_or_default()
+                } else {
+                    String::new()
+                }
+            })])
+            .x_axis_label("Minutes")
+            .include_y(-0.5)
+            .include_y(findings.len() as f64 - 0.5)
+            .include_x(0.)
+            .show(ui, |plot_ui| {
+                for (row, (finding_type, finding)) in findings.iter().enumerate() {
+                    let points: Vec<[f64; 2]> = finding
+                        .occurrence_timestamps
+                        .iter()
+                        .map(|t| {
+                            let minutes = t.saturating_sub(session_start_ms) as f64 / 60_000.;
+                            [minutes, row as f64]
+                        })
+                        .collect();
+                    plot_ui.points(
+                        Points::new(finding_type.to_string(), PlotPoints::new(points)).radius(2.),
+                    );
+                }
+            });
+    }
+
     /// Display setup recommendations for confirmed findings.
     ///
     /// Shows recommendations grouped by setup category, with parameter name,
@@ -225,14 +436,16 @@ impl LiveTelemetryApp {
     /// - Shows parameter name, adjustment direction, and description
     /// - Supports displaying multiple recommendation sets
     /// - Updates in real-time as confirmation state changes
-    /// - Prioritizes recommendations by impact
+    /// - Prioritizes recommendations by impact, ease of adjustment or a blend of both
     /// - Highlights conflicting recommendations
-    fn show_recommendations(&self, ui: &mut egui::Ui) {
-        // Get processed recommendations with priority and conflict detection
-        let processed_recommendations = self.setup_assistant.get_processed_recommendations();
+    /// - Marks recommendations as tried, greyed out and kept across sessions
+    /// - Lists slow, medium and fast corners separately when corners were classified
+    fn show_recommendations(&mut self, ui: &mut egui::Ui) {
+        // Processed recommendations with priority and conflict detection, per corner type
+        let mut groups = self.setup_assistant.get_recommendations_by_corner_type();
 
         // If no confirmed findings, show a message
-        if processed_recommendations.is_empty() {
+        if groups.iter().all(|group| group.recommendations.is_empty()) {
             ui.add_space(15.0);
             ui.vertical_centered(|ui| {
                 ui.label(
@@ -248,10 +461,33 @@ impl LiveTelemetryApp {
         ui.heading("Setup Recommendations");
         ui.add_space(8.0);
 
-        // Show priority info
+        // Sort order and conflict legend
         ui.horizontal(|ui| {
             ui.label(
-                egui::RichText::new("Sorted by priority • ")
+                egui::RichText::new("Sort by")
+                    .size(12.0)
+                    .color(egui::Color32::GRAY),
+            );
+            for order in RecommendationOrder::ALL {
+                if ui
+                    .selectable_value(
+                        &mut self.app_config.setup_recommendation_order,
+                        order,
+                        order.to_string(),
+                    )
+                    .on_hover_text(match order {
+                        RecommendationOrder::Impact => "Highest priority first",
+                        RecommendationOrder::Ease => "Quickest garage changes first",
+                        RecommendationOrder::Blend => "Balance priority and ease of adjustment",
+                    })
+                    .changed()
+                    && let Err(e) = self.app_config.save()
+                {
+                    log::error!("Failed to save config after changing sort order: {}", e);
+                }
+            }
+            ui.label(
+                egui::RichText::new("•")
                     .size(12.0)
                     .color(egui::Color32::GRAY),
             );
@@ -263,91 +499,263 @@ impl LiveTelemetryApp {
         });
         ui.add_space(12.0);
 
-        // Display recommendations in priority order (already sorted by process_recommendations)
-        for proc_rec in &processed_recommendations {
-            let rec = &proc_rec.recommendation;
-
-            // Priority badge, category, parameter, and adjustment on one line
-            ui.horizontal(|ui| {
-                // Priority badge with color coding
-                let priority_color = match rec.priority {
-                    5 => egui::Color32::from_rgb(255, 100, 100), // Red - highest priority
-                    4 => egui::Color32::from_rgb(255, 165, 0),   // Orange
-                    3 => egui::Color32::from_rgb(255, 215, 0),   // Yellow
-                    2 => egui::Color32::from_rgb(144, 238, 144), // Light green
-                    _ => egui::Color32::GRAY,                    // Gray - lowest
+        let is_classified = groups.iter().any(|group| group.corner_type.is_some());
+        for (group_no, group) in groups.iter_mut().enumerate() {
+            // sessions without classified corners have a single group without a heading
+            if is_classified {
+                let findings = group
+                    .findings
+                    .iter()
+                    .map(|f| format!("{} ({})", f.finding_type, f.occurrence_count))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let corners = match group.corner_type {
+                    Some(corner_type) => format!("{} corners", corner_type),
+                    None => "Other / unclassified".to_string(),
                 };
-
+                ui.add_space(4.0);
                 ui.label(
-                    egui::RichText::new(format!("P{}", rec.priority))
-                        .small()
+                    RichText::new(format!("{}: {}", corners, findings))
                         .strong()
-                        .color(priority_color),
+                        .color(Color32::WHITE),
                 );
+                ui.add_space(6.0);
+            }
 
-                // Conflict indicator
-                if proc_rec.has_conflict {
-                    ui.label(
-                        egui::RichText::new("⚠️").color(egui::Color32::from_rgb(255, 200, 100)),
-                    );
-                } else {
-                    ui.label("•");
+            self.app_config
+                .setup_recommendation_order
+                .sort(&mut group.recommendations);
+            // a parameter can be recommended for several corner types
+            ui.push_id(group_no, |ui| {
+                for proc_rec in &group.recommendations {
+                    self.show_recommendation(ui, proc_rec);
                 }
+            });
+        }
+    }
 
-                // Category badge (small, subtle)
-                ui.label(
-                    egui::RichText::new(format!("[{}]", rec.category))
-                        .small()
-                        .color(egui::Color32::DARK_GRAY),
-                );
+    /// Display one recommendation with its priority, ease, tried checkbox, confidence,
+    /// description, rationale and conflicts.
+    fn show_recommendation(&mut self, ui: &mut egui::Ui, proc_rec: &ProcessedRecommendation) {
+        let rec = &proc_rec.recommendation;
+        let mut is_tried = self.setup_assistant.is_tried(rec);
 
+        // Priority badge, category, parameter, and adjustment on one line
+        ui.horizontal(|ui| {
+            // Changes already made in an earlier session
+            if ui
+                .checkbox(&mut is_tried, "")
+                .on_hover_text("Tried, kept across sessions")
+                .changed()
+            {
+                self.setup_assistant.set_tried(rec, is_tried);
+                self.persist_setup_state();
+                if let Err(e) = self.app_config.save() {
+                    log::error!("Failed to save config after marking recommendation: {}", e);
+                }
+            }
+            // Priority badge with color coding
+            let priority_color = match rec.priority {
+                5 => egui::Color32::from_rgb(255, 100, 100), // Red - highest priority
+                4 => egui::Color32::from_rgb(255, 165, 0),   // Orange
+                3 => egui::Color32::from_rgb(255, 215, 0),   // Yellow
+                2 => egui::Color32::from_rgb(144, 238, 144), // Light green
+                _ => egui::Color32::GRAY,                    // Gray - lowest
+            };
+
+            ui.label(
+                egui::RichText::new(format!("P{}", rec.priority))
+                    .small()
+                    .strong()
+                    .color(priority_color),
+            );
+            ui.label(
+                egui::RichText::new(format!("E{}", rec.ease))
+                    .small()
+                    .color(egui::Color32::GRAY),
+            )
+            .on_hover_text("Ease of adjustment, 5 is a quick garage change, 1 a rebuild");
+
+            // Conflict indicator
+            if proc_rec.has_conflict {
                 ui.label(
-                    egui::RichText::new(&rec.parameter)
-                        .strong()
-                        .color(egui::Color32::from_rgb(242, 97, 63)),
+                    egui::RichText::new("⚠️").color(egui::Color32::from_rgb(255, 200, 100)),
                 );
-                ui.label("-");
-                ui.label(egui::RichText::new(&rec.adjustment).color(egui::Color32::WHITE));
+            } else {
+                ui.label("•");
+            }
+
+            // Category badge (small, subtle)
+            ui.label(
+                egui::RichText::new(format!("[{}]", rec.category))
+                    .small()
+                    .color(egui::Color32::DARK_GRAY),
+            );
+
+            // Tried changes are struck through so the untried ones stand out
+            let mut parameter = egui::RichText::new(&rec.parameter).strong();
+            let mut adjustment = egui::RichText::new(&rec.adjustment);
+            if is_tried {
+                parameter = parameter.strikethrough().color(egui::Color32::GRAY);
+                adjustment = adjustment.strikethrough().color(egui::Color32::GRAY);
+            } else {
+                parameter = parameter.color(egui::Color32::from_rgb(242, 97, 63));
+                adjustment = adjustment.color(egui::Color32::WHITE);
+            }
+            ui.label(parameter);
+            ui.label("-");
+            ui.label(adjustment);
+
+            // Confidence from the occurrences, severity and conflicts behind the change
+            let confidence_color = if proc_rec.confidence >= 0.6 {
+                egui::Color32::from_rgb(144, 238, 144) // Light green - well backed
+            } else if proc_rec.confidence >= 0.3 {
+                egui::Color32::from_rgb(255, 215, 0) // Yellow
+            } else {
+                egui::Color32::GRAY // Marginal or conflicted
+            };
+            ui.label(
+                egui::RichText::new(format!("{:.0}% confidence", proc_rec.confidence * 100.))
+                    .small()
+                    .color(confidence_color),
+            )
+            .on_hover_text(
+                "Based on how often and how severely the issues behind this change were detected, lowered by conflicting changes",
+            );
+        });
+
+        // Description indented below with improved readability
+        ui.horizontal(|ui| {
+            ui.add_space(15.0);
+            ui.label(
+                egui::RichText::new(&rec.description)
+                    .italics()
+                    .size(12.0)
+                    .color(egui::Color32::GRAY),
+            );
+        });
+
+        // Physics behind the change, collapsed so the list stays short
+        if let Some(rationale) = &rec.rationale {
+            ui.horizontal(|ui| {
+                ui.add_space(15.0);
+                egui::CollapsingHeader::new(egui::RichText::new("Why it helps").size(11.0))
+                    .id_salt((&rec.parameter, &rec.adjustment))
+                    .show(ui, |ui| {
+                        ui.label(
+                            egui::RichText::new(rationale)
+                                .size(11.0)
+                                .color(egui::Color32::LIGHT_GRAY),
+                        );
+                    });
             });
+        }
 
-            // Description indented below with improved readability
+        // Show conflict details if present
+        if proc_rec.has_conflict && !proc_rec.conflicts.is_empty() {
             ui.horizontal(|ui| {
                 ui.add_space(15.0);
                 ui.label(
-                    egui::RichText::new(&rec.description)
+                    egui::RichText::new("⚠️ Conflicts with: ")
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(255, 200, 100)),
+                );
+
+                let conflict_text = proc_rec
+                    .conflicts
+                    .iter()
+                    .map(|c| format!("{} ({})", c.parameter, c.adjustment))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                ui.label(
+                    egui::RichText::new(conflict_text)
+                        .size(11.0)
                         .italics()
-                        .size(12.0)
-                        .color(egui::Color32::GRAY),
+                        .color(egui::Color32::from_rgb(255, 200, 100)),
                 );
             });
+        }
 
-            // Show conflict details if present
-            if proc_rec.has_conflict && !proc_rec.conflicts.is_empty() {
-                ui.horizontal(|ui| {
-                    ui.add_space(15.0);
-                    ui.label(
-                        egui::RichText::new("⚠️ Conflicts with: ")
-                            .size(11.0)
-                            .color(egui::Color32::from_rgb(255, 200, 100)),
-                    );
+        ui.add_space(6.0);
+    }
+}
 
-                    let conflict_text = proc_rec
-                        .conflicts
-                        .iter()
-                        .map(|c| format!("{} ({})", c.parameter, c.adjustment))
-                        .collect::<Vec<_>>()
-                        .join(", ");
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use crate::setup_assistant::CornerPhase;
+
+    use super::*;
+
+    /// Assistant with understeer and oversteer found, and understeer confirmed
+    fn assistant() -> SetupAssistant {
+        let mut setup_assistant = SetupAssistant::new();
+        let findings = [
+            FindingType::CornerEntryUndersteer,
+            FindingType::MidCornerOversteer,
+        ]
+        .into_iter()
+        .map(|finding_type| {
+            let finding = Finding {
+                finding_type: finding_type.clone(),
+                occurrence_count: 3,
+                corner_phase: CornerPhase::Entry,
+                last_detected: 1000,
+                severity: 0.5,
+                occurrence_timestamps: VecDeque::new(),
+                occurrence_lap_distances: VecDeque::new(),
+                occurrence_causes: VecDeque::new(),
+                cause_tally: Default::default(),
+                corner_type_tally: Default::default(),
+            };
+            (finding_type, finding)
+        })
+        .collect();
+        setup_assistant.restore_findings(findings);
+        setup_assistant
+            .restore_confirmed_findings(HashSet::from([FindingType::CornerEntryUndersteer]));
+        setup_assistant
+    }
 
-                    ui.label(
-                        egui::RichText::new(conflict_text)
-                            .size(11.0)
-                            .italics()
-                            .color(egui::Color32::from_rgb(255, 200, 100)),
-                    );
-                });
-            }
+    #[test]
+    fn test_resets() {
+        let mut cleared = assistant();
+        SetupReset::ClearFindings.apply(&mut cleared);
+        assert!(cleared.get_findings().is_empty());
+        assert!(cleared.get_confirmed_findings_for_persistence().is_empty());
+
+        let mut unconfirmed = assistant();
+        SetupReset::ClearConfirmations.apply(&mut unconfirmed);
+        assert_eq!(unconfirmed.get_findings().len(), 2);
+        assert!(
+            unconfirmed
+                .get_confirmed_findings_for_persistence()
+                .is_empty()
+        );
+
+        let mut new_segment = assistant();
+        SetupReset::NewSegment.apply(&mut new_segment);
+        assert!(new_segment.get_findings().is_empty());
+        assert!(
+            new_segment
+                .get_confirmed_findings_for_persistence()
+                .contains(&FindingType::CornerEntryUndersteer)
+        );
+    }
 
-            ui.add_space(6.0);
-        }
+    #[test]
+    fn test_snapshot_undoes_reset() {
+        let mut setup_assistant = assistant();
+        let snapshot = SetupSnapshot::of(&setup_assistant);
+        SetupReset::ClearFindings.apply(&mut setup_assistant);
+
+        snapshot.restore(&mut setup_assistant);
+        assert_eq!(setup_assistant.get_findings().len(), 2);
+        assert_eq!(
+            setup_assistant.get_confirmed_findings_for_persistence(),
+            &HashSet::from([FindingType::CornerEntryUndersteer])
+        );
     }
 }
@@ -1,14 +1,32 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use egui::{Button, Color32, CornerRadius, Frame, Id, Layout, Sense, Vec2b, ViewportCommand};
+use egui::{
+    Button, Color32, ComboBox, CornerRadius, Frame, Layout, RichText, Slider, TextEdit, Vec2b,
+};
 use egui_plot::{Line, PlotPoints};
+use log::error;
 
-use crate::ui::stroke_shade;
+#[cfg(feature = "acc-broadcast")]
+use crate::telemetry::acc_broadcast::RaceStandings;
+use crate::{
+    session_notes::SessionNotes,
+    telemetry::{TelemetryData, stint::Pace},
+    ui::{stroke_shade, theme::ThemeName},
+};
 
 use super::{
-    DEFAULT_BUTTON_CORNER_RADIUS, DEFAULT_WINDOW_CORNER_RADIUS, LiveTelemetryApp, PALETTE_ORANGE,
+    DEFAULT_BUTTON_CORNER_RADIUS, DEFAULT_WINDOW_CORNER_RADIUS, LiveTelemetryApp,
+    MOVE_MODE_SHORTCUT,
+    config::{
+        MAX_REFRESH_RATE_MS, MAX_WINDOW_SIZE_S, MIN_REFRESH_RATE_MS, MIN_WINDOW_SIZE_S, SpeedUnit,
+        WindowLayout,
+    },
+    live_visuals,
 };
 
+/// Shown in the readout for fields the sim did not report
+const MISSING_VALUE: &str = "-";
+
 impl LiveTelemetryApp {
     pub(crate) fn telemetry_view(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::TopBottomPanel::top("settings")
@@ -19,15 +37,7 @@ impl LiveTelemetryApp {
                 ..Default::default()
             }))
             .show(ctx, |ui| {
-                let drag_sense = ui.interact(ui.max_rect(), Id::new("window-drag"), Sense::drag());
-                if drag_sense.dragged() {
-                    ui.ctx().send_viewport_cmd(ViewportCommand::StartDrag);
-                }
-                if drag_sense.drag_stopped()
-                    && let Some(outer_rect) = ui.input(|is| is.viewport().outer_rect)
-                {
-                    self.app_config.telemetry_window_position = outer_rect.min.into();
-                }
+                self.show_move_handle(ui, |config| &mut config.telemetry_window_position);
 
                 ui.with_layout(Layout::left_to_right(egui::Align::Center), |ui| {
                     ui.add_space(10.);
@@ -41,15 +51,8 @@ impl LiveTelemetryApp {
                     {
                         self.app_config.show_setup_window = !self.app_config.show_setup_window;
 
-                        // Save setup assistant state to config
-                        self.app_config.setup_assistant_findings =
-                            self.setup_assistant.get_findings_for_persistence().clone();
-                        self.app_config.setup_assistant_confirmed_findings = self
-                            .setup_assistant
-                            .get_confirmed_findings_for_persistence()
-                            .clone();
-
                         // Save config immediately to persist visibility state and findings
+                        self.persist_setup_state();
                         if let Err(e) = self.app_config.save() {
                             log::error!("Failed to save config after toggling setup window: {}", e);
                         }
@@ -63,6 +66,29 @@ impl LiveTelemetryApp {
                     {
                         self.app_config.show_alerts = !self.app_config.show_alerts;
                     };
+                    if ui
+                        .add(
+                            Button::new(RichText::new("✥").color(Color32::WHITE))
+                                .selected(self.move_mode)
+                                .corner_radius(DEFAULT_BUTTON_CORNER_RADIUS),
+                        )
+                        .on_hover_text(format!(
+                            "Move windows ({})",
+                            ctx.format_shortcut(&MOVE_MODE_SHORTCUT)
+                        ))
+                        .clicked()
+                    {
+                        self.move_mode = !self.move_mode;
+                    };
+                    if ui
+                        .add(
+                            Button::new(RichText::new("⚙").color(Color32::WHITE))
+                                .corner_radius(DEFAULT_BUTTON_CORNER_RADIUS),
+                        )
+                        .clicked()
+                    {
+                        self.show_settings = !self.show_settings;
+                    };
 
                     ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
                         ui.add_space(10.);
@@ -77,10 +103,24 @@ impl LiveTelemetryApp {
                         {
                             ctx.send_viewport_cmd(egui::ViewportCommand::Close);
                         }
+                        if self.app_config.show_frame_stats {
+                            self.frame_stats.show(ui);
+                        }
                     });
                 });
             });
 
+        // in the unified layout the other views are panels around the chart, and egui
+        // needs the panels before the central panel
+        if self.app_config.window_layout == WindowLayout::Unified {
+            if self.app_config.show_setup_window {
+                self.setup_panel(ctx);
+            }
+            if self.app_config.show_alerts {
+                self.alerts_panel(ctx);
+            }
+        }
+
         egui::CentralPanel::default()
             .frame(Frame::new().corner_radius(CornerRadius {
                 sw: DEFAULT_WINDOW_CORNER_RADIUS,
@@ -88,6 +128,17 @@ impl LiveTelemetryApp {
                 ..Default::default()
             }))
             .show(ctx, |ui| {
+                if self.show_settings {
+                    self.show_settings_panel(ui);
+                }
+                if self.app_config.show_readout
+                    && let Some(point) = self.telemetry_points.back()
+                {
+                    let pace = self.lap_timer.pace(self.app_config.pace_laps);
+                    let race_fields = self.race_fields();
+                    show_readout(ui, point, self.app_config.speed_unit, pace, race_fields);
+                }
+
                 let plot = egui_plot::Plot::new("measurements")
                     .allow_drag(false)
                     .allow_scroll(false)
@@ -115,32 +166,363 @@ impl LiveTelemetryApp {
                 let throttle_points = PlotPoints::new(throttle_vec);
                 let brake_points = PlotPoints::new(brake_vec);
                 let steering_points = PlotPoints::new(steering_vec);
+                let theme = self.app_config.theme.theme();
 
                 plot.show_background(false).show(ui, |plot_ui| {
                     plot_ui.line(
                         Line::new("Throttle", throttle_points)
-                            .color(Color32::GREEN)
+                            .color(theme.throttle)
                             .fill(0.),
                     );
                     plot_ui.line(
                         Line::new("Brake", brake_points)
                             .gradient_color(
-                                Arc::new(|point| {
+                                Arc::new(move |point| {
                                     stroke_shade(
-                                        PALETTE_ORANGE,
-                                        Color32::RED,
+                                        theme.brake_light,
+                                        theme.brake,
                                         (point.y / 100.) as f32,
                                     )
                                 }),
                                 true,
                             )
-                            .color(Color32::RED)
+                            .color(theme.brake)
                             .fill(0.),
                     );
-                    plot_ui.line(Line::new("Steering", steering_points).color(Color32::LIGHT_GRAY));
+                    plot_ui.line(Line::new("Steering", steering_points).color(theme.steering));
                 });
             });
-        // make it always repaint. TODO: can we slow down here?
-        ctx.request_repaint();
+        // repaint at the configured refresh rate, slower rates reduce CPU load
+        ctx.request_repaint_after(Duration::from_millis(
+            self.app_config.effective_refresh_rate_ms() as u64,
+        ));
+    }
+
+    /// Position, gaps and last flag from the ACC broadcasting API, empty when the client
+    /// is not running.
+    #[cfg(feature = "acc-broadcast")]
+    fn race_fields(&self) -> Vec<(&'static str, String)> {
+        self.acc_broadcast
+            .as_ref()
+            .map(|client| standings_fields(&client.standings()))
+            .unwrap_or_default()
+    }
+
+    #[cfg(not(feature = "acc-broadcast"))]
+    fn race_fields(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    /// Display the refresh rate and history window sliders, the frame stats toggle, the
+    /// layout and theme selectors and, while recording, the notes of the session.
+    ///
+    /// Changing either slider recomputes the number of points kept in the chart window,
+    /// changing the layout resizes the window and changing the theme applies it immediately.
+    fn show_settings_panel(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Refresh (ms)").color(Color32::WHITE));
+            changed |= ui
+                .add(Slider::new(
+                    &mut self.app_config.refresh_rate_ms,
+                    MIN_REFRESH_RATE_MS..=MAX_REFRESH_RATE_MS,
+                ))
+                .changed();
+        });
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Window (s)").color(Color32::WHITE));
+            changed |= ui
+                .add(Slider::new(
+                    &mut self.app_config.window_size_s,
+                    MIN_WINDOW_SIZE_S..=MAX_WINDOW_SIZE_S,
+                ))
+                .changed();
+        });
+
+        ui.checkbox(
+            &mut self.app_config.show_frame_stats,
+            RichText::new("Show FPS").color(Color32::WHITE),
+        );
+        ui.horizontal(|ui| {
+            ui.checkbox(
+                &mut self.app_config.show_readout,
+                RichText::new("Show readout").color(Color32::WHITE),
+            );
+            ComboBox::from_id_salt("speed_unit_selector")
+                .selected_text(self.app_config.speed_unit.to_string())
+                .show_ui(ui, |ui| {
+                    for unit in SpeedUnit::ALL {
+                        ui.selectable_value(
+                            &mut self.app_config.speed_unit,
+                            unit,
+                            unit.to_string(),
+                        );
+                    }
+                });
+        });
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Pace laps").color(Color32::WHITE));
+            ui.add(Slider::new(&mut self.app_config.pace_laps, 2..=20));
+        });
+
+        let prev_layout = self.app_config.window_layout;
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Layout").color(Color32::WHITE));
+            ComboBox::from_id_salt("layout_selector")
+                .selected_text(self.app_config.window_layout.to_string())
+                .show_ui(ui, |ui| {
+                    for layout in WindowLayout::ALL {
+                        ui.selectable_value(
+                            &mut self.app_config.window_layout,
+                            layout,
+                            layout.to_string(),
+                        );
+                    }
+                });
+        });
+
+        let prev_theme = self.app_config.theme;
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Theme").color(Color32::WHITE));
+            ComboBox::from_id_salt("theme_selector")
+                .selected_text(self.app_config.theme.to_string())
+                .show_ui(ui, |ui| {
+                    for theme in ThemeName::ALL {
+                        ui.selectable_value(&mut self.app_config.theme, theme, theme.to_string());
+                    }
+                });
+        });
+
+        ui.label(RichText::new("Session notes").color(Color32::WHITE));
+        if self.recording.is_some() {
+            let notes = ui.add(
+                TextEdit::multiline(&mut self.session_notes)
+                    .desired_rows(2)
+                    .hint_text("Setup, fuel, track conditions..."),
+            );
+            if notes.lost_focus() {
+                self.save_session_notes();
+            }
+        } else {
+            ui.label(
+                RichText::new("Record with --output to keep notes with the session")
+                    .color(Color32::GRAY),
+            );
+        }
+
+        if changed {
+            self.update_window_size_points();
+        }
+        if self.app_config.window_layout != prev_layout {
+            ui.ctx().send_viewport_cmd(egui::ViewportCommand::InnerSize(
+                self.app_config.window_layout.main_window_size(),
+            ));
+        }
+        if self.app_config.theme != prev_theme {
+            ui.ctx()
+                .set_visuals(live_visuals(&self.app_config.theme.theme()));
+        }
+    }
+}
+
+impl LiveTelemetryApp {
+    /// Write the notes of the current session next to the file being recorded, keeping
+    /// the notes of the other sessions in it.
+    pub(super) fn save_session_notes(&self) {
+        let Some((recording, session)) = self
+            .recording
+            .as_ref()
+            .and_then(|recording| recording.session(self.session_no))
+        else {
+            return;
+        };
+        let result = SessionNotes::load(&recording).and_then(|mut notes| {
+            *notes.get_mut(session) = self.session_notes.clone();
+            notes.save(&recording)
+        });
+        if let Err(e) = result {
+            error!(
+                "Error while saving session notes for {:?}: {}",
+                recording, e
+            );
+        }
+    }
+}
+
+/// Latest values of the point, the pace of the session and the race standings as a
+/// single row of labels.
+fn show_readout(
+    ui: &mut egui::Ui,
+    point: &TelemetryData,
+    speed_unit: SpeedUnit,
+    pace: Option<Pace>,
+    race_fields: Vec<(&'static str, String)>,
+) {
+    let pace_field = (
+        "Pace",
+        pace.map_or_else(|| MISSING_VALUE.to_string(), |pace| format_pace(&pace)),
+    );
+    ui.horizontal(|ui| {
+        for (label, value) in readout_fields(point, speed_unit)
+            .into_iter()
+            .chain([pace_field])
+            .chain(race_fields)
+        {
+            ui.label(RichText::new(label).color(Color32::GRAY));
+            ui.label(
+                RichText::new(value)
+                    .color(Color32::WHITE)
+                    .strong()
+                    .monospace(),
+            );
+            ui.add_space(6.);
+        }
+    });
+}
+
+fn readout_fields(point: &TelemetryData, speed_unit: SpeedUnit) -> Vec<(&'static str, String)> {
+    let or_missing = |value: Option<String>| value.unwrap_or_else(|| MISSING_VALUE.to_string());
+    let pct = |value: Option<f32>| or_missing(value.map(|v| format!("{:.0}%", v * 100.)));
+    vec![
+        (
+            "Speed",
+            or_missing(
+                point
+                    .speed_mps
+                    .map(|s| format!("{:.0} {}", speed_unit.speed_from_mps(s), speed_unit)),
+            ),
+        ),
+        (
+            "Gear",
+            or_missing(point.gear.map(|g| match g {
+                -1 => "R".to_string(),
+                0 => "N".to_string(),
+                g => g.to_string(),
+            })),
+        ),
+        (
+            "RPM",
+            or_missing(point.engine_rpm.map(|r| format!("{:.0}", r))),
+        ),
+        ("Throttle", pct(point.throttle)),
+        ("Brake", pct(point.brake)),
+        (
+            "Last",
+            or_missing(point.last_lap_time_s.map(format_lap_time)),
+        ),
+        (
+            "Best",
+            or_missing(point.best_lap_time_s.map(format_lap_time)),
+        ),
+    ]
+}
+
+/// Race position out of the cars in the session and the estimated gaps to the cars
+/// around, nothing until ACC reports the focused car.
+#[cfg(feature = "acc-broadcast")]
+fn standings_fields(standings: &RaceStandings) -> Vec<(&'static str, String)> {
+    let Some(position) = standings.position else {
+        return Vec::new();
+    };
+    let gap = |gap_ms: Option<f32>| {
+        gap_ms.map_or_else(
+            || MISSING_VALUE.to_string(),
+            |ms| format!("{:.1}s", ms / 1000.),
+        )
+    };
+    let mut fields = vec![
+        ("Pos", format!("{}/{}", position, standings.car_count)),
+        ("Ahead", gap(standings.gap_ahead_ms)),
+        ("Behind", gap(standings.gap_behind_ms)),
+    ];
+    if let Some(flag) = standings.last_flag {
+        fields.push(("Flag", format!("{:?}", flag)));
+    }
+    fields
+}
+
+/// Average lap time and its spread, e.g. 1:32.456 ± 0.31s
+pub(crate) fn format_pace(pace: &Pace) -> String {
+    format!(
+        "{} ± {:.2}s",
+        format_lap_time(pace.avg_lap_time_s as f32),
+        pace.std_dev_s
+    )
+}
+
+/// Lap time as minutes, seconds and milliseconds, e.g. 1:32.456
+pub(crate) fn format_lap_time(lap_time_s: f32) -> String {
+    if !lap_time_s.is_finite() || lap_time_s <= 0. {
+        return MISSING_VALUE.to_string();
+    }
+    let total_ms = (lap_time_s * 1000.).round() as u64;
+    format!(
+        "{}:{:02}.{:03}",
+        total_ms / 60_000,
+        total_ms / 1000 % 60,
+        total_ms % 1000
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_lap_time() {
+        assert_eq!(format_lap_time(92.456), "1:32.456");
+        assert_eq!(format_lap_time(59.9996), "1:00.000");
+        assert_eq!(format_lap_time(0.), MISSING_VALUE);
+    }
+
+    #[test]
+    fn test_format_pace() {
+        let pace = Pace {
+            laps: 5,
+            avg_lap_time_s: 92.456,
+            std_dev_s: 0.314,
+        };
+        assert_eq!(format_pace(&pace), "1:32.456 ± 0.31s");
+    }
+
+    #[test]
+    fn test_readout_fields() {
+        let point = TelemetryData {
+            speed_mps: Some(25.),
+            gear: Some(-1),
+            throttle: Some(0.456),
+            ..TelemetryData::default()
+        };
+        let fields = readout_fields(&point, SpeedUnit::Kmh);
+
+        assert_eq!(fields[0], ("Speed", "90 km/h".to_string()));
+        assert_eq!(fields[1], ("Gear", "R".to_string()));
+        assert_eq!(fields[2], ("RPM", MISSING_VALUE.to_string()));
+        assert_eq!(fields[3], ("Throttle", "46%".to_string()));
+    }
+
+    #[cfg(feature = "acc-broadcast")]
+    #[test]
+    fn test_standings_fields() {
+        use crate::telemetry::acc_broadcast::RaceFlag;
+
+        assert!(standings_fields(&RaceStandings::default()).is_empty());
+
+        let standings = RaceStandings {
+            position: Some(1),
+            car_count: 24,
+            gap_behind_ms: Some(1_234.),
+            last_flag: Some(RaceFlag::Green),
+            ..RaceStandings::default()
+        };
+        assert_eq!(
+            standings_fields(&standings),
+            vec![
+                ("Pos", "1/24".to_string()),
+                ("Ahead", MISSING_VALUE.to_string()),
+                ("Behind", "1.2s".to_string()),
+                ("Flag", "Green".to_string()),
+            ]
+        );
     }
 }
@@ -1,15 +1,21 @@
-use std::time::SystemTime;
+use std::sync::Arc;
 
-use egui::{Align, Button, Color32, Image, Layout, Response, RichText, Ui};
+use egui::{Align, Button, Color32, Image, Layout, Response, RichText, Stroke, StrokeKind, Ui};
 use log::debug;
 
 use crate::{
     OcypodeError,
-    telemetry::{TelemetryAnnotation, TelemetryData},
+    telemetry::{
+        TelemetryAnnotation, TelemetryData,
+        clock::{Clock, SystemClock},
+    },
+    ui::live::config::AnnotationPriority,
 };
 
 pub(crate) mod analysis;
+pub(crate) mod frame_stats;
 pub(crate) mod live;
+pub(crate) mod theme;
 
 const ALERT_DURATION_MS: u128 = 500;
 pub(crate) type AlertImageSelector<'a> = fn(&TelemetryData) -> Image<'a>;
@@ -17,6 +23,29 @@ pub(crate) type AlertImageSelector<'a> = fn(&TelemetryData) -> Image<'a>;
 pub(crate) trait Alert {
     fn update_state(&mut self, telemetry_point: &TelemetryData) -> Result<(), OcypodeError>;
     fn show(&mut self, ui: &mut Ui, align: Align) -> Response;
+    /// Returns true if the alert shows the given annotation type
+    fn reports(&self, annotation: &TelemetryAnnotation) -> bool;
+
+    /// Show the alert, outlined when it reports `most_important`, the annotation that
+    /// wins the [`AnnotationPriority`] ordering on the current point.
+    fn show_prioritized(
+        &mut self,
+        ui: &mut Ui,
+        align: Align,
+        most_important: Option<&TelemetryAnnotation>,
+        color: Color32,
+    ) -> Response {
+        let response = self.show(ui, align);
+        if most_important.is_some_and(|a| self.reports(a)) {
+            ui.painter().rect_stroke(
+                response.rect.expand(2.),
+                4.,
+                Stroke::new(2., color),
+                StrokeKind::Outside,
+            );
+        }
+        response
+    }
 }
 
 pub(crate) struct DefaultAlert<'i> {
@@ -24,6 +53,8 @@ pub(crate) struct DefaultAlert<'i> {
     current_image: Image<'i>,
     text: String,
     is_button: bool,
+    /// Display names of the annotation types the alert shows
+    annotation_types: &'static [&'static str],
 }
 
 impl<'i> DefaultAlert<'i> {
@@ -33,9 +64,16 @@ impl<'i> DefaultAlert<'i> {
             text,
             current_image: image_selector(&TelemetryData::default()),
             is_button: false,
+            annotation_types: &[],
         }
     }
 
+    /// Annotation types, by display name, the alert shows.
+    pub(crate) fn reporting(mut self, annotation_types: &'static [&'static str]) -> Self {
+        self.annotation_types = annotation_types;
+        self
+    }
+
     pub(crate) fn abs() -> Self {
         Self::with_image("ABS".to_string(), |telemetry| {
             let mut abs_image = egui::include_image!("../../assets/brake-green.png");
@@ -49,6 +87,7 @@ impl<'i> DefaultAlert<'i> {
             }
             abs_image.into()
         })
+        .reporting(&["front_brake_lock", "rear_brake_lock"])
     }
 
     pub(crate) fn shift() -> Self {
@@ -78,8 +117,37 @@ impl<'i> DefaultAlert<'i> {
                 _ => false,
             });
 
+            for annotation in &telemetry.annotations {
+                if let TelemetryAnnotation::ShiftPoint {
+                    is_over_revving,
+                    is_under_revving,
+                    ..
+                } = annotation
+                {
+                    if *is_over_revving {
+                        shift_image = egui::include_image!("../../assets/shift-red.png");
+                    } else if *is_under_revving {
+                        shift_image = egui::include_image!("../../assets/shift-orange.png");
+                    }
+                }
+            }
+
+            // an over-rev risks the engine, it wins over the shift point advice
+            if telemetry.annotations.iter().any(|p| {
+                matches!(
+                    p,
+                    TelemetryAnnotation::OverRev {
+                        is_over_rev: true,
+                        ..
+                    }
+                )
+            }) {
+                shift_image = egui::include_image!("../../assets/shift-red.png");
+            }
+
             shift_image.into()
         })
+        .reporting(&["over_rev", "short_shift", "shift_point"])
     }
 
     pub(crate) fn traction() -> Self {
@@ -92,6 +160,7 @@ impl<'i> DefaultAlert<'i> {
                     cur_gear: _,
                     cur_rpm_increase: _,
                     is_wheelspin,
+                    slip_ratio: _,
                 } => {
                     if *is_wheelspin {
                         traction_image = egui::include_image!("../../assets/wheelspin-red.png");
@@ -100,9 +169,21 @@ impl<'i> DefaultAlert<'i> {
                 }
                 _ => false,
             });
+            if telemetry.annotations.iter().any(|p| {
+                matches!(
+                    p,
+                    TelemetryAnnotation::ThrottleStab {
+                        is_stabbing: true,
+                        ..
+                    }
+                )
+            }) {
+                traction_image = egui::include_image!("../../assets/wheelspin-red.png");
+            }
 
             traction_image.into()
         })
+        .reporting(&["wheelspin", "throttle_stab"])
     }
 
     pub(crate) fn trailbrake_steering() -> Self {
@@ -125,9 +206,27 @@ impl<'i> DefaultAlert<'i> {
                 }
                 _ => false,
             });
+            if telemetry.annotations.iter().any(|p| {
+                matches!(
+                    p,
+                    TelemetryAnnotation::EntrySteeringOvershoot {
+                        is_overshoot: true,
+                        ..
+                    } | TelemetryAnnotation::SteeringReversals { is_busy: true, .. }
+                )
+            }) {
+                trailbrake_image = egui::include_image!("../../assets/steering-red.png");
+            }
 
             trailbrake_image.into()
         })
+        .reporting(&[
+            "trailbrake",
+            "trailbrake_release",
+            "entry_steering_overshoot",
+            "pedal_overlap",
+            "steering_reversals",
+        ])
     }
 
     pub(crate) fn button(mut self) -> Self {
@@ -153,22 +252,35 @@ impl Alert for DefaultAlert<'_> {
         })
         .inner
     }
+
+    fn reports(&self, annotation: &TelemetryAnnotation) -> bool {
+        let name = annotation.to_string();
+        self.annotation_types.contains(&name.as_str())
+    }
 }
 
 pub(crate) struct ScrubSlipAlert {
-    alert_start_time: SystemTime,
+    /// When the last slip or scrub was reported, the alert shows it for 500ms
+    alert_start_ms: u128,
+    clock: Arc<dyn Clock>,
     is_slip: bool,
     is_scrub: bool,
+    /// Scrub while braking through a change of direction
+    is_transition: bool,
     is_button: bool,
+    priority: AnnotationPriority,
 }
 
 impl Default for ScrubSlipAlert {
     fn default() -> Self {
         Self {
-            alert_start_time: SystemTime::now(),
+            alert_start_ms: 0,
+            clock: Arc::new(SystemClock),
             is_slip: false,
             is_scrub: false,
+            is_transition: false,
             is_button: false,
+            priority: AnnotationPriority::default(),
         }
     }
 }
@@ -178,45 +290,51 @@ impl ScrubSlipAlert {
         self.is_button = true;
         self
     }
+
+    /// Ordering that decides between slip and scrub when both are detected on a point.
+    pub(crate) fn with_priority(mut self, priority: AnnotationPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Time the alert expiry against `clock` instead of the wall clock.
+    #[cfg(test)]
+    fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns true while the last slip or scrub is still shown
+    fn is_alerting(&self) -> bool {
+        self.clock.now_ms().saturating_sub(self.alert_start_ms) < ALERT_DURATION_MS
+    }
 }
 
 impl Alert for ScrubSlipAlert {
     fn update_state(&mut self, telemetry_point: &TelemetryData) -> Result<(), OcypodeError> {
-        for annotation in &telemetry_point.annotations {
-            match annotation {
-                TelemetryAnnotation::Slip {
-                    prev_speed: _,
-                    cur_speed: _,
-                    is_slip,
-                } => {
-                    self.is_slip = *is_slip;
-                    self.alert_start_time = SystemTime::now();
-                    break;
-                }
-                TelemetryAnnotation::Scrub {
-                    avg_yaw_rate_change: _,
-                    cur_yaw_rate_change: _,
-                    is_scrubbing,
-                } => {
-                    self.is_scrub = *is_scrubbing;
-                    self.alert_start_time = SystemTime::now();
-                    break;
-                }
-                _ => continue,
-            };
+        let mut slip_or_scrub = telemetry_point
+            .annotations
+            .iter()
+            .filter(|a| self.reports(a))
+            .peekable();
+        if slip_or_scrub.peek().is_none() {
+            return Ok(());
         }
+        let most_important = self.priority.most_important(slip_or_scrub);
+        self.is_slip = matches!(most_important, Some(TelemetryAnnotation::Slip { .. }));
+        self.is_scrub = matches!(most_important, Some(TelemetryAnnotation::Scrub { .. }));
+        self.is_transition = matches!(
+            most_important,
+            Some(TelemetryAnnotation::TransitionInstability { .. })
+        );
+        self.alert_start_ms = self.clock.now_ms();
         Ok(())
     }
 
     fn show(&mut self, ui: &mut Ui, button_align: Align) -> Response {
         let mut turn_image = egui::include_image!("../../assets/turn-grey.png");
         let mut text = "slip";
-        if SystemTime::now()
-            .duration_since(self.alert_start_time)
-            .unwrap()
-            .as_millis()
-            < ALERT_DURATION_MS
-        {
+        if self.is_alerting() {
             if self.is_slip {
                 turn_image = egui::include_image!("../../assets/turn-slip-red.png");
                 text = "Slip";
@@ -225,6 +343,10 @@ impl Alert for ScrubSlipAlert {
                 turn_image = egui::include_image!("../../assets/turn-scrub-red.png");
                 text = "Scrub";
             }
+            if self.is_transition {
+                turn_image = egui::include_image!("../../assets/turn-scrub-red.png");
+                text = "Transition";
+            }
         }
 
         ui.with_layout(Layout::top_down(button_align), |ui| {
@@ -237,6 +359,15 @@ impl Alert for ScrubSlipAlert {
         })
         .inner
     }
+
+    fn reports(&self, annotation: &TelemetryAnnotation) -> bool {
+        matches!(
+            annotation,
+            TelemetryAnnotation::Slip { .. }
+                | TelemetryAnnotation::Scrub { .. }
+                | TelemetryAnnotation::TransitionInstability { .. }
+        )
+    }
 }
 
 pub(crate) fn stroke_shade(start: Color32, end: Color32, y: f32) -> Color32 {
@@ -258,3 +389,65 @@ pub(crate) fn stroke_shade(start: Color32, end: Color32, y: f32) -> Color32 {
         .unwrap(),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::clock::ManualClock;
+
+    fn slip_and_scrub() -> TelemetryData {
+        TelemetryData {
+            annotations: vec![
+                TelemetryAnnotation::Scrub {
+                    avg_yaw_rate_change: 0.1,
+                    cur_yaw_rate_change: 0.4,
+                    is_scrubbing: true,
+                },
+                TelemetryAnnotation::Slip {
+                    prev_speed: 40.,
+                    cur_speed: 38.,
+                    is_slip: true,
+                    slip_ratio: None,
+                },
+            ],
+            ..TelemetryData::default()
+        }
+    }
+
+    #[test]
+    fn test_scrub_slip_alert_follows_priority() {
+        let mut alert = ScrubSlipAlert::default();
+        alert.update_state(&slip_and_scrub()).unwrap();
+        assert!(alert.is_slip);
+        assert!(!alert.is_scrub);
+
+        let mut alert =
+            ScrubSlipAlert::default().with_priority(AnnotationPriority(vec!["scrub".to_string()]));
+        alert.update_state(&slip_and_scrub()).unwrap();
+        assert!(!alert.is_slip);
+        assert!(alert.is_scrub);
+    }
+
+    #[test]
+    fn test_scrub_slip_alert_expires_after_alert_duration() {
+        let clock = ManualClock::new(10_000);
+        let mut alert = ScrubSlipAlert::default().with_clock(Arc::new(clock.clone()));
+        alert.update_state(&slip_and_scrub()).unwrap();
+        assert!(alert.is_alerting());
+        clock.advance(ALERT_DURATION_MS as u64 - 1);
+        assert!(alert.is_alerting());
+        clock.advance(1);
+        assert!(!alert.is_alerting());
+
+        // a new slip restarts the alert
+        alert.update_state(&slip_and_scrub()).unwrap();
+        assert!(alert.is_alerting());
+    }
+
+    #[test]
+    fn test_default_alert_reports_its_annotations() {
+        let annotations = slip_and_scrub().annotations;
+        assert!(ScrubSlipAlert::default().reports(&annotations[0]));
+        assert!(!DefaultAlert::traction().reports(&annotations[1]));
+    }
+}
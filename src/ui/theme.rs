@@ -0,0 +1,154 @@
+use egui::{Color32, Visuals, style::Widgets};
+use serde::{Deserialize, Serialize};
+
+pub(crate) const PALETTE_BLACK: Color32 = Color32::from_rgb(12, 12, 12);
+pub(crate) const PALETTE_BROWN: Color32 = Color32::from_rgb(72, 30, 20);
+pub(crate) const PALETTE_MAROON: Color32 = Color32::from_rgb(155, 57, 34);
+pub(crate) const PALETTE_ORANGE: Color32 = Color32::from_rgb(242, 97, 63);
+
+/// Named color palettes the user can pick from in the settings.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ThemeName {
+    #[default]
+    Ocypode,
+    HighContrast,
+    /// Based on the Okabe-Ito palette, distinguishable with red-green color blindness
+    ColorblindSafe,
+}
+
+impl ThemeName {
+    pub(crate) const ALL: [ThemeName; 3] = [
+        ThemeName::Ocypode,
+        ThemeName::HighContrast,
+        ThemeName::ColorblindSafe,
+    ];
+
+    pub(crate) fn theme(&self) -> Theme {
+        match self {
+            ThemeName::Ocypode => Theme {
+                background: PALETTE_BLACK,
+                surface: PALETTE_BROWN,
+                accent: PALETTE_MAROON,
+                throttle: Color32::GREEN,
+                brake_light: PALETTE_ORANGE,
+                brake: Color32::RED,
+                steering: Color32::LIGHT_GRAY,
+                annotation: Color32::BLUE,
+                comparison_throttle: Color32::DARK_GREEN,
+                comparison_brake: Color32::DARK_RED,
+                comparison_steering: Color32::DARK_GRAY.gamma_multiply(0.3),
+                speed: Color32::GOLD,
+                comparison_speed: Color32::from_rgb(140, 118, 0),
+            },
+            ThemeName::HighContrast => Theme {
+                background: Color32::BLACK,
+                surface: Color32::from_rgb(40, 40, 40),
+                accent: Color32::YELLOW,
+                throttle: Color32::from_rgb(0, 255, 0),
+                brake_light: Color32::from_rgb(255, 140, 0),
+                brake: Color32::from_rgb(255, 0, 0),
+                steering: Color32::WHITE,
+                annotation: Color32::from_rgb(0, 255, 255),
+                comparison_throttle: Color32::from_rgb(0, 140, 0),
+                comparison_brake: Color32::from_rgb(150, 0, 0),
+                comparison_steering: Color32::GRAY,
+                speed: Color32::from_rgb(255, 0, 255),
+                comparison_speed: Color32::from_rgb(140, 0, 140),
+            },
+            ThemeName::ColorblindSafe => Theme {
+                background: PALETTE_BLACK,
+                surface: Color32::from_rgb(30, 40, 60),
+                accent: Color32::from_rgb(86, 180, 233),
+                throttle: Color32::from_rgb(0, 114, 178),
+                brake_light: Color32::from_rgb(240, 228, 66),
+                brake: Color32::from_rgb(213, 94, 0),
+                steering: Color32::LIGHT_GRAY,
+                annotation: Color32::from_rgb(204, 121, 167),
+                comparison_throttle: Color32::from_rgb(0, 70, 110),
+                comparison_brake: Color32::from_rgb(130, 57, 0),
+                comparison_steering: Color32::DARK_GRAY.gamma_multiply(0.3),
+                speed: Color32::from_rgb(86, 180, 233),
+                comparison_speed: Color32::from_rgb(40, 100, 130),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for ThemeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeName::Ocypode => write!(f, "Ocypode"),
+            ThemeName::HighContrast => write!(f, "High contrast"),
+            ThemeName::ColorblindSafe => write!(f, "Colorblind safe"),
+        }
+    }
+}
+
+/// Colors used to build the egui visuals and the telemetry charts.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Theme {
+    pub(crate) background: Color32,
+    pub(crate) surface: Color32,
+    pub(crate) accent: Color32,
+    pub(crate) throttle: Color32,
+    /// Brake line color at light pressure, shaded towards `brake` at full pressure
+    pub(crate) brake_light: Color32,
+    pub(crate) brake: Color32,
+    pub(crate) steering: Color32,
+    pub(crate) annotation: Color32,
+    pub(crate) comparison_throttle: Color32,
+    pub(crate) comparison_brake: Color32,
+    pub(crate) comparison_steering: Color32,
+    pub(crate) speed: Color32,
+    pub(crate) comparison_speed: Color32,
+}
+
+impl Theme {
+    /// Dark visuals using the theme colors, windows are filled with `window_fill`.
+    pub(crate) fn visuals(&self, window_fill: Color32) -> Visuals {
+        Visuals {
+            dark_mode: true,
+            hyperlink_color: self.accent,
+            faint_bg_color: self.background,
+            extreme_bg_color: self.surface,
+            panel_fill: self.background,
+            button_frame: true,
+            window_fill,
+            widgets: Widgets::dark(),
+            striped: false,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_name_serialization() {
+        for name in ThemeName::ALL {
+            let json = serde_json::to_string(&name).unwrap();
+            assert_eq!(serde_json::from_str::<ThemeName>(&json).unwrap(), name);
+        }
+    }
+
+    #[test]
+    fn test_chart_lines_are_distinct() {
+        for name in ThemeName::ALL {
+            let theme = name.theme();
+            let lines = [
+                theme.throttle,
+                theme.brake,
+                theme.steering,
+                theme.speed,
+                theme.annotation,
+            ];
+            for (i, a) in lines.iter().enumerate() {
+                for b in &lines[i + 1..] {
+                    assert_ne!(a, b, "{} theme reuses a chart color", name);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,351 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    OcypodeError,
+    session_analysis::{LapFilter, split_sessions},
+    telemetry::{TelemetryOutput, migration::RecordMigrator},
+    ui::analysis::is_legacy_format,
+};
+
+/// Problem found in a single record of a telemetry file
+#[derive(Debug, PartialEq)]
+pub(crate) enum ValidationIssue {
+    /// Line that is not a valid `TelemetryOutput` record, dropped by `--fix`
+    Malformed { line: usize, reason: String },
+    /// Data point numbered or timestamped before the previous point of its session,
+    /// dropped by `--fix`
+    OutOfOrder {
+        line: usize,
+        point_no: usize,
+        prev_point_no: usize,
+    },
+    /// Session header the analysis window refuses to load, cannot be repaired
+    InvalidSession { line: usize, reason: String },
+}
+
+impl ValidationIssue {
+    /// Whether `--fix` can repair the file by dropping the record
+    pub(crate) fn is_recoverable(&self) -> bool {
+        !matches!(self, ValidationIssue::InvalidSession { .. })
+    }
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationIssue::Malformed { line, reason } => {
+                write!(f, "line {}: malformed record: {}", line, reason)
+            }
+            ValidationIssue::OutOfOrder {
+                line,
+                point_no,
+                prev_point_no,
+            } => write!(
+                f,
+                "line {}: point {} is out of order, previous point was {}",
+                line, point_no, prev_point_no
+            ),
+            ValidationIssue::InvalidSession { line, reason } => {
+                write!(f, "line {}: {}", line, reason)
+            }
+        }
+    }
+}
+
+/// Summary of a telemetry file, counted over the records that are kept by `--fix`
+#[derive(Debug, Default)]
+pub(crate) struct ValidationReport {
+    pub(crate) sessions: usize,
+    /// Laps the analysis window lists, without the laps rejected by the lap filter and
+    /// the lap still in progress when a session ends
+    pub(crate) laps: usize,
+    pub(crate) points: usize,
+    pub(crate) issues: Vec<ValidationIssue>,
+    /// Lines of the records without recoverable issues, written by `--fix`
+    valid_lines: Vec<String>,
+}
+
+impl ValidationReport {
+    /// True when the file has issues that dropping records cannot repair
+    pub(crate) fn has_unrecoverable_issues(&self) -> bool {
+        self.issues.iter().any(|i| !i.is_recoverable())
+    }
+
+    /// Write the records without recoverable issues to `output`.
+    pub(crate) fn write_fixed(&self, output: &Path) -> Result<(), OcypodeError> {
+        let file = File::create(output).map_err(|e| OcypodeError::WriterError { source: e })?;
+        let mut writer = BufWriter::new(file);
+        for line in &self.valid_lines {
+            writeln!(writer, "{}", line).map_err(|e| OcypodeError::WriterError { source: e })?;
+        }
+        writer
+            .flush()
+            .map_err(|e| OcypodeError::WriterError { source: e })
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} sessions, {} laps, {} points",
+            self.sessions, self.laps, self.points
+        )?;
+        for issue in &self.issues {
+            writeln!(f, "  {}", issue)?;
+        }
+        match self.issues.len() {
+            0 => write!(f, "No issues found"),
+            issues => write!(f, "{} issues found", issues),
+        }
+    }
+}
+
+/// Default path of the cleaned copy, `session.jsonl` becomes `session.fixed.jsonl`
+pub(crate) fn fixed_file_path(input: &Path) -> PathBuf {
+    let stem = input
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    input.with_file_name(format!("{}.fixed.jsonl", stem))
+}
+
+/// Check every record of a JSONL telemetry file.
+///
+/// Records of older format versions are upgraded first, as the analysis window does.
+/// A record is reported when it does not parse, when a data point is numbered or
+/// timestamped before the previous point of its session, or when a session header
+/// fails `SessionInfo::validate`. Sessions and laps are split from the remaining
+/// records as the analysis window does, dropping the laps `lap_filter` rejects.
+/// Returns an error when the file does not exist, uses the legacy format or holds no
+/// session, problems with single records are collected in the report.
+pub(crate) fn validate_jsonl(
+    input: &PathBuf,
+    lap_filter: &LapFilter,
+) -> Result<ValidationReport, OcypodeError> {
+    if !input.exists() {
+        return Err(OcypodeError::InvalidTelemetryFile {
+            path: format!("{:?}", input),
+        });
+    }
+    if is_legacy_format(input) {
+        return Err(OcypodeError::LegacyTelemetryFormat);
+    }
+
+    let file = File::open(input).map_err(|e| OcypodeError::TelemetryLoaderError { source: e })?;
+    let mut report = ValidationReport::default();
+    // point number and timestamp of the last point of the current session
+    let mut prev_point: Option<(usize, u128)> = None;
+    let mut outputs = Vec::new();
+    let mut migrator = RecordMigrator::new();
+    for (line_no, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|e| OcypodeError::TelemetryLoaderError { source: e })?;
+        let line_no = line_no + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let output = match migrator.parse(&line) {
+            Ok(output) => output,
+            Err(e) => {
+                report.issues.push(ValidationIssue::Malformed {
+                    line: line_no,
+                    reason: e.to_string(),
+                });
+                continue;
+            }
+        };
+
+        match &output {
+            TelemetryOutput::DataPoint(point) => {
+                if let Some((prev_point_no, prev_timestamp_ms)) = prev_point
+                    && (point.point_no <= prev_point_no || point.timestamp_ms < prev_timestamp_ms)
+                {
+                    report.issues.push(ValidationIssue::OutOfOrder {
+                        line: line_no,
+                        point_no: point.point_no,
+                        prev_point_no,
+                    });
+                    continue;
+                }
+                prev_point = Some((point.point_no, point.timestamp_ms));
+                report.points += 1;
+            }
+            TelemetryOutput::SessionChange(session_info) => {
+                if let Err(e) = session_info.validate() {
+                    report.issues.push(ValidationIssue::InvalidSession {
+                        line: line_no,
+                        reason: e.to_string(),
+                    });
+                }
+                prev_point = None;
+            }
+        }
+        outputs.push(output);
+        report.valid_lines.push(line);
+    }
+
+    if outputs.is_empty() {
+        return Err(OcypodeError::InvalidTelemetryFile {
+            path: format!("{:?}", input),
+        });
+    }
+    let sessions = split_sessions(outputs, lap_filter);
+    report.sessions = sessions.len();
+    report.laps = sessions
+        .iter()
+        .flat_map(|session| &session.laps)
+        .filter(|lap| !lap.is_empty())
+        .count();
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::{SessionInfo, TelemetryData};
+    use tempfile::NamedTempFile;
+
+    fn session_line(track_name: &str) -> String {
+        serde_json::to_string(&TelemetryOutput::SessionChange(SessionInfo {
+            track_name: track_name.to_string(),
+            ..Default::default()
+        }))
+        .unwrap()
+    }
+
+    fn point_line(point_no: usize, lap_number: u32) -> String {
+        serde_json::to_string(&TelemetryOutput::DataPoint(Box::new(TelemetryData {
+            point_no,
+            timestamp_ms: point_no as u128 * 100,
+            lap_number: Some(lap_number),
+            ..Default::default()
+        })))
+        .unwrap()
+    }
+
+    fn write_file(lines: &[String]) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        for line in lines {
+            writeln!(file, "{}", line).unwrap();
+        }
+        file.flush().unwrap();
+        file
+    }
+
+    #[test]
+    fn test_counts_sessions_laps_and_points() {
+        let file = write_file(&[
+            session_line("Monza"),
+            point_line(1, 1),
+            point_line(2, 1),
+            point_line(3, 2),
+            session_line("Spa"),
+            point_line(4, 1),
+        ]);
+
+        let report = validate_jsonl(&file.path().to_path_buf(), &LapFilter::default()).unwrap();
+        assert_eq!(report.sessions, 2);
+        // the lap in progress at the end of the recording is not listed
+        assert_eq!(report.laps, 2);
+        assert_eq!(report.points, 4);
+        assert!(report.issues.is_empty());
+    }
+
+    #[test]
+    fn test_laps_are_counted_after_the_lap_filter() {
+        let mut lines = vec![session_line("Monza")];
+        lines.extend((1..=10).map(|i| point_line(i, 1)));
+        lines.extend((11..=12).map(|i| point_line(i, 2)));
+        lines.extend((13..=22).map(|i| point_line(i, 3)));
+        lines.push(point_line(23, 4));
+        let file = write_file(&lines);
+        let lap_filter = LapFilter {
+            min_points: 5,
+            ..LapFilter::default()
+        };
+
+        let report = validate_jsonl(&file.path().to_path_buf(), &lap_filter).unwrap();
+        assert_eq!(report.laps, 2);
+        assert_eq!(report.points, 23);
+    }
+
+    #[test]
+    fn test_flags_malformed_and_out_of_order_records() {
+        let file = write_file(&[
+            session_line("Monza"),
+            point_line(1, 1),
+            "{\"DataPoint\":{\"point_no\":".to_string(),
+            point_line(3, 1),
+            point_line(2, 1),
+        ]);
+
+        let report = validate_jsonl(&file.path().to_path_buf(), &LapFilter::default()).unwrap();
+        assert_eq!(report.points, 2);
+        assert_eq!(report.issues.len(), 2);
+        assert!(matches!(
+            report.issues[0],
+            ValidationIssue::Malformed { line: 3, .. }
+        ));
+        assert_eq!(
+            report.issues[1],
+            ValidationIssue::OutOfOrder {
+                line: 5,
+                point_no: 2,
+                prev_point_no: 3
+            }
+        );
+        assert!(!report.has_unrecoverable_issues());
+    }
+
+    #[test]
+    fn test_fixed_copy_loads_cleanly() {
+        let file = write_file(&[
+            session_line("Monza"),
+            point_line(1, 1),
+            "not json".to_string(),
+            point_line(1, 1),
+            point_line(2, 1),
+        ]);
+        let report = validate_jsonl(&file.path().to_path_buf(), &LapFilter::default()).unwrap();
+        assert_eq!(report.issues.len(), 2);
+
+        let fixed = NamedTempFile::new().unwrap();
+        report.write_fixed(fixed.path()).unwrap();
+
+        let fixed_report =
+            validate_jsonl(&fixed.path().to_path_buf(), &LapFilter::default()).unwrap();
+        assert!(fixed_report.issues.is_empty());
+        assert_eq!(fixed_report.points, 2);
+    }
+
+    #[test]
+    fn test_invalid_session_is_unrecoverable() {
+        let file = write_file(&[session_line(""), point_line(1, 1)]);
+
+        let report = validate_jsonl(&file.path().to_path_buf(), &LapFilter::default()).unwrap();
+        assert!(report.has_unrecoverable_issues());
+    }
+
+    #[test]
+    fn test_empty_file_is_an_error() {
+        let file = write_file(&["".to_string()]);
+        assert!(matches!(
+            validate_jsonl(&file.path().to_path_buf(), &LapFilter::default()),
+            Err(OcypodeError::InvalidTelemetryFile { .. })
+        ));
+    }
+
+    #[test]
+    fn test_fixed_file_path() {
+        assert_eq!(
+            fixed_file_path(Path::new("/tmp/session.jsonl")),
+            PathBuf::from("/tmp/session.fixed.jsonl")
+        );
+    }
+}
@@ -1,14 +1,53 @@
 use std::{
     fs::File,
     io::{BufWriter, Write},
-    path::PathBuf,
-    sync::mpsc::Receiver,
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError, Sender},
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use log::warn;
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
 
 use crate::{OcypodeError, telemetry::TelemetryOutput};
 
+/// How often the recorder checks for a shutdown request while no telemetry arrives
+const SHUTDOWN_POLL_INTERVAL_MS: u64 = 100;
+
+/// How long a write to a telemetry stream client may block before the client is dropped
+const CLIENT_WRITE_TIMEOUT_MS: u64 = 1000;
+
+/// Where each session of a live run is recorded, shared between the recorder and the
+/// live window. Sessions are counted from 0 in the order they are recorded, only
+/// sessions with data count, like the recorder and the analysis window split them.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RecordingIndex {
+    sessions: Arc<Mutex<Vec<(PathBuf, usize)>>>,
+}
+
+impl RecordingIndex {
+    /// File the `session`-th recorded session is written to and its index in the file,
+    /// as the analysis window numbers it. `None` until its first point is recorded.
+    pub(crate) fn session(&self, session: usize) -> Option<(PathBuf, usize)> {
+        self.sessions
+            .lock()
+            .ok()
+            .and_then(|sessions| sessions.get(session).cloned())
+    }
+
+    fn push(&self, path: &Path, session: usize) {
+        if let Ok(mut sessions) = self.sessions.lock() {
+            sessions.push((path.to_path_buf(), session));
+        }
+    }
+}
+
 #[cfg(test)]
 use std::io::BufRead;
 
@@ -32,17 +71,19 @@ use std::io::BufRead;
 /// - `timestamp_ms`: Unix timestamp in milliseconds
 /// - `game_source`: The source game (e.g., "IRacing" or "ACC")
 /// - Vehicle state: `gear`, `speed_mps`, `engine_rpm`, `max_engine_rpm`, `shift_point_rpm`
-/// - Inputs: `throttle`, `brake`, `clutch`, `steering_angle_rad`, `steering_pct`
+/// - Inputs: `throttle`, `brake`, `brake_pressure_pct`, `clutch`, `steering_angle_rad`, `steering_pct`
 /// - Position data: `lap_distance_m`, `lap_distance_pct`, `lap_number`, `track_position_pct`, `track_sector`
 /// - World coordinates: `world_position_x`, `world_position_y`, `world_position_z`
 /// - World velocity: `world_velocity_x`, `world_velocity_y`, `world_velocity_z`
 /// - Timing: `last_lap_time_s`, `best_lap_time_s`
-/// - Flags: `is_pit_limiter_engaged`, `is_in_pit_lane`, `is_abs_active`
+/// - Flags: `is_pit_limiter_engaged`, `is_in_pit_lane`, `is_abs_active`, `is_tc_active`
 /// - GPS (iRacing only): `latitude_deg`, `longitude_deg`
 /// - Acceleration: `lateral_accel_mps2`, `longitudinal_accel_mps2`
 /// - Orientation: `pitch_rad`, `roll_rad`, `yaw_rad`
 /// - Rates (iRacing only): `pitch_rate_rps`, `roll_rate_rps`, `yaw_rate_rps`
 /// - Tire data: `lf_tire_info`, `rf_tire_info`, `lr_tire_info`, `rr_tire_info`
+/// - Wheel speeds: `lf_wheel_speed_mps`, `rf_wheel_speed_mps`, `lr_wheel_speed_mps`, `rr_wheel_speed_mps`
+/// - Wheel slip ratios (ACC only): `lf_slip_ratio`, `rf_slip_ratio`, `lr_slip_ratio`, `rr_slip_ratio`
 /// - `annotations`: Array of analyzer-generated annotations (slip, wheelspin, etc.)
 ///
 /// Example:
@@ -82,8 +123,107 @@ pub fn write_telemetry(
     telemetry_receiver: Receiver<TelemetryOutput>,
 ) -> Result<(), OcypodeError> {
     let telemetry_file = File::create(file).map_err(|e| OcypodeError::WriterError { source: e })?;
-    let mut telemetry_file_writer = BufWriter::new(telemetry_file);
+    write_jsonl(BufWriter::new(telemetry_file), telemetry_receiver)
+}
+
+/// Streams telemetry to stdout in the JSON Lines format of [`write_telemetry`], one line
+/// per point as it arrives, for other tools to consume.
+pub fn stream_telemetry_stdout(
+    telemetry_receiver: Receiver<TelemetryOutput>,
+) -> Result<(), OcypodeError> {
+    // stdout is line buffered, so each point is flushed as soon as it is written
+    write_jsonl(std::io::stdout().lock(), telemetry_receiver)
+}
+
+/// Clients of the telemetry stream and the session a new client starts from
+#[derive(Default)]
+struct StreamClients {
+    clients: Vec<TcpStream>,
+    session_change: Option<String>,
+}
+
+/// Streams telemetry as JSON Lines to every client connected to `listener`, for
+/// `ocypode live --remote` on another machine.
+///
+/// Clients can connect and leave at any time. A new client first receives the current
+/// session, then every point from then on. A client that stops reading is dropped rather
+/// than holding up the others. Runs until the collector stops sending telemetry.
+pub fn serve_telemetry_tcp(listener: TcpListener, telemetry_receiver: Receiver<TelemetryOutput>) {
+    let state = Arc::new(Mutex::new(StreamClients::default()));
+    let accept_state = Arc::clone(&state);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => accept_client(stream, &accept_state),
+                Err(e) => warn!("Could not accept telemetry stream client: {}", e),
+            }
+        }
+    });
 
+    for point in &telemetry_receiver {
+        let line = match serde_json::to_string(&point) {
+            Ok(json) => format!("{}\n", json),
+            Err(e) => {
+                warn!("Skipping telemetry point due to serialization error: {}", e);
+                continue;
+            }
+        };
+        // write outside the lock, a slow client must not hold up new connections
+        let mut clients = {
+            let Ok(mut state) = state.lock() else {
+                return;
+            };
+            if matches!(point, TelemetryOutput::SessionChange(_)) {
+                state.session_change = Some(line.clone());
+            }
+            std::mem::take(&mut state.clients)
+        };
+        clients.retain_mut(|client| match client.write_all(line.as_bytes()) {
+            Ok(()) => true,
+            Err(e) => {
+                info!("Telemetry stream client disconnected: {}", e);
+                false
+            }
+        });
+        let Ok(mut state) = state.lock() else {
+            return;
+        };
+        // clients that connected while writing are already in the list
+        clients.append(&mut state.clients);
+        state.clients = clients;
+    }
+}
+
+fn accept_client(mut stream: TcpStream, state: &Mutex<StreamClients>) {
+    let peer = stream
+        .peer_addr()
+        .map(|addr| addr.to_string())
+        .unwrap_or_default();
+    let configured = stream
+        .set_write_timeout(Some(Duration::from_millis(CLIENT_WRITE_TIMEOUT_MS)))
+        .and_then(|_| stream.set_nodelay(true));
+    if let Err(e) = configured {
+        warn!("Could not set up telemetry stream client {}: {}", peer, e);
+        return;
+    }
+
+    let Ok(mut state) = state.lock() else {
+        return;
+    };
+    if let Some(session_change) = &state.session_change
+        && let Err(e) = stream.write_all(session_change.as_bytes())
+    {
+        warn!("Could not send the session to {}: {}", peer, e);
+        return;
+    }
+    info!("Telemetry stream client {} connected", peer);
+    state.clients.push(stream);
+}
+
+fn write_jsonl(
+    mut telemetry_file_writer: impl Write,
+    telemetry_receiver: Receiver<TelemetryOutput>,
+) -> Result<(), OcypodeError> {
     for point in &telemetry_receiver {
         // Serialize TelemetryOutput to JSON
         // This includes TelemetryData (with game_source) for DataPoint
@@ -94,11 +234,10 @@ pub fn write_telemetry(
         });
 
         match json_line {
-            Ok(json) => {
-                if let Err(e) = writeln!(telemetry_file_writer, "{}", json) {
-                    warn!("Error while writing telemetry point to output file: {}", e);
-                }
-            }
+            // a closed pipe or a full disk will not take the next points either, stop and
+            // let the collector drop this sink
+            Ok(json) => writeln!(telemetry_file_writer, "{}", json)
+                .map_err(|e| OcypodeError::WriterError { source: e })?,
             Err(e) => {
                 warn!("Skipping telemetry point due to serialization error: {}", e);
             }
@@ -111,6 +250,334 @@ pub fn write_telemetry(
     Ok(())
 }
 
+/// A file being written by a file writer running on its own thread
+struct RecordingFile {
+    path: PathBuf,
+    sender: Sender<TelemetryOutput>,
+    handle: JoinHandle<Result<(), OcypodeError>>,
+    has_data: bool,
+    /// Index of the session being written, a session change only starts a new session
+    /// once the current one has data, like the analysis window splits them
+    session: usize,
+    session_has_data: bool,
+    recording: RecordingIndex,
+}
+
+impl RecordingFile {
+    fn start<F>(path: PathBuf, write_file: &F, recording: &RecordingIndex) -> Self
+    where
+        F: Fn(&PathBuf, Receiver<TelemetryOutput>) -> Result<(), OcypodeError>
+            + Clone
+            + Send
+            + 'static,
+    {
+        info!("Recording telemetry to {:?}", path);
+        let (sender, receiver) = mpsc::channel();
+        let write_file = write_file.clone();
+        let thread_path = path.clone();
+        let handle = thread::spawn(move || write_file(&thread_path, receiver));
+        Self {
+            path,
+            sender,
+            handle,
+            has_data: false,
+            session: 0,
+            session_has_data: false,
+            recording: recording.clone(),
+        }
+    }
+
+    fn send(&mut self, output: TelemetryOutput) {
+        match &output {
+            TelemetryOutput::DataPoint(_) => {
+                if !self.session_has_data {
+                    self.recording.push(&self.path, self.session);
+                }
+                self.has_data = true;
+                self.session_has_data = true;
+            }
+            TelemetryOutput::SessionChange(_) if self.session_has_data => {
+                self.session += 1;
+                self.session_has_data = false;
+            }
+            TelemetryOutput::SessionChange(_) => {}
+        }
+        // The writer thread only stops early on an error, which `close` reports
+        let _ = self.sender.send(output);
+    }
+
+    /// Closes the channel and waits for the writer to flush and close the file.
+    fn close(self) {
+        drop(self.sender);
+        match self.handle.join() {
+            Ok(Ok(())) => info!("Closed telemetry file {:?}", self.path),
+            Ok(Err(e)) => error!("Error while writing telemetry to {:?}: {}", self.path, e),
+            Err(_) => error!("Telemetry writer for {:?} panicked", self.path),
+        }
+    }
+}
+
+/// Records telemetry with `write_file` until the channel closes or `shutdown` is set.
+///
+/// `write_file` is a file writer such as [`write_telemetry`], it runs on its own
+/// thread and the file is always flushed and closed before this function returns,
+/// which lets the Ctrl-C handler wait for a complete file. Points already queued in
+/// the channel when `shutdown` is set are still recorded.
+///
+/// When `split_sessions` is false `output` is the file to write. Otherwise it is a
+/// directory, created if missing, and every `SessionChange` that arrives after
+/// telemetry has been recorded starts a new file. Files are named after the track
+/// and the UTC time the session started, e.g. `monza_20261016-143012.jsonl`. Every
+/// session is added to `recording` when its first point is recorded.
+pub fn record_telemetry<F>(
+    output: &Path,
+    split_sessions: bool,
+    extension: &str,
+    telemetry_receiver: Receiver<TelemetryOutput>,
+    shutdown: &AtomicBool,
+    recording: &RecordingIndex,
+    write_file: F,
+) -> Result<(), OcypodeError>
+where
+    F: Fn(&PathBuf, Receiver<TelemetryOutput>) -> Result<(), OcypodeError> + Clone + Send + 'static,
+{
+    if split_sessions {
+        std::fs::create_dir_all(output).map_err(|e| OcypodeError::WriterError { source: e })?;
+    }
+
+    let mut current: Option<RecordingFile> = None;
+    loop {
+        let point = if shutdown.load(Ordering::SeqCst) {
+            // the collector may still be sending, record what is queued and stop there
+            match telemetry_receiver.try_recv() {
+                Ok(point) => point,
+                Err(_) => break,
+            }
+        } else {
+            match telemetry_receiver.recv_timeout(Duration::from_millis(SHUTDOWN_POLL_INTERVAL_MS))
+            {
+                Ok(point) => point,
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        };
+
+        let track_name = match &point {
+            TelemetryOutput::SessionChange(session_info) => Some(session_info.track_name.as_str()),
+            TelemetryOutput::DataPoint(_) => None,
+        };
+        let starts_new_file = match &current {
+            None => true,
+            Some(file) => split_sessions && track_name.is_some() && file.has_data,
+        };
+        if starts_new_file {
+            if let Some(file) = current.take() {
+                file.close();
+            }
+            let path = if split_sessions {
+                session_file_path(output, track_name.unwrap_or_default(), extension)
+            } else {
+                output.to_path_buf()
+            };
+            current = Some(RecordingFile::start(path, &write_file, recording));
+        }
+        if let Some(file) = current.as_mut() {
+            file.send(point);
+        }
+    }
+
+    if let Some(file) = current {
+        file.close();
+    }
+    Ok(())
+}
+
+/// Extensions of the recordings written with `--split-sessions`
+const RECORDING_EXTENSIONS: [&str; 2] = ["jsonl", "parquet"];
+
+/// Which old `--split-sessions` recordings are deleted when the live command starts.
+///
+/// Opt-in, nothing is deleted while both limits are unset, which is the default.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub(crate) struct RecordingRetention {
+    /// Keep only this many of the most recent recordings, 0 is ignored
+    pub(crate) keep_last: Option<usize>,
+    /// Delete recordings started more than this many days ago
+    pub(crate) max_age_days: Option<u64>,
+}
+
+impl RecordingRetention {
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.keep_last.is_some_and(|n| n > 0) || self.max_age_days.is_some()
+    }
+}
+
+/// Start time of a recording named `<track>_<YYYYmmdd-HHMMSS>[-<n>].<extension>` by
+/// `session_file_path`, `None` for any other file.
+fn recording_timestamp(path: &Path) -> Option<u64> {
+    let extension = path.extension()?.to_str()?;
+    if !RECORDING_EXTENSIONS.contains(&extension) {
+        return None;
+    }
+    let (_, timestamp) = path.file_stem()?.to_str()?.rsplit_once('_')?;
+    let (timestamp, suffix) = timestamp.split_at_checked(15)?;
+    let is_suffix_valid = suffix.is_empty()
+        || suffix
+            .strip_prefix('-')
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+    if !is_suffix_valid {
+        return None;
+    }
+    parse_utc_timestamp(timestamp)
+}
+
+/// Deletes the recordings in `dir` that fall outside `retention`, and their notes.
+///
+/// Only files named like the `--split-sessions` recordings are considered, anything
+/// else in the directory is left alone. Recordings are dated by the start time in
+/// their name. Returns the deleted recordings.
+pub(crate) fn apply_retention(
+    dir: &Path,
+    retention: &RecordingRetention,
+    now_s: u64,
+) -> Result<Vec<PathBuf>, OcypodeError> {
+    if !retention.is_enabled() || !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut recordings: Vec<(u64, PathBuf)> = std::fs::read_dir(dir)
+        .map_err(|e| OcypodeError::WriterError { source: e })?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter_map(|path| Some((recording_timestamp(&path)?, path)))
+        .collect();
+    // most recent first
+    recordings.sort_by(|a, b| b.cmp(a));
+
+    let keep_last = retention.keep_last.filter(|n| *n > 0).unwrap_or(usize::MAX);
+    let min_timestamp = retention
+        .max_age_days
+        .map_or(0, |days| now_s.saturating_sub(days.saturating_mul(86_400)));
+
+    let mut deleted = Vec::new();
+    for (i, (timestamp, path)) in recordings.into_iter().enumerate() {
+        if i < keep_last && timestamp >= min_timestamp {
+            continue;
+        }
+        if let Err(e) = std::fs::remove_file(&path) {
+            warn!("Could not delete old recording {:?}: {}", path, e);
+            continue;
+        }
+        info!("Deleted old recording {:?}", path);
+        let notes = crate::session_notes::notes_file_path(&path);
+        if notes.is_file()
+            && let Err(e) = std::fs::remove_file(&notes)
+        {
+            warn!("Could not delete session notes {:?}: {}", notes, e);
+        }
+        deleted.push(path);
+    }
+    Ok(deleted)
+}
+
+/// Returns an unused `<track>_<YYYYmmdd-HHMMSS>.<extension>` path in `dir`.
+fn session_file_path(dir: &Path, track_name: &str, extension: &str) -> PathBuf {
+    let track: String = track_name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let track = if track.is_empty() {
+        "unknown".to_string()
+    } else {
+        track
+    };
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let stem = format!("{}_{}", track, format_utc_timestamp(secs));
+
+    let mut path = dir.join(format!("{}.{}", stem, extension));
+    let mut suffix = 1;
+    while path.exists() {
+        path = dir.join(format!("{}-{}.{}", stem, suffix, extension));
+        suffix += 1;
+    }
+    path
+}
+
+/// Parses the `YYYYmmdd-HHMMSS` UTC timestamp written by `format_utc_timestamp` back
+/// into seconds since the Unix epoch.
+pub(crate) fn parse_utc_timestamp(timestamp: &str) -> Option<u64> {
+    let (date, time) = timestamp.split_once('-')?;
+    if date.len() != 8
+        || time.len() != 6
+        || !(date.chars().chain(time.chars())).all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let field = |s: &str, range: std::ops::Range<usize>| s[range].parse::<i64>().ok();
+    let (year, month, day) = (field(date, 0..4)?, field(date, 4..6)?, field(date, 6..8)?);
+    let (hour, minute, second) = (field(time, 0..2)?, field(time, 2..4)?, field(time, 4..6)?);
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+
+    // Days since 1970-01-01 from the civil date, see
+    // https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    u64::try_from(days * 86_400 + hour * 3600 + minute * 60 + second).ok()
+}
+
+/// Formats seconds since the Unix epoch as `YYYYmmdd-HHMMSS` in UTC.
+pub(crate) fn format_utc_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let secs_of_day = secs % 86_400;
+
+    // Civil date from days since 1970-01-01, see
+    // https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}{:02}{:02}-{:02}{:02}{:02}",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -262,4 +729,363 @@ mod tests {
             assert_eq!(data_point.get("game_source").unwrap(), "IRacing");
         }
     }
+
+    fn session_change(track_name: &str) -> TelemetryOutput {
+        TelemetryOutput::SessionChange(SessionInfo {
+            track_name: track_name.to_string(),
+            ..Default::default()
+        })
+    }
+
+    fn data_point(point_no: usize) -> TelemetryOutput {
+        TelemetryOutput::DataPoint(Box::new(TelemetryData {
+            point_no,
+            ..Default::default()
+        }))
+    }
+
+    fn read_lines(path: &Path) -> Vec<String> {
+        BufReader::new(File::open(path).unwrap())
+            .lines()
+            .map(|l| l.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_record_telemetry_splits_by_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let (tx, rx) = mpsc::channel();
+
+        tx.send(session_change("Laguna Seca")).unwrap();
+        tx.send(data_point(0)).unwrap();
+        tx.send(data_point(1)).unwrap();
+        tx.send(session_change("Monza")).unwrap();
+        tx.send(data_point(2)).unwrap();
+        drop(tx);
+
+        let shutdown = AtomicBool::new(false);
+        let recording = RecordingIndex::default();
+        record_telemetry(
+            dir.path(),
+            true,
+            "jsonl",
+            rx,
+            &shutdown,
+            &recording,
+            write_telemetry,
+        )
+        .unwrap();
+
+        let mut files: Vec<PathBuf> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        files.sort();
+        assert_eq!(files.len(), 2);
+
+        let laguna_seca = files[0].file_name().unwrap().to_str().unwrap();
+        assert!(laguna_seca.starts_with("laguna_seca_"));
+        assert!(laguna_seca.ends_with(".jsonl"));
+        assert_eq!(read_lines(&files[0]).len(), 3);
+
+        let monza = read_lines(&files[1]);
+        assert_eq!(monza.len(), 2);
+        let first: serde_json::Value = serde_json::from_str(&monza[0]).unwrap();
+        assert_eq!(first["SessionChange"]["track_name"], "Monza");
+
+        // each file holds a single session
+        assert_eq!(recording.session(0), Some((files[0].clone(), 0)));
+        assert_eq!(recording.session(1), Some((files[1].clone(), 0)));
+        assert_eq!(recording.session(2), None);
+    }
+
+    #[test]
+    fn test_record_telemetry_single_file() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let (tx, rx) = mpsc::channel();
+
+        tx.send(session_change("Laguna Seca")).unwrap();
+        tx.send(data_point(0)).unwrap();
+        // a header without points is replaced by the next one
+        tx.send(session_change("Spa")).unwrap();
+        tx.send(session_change("Monza")).unwrap();
+        tx.send(data_point(1)).unwrap();
+        drop(tx);
+
+        let shutdown = AtomicBool::new(false);
+        let recording = RecordingIndex::default();
+        record_telemetry(
+            temp_file.path(),
+            false,
+            "jsonl",
+            rx,
+            &shutdown,
+            &recording,
+            write_telemetry,
+        )
+        .unwrap();
+
+        assert_eq!(read_lines(temp_file.path()).len(), 5);
+        let path = temp_file.path().to_path_buf();
+        assert_eq!(recording.session(0), Some((path.clone(), 0)));
+        assert_eq!(recording.session(1), Some((path, 1)));
+    }
+
+    #[test]
+    fn test_record_telemetry_flushes_on_shutdown() {
+        let dir = tempfile::tempdir().unwrap();
+        let (tx, rx) = mpsc::channel();
+        let shutdown = std::sync::Arc::new(AtomicBool::new(false));
+
+        let recorder_shutdown = shutdown.clone();
+        let recorder_dir = dir.path().to_path_buf();
+        let recorder = thread::spawn(move || {
+            record_telemetry(
+                &recorder_dir,
+                true,
+                "jsonl",
+                rx,
+                &recorder_shutdown,
+                &RecordingIndex::default(),
+                write_telemetry,
+            )
+        });
+
+        tx.send(session_change("Spa")).unwrap();
+        tx.send(data_point(0)).unwrap();
+        // Ctrl-C with the sender still open like in a live session, the queued points are
+        // recorded before the recorder returns
+        shutdown.store(true, Ordering::SeqCst);
+        recorder.join().unwrap().unwrap();
+        drop(tx);
+
+        let files: Vec<PathBuf> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(files.len(), 1);
+        assert_eq!(read_lines(&files[0]).len(), 2);
+    }
+
+    #[test]
+    fn test_record_telemetry_drains_queue_on_shutdown() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let (tx, rx) = mpsc::channel();
+
+        tx.send(session_change("Spa")).unwrap();
+        for point_no in 0..1000 {
+            tx.send(data_point(point_no)).unwrap();
+        }
+        // Ctrl-C before the recorder got to any of the points, with the collector still
+        // connected
+        let shutdown = AtomicBool::new(true);
+        record_telemetry(
+            temp_file.path(),
+            false,
+            "jsonl",
+            rx,
+            &shutdown,
+            &RecordingIndex::default(),
+            write_telemetry,
+        )
+        .unwrap();
+
+        let lines = read_lines(temp_file.path());
+        assert_eq!(lines.len(), 1001);
+        let last: serde_json::Value = serde_json::from_str(&lines[1000]).unwrap();
+        assert_eq!(last["DataPoint"]["point_no"], 999);
+        drop(tx);
+    }
+
+    #[test]
+    fn test_format_utc_timestamp() {
+        assert_eq!(format_utc_timestamp(0), "19700101-000000");
+        assert_eq!(format_utc_timestamp(951_782_400), "20000229-000000");
+        assert_eq!(format_utc_timestamp(1_792_161_012), "20261016-143012");
+    }
+
+    #[test]
+    fn test_parse_utc_timestamp() {
+        assert_eq!(parse_utc_timestamp("19700101-000000"), Some(0));
+        assert_eq!(parse_utc_timestamp("20000229-000000"), Some(951_782_400));
+        assert_eq!(parse_utc_timestamp("20261016-143012"), Some(1_792_161_012));
+        assert_eq!(parse_utc_timestamp("20261316-143012"), None);
+        assert_eq!(parse_utc_timestamp("monza"), None);
+    }
+
+    #[test]
+    fn test_session_file_path_sanitizes_track_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = session_file_path(dir.path(), "Nürburgring / GP", "parquet");
+        let name = path.file_name().unwrap().to_str().unwrap();
+        assert!(name.starts_with("n_rburgring___gp_"));
+        assert!(name.ends_with(".parquet"));
+
+        let unknown = session_file_path(dir.path(), "", "jsonl");
+        assert!(
+            unknown
+                .file_name()
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .starts_with("unknown_")
+        );
+    }
+
+    #[test]
+    fn test_write_jsonl_writes_one_line_per_output() {
+        let (tx, rx) = mpsc::channel();
+        tx.send(TelemetryOutput::SessionChange(SessionInfo::default()))
+            .unwrap();
+        for point_no in 0..3 {
+            tx.send(TelemetryOutput::DataPoint(Box::new(TelemetryData {
+                point_no,
+                ..Default::default()
+            })))
+            .unwrap();
+        }
+        drop(tx);
+
+        let mut output = Vec::new();
+        write_jsonl(&mut output, rx).unwrap();
+
+        let lines: Vec<&str> = std::str::from_utf8(&output).unwrap().lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("{\"SessionChange\""));
+        let last: serde_json::Value = serde_json::from_str(lines[3]).unwrap();
+        assert_eq!(last["DataPoint"]["point_no"], 2);
+    }
+
+    /// Writer that fails every write, like stdout piped into a tool that exited
+    struct BrokenPipe {
+        writes: usize,
+    }
+
+    impl Write for BrokenPipe {
+        fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+            self.writes += 1;
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_jsonl_stops_at_the_first_write_error() {
+        let (tx, rx) = mpsc::channel();
+        for point_no in 0..3 {
+            tx.send(TelemetryOutput::DataPoint(Box::new(TelemetryData {
+                point_no,
+                ..Default::default()
+            })))
+            .unwrap();
+        }
+
+        let mut output = BrokenPipe { writes: 0 };
+        assert!(matches!(
+            write_jsonl(&mut output, rx),
+            Err(OcypodeError::WriterError { .. })
+        ));
+        assert_eq!(output.writes, 1);
+        // the receiver is gone, so the collector drops this sink
+        assert!(
+            tx.send(TelemetryOutput::SessionChange(SessionInfo::default()))
+                .is_err()
+        );
+    }
+
+    /// Creates empty recordings named after `timestamps` in `dir`
+    fn recordings(dir: &Path, timestamps: &[u64]) -> Vec<PathBuf> {
+        timestamps
+            .iter()
+            .map(|t| {
+                let path = dir.join(format!("monza_{}.jsonl", format_utc_timestamp(*t)));
+                File::create(&path).unwrap();
+                path
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_apply_retention_disabled_by_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = recordings(dir.path(), &[0, 86_400]);
+
+        let deleted =
+            apply_retention(dir.path(), &RecordingRetention::default(), 100 * 86_400).unwrap();
+
+        assert!(deleted.is_empty());
+        assert!(files.iter().all(|f| f.exists()));
+    }
+
+    #[test]
+    fn test_apply_retention_keeps_last_recordings() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = recordings(dir.path(), &[3 * 86_400, 86_400, 2 * 86_400]);
+        let notes = crate::session_notes::notes_file_path(&files[1]);
+        File::create(&notes).unwrap();
+        let other = dir.path().join("my_session.jsonl");
+        File::create(&other).unwrap();
+
+        let retention = RecordingRetention {
+            keep_last: Some(2),
+            max_age_days: None,
+        };
+        let deleted = apply_retention(dir.path(), &retention, 4 * 86_400).unwrap();
+
+        assert_eq!(deleted, vec![files[1].clone()]);
+        assert!(!notes.exists());
+        assert!(files[0].exists() && files[2].exists());
+        assert!(other.exists());
+    }
+
+    #[test]
+    fn test_apply_retention_deletes_old_recordings() {
+        let dir = tempfile::tempdir().unwrap();
+        let now = 30 * 86_400;
+        let files = recordings(dir.path(), &[now - 10 * 86_400, now - 86_400]);
+
+        let retention = RecordingRetention {
+            keep_last: Some(0),
+            max_age_days: Some(7),
+        };
+        let deleted = apply_retention(dir.path(), &retention, now).unwrap();
+
+        assert_eq!(deleted, vec![files[0].clone()]);
+        assert!(files[1].exists());
+    }
+
+    #[test]
+    fn test_apply_retention_keeps_everything_for_huge_ages() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = recordings(dir.path(), &[0, 86_400]);
+
+        let retention = RecordingRetention {
+            keep_last: Some(0),
+            max_age_days: Some(u64::MAX),
+        };
+        let deleted = apply_retention(dir.path(), &retention, 2 * 86_400).unwrap();
+
+        assert!(deleted.is_empty());
+        assert!(files.iter().all(|file| file.exists()));
+    }
+
+    #[test]
+    fn test_recording_timestamp() {
+        assert_eq!(
+            recording_timestamp(Path::new("laguna_seca_20261016-143012-1.parquet")),
+            Some(1_792_161_012)
+        );
+        assert_eq!(
+            recording_timestamp(Path::new("monza_20261016-143012.notes.json")),
+            None
+        );
+        assert_eq!(
+            recording_timestamp(Path::new("monza_20261016-143012-copy.jsonl")),
+            None
+        );
+        assert_eq!(recording_timestamp(Path::new("my_session.jsonl")), None);
+    }
 }
@@ -0,0 +1,166 @@
+use std::io::Write;
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+
+use ocypode::telemetry::{GameSource, TelemetryAnnotation};
+use ocypode::{
+    MockTelemetryProducer, NetworkTelemetryProducer, SessionInfo, TelemetryData,
+    TelemetryDataBuilder, TelemetryOutput, annotation_channel, collect_telemetry,
+};
+
+fn point(point_no: usize, gear: i8, engine_rpm: f32) -> TelemetryData {
+    TelemetryDataBuilder::new()
+        .point_no(point_no)
+        .timestamp_ms(point_no as u128 * 100)
+        .game_source(GameSource::IRacing)
+        .gear(gear)
+        .engine_rpm(engine_rpm)
+        .shift_point_rpm(7000.0)
+        .speed_mps(40.0 + point_no as f32)
+        .throttle(1.0)
+        .brake(0.0)
+        .clutch(0.0)
+        .build()
+}
+
+#[test]
+fn test_replayed_frames_are_annotated() {
+    // upshift at 5000 RPM, well below the 7000 RPM shift point
+    let points = vec![
+        point(0, 2, 4800.0),
+        point(1, 2, 5000.0),
+        point(2, 3, 4000.0),
+    ];
+    let mut producer = MockTelemetryProducer::from_points(points);
+    producer.track_name = "Test Track".to_string();
+    producer.max_steering_angle = 720.0;
+
+    let (telemetry_sender, telemetry_receiver) = mpsc::channel();
+    let (writer_sender, writer_receiver) = mpsc::channel();
+
+    // stops with an error once the mock runs out of points
+    assert!(collect_telemetry(producer, vec![telemetry_sender, writer_sender]).is_err());
+
+    for receiver in [telemetry_receiver, writer_receiver] {
+        let outputs: Vec<TelemetryOutput> = receiver.try_iter().collect();
+        assert_eq!(outputs.len(), 4);
+        match &outputs[0] {
+            TelemetryOutput::SessionChange(session_info) => {
+                assert_eq!(session_info.track_name, "Test Track")
+            }
+            other => panic!("Expected SessionChange, got {:?}", other),
+        }
+
+        let points: Vec<&TelemetryData> = outputs[1..]
+            .iter()
+            .map(|output| match output {
+                TelemetryOutput::DataPoint(point) => point.as_ref(),
+                other => panic!("Expected DataPoint, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            points.iter().map(|p| p.point_no).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert!(points[2].annotations.iter().any(|a| matches!(
+            a,
+            TelemetryAnnotation::ShiftPoint {
+                is_under_revving: true,
+                ..
+            }
+        )));
+    }
+}
+
+#[test]
+fn test_remote_stream_is_annotated_locally() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    // stands in for `ocypode serve`, closing the connection once everything is sent
+    let sender = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let session = TelemetryOutput::SessionChange(SessionInfo {
+            track_name: "Remote Track".to_string(),
+            ..SessionInfo::default()
+        });
+        let mut first = point(0, 2, 4800.0);
+        // annotations from the sending machine are replaced by the local analyzers
+        first.annotations = vec![TelemetryAnnotation::ShortShifting {
+            gear_change_rpm: 4800.0,
+            optimal_rpm: 7000.0,
+            is_short_shifting: true,
+        }];
+        let outputs = [
+            session,
+            TelemetryOutput::DataPoint(Box::new(first)),
+            TelemetryOutput::DataPoint(Box::new(point(1, 2, 5000.0))),
+            TelemetryOutput::DataPoint(Box::new(point(2, 3, 4000.0))),
+        ];
+        for output in outputs {
+            writeln!(stream, "{}", serde_json::to_string(&output).unwrap()).unwrap();
+        }
+    });
+
+    let (telemetry_sender, telemetry_receiver) = mpsc::channel();
+    // stops with an error once the stream closes
+    assert!(
+        collect_telemetry(
+            NetworkTelemetryProducer::new(address),
+            vec![telemetry_sender]
+        )
+        .is_err()
+    );
+    sender.join().unwrap();
+
+    let outputs: Vec<TelemetryOutput> = telemetry_receiver.try_iter().collect();
+    assert_eq!(outputs.len(), 4);
+    match &outputs[0] {
+        TelemetryOutput::SessionChange(session_info) => {
+            assert_eq!(session_info.track_name, "Remote Track")
+        }
+        other => panic!("Expected SessionChange, got {:?}", other),
+    }
+    let points: Vec<&TelemetryData> = outputs[1..]
+        .iter()
+        .map(|output| match output {
+            TelemetryOutput::DataPoint(point) => point.as_ref(),
+            other => panic!("Expected DataPoint, got {:?}", other),
+        })
+        .collect();
+    assert!(points[0].annotations.is_empty());
+    assert!(points[2].annotations.iter().any(|a| matches!(
+        a,
+        TelemetryAnnotation::ShiftPoint {
+            is_under_revving: true,
+            ..
+        }
+    )));
+}
+
+#[test]
+fn test_annotations_are_streamed_as_detected() {
+    let points = vec![
+        point(0, 2, 4800.0),
+        point(1, 2, 5000.0),
+        point(2, 3, 4000.0),
+    ];
+    let mut producer = MockTelemetryProducer::from_points(points);
+    producer.track_name = "Test Track".to_string();
+    producer.max_steering_angle = 720.0;
+
+    let (annotation_sender, annotations) = annotation_channel();
+    assert!(collect_telemetry(producer, vec![annotation_sender]).is_err());
+
+    // the channel closes once the collector stops
+    let events: Vec<_> = annotations.iter().collect();
+    assert!(!events.is_empty());
+    assert!(events.iter().all(|event| event.annotation.is_active()));
+    let short_shift = events
+        .iter()
+        .find(|event| matches!(event.annotation, TelemetryAnnotation::ShiftPoint { .. }))
+        .unwrap();
+    assert_eq!(short_shift.point_no, 2);
+    assert_eq!(short_shift.timestamp_ms, 200);
+}